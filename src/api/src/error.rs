@@ -34,6 +34,12 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Column datatype {:?} is not supported by the protocol yet", datatype))]
+    UnsupportedColumnDataType {
+        datatype: ConcreteDataType,
+        location: Location,
+    },
+
     #[snafu(display("Failed to convert column default constraint, column: {}", column))]
     ConvertColumnDefaultConstraint {
         column: String,
@@ -54,6 +60,7 @@ impl ErrorExt for Error {
         match self {
             Error::UnknownColumnDataType { .. } => StatusCode::InvalidArguments,
             Error::IntoColumnDataType { .. } => StatusCode::Unexpected,
+            Error::UnsupportedColumnDataType { .. } => StatusCode::Unsupported,
             Error::ConvertColumnDefaultConstraint { source, .. }
             | Error::InvalidColumnDefaultConstraint { source, .. } => source.status_code(),
         }
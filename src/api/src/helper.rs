@@ -156,6 +156,11 @@ impl TryFrom<ConcreteDataType> for ColumnDataTypeWrapper {
                 DurationType::Microsecond(_) => ColumnDataType::DurationMicrosecond,
                 DurationType::Nanosecond(_) => ColumnDataType::DurationNanosecond,
             },
+            // The protocol can't mark a binary column as JSON or carry the precision
+            // and scale of decimals yet.
+            ConcreteDataType::Json(_) | ConcreteDataType::Decimal128(_) => {
+                return error::UnsupportedColumnDataTypeSnafu { datatype }.fail()
+            }
             ConcreteDataType::Null(_)
             | ConcreteDataType::List(_)
             | ConcreteDataType::Dictionary(_) => {
@@ -292,58 +297,72 @@ pub fn values_with_capacity(datatype: ColumnDataType, capacity: usize) -> Values
 }
 
 // The type of vals must be same.
-pub fn push_vals(column: &mut Column, origin_count: usize, vector: VectorRef) {
+pub fn push_vals(column: &mut Column, origin_count: usize, vector: VectorRef) -> Result<()> {
     let values = column.values.get_or_insert_with(Values::default);
     let mut null_mask = BitVec::from_slice(&column.null_mask);
     let len = vector.len();
     null_mask.reserve_exact(origin_count + len);
     null_mask.extend(BitVec::repeat(false, len));
 
-    (0..len).for_each(|idx| match vector.get(idx) {
-        Value::Null => null_mask.set(idx + origin_count, true),
-        Value::Boolean(val) => values.bool_values.push(val),
-        Value::UInt8(val) => values.u8_values.push(val.into()),
-        Value::UInt16(val) => values.u16_values.push(val.into()),
-        Value::UInt32(val) => values.u32_values.push(val),
-        Value::UInt64(val) => values.u64_values.push(val),
-        Value::Int8(val) => values.i8_values.push(val.into()),
-        Value::Int16(val) => values.i16_values.push(val.into()),
-        Value::Int32(val) => values.i32_values.push(val),
-        Value::Int64(val) => values.i64_values.push(val),
-        Value::Float32(val) => values.f32_values.push(*val),
-        Value::Float64(val) => values.f64_values.push(*val),
-        Value::String(val) => values.string_values.push(val.as_utf8().to_string()),
-        Value::Binary(val) => values.binary_values.push(val.to_vec()),
-        Value::Date(val) => values.date_values.push(val.val()),
-        Value::DateTime(val) => values.datetime_values.push(val.val()),
-        Value::Timestamp(val) => match val.unit() {
-            TimeUnit::Second => values.timestamp_second_values.push(val.value()),
-            TimeUnit::Millisecond => values.timestamp_millisecond_values.push(val.value()),
-            TimeUnit::Microsecond => values.timestamp_microsecond_values.push(val.value()),
-            TimeUnit::Nanosecond => values.timestamp_nanosecond_values.push(val.value()),
-        },
-        Value::Time(val) => match val.unit() {
-            TimeUnit::Second => values.time_second_values.push(val.value()),
-            TimeUnit::Millisecond => values.time_millisecond_values.push(val.value()),
-            TimeUnit::Microsecond => values.time_microsecond_values.push(val.value()),
-            TimeUnit::Nanosecond => values.time_nanosecond_values.push(val.value()),
-        },
-        Value::Interval(val) => match val.unit() {
-            IntervalUnit::YearMonth => values.interval_year_month_values.push(val.to_i32()),
-            IntervalUnit::DayTime => values.interval_day_time_values.push(val.to_i64()),
-            IntervalUnit::MonthDayNano => values
-                .interval_month_day_nano_values
-                .push(convert_i128_to_interval(val.to_i128())),
-        },
-        Value::Duration(val) => match val.unit() {
-            TimeUnit::Second => values.duration_second_values.push(val.value()),
-            TimeUnit::Millisecond => values.duration_millisecond_values.push(val.value()),
-            TimeUnit::Microsecond => values.duration_microsecond_values.push(val.value()),
-            TimeUnit::Nanosecond => values.duration_nanosecond_values.push(val.value()),
-        },
-        Value::List(_) => unreachable!(),
-    });
+    for idx in 0..len {
+        match vector.get(idx) {
+            Value::Null => null_mask.set(idx + origin_count, true),
+            Value::Boolean(val) => values.bool_values.push(val),
+            Value::UInt8(val) => values.u8_values.push(val.into()),
+            Value::UInt16(val) => values.u16_values.push(val.into()),
+            Value::UInt32(val) => values.u32_values.push(val),
+            Value::UInt64(val) => values.u64_values.push(val),
+            Value::Int8(val) => values.i8_values.push(val.into()),
+            Value::Int16(val) => values.i16_values.push(val.into()),
+            Value::Int32(val) => values.i32_values.push(val),
+            Value::Int64(val) => values.i64_values.push(val),
+            Value::Float32(val) => values.f32_values.push(*val),
+            Value::Float64(val) => values.f64_values.push(*val),
+            Value::String(val) => values.string_values.push(val.as_utf8().to_string()),
+            Value::Binary(val) => values.binary_values.push(val.to_vec()),
+            Value::Date(val) => values.date_values.push(val.val()),
+            Value::DateTime(val) => values.datetime_values.push(val.val()),
+            Value::Timestamp(val) => match val.unit() {
+                TimeUnit::Second => values.timestamp_second_values.push(val.value()),
+                TimeUnit::Millisecond => values.timestamp_millisecond_values.push(val.value()),
+                TimeUnit::Microsecond => values.timestamp_microsecond_values.push(val.value()),
+                TimeUnit::Nanosecond => values.timestamp_nanosecond_values.push(val.value()),
+            },
+            Value::Time(val) => match val.unit() {
+                TimeUnit::Second => values.time_second_values.push(val.value()),
+                TimeUnit::Millisecond => values.time_millisecond_values.push(val.value()),
+                TimeUnit::Microsecond => values.time_microsecond_values.push(val.value()),
+                TimeUnit::Nanosecond => values.time_nanosecond_values.push(val.value()),
+            },
+            Value::Interval(val) => match val.unit() {
+                IntervalUnit::YearMonth => values.interval_year_month_values.push(val.to_i32()),
+                IntervalUnit::DayTime => values.interval_day_time_values.push(val.to_i64()),
+                IntervalUnit::MonthDayNano => values
+                    .interval_month_day_nano_values
+                    .push(convert_i128_to_interval(val.to_i128())),
+            },
+            Value::Duration(val) => match val.unit() {
+                TimeUnit::Second => values.duration_second_values.push(val.value()),
+                TimeUnit::Millisecond => values.duration_millisecond_values.push(val.value()),
+                TimeUnit::Microsecond => values.duration_microsecond_values.push(val.value()),
+                TimeUnit::Nanosecond => values.duration_nanosecond_values.push(val.value()),
+            },
+            value @ Value::Decimal128(_) => {
+                return error::UnsupportedColumnDataTypeSnafu {
+                    datatype: value.data_type(),
+                }
+                .fail()
+            }
+            Value::List(_) => {
+                return error::IntoColumnDataTypeSnafu {
+                    from: vector.data_type(),
+                }
+                .fail()
+            }
+        }
+    }
     column.null_mask = null_mask.into_vec();
+    Ok(())
 }
 
 /// Returns the type name of the [Request].
@@ -439,8 +458,8 @@ pub fn pb_value_to_value_ref(value: &v1::Value) -> ValueRef {
     }
 }
 
-pub fn pb_values_to_vector_ref(data_type: &ConcreteDataType, values: Values) -> VectorRef {
-    match data_type {
+pub fn pb_values_to_vector_ref(data_type: &ConcreteDataType, values: Values) -> Result<VectorRef> {
+    let vector: VectorRef = match data_type {
         ConcreteDataType::Boolean(_) => Arc::new(BooleanVector::from(values.bool_values)),
         ConcreteDataType::Int8(_) => Arc::new(PrimitiveVector::<Int8Type>::from_iter_values(
             values.i8_values.into_iter().map(|x| x as i8),
@@ -460,7 +479,9 @@ pub fn pb_values_to_vector_ref(data_type: &ConcreteDataType, values: Values) ->
         ConcreteDataType::UInt64(_) => Arc::new(UInt64Vector::from_vec(values.u64_values)),
         ConcreteDataType::Float32(_) => Arc::new(Float32Vector::from_vec(values.f32_values)),
         ConcreteDataType::Float64(_) => Arc::new(Float64Vector::from_vec(values.f64_values)),
-        ConcreteDataType::Binary(_) => Arc::new(BinaryVector::from(values.binary_values)),
+        ConcreteDataType::Binary(_) | ConcreteDataType::Json(_) => {
+            Arc::new(BinaryVector::from(values.binary_values))
+        }
         ConcreteDataType::String(_) => Arc::new(StringVector::from_vec(values.string_values)),
         ConcreteDataType::Date(_) => Arc::new(DateVector::from_vec(values.date_values)),
         ConcreteDataType::DateTime(_) => Arc::new(DateTimeVector::from_vec(values.datetime_values)),
@@ -522,15 +543,25 @@ pub fn pb_values_to_vector_ref(data_type: &ConcreteDataType, values: Values) ->
                 values.duration_nanosecond_values,
             )),
         },
+        ConcreteDataType::Decimal128(_) => {
+            return error::UnsupportedColumnDataTypeSnafu {
+                datatype: data_type.clone(),
+            }
+            .fail()
+        }
         ConcreteDataType::Null(_) | ConcreteDataType::List(_) | ConcreteDataType::Dictionary(_) => {
-            unreachable!()
+            return error::IntoColumnDataTypeSnafu {
+                from: data_type.clone(),
+            }
+            .fail()
         }
-    }
+    };
+    Ok(vector)
 }
 
-pub fn pb_values_to_values(data_type: &ConcreteDataType, values: Values) -> Vec<Value> {
+pub fn pb_values_to_values(data_type: &ConcreteDataType, values: Values) -> Result<Vec<Value>> {
     // TODO(fys): use macros to optimize code
-    match data_type {
+    let values = match data_type {
         ConcreteDataType::Int64(_) => values
             .i64_values
             .into_iter()
@@ -595,7 +626,7 @@ pub fn pb_values_to_values(data_type: &ConcreteDataType, values: Values) -> Vec<
             .into_iter()
             .map(|val| val.into())
             .collect(),
-        ConcreteDataType::Binary(_) => values
+        ConcreteDataType::Binary(_) | ConcreteDataType::Json(_) => values
             .binary_values
             .into_iter()
             .map(|val| val.into())
@@ -692,10 +723,20 @@ pub fn pb_values_to_values(data_type: &ConcreteDataType, values: Values) -> Vec<
             .into_iter()
             .map(|v| Value::Duration(Duration::new_nanosecond(v)))
             .collect(),
+        ConcreteDataType::Decimal128(_) => {
+            return error::UnsupportedColumnDataTypeSnafu {
+                datatype: data_type.clone(),
+            }
+            .fail()
+        }
         ConcreteDataType::Null(_) | ConcreteDataType::List(_) | ConcreteDataType::Dictionary(_) => {
-            unreachable!()
+            return error::IntoColumnDataTypeSnafu {
+                from: data_type.clone(),
+            }
+            .fail()
         }
-    }
+    };
+    Ok(values)
 }
 
 /// Returns true if the pb semantic type is valid.
@@ -816,7 +857,7 @@ pub fn to_proto_value(value: Value) -> Option<v1::Value> {
                 value_data: Some(ValueData::DurationNanosecondValue(v.value())),
             },
         },
-        Value::List(_) => return None,
+        Value::Decimal128(_) | Value::List(_) => return None,
     };
 
     Some(proto_value)
@@ -875,7 +916,7 @@ pub fn to_column_data_type(data_type: &ConcreteDataType) -> Option<ColumnDataTyp
         ConcreteDataType::UInt64(_) => ColumnDataType::Uint64,
         ConcreteDataType::Float32(_) => ColumnDataType::Float32,
         ConcreteDataType::Float64(_) => ColumnDataType::Float64,
-        ConcreteDataType::Binary(_) => ColumnDataType::Binary,
+        ConcreteDataType::Binary(_) | ConcreteDataType::Json(_) => ColumnDataType::Binary,
         ConcreteDataType::String(_) => ColumnDataType::String,
         ConcreteDataType::Date(_) => ColumnDataType::Date,
         ConcreteDataType::DateTime(_) => ColumnDataType::Datetime,
@@ -908,9 +949,10 @@ pub fn to_column_data_type(data_type: &ConcreteDataType) -> Option<ColumnDataTyp
             ColumnDataType::IntervalMonthDayNano
         }
         ConcreteDataType::Interval(IntervalType::DayTime(_)) => ColumnDataType::IntervalDayTime,
-        ConcreteDataType::Null(_) | ConcreteDataType::List(_) | ConcreteDataType::Dictionary(_) => {
-            return None
-        }
+        ConcreteDataType::Decimal128(_)
+        | ConcreteDataType::Null(_)
+        | ConcreteDataType::List(_)
+        | ConcreteDataType::Dictionary(_) => return None,
     };
 
     Some(column_data_type)
@@ -919,19 +961,19 @@ pub fn to_column_data_type(data_type: &ConcreteDataType) -> Option<ColumnDataTyp
 pub fn vectors_to_rows<'a>(
     columns: impl Iterator<Item = &'a VectorRef>,
     row_count: usize,
-) -> Vec<Row> {
+) -> Result<Vec<Row>> {
     let mut rows = vec![Row { values: vec![] }; row_count];
     for column in columns {
         for (row_index, row) in rows.iter_mut().enumerate() {
-            row.values.push(value_to_grpc_value(column.get(row_index)))
+            row.values.push(value_to_grpc_value(column.get(row_index))?)
         }
     }
 
-    rows
+    Ok(rows)
 }
 
-pub fn value_to_grpc_value(value: Value) -> GrpcValue {
-    GrpcValue {
+pub fn value_to_grpc_value(value: Value) -> Result<GrpcValue> {
+    Ok(GrpcValue {
         value_data: match value {
             Value::Null => None,
             Value::Boolean(v) => Some(ValueData::BoolValue(v)),
@@ -974,9 +1016,14 @@ pub fn value_to_grpc_value(value: Value) -> GrpcValue {
                 TimeUnit::Microsecond => ValueData::DurationMicrosecondValue(v.value()),
                 TimeUnit::Nanosecond => ValueData::DurationNanosecondValue(v.value()),
             }),
-            Value::List(_) => unreachable!(),
+            value @ (Value::Decimal128(_) | Value::List(_)) => {
+                return error::UnsupportedColumnDataTypeSnafu {
+                    datatype: value.data_type(),
+                }
+                .fail()
+            }
         },
-    }
+    })
 }
 
 /// Returns true if the column type is equal to expected type.
@@ -998,7 +1045,7 @@ mod tests {
         TimestampMillisecondType, TimestampSecondType, UInt32Type,
     };
     use datatypes::vectors::{
-        BooleanVector, DurationMicrosecondVector, DurationMillisecondVector,
+        BooleanVector, Decimal128Vector, DurationMicrosecondVector, DurationMillisecondVector,
         DurationNanosecondVector, DurationSecondVector, IntervalDayTimeVector,
         IntervalMonthDayNanoVector, IntervalYearMonthVector, TimeMicrosecondVector,
         TimeMillisecondVector, TimeNanosecondVector, TimeSecondVector, TimestampMicrosecondVector,
@@ -1293,28 +1340,28 @@ mod tests {
         };
 
         let vector = Arc::new(TimestampNanosecondVector::from_vec(vec![1, 2, 3]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![1, 2, 3],
             column.values.as_ref().unwrap().timestamp_nanosecond_values
         );
 
         let vector = Arc::new(TimestampMillisecondVector::from_vec(vec![4, 5, 6]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![4, 5, 6],
             column.values.as_ref().unwrap().timestamp_millisecond_values
         );
 
         let vector = Arc::new(TimestampMicrosecondVector::from_vec(vec![7, 8, 9]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![7, 8, 9],
             column.values.as_ref().unwrap().timestamp_microsecond_values
         );
 
         let vector = Arc::new(TimestampSecondVector::from_vec(vec![10, 11, 12]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![10, 11, 12],
             column.values.as_ref().unwrap().timestamp_second_values
@@ -1334,28 +1381,28 @@ mod tests {
         };
 
         let vector = Arc::new(TimeNanosecondVector::from_vec(vec![1, 2, 3]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![1, 2, 3],
             column.values.as_ref().unwrap().time_nanosecond_values
         );
 
         let vector = Arc::new(TimeMillisecondVector::from_vec(vec![4, 5, 6]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![4, 5, 6],
             column.values.as_ref().unwrap().time_millisecond_values
         );
 
         let vector = Arc::new(TimeMicrosecondVector::from_vec(vec![7, 8, 9]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![7, 8, 9],
             column.values.as_ref().unwrap().time_microsecond_values
         );
 
         let vector = Arc::new(TimeSecondVector::from_vec(vec![10, 11, 12]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![10, 11, 12],
             column.values.as_ref().unwrap().time_second_values
@@ -1375,14 +1422,14 @@ mod tests {
         };
 
         let vector = Arc::new(IntervalYearMonthVector::from_vec(vec![1, 2, 3]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![1, 2, 3],
             column.values.as_ref().unwrap().interval_year_month_values
         );
 
         let vector = Arc::new(IntervalDayTimeVector::from_vec(vec![4, 5, 6]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![4, 5, 6],
             column.values.as_ref().unwrap().interval_day_time_values
@@ -1390,7 +1437,7 @@ mod tests {
 
         let vector = Arc::new(IntervalMonthDayNanoVector::from_vec(vec![7, 8, 9]));
         let len = vector.len();
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         (0..len).for_each(|i| {
             assert_eq!(
                 7 + i as i64,
@@ -1419,28 +1466,28 @@ mod tests {
         };
 
         let vector = Arc::new(DurationNanosecondVector::from_vec(vec![1, 2, 3]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![1, 2, 3],
             column.values.as_ref().unwrap().duration_nanosecond_values
         );
 
         let vector = Arc::new(DurationMicrosecondVector::from_vec(vec![7, 8, 9]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![7, 8, 9],
             column.values.as_ref().unwrap().duration_microsecond_values
         );
 
         let vector = Arc::new(DurationMillisecondVector::from_vec(vec![4, 5, 6]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![4, 5, 6],
             column.values.as_ref().unwrap().duration_millisecond_values
         );
 
         let vector = Arc::new(DurationSecondVector::from_vec(vec![10, 11, 12]));
-        push_vals(&mut column, 3, vector);
+        push_vals(&mut column, 3, vector).unwrap();
         assert_eq!(
             vec![10, 11, 12],
             column.values.as_ref().unwrap().duration_second_values
@@ -1464,7 +1511,7 @@ mod tests {
         let row_count = 4;
 
         let vector = Arc::new(BooleanVector::from(vec![Some(true), None, Some(false)]));
-        push_vals(&mut column, row_count, vector);
+        push_vals(&mut column, row_count, vector).unwrap();
         // Some(false), None, Some(true), Some(true), Some(true), None, Some(false)
         let bool_values = column.values.unwrap().bool_values;
         assert_eq!(vec![false, true, true, true, false], bool_values);
@@ -1490,7 +1537,8 @@ mod tests {
                 timestamp_second_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Timestamp(Timestamp::new_second(1_i64)),
             Value::Timestamp(Timestamp::new_second(2_i64)),
@@ -1505,7 +1553,8 @@ mod tests {
                 timestamp_millisecond_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Timestamp(Timestamp::new_millisecond(1_i64)),
             Value::Timestamp(Timestamp::new_millisecond(2_i64)),
@@ -1523,7 +1572,8 @@ mod tests {
                 time_second_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Time(Time::new_second(1_i64)),
             Value::Time(Time::new_second(2_i64)),
@@ -1538,7 +1588,8 @@ mod tests {
                 time_millisecond_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Time(Time::new_millisecond(1_i64)),
             Value::Time(Time::new_millisecond(2_i64)),
@@ -1556,7 +1607,8 @@ mod tests {
                 duration_second_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Duration(Duration::new_second(1_i64)),
             Value::Duration(Duration::new_second(2_i64)),
@@ -1571,7 +1623,8 @@ mod tests {
                 duration_millisecond_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Duration(Duration::new_millisecond(1_i64)),
             Value::Duration(Duration::new_millisecond(2_i64)),
@@ -1589,7 +1642,8 @@ mod tests {
                 interval_year_month_values: vec![1_i32, 2_i32, 3_i32],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Interval(Interval::from_year_month(1_i32)),
             Value::Interval(Interval::from_year_month(2_i32)),
@@ -1604,7 +1658,8 @@ mod tests {
                 interval_day_time_values: vec![1_i64, 2_i64, 3_i64],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Interval(Interval::from_i64(1_i64)),
             Value::Interval(Interval::from_i64(2_i64)),
@@ -1635,7 +1690,8 @@ mod tests {
                 ],
                 ..Default::default()
             },
-        );
+        )
+        .unwrap();
         let expect = vec![
             Value::Interval(Interval::from_month_day_nano(1, 2, 3)),
             Value::Interval(Interval::from_month_day_nano(5, 6, 7)),
@@ -1655,7 +1711,7 @@ mod tests {
                     };
 
                     let data_type = ConcreteDataType::[<$concrete_data_type _datatype>]();
-                    let result = pb_values_to_values(&data_type, values);
+                    let result = pb_values_to_values(&data_type, values).unwrap();
 
                     assert_eq!(
                         $expected_ret,
@@ -1810,7 +1866,7 @@ mod tests {
             Arc::new(string_vec),
         ];
 
-        let result = vectors_to_rows(vector_refs.iter(), 3);
+        let result = vectors_to_rows(vector_refs.iter(), 3).unwrap();
 
         assert_eq!(result.len(), 3);
 
@@ -1859,4 +1915,56 @@ mod tests {
         assert_eq!(values[6], ValueData::DateValue(30));
         assert_eq!(values[7], ValueData::StringValue("c".to_string()));
     }
+
+    #[test]
+    fn test_decimal128_datatype_conversion() {
+        let result: Result<ColumnDataTypeWrapper> =
+            ConcreteDataType::decimal128_datatype(10, 2).try_into();
+        assert!(matches!(
+            result,
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decimal128_values_conversion() {
+        let vector: VectorRef = Arc::new(
+            Decimal128Vector::from_values(vec![1, 2])
+                .with_precision_and_scale(10, 2)
+                .unwrap(),
+        );
+
+        let mut column = Column::default();
+        assert!(matches!(
+            push_vals(&mut column, 0, vector.clone()),
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+        assert!(matches!(
+            value_to_grpc_value(vector.get(0)),
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+        assert!(matches!(
+            vectors_to_rows([vector].iter(), 2),
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+
+        let data_type = ConcreteDataType::decimal128_datatype(10, 2);
+        assert!(matches!(
+            pb_values_to_vector_ref(&data_type, Values::default()),
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+        assert!(matches!(
+            pb_values_to_values(&data_type, Values::default()),
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_json_datatype_conversion() {
+        let result: Result<ColumnDataTypeWrapper> = ConcreteDataType::json_datatype().try_into();
+        assert!(matches!(
+            result,
+            Err(error::Error::UnsupportedColumnDataType { .. })
+        ));
+    }
 }
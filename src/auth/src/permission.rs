@@ -30,6 +30,11 @@ pub enum PermissionReq<'a> {
     PromStoreWrite,
     PromStoreRead,
     Otlp,
+    FlightSqlIngest,
+    /// Listing and killing the queries of other users by `SHOW PROCESSLIST` and `KILL`,
+    /// and reading their slow queries in `information_schema.slow_queries`. Users can
+    /// always list, kill and read their own queries.
+    ProcessAdmin,
 }
 
 #[derive(Debug)]
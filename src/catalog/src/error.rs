@@ -231,6 +231,26 @@ pub enum Error {
         source: common_meta::error::Error,
         location: Location,
     },
+
+    #[snafu(display("Failed to find table partitions, table id: {}", table_id))]
+    FindPartitions {
+        table_id: u32,
+        source: partition::error::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to list region statistics"))]
+    ListRegionStats {
+        source: BoxedError,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to plan view {}", name))]
+    PlanView {
+        name: String,
+        source: BoxedError,
+        location: Location,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -288,6 +308,9 @@ impl ErrorExt for Error {
             Error::QueryAccessDenied { .. } => StatusCode::AccessDenied,
             Error::Datafusion { .. } => StatusCode::EngineExecuteQuery,
             Error::TableMetadataManager { source, .. } => source.status_code(),
+            Error::FindPartitions { source, .. } => source.status_code(),
+            Error::ListRegionStats { source, .. } => source.status_code(),
+            Error::PlanView { source, .. } => source.status_code(),
         }
     }
 
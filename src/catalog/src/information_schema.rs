@@ -13,12 +13,23 @@
 // limitations under the License.
 
 mod columns;
+mod key_column_usage;
+mod memory_table;
+mod partitions;
+mod region_peers;
+mod region_statistics;
+mod schemata;
+mod slow_queries;
 mod tables;
+mod views;
 
 use std::collections::HashMap;
 use std::sync::{Arc, Weak};
 
-use common_catalog::consts::INFORMATION_SCHEMA_NAME;
+use common_catalog::consts::{
+    INFORMATION_SCHEMA_BUILD_INFO_TABLE_ID, INFORMATION_SCHEMA_ENGINES_TABLE_ID,
+    INFORMATION_SCHEMA_NAME,
+};
 use common_error::ext::BoxedError;
 use common_recordbatch::{RecordBatchStreamAdaptor, SendableRecordBatchStream};
 use datatypes::schema::SchemaRef;
@@ -34,12 +45,49 @@ use table::thin_table::{ThinTable, ThinTableAdapter};
 use table::TableRef;
 
 use self::columns::InformationSchemaColumns;
+use self::key_column_usage::InformationSchemaKeyColumnUsage;
+use self::memory_table::get_schema_columns;
+pub(crate) use self::memory_table::MemoryTable;
+use self::partitions::InformationSchemaPartitions;
+use self::region_peers::InformationSchemaRegionPeers;
+use self::region_statistics::InformationSchemaRegionStatistics;
+pub use self::region_statistics::{RegionStatsEntry, RegionStatsProvider, RegionStatsProviderRef};
+use self::schemata::InformationSchemaSchemata;
+use self::slow_queries::InformationSchemaSlowQueries;
+pub use self::slow_queries::{
+    SlowQueryEntry, SlowQueryProvider, SlowQueryProviderRef, SLOW_QUERY_OWNER,
+};
 use crate::error::Result;
 use crate::information_schema::tables::InformationSchemaTables;
+use crate::information_schema::views::InformationSchemaViews;
 use crate::CatalogManager;
 
 pub const TABLES: &str = "tables";
 pub const COLUMNS: &str = "columns";
+pub const SCHEMATA: &str = "schemata";
+pub const KEY_COLUMN_USAGE: &str = "key_column_usage";
+pub const ENGINES: &str = "engines";
+pub const PARTITIONS: &str = "partitions";
+pub const REGION_PEERS: &str = "region_peers";
+pub const REGION_STATISTICS: &str = "region_statistics";
+pub const BUILD_INFO: &str = "build_info";
+pub const SLOW_QUERIES: &str = "slow_queries";
+pub const VIEWS: &str = "views";
+
+/// Names of all the tables in information schema.
+pub const INFORMATION_SCHEMA_TABLE_NAMES: &[&str] = &[
+    TABLES,
+    COLUMNS,
+    SCHEMATA,
+    KEY_COLUMN_USAGE,
+    ENGINES,
+    PARTITIONS,
+    REGION_PEERS,
+    REGION_STATISTICS,
+    BUILD_INFO,
+    SLOW_QUERIES,
+    VIEWS,
+];
 
 pub struct InformationSchemaProvider {
     catalog_name: String,
@@ -55,27 +103,26 @@ impl InformationSchemaProvider {
     }
 
     /// Build a map of [TableRef] in information schema.
-    /// Including `tables` and `columns`.
+    /// Including all the tables listed in [INFORMATION_SCHEMA_TABLE_NAMES].
     pub fn build(
         catalog_name: String,
         catalog_manager: Weak<dyn CatalogManager>,
     ) -> HashMap<String, TableRef> {
         let provider = Self::new(catalog_name, catalog_manager);
 
-        let mut schema = HashMap::new();
-        schema.insert(TABLES.to_owned(), provider.table(TABLES).unwrap());
-        schema.insert(COLUMNS.to_owned(), provider.table(COLUMNS).unwrap());
-        schema
+        INFORMATION_SCHEMA_TABLE_NAMES
+            .iter()
+            .map(|name| (name.to_string(), provider.table(name).unwrap()))
+            .collect()
     }
 
     pub fn table(&self, name: &str) -> Option<TableRef> {
         self.information_table(name).map(|table| {
-            let table_info = Self::table_info(self.catalog_name.clone(), &table);
-            let filter_pushdown = FilterPushDownType::Unsupported;
-            let thin_table = ThinTable::new(table_info, filter_pushdown);
-
-            let data_source = Arc::new(InformationTableDataSource::new(table));
-            Arc::new(ThinTableAdapter::new(thin_table, data_source)) as _
+            build_table(
+                self.catalog_name.clone(),
+                INFORMATION_SCHEMA_NAME.to_string(),
+                table,
+            )
         })
     }
 
@@ -89,31 +136,86 @@ impl InformationSchemaProvider {
                 self.catalog_name.clone(),
                 self.catalog_manager.clone(),
             )) as _),
+            SCHEMATA => Some(Arc::new(InformationSchemaSchemata::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            KEY_COLUMN_USAGE => Some(Arc::new(InformationSchemaKeyColumnUsage::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            PARTITIONS => Some(Arc::new(InformationSchemaPartitions::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            REGION_PEERS => Some(Arc::new(InformationSchemaRegionPeers::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            REGION_STATISTICS => Some(Arc::new(InformationSchemaRegionStatistics::new(
+                self.catalog_manager.clone(),
+            )) as _),
+            SLOW_QUERIES => Some(Arc::new(InformationSchemaSlowQueries::new(
+                self.catalog_manager.clone(),
+            )) as _),
+            VIEWS => Some(Arc::new(InformationSchemaViews::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            ENGINES => Some(Arc::new(MemoryTable::new(
+                INFORMATION_SCHEMA_ENGINES_TABLE_ID,
+                ENGINES,
+                get_schema_columns(ENGINES),
+            )) as _),
+            BUILD_INFO => Some(Arc::new(MemoryTable::new(
+                INFORMATION_SCHEMA_BUILD_INFO_TABLE_ID,
+                BUILD_INFO,
+                get_schema_columns(BUILD_INFO),
+            )) as _),
             _ => None,
         }
     }
+}
 
-    fn table_info(catalog_name: String, table: &InformationTableRef) -> TableInfoRef {
-        let table_meta = TableMetaBuilder::default()
-            .schema(table.schema())
-            .primary_key_indices(vec![])
-            .next_column_id(0)
-            .build()
-            .unwrap();
-        let table_info = TableInfoBuilder::default()
-            .table_id(table.table_id())
-            .name(table.table_name().to_owned())
-            .catalog_name(catalog_name)
-            .schema_name(INFORMATION_SCHEMA_NAME.to_owned())
-            .meta(table_meta)
-            .table_type(table.table_type())
-            .build()
-            .unwrap();
-        Arc::new(table_info)
-    }
+/// Builds a [TableRef] in `schema_name` that reads from the virtual `table`.
+pub(crate) fn build_table(
+    catalog_name: String,
+    schema_name: String,
+    table: InformationTableRef,
+) -> TableRef {
+    let table_info = table_info(catalog_name, schema_name, &table);
+    let filter_pushdown = FilterPushDownType::Unsupported;
+    let thin_table = ThinTable::new(table_info, filter_pushdown);
+
+    let data_source = Arc::new(InformationTableDataSource::new(table));
+    Arc::new(ThinTableAdapter::new(thin_table, data_source)) as _
+}
+
+fn table_info(
+    catalog_name: String,
+    schema_name: String,
+    table: &InformationTableRef,
+) -> TableInfoRef {
+    let table_meta = TableMetaBuilder::default()
+        .schema(table.schema())
+        .primary_key_indices(vec![])
+        .next_column_id(0)
+        .build()
+        .unwrap();
+    let table_info = TableInfoBuilder::default()
+        .table_id(table.table_id())
+        .name(table.table_name().to_owned())
+        .catalog_name(catalog_name)
+        .schema_name(schema_name)
+        .meta(table_meta)
+        .table_type(table.table_type())
+        .build()
+        .unwrap();
+    Arc::new(table_info)
 }
 
-trait InformationTable {
+/// A virtual table in a system schema, e.g. `information_schema` or `pg_catalog`.
+pub(crate) trait InformationTable {
     fn table_id(&self) -> TableId;
 
     fn table_name(&self) -> &'static str;
@@ -127,7 +229,7 @@ trait InformationTable {
     }
 }
 
-type InformationTableRef = Arc<dyn InformationTable + Send + Sync>;
+pub(crate) type InformationTableRef = Arc<dyn InformationTable + Send + Sync>;
 
 struct InformationTableDataSource {
     table: InformationTableRef,
@@ -0,0 +1,167 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::consts::{FILE_ENGINE, MITO_ENGINE};
+use common_recordbatch::{RecordBatch, RecordBatches, SendableRecordBatchStream};
+use datatypes::prelude::ConcreteDataType;
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef};
+use datatypes::vectors::{StringVector, VectorRef};
+use snafu::ResultExt;
+use store_api::storage::TableId;
+
+use super::{InformationTable, BUILD_INFO, ENGINES};
+use crate::error::{CreateRecordBatchSnafu, Result};
+
+/// An information table whose content is fixed at build time and kept in memory.
+pub(crate) struct MemoryTable {
+    table_id: TableId,
+    table_name: &'static str,
+    schema: SchemaRef,
+    columns: Vec<VectorRef>,
+}
+
+impl MemoryTable {
+    pub(crate) fn new(
+        table_id: TableId,
+        table_name: &'static str,
+        (schema, columns): (SchemaRef, Vec<VectorRef>),
+    ) -> Self {
+        Self {
+            table_id,
+            table_name,
+            schema,
+            columns,
+        }
+    }
+}
+
+impl InformationTable for MemoryTable {
+    fn table_id(&self) -> TableId {
+        self.table_id
+    }
+
+    fn table_name(&self) -> &'static str {
+        self.table_name
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn to_stream(&self) -> Result<SendableRecordBatchStream> {
+        let batch = RecordBatch::new(self.schema.clone(), self.columns.clone())
+            .context(CreateRecordBatchSnafu)?;
+        let batches = RecordBatches::try_new(self.schema.clone(), vec![batch])
+            .context(CreateRecordBatchSnafu)?;
+        Ok(batches.as_stream())
+    }
+}
+
+fn string_columns(names: &[&str]) -> SchemaRef {
+    Arc::new(Schema::new(
+        names
+            .iter()
+            .map(|name| ColumnSchema::new(*name, ConcreteDataType::string_datatype(), false))
+            .collect(),
+    ))
+}
+
+fn string_vector(values: &[&str]) -> VectorRef {
+    Arc::new(StringVector::from(values.to_vec()))
+}
+
+/// Returns the schema and the content of the memory table `table_name`.
+///
+/// # Panics
+/// Panics if `table_name` is not a memory table.
+pub(super) fn get_schema_columns(table_name: &str) -> (SchemaRef, Vec<VectorRef>) {
+    match table_name {
+        // Columns are based on <https://dev.mysql.com/doc/refman/8.0/en/information-schema-engines-table.html>
+        ENGINES => (
+            string_columns(&[
+                "engine",
+                "support",
+                "comment",
+                "transactions",
+                "xa",
+                "savepoints",
+            ]),
+            vec![
+                string_vector(&[MITO_ENGINE, FILE_ENGINE]),
+                string_vector(&["DEFAULT", "YES"]),
+                string_vector(&[
+                    "Storage engine for time-series data",
+                    "Storage engine for external files",
+                ]),
+                string_vector(&["NO", "NO"]),
+                string_vector(&["NO", "NO"]),
+                string_vector(&["NO", "NO"]),
+            ],
+        ),
+
+        BUILD_INFO => {
+            let build_info = common_version::build_info();
+            (
+                string_columns(&[
+                    "git_branch",
+                    "git_commit",
+                    "git_commit_short",
+                    "git_dirty",
+                    "rustc_version",
+                    "source_time",
+                    "pkg_version",
+                ]),
+                vec![
+                    string_vector(&[build_info.branch]),
+                    string_vector(&[build_info.commit]),
+                    string_vector(&[build_info.commit_short]),
+                    string_vector(&[build_info.dirty]),
+                    string_vector(&[build_info.rustc]),
+                    string_vector(&[build_info.source_time]),
+                    string_vector(&[build_info.version]),
+                ],
+            )
+        }
+
+        _ => unreachable!("Unknown memory table: {}", table_name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_catalog::consts::INFORMATION_SCHEMA_ENGINES_TABLE_ID;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_engines_table() {
+        let table = MemoryTable::new(
+            INFORMATION_SCHEMA_ENGINES_TABLE_ID,
+            ENGINES,
+            get_schema_columns(ENGINES),
+        );
+        let stream = table.to_stream().unwrap();
+        let batches = RecordBatches::try_collect(stream).await.unwrap();
+        let expected = "\
++--------+---------+-------------------------------------+--------------+----+------------+
+| engine | support | comment                             | transactions | xa | savepoints |
++--------+---------+-------------------------------------+--------------+----+------------+
+| mito   | DEFAULT | Storage engine for time-series data | NO           | NO | NO         |
+| file   | YES     | Storage engine for external files   | NO           | NO | NO         |
++--------+---------+-------------------------------------+--------------+----+------------+";
+        assert_eq!(expected, batches.pretty_print().unwrap());
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Weak};
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use common_catalog::consts::INFORMATION_SCHEMA_REGION_PEERS_TABLE_ID;
+use common_error::ext::BoxedError;
+use common_meta::peer::Peer;
+use common_query::physical_plan::TaskContext;
+use common_recordbatch::adapter::RecordBatchStreamAdapter;
+use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter as DfRecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream as DfPartitionStream;
+use datafusion::physical_plan::SendableRecordBatchStream as DfSendableRecordBatchStream;
+use datatypes::prelude::{ConcreteDataType, ScalarVectorBuilder, VectorRef};
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef};
+use datatypes::vectors::{StringVectorBuilder, UInt64VectorBuilder};
+use snafu::{OptionExt, ResultExt};
+use store_api::storage::{RegionId, TableId};
+use table::metadata::TableType;
+
+use super::REGION_PEERS;
+use crate::error::{
+    CreateRecordBatchSnafu, InternalSnafu, Result, TableMetadataManagerSnafu,
+    UpgradeWeakCatalogManagerRefSnafu,
+};
+use crate::information_schema::InformationTable;
+use crate::kvbackend::KvBackendCatalogManager;
+use crate::CatalogManager;
+
+/// The `information_schema.region_peers` table implementation.
+pub(super) struct InformationSchemaRegionPeers {
+    schema: SchemaRef,
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+}
+
+impl InformationSchemaRegionPeers {
+    pub(super) fn new(catalog_name: String, catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            schema: Self::schema(),
+            catalog_name,
+            catalog_manager,
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new("table_catalog", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("table_schema", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("table_name", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("region_id", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("peer_id", ConcreteDataType::uint64_datatype(), true),
+            ColumnSchema::new("peer_addr", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new("is_leader", ConcreteDataType::string_datatype(), false),
+        ]))
+    }
+
+    fn builder(&self) -> InformationSchemaRegionPeersBuilder {
+        InformationSchemaRegionPeersBuilder::new(
+            self.schema.clone(),
+            self.catalog_name.clone(),
+            self.catalog_manager.clone(),
+        )
+    }
+}
+
+impl InformationTable for InformationSchemaRegionPeers {
+    fn table_id(&self) -> TableId {
+        INFORMATION_SCHEMA_REGION_PEERS_TABLE_ID
+    }
+
+    fn table_name(&self) -> &'static str {
+        REGION_PEERS
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn to_stream(&self) -> Result<SendableRecordBatchStream> {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        let stream = Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_region_peers()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ));
+        Ok(Box::pin(
+            RecordBatchStreamAdapter::try_new(stream)
+                .map_err(BoxedError::new)
+                .context(InternalSnafu)?,
+        ))
+    }
+}
+
+/// Builds the `information_schema.region_peers` table row by row.
+///
+/// Each row is a peer of a region, taken from the table routes.
+struct InformationSchemaRegionPeersBuilder {
+    schema: SchemaRef,
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+
+    catalog_names: StringVectorBuilder,
+    schema_names: StringVectorBuilder,
+    table_names: StringVectorBuilder,
+    region_ids: UInt64VectorBuilder,
+    peer_ids: UInt64VectorBuilder,
+    peer_addrs: StringVectorBuilder,
+    is_leaders: StringVectorBuilder,
+}
+
+impl InformationSchemaRegionPeersBuilder {
+    fn new(
+        schema: SchemaRef,
+        catalog_name: String,
+        catalog_manager: Weak<dyn CatalogManager>,
+    ) -> Self {
+        Self {
+            schema,
+            catalog_name,
+            catalog_manager,
+            catalog_names: StringVectorBuilder::with_capacity(42),
+            schema_names: StringVectorBuilder::with_capacity(42),
+            table_names: StringVectorBuilder::with_capacity(42),
+            region_ids: UInt64VectorBuilder::with_capacity(42),
+            peer_ids: UInt64VectorBuilder::with_capacity(42),
+            peer_addrs: StringVectorBuilder::with_capacity(42),
+            is_leaders: StringVectorBuilder::with_capacity(42),
+        }
+    }
+
+    /// Construct the `information_schema.region_peers` virtual table
+    async fn make_region_peers(&mut self) -> Result<RecordBatch> {
+        let catalog_name = self.catalog_name.clone();
+        let catalog_manager = self
+            .catalog_manager
+            .upgrade()
+            .context(UpgradeWeakCatalogManagerRefSnafu)?;
+        // Only tables managed by the kv backend have routes.
+        let Some(kv_catalog_manager) = catalog_manager
+            .as_any()
+            .downcast_ref::<KvBackendCatalogManager>()
+        else {
+            return self.finish();
+        };
+        let table_route_manager = kv_catalog_manager
+            .table_metadata_manager_ref()
+            .table_route_manager();
+
+        for schema_name in catalog_manager.schema_names(&catalog_name).await? {
+            if !catalog_manager
+                .schema_exists(&catalog_name, &schema_name)
+                .await?
+            {
+                continue;
+            }
+
+            for table_name in catalog_manager
+                .table_names(&catalog_name, &schema_name)
+                .await?
+            {
+                let Some(table) = catalog_manager
+                    .table(&catalog_name, &schema_name, &table_name)
+                    .await?
+                else {
+                    continue;
+                };
+                if table.table_type() != TableType::Base {
+                    continue;
+                }
+
+                let table_id = table.table_info().ident.table_id;
+                let Some(table_route) = table_route_manager
+                    .get(table_id)
+                    .await
+                    .context(TableMetadataManagerSnafu)?
+                else {
+                    continue;
+                };
+
+                for route in &table_route.region_routes {
+                    let region_id = route.region.id;
+                    self.add_region_peer(
+                        &catalog_name,
+                        &schema_name,
+                        &table_name,
+                        region_id,
+                        route.leader_peer.as_ref(),
+                        route.leader_peer.is_some(),
+                    );
+                    for follower in &route.follower_peers {
+                        self.add_region_peer(
+                            &catalog_name,
+                            &schema_name,
+                            &table_name,
+                            region_id,
+                            Some(follower),
+                            false,
+                        );
+                    }
+                }
+            }
+        }
+
+        self.finish()
+    }
+
+    fn add_region_peer(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        region_id: RegionId,
+        peer: Option<&Peer>,
+        is_leader: bool,
+    ) {
+        self.catalog_names.push(Some(catalog_name));
+        self.schema_names.push(Some(schema_name));
+        self.table_names.push(Some(table_name));
+        self.region_ids.push(Some(region_id.as_u64()));
+        self.peer_ids.push(peer.map(|p| p.id));
+        self.peer_addrs.push(peer.map(|p| p.addr.as_str()));
+        self.is_leaders
+            .push(Some(if is_leader { "Yes" } else { "No" }));
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch> {
+        let columns: Vec<VectorRef> = vec![
+            Arc::new(self.catalog_names.finish()),
+            Arc::new(self.schema_names.finish()),
+            Arc::new(self.table_names.finish()),
+            Arc::new(self.region_ids.finish()),
+            Arc::new(self.peer_ids.finish()),
+            Arc::new(self.peer_addrs.finish()),
+            Arc::new(self.is_leaders.finish()),
+        ];
+        RecordBatch::new(self.schema.clone(), columns).context(CreateRecordBatchSnafu)
+    }
+}
+
+impl DfPartitionStream for InformationSchemaRegionPeers {
+    fn schema(&self) -> &ArrowSchemaRef {
+        self.schema.arrow_schema()
+    }
+
+    fn execute(&self, _: Arc<TaskContext>) -> DfSendableRecordBatchStream {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_region_peers()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ))
+    }
+}
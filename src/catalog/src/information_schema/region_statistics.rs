@@ -0,0 +1,239 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Weak};
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use common_catalog::consts::INFORMATION_SCHEMA_REGION_STATISTICS_TABLE_ID;
+use common_error::ext::BoxedError;
+use common_query::physical_plan::TaskContext;
+use common_recordbatch::adapter::RecordBatchStreamAdapter;
+use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter as DfRecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream as DfPartitionStream;
+use datafusion::physical_plan::SendableRecordBatchStream as DfSendableRecordBatchStream;
+use datatypes::prelude::{ConcreteDataType, ScalarVectorBuilder, VectorRef};
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef};
+use datatypes::vectors::{StringVectorBuilder, UInt32VectorBuilder, UInt64VectorBuilder};
+use snafu::{OptionExt, ResultExt};
+use store_api::storage::{RegionId, TableId};
+
+use super::REGION_STATISTICS;
+use crate::error::{
+    CreateRecordBatchSnafu, InternalSnafu, ListRegionStatsSnafu, Result,
+    UpgradeWeakCatalogManagerRefSnafu,
+};
+use crate::information_schema::InformationTable;
+use crate::kvbackend::KvBackendCatalogManager;
+use crate::CatalogManager;
+
+/// Statistics of a region, as reported by the datanode that serves it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionStatsEntry {
+    pub region_id: RegionId,
+    pub engine: String,
+    pub num_rows: u64,
+    /// Approximate bytes of the region, including memtables and SST files.
+    pub disk_size: u64,
+    /// Bytes of the memtables, if the datanode reports it.
+    pub memtable_size: Option<u64>,
+    /// Bytes of the SST files, if the datanode reports it.
+    pub sst_size: Option<u64>,
+}
+
+/// Provides the statistics of all the regions in the cluster.
+#[async_trait::async_trait]
+pub trait RegionStatsProvider: Send + Sync {
+    async fn list_region_stats(&self) -> std::result::Result<Vec<RegionStatsEntry>, BoxedError>;
+}
+
+pub type RegionStatsProviderRef = Arc<dyn RegionStatsProvider>;
+
+/// The `information_schema.region_statistics` table implementation.
+pub(super) struct InformationSchemaRegionStatistics {
+    schema: SchemaRef,
+    catalog_manager: Weak<dyn CatalogManager>,
+}
+
+impl InformationSchemaRegionStatistics {
+    pub(super) fn new(catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            schema: Self::schema(),
+            catalog_manager,
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new("region_id", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("table_id", ConcreteDataType::uint32_datatype(), false),
+            ColumnSchema::new("region_number", ConcreteDataType::uint32_datatype(), false),
+            ColumnSchema::new("engine", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new("region_rows", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("disk_size", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("memtable_size", ConcreteDataType::uint64_datatype(), true),
+            ColumnSchema::new("sst_size", ConcreteDataType::uint64_datatype(), true),
+        ]))
+    }
+
+    fn builder(&self) -> InformationSchemaRegionStatisticsBuilder {
+        InformationSchemaRegionStatisticsBuilder::new(
+            self.schema.clone(),
+            self.catalog_manager.clone(),
+        )
+    }
+}
+
+impl InformationTable for InformationSchemaRegionStatistics {
+    fn table_id(&self) -> TableId {
+        INFORMATION_SCHEMA_REGION_STATISTICS_TABLE_ID
+    }
+
+    fn table_name(&self) -> &'static str {
+        REGION_STATISTICS
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn to_stream(&self) -> Result<SendableRecordBatchStream> {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        let stream = Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_region_statistics()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ));
+        Ok(Box::pin(
+            RecordBatchStreamAdapter::try_new(stream)
+                .map_err(BoxedError::new)
+                .context(InternalSnafu)?,
+        ))
+    }
+}
+
+/// Builds the `information_schema.region_statistics` table row by row.
+struct InformationSchemaRegionStatisticsBuilder {
+    schema: SchemaRef,
+    catalog_manager: Weak<dyn CatalogManager>,
+
+    region_ids: UInt64VectorBuilder,
+    table_ids: UInt32VectorBuilder,
+    region_numbers: UInt32VectorBuilder,
+    engines: StringVectorBuilder,
+    region_rows: UInt64VectorBuilder,
+    disk_sizes: UInt64VectorBuilder,
+    memtable_sizes: UInt64VectorBuilder,
+    sst_sizes: UInt64VectorBuilder,
+}
+
+impl InformationSchemaRegionStatisticsBuilder {
+    fn new(schema: SchemaRef, catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            schema,
+            catalog_manager,
+            region_ids: UInt64VectorBuilder::with_capacity(42),
+            table_ids: UInt32VectorBuilder::with_capacity(42),
+            region_numbers: UInt32VectorBuilder::with_capacity(42),
+            engines: StringVectorBuilder::with_capacity(42),
+            region_rows: UInt64VectorBuilder::with_capacity(42),
+            disk_sizes: UInt64VectorBuilder::with_capacity(42),
+            memtable_sizes: UInt64VectorBuilder::with_capacity(42),
+            sst_sizes: UInt64VectorBuilder::with_capacity(42),
+        }
+    }
+
+    /// Construct the `information_schema.region_statistics` virtual table
+    async fn make_region_statistics(&mut self) -> Result<RecordBatch> {
+        let catalog_manager = self
+            .catalog_manager
+            .upgrade()
+            .context(UpgradeWeakCatalogManagerRefSnafu)?;
+        let Some(provider) = catalog_manager
+            .as_any()
+            .downcast_ref::<KvBackendCatalogManager>()
+            .and_then(|manager| manager.region_stats_provider())
+        else {
+            return self.finish();
+        };
+
+        let mut stats = provider
+            .list_region_stats()
+            .await
+            .context(ListRegionStatsSnafu)?;
+        stats.sort_by_key(|stat| stat.region_id.as_u64());
+        for stat in &stats {
+            self.add_region_stats(stat);
+        }
+
+        self.finish()
+    }
+
+    fn add_region_stats(&mut self, stat: &RegionStatsEntry) {
+        self.region_ids.push(Some(stat.region_id.as_u64()));
+        self.table_ids.push(Some(stat.region_id.table_id()));
+        self.region_numbers
+            .push(Some(stat.region_id.region_number()));
+        self.engines.push(if stat.engine.is_empty() {
+            None
+        } else {
+            Some(&stat.engine)
+        });
+        self.region_rows.push(Some(stat.num_rows));
+        self.disk_sizes.push(Some(stat.disk_size));
+        self.memtable_sizes.push(stat.memtable_size);
+        self.sst_sizes.push(stat.sst_size);
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch> {
+        let columns: Vec<VectorRef> = vec![
+            Arc::new(self.region_ids.finish()),
+            Arc::new(self.table_ids.finish()),
+            Arc::new(self.region_numbers.finish()),
+            Arc::new(self.engines.finish()),
+            Arc::new(self.region_rows.finish()),
+            Arc::new(self.disk_sizes.finish()),
+            Arc::new(self.memtable_sizes.finish()),
+            Arc::new(self.sst_sizes.finish()),
+        ];
+        RecordBatch::new(self.schema.clone(), columns).context(CreateRecordBatchSnafu)
+    }
+}
+
+impl DfPartitionStream for InformationSchemaRegionStatistics {
+    fn schema(&self) -> &ArrowSchemaRef {
+        self.schema.arrow_schema()
+    }
+
+    fn execute(&self, _: Arc<TaskContext>) -> DfSendableRecordBatchStream {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_region_statistics()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ))
+    }
+}
@@ -0,0 +1,352 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use common_catalog::consts::INFORMATION_SCHEMA_SLOW_QUERIES_TABLE_ID;
+use common_error::ext::BoxedError;
+use common_query::physical_plan::TaskContext;
+use common_recordbatch::adapter::RecordBatchStreamAdapter;
+use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter as DfRecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream as DfPartitionStream;
+use datafusion::physical_plan::SendableRecordBatchStream as DfSendableRecordBatchStream;
+use datatypes::prelude::{ConcreteDataType, ScalarVectorBuilder, VectorRef};
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef};
+use datatypes::timestamp::TimestampMillisecond;
+use datatypes::vectors::{
+    StringVectorBuilder, TimestampMillisecondVectorBuilder, UInt64VectorBuilder,
+};
+use session::resource_usage::RegionScanUsage;
+use snafu::{OptionExt, ResultExt};
+use store_api::storage::{RegionId, TableId};
+
+use super::SLOW_QUERIES;
+use crate::error::{
+    CreateRecordBatchSnafu, InternalSnafu, Result, UpgradeWeakCatalogManagerRefSnafu,
+};
+use crate::information_schema::InformationTable;
+use crate::kvbackend::KvBackendCatalogManager;
+use crate::CatalogManager;
+
+/// A query that took longer than the slow query threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowQueryEntry {
+    /// Start time of the query, in milliseconds since the unix epoch.
+    pub start_time: i64,
+    pub elapsed: Duration,
+    pub user: String,
+    pub client_addr: Option<String>,
+    /// The protocol the query comes from, e.g. `mysql` or `http`.
+    pub protocol: String,
+    pub db: String,
+    pub query: String,
+    /// Summary of the logical plan, if the query is planned by the query engine.
+    pub plan: Option<String>,
+    /// Rows received from each region, ordered by region id.
+    pub region_scans: Vec<(u64, RegionScanUsage)>,
+    /// Peak memory reserved by the query engine while executing the query.
+    pub peak_memory: usize,
+}
+
+tokio::task_local! {
+    /// The user whose slow queries the statements in the scope can read, or `None` if
+    /// they can read the slow queries of all users. Statements out of any scope read
+    /// no slow queries.
+    pub static SLOW_QUERY_OWNER: Option<String>;
+}
+
+/// Provides the recently recorded slow queries.
+pub trait SlowQueryProvider: Send + Sync {
+    fn slow_queries(&self) -> Vec<SlowQueryEntry>;
+}
+
+pub type SlowQueryProviderRef = Arc<dyn SlowQueryProvider>;
+
+/// The `information_schema.slow_queries` table implementation.
+pub(super) struct InformationSchemaSlowQueries {
+    schema: SchemaRef,
+    catalog_manager: Weak<dyn CatalogManager>,
+}
+
+impl InformationSchemaSlowQueries {
+    pub(super) fn new(catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            schema: Self::schema(),
+            catalog_manager,
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new(
+                "start_time",
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                false,
+            ),
+            ColumnSchema::new("elapsed_ms", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("user", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("client_addr", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new("protocol", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("db", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("query", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("plan", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new("region_rows", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new(
+                "region_memory_size",
+                ConcreteDataType::uint64_datatype(),
+                false,
+            ),
+            ColumnSchema::new("region_scans", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new("peak_memory", ConcreteDataType::uint64_datatype(), false),
+        ]))
+    }
+
+    /// The builder is created when the table is scanned, in the scope of the statement,
+    /// so it captures the [SLOW_QUERY_OWNER] before the stream is polled.
+    fn builder(&self) -> InformationSchemaSlowQueriesBuilder {
+        InformationSchemaSlowQueriesBuilder::new(
+            self.schema.clone(),
+            self.catalog_manager.clone(),
+            SLOW_QUERY_OWNER.try_with(Clone::clone).ok(),
+        )
+    }
+}
+
+impl InformationTable for InformationSchemaSlowQueries {
+    fn table_id(&self) -> TableId {
+        INFORMATION_SCHEMA_SLOW_QUERIES_TABLE_ID
+    }
+
+    fn table_name(&self) -> &'static str {
+        SLOW_QUERIES
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn to_stream(&self) -> Result<SendableRecordBatchStream> {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        let stream = Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_slow_queries()
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ));
+        Ok(Box::pin(
+            RecordBatchStreamAdapter::try_new(stream)
+                .map_err(BoxedError::new)
+                .context(InternalSnafu)?,
+        ))
+    }
+}
+
+/// Builds the `information_schema.slow_queries` table row by row.
+struct InformationSchemaSlowQueriesBuilder {
+    schema: SchemaRef,
+    catalog_manager: Weak<dyn CatalogManager>,
+    /// The [SLOW_QUERY_OWNER], `None` if the table is scanned out of its scope.
+    owner: Option<Option<String>>,
+
+    start_times: TimestampMillisecondVectorBuilder,
+    elapsed: UInt64VectorBuilder,
+    users: StringVectorBuilder,
+    client_addrs: StringVectorBuilder,
+    protocols: StringVectorBuilder,
+    dbs: StringVectorBuilder,
+    queries: StringVectorBuilder,
+    plans: StringVectorBuilder,
+    region_rows: UInt64VectorBuilder,
+    region_memory_sizes: UInt64VectorBuilder,
+    region_scans: StringVectorBuilder,
+    peak_memories: UInt64VectorBuilder,
+}
+
+impl InformationSchemaSlowQueriesBuilder {
+    fn new(
+        schema: SchemaRef,
+        catalog_manager: Weak<dyn CatalogManager>,
+        owner: Option<Option<String>>,
+    ) -> Self {
+        Self {
+            schema,
+            catalog_manager,
+            owner,
+            start_times: TimestampMillisecondVectorBuilder::with_capacity(42),
+            elapsed: UInt64VectorBuilder::with_capacity(42),
+            users: StringVectorBuilder::with_capacity(42),
+            client_addrs: StringVectorBuilder::with_capacity(42),
+            protocols: StringVectorBuilder::with_capacity(42),
+            dbs: StringVectorBuilder::with_capacity(42),
+            queries: StringVectorBuilder::with_capacity(42),
+            plans: StringVectorBuilder::with_capacity(42),
+            region_rows: UInt64VectorBuilder::with_capacity(42),
+            region_memory_sizes: UInt64VectorBuilder::with_capacity(42),
+            region_scans: StringVectorBuilder::with_capacity(42),
+            peak_memories: UInt64VectorBuilder::with_capacity(42),
+        }
+    }
+
+    /// Construct the `information_schema.slow_queries` virtual table
+    fn make_slow_queries(&mut self) -> Result<RecordBatch> {
+        let Some(owner) = self.owner.clone() else {
+            return self.finish();
+        };
+        let catalog_manager = self
+            .catalog_manager
+            .upgrade()
+            .context(UpgradeWeakCatalogManagerRefSnafu)?;
+        let Some(provider) = catalog_manager
+            .as_any()
+            .downcast_ref::<KvBackendCatalogManager>()
+            .and_then(|manager| manager.slow_query_provider())
+        else {
+            return self.finish();
+        };
+
+        for entry in provider
+            .slow_queries()
+            .iter()
+            .filter(|entry| owner.as_ref().map_or(true, |owner| *owner == entry.user))
+        {
+            self.add_slow_query(entry);
+        }
+
+        self.finish()
+    }
+
+    fn add_slow_query(&mut self, entry: &SlowQueryEntry) {
+        self.start_times
+            .push(Some(TimestampMillisecond::from(entry.start_time)));
+        self.elapsed.push(Some(entry.elapsed.as_millis() as u64));
+        self.users.push(Some(&entry.user));
+        self.client_addrs.push(entry.client_addr.as_deref());
+        self.protocols.push(Some(&entry.protocol));
+        self.dbs.push(Some(&entry.db));
+        self.queries.push(Some(&entry.query));
+        self.plans.push(entry.plan.as_deref());
+
+        let (rows, memory_size) =
+            entry
+                .region_scans
+                .iter()
+                .fold((0, 0), |(rows, memory_size), (_, usage)| {
+                    (
+                        rows + usage.rows as u64,
+                        memory_size + usage.memory_size as u64,
+                    )
+                });
+        self.region_rows.push(Some(rows));
+        self.region_memory_sizes.push(Some(memory_size));
+        let region_scans = if entry.region_scans.is_empty() {
+            None
+        } else {
+            Some(format_region_scans(&entry.region_scans))
+        };
+        self.region_scans.push(region_scans.as_deref());
+        self.peak_memories.push(Some(entry.peak_memory as u64));
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch> {
+        let columns: Vec<VectorRef> = vec![
+            Arc::new(self.start_times.finish()),
+            Arc::new(self.elapsed.finish()),
+            Arc::new(self.users.finish()),
+            Arc::new(self.client_addrs.finish()),
+            Arc::new(self.protocols.finish()),
+            Arc::new(self.dbs.finish()),
+            Arc::new(self.queries.finish()),
+            Arc::new(self.plans.finish()),
+            Arc::new(self.region_rows.finish()),
+            Arc::new(self.region_memory_sizes.finish()),
+            Arc::new(self.region_scans.finish()),
+            Arc::new(self.peak_memories.finish()),
+        ];
+        RecordBatch::new(self.schema.clone(), columns).context(CreateRecordBatchSnafu)
+    }
+}
+
+/// Formats the per-region scans as `<region_id>: <rows> rows, <size> bytes in memory`, one
+/// region per line.
+fn format_region_scans(region_scans: &[(u64, RegionScanUsage)]) -> String {
+    region_scans
+        .iter()
+        .map(|(region_id, usage)| {
+            format!(
+                "{}: {} rows, {} bytes in memory",
+                RegionId::from_u64(*region_id),
+                usage.rows,
+                usage.memory_size
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl DfPartitionStream for InformationSchemaSlowQueries {
+    fn schema(&self) -> &ArrowSchemaRef {
+        self.schema.arrow_schema()
+    }
+
+    fn execute(&self, _: Arc<TaskContext>) -> DfSendableRecordBatchStream {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_slow_queries()
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_region_scans() {
+        let region_scans = vec![
+            (
+                RegionId::new(1024, 0).as_u64(),
+                RegionScanUsage {
+                    rows: 10,
+                    memory_size: 100,
+                },
+            ),
+            (
+                RegionId::new(1024, 1).as_u64(),
+                RegionScanUsage {
+                    rows: 1,
+                    memory_size: 8,
+                },
+            ),
+        ];
+        assert_eq!(
+            "4398046511104(1024, 0): 10 rows, 100 bytes in memory\n\
+             4398046511105(1024, 1): 1 rows, 8 bytes in memory",
+            format_region_scans(&region_scans)
+        );
+    }
+}
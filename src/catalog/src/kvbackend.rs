@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub use client::{CachedMetaKvBackend, MetaKvBackend};
+pub use client::{CachedMetaKvBackend, MetaKvBackend, MetaRegionStatsProvider};
 
 mod client;
 mod manager;
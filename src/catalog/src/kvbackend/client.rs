@@ -33,7 +33,9 @@ use common_telemetry::{debug, timer};
 use meta_client::client::MetaClient;
 use moka::future::{Cache, CacheBuilder};
 use snafu::{OptionExt, ResultExt};
+use store_api::storage::RegionId;
 
+use crate::information_schema::{RegionStatsEntry, RegionStatsProvider};
 use crate::metrics::{METRIC_CATALOG_KV_GET, METRIC_CATALOG_KV_REMOTE_GET};
 
 const CACHE_MAX_CAPACITY: u64 = 10000;
@@ -331,3 +333,36 @@ impl KvBackend for MetaKvBackend {
         self
     }
 }
+
+/// Provides the region statistics from the datanode heartbeats collected by `metasrv`.
+///
+/// The `RegionStat` of the heartbeat protocol only has the approximate size of a
+/// region (memtables and SST files together), so `memtable_size` and `sst_size` are
+/// absent until the protocol reports them separately.
+#[derive(Debug)]
+pub struct MetaRegionStatsProvider {
+    pub client: Arc<MetaClient>,
+}
+
+#[async_trait::async_trait]
+impl RegionStatsProvider for MetaRegionStatsProvider {
+    async fn list_region_stats(&self) -> std::result::Result<Vec<RegionStatsEntry>, BoxedError> {
+        let stats = self
+            .client
+            .list_region_stats()
+            .await
+            .map_err(BoxedError::new)?;
+        Ok(stats
+            .into_iter()
+            .map(|stat| RegionStatsEntry {
+                region_id: RegionId::from_u64(stat.region_id),
+                engine: stat.engine,
+                num_rows: stat.approximate_rows.max(0) as u64,
+                disk_size: stat.approximate_bytes.max(0) as u64,
+                // Not reported by the heartbeat protocol yet.
+                memtable_size: None,
+                sst_size: None,
+            })
+            .collect())
+    }
+}
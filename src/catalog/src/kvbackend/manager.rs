@@ -14,9 +14,11 @@
 
 use std::any::Any;
 use std::collections::BTreeSet;
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, RwLock, Weak};
 
-use common_catalog::consts::{DEFAULT_SCHEMA_NAME, INFORMATION_SCHEMA_NAME, NUMBERS_TABLE_ID};
+use common_catalog::consts::{
+    DEFAULT_SCHEMA_NAME, INFORMATION_SCHEMA_NAME, NUMBERS_TABLE_ID, PG_CATALOG_NAME,
+};
 use common_error::ext::BoxedError;
 use common_meta::cache_invalidator::{
     CacheInvalidator, Context, KvCacheInvalidatorRef, TableMetadataCacheInvalidator,
@@ -41,7 +43,11 @@ use crate::error::{
     self as catalog_err, ListCatalogsSnafu, ListSchemasSnafu, Result as CatalogResult,
     TableMetadataManagerSnafu,
 };
-use crate::information_schema::{InformationSchemaProvider, COLUMNS, TABLES};
+use crate::information_schema::{
+    InformationSchemaProvider, RegionStatsProviderRef, SlowQueryProviderRef,
+    INFORMATION_SCHEMA_TABLE_NAMES,
+};
+use crate::pg_catalog::{PGCatalogProvider, PG_CATALOG_TABLE_NAMES};
 use crate::CatalogManager;
 
 /// Access all existing catalog, schema and tables.
@@ -58,6 +64,10 @@ pub struct KvBackendCatalogManager {
     partition_manager: PartitionRuleManagerRef,
     table_metadata_manager: TableMetadataManagerRef,
     datanode_manager: DatanodeManagerRef,
+    /// Provides the statistics of regions for `information_schema.region_statistics`.
+    region_stats_provider: Option<RegionStatsProviderRef>,
+    /// Provides the recorded slow queries for `information_schema.slow_queries`.
+    slow_query_provider: Arc<RwLock<Option<SlowQueryProviderRef>>>,
     /// A sub-CatalogManager that handles system tables
     system_catalog: SystemCatalog,
 }
@@ -75,6 +85,17 @@ impl CacheInvalidator for KvBackendCatalogManager {
             .invalidate_table_id(ctx, table_id)
             .await
     }
+
+    async fn invalidate_schema(
+        &self,
+        ctx: &Context,
+        catalog: &str,
+        schema: &str,
+    ) -> MetaResult<()> {
+        self.table_metadata_cache_invalidator
+            .invalidate_schema(ctx, catalog, schema)
+            .await
+    }
 }
 
 impl KvBackendCatalogManager {
@@ -82,6 +103,7 @@ impl KvBackendCatalogManager {
         backend: KvBackendRef,
         backend_cache_invalidator: KvCacheInvalidatorRef,
         datanode_manager: DatanodeManagerRef,
+        region_stats_provider: Option<RegionStatsProviderRef>,
     ) -> Arc<Self> {
         Arc::new_cyclic(|me| Self {
             partition_manager: Arc::new(PartitionRuleManager::new(backend.clone())),
@@ -90,6 +112,8 @@ impl KvBackendCatalogManager {
                 backend_cache_invalidator.clone(),
             ),
             datanode_manager,
+            region_stats_provider,
+            slow_query_provider: Arc::new(RwLock::new(None)),
             system_catalog: SystemCatalog {
                 catalog_manager: me.clone(),
             },
@@ -108,10 +132,18 @@ impl KvBackendCatalogManager {
         self.datanode_manager.clone()
     }
 
-    pub async fn invalidate_schema(&self, catalog: &str, schema: &str) {
-        self.table_metadata_cache_invalidator
-            .invalidate_schema(catalog, schema)
-            .await
+    pub fn region_stats_provider(&self) -> Option<RegionStatsProviderRef> {
+        self.region_stats_provider.clone()
+    }
+
+    pub fn slow_query_provider(&self) -> Option<SlowQueryProviderRef> {
+        self.slow_query_provider.read().unwrap().clone()
+    }
+
+    /// Sets the provider of `information_schema.slow_queries`. The slow query log is
+    /// created after the catalog manager, so it can't be passed to [Self::new].
+    pub fn set_slow_query_provider(&self, provider: SlowQueryProviderRef) {
+        *self.slow_query_provider.write().unwrap() = Some(provider);
     }
 }
 
@@ -251,8 +283,8 @@ impl CatalogManager for KvBackendCatalogManager {
 // a new catalog is created.
 /// Existing system tables:
 /// - public.numbers
-/// - information_schema.tables
-/// - information_schema.columns
+/// - information_schema.{tables, columns, schemata, ...}, see [INFORMATION_SCHEMA_TABLE_NAMES]
+/// - pg_catalog.{pg_namespace, pg_class, ...}, see [PG_CATALOG_TABLE_NAMES]
 #[derive(Clone)]
 struct SystemCatalog {
     catalog_manager: Weak<KvBackendCatalogManager>,
@@ -260,12 +292,23 @@ struct SystemCatalog {
 
 impl SystemCatalog {
     fn schema_names(&self) -> Vec<String> {
-        vec![INFORMATION_SCHEMA_NAME.to_string()]
+        vec![
+            INFORMATION_SCHEMA_NAME.to_string(),
+            PG_CATALOG_NAME.to_string(),
+        ]
     }
 
     fn table_names(&self, schema: &str) -> Vec<String> {
         if schema == INFORMATION_SCHEMA_NAME {
-            vec![TABLES.to_string(), COLUMNS.to_string()]
+            INFORMATION_SCHEMA_TABLE_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
+        } else if schema == PG_CATALOG_NAME {
+            PG_CATALOG_TABLE_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
         } else if schema == DEFAULT_SCHEMA_NAME {
             vec![NUMBERS_TABLE_NAME.to_string()]
         } else {
@@ -274,12 +317,14 @@ impl SystemCatalog {
     }
 
     fn schema_exist(&self, schema: &str) -> bool {
-        schema == INFORMATION_SCHEMA_NAME
+        schema == INFORMATION_SCHEMA_NAME || schema == PG_CATALOG_NAME
     }
 
     fn table_exist(&self, schema: &str, table: &str) -> bool {
         if schema == INFORMATION_SCHEMA_NAME {
-            table == TABLES || table == COLUMNS
+            INFORMATION_SCHEMA_TABLE_NAMES.contains(&table)
+        } else if schema == PG_CATALOG_NAME {
+            PG_CATALOG_TABLE_NAMES.contains(&table)
         } else if schema == DEFAULT_SCHEMA_NAME {
             table == NUMBERS_TABLE_NAME
         } else {
@@ -292,6 +337,10 @@ impl SystemCatalog {
             let information_schema_provider =
                 InformationSchemaProvider::new(catalog.to_string(), self.catalog_manager.clone());
             information_schema_provider.table(table_name)
+        } else if schema == PG_CATALOG_NAME {
+            let pg_catalog_provider =
+                PGCatalogProvider::new(catalog.to_string(), self.catalog_manager.clone());
+            pg_catalog_provider.table(table_name)
         } else if schema == DEFAULT_SCHEMA_NAME && table_name == NUMBERS_TABLE_NAME {
             Some(NumbersTable::table(NUMBERS_TABLE_ID))
         } else {
@@ -17,13 +17,16 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock, Weak};
 
-use common_catalog::consts::{DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, INFORMATION_SCHEMA_NAME};
+use common_catalog::consts::{
+    DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME, INFORMATION_SCHEMA_NAME, PG_CATALOG_NAME,
+};
 use metrics::{decrement_gauge, increment_gauge};
 use snafu::OptionExt;
 use table::TableRef;
 
 use crate::error::{CatalogNotFoundSnafu, Result, SchemaNotFoundSnafu, TableExistsSnafu};
 use crate::information_schema::InformationSchemaProvider;
+use crate::pg_catalog::PGCatalogProvider;
 use crate::{CatalogManager, DeregisterTableRequest, RegisterSchemaRequest, RegisterTableRequest};
 
 type SchemaEntries = HashMap<String, HashMap<String, TableRef>>;
@@ -248,11 +251,14 @@ impl MemoryCatalogManager {
 
     fn create_catalog_entry(self: &Arc<Self>, catalog: String) -> SchemaEntries {
         let information_schema = InformationSchemaProvider::build(
-            catalog,
+            catalog.clone(),
             Arc::downgrade(self) as Weak<dyn CatalogManager>,
         );
+        let pg_catalog =
+            PGCatalogProvider::build(catalog, Arc::downgrade(self) as Weak<dyn CatalogManager>);
         let mut catalog = HashMap::new();
         catalog.insert(INFORMATION_SCHEMA_NAME.to_string(), information_schema);
+        catalog.insert(PG_CATALOG_NAME.to_string(), pg_catalog);
         catalog
     }
 
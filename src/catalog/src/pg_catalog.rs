@@ -0,0 +1,213 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The read-only `pg_catalog` schema for PostgreSQL clients, which browse
+//! schemas, tables and columns through it.
+
+mod pg_attribute;
+mod pg_class;
+mod pg_description;
+mod pg_namespace;
+mod pg_type;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+
+use common_catalog::consts::{PG_CATALOG_NAME, PG_CATALOG_PG_TYPE_TABLE_ID};
+use datatypes::prelude::ConcreteDataType;
+use table::TableRef;
+
+use self::pg_attribute::PGAttribute;
+use self::pg_class::PGClass;
+use self::pg_description::PGDescription;
+use self::pg_namespace::PGNamespace;
+use self::pg_type::pg_type_schema_columns;
+use crate::information_schema::{build_table, InformationTableRef, MemoryTable};
+use crate::CatalogManager;
+
+pub const PG_NAMESPACE: &str = "pg_namespace";
+pub const PG_CLASS: &str = "pg_class";
+pub const PG_ATTRIBUTE: &str = "pg_attribute";
+pub const PG_TYPE: &str = "pg_type";
+pub const PG_DESCRIPTION: &str = "pg_description";
+
+/// Names of all the tables in pg_catalog.
+pub const PG_CATALOG_TABLE_NAMES: &[&str] = &[
+    PG_NAMESPACE,
+    PG_CLASS,
+    PG_ATTRIBUTE,
+    PG_TYPE,
+    PG_DESCRIPTION,
+];
+
+/// Oid of the owner of all the objects, i.e. the bootstrap superuser of PostgreSQL.
+///
+/// GreptimeDB has no owners of schemas and tables.
+const OWNER_OID: u32 = 10;
+
+/// Oid of `pg_class` in PostgreSQL, which `pg_description` refers to for tables and
+/// columns.
+const PG_CLASS_OID: u32 = 1259;
+
+pub struct PGCatalogProvider {
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+}
+
+impl PGCatalogProvider {
+    pub fn new(catalog_name: String, catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            catalog_name,
+            catalog_manager,
+        }
+    }
+
+    /// Build a map of [TableRef] in pg_catalog.
+    /// Including all the tables listed in [PG_CATALOG_TABLE_NAMES].
+    pub fn build(
+        catalog_name: String,
+        catalog_manager: Weak<dyn CatalogManager>,
+    ) -> HashMap<String, TableRef> {
+        let provider = Self::new(catalog_name, catalog_manager);
+
+        PG_CATALOG_TABLE_NAMES
+            .iter()
+            .map(|name| (name.to_string(), provider.table(name).unwrap()))
+            .collect()
+    }
+
+    pub fn table(&self, name: &str) -> Option<TableRef> {
+        self.pg_catalog_table(name).map(|table| {
+            build_table(
+                self.catalog_name.clone(),
+                PG_CATALOG_NAME.to_string(),
+                table,
+            )
+        })
+    }
+
+    fn pg_catalog_table(&self, name: &str) -> Option<InformationTableRef> {
+        match name.to_ascii_lowercase().as_str() {
+            PG_NAMESPACE => Some(Arc::new(PGNamespace::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            PG_CLASS => Some(Arc::new(PGClass::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            PG_ATTRIBUTE => Some(Arc::new(PGAttribute::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            PG_TYPE => Some(Arc::new(MemoryTable::new(
+                PG_CATALOG_PG_TYPE_TABLE_ID,
+                PG_TYPE,
+                pg_type_schema_columns(),
+            )) as _),
+            PG_DESCRIPTION => Some(Arc::new(PGDescription::new(
+                self.catalog_name.clone(),
+                self.catalog_manager.clone(),
+            )) as _),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the oid of the schema `schema_name`.
+///
+/// Schemas have no ids in GreptimeDB, so the oid is the 32-bit FNV-1a hash of the
+/// name. The hash is fixed, so every frontend and every release returns the same
+/// oid for a schema and clients can cache it.
+fn namespace_oid(schema_name: &str) -> u32 {
+    const FNV_OFFSET_BASIS: u32 = 0x811c9dc5;
+    const FNV_PRIME: u32 = 0x01000193;
+
+    schema_name.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Oids of the PostgreSQL types that GreptimeDB data types map to.
+mod oid {
+    pub(super) const BOOL: u32 = 16;
+    pub(super) const BYTEA: u32 = 17;
+    pub(super) const CHAR: u32 = 18;
+    pub(super) const INT8: u32 = 20;
+    pub(super) const INT2: u32 = 21;
+    pub(super) const INT4: u32 = 23;
+    pub(super) const TEXT: u32 = 25;
+    pub(super) const JSON: u32 = 114;
+    pub(super) const FLOAT4: u32 = 700;
+    pub(super) const FLOAT8: u32 = 701;
+    pub(super) const UNKNOWN: u32 = 705;
+    pub(super) const VARCHAR: u32 = 1043;
+    pub(super) const DATE: u32 = 1082;
+    pub(super) const TIME: u32 = 1083;
+    pub(super) const TIMESTAMP: u32 = 1114;
+    pub(super) const INTERVAL: u32 = 1186;
+    pub(super) const NUMERIC: u32 = 1700;
+}
+
+/// Returns the oid of the PostgreSQL type `data_type` is presented as.
+///
+/// Keep it in sync with the type mapping of the PostgreSQL server.
+fn pg_type_oid(data_type: &ConcreteDataType) -> u32 {
+    match data_type {
+        ConcreteDataType::Null(_) => oid::UNKNOWN,
+        ConcreteDataType::Boolean(_) => oid::BOOL,
+        ConcreteDataType::Int8(_) | ConcreteDataType::UInt8(_) => oid::CHAR,
+        ConcreteDataType::Int16(_) | ConcreteDataType::UInt16(_) => oid::INT2,
+        ConcreteDataType::Int32(_) | ConcreteDataType::UInt32(_) => oid::INT4,
+        ConcreteDataType::Int64(_) | ConcreteDataType::UInt64(_) => oid::INT8,
+        ConcreteDataType::Float32(_) => oid::FLOAT4,
+        ConcreteDataType::Float64(_) => oid::FLOAT8,
+        ConcreteDataType::Decimal128(_) => oid::NUMERIC,
+        ConcreteDataType::Binary(_) => oid::BYTEA,
+        ConcreteDataType::String(_) => oid::VARCHAR,
+        ConcreteDataType::Json(_) => oid::JSON,
+        ConcreteDataType::Date(_) => oid::DATE,
+        ConcreteDataType::DateTime(_) | ConcreteDataType::Timestamp(_) => oid::TIMESTAMP,
+        ConcreteDataType::Time(_) => oid::TIME,
+        ConcreteDataType::Interval(_) => oid::INTERVAL,
+        ConcreteDataType::Duration(_)
+        | ConcreteDataType::List(_)
+        | ConcreteDataType::Dictionary(_) => oid::TEXT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespace_oid() {
+        assert_eq!(3432027008, namespace_oid("public"));
+        assert_eq!(0x811c9dc5, namespace_oid(""));
+        assert_ne!(namespace_oid("public"), namespace_oid(PG_CATALOG_NAME));
+    }
+
+    #[test]
+    fn test_pg_type_oid() {
+        assert_eq!(oid::INT8, pg_type_oid(&ConcreteDataType::int64_datatype()));
+        assert_eq!(
+            oid::VARCHAR,
+            pg_type_oid(&ConcreteDataType::string_datatype())
+        );
+        assert_eq!(
+            oid::TIMESTAMP,
+            pg_type_oid(&ConcreteDataType::timestamp_millisecond_datatype())
+        );
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Weak};
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use common_catalog::consts::PG_CATALOG_PG_ATTRIBUTE_TABLE_ID;
+use common_error::ext::BoxedError;
+use common_query::physical_plan::TaskContext;
+use common_recordbatch::adapter::RecordBatchStreamAdapter;
+use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter as DfRecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream as DfPartitionStream;
+use datafusion::physical_plan::SendableRecordBatchStream as DfSendableRecordBatchStream;
+use datatypes::prelude::{ConcreteDataType, ScalarVectorBuilder, VectorRef};
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef};
+use datatypes::vectors::{
+    BooleanVectorBuilder, Int16VectorBuilder, Int32VectorBuilder, StringVectorBuilder,
+    UInt32VectorBuilder,
+};
+use snafu::{OptionExt, ResultExt};
+use store_api::storage::TableId;
+
+use super::{pg_type_oid, PG_ATTRIBUTE};
+use crate::error::{
+    CreateRecordBatchSnafu, InternalSnafu, Result, UpgradeWeakCatalogManagerRefSnafu,
+};
+use crate::information_schema::InformationTable;
+use crate::CatalogManager;
+
+/// `atttypmod` of a column whose type has no modifier.
+const NO_TYPMOD: i32 = -1;
+
+/// The `pg_catalog.pg_attribute` table implementation.
+pub(super) struct PGAttribute {
+    schema: SchemaRef,
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+}
+
+impl PGAttribute {
+    pub(super) fn new(catalog_name: String, catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            schema: Self::schema(),
+            catalog_name,
+            catalog_manager,
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new("attrelid", ConcreteDataType::uint32_datatype(), false),
+            ColumnSchema::new("attname", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("atttypid", ConcreteDataType::uint32_datatype(), false),
+            ColumnSchema::new("attnum", ConcreteDataType::int16_datatype(), false),
+            ColumnSchema::new("atttypmod", ConcreteDataType::int32_datatype(), false),
+            ColumnSchema::new("attnotnull", ConcreteDataType::boolean_datatype(), false),
+            ColumnSchema::new("attisdropped", ConcreteDataType::boolean_datatype(), false),
+        ]))
+    }
+
+    fn builder(&self) -> PGAttributeBuilder {
+        PGAttributeBuilder::new(
+            self.schema.clone(),
+            self.catalog_name.clone(),
+            self.catalog_manager.clone(),
+        )
+    }
+}
+
+impl InformationTable for PGAttribute {
+    fn table_id(&self) -> TableId {
+        PG_CATALOG_PG_ATTRIBUTE_TABLE_ID
+    }
+
+    fn table_name(&self) -> &'static str {
+        PG_ATTRIBUTE
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn to_stream(&self) -> Result<SendableRecordBatchStream> {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        let stream = Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_attribute()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ));
+        Ok(Box::pin(
+            RecordBatchStreamAdapter::try_new(stream)
+                .map_err(BoxedError::new)
+                .context(InternalSnafu)?,
+        ))
+    }
+}
+
+/// Builds the `pg_catalog.pg_attribute` table row by row
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/catalog-pg-attribute.html>
+struct PGAttributeBuilder {
+    schema: SchemaRef,
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+
+    attrelids: UInt32VectorBuilder,
+    attnames: StringVectorBuilder,
+    atttypids: UInt32VectorBuilder,
+    attnums: Int16VectorBuilder,
+    atttypmods: Int32VectorBuilder,
+    attnotnulls: BooleanVectorBuilder,
+    attisdroppeds: BooleanVectorBuilder,
+}
+
+impl PGAttributeBuilder {
+    fn new(
+        schema: SchemaRef,
+        catalog_name: String,
+        catalog_manager: Weak<dyn CatalogManager>,
+    ) -> Self {
+        Self {
+            schema,
+            catalog_name,
+            catalog_manager,
+            attrelids: UInt32VectorBuilder::with_capacity(42),
+            attnames: StringVectorBuilder::with_capacity(42),
+            atttypids: UInt32VectorBuilder::with_capacity(42),
+            attnums: Int16VectorBuilder::with_capacity(42),
+            atttypmods: Int32VectorBuilder::with_capacity(42),
+            attnotnulls: BooleanVectorBuilder::with_capacity(42),
+            attisdroppeds: BooleanVectorBuilder::with_capacity(42),
+        }
+    }
+
+    /// Construct the `pg_catalog.pg_attribute` virtual table
+    async fn make_attribute(&mut self) -> Result<RecordBatch> {
+        let catalog_name = self.catalog_name.clone();
+        let catalog_manager = self
+            .catalog_manager
+            .upgrade()
+            .context(UpgradeWeakCatalogManagerRefSnafu)?;
+
+        for schema_name in catalog_manager.schema_names(&catalog_name).await? {
+            if !catalog_manager
+                .schema_exists(&catalog_name, &schema_name)
+                .await?
+            {
+                continue;
+            }
+
+            for table_name in catalog_manager
+                .table_names(&catalog_name, &schema_name)
+                .await?
+            {
+                let Some(table) = catalog_manager
+                    .table(&catalog_name, &schema_name, &table_name)
+                    .await?
+                else {
+                    continue;
+                };
+
+                let table_id = table.table_info().ident.table_id;
+                for (idx, column) in table.schema().column_schemas().iter().enumerate() {
+                    self.attrelids.push(Some(table_id));
+                    self.attnames.push(Some(&column.name));
+                    self.atttypids.push(Some(pg_type_oid(&column.data_type)));
+                    // Numbers of user columns start from 1.
+                    self.attnums.push(Some(idx as i16 + 1));
+                    // No type has a modifier, e.g. the length of a varchar.
+                    self.atttypmods.push(Some(NO_TYPMOD));
+                    self.attnotnulls.push(Some(!column.is_nullable()));
+                    // Dropped columns are removed from the schema.
+                    self.attisdroppeds.push(Some(false));
+                }
+            }
+        }
+
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch> {
+        let columns: Vec<VectorRef> = vec![
+            Arc::new(self.attrelids.finish()),
+            Arc::new(self.attnames.finish()),
+            Arc::new(self.atttypids.finish()),
+            Arc::new(self.attnums.finish()),
+            Arc::new(self.atttypmods.finish()),
+            Arc::new(self.attnotnulls.finish()),
+            Arc::new(self.attisdroppeds.finish()),
+        ];
+        RecordBatch::new(self.schema.clone(), columns).context(CreateRecordBatchSnafu)
+    }
+}
+
+impl DfPartitionStream for PGAttribute {
+    fn schema(&self) -> &ArrowSchemaRef {
+        self.schema.arrow_schema()
+    }
+
+    fn execute(&self, _: Arc<TaskContext>) -> DfSendableRecordBatchStream {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_attribute()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ))
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Weak};
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use common_catalog::consts::PG_CATALOG_PG_DESCRIPTION_TABLE_ID;
+use common_error::ext::BoxedError;
+use common_query::physical_plan::TaskContext;
+use common_recordbatch::adapter::RecordBatchStreamAdapter;
+use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter as DfRecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream as DfPartitionStream;
+use datafusion::physical_plan::SendableRecordBatchStream as DfSendableRecordBatchStream;
+use datatypes::prelude::{ConcreteDataType, ScalarVectorBuilder, VectorRef};
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef, COMMENT_KEY};
+use datatypes::vectors::{Int32VectorBuilder, StringVectorBuilder, UInt32VectorBuilder};
+use snafu::{OptionExt, ResultExt};
+use store_api::storage::TableId;
+
+use super::{PG_CLASS_OID, PG_DESCRIPTION};
+use crate::error::{
+    CreateRecordBatchSnafu, InternalSnafu, Result, UpgradeWeakCatalogManagerRefSnafu,
+};
+use crate::information_schema::InformationTable;
+use crate::CatalogManager;
+
+/// The `pg_catalog.pg_description` table implementation.
+pub(super) struct PGDescription {
+    schema: SchemaRef,
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+}
+
+impl PGDescription {
+    pub(super) fn new(catalog_name: String, catalog_manager: Weak<dyn CatalogManager>) -> Self {
+        Self {
+            schema: Self::schema(),
+            catalog_name,
+            catalog_manager,
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new("objoid", ConcreteDataType::uint32_datatype(), false),
+            ColumnSchema::new("classoid", ConcreteDataType::uint32_datatype(), false),
+            ColumnSchema::new("objsubid", ConcreteDataType::int32_datatype(), false),
+            ColumnSchema::new("description", ConcreteDataType::string_datatype(), false),
+        ]))
+    }
+
+    fn builder(&self) -> PGDescriptionBuilder {
+        PGDescriptionBuilder::new(
+            self.schema.clone(),
+            self.catalog_name.clone(),
+            self.catalog_manager.clone(),
+        )
+    }
+}
+
+impl InformationTable for PGDescription {
+    fn table_id(&self) -> TableId {
+        PG_CATALOG_PG_DESCRIPTION_TABLE_ID
+    }
+
+    fn table_name(&self) -> &'static str {
+        PG_DESCRIPTION
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn to_stream(&self) -> Result<SendableRecordBatchStream> {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        let stream = Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_description()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ));
+        Ok(Box::pin(
+            RecordBatchStreamAdapter::try_new(stream)
+                .map_err(BoxedError::new)
+                .context(InternalSnafu)?,
+        ))
+    }
+}
+
+/// Builds the `pg_catalog.pg_description` table row by row
+///
+/// Only the comments of tables and columns are listed.
+/// Columns are based on <https://www.postgresql.org/docs/current/catalog-pg-description.html>
+struct PGDescriptionBuilder {
+    schema: SchemaRef,
+    catalog_name: String,
+    catalog_manager: Weak<dyn CatalogManager>,
+
+    objoids: UInt32VectorBuilder,
+    classoids: UInt32VectorBuilder,
+    objsubids: Int32VectorBuilder,
+    descriptions: StringVectorBuilder,
+}
+
+impl PGDescriptionBuilder {
+    fn new(
+        schema: SchemaRef,
+        catalog_name: String,
+        catalog_manager: Weak<dyn CatalogManager>,
+    ) -> Self {
+        Self {
+            schema,
+            catalog_name,
+            catalog_manager,
+            objoids: UInt32VectorBuilder::with_capacity(42),
+            classoids: UInt32VectorBuilder::with_capacity(42),
+            objsubids: Int32VectorBuilder::with_capacity(42),
+            descriptions: StringVectorBuilder::with_capacity(42),
+        }
+    }
+
+    /// Construct the `pg_catalog.pg_description` virtual table
+    async fn make_description(&mut self) -> Result<RecordBatch> {
+        let catalog_name = self.catalog_name.clone();
+        let catalog_manager = self
+            .catalog_manager
+            .upgrade()
+            .context(UpgradeWeakCatalogManagerRefSnafu)?;
+
+        for schema_name in catalog_manager.schema_names(&catalog_name).await? {
+            if !catalog_manager
+                .schema_exists(&catalog_name, &schema_name)
+                .await?
+            {
+                continue;
+            }
+
+            for table_name in catalog_manager
+                .table_names(&catalog_name, &schema_name)
+                .await?
+            {
+                let Some(table) = catalog_manager
+                    .table(&catalog_name, &schema_name, &table_name)
+                    .await?
+                else {
+                    continue;
+                };
+
+                let table_info = table.table_info();
+                let table_id = table_info.ident.table_id;
+                // The comment of the table itself has the sub id 0.
+                if let Some(desc) = table_info.desc.as_deref().filter(|d| !d.is_empty()) {
+                    self.add_description(table_id, 0, desc);
+                }
+                for (idx, column) in table.schema().column_schemas().iter().enumerate() {
+                    if let Some(comment) = column.metadata().get(COMMENT_KEY) {
+                        // Same as `attnum` in `pg_attribute`.
+                        self.add_description(table_id, idx as i32 + 1, comment);
+                    }
+                }
+            }
+        }
+
+        self.finish()
+    }
+
+    fn add_description(&mut self, table_id: TableId, objsubid: i32, description: &str) {
+        self.objoids.push(Some(table_id));
+        // Tables are described in `pg_class`.
+        self.classoids.push(Some(PG_CLASS_OID));
+        self.objsubids.push(Some(objsubid));
+        self.descriptions.push(Some(description));
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch> {
+        let columns: Vec<VectorRef> = vec![
+            Arc::new(self.objoids.finish()),
+            Arc::new(self.classoids.finish()),
+            Arc::new(self.objsubids.finish()),
+            Arc::new(self.descriptions.finish()),
+        ];
+        RecordBatch::new(self.schema.clone(), columns).context(CreateRecordBatchSnafu)
+    }
+}
+
+impl DfPartitionStream for PGDescription {
+    fn schema(&self) -> &ArrowSchemaRef {
+        self.schema.arrow_schema()
+    }
+
+    fn execute(&self, _: Arc<TaskContext>) -> DfSendableRecordBatchStream {
+        let schema = self.schema.arrow_schema().clone();
+        let mut builder = self.builder();
+        Box::pin(DfRecordBatchStreamAdapter::new(
+            schema,
+            futures::stream::once(async move {
+                builder
+                    .make_description()
+                    .await
+                    .map(|x| x.into_df_record_batch())
+                    .map_err(Into::into)
+            }),
+        ))
+    }
+}
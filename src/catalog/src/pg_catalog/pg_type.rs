@@ -0,0 +1,69 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::consts::PG_CATALOG_NAME;
+use datatypes::prelude::ConcreteDataType;
+use datatypes::schema::{ColumnSchema, Schema, SchemaRef};
+use datatypes::vectors::{Int16Vector, StringVector, UInt32Vector, VectorRef};
+
+use super::{namespace_oid, oid};
+
+/// The PostgreSQL types GreptimeDB data types are presented as: (oid, name, length).
+///
+/// A negative length means the type has variable length.
+const PG_TYPES: &[(u32, &str, i16)] = &[
+    (oid::BOOL, "bool", 1),
+    (oid::BYTEA, "bytea", -1),
+    (oid::CHAR, "char", 1),
+    (oid::INT8, "int8", 8),
+    (oid::INT2, "int2", 2),
+    (oid::INT4, "int4", 4),
+    (oid::TEXT, "text", -1),
+    (oid::JSON, "json", -1),
+    (oid::FLOAT4, "float4", 4),
+    (oid::FLOAT8, "float8", 8),
+    (oid::UNKNOWN, "unknown", -2),
+    (oid::VARCHAR, "varchar", -1),
+    (oid::DATE, "date", 4),
+    (oid::TIME, "time", 8),
+    (oid::TIMESTAMP, "timestamp", 8),
+    (oid::INTERVAL, "interval", 16),
+    (oid::NUMERIC, "numeric", -1),
+];
+
+/// Returns the schema and the content of `pg_catalog.pg_type`.
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/catalog-pg-type.html>
+pub(super) fn pg_type_schema_columns() -> (SchemaRef, Vec<VectorRef>) {
+    let schema = Arc::new(Schema::new(vec![
+        ColumnSchema::new("oid", ConcreteDataType::uint32_datatype(), false),
+        ColumnSchema::new("typname", ConcreteDataType::string_datatype(), false),
+        ColumnSchema::new("typnamespace", ConcreteDataType::uint32_datatype(), false),
+        ColumnSchema::new("typlen", ConcreteDataType::int16_datatype(), false),
+    ]));
+    let columns: Vec<VectorRef> = vec![
+        Arc::new(UInt32Vector::from_values(PG_TYPES.iter().map(|t| t.0))),
+        Arc::new(StringVector::from(
+            PG_TYPES.iter().map(|t| t.1).collect::<Vec<_>>(),
+        )),
+        // All the types are built-in types of pg_catalog.
+        Arc::new(UInt32Vector::from_values(
+            std::iter::repeat(namespace_oid(PG_CATALOG_NAME)).take(PG_TYPES.len()),
+        )),
+        Arc::new(Int16Vector::from_values(PG_TYPES.iter().map(|t| t.2))),
+    ];
+    (schema, columns)
+}
@@ -15,24 +15,45 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use common_catalog::consts::INFORMATION_SCHEMA_NAME;
+use common_catalog::consts::{INFORMATION_SCHEMA_NAME, PG_CATALOG_NAME};
 use common_catalog::format_full_table_name;
+use common_error::ext::BoxedError;
 use datafusion::common::{ResolvedTableReference, TableReference};
 use datafusion::datasource::provider_as_source;
-use datafusion::logical_expr::TableSource;
-use session::context::QueryContext;
-use snafu::{ensure, OptionExt};
+use datafusion::datasource::view::ViewTable;
+use datafusion::logical_expr::{LogicalPlan, TableSource};
+use session::context::{QueryContext, QueryContextRef};
+use snafu::{ensure, OptionExt, ResultExt};
 use table::table::adapter::DfTableProviderAdapter;
 
-use crate::error::{QueryAccessDeniedSnafu, Result, TableNotExistSnafu};
+use crate::error::{
+    DatafusionSnafu, PlanViewSnafu, QueryAccessDeniedSnafu, Result, TableNotExistSnafu,
+    UnimplementedSnafu,
+};
 use crate::CatalogManagerRef;
 
+/// Plans the SQL definitions of views, so that views can be inlined into the queries
+/// selecting from them.
+#[async_trait::async_trait]
+pub trait ViewPlanner: Send + Sync {
+    /// Plans the `definition` of a view, names in the definition are resolved in the
+    /// catalog and schema of `query_ctx`.
+    async fn plan_view(
+        &self,
+        definition: &str,
+        query_ctx: QueryContextRef,
+    ) -> std::result::Result<LogicalPlan, BoxedError>;
+}
+
+pub type ViewPlannerRef = Arc<dyn ViewPlanner>;
+
 pub struct DfTableSourceProvider {
     catalog_manager: CatalogManagerRef,
     resolved_tables: HashMap<String, Arc<dyn TableSource>>,
     disallow_cross_schema_query: bool,
     default_catalog: String,
     default_schema: String,
+    view_planner: Option<ViewPlannerRef>,
 }
 
 impl DfTableSourceProvider {
@@ -47,9 +68,16 @@ impl DfTableSourceProvider {
             resolved_tables: HashMap::new(),
             default_catalog: query_ctx.current_catalog().to_owned(),
             default_schema: query_ctx.current_schema().to_owned(),
+            view_planner: None,
         }
     }
 
+    /// Sets the planner to resolve views, views can't be queried without it.
+    pub fn with_view_planner(mut self, view_planner: ViewPlannerRef) -> Self {
+        self.view_planner = Some(view_planner);
+        self
+    }
+
     pub fn resolve_table_ref<'a>(
         &'a self,
         table_ref: TableReference<'a>,
@@ -60,7 +88,8 @@ impl DfTableSourceProvider {
                 TableReference::Partial { schema, .. } => {
                     ensure!(
                         schema.as_ref() == self.default_schema
-                            || schema.as_ref() == INFORMATION_SCHEMA_NAME,
+                            || schema.as_ref() == INFORMATION_SCHEMA_NAME
+                            || schema.as_ref() == PG_CATALOG_NAME,
                         QueryAccessDeniedSnafu {
                             catalog: &self.default_catalog,
                             schema: schema.as_ref(),
@@ -73,7 +102,8 @@ impl DfTableSourceProvider {
                     ensure!(
                         catalog.as_ref() == self.default_catalog
                             && (schema.as_ref() == self.default_schema
-                                || schema.as_ref() == INFORMATION_SCHEMA_NAME),
+                                || schema.as_ref() == INFORMATION_SCHEMA_NAME
+                                || schema.as_ref() == PG_CATALOG_NAME),
                         QueryAccessDeniedSnafu {
                             catalog: catalog.as_ref(),
                             schema: schema.as_ref()
@@ -109,8 +139,34 @@ impl DfTableSourceProvider {
                 table: format_full_table_name(catalog_name, schema_name, table_name),
             })?;
 
-        let provider = DfTableProviderAdapter::new(table);
-        let source = provider_as_source(Arc::new(provider));
+        let table_info = table.table_info();
+        let source = if let Some(definition) = table_info.view_definition() {
+            let full_name = table_info.full_table_name();
+            let view_planner = self
+                .view_planner
+                .as_ref()
+                .with_context(|| UnimplementedSnafu {
+                    operation: format!("querying view {full_name} here"),
+                })?;
+            // Names in the definition are resolved in the schema of the view.
+            let plan = view_planner
+                .plan_view(
+                    definition,
+                    QueryContext::with(&table_info.catalog_name, &table_info.schema_name),
+                )
+                .await
+                .context(PlanViewSnafu { name: &full_name })?;
+            // The view is inlined into the query by DataFusion's `InlineTableScan` rule.
+            let view =
+                ViewTable::try_new(plan, Some(definition.to_string())).with_context(|_| {
+                    DatafusionSnafu {
+                        msg: format!("failed to create view {full_name}"),
+                    }
+                })?;
+            provider_as_source(Arc::new(view))
+        } else {
+            provider_as_source(Arc::new(DfTableProviderAdapter::new(table)))
+        };
         let _ = self.resolved_tables.insert(resolved_name, source.clone());
         Ok(source)
     }
@@ -187,5 +243,11 @@ mod tests {
             table: Cow::Borrowed("columns"),
         };
         assert!(table_provider.resolve_table_ref(table_ref).is_err());
+
+        let table_ref = TableReference::Partial {
+            schema: Cow::Borrowed("pg_catalog"),
+            table: Cow::Borrowed("pg_class"),
+        };
+        let _ = table_provider.resolve_table_ref(table_ref).unwrap();
     }
 }
@@ -256,6 +256,7 @@ async fn create_query_engine(meta_addr: &str) -> Result<DatafusionQueryEngine> {
         cached_meta_backend.clone(),
         cached_meta_backend.clone(),
         datanode_clients,
+        None,
     );
     let plugins: Arc<Plugins> = Default::default();
     let state = Arc::new(QueryEngineState::new(
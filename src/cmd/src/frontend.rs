@@ -194,6 +194,14 @@ impl StartCommand {
             .build_servers(&opts)
             .await
             .context(error::StartFrontendSnafu)?;
+        instance
+            .build_ruler(&opts)
+            .context(error::StartFrontendSnafu)?;
+        instance.build_flow(&opts);
+        instance
+            .build_audit(&opts)
+            .context(error::StartFrontendSnafu)?;
+        instance.build_slow_query_log(&opts);
 
         Ok(Instance { frontend: instance })
     }
@@ -28,9 +28,13 @@ use datanode::config::{DatanodeOptions, ProcedureConfig, StorageConfig};
 use datanode::datanode::{Datanode, DatanodeBuilder};
 use datanode::region_server::RegionServer;
 use frontend::frontend::FrontendOptions;
-use frontend::instance::{FrontendInstance, Instance as FeInstance, StandaloneDatanodeManager};
+use frontend::instance::{
+    FrontendInstance, Instance as FeInstance, StandaloneDatanodeManager,
+    StandaloneRegionStatsProvider,
+};
 use frontend::service_config::{
-    GrpcOptions, InfluxdbOptions, MysqlOptions, OpentsdbOptions, PostgresOptions, PromStoreOptions,
+    AuditOptions, FlowOptions, GrpcOptions, InfluxdbOptions, MysqlOptions, OpentsdbOptions,
+    PostgresOptions, PromStoreOptions, RulerOptions, SlowQueryOptions,
 };
 use serde::{Deserialize, Serialize};
 use servers::http::HttpOptions;
@@ -92,6 +96,10 @@ pub struct StandaloneOptions {
     pub opentsdb_options: OpentsdbOptions,
     pub influxdb_options: InfluxdbOptions,
     pub prom_store_options: PromStoreOptions,
+    pub ruler_options: RulerOptions,
+    pub flow_options: FlowOptions,
+    pub audit_options: AuditOptions,
+    pub slow_query_options: SlowQueryOptions,
     pub wal: WalConfig,
     pub storage: StorageConfig,
     pub kv_store: KvStoreConfig,
@@ -111,6 +119,10 @@ impl Default for StandaloneOptions {
             opentsdb_options: OpentsdbOptions::default(),
             influxdb_options: InfluxdbOptions::default(),
             prom_store_options: PromStoreOptions::default(),
+            ruler_options: RulerOptions::default(),
+            flow_options: FlowOptions::default(),
+            audit_options: AuditOptions::default(),
+            slow_query_options: SlowQueryOptions::default(),
             wal: WalConfig::default(),
             storage: StorageConfig::default(),
             kv_store: KvStoreConfig::default(),
@@ -131,6 +143,10 @@ impl StandaloneOptions {
             opentsdb: self.opentsdb_options,
             influxdb: self.influxdb_options,
             prom_store: self.prom_store_options,
+            ruler: self.ruler_options,
+            flow: self.flow_options,
+            audit: self.audit_options,
+            slow_query: self.slow_query_options,
             meta_client: None,
             logging: self.logging,
             ..Default::default()
@@ -319,6 +335,9 @@ impl StartCommand {
             kv_store.clone(),
             Arc::new(DummyKvCacheInvalidator),
             Arc::new(StandaloneDatanodeManager(region_server.clone())),
+            Some(Arc::new(StandaloneRegionStatsProvider(
+                region_server.clone(),
+            ))),
         );
 
         catalog_manager
@@ -341,6 +360,10 @@ impl StartCommand {
             .build_servers(&fe_opts)
             .await
             .context(StartFrontendSnafu)?;
+        frontend.build_ruler(&fe_opts).context(StartFrontendSnafu)?;
+        frontend.build_flow(&fe_opts);
+        frontend.build_audit(&fe_opts).context(StartFrontendSnafu)?;
+        frontend.build_slow_query_log(&fe_opts);
 
         Ok(Instance { datanode, frontend })
     }
@@ -14,6 +14,7 @@
 
 pub const SYSTEM_CATALOG_NAME: &str = "system";
 pub const INFORMATION_SCHEMA_NAME: &str = "information_schema";
+pub const PG_CATALOG_NAME: &str = "pg_catalog";
 pub const SYSTEM_CATALOG_TABLE_NAME: &str = "system_catalog";
 pub const DEFAULT_CATALOG_NAME: &str = "greptime";
 pub const DEFAULT_SCHEMA_NAME: &str = "public";
@@ -33,6 +34,34 @@ pub const NUMBERS_TABLE_ID: u32 = 2;
 pub const INFORMATION_SCHEMA_TABLES_TABLE_ID: u32 = 3;
 /// id for information_schema.columns
 pub const INFORMATION_SCHEMA_COLUMNS_TABLE_ID: u32 = 4;
+/// id for information_schema.schemata
+pub const INFORMATION_SCHEMA_SCHEMATA_TABLE_ID: u32 = 5;
+/// id for information_schema.key_column_usage
+pub const INFORMATION_SCHEMA_KEY_COLUMN_USAGE_TABLE_ID: u32 = 6;
+/// id for information_schema.engines
+pub const INFORMATION_SCHEMA_ENGINES_TABLE_ID: u32 = 7;
+/// id for information_schema.partitions
+pub const INFORMATION_SCHEMA_PARTITIONS_TABLE_ID: u32 = 8;
+/// id for information_schema.region_peers
+pub const INFORMATION_SCHEMA_REGION_PEERS_TABLE_ID: u32 = 9;
+/// id for information_schema.region_statistics
+pub const INFORMATION_SCHEMA_REGION_STATISTICS_TABLE_ID: u32 = 10;
+/// id for information_schema.build_info
+pub const INFORMATION_SCHEMA_BUILD_INFO_TABLE_ID: u32 = 11;
+/// id for pg_catalog.pg_namespace
+pub const PG_CATALOG_PG_NAMESPACE_TABLE_ID: u32 = 12;
+/// id for pg_catalog.pg_class
+pub const PG_CATALOG_PG_CLASS_TABLE_ID: u32 = 13;
+/// id for pg_catalog.pg_attribute
+pub const PG_CATALOG_PG_ATTRIBUTE_TABLE_ID: u32 = 14;
+/// id for pg_catalog.pg_type
+pub const PG_CATALOG_PG_TYPE_TABLE_ID: u32 = 15;
+/// id for information_schema.slow_queries
+pub const INFORMATION_SCHEMA_SLOW_QUERIES_TABLE_ID: u32 = 16;
+/// id for information_schema.views
+pub const INFORMATION_SCHEMA_VIEWS_TABLE_ID: u32 = 17;
+/// id for pg_catalog.pg_description
+pub const PG_CATALOG_PG_DESCRIPTION_TABLE_ID: u32 = 18;
 
 pub const MITO_ENGINE: &str = "mito";
 pub const MITO2_ENGINE: &str = "mito2";
@@ -0,0 +1,336 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use snafu::{ensure, OptionExt};
+
+use crate::error::{
+    DecimalOverflowSnafu, Error, InvalidPrecisionOrScaleSnafu, ParseDecimalSnafu, Result,
+};
+
+/// The maximum precision of [Decimal128], same as arrow's `DECIMAL128_MAX_PRECISION`.
+pub const DECIMAL128_MAX_PRECISION: u8 = 38;
+/// The maximum scale of [Decimal128].
+pub const DECIMAL128_MAX_SCALE: i8 = 38;
+/// The default scale used when the scale of a decimal is not specified.
+pub const DECIMAL128_DEFAULT_SCALE: i8 = 10;
+
+/// A 128-bit fixed-point decimal number. The numeric value is `value * 10^-scale`
+/// and it must not have more than `precision` significant digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Decimal128 {
+    value: i128,
+    precision: u8,
+    scale: i8,
+}
+
+impl Decimal128 {
+    /// Creates a new decimal without checking the precision and scale.
+    ///
+    /// Callers must ensure the arguments are valid, e.g. they come from an arrow
+    /// decimal array that has already been validated.
+    pub const fn new(value: i128, precision: u8, scale: i8) -> Self {
+        Self {
+            value,
+            precision,
+            scale,
+        }
+    }
+
+    /// Creates a new decimal, returns error if the precision or scale is invalid
+    /// or the value doesn't fit into the precision.
+    pub fn try_new(value: i128, precision: u8, scale: i8) -> Result<Self> {
+        valid_precision_and_scale(precision, scale)?;
+        ensure!(
+            value.unsigned_abs() < pow10(precision as u32),
+            DecimalOverflowSnafu {
+                value: Self::new(value, precision, scale).to_string(),
+                precision,
+            }
+        );
+        Ok(Self::new(value, precision, scale))
+    }
+
+    /// Returns the unscaled integer value.
+    pub fn val(&self) -> i128 {
+        self.value
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// Splits the unscaled value into its high and low 64 bits.
+    pub fn split_value(&self) -> (i64, i64) {
+        ((self.value >> 64) as i64, self.value as i64)
+    }
+
+    /// Builds a decimal from the high and low 64 bits returned by [Decimal128::split_value].
+    pub fn from_value_precision_scale(hi: i64, lo: i64, precision: u8, scale: i8) -> Self {
+        let value = ((hi as i128) << 64) | (lo as u64 as i128);
+        Self::new(value, precision, scale)
+    }
+
+    /// Converts the decimal into another precision and scale. Digits removed by
+    /// reducing the scale are rounded half away from zero.
+    pub fn rescale(&self, precision: u8, scale: i8) -> Result<Self> {
+        valid_precision_and_scale(precision, scale)?;
+
+        let value = match scale.cmp(&self.scale) {
+            Ordering::Equal => Some(self.value),
+            Ordering::Greater => self
+                .value
+                .checked_mul(pow10((scale - self.scale) as u32) as i128),
+            Ordering::Less => {
+                let divisor = pow10((self.scale - scale) as u32) as i128;
+                let (quotient, remainder) = (self.value / divisor, self.value % divisor);
+                if remainder.unsigned_abs() * 2 >= divisor as u128 {
+                    Some(quotient + self.value.signum())
+                } else {
+                    Some(quotient)
+                }
+            }
+        }
+        .with_context(|| DecimalOverflowSnafu {
+            value: self.to_string(),
+            precision,
+        })?;
+
+        Self::try_new(value, precision, scale)
+    }
+
+    /// Converts the decimal to a (possibly lossy) f64.
+    pub fn as_f64(&self) -> f64 {
+        self.value as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// Returns the integer part and the fractional part (as an unscaled value).
+    fn split_at_point(&self) -> (i128, i128) {
+        let divisor = pow10(self.scale as u32) as i128;
+        (self.value / divisor, self.value % divisor)
+    }
+}
+
+impl Default for Decimal128 {
+    fn default() -> Self {
+        Self::new(0, DECIMAL128_MAX_PRECISION, DECIMAL128_DEFAULT_SCALE)
+    }
+}
+
+impl PartialOrd for Decimal128 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal128 {
+    /// Compares decimals by their numeric value. Decimals with the same numeric value
+    /// are then ordered by scale and precision so the ordering is consistent with [Eq].
+    fn cmp(&self, other: &Self) -> Ordering {
+        let numeric = if self.scale == other.scale {
+            self.value.cmp(&other.value)
+        } else {
+            let (self_int, self_frac) = self.split_at_point();
+            let (other_int, other_frac) = other.split_at_point();
+            // The fractional parts have less than `max(scale)` digits, so aligning
+            // them to the larger scale never overflows.
+            let max_scale = self.scale.max(other.scale);
+            let self_frac = self_frac * pow10((max_scale - self.scale) as u32) as i128;
+            let other_frac = other_frac * pow10((max_scale - other.scale) as u32) as i128;
+            self_int.cmp(&other_int).then(self_frac.cmp(&other_frac))
+        };
+
+        numeric
+            .then(self.scale.cmp(&other.scale))
+            .then(self.precision.cmp(&other.precision))
+    }
+}
+
+impl Display for Decimal128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.value < 0 { "-" } else { "" };
+        let digits = self.value.unsigned_abs().to_string();
+        let scale = self.scale.max(0) as usize;
+        if scale == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+
+        let digits = format!("{digits:0>width$}", width = scale + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+        write!(f, "{sign}{int_part}.{frac_part}")
+    }
+}
+
+impl FromStr for Decimal128 {
+    type Err = Error;
+
+    /// Parses a decimal literal such as `-123.4500`. The precision and scale are
+    /// inferred from the literal.
+    fn from_str(s: &str) -> Result<Self> {
+        let raw = s.trim();
+        let unsigned = raw.strip_prefix(['-', '+']).unwrap_or(raw);
+        let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        ensure!(
+            !(int_part.is_empty() && frac_part.is_empty())
+                && int_part
+                    .chars()
+                    .chain(frac_part.chars())
+                    .all(|c| c.is_ascii_digit()),
+            ParseDecimalSnafu { raw }
+        );
+
+        let int_part = int_part.trim_start_matches('0');
+        let scale = frac_part.len();
+        let precision = (int_part.len() + scale).max(1);
+        ensure!(
+            precision <= DECIMAL128_MAX_PRECISION as usize,
+            ParseDecimalSnafu { raw }
+        );
+
+        let digits = format!("{int_part}{frac_part}");
+        let mut value = if digits.is_empty() {
+            0
+        } else {
+            digits
+                .parse::<i128>()
+                .ok()
+                .context(ParseDecimalSnafu { raw })?
+        };
+        if raw.starts_with('-') {
+            value = -value;
+        }
+
+        Ok(Self::new(value, precision as u8, scale as i8))
+    }
+}
+
+/// Checks whether the precision and scale are valid for [Decimal128].
+pub fn valid_precision_and_scale(precision: u8, scale: i8) -> Result<()> {
+    ensure!(
+        precision > 0
+            && precision <= DECIMAL128_MAX_PRECISION
+            && scale >= 0
+            && scale <= DECIMAL128_MAX_SCALE
+            && scale as u8 <= precision,
+        InvalidPrecisionOrScaleSnafu { precision, scale }
+    );
+    Ok(())
+}
+
+/// Returns `10^exp`, `exp` must not be larger than [DECIMAL128_MAX_PRECISION].
+fn pow10(exp: u32) -> u128 {
+    10u128.pow(exp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_display() {
+        assert_eq!("123.45", Decimal128::new(12345, 5, 2).to_string());
+        assert_eq!("-0.05", Decimal128::new(-5, 3, 2).to_string());
+        assert_eq!("0.000", Decimal128::new(0, 3, 3).to_string());
+        assert_eq!("42", Decimal128::new(42, 10, 0).to_string());
+        assert_eq!("-42", Decimal128::new(-42, 10, 0).to_string());
+    }
+
+    #[test]
+    fn test_decimal_from_str() {
+        let d = Decimal128::from_str("-123.4500").unwrap();
+        assert_eq!(-1234500, d.val());
+        assert_eq!(7, d.precision());
+        assert_eq!(4, d.scale());
+        assert_eq!("-123.4500", d.to_string());
+
+        let d = Decimal128::from_str("0.01").unwrap();
+        assert_eq!((1, 2, 2), (d.val(), d.precision(), d.scale()));
+
+        let d = Decimal128::from_str("+42").unwrap();
+        assert_eq!((42, 2, 0), (d.val(), d.precision(), d.scale()));
+
+        let d = Decimal128::from_str(".5").unwrap();
+        assert_eq!((5, 1, 1), (d.val(), d.precision(), d.scale()));
+
+        assert!(Decimal128::from_str("").is_err());
+        assert!(Decimal128::from_str(".").is_err());
+        assert!(Decimal128::from_str("1.2.3").is_err());
+        assert!(Decimal128::from_str("1e10").is_err());
+        assert!(Decimal128::from_str(&"9".repeat(39)).is_err());
+    }
+
+    #[test]
+    fn test_decimal_try_new() {
+        assert!(Decimal128::try_new(99999, 5, 2).is_ok());
+        assert!(Decimal128::try_new(-99999, 5, 2).is_ok());
+        assert!(Decimal128::try_new(100000, 5, 2).is_err());
+        assert!(Decimal128::try_new(1, 0, 0).is_err());
+        assert!(Decimal128::try_new(1, 39, 0).is_err());
+        assert!(Decimal128::try_new(1, 5, 6).is_err());
+        assert!(Decimal128::try_new(1, 5, -1).is_err());
+    }
+
+    #[test]
+    fn test_decimal_rescale() {
+        let d = Decimal128::new(12345, 5, 2);
+        assert_eq!(Decimal128::new(1234500, 7, 4), d.rescale(7, 4).unwrap());
+        assert_eq!(Decimal128::new(1235, 4, 1), d.rescale(4, 1).unwrap());
+        assert_eq!(Decimal128::new(123, 3, 0), d.rescale(3, 0).unwrap());
+        assert_eq!(
+            Decimal128::new(-1235, 4, 1),
+            Decimal128::new(-12345, 5, 2).rescale(4, 1).unwrap()
+        );
+        assert!(d.rescale(4, 2).is_err());
+        assert!(Decimal128::new(i128::MAX / 10, 38, 0)
+            .rescale(38, 10)
+            .is_err());
+    }
+
+    #[test]
+    fn test_decimal_split_value() {
+        for value in [0, 1, -1, i64::MAX as i128 + 1, i128::MIN, i128::MAX] {
+            let d = Decimal128::new(value, 38, 0);
+            let (hi, lo) = d.split_value();
+            assert_eq!(d, Decimal128::from_value_precision_scale(hi, lo, 38, 0));
+        }
+    }
+
+    #[test]
+    fn test_decimal_cmp() {
+        let a = Decimal128::new(150, 5, 2);
+        let b = Decimal128::new(15, 5, 1);
+        let c = Decimal128::new(-15, 5, 1);
+        let d = Decimal128::new(-2, 5, 2);
+        assert_eq!(Ordering::Greater, a.cmp(&b));
+        assert_ne!(a, b);
+        assert!(c < d);
+        assert!(d < b);
+        assert!(Decimal128::new(12, 5, 1) > Decimal128::new(119, 5, 2));
+        assert!(Decimal128::new(-12, 5, 1) < Decimal128::new(-119, 5, 2));
+    }
+
+    #[test]
+    fn test_decimal_as_f64() {
+        assert_eq!(1.5, Decimal128::new(150, 5, 2).as_f64());
+        assert_eq!(-0.25, Decimal128::new(-25, 5, 2).as_f64());
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_error::ext::ErrorExt;
+use common_error::status_code::StatusCode;
+use snafu::{Location, Snafu};
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub))]
+pub enum Error {
+    #[snafu(display("Failed to parse string to decimal, raw: {}", raw))]
+    ParseDecimal { raw: String, location: Location },
+
+    #[snafu(display(
+        "Invalid decimal precision or scale, precision: {}, scale: {}",
+        precision,
+        scale
+    ))]
+    InvalidPrecisionOrScale {
+        precision: u8,
+        scale: i8,
+        location: Location,
+    },
+
+    #[snafu(display("Decimal value {} overflows precision {}", value, precision))]
+    DecimalOverflow {
+        value: String,
+        precision: u8,
+        location: Location,
+    },
+}
+
+impl ErrorExt for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::ParseDecimal { .. }
+            | Error::InvalidPrecisionOrScale { .. }
+            | Error::DecimalOverflow { .. } => StatusCode::InvalidArguments,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn location_opt(&self) -> Option<common_error::snafu::Location> {
+        match self {
+            Error::ParseDecimal { location, .. }
+            | Error::InvalidPrecisionOrScale { location, .. }
+            | Error::DecimalOverflow { location, .. } => Some(*location),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,262 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+use common_query::error::{InvalidFuncArgsSnafu, Result, UnsupportedInputDataTypeSnafu};
+use common_query::prelude::{Signature, TypeSignature, Volatility};
+use datatypes::prelude::ConcreteDataType;
+use datatypes::vectors::{BooleanVector, Float64Vector, Int64Vector, StringVector, VectorRef};
+use snafu::ensure;
+
+use crate::scalars::function::{Function, FunctionContext};
+
+/// Looks up `path` in the binary JSON `json` and returns the first matched value
+/// in its binary JSON form.
+fn get_json_by_path(json: &[u8], path: &str) -> Option<Vec<u8>> {
+    let path = jsonb::jsonpath::parse_json_path(path.as_bytes()).ok()?;
+    let mut data = Vec::new();
+    let mut offsets = Vec::new();
+    jsonb::get_by_path(json, path, &mut data, &mut offsets);
+    if data.is_empty() {
+        None
+    } else {
+        Some(data)
+    }
+}
+
+/// Defines a `json_get_*` function that extracts the value under a JSON path and
+/// converts it to the given type. Returns null if the path does not exist or the
+/// value can't be converted.
+macro_rules! json_get {
+    (
+        $name: ident,
+        $func_name: expr,
+        $return_type: expr,
+        $vector: ident,
+        $convert: ident,
+        $display_name: expr,
+        $doc: expr
+    ) => {
+        #[doc = $doc]
+        #[derive(Clone, Debug, Default)]
+        pub struct $name;
+
+        impl Function for $name {
+            fn name(&self) -> &str {
+                $func_name
+            }
+
+            fn return_type(&self, _input_types: &[ConcreteDataType]) -> Result<ConcreteDataType> {
+                Ok($return_type)
+            }
+
+            fn signature(&self) -> Signature {
+                json_path_signature()
+            }
+
+            fn eval(&self, _func_ctx: FunctionContext, columns: &[VectorRef]) -> Result<VectorRef> {
+                ensure_json_and_path(self.name(), columns)?;
+
+                let (jsons, paths) = (&columns[0], &columns[1]);
+                let results = (0..jsons.len())
+                    .map(|i| {
+                        let (json, path) = (jsons.get_ref(i), paths.get_ref(i));
+                        let json = json.as_binary().ok().flatten()?;
+                        let path = path.as_string().ok().flatten()?;
+                        let value = get_json_by_path(json, path)?;
+                        jsonb::$convert(&value).ok()
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(Arc::new($vector::from(results)))
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", $display_name)
+            }
+        }
+    };
+}
+
+/// The signature of functions taking a JSON value and a JSON path.
+pub(super) fn json_path_signature() -> Signature {
+    Signature::one_of(
+        vec![
+            TypeSignature::Exact(vec![
+                ConcreteDataType::json_datatype(),
+                ConcreteDataType::string_datatype(),
+            ]),
+            TypeSignature::Exact(vec![
+                ConcreteDataType::binary_datatype(),
+                ConcreteDataType::string_datatype(),
+            ]),
+        ],
+        Volatility::Immutable,
+    )
+}
+
+/// Ensures `columns` are a JSON column followed by a string path column.
+pub(super) fn ensure_json_and_path(function: &str, columns: &[VectorRef]) -> Result<()> {
+    ensure!(
+        columns.len() == 2,
+        InvalidFuncArgsSnafu {
+            err_msg: format!(
+                "The length of the args is not correct, expect exactly two, have: {}",
+                columns.len()
+            ),
+        }
+    );
+    ensure!(
+        matches!(
+            columns[0].data_type(),
+            ConcreteDataType::Binary(_) | ConcreteDataType::Json(_)
+        ) && matches!(columns[1].data_type(), ConcreteDataType::String(_)),
+        UnsupportedInputDataTypeSnafu {
+            function,
+            datatypes: columns.iter().map(|c| c.data_type()).collect::<Vec<_>>(),
+        }
+    );
+    Ok(())
+}
+
+json_get!(
+    JsonGetInt,
+    "json_get_int",
+    ConcreteDataType::int64_datatype(),
+    Int64Vector,
+    to_i64,
+    "JSON_GET_INT",
+    "Get the value from the JSONB by the given path and return it as an integer."
+);
+
+json_get!(
+    JsonGetFloat,
+    "json_get_float",
+    ConcreteDataType::float64_datatype(),
+    Float64Vector,
+    to_f64,
+    "JSON_GET_FLOAT",
+    "Get the value from the JSONB by the given path and return it as a float."
+);
+
+json_get!(
+    JsonGetBool,
+    "json_get_bool",
+    ConcreteDataType::boolean_datatype(),
+    BooleanVector,
+    to_bool,
+    "JSON_GET_BOOL",
+    "Get the value from the JSONB by the given path and return it as a boolean."
+);
+
+json_get!(
+    JsonGetString,
+    "json_get_string",
+    ConcreteDataType::string_datatype(),
+    StringVector,
+    to_str,
+    "JSON_GET_STRING",
+    "Get the value from the JSONB by the given path and return it as a string."
+);
+
+#[cfg(test)]
+mod tests {
+    use datatypes::types::parse_string_to_jsonb;
+    use datatypes::value::Value;
+    use datatypes::vectors::BinaryVector;
+
+    use super::*;
+
+    fn json_vector(jsons: &[&str]) -> VectorRef {
+        let jsons = jsons
+            .iter()
+            .map(|s| Some(parse_string_to_jsonb(s).unwrap()))
+            .collect::<Vec<_>>();
+        Arc::new(BinaryVector::from(jsons))
+    }
+
+    #[test]
+    fn test_json_get_int() {
+        let f = JsonGetInt;
+        assert_eq!("json_get_int", f.name());
+        assert_eq!(
+            ConcreteDataType::int64_datatype(),
+            f.return_type(&[]).unwrap()
+        );
+
+        let jsons = json_vector(&[r#"{"a": {"b": 2}, "b": 2}"#, r#"{"a": 4}"#, r#"{"a": "x"}"#]);
+        let paths: VectorRef = Arc::new(StringVector::from(vec!["$.a.b", "$.a", "$.a"]));
+        let vector = f.eval(FunctionContext::default(), &[jsons, paths]).unwrap();
+
+        assert_eq!(3, vector.len());
+        assert_eq!(Value::Int64(2), vector.get(0));
+        assert_eq!(Value::Int64(4), vector.get(1));
+        assert_eq!(Value::Null, vector.get(2));
+    }
+
+    #[test]
+    fn test_json_get_float() {
+        let f = JsonGetFloat;
+        assert_eq!("json_get_float", f.name());
+
+        let jsons = json_vector(&[r#"{"a": 2.1}"#, r#"{"a": 3}"#, r#"{"b": 1.5}"#]);
+        let paths: VectorRef = Arc::new(StringVector::from(vec!["$.a", "$.a", "$.a"]));
+        let vector = f.eval(FunctionContext::default(), &[jsons, paths]).unwrap();
+
+        assert_eq!(Value::Float64(2.1.into()), vector.get(0));
+        assert_eq!(Value::Float64(3.0.into()), vector.get(1));
+        assert_eq!(Value::Null, vector.get(2));
+    }
+
+    #[test]
+    fn test_json_get_bool() {
+        let f = JsonGetBool;
+        assert_eq!("json_get_bool", f.name());
+
+        let jsons = json_vector(&[r#"{"a": true}"#, r#"{"a": {"b": false}}"#]);
+        let paths: VectorRef = Arc::new(StringVector::from(vec!["$.a", "$.a.b"]));
+        let vector = f.eval(FunctionContext::default(), &[jsons, paths]).unwrap();
+
+        assert_eq!(Value::Boolean(true), vector.get(0));
+        assert_eq!(Value::Boolean(false), vector.get(1));
+    }
+
+    #[test]
+    fn test_json_get_string() {
+        let f = JsonGetString;
+        assert_eq!("json_get_string", f.name());
+
+        let jsons = json_vector(&[r#"{"a": "hello"}"#, r#"{"a": {"b": "world"}}"#, "{}"]);
+        let paths: VectorRef = Arc::new(StringVector::from(vec!["$.a", "$.a.b", "$.a"]));
+        let vector = f.eval(FunctionContext::default(), &[jsons, paths]).unwrap();
+
+        assert_eq!(Value::String("hello".into()), vector.get(0));
+        assert_eq!(Value::String("world".into()), vector.get(1));
+        assert_eq!(Value::Null, vector.get(2));
+    }
+
+    #[test]
+    fn test_json_get_invalid_args() {
+        let f = JsonGetInt;
+        let paths: VectorRef = Arc::new(StringVector::from(vec!["$.a"]));
+        assert!(f
+            .eval(FunctionContext::default(), &[paths.clone(), paths.clone()])
+            .is_err());
+        assert!(f.eval(FunctionContext::default(), &[paths]).is_err());
+    }
+}
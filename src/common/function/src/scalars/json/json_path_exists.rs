@@ -0,0 +1,104 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+use common_query::error::Result;
+use common_query::prelude::Signature;
+use datatypes::prelude::ConcreteDataType;
+use datatypes::vectors::{BooleanVector, VectorRef};
+
+use super::json_get::{ensure_json_and_path, json_path_signature};
+use crate::scalars::function::{Function, FunctionContext};
+
+/// Checks whether the given JSON path exists in the JSONB value.
+#[derive(Clone, Debug, Default)]
+pub struct JsonPathExistsFunction;
+
+const NAME: &str = "json_path_exists";
+
+impl Function for JsonPathExistsFunction {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn return_type(&self, _input_types: &[ConcreteDataType]) -> Result<ConcreteDataType> {
+        Ok(ConcreteDataType::boolean_datatype())
+    }
+
+    fn signature(&self) -> Signature {
+        json_path_signature()
+    }
+
+    fn eval(&self, _func_ctx: FunctionContext, columns: &[VectorRef]) -> Result<VectorRef> {
+        ensure_json_and_path(NAME, columns)?;
+
+        let (jsons, paths) = (&columns[0], &columns[1]);
+        let results = (0..jsons.len())
+            .map(|i| {
+                let (json, path) = (jsons.get_ref(i), paths.get_ref(i));
+                let json = json.as_binary().ok().flatten()?;
+                let path = path.as_string().ok().flatten()?;
+                // An invalid path never matches anything.
+                let exists = jsonb::jsonpath::parse_json_path(path.as_bytes())
+                    .map(|path| jsonb::path_exists(json, path))
+                    .unwrap_or(false);
+                Some(exists)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Arc::new(BooleanVector::from(results)))
+    }
+}
+
+impl Display for JsonPathExistsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JSON_PATH_EXISTS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::types::parse_string_to_jsonb;
+    use datatypes::value::Value;
+    use datatypes::vectors::{BinaryVector, StringVector};
+
+    use super::*;
+
+    #[test]
+    fn test_json_path_exists() {
+        let f = JsonPathExistsFunction;
+        assert_eq!("json_path_exists", f.name());
+        assert_eq!(
+            ConcreteDataType::boolean_datatype(),
+            f.return_type(&[]).unwrap()
+        );
+
+        let jsons = [r#"{"a": {"b": 1}}"#, r#"{"a": 1}"#, r#"[1, 2]"#]
+            .iter()
+            .map(|s| Some(parse_string_to_jsonb(s).unwrap()))
+            .chain([None])
+            .collect::<Vec<_>>();
+        let jsons: VectorRef = Arc::new(BinaryVector::from(jsons));
+        let paths: VectorRef = Arc::new(StringVector::from(vec!["$.a.b", "$.b", "$[1]", "$.a"]));
+        let vector = f.eval(FunctionContext::default(), &[jsons, paths]).unwrap();
+
+        assert_eq!(4, vector.len());
+        assert_eq!(Value::Boolean(true), vector.get(0));
+        assert_eq!(Value::Boolean(false), vector.get(1));
+        assert_eq!(Value::Boolean(true), vector.get(2));
+        assert_eq!(Value::Null, vector.get(3));
+    }
+}
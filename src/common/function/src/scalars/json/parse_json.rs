@@ -0,0 +1,122 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+use common_query::error::{InvalidFuncArgsSnafu, Result, UnsupportedInputDataTypeSnafu};
+use common_query::prelude::{Signature, Volatility};
+use datatypes::prelude::ConcreteDataType;
+use datatypes::types::parse_string_to_jsonb;
+use datatypes::vectors::{BinaryVector, VectorRef};
+use snafu::ensure;
+
+use crate::scalars::function::{Function, FunctionContext};
+
+/// Parses JSON text into a JSONB value.
+#[derive(Clone, Debug, Default)]
+pub struct ParseJsonFunction;
+
+const NAME: &str = "parse_json";
+
+impl Function for ParseJsonFunction {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn return_type(&self, _input_types: &[ConcreteDataType]) -> Result<ConcreteDataType> {
+        Ok(ConcreteDataType::json_datatype())
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::exact(
+            vec![ConcreteDataType::string_datatype()],
+            Volatility::Immutable,
+        )
+    }
+
+    fn eval(&self, _func_ctx: FunctionContext, columns: &[VectorRef]) -> Result<VectorRef> {
+        ensure!(
+            columns.len() == 1,
+            InvalidFuncArgsSnafu {
+                err_msg: format!(
+                    "The length of the args is not correct, expect exactly one, have: {}",
+                    columns.len()
+                ),
+            }
+        );
+        ensure!(
+            matches!(columns[0].data_type(), ConcreteDataType::String(_)),
+            UnsupportedInputDataTypeSnafu {
+                function: NAME,
+                datatypes: columns.iter().map(|c| c.data_type()).collect::<Vec<_>>(),
+            }
+        );
+
+        let texts = &columns[0];
+        let results = (0..texts.len())
+            .map(|i| {
+                let text = texts.get_ref(i);
+                match text.as_string().ok().flatten() {
+                    Some(text) => parse_string_to_jsonb(text).map(Some).map_err(|e| {
+                        InvalidFuncArgsSnafu {
+                            err_msg: e.to_string(),
+                        }
+                        .build()
+                    }),
+                    None => Ok(None),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(BinaryVector::from(results)))
+    }
+}
+
+impl Display for ParseJsonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PARSE_JSON")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::types::jsonb_to_string;
+    use datatypes::vectors::StringVector;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_json() {
+        let f = ParseJsonFunction;
+        assert_eq!("parse_json", f.name());
+        assert_eq!(
+            ConcreteDataType::json_datatype(),
+            f.return_type(&[]).unwrap()
+        );
+
+        let texts: VectorRef = Arc::new(StringVector::from(vec![Some(r#"{"a": [1, "b"]}"#), None]));
+        let vector = f.eval(FunctionContext::default(), &[texts]).unwrap();
+        assert_eq!(2, vector.len());
+        let json = vector.get_ref(0);
+        assert_eq!(
+            r#"{"a":[1,"b"]}"#,
+            jsonb_to_string(json.as_binary().unwrap().unwrap())
+        );
+        assert!(vector.get_ref(1).is_null());
+
+        let texts: VectorRef = Arc::new(StringVector::from(vec!["{invalid"]));
+        assert!(f.eval(FunctionContext::default(), &[texts]).is_err());
+    }
+}
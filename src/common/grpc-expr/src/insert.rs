@@ -23,7 +23,7 @@ use snafu::{ensure, ResultExt};
 use table::engine::TableReference;
 use table::metadata::TableId;
 
-use crate::error::{CreateVectorSnafu, Result, UnexpectedValuesLengthSnafu};
+use crate::error::{ColumnDataTypeSnafu, CreateVectorSnafu, Result, UnexpectedValuesLengthSnafu};
 use crate::util;
 use crate::util::ColumnExpr;
 
@@ -59,10 +59,11 @@ pub(crate) fn add_values_to_builder(
     null_mask: Vec<u8>,
 ) -> Result<VectorRef> {
     if null_mask.is_empty() {
-        Ok(helper::pb_values_to_vector_ref(&data_type, values))
+        helper::pb_values_to_vector_ref(&data_type, values).context(ColumnDataTypeSnafu)
     } else {
         let builder = &mut data_type.create_mutable_vector(row_count);
-        let values = helper::pb_values_to_values(&data_type, values);
+        let values =
+            helper::pb_values_to_values(&data_type, values).context(ColumnDataTypeSnafu)?;
         let null_mask = BitVec::from_vec(null_mask);
         ensure!(
             null_mask.count_ones() + values.len() == row_count,
@@ -71,6 +71,10 @@ macro_rules! convert_arrow_array_to_grpc_vals {
                     return Ok(vals);
                 },
             )+
+            // The protocol has no decimal values yet.
+            ConcreteDataType::Decimal128(_) => ConversionSnafu {
+                from: format!("{:?}", $data_type),
+            }.fail(),
             ConcreteDataType::Null(_) | ConcreteDataType::List(_) | ConcreteDataType::Dictionary(_) => unreachable!("Should not send {:?} in gRPC", $data_type),
         }
     }};
@@ -128,7 +132,7 @@ pub fn values(arrays: &[VectorRef]) -> Result<Values> {
             |x| { x }
         ),
         (
-            ConcreteDataType::Binary(_),
+            ConcreteDataType::Binary(_) | ConcreteDataType::Json(_),
             BinaryVector,
             binary_values,
             |x| { x.into() }
@@ -51,6 +51,9 @@ pub trait CacheInvalidator: Send + Sync {
     async fn invalidate_table_id(&self, ctx: &Context, table_id: TableId) -> Result<()>;
 
     async fn invalidate_table_name(&self, ctx: &Context, table_name: TableName) -> Result<()>;
+
+    // Invalidates schema cache
+    async fn invalidate_schema(&self, ctx: &Context, catalog: &str, schema: &str) -> Result<()>;
 }
 
 pub type CacheInvalidatorRef = Arc<dyn CacheInvalidator>;
@@ -66,6 +69,10 @@ impl CacheInvalidator for DummyCacheInvalidator {
     async fn invalidate_table_name(&self, _ctx: &Context, _table_name: TableName) -> Result<()> {
         Ok(())
     }
+
+    async fn invalidate_schema(&self, _ctx: &Context, _catalog: &str, _schema: &str) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
@@ -75,11 +82,6 @@ impl TableMetadataCacheInvalidator {
     pub fn new(kv_cache_invalidator: KvCacheInvalidatorRef) -> Self {
         Self(kv_cache_invalidator)
     }
-
-    pub async fn invalidate_schema(&self, catalog: &str, schema: &str) {
-        let key = SchemaNameKey::new(catalog, schema).as_raw_key();
-        self.0.invalidate_key(&key).await;
-    }
 }
 
 #[async_trait::async_trait]
@@ -101,4 +103,11 @@ impl CacheInvalidator for TableMetadataCacheInvalidator {
 
         Ok(())
     }
+
+    async fn invalidate_schema(&self, _ctx: &Context, catalog: &str, schema: &str) -> Result<()> {
+        let key = SchemaNameKey::new(catalog, schema).as_raw_key();
+        self.0.invalidate_key(&key).await;
+
+        Ok(())
+    }
 }
@@ -27,6 +27,7 @@ use crate::rpc::router::RegionRoute;
 
 pub mod alter_table;
 pub mod create_table;
+pub mod drop_database;
 pub mod drop_table;
 pub mod utils;
 
@@ -42,6 +43,12 @@ pub trait DdlTaskExecutor: Send + Sync {
         ctx: &ExecutorContext,
         request: SubmitDdlTaskRequest,
     ) -> Result<SubmitDdlTaskResponse>;
+
+    /// Returns true if the executor accepts drop database tasks, otherwise the caller
+    /// drops the tables of the database one by one.
+    fn supports_drop_database(&self) -> bool {
+        false
+    }
 }
 
 pub type DdlTaskExecutorRef = Arc<dyn DdlTaskExecutor>;
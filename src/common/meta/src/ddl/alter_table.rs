@@ -37,7 +37,7 @@ use table::metadata::{RawTableInfo, TableId, TableInfo};
 use table::requests::AlterKind;
 
 use crate::cache_invalidator::Context;
-use crate::ddl::utils::handle_operate_region_error;
+use crate::ddl::utils::{handle_operate_region_error, schema_lock_key};
 use crate::ddl::DdlContext;
 use crate::error::{
     self, ConvertAlterTableRequestSnafu, InvalidProtoMsgSnafu, Result, TableRouteNotFoundSnafu,
@@ -341,7 +341,10 @@ impl AlterTableProcedure {
             table_ref.schema,
             table_ref.table,
         );
-        let mut lock_key = vec![table_key];
+        let mut lock_key = vec![
+            schema_lock_key(table_ref.catalog, table_ref.schema),
+            table_key,
+        ];
 
         if let Ok(Kind::RenameTable(RenameTable { new_table_name })) = self.alter_kind() {
             lock_key.push(common_catalog::format_full_table_name(
@@ -27,9 +27,11 @@ use snafu::{ensure, OptionExt, ResultExt};
 use store_api::storage::RegionId;
 use strum::AsRefStr;
 use table::engine::TableReference;
-use table::metadata::{RawTableInfo, TableId};
+use table::metadata::{RawTableInfo, TableId, TableType};
 
-use crate::ddl::utils::{handle_operate_region_error, handle_retry_error, region_storage_path};
+use crate::ddl::utils::{
+    handle_operate_region_error, handle_retry_error, region_storage_path, schema_lock_key,
+};
 use crate::ddl::DdlContext;
 use crate::error::{self, Result};
 use crate::key::table_name::TableNameKey;
@@ -102,7 +104,12 @@ impl CreateTableProcedure {
             return Ok(Status::Done);
         }
 
-        self.creator.data.state = CreateTableState::DatanodeCreateRegions;
+        self.creator.data.state = if self.table_info().table_type == TableType::View {
+            // A view has no regions, only its metadata is created.
+            CreateTableState::CreateMetadata
+        } else {
+            CreateTableState::DatanodeCreateRegions
+        };
 
         Ok(Status::executing(true))
     }
@@ -277,7 +284,7 @@ impl Procedure for CreateTableProcedure {
             table_ref.table,
         );
 
-        LockKey::single(key)
+        LockKey::new([schema_lock_key(table_ref.catalog, table_ref.schema), key])
     }
 }
 
@@ -0,0 +1,606 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use api::v1::region::{
+    region_request, DropRequest as PbDropRegionRequest, RegionRequest, RegionRequestHeader,
+};
+use async_trait::async_trait;
+use common_catalog::consts::{DEFAULT_SCHEMA_NAME, INFORMATION_SCHEMA_NAME, PG_CATALOG_NAME};
+use common_error::ext::{BoxedError, ErrorExt};
+use common_error::status_code::StatusCode;
+use common_procedure::error::{FromJsonSnafu, ToJsonSnafu};
+use common_procedure::{
+    Context as ProcedureContext, LockKey, Procedure, Result as ProcedureResult, Status,
+};
+use common_telemetry::{debug, info, warn};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use snafu::{ensure, ResultExt};
+use store_api::storage::RegionId;
+use strum::AsRefStr;
+use table::metadata::TableId;
+
+use super::utils::{handle_retry_error, schema_lock_key};
+use crate::cache_invalidator::Context;
+use crate::ddl::DdlContext;
+use crate::error::{self, Result};
+use crate::key::schema_name::SchemaNameKey;
+use crate::metrics;
+use crate::rpc::ddl::DropDatabaseTask;
+use crate::rpc::router::{find_leader_regions, find_leaders, RegionRoute};
+use crate::table_name::TableName;
+
+/// Databases that can't be dropped.
+const RESERVED_SCHEMAS: [&str; 3] = [
+    DEFAULT_SCHEMA_NAME,
+    INFORMATION_SCHEMA_NAME,
+    PG_CATALOG_NAME,
+];
+
+/// Returns true if the database is created by the system and can't be dropped.
+pub fn is_reserved_schema(schema: &str) -> bool {
+    RESERVED_SCHEMAS.contains(&schema)
+}
+
+/// Drops a database and all tables in it.
+///
+/// Tables are dropped one by one and the progress is persisted after each table, so the
+/// procedure resumes from the table being dropped if it's interrupted, e.g., a datanode
+/// is unavailable.
+///
+/// The procedure holds the [schema_lock_key] during its whole execution, which DDL
+/// procedures of tables in the database also acquire.
+pub struct DropDatabaseProcedure {
+    pub context: DdlContext,
+    pub data: DropDatabaseData,
+}
+
+impl DropDatabaseProcedure {
+    pub const TYPE_NAME: &'static str = "metasrv-procedure::DropDatabase";
+
+    pub fn new(cluster_id: u64, task: DropDatabaseTask, context: DdlContext) -> Self {
+        Self {
+            context,
+            data: DropDatabaseData::new(cluster_id, task),
+        }
+    }
+
+    pub fn from_json(json: &str, context: DdlContext) -> ProcedureResult<Self> {
+        let data = serde_json::from_str(json).context(FromJsonSnafu)?;
+        Ok(Self { context, data })
+    }
+
+    fn schema_key(&self) -> SchemaNameKey<'_> {
+        SchemaNameKey::new(&self.data.task.catalog, &self.data.task.schema)
+    }
+
+    /// Checks the schema exists and collects the tables to drop.
+    async fn on_prepare(&mut self) -> Result<Status> {
+        let task = &self.data.task;
+        ensure!(
+            !is_reserved_schema(&task.schema),
+            error::UnsupportedSnafu {
+                operation: format!("dropping reserved database {}", task.schema),
+            }
+        );
+        let table_metadata_manager = &self.context.table_metadata_manager;
+
+        let exists = table_metadata_manager
+            .schema_manager()
+            .exists(self.schema_key())
+            .await?;
+
+        if !exists {
+            return if task.drop_if_exists {
+                Ok(Status::Done)
+            } else {
+                error::SchemaNotFoundSnafu {
+                    catalog: &task.catalog,
+                    schema: &task.schema,
+                }
+                .fail()
+            };
+        }
+
+        self.data.tables = table_metadata_manager
+            .table_name_manager()
+            .tables(&task.catalog, &task.schema)
+            .await?
+            .into_iter()
+            .map(|(table_name, value)| (table_name, value.table_id()))
+            .collect();
+
+        self.data.state = DropDatabaseState::DropTables;
+
+        Ok(Status::executing(true))
+    }
+
+    /// Drops the last table in [DropDatabaseData::tables]: drops its regions, removes its
+    /// metadata and invalidates its cache.
+    ///
+    /// Regions are dropped before the metadata is removed, so the table route is still
+    /// available if the procedure is resumed.
+    async fn on_drop_tables(&mut self) -> Result<Status> {
+        let Some((table, table_id)) = self.data.tables.last().cloned() else {
+            self.data.state = DropDatabaseState::RemoveSchema;
+            return Ok(Status::executing(true));
+        };
+
+        let table_metadata_manager = &self.context.table_metadata_manager;
+        let (table_info_value, table_route_value) =
+            table_metadata_manager.get_full_table_info(table_id).await?;
+
+        match (table_info_value, table_route_value) {
+            (Some(table_info_value), Some(table_route_value)) => {
+                self.drop_regions(table_id, &table_route_value.region_routes)
+                    .await?;
+
+                table_metadata_manager
+                    .delete_table_metadata(&table_info_value, &table_route_value)
+                    .await?;
+
+                let ctx = Context {
+                    subject: Some("Invalidate table cache by dropping database".to_string()),
+                };
+                let table_name =
+                    TableName::new(&self.data.task.catalog, &self.data.task.schema, &table);
+                let cache_invalidator = &self.context.cache_invalidator;
+                cache_invalidator
+                    .invalidate_table_name(&ctx, table_name)
+                    .await?;
+                cache_invalidator
+                    .invalidate_table_id(&ctx, table_id)
+                    .await?;
+
+                info!(
+                    "Dropped table {table}({table_id}) of database {}",
+                    self.data.task.schema
+                );
+            }
+            _ => {
+                // The table is dropped by others, or the procedure is resumed after its
+                // metadata is removed.
+                warn!("Metadata of table {table}({table_id}) not found, skip dropping it");
+            }
+        }
+
+        let _ = self.data.tables.pop();
+
+        Ok(Status::executing(true))
+    }
+
+    async fn drop_regions(&self, table_id: TableId, region_routes: &[RegionRoute]) -> Result<()> {
+        let leaders = find_leaders(region_routes);
+        let mut drop_region_tasks = Vec::with_capacity(leaders.len());
+
+        for datanode in leaders {
+            let requester = self.context.datanode_manager.datanode(&datanode).await;
+
+            let regions = find_leader_regions(region_routes, &datanode);
+            for region_number in regions {
+                let region_id = RegionId::new(table_id, region_number);
+                debug!("Dropping region {region_id} on Datanode {datanode:?}");
+
+                let request = RegionRequest {
+                    header: Some(RegionRequestHeader {
+                        trace_id: 0,
+                        span_id: 0,
+                    }),
+                    body: Some(region_request::Body::Drop(PbDropRegionRequest {
+                        region_id: region_id.as_u64(),
+                    })),
+                };
+
+                let requester = requester.clone();
+
+                drop_region_tasks.push(async move {
+                    if let Err(err) = requester.handle(request).await {
+                        if err.status_code() != StatusCode::RegionNotFound {
+                            // The datanode may be unavailable for a while, retries the
+                            // procedure from the current table instead of failing it.
+                            return Err(error::Error::RetryLater {
+                                source: BoxedError::new(err),
+                            });
+                        }
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        join_all(drop_region_tasks)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(())
+    }
+
+    async fn on_remove_schema(&mut self) -> Result<Status> {
+        let table_metadata_manager = &self.context.table_metadata_manager;
+        let task = &self.data.task;
+        table_metadata_manager
+            .flow_info_manager()
+            .delete_schema_flows(&task.catalog, &task.schema)
+            .await?;
+        table_metadata_manager
+            .schema_manager()
+            .delete(self.schema_key())
+            .await?;
+
+        info!(
+            "Deleted schema metadata and flows for database {}.{}",
+            self.data.task.catalog, self.data.task.schema
+        );
+
+        self.data.state = DropDatabaseState::InvalidateSchemaCache;
+
+        Ok(Status::executing(true))
+    }
+
+    async fn on_invalidate_schema_cache(&mut self) -> Result<Status> {
+        let ctx = Context {
+            subject: Some("Invalidate schema cache by dropping database".to_string()),
+        };
+
+        self.context
+            .cache_invalidator
+            .invalidate_schema(&ctx, &self.data.task.catalog, &self.data.task.schema)
+            .await?;
+
+        Ok(Status::Done)
+    }
+
+    async fn execute_inner(&mut self) -> Result<Status> {
+        match self.data.state {
+            DropDatabaseState::Prepare => self.on_prepare().await,
+            DropDatabaseState::DropTables => self.on_drop_tables().await,
+            DropDatabaseState::RemoveSchema => self.on_remove_schema().await,
+            DropDatabaseState::InvalidateSchemaCache => self.on_invalidate_schema_cache().await,
+        }
+    }
+}
+
+#[async_trait]
+impl Procedure for DropDatabaseProcedure {
+    fn type_name(&self) -> &str {
+        Self::TYPE_NAME
+    }
+
+    async fn execute(&mut self, _ctx: &ProcedureContext) -> ProcedureResult<Status> {
+        let state = &self.data.state;
+
+        let _timer = common_telemetry::timer!(
+            metrics::METRIC_META_PROCEDURE_DROP_DATABASE,
+            &[("step", state.as_ref().to_string())]
+        );
+
+        self.execute_inner().await.map_err(handle_retry_error)
+    }
+
+    fn dump(&self) -> ProcedureResult<String> {
+        serde_json::to_string(&self.data).context(ToJsonSnafu)
+    }
+
+    fn lock_key(&self) -> LockKey {
+        let key = schema_lock_key(&self.data.task.catalog, &self.data.task.schema);
+
+        LockKey::single(key)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DropDatabaseData {
+    pub state: DropDatabaseState,
+    pub cluster_id: u64,
+    pub task: DropDatabaseTask,
+    /// Names and ids of the tables that are not dropped yet.
+    pub tables: Vec<(String, TableId)>,
+}
+
+impl DropDatabaseData {
+    pub fn new(cluster_id: u64, task: DropDatabaseTask) -> Self {
+        Self {
+            state: DropDatabaseState::Prepare,
+            cluster_id,
+            task,
+            tables: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, AsRefStr)]
+pub enum DropDatabaseState {
+    /// Prepares to drop the database
+    Prepare,
+    /// Drops tables one by one
+    DropTables,
+    /// Removes the schema metadata and flows in the schema
+    RemoveSchema,
+    /// Invalidates Schema Cache
+    InvalidateSchemaCache,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use api::v1::region::QueryRequest;
+    use common_recordbatch::SendableRecordBatchStream;
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::{ColumnSchema, SchemaBuilder};
+    use table::metadata::{RawTableInfo, TableInfoBuilder, TableMetaBuilder};
+
+    use super::*;
+    use crate::cache_invalidator::DummyCacheInvalidator;
+    use crate::datanode_manager::{AffectedRows, Datanode, DatanodeManager, DatanodeRef};
+    use crate::key::table_name::TableNameKey;
+    use crate::key::TableMetadataManager;
+    use crate::kv_backend::memory::MemoryKvBackend;
+    use crate::peer::Peer;
+    use crate::rpc::router::Region;
+
+    const CATALOG: &str = "greptime";
+    const SCHEMA: &str = "my_db";
+
+    /// A datanode that records the dropped regions and fails while it's unavailable.
+    #[derive(Default)]
+    struct MockDatanode {
+        unavailable: AtomicBool,
+        dropped_regions: Mutex<Vec<RegionId>>,
+    }
+
+    #[async_trait]
+    impl Datanode for MockDatanode {
+        async fn handle(&self, request: RegionRequest) -> Result<AffectedRows> {
+            if self.unavailable.load(Ordering::Relaxed) {
+                return error::UnexpectedSnafu {
+                    err_msg: "datanode is unavailable",
+                }
+                .fail();
+            }
+            let Some(region_request::Body::Drop(request)) = request.body else {
+                unreachable!()
+            };
+            self.dropped_regions
+                .lock()
+                .unwrap()
+                .push(RegionId::from_u64(request.region_id));
+            Ok(0)
+        }
+
+        async fn handle_query(&self, _request: QueryRequest) -> Result<SendableRecordBatchStream> {
+            unreachable!()
+        }
+    }
+
+    struct MockDatanodeManager(Arc<MockDatanode>);
+
+    #[async_trait]
+    impl DatanodeManager for MockDatanodeManager {
+        async fn datanode(&self, _datanode: &Peer) -> DatanodeRef {
+            self.0.clone()
+        }
+    }
+
+    fn new_table_info(table_id: TableId, name: &str) -> RawTableInfo {
+        let column_schemas = vec![ColumnSchema::new(
+            "ts",
+            ConcreteDataType::timestamp_millisecond_datatype(),
+            false,
+        )
+        .with_time_index(true)];
+        let schema = SchemaBuilder::try_from(column_schemas)
+            .unwrap()
+            .build()
+            .unwrap();
+        let meta = TableMetaBuilder::default()
+            .schema(Arc::new(schema))
+            .primary_key_indices(vec![])
+            .engine("engine")
+            .next_column_id(1)
+            .build()
+            .unwrap();
+        TableInfoBuilder::default()
+            .table_id(table_id)
+            .name(name)
+            .catalog_name(CATALOG)
+            .schema_name(SCHEMA)
+            .meta(meta)
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn new_region_routes(table_id: TableId) -> Vec<RegionRoute> {
+        (0..2)
+            .map(|region_number| RegionRoute {
+                region: Region {
+                    id: RegionId::new(table_id, region_number),
+                    name: format!("r{region_number}"),
+                    partition: None,
+                    attrs: BTreeMap::new(),
+                },
+                leader_peer: Some(Peer::new(region_number as u64, "a")),
+                follower_peers: vec![],
+            })
+            .collect()
+    }
+
+    async fn new_context(datanode: Arc<MockDatanode>) -> DdlContext {
+        let table_metadata_manager = Arc::new(TableMetadataManager::new(Arc::new(
+            MemoryKvBackend::default(),
+        )));
+        table_metadata_manager
+            .schema_manager()
+            .create(SchemaNameKey::new(CATALOG, SCHEMA), None, false)
+            .await
+            .unwrap();
+        for (table_id, name) in [(1024, "t1"), (1025, "t2")] {
+            table_metadata_manager
+                .create_table_metadata(new_table_info(table_id, name), new_region_routes(table_id))
+                .await
+                .unwrap();
+        }
+
+        DdlContext {
+            datanode_manager: Arc::new(MockDatanodeManager(datanode)),
+            cache_invalidator: Arc::new(DummyCacheInvalidator),
+            table_metadata_manager,
+        }
+    }
+
+    fn new_procedure(schema: &str, context: DdlContext) -> DropDatabaseProcedure {
+        let task = DropDatabaseTask {
+            catalog: CATALOG.to_string(),
+            schema: schema.to_string(),
+            drop_if_exists: false,
+        };
+        DropDatabaseProcedure::new(0, task, context)
+    }
+
+    async fn execute_until_done(procedure: &mut DropDatabaseProcedure) {
+        while !matches!(procedure.execute_inner().await.unwrap(), Status::Done) {}
+    }
+
+    async fn assert_dropped(context: &DdlContext) {
+        let table_metadata_manager = &context.table_metadata_manager;
+        assert!(!table_metadata_manager
+            .schema_manager()
+            .exists(SchemaNameKey::new(CATALOG, SCHEMA))
+            .await
+            .unwrap());
+        for (table_id, name) in [(1024, "t1"), (1025, "t2")] {
+            assert!(table_metadata_manager
+                .table_name_manager()
+                .get(TableNameKey::new(CATALOG, SCHEMA, name))
+                .await
+                .unwrap()
+                .is_none());
+            let (table_info, table_route) = table_metadata_manager
+                .get_full_table_info(table_id)
+                .await
+                .unwrap();
+            assert!(table_info.is_none() && table_route.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_database() {
+        let datanode = Arc::new(MockDatanode::default());
+        let context = new_context(datanode.clone()).await;
+        let mut procedure = new_procedure(SCHEMA, context.clone());
+        execute_until_done(&mut procedure).await;
+
+        assert_dropped(&context).await;
+        let mut dropped_regions = datanode.dropped_regions.lock().unwrap().clone();
+        dropped_regions.sort_by_key(|region_id| region_id.as_u64());
+        let expected = [1024, 1025]
+            .into_iter()
+            .flat_map(|table_id| [RegionId::new(table_id, 0), RegionId::new(table_id, 1)])
+            .collect::<Vec<_>>();
+        assert_eq!(expected, dropped_regions);
+    }
+
+    #[tokio::test]
+    async fn test_drop_database_resume() {
+        let datanode = Arc::new(MockDatanode::default());
+        let context = new_context(datanode.clone()).await;
+        let mut procedure = new_procedure(SCHEMA, context.clone());
+        assert!(procedure.execute_inner().await.unwrap().need_persist());
+        assert_eq!(2, procedure.data.tables.len());
+
+        // Drops the first table, then the datanode goes down.
+        assert!(procedure.execute_inner().await.unwrap().need_persist());
+        assert_eq!(1, procedure.data.tables.len());
+        datanode.unavailable.store(true, Ordering::Relaxed);
+        let err = procedure.execute_inner().await.unwrap_err();
+        assert!(err.is_retry_later(), "{err:?}");
+        // The table is kept until its regions are dropped.
+        assert_eq!(1, procedure.data.tables.len());
+
+        // Restarts the procedure from the persisted data.
+        let json = procedure.dump().unwrap();
+        let mut procedure = DropDatabaseProcedure::from_json(&json, context.clone()).unwrap();
+        assert!(matches!(
+            procedure.data.state,
+            DropDatabaseState::DropTables
+        ));
+        assert_eq!(1, procedure.data.tables.len());
+        datanode.unavailable.store(false, Ordering::Relaxed);
+        execute_until_done(&mut procedure).await;
+
+        assert_dropped(&context).await;
+        assert_eq!(4, datanode.dropped_regions.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_drop_database_resume_after_metadata_deleted() {
+        let datanode = Arc::new(MockDatanode::default());
+        let context = new_context(datanode.clone()).await;
+        let mut procedure = new_procedure(SCHEMA, context.clone());
+        assert!(procedure.execute_inner().await.unwrap().need_persist());
+
+        // The procedure is interrupted after the metadata of the last table is deleted.
+        let (_, table_id) = procedure.data.tables.last().cloned().unwrap();
+        let (table_info, table_route) = context
+            .table_metadata_manager
+            .get_full_table_info(table_id)
+            .await
+            .unwrap();
+        context
+            .table_metadata_manager
+            .delete_table_metadata(&table_info.unwrap(), &table_route.unwrap())
+            .await
+            .unwrap();
+
+        let json = procedure.dump().unwrap();
+        let mut procedure = DropDatabaseProcedure::from_json(&json, context.clone()).unwrap();
+        execute_until_done(&mut procedure).await;
+
+        assert_dropped(&context).await;
+        // Only regions of the other table are dropped.
+        assert_eq!(2, datanode.dropped_regions.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_drop_reserved_database() {
+        let datanode = Arc::new(MockDatanode::default());
+        let context = new_context(datanode).await;
+        for schema in RESERVED_SCHEMAS {
+            let mut procedure = new_procedure(schema, context.clone());
+            let err = procedure.execute_inner().await.unwrap_err();
+            assert!(matches!(err, error::Error::Unsupported { .. }), "{err:?}");
+        }
+    }
+
+    #[test]
+    fn test_lock_key() {
+        let datanode = Arc::new(MockDatanode::default());
+        let context = DdlContext {
+            datanode_manager: Arc::new(MockDatanodeManager(datanode)),
+            cache_invalidator: Arc::new(DummyCacheInvalidator),
+            table_metadata_manager: Arc::new(TableMetadataManager::new(Arc::new(
+                MemoryKvBackend::default(),
+            ))),
+        };
+        let procedure = new_procedure(SCHEMA, context);
+        assert_eq!(
+            vec![&schema_lock_key(CATALOG, SCHEMA)],
+            procedure.lock_key().keys_to_lock().collect::<Vec<_>>()
+        );
+    }
+}
@@ -33,7 +33,7 @@ use table::metadata::{RawTableInfo, TableId};
 
 use super::utils::handle_retry_error;
 use crate::cache_invalidator::Context;
-use crate::ddl::utils::handle_operate_region_error;
+use crate::ddl::utils::{handle_operate_region_error, schema_lock_key};
 use crate::ddl::DdlContext;
 use crate::error::{self, Result};
 use crate::key::table_info::TableInfoValue;
@@ -222,7 +222,7 @@ impl Procedure for DropTableProcedure {
             table_ref.table,
         );
 
-        LockKey::single(key)
+        LockKey::new([schema_lock_key(table_ref.catalog, table_ref.schema), key])
     }
 }
 
@@ -43,6 +43,13 @@ pub fn handle_retry_error(e: Error) -> ProcedureError {
     }
 }
 
+/// Returns the lock key of the schema. DDL procedures of tables also acquire it, so they
+/// are excluded while the schema is being dropped.
+#[inline]
+pub fn schema_lock_key(catalog: &str, schema: &str) -> String {
+    format!("{}.{}", catalog, schema)
+}
+
 #[inline]
 pub fn region_storage_path(catalog: &str, schema: &str) -> String {
     format!("{}/{}", catalog, schema)
@@ -22,6 +22,7 @@ use crate::cache_invalidator::CacheInvalidatorRef;
 use crate::datanode_manager::DatanodeManagerRef;
 use crate::ddl::alter_table::AlterTableProcedure;
 use crate::ddl::create_table::CreateTableProcedure;
+use crate::ddl::drop_database::DropDatabaseProcedure;
 use crate::ddl::drop_table::DropTableProcedure;
 use crate::ddl::{
     DdlContext, DdlTaskExecutor, ExecutorContext, TableMetadataAllocatorContext,
@@ -35,10 +36,10 @@ use crate::key::table_info::TableInfoValue;
 use crate::key::table_name::TableNameKey;
 use crate::key::table_route::TableRouteValue;
 use crate::key::TableMetadataManagerRef;
-use crate::rpc::ddl::DdlTask::{AlterTable, CreateTable, DropTable, TruncateTable};
+use crate::rpc::ddl::DdlTask::{AlterTable, CreateTable, DropDatabase, DropTable, TruncateTable};
 use crate::rpc::ddl::{
-    AlterTableTask, CreateTableTask, DropTableTask, SubmitDdlTaskRequest, SubmitDdlTaskResponse,
-    TruncateTableTask,
+    AlterTableTask, CreateTableTask, DropDatabaseTask, DropTableTask, SubmitDdlTaskRequest,
+    SubmitDdlTaskResponse, TruncateTableTask,
 };
 use crate::rpc::router::RegionRoute;
 
@@ -122,6 +123,20 @@ impl DdlManager {
             )
             .context(RegisterProcedureLoaderSnafu {
                 type_name: AlterTableProcedure::TYPE_NAME,
+            })?;
+
+        let context = self.create_context();
+
+        self.procedure_manager
+            .register_loader(
+                DropDatabaseProcedure::TYPE_NAME,
+                Box::new(move |json| {
+                    let context = context.clone();
+                    DropDatabaseProcedure::from_json(json, context).map(|p| Box::new(p) as _)
+                }),
+            )
+            .context(RegisterProcedureLoaderSnafu {
+                type_name: DropDatabaseProcedure::TYPE_NAME,
             })
     }
 
@@ -179,6 +194,20 @@ impl DdlManager {
         self.submit_procedure(procedure_with_id).await
     }
 
+    pub async fn submit_drop_database_task(
+        &self,
+        cluster_id: u64,
+        drop_database_task: DropDatabaseTask,
+    ) -> Result<ProcedureId> {
+        let context = self.create_context();
+
+        let procedure = DropDatabaseProcedure::new(cluster_id, drop_database_task, context);
+
+        let procedure_with_id = ProcedureWithId::with_random_id(Box::new(procedure));
+
+        self.submit_procedure(procedure_with_id).await
+    }
+
     pub async fn submit_truncate_table_task(
         &self,
         cluster_id: u64,
@@ -327,6 +356,26 @@ async fn handle_drop_table_task(
     })
 }
 
+async fn handle_drop_database_task(
+    ddl_manager: &DdlManager,
+    cluster_id: u64,
+    drop_database_task: DropDatabaseTask,
+) -> Result<SubmitDdlTaskResponse> {
+    let catalog = drop_database_task.catalog.clone();
+    let schema = drop_database_task.schema.clone();
+
+    let id = ddl_manager
+        .submit_drop_database_task(cluster_id, drop_database_task)
+        .await?;
+
+    info!("Database: {catalog}.{schema} is dropped via procedure_id {id:?}");
+
+    Ok(SubmitDdlTaskResponse {
+        key: id.to_string().into(),
+        ..Default::default()
+    })
+}
+
 async fn handle_create_table_task(
     ddl_manager: &DdlManager,
     cluster_id: u64,
@@ -375,6 +424,13 @@ impl DdlTaskExecutor for DdlManager {
             TruncateTable(truncate_table_task) => {
                 handle_truncate_table_task(self, cluster_id, truncate_table_task).await
             }
+            DropDatabase(drop_database_task) => {
+                handle_drop_database_task(self, cluster_id, drop_database_task).await
+            }
         }
     }
+
+    fn supports_drop_database(&self) -> bool {
+        true
+    }
 }
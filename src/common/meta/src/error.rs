@@ -136,6 +136,9 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Unknown schema option: {}", key))]
+    UnknownSchemaOption { key: String, location: Location },
+
     #[snafu(display("Corrupted table route data, err: {}", err_msg))]
     RouteInfoCorrupted { err_msg: String, location: Location },
 
@@ -174,6 +177,13 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Schema not found, catalog: {}, schema: {}", catalog, schema))]
+    SchemaNotFound {
+        catalog: String,
+        schema: String,
+        location: Location,
+    },
+
     #[snafu(display("Failed to convert raw key to str"))]
     ConvertRawKey {
         location: Location,
@@ -249,7 +259,6 @@ impl ErrorExt for Error {
             IllegalServerState { .. } | EtcdTxnOpResponse { .. } => StatusCode::Internal,
 
             SerdeJson { .. }
-            | ParseOption { .. }
             | RouteInfoCorrupted { .. }
             | InvalidProtoMsg { .. }
             | InvalidTableMetadata { .. }
@@ -269,9 +278,12 @@ impl ErrorExt for Error {
             | RenameTable { .. }
             | Unsupported { .. } => StatusCode::Internal,
 
-            PrimaryKeyNotFound { .. } => StatusCode::InvalidArguments,
+            PrimaryKeyNotFound { .. } | ParseOption { .. } | UnknownSchemaOption { .. } => {
+                StatusCode::InvalidArguments
+            }
 
             TableNotFound { .. } => StatusCode::TableNotFound,
+            SchemaNotFound { .. } => StatusCode::DatabaseNotFound,
             TableAlreadyExists { .. } => StatusCode::TableAlreadyExists,
 
             EncodeJson { .. }
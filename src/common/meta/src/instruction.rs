@@ -90,6 +90,7 @@ pub enum Instruction {
     CloseRegion(RegionIdent),
     InvalidateTableIdCache(TableId),
     InvalidateTableNameCache(TableName),
+    InvalidateSchemaCache { catalog: String, schema: String },
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -36,6 +36,10 @@
 //!     - The value is a [TableNameValue] struct; it contains the table id.
 //!     - Used in the table name to table id lookup.
 //!
+//! 6. Flow info key: `__flow_info/{catalog_name}/{schema_name}/{flow_name}`
+//!     - The value is a [FlowInfoValue] struct; it contains the query, the sink table and the
+//!       progress of a flow.
+//!
 //! All keys have related managers. The managers take care of the serialization and deserialization
 //! of keys and values, and the interaction with the underlying KV store backend.
 //!
@@ -45,6 +49,7 @@
 
 pub mod catalog_name;
 pub mod datanode_table;
+pub mod flow_info;
 pub mod schema_name;
 pub mod table_info;
 pub mod table_name;
@@ -69,6 +74,7 @@ use table_info::{TableInfoKey, TableInfoManager, TableInfoValue};
 use table_name::{TableNameKey, TableNameManager, TableNameValue};
 
 use self::catalog_name::{CatalogManager, CatalogNameKey, CatalogNameValue};
+use self::flow_info::{FlowInfoManager, FlowInfoValue};
 use self::schema_name::{SchemaManager, SchemaNameKey, SchemaNameValue};
 use self::table_route::{TableRouteManager, TableRouteValue};
 use crate::ddl::utils::region_storage_path;
@@ -89,6 +95,7 @@ const TABLE_REGION_KEY_PREFIX: &str = "__table_region";
 const CATALOG_NAME_KEY_PREFIX: &str = "__catalog_name";
 const SCHEMA_NAME_KEY_PREFIX: &str = "__schema_name";
 const TABLE_ROUTE_PREFIX: &str = "__table_route";
+const FLOW_INFO_KEY_PREFIX: &str = "__flow_info";
 
 pub type RegionDistribution = BTreeMap<DatanodeId, Vec<RegionNumber>>;
 
@@ -120,6 +127,14 @@ lazy_static! {
     .unwrap();
 }
 
+lazy_static! {
+    /// FLOW_INFO_KEY: {FLOW_INFO_KEY_PREFIX}/{catalog_name}/{schema_name}/{flow_name}
+    static ref FLOW_INFO_KEY_PATTERN: Regex = Regex::new(&format!(
+        "^{FLOW_INFO_KEY_PREFIX}/({NAME_PATTERN})/({NAME_PATTERN})/({NAME_PATTERN})$"
+    ))
+    .unwrap();
+}
+
 pub fn to_removed_key(key: &str) -> String {
     format!("{REMOVED_PREFIX}-{key}")
 }
@@ -137,6 +152,7 @@ pub struct TableMetadataManager {
     catalog_manager: CatalogManager,
     schema_manager: SchemaManager,
     table_route_manager: TableRouteManager,
+    flow_info_manager: FlowInfoManager,
     kv_backend: KvBackendRef,
 }
 
@@ -163,6 +179,7 @@ impl TableMetadataManager {
             catalog_manager: CatalogManager::new(kv_backend.clone()),
             schema_manager: SchemaManager::new(kv_backend.clone()),
             table_route_manager: TableRouteManager::new(kv_backend.clone()),
+            flow_info_manager: FlowInfoManager::new(kv_backend.clone()),
             kv_backend,
         }
     }
@@ -203,6 +220,10 @@ impl TableMetadataManager {
         &self.table_route_manager
     }
 
+    pub fn flow_info_manager(&self) -> &FlowInfoManager {
+        &self.flow_info_manager
+    }
+
     #[cfg(feature = "testing")]
     pub fn kv_backend(&self) -> &KvBackendRef {
         &self.kv_backend
@@ -543,7 +564,8 @@ impl_table_meta_value! {
     TableNameValue,
     TableInfoValue,
     DatanodeTableValue,
-    TableRouteValue
+    TableRouteValue,
+    FlowInfoValue
 }
 
 impl_optional_meta_value! {
@@ -0,0 +1,287 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use futures::stream::BoxStream;
+use serde::{Deserialize, Serialize};
+use snafu::{OptionExt, ResultExt};
+
+use crate::error::{self, Error, InvalidTableMetadataSnafu, Result};
+use crate::key::{TableMetaKey, FLOW_INFO_KEY_PATTERN, FLOW_INFO_KEY_PREFIX};
+use crate::kv_backend::KvBackendRef;
+use crate::range_stream::{PaginationStream, DEFAULT_PAGE_SIZE};
+use crate::rpc::store::{CompareAndPutRequest, DeleteRangeRequest, RangeRequest};
+use crate::rpc::KeyValue;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowInfoKey<'a> {
+    pub catalog: &'a str,
+    pub schema: &'a str,
+    pub flow_name: &'a str,
+}
+
+impl<'a> FlowInfoKey<'a> {
+    pub fn new(catalog: &'a str, schema: &'a str, flow_name: &'a str) -> Self {
+        Self {
+            catalog,
+            schema,
+            flow_name,
+        }
+    }
+
+    /// Returns the prefix of all flow keys.
+    pub fn range_start_key() -> String {
+        format!("{}/", FLOW_INFO_KEY_PREFIX)
+    }
+
+    /// Returns the prefix of flow keys in the schema.
+    pub fn schema_range_start_key(catalog: &str, schema: &str) -> String {
+        format!("{}/{}/{}/", FLOW_INFO_KEY_PREFIX, catalog, schema)
+    }
+}
+
+impl Display for FlowInfoKey<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}/{}",
+            FLOW_INFO_KEY_PREFIX, self.catalog, self.schema, self.flow_name
+        )
+    }
+}
+
+impl TableMetaKey for FlowInfoKey<'_> {
+    fn as_raw_key(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FlowInfoKey<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        let captures = FLOW_INFO_KEY_PATTERN
+            .captures(s)
+            .context(InvalidTableMetadataSnafu {
+                err_msg: format!("Illegal FlowInfoKey format: '{s}'"),
+            })?;
+
+        // Safety: pass the regex check above
+        Ok(Self {
+            catalog: captures.get(1).unwrap().as_str(),
+            schema: captures.get(2).unwrap().as_str(),
+            flow_name: captures.get(3).unwrap().as_str(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlowInfoValue {
+    /// Name of the table results are written to, it's in the same database as the flow.
+    pub sink_table_name: String,
+    /// SQL of the query the flow evaluates.
+    pub query: String,
+    /// Time windows before the watermark (in milliseconds) are complete and won't be
+    /// evaluated again. `None` if the flow has never been evaluated.
+    pub watermark: Option<i64>,
+    /// The flow is being evaluated by a frontend until this time (in milliseconds), other
+    /// frontends don't evaluate it before the claim expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimed_until: Option<i64>,
+}
+
+impl FlowInfoValue {
+    pub fn new(sink_table_name: String, query: String) -> Self {
+        Self {
+            sink_table_name,
+            query,
+            watermark: None,
+            claimed_until: None,
+        }
+    }
+}
+
+/// A flow and its fully qualified name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowInfo {
+    pub catalog: String,
+    pub schema: String,
+    pub flow_name: String,
+    pub value: FlowInfoValue,
+}
+
+/// Decodes `KeyValue` to [FlowInfo].
+pub fn flow_info_decoder(kv: KeyValue) -> Result<(String, FlowInfo)> {
+    let str = std::str::from_utf8(&kv.key).context(error::ConvertRawKeySnafu)?;
+    let key = FlowInfoKey::try_from(str)?;
+    let value = FlowInfoValue::try_from_raw_value(&kv.value)?;
+
+    Ok((
+        str.to_string(),
+        FlowInfo {
+            catalog: key.catalog.to_string(),
+            schema: key.schema.to_string(),
+            flow_name: key.flow_name.to_string(),
+            value,
+        },
+    ))
+}
+
+pub struct FlowInfoManager {
+    kv_backend: KvBackendRef,
+}
+
+impl FlowInfoManager {
+    pub fn new(kv_backend: KvBackendRef) -> Self {
+        Self { kv_backend }
+    }
+
+    /// Creates the flow, returns false if the flow already exists.
+    pub async fn create(&self, key: FlowInfoKey<'_>, value: &FlowInfoValue) -> Result<bool> {
+        let raw_key = key.as_raw_key();
+        let raw_value = value.try_as_raw_value()?;
+
+        self.kv_backend
+            .put_conditionally(raw_key, raw_value, true)
+            .await
+    }
+
+    pub async fn get(&self, key: FlowInfoKey<'_>) -> Result<Option<FlowInfoValue>> {
+        let raw_key = key.as_raw_key();
+        self.kv_backend
+            .get(&raw_key)
+            .await?
+            .map(|kv| FlowInfoValue::try_from_raw_value(&kv.value))
+            .transpose()
+    }
+
+    /// Updates the flow to `new_value` if its value is still `current_value`.
+    ///
+    /// Returns false if the flow is changed or removed by others.
+    pub async fn update(
+        &self,
+        key: FlowInfoKey<'_>,
+        current_value: &FlowInfoValue,
+        new_value: &FlowInfoValue,
+    ) -> Result<bool> {
+        let req = CompareAndPutRequest::new()
+            .with_key(key.as_raw_key())
+            .with_expect(current_value.try_as_raw_value()?)
+            .with_value(new_value.try_as_raw_value()?);
+
+        Ok(self.kv_backend.compare_and_put(req).await?.success)
+    }
+
+    /// Deletes the flow, returns false if the flow doesn't exist.
+    pub async fn delete(&self, key: FlowInfoKey<'_>) -> Result<bool> {
+        let raw_key = key.as_raw_key();
+
+        Ok(self.kv_backend.delete(&raw_key, true).await?.is_some())
+    }
+
+    /// Deletes all flows in the schema.
+    pub async fn delete_schema_flows(&self, catalog: &str, schema: &str) -> Result<()> {
+        let start_key = FlowInfoKey::schema_range_start_key(catalog, schema);
+        let req = DeleteRangeRequest::new().with_prefix(start_key.as_bytes());
+        let _ = self.kv_backend.delete_range(req).await?;
+
+        Ok(())
+    }
+
+    /// Returns a stream of all flows.
+    pub async fn flows(&self) -> BoxStream<'static, Result<FlowInfo>> {
+        let start_key = FlowInfoKey::range_start_key();
+        let req = RangeRequest::new().with_prefix(start_key.as_bytes());
+
+        let stream = PaginationStream::new(
+            self.kv_backend.clone(),
+            req,
+            DEFAULT_PAGE_SIZE,
+            Arc::new(flow_info_decoder),
+        );
+
+        Box::pin(futures::StreamExt::map(stream, |kv| kv.map(|kv| kv.1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::kv_backend::memory::MemoryKvBackend;
+
+    #[test]
+    fn test_serialization() {
+        let key = FlowInfoKey::new("my-catalog", "my-schema", "my-flow");
+        assert_eq!(key.to_string(), "__flow_info/my-catalog/my-schema/my-flow");
+
+        let parsed: FlowInfoKey<'_> = "__flow_info/my-catalog/my-schema/my-flow"
+            .try_into()
+            .unwrap();
+        assert_eq!(key, parsed);
+
+        let value = FlowInfoValue {
+            sink_table_name: "sink".to_string(),
+            query: "SELECT 1".to_string(),
+            watermark: Some(1000),
+            claimed_until: Some(2000),
+        };
+        let raw = value.try_as_raw_value().unwrap();
+        assert_eq!(value, FlowInfoValue::try_from_raw_value(&raw).unwrap());
+
+        // Values without a claim keep the old format, so they can still be compared and set.
+        let raw = br#"{"sink_table_name":"sink","query":"SELECT 1","watermark":1000}"#;
+        let value = FlowInfoValue::try_from_raw_value(raw).unwrap();
+        assert_eq!(None, value.claimed_until);
+        assert_eq!(raw.to_vec(), value.try_as_raw_value().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_flow_info_manager() {
+        let manager = FlowInfoManager::new(Arc::new(MemoryKvBackend::default()));
+        let key = FlowInfoKey::new("greptime", "public", "flow_1");
+        let value = FlowInfoValue::new("sink".to_string(), "SELECT 1".to_string());
+        assert!(manager.create(key, &value).await.unwrap());
+        assert!(!manager.create(key, &value).await.unwrap());
+        assert_eq!(Some(value.clone()), manager.get(key).await.unwrap());
+
+        let new_value = FlowInfoValue {
+            watermark: Some(1000),
+            ..value.clone()
+        };
+        assert!(manager.update(key, &value, &new_value).await.unwrap());
+        // The value is changed.
+        assert!(!manager.update(key, &value, &new_value).await.unwrap());
+        assert_eq!(Some(new_value.clone()), manager.get(key).await.unwrap());
+
+        let other_key = FlowInfoKey::new("greptime", "other", "flow_2");
+        assert!(manager.create(other_key, &value).await.unwrap());
+        let flows: Vec<_> = manager.flows().await.try_collect().await.unwrap();
+        assert_eq!(2, flows.len());
+
+        manager
+            .delete_schema_flows("greptime", "other")
+            .await
+            .unwrap();
+        assert!(manager.get(other_key).await.unwrap().is_none());
+
+        assert!(manager.delete(key).await.unwrap());
+        assert!(!manager.delete(key).await.unwrap());
+        // The flow is removed, the update fails.
+        assert!(!manager.update(key, &new_value, &value).await.unwrap());
+    }
+}
@@ -26,7 +26,9 @@ use metrics::increment_counter;
 use serde::{Deserialize, Serialize};
 use snafu::{OptionExt, ResultExt};
 
-use crate::error::{self, Error, InvalidTableMetadataSnafu, ParseOptionSnafu, Result};
+use crate::error::{
+    self, Error, InvalidTableMetadataSnafu, ParseOptionSnafu, Result, UnknownSchemaOptionSnafu,
+};
 use crate::key::{TableMetaKey, SCHEMA_NAME_KEY_PATTERN, SCHEMA_NAME_KEY_PREFIX};
 use crate::kv_backend::KvBackendRef;
 use crate::range_stream::{PaginationStream, DEFAULT_PAGE_SIZE};
@@ -61,20 +63,40 @@ impl TryFrom<&HashMap<String, String>> for SchemaNameValue {
     type Error = Error;
 
     fn try_from(value: &HashMap<String, String>) -> std::result::Result<Self, Self::Error> {
-        let ttl = value
-            .get(OPT_KEY_TTL)
-            .map(|ttl_str| {
-                ttl_str.parse::<humantime::Duration>().map_err(|_| {
+        let mut schema_value = SchemaNameValue::default();
+        for (key, value) in value {
+            schema_value.set_option(key, value)?;
+        }
+        Ok(schema_value)
+    }
+}
+
+impl SchemaNameValue {
+    /// Sets the option `key` to `value`.
+    pub fn set_option(&mut self, key: &str, value: &str) -> Result<()> {
+        match key.to_lowercase().as_str() {
+            OPT_KEY_TTL => {
+                let ttl = value.parse::<humantime::Duration>().map_err(|_| {
                     ParseOptionSnafu {
                         key: OPT_KEY_TTL,
-                        value: ttl_str.clone(),
+                        value,
                     }
                     .build()
-                })
-            })
-            .transpose()?
-            .map(|ttl| ttl.into());
-        Ok(Self { ttl })
+                })?;
+                self.ttl = Some(ttl.into());
+            }
+            _ => return UnknownSchemaOptionSnafu { key }.fail(),
+        }
+        Ok(())
+    }
+
+    /// Resets the option `key` to its default value.
+    pub fn unset_option(&mut self, key: &str) -> Result<()> {
+        match key.to_lowercase().as_str() {
+            OPT_KEY_TTL => self.ttl = None,
+            _ => return UnknownSchemaOptionSnafu { key }.fail(),
+        }
+        Ok(())
     }
 }
 
@@ -161,12 +183,32 @@ impl SchemaManager {
         Ok(())
     }
 
+    /// Overwrites the value of `SchemaNameKey`.
+    pub async fn update(&self, schema: SchemaNameKey<'_>, value: &SchemaNameValue) -> Result<()> {
+        let raw_key = schema.as_raw_key();
+        let raw_value = value.try_as_raw_value()?;
+        let _ = self
+            .kv_backend
+            .put_conditionally(raw_key, raw_value, false)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn exists(&self, schema: SchemaNameKey<'_>) -> Result<bool> {
         let raw_key = schema.as_raw_key();
 
         self.kv_backend.exists(&raw_key).await
     }
 
+    /// Deletes `SchemaNameKey`, it's a no-op if the key doesn't exist.
+    pub async fn delete(&self, schema: SchemaNameKey<'_>) -> Result<()> {
+        let raw_key = schema.as_raw_key();
+        let _ = self.kv_backend.delete(&raw_key, false).await?;
+
+        Ok(())
+    }
+
     pub async fn get(&self, schema: SchemaNameKey<'_>) -> Result<Option<SchemaNameValue>> {
         let raw_key = schema.as_raw_key();
         let value = self.kv_backend.get(&raw_key).await?;
@@ -219,6 +261,39 @@ mod tests {
         assert!(none.is_none());
         let err_empty = SchemaNameValue::try_from_raw_value("".as_bytes());
         assert!(err_empty.is_err());
+
+        opts.insert("unknown".to_string(), "1".to_string());
+        assert!(SchemaNameValue::try_from(&opts).is_err());
+    }
+
+    #[test]
+    fn test_set_unset_option() {
+        let mut value = SchemaNameValue::default();
+        value.set_option("TTL", "7d").unwrap();
+        assert_eq!(Some(Duration::from_secs(3600 * 24 * 7)), value.ttl);
+        value.set_option("ttl", "abc").unwrap_err();
+        value.set_option("unknown", "1").unwrap_err();
+
+        value.unset_option("ttl").unwrap();
+        assert_eq!(SchemaNameValue::default(), value);
+        value.unset_option("unknown").unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_update() {
+        let manager = SchemaManager::new(Arc::new(MemoryKvBackend::default()));
+        let schema_key = SchemaNameKey::new("my-catalog", "my-schema");
+        manager.create(schema_key, None, false).await.unwrap();
+        assert_eq!(
+            Some(SchemaNameValue::default()),
+            manager.get(schema_key).await.unwrap()
+        );
+
+        let value = SchemaNameValue {
+            ttl: Some(Duration::from_secs(10)),
+        };
+        manager.update(schema_key, &value).await.unwrap();
+        assert_eq!(Some(value), manager.get(schema_key).await.unwrap());
     }
 
     #[tokio::test]
@@ -233,4 +308,18 @@ mod tests {
 
         assert!(!manager.exists(wrong_schema_key).await.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let manager = SchemaManager::new(Arc::new(MemoryKvBackend::default()));
+        let schema_key = SchemaNameKey::new("my-catalog", "my-schema");
+        manager.create(schema_key, None, false).await.unwrap();
+        assert!(manager.exists(schema_key).await.unwrap());
+
+        manager.delete(schema_key).await.unwrap();
+        assert!(!manager.exists(schema_key).await.unwrap());
+
+        // Deleting a non-existent schema is fine.
+        manager.delete(schema_key).await.unwrap();
+    }
 }
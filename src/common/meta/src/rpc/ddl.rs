@@ -36,6 +36,7 @@ pub enum DdlTask {
     DropTable(DropTableTask),
     AlterTable(AlterTableTask),
     TruncateTable(TruncateTableTask),
+    DropDatabase(DropDatabaseTask),
 }
 
 impl DdlTask {
@@ -68,6 +69,14 @@ impl DdlTask {
     pub fn new_truncate_table(truncate_table: TruncateTableExpr) -> Self {
         DdlTask::TruncateTable(TruncateTableTask { truncate_table })
     }
+
+    pub fn new_drop_database(catalog: String, schema: String, drop_if_exists: bool) -> Self {
+        DdlTask::DropDatabase(DropDatabaseTask {
+            catalog,
+            schema,
+            drop_if_exists,
+        })
+    }
 }
 
 impl TryFrom<Task> for DdlTask {
@@ -114,6 +123,15 @@ impl TryFrom<SubmitDdlTaskRequest> for PbSubmitDdlTaskRequest {
             DdlTask::TruncateTable(task) => Task::TruncateTableTask(PbTruncateTableTask {
                 truncate_table: Some(task.truncate_table),
             }),
+            // The proto has no drop database task yet, so it can only be executed by
+            // a local `DdlManager`. Other executors don't support it, and the tables of
+            // the database are dropped one by one instead.
+            DdlTask::DropDatabase(_) => {
+                return error::UnsupportedSnafu {
+                    operation: "submitting DROP DATABASE to metasrv",
+                }
+                .fail()
+            }
         };
 
         Ok(Self {
@@ -201,6 +219,13 @@ impl TryFrom<PbDropTableTask> for DropTableTask {
     }
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DropDatabaseTask {
+    pub catalog: String,
+    pub schema: String,
+    pub drop_if_exists: bool,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CreateTableTask {
     pub create_table: CreateTableExpr,
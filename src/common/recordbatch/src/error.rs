@@ -37,6 +37,9 @@ pub enum Error {
         source: datatypes::error::Error,
     },
 
+    #[snafu(display("Query is cancelled"))]
+    QueryCancelled { location: Location },
+
     #[snafu(display("External error"))]
     External {
         location: Location,
@@ -64,6 +67,12 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Failed to merge sorted streams"))]
+    MergeSortedStreams {
+        source: datafusion_common::DataFusionError,
+        location: Location,
+    },
+
     #[snafu(display("Failed to init Recordbatch stream"))]
     InitRecordbatchStream {
         source: datafusion_common::DataFusionError,
@@ -112,9 +121,12 @@ impl ErrorExt for Error {
             | Error::PollStream { .. }
             | Error::Format { .. }
             | Error::InitRecordbatchStream { .. }
+            | Error::MergeSortedStreams { .. }
             | Error::ColumnNotExists { .. }
             | Error::ProjectArrowRecordBatch { .. } => StatusCode::Internal,
 
+            Error::QueryCancelled { .. } => StatusCode::Cancelled,
+
             Error::External { source, .. } => source.status_code(),
 
             Error::SchemaConversion { source, .. } | Error::CastVector { source, .. } => {
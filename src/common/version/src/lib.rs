@@ -12,24 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-const DEFAULT_VALUE: &str = "unknown";
+mod setup;
 
-#[allow(clippy::print_stdout)]
-pub fn setup_git_versions() {
-    println!(
-        "cargo:rustc-env=GIT_COMMIT={}",
-        build_data::get_git_commit().unwrap_or_else(|_| DEFAULT_VALUE.to_string())
-    );
-    println!(
-        "cargo:rustc-env=GIT_COMMIT_SHORT={}",
-        build_data::get_git_commit_short().unwrap_or_else(|_| DEFAULT_VALUE.to_string())
-    );
-    println!(
-        "cargo:rustc-env=GIT_BRANCH={}",
-        build_data::get_git_branch().unwrap_or_else(|_| DEFAULT_VALUE.to_string())
-    );
-    println!(
-        "cargo:rustc-env=GIT_DIRTY={}",
-        build_data::get_git_dirty().map_or(DEFAULT_VALUE.to_string(), |v| v.to_string())
-    );
+pub use setup::setup_git_versions;
+
+/// Build information of the current binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    pub branch: &'static str,
+    pub commit: &'static str,
+    pub commit_short: &'static str,
+    pub dirty: &'static str,
+    pub rustc: &'static str,
+    pub source_time: &'static str,
+    pub version: &'static str,
+}
+
+/// Returns the [BuildInfo] collected at compile time.
+pub const fn build_info() -> BuildInfo {
+    BuildInfo {
+        branch: env!("GIT_BRANCH"),
+        commit: env!("GIT_COMMIT"),
+        commit_short: env!("GIT_COMMIT_SHORT"),
+        dirty: env!("GIT_DIRTY"),
+        rustc: env!("RUSTC_VERSION"),
+        source_time: env!("SOURCE_TIMESTAMP"),
+        version: env!("CARGO_PKG_VERSION"),
+    }
 }
@@ -262,11 +262,17 @@ impl HeartbeatTask {
         let regions = region_server.opened_regions();
         regions
             .into_iter()
-            .map(|(region_id, engine)| RegionStat {
-                region_id: region_id.as_u64(),
-                engine,
-                // TODO(ruihang): scratch more info
-                ..Default::default()
+            .map(|(region_id, engine)| {
+                let stat = region_server
+                    .region_statistic(region_id)
+                    .unwrap_or_default();
+                RegionStat {
+                    region_id: region_id.as_u64(),
+                    engine,
+                    approximate_bytes: stat.approximate_bytes() as i64,
+                    approximate_rows: stat.num_rows as i64,
+                    ..Default::default()
+                }
             })
             .collect::<Vec<_>>()
     }
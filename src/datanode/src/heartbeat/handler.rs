@@ -63,9 +63,9 @@ impl RegionHeartbeatResponseHandler {
                 let close_region_req = RegionRequest::Close(RegionCloseRequest {});
                 Ok((region_id, close_region_req))
             }
-            Instruction::InvalidateTableIdCache(_) | Instruction::InvalidateTableNameCache(_) => {
-                InvalidHeartbeatResponseSnafu.fail()
-            }
+            Instruction::InvalidateTableIdCache(_)
+            | Instruction::InvalidateTableNameCache(_)
+            | Instruction::InvalidateSchemaCache { .. } => InvalidHeartbeatResponseSnafu.fail(),
         }
     }
 
@@ -83,7 +83,9 @@ impl RegionHeartbeatResponseHandler {
                 result: false,
                 error: None,
             }),
-            Instruction::InvalidateTableIdCache(_) | Instruction::InvalidateTableNameCache(_) => {
+            Instruction::InvalidateTableIdCache(_)
+            | Instruction::InvalidateTableNameCache(_)
+            | Instruction::InvalidateSchemaCache { .. } => {
                 InstructionReply::InvalidateTableCache(SimpleReply {
                     result: false,
                     error: None,
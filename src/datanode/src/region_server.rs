@@ -47,7 +47,7 @@ use servers::grpc::region_server::RegionServerHandler;
 use session::context::QueryContext;
 use snafu::{OptionExt, ResultExt};
 use store_api::metadata::RegionMetadataRef;
-use store_api::region_engine::RegionEngineRef;
+use store_api::region_engine::{RegionEngineRef, RegionStatistic};
 use store_api::region_request::{RegionCloseRequest, RegionRequest};
 use store_api::storage::{RegionId, ScanRequest};
 use substrait::{DFLogicalSubstraitConvertor, SubstraitPlan};
@@ -105,6 +105,14 @@ impl RegionServer {
             .collect()
     }
 
+    /// Returns the statistic of the region if it's opened in this server.
+    pub fn region_statistic(&self, region_id: RegionId) -> Option<RegionStatistic> {
+        self.inner
+            .region_map
+            .get(&region_id)
+            .and_then(|engine| engine.region_statistic(region_id))
+    }
+
     pub fn set_writable(&self, region_id: RegionId, writable: bool) -> Result<()> {
         let engine = self
             .inner
@@ -19,6 +19,7 @@ use arrow::compute::cast as arrow_array_cast;
 use arrow::datatypes::{
     DataType as ArrowDataType, IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit,
 };
+use common_decimal::decimal128::{DECIMAL128_DEFAULT_SCALE, DECIMAL128_MAX_PRECISION};
 use common_time::interval::IntervalUnit;
 use common_time::timestamp::TimeUnit;
 use paste::paste;
@@ -27,13 +28,13 @@ use serde::{Deserialize, Serialize};
 use crate::error::{self, Error, Result};
 use crate::type_id::LogicalTypeId;
 use crate::types::{
-    BinaryType, BooleanType, DateTimeType, DateType, DictionaryType, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, DurationType, Float32Type,
-    Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, IntervalDayTimeType,
-    IntervalMonthDayNanoType, IntervalType, IntervalYearMonthType, ListType, NullType, StringType,
-    TimeMillisecondType, TimeType, TimestampMicrosecondType, TimestampMillisecondType,
-    TimestampNanosecondType, TimestampSecondType, TimestampType, UInt16Type, UInt32Type,
-    UInt64Type, UInt8Type,
+    BinaryType, BooleanType, DateTimeType, DateType, Decimal128Type, DictionaryType,
+    DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType, DurationSecondType,
+    DurationType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    IntervalDayTimeType, IntervalMonthDayNanoType, IntervalType, IntervalYearMonthType, JsonType,
+    ListType, NullType, StringType, TimeMillisecondType, TimeType, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, TimestampType,
+    UInt16Type, UInt32Type, UInt64Type, UInt8Type,
 };
 use crate::value::Value;
 use crate::vectors::MutableVector;
@@ -56,10 +57,16 @@ pub enum ConcreteDataType {
     Float32(Float32Type),
     Float64(Float64Type),
 
+    // Decimal type:
+    Decimal128(Decimal128Type),
+
     // String types:
     Binary(BinaryType),
     String(StringType),
 
+    // JSON type:
+    Json(JsonType),
+
     // Date and time types:
     Date(DateType),
     DateTime(DateTimeType),
@@ -92,8 +99,12 @@ impl fmt::Display for ConcreteDataType {
             ConcreteDataType::UInt64(_) => write!(f, "UInt64"),
             ConcreteDataType::Float32(_) => write!(f, "Float32"),
             ConcreteDataType::Float64(_) => write!(f, "Float64"),
+            ConcreteDataType::Decimal128(d) => {
+                write!(f, "Decimal({}, {})", d.precision(), d.scale())
+            }
             ConcreteDataType::Binary(_) => write!(f, "Binary"),
             ConcreteDataType::String(_) => write!(f, "String"),
+            ConcreteDataType::Json(_) => write!(f, "Json"),
             ConcreteDataType::Date(_) => write!(f, "Date"),
             ConcreteDataType::DateTime(_) => write!(f, "DateTime"),
             ConcreteDataType::Timestamp(_) => write!(f, "Timestamp"),
@@ -134,6 +145,8 @@ impl ConcreteDataType {
                 | ConcreteDataType::Time(_)
                 | ConcreteDataType::Interval(_)
                 | ConcreteDataType::Duration(_)
+                | ConcreteDataType::Decimal128(_)
+                | ConcreteDataType::Json(_)
         )
     }
 
@@ -144,6 +157,7 @@ impl ConcreteDataType {
                 | ConcreteDataType::Int16(_)
                 | ConcreteDataType::Int32(_)
                 | ConcreteDataType::Int64(_)
+                | ConcreteDataType::Decimal128(_)
                 | ConcreteDataType::Date(_)
                 | ConcreteDataType::DateTime(_)
                 | ConcreteDataType::Timestamp(_)
@@ -202,6 +216,14 @@ impl ConcreteDataType {
         ConcreteDataType::try_from(dt).expect("Unimplemented type")
     }
 
+    pub fn is_json(&self) -> bool {
+        matches!(self, ConcreteDataType::Json(_))
+    }
+
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, ConcreteDataType::Decimal128(_))
+    }
+
     pub fn is_null(&self) -> bool {
         matches!(self, ConcreteDataType::Null(NullType))
     }
@@ -247,6 +269,14 @@ impl ConcreteDataType {
             _ => None,
         }
     }
+
+    /// Try to cast data type as a [`Decimal128Type`].
+    pub fn as_decimal128(&self) -> Option<Decimal128Type> {
+        match self {
+            ConcreteDataType::Decimal128(d) => Some(*d),
+            _ => None,
+        }
+    }
 }
 
 impl From<&ConcreteDataType> for ConcreteDataType {
@@ -291,6 +321,9 @@ impl TryFrom<&ArrowDataType> for ConcreteDataType {
             ArrowDataType::Duration(u) => {
                 ConcreteDataType::Duration(DurationType::from_unit(u.into()))
             }
+            ArrowDataType::Decimal128(precision, scale) => {
+                ConcreteDataType::decimal128_datatype(*precision, *scale)
+            }
             _ => {
                 return error::UnsupportedArrowTypeSnafu {
                     arrow_type: dt.clone(),
@@ -319,7 +352,7 @@ macro_rules! impl_new_concrete_type_functions {
 
 impl_new_concrete_type_functions!(
     Null, Boolean, UInt8, UInt16, UInt32, UInt64, Int8, Int16, Int32, Int64, Float32, Float64,
-    Binary, Date, DateTime, String
+    Binary, Date, DateTime, String, Json
 );
 
 impl ConcreteDataType {
@@ -447,6 +480,17 @@ impl ConcreteDataType {
         ConcreteDataType::List(ListType::new(item_type))
     }
 
+    /// Creates a [Decimal128(Decimal128Type)] datatype.
+    pub fn decimal128_datatype(precision: u8, scale: i8) -> ConcreteDataType {
+        ConcreteDataType::Decimal128(Decimal128Type::new(precision, scale))
+    }
+
+    /// Creates a [Decimal128(Decimal128Type)] datatype with the max precision and
+    /// default scale.
+    pub fn decimal128_default_datatype() -> ConcreteDataType {
+        Self::decimal128_datatype(DECIMAL128_MAX_PRECISION, DECIMAL128_DEFAULT_SCALE)
+    }
+
     pub fn dictionary_datatype(
         key_type: ConcreteDataType,
         value_type: ConcreteDataType,
@@ -846,5 +890,33 @@ mod tests {
             ConcreteDataType::duration_second_datatype().to_string(),
             "Duration"
         );
+        assert_eq!(
+            ConcreteDataType::from_arrow_type(&ArrowDataType::Decimal128(10, 2)).to_string(),
+            "Decimal(10, 2)"
+        );
+    }
+
+    #[test]
+    fn test_decimal128_datatype() {
+        let decimal = ConcreteDataType::decimal128_datatype(10, 2);
+        assert!(decimal.is_decimal());
+        assert!(decimal.is_stringifiable());
+        assert_eq!(ArrowDataType::Decimal128(10, 2), decimal.as_arrow_type());
+        assert_eq!(Some(Decimal128Type::new(10, 2)), decimal.as_decimal128());
+        assert_eq!(
+            ConcreteDataType::decimal128_datatype(38, 10),
+            ConcreteDataType::decimal128_default_datatype()
+        );
+        assert!(!ConcreteDataType::int64_datatype().is_decimal());
+    }
+
+    #[test]
+    fn test_json_datatype() {
+        let json = ConcreteDataType::json_datatype();
+        assert!(json.is_json());
+        assert!(json.is_stringifiable());
+        assert_eq!("Json", json.to_string());
+        assert_eq!(ArrowDataType::LargeBinary, json.as_arrow_type());
+        assert!(!ConcreteDataType::binary_datatype().is_json());
     }
 }
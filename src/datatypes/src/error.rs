@@ -114,6 +114,19 @@ pub enum Error {
 
     #[snafu(display("Failed to unpack value to given type: {}", reason))]
     TryFromValue { reason: String, location: Location },
+
+    #[snafu(display("Invalid JSON text: {}, error: {}", value, error))]
+    InvalidJson {
+        value: String,
+        error: String,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to convert decimal value"))]
+    Decimal {
+        source: common_decimal::error::Error,
+        location: Location,
+    },
 }
 
 impl ErrorExt for Error {
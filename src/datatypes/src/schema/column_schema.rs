@@ -21,6 +21,7 @@ use snafu::{ensure, ResultExt};
 use crate::data_type::{ConcreteDataType, DataType};
 use crate::error::{self, Error, Result};
 use crate::schema::constraint::ColumnDefaultConstraint;
+use crate::types::JSON_TYPE_NAME;
 use crate::value::Value;
 use crate::vectors::VectorRef;
 
@@ -31,6 +32,9 @@ pub const TIME_INDEX_KEY: &str = "greptime:time_index";
 pub const COMMENT_KEY: &str = "greptime:storage:comment";
 /// Key used to store default constraint in arrow field's metadata.
 const DEFAULT_CONSTRAINT_KEY: &str = "greptime:default_constraint";
+/// Key used to store the logical type in arrow field's metadata, for types that share
+/// the same arrow type with others, e.g. JSON.
+pub const TYPE_KEY: &str = "greptime:type";
 
 /// Schema of a column, used as an immutable struct.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -183,8 +187,11 @@ impl TryFrom<&Field> for ColumnSchema {
     type Error = Error;
 
     fn try_from(field: &Field) -> Result<ColumnSchema> {
-        let data_type = ConcreteDataType::try_from(field.data_type())?;
         let mut metadata = field.metadata().clone();
+        let data_type = match metadata.remove(TYPE_KEY).as_deref() {
+            Some(JSON_TYPE_NAME) => ConcreteDataType::json_datatype(),
+            _ => ConcreteDataType::try_from(field.data_type())?,
+        };
         let default_constraint = match metadata.remove(DEFAULT_CONSTRAINT_KEY) {
             Some(json) => {
                 Some(serde_json::from_str(&json).context(error::DeserializeSnafu { json })?)
@@ -223,6 +230,9 @@ impl TryFrom<&ColumnSchema> for Field {
                 }
             );
         }
+        if column_schema.data_type.is_json() {
+            let _ = metadata.insert(TYPE_KEY.to_string(), JSON_TYPE_NAME.to_string());
+        }
 
         Ok(Field::new(
             &column_schema.name,
@@ -278,6 +288,17 @@ mod tests {
         assert_eq!(column_schema, new_column_schema);
     }
 
+    #[test]
+    fn test_json_column_schema() {
+        let column_schema = ColumnSchema::new("test", ConcreteDataType::json_datatype(), true);
+        let field = Field::try_from(&column_schema).unwrap();
+        assert_eq!(ArrowDataType::LargeBinary, *field.data_type());
+        assert_eq!(JSON_TYPE_NAME, field.metadata().get(TYPE_KEY).unwrap());
+
+        let new_column_schema = ColumnSchema::try_from(&field).unwrap();
+        assert_eq!(column_schema, new_column_schema);
+    }
+
     #[test]
     fn test_column_schema_with_metadata() {
         let metadata = Metadata::from([("k1".to_string(), "v1".to_string())]);
@@ -20,6 +20,7 @@ use snafu::{ensure, ResultExt};
 
 use crate::data_type::{ConcreteDataType, DataType};
 use crate::error::{self, Result};
+use crate::type_id::LogicalTypeId;
 use crate::value::Value;
 use crate::vectors::operations::VectorOp;
 use crate::vectors::{TimestampMillisecondVector, VectorRef};
@@ -91,8 +92,11 @@ impl ColumnDefaultConstraint {
                 if !v.is_null() {
                     // Whether the value could be nullable has been checked before, only need
                     // to check the type compatibility here.
+                    // JSON values are stored as binary.
+                    let value_type_matches = data_type.logical_type_id() == v.logical_type_id()
+                        || (data_type.is_json() && v.logical_type_id() == LogicalTypeId::Binary);
                     ensure!(
-                        data_type.logical_type_id() == v.logical_type_id(),
+                        value_type_matches,
                         error::DefaultValueTypeSnafu {
                             reason: format!(
                                 "column has type {:?} but default value has type {:?}",
@@ -30,10 +30,16 @@ pub enum LogicalTypeId {
     Float32,
     Float64,
 
+    /// A 128-bit decimal with precision and scale.
+    Decimal128,
+
     // String types:
     String,
     Binary,
 
+    /// JSON stored as binary JSON.
+    Json,
+
     // Date & Time types:
     /// Date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
@@ -91,8 +97,10 @@ impl LogicalTypeId {
             LogicalTypeId::UInt64 => ConcreteDataType::uint64_datatype(),
             LogicalTypeId::Float32 => ConcreteDataType::float32_datatype(),
             LogicalTypeId::Float64 => ConcreteDataType::float64_datatype(),
+            LogicalTypeId::Decimal128 => ConcreteDataType::decimal128_default_datatype(),
             LogicalTypeId::String => ConcreteDataType::string_datatype(),
             LogicalTypeId::Binary => ConcreteDataType::binary_datatype(),
+            LogicalTypeId::Json => ConcreteDataType::json_datatype(),
             LogicalTypeId::Date => ConcreteDataType::date_datatype(),
             LogicalTypeId::DateTime => ConcreteDataType::datetime_datatype(),
             LogicalTypeId::TimestampSecond => ConcreteDataType::timestamp_second_datatype(),
@@ -97,8 +97,19 @@ pub fn can_cast_type(src_value: &Value, dest_type: &ConcreteDataType) -> bool {
             | Float32(_) | Float64(_) | String(_),
         ) => true,
 
+        // decimal type cast
+        (
+            UInt8(_) | UInt16(_) | UInt32(_) | UInt64(_) | Int8(_) | Int16(_) | Int32(_) | Int64(_)
+            | Float32(_) | Float64(_) | String(_) | Decimal128(_),
+            Decimal128(_),
+        ) => true,
+        (Decimal128(_), String(_)) => true,
+
         (String(_), Binary(_)) => true,
 
+        // json type cast
+        (String(_) | Binary(_), Json(_)) => true,
+
         // temporal types cast
         // Date type
         (Date(_), Int32(_) | Timestamp(_) | String(_)) => true,
@@ -300,4 +311,38 @@ mod tests {
             string_datatype
         );
     }
+
+    #[test]
+    fn test_cast_to_decimal128() {
+        let cast_option = CastOption { strict: true };
+        let dest_type = ConcreteDataType::decimal128_datatype(5, 2);
+
+        let res = cast_with_opt(Value::Int32(12), &dest_type, &cast_option).unwrap();
+        assert_eq!("12.00", res.to_string());
+        let res = cast_with_opt(Value::Float64(1.005.into()), &dest_type, &cast_option).unwrap();
+        assert_eq!("1.01", res.to_string());
+        let res = cast_with_opt(
+            Value::String(StringBytes::from("-3.5")),
+            &dest_type,
+            &cast_option,
+        )
+        .unwrap();
+        assert_eq!("-3.50", res.to_string());
+
+        // Out of precision.
+        assert!(cast_with_opt(Value::Int32(1000), &dest_type, &cast_option).is_err());
+        let cast_option = CastOption { strict: false };
+        assert_eq!(
+            Value::Null,
+            cast_with_opt(Value::Int32(1000), &dest_type, &cast_option).unwrap()
+        );
+
+        let res = cast_with_opt(
+            Value::Decimal128(common_decimal::Decimal128::new(12345, 5, 2)),
+            &ConcreteDataType::string_datatype(),
+            &cast_option,
+        )
+        .unwrap();
+        assert_eq!(Value::String(StringBytes::from("123.45")), res);
+    }
 }
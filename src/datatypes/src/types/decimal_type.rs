@@ -0,0 +1,109 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use arrow::datatypes::DataType as ArrowDataType;
+use common_decimal::decimal128::{DECIMAL128_DEFAULT_SCALE, DECIMAL128_MAX_PRECISION};
+use common_decimal::Decimal128;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{DataType, ScalarVectorBuilder};
+use crate::type_id::LogicalTypeId;
+use crate::value::Value;
+use crate::vectors::{Decimal128VectorBuilder, MutableVector};
+
+/// Decimal type with precision and scale information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Decimal128Type {
+    precision: u8,
+    scale: i8,
+}
+
+impl Decimal128Type {
+    pub fn new(precision: u8, scale: i8) -> Self {
+        Self { precision, scale }
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// Converts the decimal to the precision and scale of this type, returns `None`
+    /// if the value doesn't fit.
+    fn rescale(&self, decimal: Decimal128) -> Option<Value> {
+        decimal
+            .rescale(self.precision, self.scale)
+            .ok()
+            .map(Value::Decimal128)
+    }
+}
+
+impl Default for Decimal128Type {
+    fn default() -> Self {
+        Self::new(DECIMAL128_MAX_PRECISION, DECIMAL128_DEFAULT_SCALE)
+    }
+}
+
+impl DataType for Decimal128Type {
+    fn name(&self) -> &str {
+        "Decimal128"
+    }
+
+    fn logical_type_id(&self) -> LogicalTypeId {
+        LogicalTypeId::Decimal128
+    }
+
+    fn default_value(&self) -> Value {
+        Value::Decimal128(Decimal128::new(0, self.precision, self.scale))
+    }
+
+    fn as_arrow_type(&self) -> ArrowDataType {
+        ArrowDataType::Decimal128(self.precision, self.scale)
+    }
+
+    fn create_mutable_vector(&self, capacity: usize) -> Box<dyn MutableVector> {
+        Box::new(
+            Decimal128VectorBuilder::with_capacity(capacity)
+                .with_precision_and_scale(self.precision, self.scale),
+        )
+    }
+
+    fn is_timestamp_compatible(&self) -> bool {
+        false
+    }
+
+    fn try_cast(&self, from: Value) -> Option<Value> {
+        let integer = |v: i128| Decimal128::new(v, DECIMAL128_MAX_PRECISION, 0);
+        match from {
+            Value::Decimal128(v) => self.rescale(v),
+            Value::Int8(v) => self.rescale(integer(v as i128)),
+            Value::Int16(v) => self.rescale(integer(v as i128)),
+            Value::Int32(v) => self.rescale(integer(v as i128)),
+            Value::Int64(v) => self.rescale(integer(v as i128)),
+            Value::UInt8(v) => self.rescale(integer(v as i128)),
+            Value::UInt16(v) => self.rescale(integer(v as i128)),
+            Value::UInt32(v) => self.rescale(integer(v as i128)),
+            Value::UInt64(v) => self.rescale(integer(v as i128)),
+            Value::Float32(v) => self.rescale(Decimal128::from_str(&v.to_string()).ok()?),
+            Value::Float64(v) => self.rescale(Decimal128::from_str(&v.to_string()).ok()?),
+            Value::String(v) => self.rescale(Decimal128::from_str(v.as_utf8()).ok()?),
+            _ => None,
+        }
+    }
+}
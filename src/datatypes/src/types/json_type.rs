@@ -0,0 +1,120 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow::datatypes::DataType as ArrowDataType;
+use common_base::bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use crate::data_type::{DataType, DataTypeRef};
+use crate::error::{InvalidJsonSnafu, Result};
+use crate::scalars::ScalarVectorBuilder;
+use crate::type_id::LogicalTypeId;
+use crate::value::Value;
+use crate::vectors::{BinaryVectorBuilder, MutableVector};
+
+/// The name of the JSON type, also used as the value of the type metadata in arrow
+/// fields to tell JSON columns from binary columns.
+pub const JSON_TYPE_NAME: &str = "Json";
+
+/// JSON data type. Values are stored as binary JSON ([jsonb]) in a binary array.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct JsonType;
+
+impl JsonType {
+    pub fn arc() -> DataTypeRef {
+        Arc::new(Self)
+    }
+}
+
+impl DataType for JsonType {
+    fn name(&self) -> &str {
+        JSON_TYPE_NAME
+    }
+
+    fn logical_type_id(&self) -> LogicalTypeId {
+        LogicalTypeId::Json
+    }
+
+    fn default_value(&self) -> Value {
+        Bytes::default().into()
+    }
+
+    fn as_arrow_type(&self) -> ArrowDataType {
+        ArrowDataType::LargeBinary
+    }
+
+    fn create_mutable_vector(&self, capacity: usize) -> Box<dyn MutableVector> {
+        Box::new(BinaryVectorBuilder::with_capacity(capacity))
+    }
+
+    fn is_timestamp_compatible(&self) -> bool {
+        false
+    }
+
+    fn try_cast(&self, from: Value) -> Option<Value> {
+        match from {
+            Value::Binary(v) => Some(Value::Binary(v)),
+            Value::String(v) => parse_string_to_jsonb(v.as_utf8())
+                .ok()
+                .map(|v| Value::Binary(v.into())),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a JSON text into binary JSON.
+pub fn parse_string_to_jsonb(s: &str) -> Result<Vec<u8>> {
+    jsonb::parse_value(s.as_bytes())
+        .map(|v| v.to_vec())
+        .map_err(|e| {
+            InvalidJsonSnafu {
+                value: s,
+                error: e.to_string(),
+            }
+            .build()
+        })
+}
+
+/// Converts binary JSON into its text representation.
+pub fn jsonb_to_string(v: &[u8]) -> String {
+    jsonb::to_string(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonb_round_trip() {
+        let jsonb = parse_string_to_jsonb(r#"{"a": [1, 2], "b": "hello"}"#).unwrap();
+        assert_eq!(r#"{"a":[1,2],"b":"hello"}"#, jsonb_to_string(&jsonb));
+
+        assert!(parse_string_to_jsonb("{a:").is_err());
+    }
+
+    #[test]
+    fn test_json_type_cast() {
+        let json = JsonType;
+        let value = json.try_cast(Value::String("[1, 2]".into())).unwrap();
+        let Value::Binary(b) = value else {
+            unreachable!()
+        };
+        assert_eq!("[1,2]", jsonb_to_string(&b));
+
+        assert!(json.try_cast(Value::String("[1, 2".into())).is_none());
+        assert!(json.try_cast(Value::Int32(1)).is_none());
+    }
+}
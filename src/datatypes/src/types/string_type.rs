@@ -77,6 +77,7 @@ impl DataType for StringType {
             Value::Int64(v) => Some(Value::String(StringBytes::from(v.to_string()))),
             Value::Float32(v) => Some(Value::String(StringBytes::from(v.to_string()))),
             Value::Float64(v) => Some(Value::String(StringBytes::from(v.to_string()))),
+            Value::Decimal128(v) => Some(Value::String(StringBytes::from(v.to_string()))),
             Value::String(v) => Some(Value::String(v)),
             Value::Date(v) => Some(Value::String(StringBytes::from(v.to_string()))),
             Value::DateTime(v) => Some(Value::String(StringBytes::from(v.to_string()))),
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use arrow::datatypes::{DataType as ArrowDataType, Field};
 use common_base::bytes::{Bytes, StringBytes};
+use common_decimal::Decimal128;
 use common_telemetry::logging;
 use common_time::date::Date;
 use common_time::datetime::DateTime;
@@ -61,6 +62,9 @@ pub enum Value {
     Float32(OrderedF32),
     Float64(OrderedF64),
 
+    // Decimal type:
+    Decimal128(Decimal128),
+
     // String types:
     String(StringBytes),
     Binary(Bytes),
@@ -91,6 +95,7 @@ impl Display for Value {
             Value::Int64(v) => write!(f, "{v}"),
             Value::Float32(v) => write!(f, "{v}"),
             Value::Float64(v) => write!(f, "{v}"),
+            Value::Decimal128(v) => write!(f, "{v}"),
             Value::String(v) => write!(f, "{}", v.as_utf8()),
             Value::Binary(v) => {
                 let hex = v
@@ -139,6 +144,7 @@ impl Value {
             Value::Int64(_) => ConcreteDataType::int64_datatype(),
             Value::Float32(_) => ConcreteDataType::float32_datatype(),
             Value::Float64(_) => ConcreteDataType::float64_datatype(),
+            Value::Decimal128(v) => ConcreteDataType::decimal128_datatype(v.precision(), v.scale()),
             Value::String(_) => ConcreteDataType::string_datatype(),
             Value::Binary(_) => ConcreteDataType::binary_datatype(),
             Value::Date(_) => ConcreteDataType::date_datatype(),
@@ -183,6 +189,7 @@ impl Value {
             Value::Int64(v) => ValueRef::Int64(*v),
             Value::Float32(v) => ValueRef::Float32(*v),
             Value::Float64(v) => ValueRef::Float64(*v),
+            Value::Decimal128(v) => ValueRef::Decimal128(*v),
             Value::String(v) => ValueRef::String(v.as_utf8()),
             Value::Binary(v) => ValueRef::Binary(v),
             Value::Date(v) => ValueRef::Date(*v),
@@ -228,6 +235,7 @@ impl Value {
             Value::Int64(_) => LogicalTypeId::Int64,
             Value::Float32(_) => LogicalTypeId::Float32,
             Value::Float64(_) => LogicalTypeId::Float64,
+            Value::Decimal128(_) => LogicalTypeId::Decimal128,
             Value::String(_) => LogicalTypeId::String,
             Value::Binary(_) => LogicalTypeId::Binary,
             Value::List(_) => LogicalTypeId::List,
@@ -285,6 +293,9 @@ impl Value {
             Value::Int64(v) => ScalarValue::Int64(Some(*v)),
             Value::Float32(v) => ScalarValue::Float32(Some(v.0)),
             Value::Float64(v) => ScalarValue::Float64(Some(v.0)),
+            Value::Decimal128(v) => {
+                ScalarValue::Decimal128(Some(v.val()), v.precision(), v.scale())
+            }
             Value::String(v) => ScalarValue::Utf8(Some(v.as_utf8().to_string())),
             Value::Binary(v) => ScalarValue::LargeBinary(Some(v.to_vec())),
             Value::Date(v) => ScalarValue::Date32(Some(v.val())),
@@ -323,7 +334,8 @@ pub fn to_null_scalar_value(output_type: &ConcreteDataType) -> Result<ScalarValu
         ConcreteDataType::UInt64(_) => ScalarValue::UInt64(None),
         ConcreteDataType::Float32(_) => ScalarValue::Float32(None),
         ConcreteDataType::Float64(_) => ScalarValue::Float64(None),
-        ConcreteDataType::Binary(_) => ScalarValue::LargeBinary(None),
+        ConcreteDataType::Decimal128(d) => ScalarValue::Decimal128(None, d.precision(), d.scale()),
+        ConcreteDataType::Binary(_) | ConcreteDataType::Json(_) => ScalarValue::LargeBinary(None),
         ConcreteDataType::String(_) => ScalarValue::Utf8(None),
         ConcreteDataType::Date(_) => ScalarValue::Date32(None),
         ConcreteDataType::DateTime(_) => ScalarValue::Date64(None),
@@ -434,6 +446,7 @@ macro_rules! impl_ord_for_value_like {
                 ($Type::Int64(v1), $Type::Int64(v2)) => v1.cmp(v2),
                 ($Type::Float32(v1), $Type::Float32(v2)) => v1.cmp(v2),
                 ($Type::Float64(v1), $Type::Float64(v2)) => v1.cmp(v2),
+                ($Type::Decimal128(v1), $Type::Decimal128(v2)) => v1.cmp(v2),
                 ($Type::String(v1), $Type::String(v2)) => v1.cmp(v2),
                 ($Type::Binary(v1), $Type::Binary(v2)) => v1.cmp(v2),
                 ($Type::Date(v1), $Type::Date(v2)) => v1.cmp(v2),
@@ -519,6 +532,7 @@ impl_try_from_value!(Time, Time);
 impl_try_from_value!(DateTime, DateTime);
 impl_try_from_value!(Timestamp, Timestamp);
 impl_try_from_value!(Interval, Interval);
+impl_try_from_value!(Decimal128, Decimal128);
 
 macro_rules! impl_value_from {
     ($Variant: ident, $Type: ident) => {
@@ -560,6 +574,7 @@ impl_value_from!(DateTime, DateTime);
 impl_value_from!(Timestamp, Timestamp);
 impl_value_from!(Interval, Interval);
 impl_value_from!(Duration, Duration);
+impl_value_from!(Decimal128, Decimal128);
 impl_value_from!(String, String);
 
 impl From<&str> for Value {
@@ -597,6 +612,7 @@ impl TryFrom<Value> for serde_json::Value {
             Value::Int64(v) => serde_json::Value::from(v),
             Value::Float32(v) => serde_json::Value::from(v.0),
             Value::Float64(v) => serde_json::Value::from(v.0),
+            Value::Decimal128(v) => serde_json::Value::String(v.to_string()),
             Value::String(bytes) => serde_json::Value::String(bytes.as_utf8().to_string()),
             Value::Binary(bytes) => serde_json::to_value(bytes)?,
             Value::Date(v) => serde_json::Value::Number(v.val().into()),
@@ -777,9 +793,10 @@ impl TryFrom<ScalarValue> for Value {
             ScalarValue::DurationNanosecond(d) => d
                 .map(|x| Value::Duration(Duration::new(x, TimeUnit::Nanosecond)))
                 .unwrap_or(Value::Null),
-            ScalarValue::Decimal128(_, _, _)
-            | ScalarValue::Struct(_, _)
-            | ScalarValue::Dictionary(_, _) => {
+            ScalarValue::Decimal128(v, precision, scale) => v
+                .map(|x| Value::Decimal128(Decimal128::new(x, precision, scale)))
+                .unwrap_or(Value::Null),
+            ScalarValue::Struct(_, _) | ScalarValue::Dictionary(_, _) => {
                 return error::UnsupportedArrowTypeSnafu {
                     arrow_type: v.get_datatype(),
                 }
@@ -805,6 +822,7 @@ impl From<ValueRef<'_>> for Value {
             ValueRef::Int64(v) => Value::Int64(v),
             ValueRef::Float32(v) => Value::Float32(v),
             ValueRef::Float64(v) => Value::Float64(v),
+            ValueRef::Decimal128(v) => Value::Decimal128(v),
             ValueRef::String(v) => Value::String(v.into()),
             ValueRef::Binary(v) => Value::Binary(v.into()),
             ValueRef::Date(v) => Value::Date(v),
@@ -836,6 +854,9 @@ pub enum ValueRef<'a> {
     Float32(OrderedF32),
     Float64(OrderedF64),
 
+    // Decimal type:
+    Decimal128(Decimal128),
+
     // String types:
     String(&'a str),
     Binary(&'a [u8]),
@@ -968,6 +989,11 @@ impl<'a> ValueRef<'a> {
         impl_as_for_value_ref!(self, Duration)
     }
 
+    /// Cast itself to [Decimal128].
+    pub fn as_decimal128(&self) -> Result<Option<Decimal128>> {
+        impl_as_for_value_ref!(self, Decimal128)
+    }
+
     /// Cast itself to [Interval].
     pub fn as_interval(&self) -> Result<Option<Interval>> {
         impl_as_for_value_ref!(self, Interval)
@@ -1027,6 +1053,7 @@ impl_value_ref_from!(Timestamp, Timestamp);
 impl_value_ref_from!(Time, Time);
 impl_value_ref_from!(Interval, Interval);
 impl_value_ref_from!(Duration, Duration);
+impl_value_ref_from!(Decimal128, Decimal128);
 
 impl<'a> From<&'a str> for ValueRef<'a> {
     fn from(string: &'a str) -> ValueRef<'a> {
@@ -1378,11 +1405,10 @@ mod tests {
             ScalarValue::DurationNanosecond(None).try_into().unwrap()
         );
 
-        let result: Result<Value> = ScalarValue::Decimal128(Some(1), 0, 0).try_into();
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Unsupported arrow data type, type: Decimal128(0, 0)"));
+        assert_eq!(
+            Value::Decimal128(Decimal128::new(1, 10, 2)),
+            ScalarValue::Decimal128(Some(1), 10, 2).try_into().unwrap()
+        );
     }
 
     #[test]
@@ -2160,4 +2186,38 @@ mod tests {
             duration_to_scalar_value(TimeUnit::Nanosecond, Some(1))
         );
     }
+
+    #[test]
+    fn test_decimal128_value() {
+        let decimal = Decimal128::new(12345, 10, 2);
+        let value = Value::Decimal128(decimal);
+        assert_eq!("123.45", value.to_string());
+        assert_eq!(
+            ConcreteDataType::decimal128_datatype(10, 2),
+            value.data_type()
+        );
+        assert_eq!(LogicalTypeId::Decimal128, value.logical_type_id());
+        assert_eq!(ValueRef::Decimal128(decimal), value.as_value_ref());
+        assert_eq!(Some(decimal), value.as_value_ref().as_decimal128().unwrap());
+        assert_eq!(
+            serde_json::Value::String("123.45".to_string()),
+            serde_json::Value::try_from(value.clone()).unwrap()
+        );
+
+        let scalar = value
+            .try_to_scalar_value(&ConcreteDataType::decimal128_datatype(10, 2))
+            .unwrap();
+        assert_eq!(ScalarValue::Decimal128(Some(12345), 10, 2), scalar);
+        assert_eq!(value, Value::try_from(scalar).unwrap());
+        assert_eq!(
+            Value::Null,
+            ScalarValue::Decimal128(None, 10, 2).try_into().unwrap()
+        );
+        assert_eq!(
+            ScalarValue::Decimal128(None, 10, 2),
+            to_null_scalar_value(&ConcreteDataType::decimal128_datatype(10, 2)).unwrap()
+        );
+
+        assert!(Value::Decimal128(Decimal128::new(-1, 10, 2)) < value);
+    }
 }
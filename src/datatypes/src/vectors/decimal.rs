@@ -0,0 +1,480 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayBuilder, ArrayData, ArrayIter, ArrayRef};
+use arrow::datatypes::DataType as ArrowDataType;
+use arrow_array::{Decimal128Array, Decimal128Builder};
+use common_decimal::decimal128::{DECIMAL128_DEFAULT_SCALE, DECIMAL128_MAX_PRECISION};
+use common_decimal::Decimal128;
+use snafu::{OptionExt, ResultExt};
+
+use crate::data_type::ConcreteDataType;
+use crate::error::{self, Result};
+use crate::scalars::{ScalarVector, ScalarVectorBuilder};
+use crate::serialize::Serializable;
+use crate::value::{Value, ValueRef};
+use crate::vectors::{self, MutableVector, Validity, Vector, VectorRef};
+
+/// Vector of 128-bit decimals, all values share the same precision and scale.
+#[derive(Debug, PartialEq)]
+pub struct Decimal128Vector {
+    array: Decimal128Array,
+}
+
+impl Decimal128Vector {
+    pub fn new(array: Decimal128Array) -> Self {
+        Self { array }
+    }
+
+    /// Creates a vector from unscaled values with the default precision and scale.
+    pub fn from_values<I: IntoIterator<Item = i128>>(iter: I) -> Self {
+        Self::new(Decimal128Array::from_iter_values(iter)).with_default_precision_and_scale()
+    }
+
+    /// Sets the precision and scale of the vector, returns error if they are invalid.
+    pub fn with_precision_and_scale(self, precision: u8, scale: i8) -> Result<Self> {
+        let array = self
+            .array
+            .with_precision_and_scale(precision, scale)
+            .context(error::ArrowComputeSnafu)?;
+        Ok(Self { array })
+    }
+
+    fn with_default_precision_and_scale(self) -> Self {
+        let array = self.array.with_data_type(ArrowDataType::Decimal128(
+            DECIMAL128_MAX_PRECISION,
+            DECIMAL128_DEFAULT_SCALE,
+        ));
+        Self { array }
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.array.precision()
+    }
+
+    pub fn scale(&self) -> i8 {
+        self.array.scale()
+    }
+
+    pub(crate) fn as_arrow(&self) -> &dyn Array {
+        &self.array
+    }
+
+    fn to_array_data(&self) -> ArrayData {
+        self.array.to_data()
+    }
+
+    fn decimal(&self, value: i128) -> Decimal128 {
+        Decimal128::new(value, self.precision(), self.scale())
+    }
+
+    pub fn try_from_arrow_array(array: impl AsRef<dyn Array>) -> Result<Self> {
+        let array = array
+            .as_ref()
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .with_context(|| error::ConversionSnafu {
+                from: format!("{:?}", array.as_ref().data_type()),
+            })?
+            .clone();
+        Ok(Self::new(array))
+    }
+}
+
+impl From<Decimal128Array> for Decimal128Vector {
+    fn from(array: Decimal128Array) -> Self {
+        Self::new(array)
+    }
+}
+
+impl Vector for Decimal128Vector {
+    fn data_type(&self) -> ConcreteDataType {
+        ConcreteDataType::decimal128_datatype(self.precision(), self.scale())
+    }
+
+    fn vector_type_name(&self) -> String {
+        "Decimal128Vector".to_string()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    fn to_arrow_array(&self) -> ArrayRef {
+        Arc::new(self.array.clone())
+    }
+
+    fn to_boxed_arrow_array(&self) -> Box<dyn Array> {
+        Box::new(Decimal128Array::from(self.to_array_data()))
+    }
+
+    fn validity(&self) -> Validity {
+        vectors::impl_validity_for_vector!(self.array)
+    }
+
+    fn memory_size(&self) -> usize {
+        self.array.get_buffer_memory_size()
+    }
+
+    fn null_count(&self) -> usize {
+        self.array.null_count()
+    }
+
+    fn is_null(&self, row: usize) -> bool {
+        self.array.is_null(row)
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> VectorRef {
+        Arc::new(Self::new(self.array.slice(offset, length)))
+    }
+
+    fn get(&self, index: usize) -> Value {
+        match self.get_data(index) {
+            Some(v) => Value::Decimal128(v),
+            None => Value::Null,
+        }
+    }
+
+    fn get_ref(&self, index: usize) -> ValueRef {
+        match self.get_data(index) {
+            Some(v) => ValueRef::Decimal128(v),
+            None => ValueRef::Null,
+        }
+    }
+}
+
+/// Iterator over the [Decimal128] values of a [Decimal128Vector].
+pub struct Decimal128Iter<'a> {
+    precision: u8,
+    scale: i8,
+    iter: ArrayIter<&'a Decimal128Array>,
+}
+
+impl<'a> Iterator for Decimal128Iter<'a> {
+    type Item = Option<Decimal128>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|v| v.map(|v| Decimal128::new(v, self.precision, self.scale)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl ScalarVector for Decimal128Vector {
+    type OwnedItem = Decimal128;
+    type RefItem<'a> = Decimal128;
+    type Iter<'a> = Decimal128Iter<'a>;
+    type Builder = Decimal128VectorBuilder;
+
+    fn get_data(&self, idx: usize) -> Option<Self::RefItem<'_>> {
+        if self.array.is_valid(idx) {
+            Some(self.decimal(self.array.value(idx)))
+        } else {
+            None
+        }
+    }
+
+    fn iter_data(&self) -> Self::Iter<'_> {
+        Decimal128Iter {
+            precision: self.precision(),
+            scale: self.scale(),
+            iter: self.array.iter(),
+        }
+    }
+}
+
+pub struct Decimal128VectorBuilder {
+    precision: u8,
+    scale: i8,
+    mutable_array: Decimal128Builder,
+}
+
+impl Decimal128VectorBuilder {
+    /// Sets the precision and scale of the vector to build.
+    ///
+    /// The caller should ensure the precision and scale are valid.
+    pub fn with_precision_and_scale(self, precision: u8, scale: i8) -> Self {
+        let mutable_array = self
+            .mutable_array
+            .with_data_type(ArrowDataType::Decimal128(precision, scale));
+        Self {
+            precision,
+            scale,
+            mutable_array,
+        }
+    }
+}
+
+impl MutableVector for Decimal128VectorBuilder {
+    fn data_type(&self) -> ConcreteDataType {
+        ConcreteDataType::decimal128_datatype(self.precision, self.scale)
+    }
+
+    fn len(&self) -> usize {
+        self.mutable_array.len()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn to_vector(&mut self) -> VectorRef {
+        Arc::new(self.finish())
+    }
+
+    fn try_push_value_ref(&mut self, value: ValueRef) -> Result<()> {
+        match value.as_decimal128()? {
+            Some(v) => {
+                let v = if v.precision() == self.precision && v.scale() == self.scale {
+                    v
+                } else {
+                    v.rescale(self.precision, self.scale)
+                        .context(error::DecimalSnafu)?
+                };
+                self.mutable_array.append_value(v.val());
+            }
+            None => self.mutable_array.append_null(),
+        }
+        Ok(())
+    }
+
+    fn extend_slice_of(&mut self, vector: &dyn Vector, offset: usize, length: usize) -> Result<()> {
+        let sliced_vector = vector.slice(offset, length);
+        let concrete_vector = sliced_vector
+            .as_any()
+            .downcast_ref::<Decimal128Vector>()
+            .with_context(|| error::CastTypeSnafu {
+                msg: format!(
+                    "Failed to cast vector from {} to Decimal128Vector",
+                    vector.vector_type_name(),
+                ),
+            })?;
+        // Goes through `try_push_value_ref()` so values are rescaled if the source
+        // vector has a different precision or scale.
+        for value in concrete_vector.iter_data() {
+            self.try_push_value_ref(value.into())?;
+        }
+        Ok(())
+    }
+
+    fn push_null(&mut self) {
+        self.mutable_array.append_null()
+    }
+}
+
+impl ScalarVectorBuilder for Decimal128VectorBuilder {
+    type VectorType = Decimal128Vector;
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            precision: DECIMAL128_MAX_PRECISION,
+            scale: DECIMAL128_DEFAULT_SCALE,
+            mutable_array: Decimal128Builder::with_capacity(capacity).with_data_type(
+                ArrowDataType::Decimal128(DECIMAL128_MAX_PRECISION, DECIMAL128_DEFAULT_SCALE),
+            ),
+        }
+    }
+
+    /// Pushes the unscaled value of the decimal, the caller should ensure the
+    /// decimal has the same precision and scale as the builder.
+    fn push(&mut self, value: Option<<Self::VectorType as ScalarVector>::RefItem<'_>>) {
+        match value {
+            Some(v) => self.mutable_array.append_value(v.val()),
+            None => self.mutable_array.append_null(),
+        }
+    }
+
+    fn finish(&mut self) -> Self::VectorType {
+        Decimal128Vector::new(self.mutable_array.finish())
+    }
+}
+
+impl Serializable for Decimal128Vector {
+    fn serialize_to_json(&self) -> Result<Vec<serde_json::Value>> {
+        Ok(self
+            .iter_data()
+            .map(|v| match v {
+                None => serde_json::Value::Null,
+                Some(v) => serde_json::Value::String(v.to_string()),
+            })
+            .collect())
+    }
+}
+
+pub(crate) fn replicate_decimal128(
+    vector: &Decimal128Vector,
+    offsets: &[usize],
+) -> Decimal128Vector {
+    assert_eq!(offsets.len(), vector.len());
+
+    if offsets.is_empty() {
+        return Decimal128Vector::new(vector.array.slice(0, 0));
+    }
+
+    let mut builder = Decimal128VectorBuilder::with_capacity(*offsets.last().unwrap())
+        .with_precision_and_scale(vector.precision(), vector.scale());
+
+    let mut previous_offset = 0;
+    for (offset, value) in offsets.iter().zip(vector.array.iter()) {
+        let repeat_times = *offset - previous_offset;
+        match value {
+            Some(data) => {
+                for _ in 0..repeat_times {
+                    builder.mutable_array.append_value(data);
+                }
+            }
+            None => builder.mutable_array.append_nulls(repeat_times),
+        }
+        previous_offset = *offset;
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::DataType as ArrowDataType;
+
+    use super::*;
+    use crate::data_type::DataType;
+    use crate::types::Decimal128Type;
+    use crate::vectors::operations::VectorOp;
+    use crate::vectors::Int32Vector;
+
+    fn new_decimal_vector(values: Vec<Option<i128>>) -> Decimal128Vector {
+        Decimal128Vector::new(Decimal128Array::from(values))
+            .with_precision_and_scale(10, 2)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_decimal128_vector_misc() {
+        let v = new_decimal_vector(vec![Some(12345), None, Some(-1)]);
+
+        assert_eq!(3, v.len());
+        assert_eq!("Decimal128Vector", v.vector_type_name());
+        assert_eq!(ConcreteDataType::decimal128_datatype(10, 2), v.data_type());
+        assert!(!v.is_const());
+        assert_eq!(1, v.null_count());
+        assert!(v.is_null(1));
+
+        assert_eq!(Value::Decimal128(Decimal128::new(12345, 10, 2)), v.get(0));
+        assert_eq!(Value::Null, v.get(1));
+        assert_eq!(
+            ValueRef::Decimal128(Decimal128::new(-1, 10, 2)),
+            v.get_ref(2)
+        );
+
+        let arrow_arr = v.to_arrow_array();
+        assert_eq!(3, arrow_arr.len());
+        assert_eq!(&ArrowDataType::Decimal128(10, 2), arrow_arr.data_type());
+
+        let sliced = v.slice(2, 1);
+        assert_eq!(
+            ConcreteDataType::decimal128_datatype(10, 2),
+            sliced.data_type()
+        );
+        assert_eq!(Value::Decimal128(Decimal128::new(-1, 10, 2)), sliced.get(0));
+    }
+
+    #[test]
+    fn test_decimal128_vector_iter() {
+        let v = new_decimal_vector(vec![Some(1), None, Some(2)]);
+        let values: Vec<_> = v.iter_data().collect();
+        assert_eq!(
+            vec![
+                Some(Decimal128::new(1, 10, 2)),
+                None,
+                Some(Decimal128::new(2, 10, 2))
+            ],
+            values
+        );
+    }
+
+    #[test]
+    fn test_serialize_decimal128_vector_to_json() {
+        let v = new_decimal_vector(vec![Some(12345), None, Some(-5)]);
+        let json_value = v.serialize_to_json().unwrap();
+        assert_eq!(
+            "[\"123.45\",null,\"-0.05\"]",
+            serde_json::to_string(&json_value).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decimal128_vector_builder() {
+        let input = new_decimal_vector(vec![Some(1), Some(2), Some(3)]);
+
+        let mut builder = Decimal128Type::new(10, 2).create_mutable_vector(3);
+        builder.push_value_ref(ValueRef::Decimal128(Decimal128::new(100, 10, 2)));
+        // Values with different scale are rescaled.
+        builder.push_value_ref(ValueRef::Decimal128(Decimal128::new(5, 5, 1)));
+        assert!(builder.try_push_value_ref(ValueRef::Int32(123)).is_err());
+        assert!(builder
+            .try_push_value_ref(ValueRef::Decimal128(
+                Decimal128::new(1, 38, 0).rescale(38, 20).unwrap()
+            ))
+            .is_ok());
+        builder.extend_slice_of(&input, 1, 2).unwrap();
+        assert!(builder
+            .extend_slice_of(&Int32Vector::from_slice([13]), 0, 1)
+            .is_err());
+        let vector = builder.to_vector();
+
+        let expect: VectorRef = Arc::new(new_decimal_vector(vec![
+            Some(100),
+            Some(50),
+            Some(100),
+            Some(2),
+            Some(3),
+        ]));
+        assert_eq!(expect, vector);
+    }
+
+    #[test]
+    fn test_decimal128_vector_invalid_precision() {
+        assert!(Decimal128Vector::from_values([1])
+            .with_precision_and_scale(39, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_replicate_decimal128() {
+        let v = new_decimal_vector(vec![Some(1), None, Some(3)]);
+        let replicated = v.replicate(&[1, 3, 6]);
+        let expect: VectorRef = Arc::new(new_decimal_vector(vec![
+            Some(1),
+            None,
+            None,
+            Some(3),
+            Some(3),
+            Some(3),
+        ]));
+        assert_eq!(expect, replicated);
+    }
+}
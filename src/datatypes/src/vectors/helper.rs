@@ -17,7 +17,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use arrow::array::{Array, ArrayRef, StringArray};
+use arrow::array::{Array, ArrayRef, Decimal128Array, StringArray};
 use arrow::compute;
 use arrow::compute::kernels::comparison;
 use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
@@ -31,12 +31,13 @@ use crate::error::{self, Result};
 use crate::scalars::{Scalar, ScalarVectorBuilder};
 use crate::value::{ListValue, ListValueRef};
 use crate::vectors::{
-    BinaryVector, BooleanVector, ConstantVector, DateTimeVector, DateVector, Float32Vector,
-    Float64Vector, Int16Vector, Int32Vector, Int64Vector, Int8Vector, IntervalMonthDayNanoVector,
-    ListVector, ListVectorBuilder, MutableVector, NullVector, StringVector, TimeMicrosecondVector,
-    TimeMillisecondVector, TimeNanosecondVector, TimeSecondVector, TimestampMicrosecondVector,
-    TimestampMillisecondVector, TimestampNanosecondVector, TimestampSecondVector, UInt16Vector,
-    UInt32Vector, UInt64Vector, UInt8Vector, Vector, VectorRef,
+    BinaryVector, BooleanVector, ConstantVector, DateTimeVector, DateVector, Decimal128Vector,
+    Float32Vector, Float64Vector, Int16Vector, Int32Vector, Int64Vector, Int8Vector,
+    IntervalMonthDayNanoVector, ListVector, ListVectorBuilder, MutableVector, NullVector,
+    StringVector, TimeMicrosecondVector, TimeMillisecondVector, TimeNanosecondVector,
+    TimeSecondVector, TimestampMicrosecondVector, TimestampMillisecondVector,
+    TimestampNanosecondVector, TimestampSecondVector, UInt16Vector, UInt32Vector, UInt64Vector,
+    UInt8Vector, Vector, VectorRef,
 };
 
 /// Helper functions for `Vector`.
@@ -218,8 +219,12 @@ impl Helper {
             ScalarValue::IntervalMonthDayNano(v) => {
                 ConstantVector::new(Arc::new(IntervalMonthDayNanoVector::from(vec![v])), length)
             }
-            ScalarValue::Decimal128(_, _, _)
-            | ScalarValue::DurationSecond(_)
+            ScalarValue::Decimal128(v, precision, scale) => {
+                let vector = Decimal128Vector::new(Decimal128Array::from(vec![v]))
+                    .with_precision_and_scale(precision, scale)?;
+                ConstantVector::new(Arc::new(vector), length)
+            }
+            ScalarValue::DurationSecond(_)
             | ScalarValue::DurationMillisecond(_)
             | ScalarValue::DurationMicrosecond(_)
             | ScalarValue::DurationNanosecond(_)
@@ -317,6 +322,9 @@ impl Helper {
                     IntervalMonthDayNanoVector::try_from_arrow_interval_array(array)?,
                 ),
             },
+            ArrowDataType::Decimal128(_, _) => {
+                Arc::new(Decimal128Vector::try_from_arrow_array(array)?)
+            }
             ArrowDataType::Float16
             | ArrowDataType::Duration(_)
             | ArrowDataType::LargeList(_)
@@ -324,7 +332,6 @@ impl Helper {
             | ArrowDataType::Struct(_)
             | ArrowDataType::Union(_, _)
             | ArrowDataType::Dictionary(_, _)
-            | ArrowDataType::Decimal128(_, _)
             | ArrowDataType::Decimal256(_, _)
             | ArrowDataType::Map(_, _)
             | ArrowDataType::RunEndEncoded(_, _) => {
@@ -533,4 +540,31 @@ mod tests {
             assert_eq!(Value::Interval(Interval::from_i128(2000)), vector.get(i));
         }
     }
+
+    #[test]
+    fn test_try_into_decimal128_vector() {
+        let array: ArrayRef = Arc::new(
+            Decimal128Array::from(vec![Some(12345), None])
+                .with_precision_and_scale(10, 2)
+                .unwrap(),
+        );
+        let vector = Helper::try_into_vector(array).unwrap();
+        assert_eq!(
+            ConcreteDataType::decimal128_datatype(10, 2),
+            vector.data_type()
+        );
+        assert_eq!(
+            Value::Decimal128(common_decimal::Decimal128::new(12345, 10, 2)),
+            vector.get(0)
+        );
+        assert!(vector.is_null(1));
+
+        let vector =
+            Helper::try_from_scalar_value(ScalarValue::Decimal128(Some(1), 10, 2), 3).unwrap();
+        assert_eq!(3, vector.len());
+        assert_eq!(
+            Value::Decimal128(common_decimal::Decimal128::new(1, 10, 2)),
+            vector.get(2)
+        );
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::prelude::*;
+pub(crate) use crate::vectors::decimal::replicate_decimal128;
 pub(crate) use crate::vectors::null::replicate_null;
 pub(crate) use crate::vectors::primitive::replicate_primitive;
 
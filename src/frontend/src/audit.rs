@@ -0,0 +1,791 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audit log of queries and writes.
+//!
+//! [AuditInterceptor] is registered as the SQL, PromQL and gRPC interceptor, and records
+//! the user, client address, protocol, statement, duration, affected rows and status code
+//! of every finished statement or request. Streamed outputs are finished once they are
+//! consumed. Records are written as JSON lines to rotating files, or as rows to a table.
+//! Interceptors registered by other plugins keep working, they are called by
+//! [AuditInterceptor] first.
+//!
+//! Writes of the InfluxDB line protocol, OpenTSDB, Prometheus remote write and OTLP don't
+//! go through interceptors, the frontend records them by [AuditLog::record] directly.
+//!
+//! Records are never dropped. When the table writer falls behind or fails to write,
+//! records are spilled to the audit log files instead of blocking statements, the
+//! number of spilled records is logged and counted in [METRIC_AUDIT_SPILLED_RECORDS].
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use api::v1::greptime_request::Request;
+use api::v1::value::ValueData;
+use api::v1::{
+    ColumnDataType, ColumnSchema, Row, RowInsertRequest, RowInsertRequests, Rows, SemanticType,
+    Value,
+};
+use common_base::Plugins;
+use common_error::ext::ErrorExt;
+use common_error::status_code::StatusCode;
+use common_query::Output;
+use common_runtime::JoinHandle;
+use common_telemetry::{error, info, warn};
+use metrics::counter;
+use operator::insert::InserterRef;
+use operator::statement::StatementExecutor;
+use query::parser::PromQuery;
+use query::plan::LogicalPlan;
+use serde::Serialize;
+use servers::error as server_error;
+use servers::interceptor::{
+    GrpcQueryInterceptor, GrpcQueryInterceptorRef, PromQueryInterceptor, PromQueryInterceptorRef,
+    SqlQueryInterceptor, SqlQueryInterceptorRef,
+};
+use session::context::{QueryContext, QueryContextRef};
+use snafu::ResultExt;
+use sql::statements::copy::{Copy as CopyStatement, CopyTable};
+use sql::statements::statement::Statement;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tracing_appender::non_blocking::{NonBlocking, NonBlockingBuilder, WorkerGuard};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+use crate::error::{CreateAuditLogDirSnafu, Error, Result};
+use crate::metrics::METRIC_AUDIT_SPILLED_RECORDS;
+use crate::service_config::audit::{AuditLogRotation, StatementClass};
+use crate::service_config::AuditOptions;
+
+/// Prefix of the audit log file names.
+const AUDIT_LOG_FILE_PREFIX: &str = "audit.log";
+/// Number of records waiting to be written to the audit table.
+const AUDIT_TABLE_CHANNEL_SIZE: usize = 10000;
+/// Max number of records written to the audit table in one request.
+const AUDIT_TABLE_BATCH_SIZE: usize = 1024;
+
+pub type AuditLogRef = Arc<AuditLog>;
+
+/// An audit record of a finished statement or request.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditRecord {
+    /// Unix timestamp in nanoseconds when the statement finished.
+    pub timestamp: i64,
+    pub user: String,
+    pub client_addr: Option<String>,
+    pub protocol: String,
+    pub db: String,
+    pub class: StatementClass,
+    /// Statement text with secrets redacted, or the description of the gRPC request.
+    pub statement: String,
+    pub elapsed_ms: f64,
+    /// Affected rows of writes, it's `None` for queries.
+    pub affected_rows: Option<usize>,
+    pub status_code: u32,
+}
+
+/// Writes audit records of the configured statement classes to the sink.
+pub struct AuditLog {
+    classes: HashSet<StatementClass>,
+    sink: Sink,
+}
+
+enum Sink {
+    File(Arc<FileWriter>),
+    Table {
+        sender: mpsc::Sender<AuditRecord>,
+        /// Records the table writer can't take are spilled to the files.
+        spill: Arc<FileWriter>,
+        /// Records spilled since the table writer last reported.
+        spilled: Arc<AtomicU64>,
+        handle: Mutex<Option<JoinHandle<()>>>,
+    },
+}
+
+impl AuditLog {
+    /// Creates the audit log writing to rotating files in `opts.dir`.
+    pub fn try_new_file(opts: &AuditOptions) -> Result<Self> {
+        let writer = FileWriter::try_new(opts)?;
+        info!("Writing audit log to directory {}", opts.dir);
+
+        Ok(Self {
+            classes: opts.classes.iter().copied().collect(),
+            sink: Sink::File(Arc::new(writer)),
+        })
+    }
+
+    /// Creates the audit log writing to table `opts.table` in database `opts.db`, the
+    /// table is created on the first write. Records are spilled to the files in
+    /// `opts.dir` if they can't be written to the table.
+    pub fn try_new_table(
+        opts: &AuditOptions,
+        inserter: InserterRef,
+        statement_executor: Arc<StatementExecutor>,
+    ) -> Result<Self> {
+        let spill = Arc::new(FileWriter::try_new(opts)?);
+        let (sender, receiver) = mpsc::channel(AUDIT_TABLE_CHANNEL_SIZE);
+        let spilled = Arc::new(AtomicU64::new(0));
+        let writer = TableWriter {
+            table: opts.table.clone(),
+            spill: spill.clone(),
+            spilled: spilled.clone(),
+            query_ctx: QueryContext::with_db_name(Some(&opts.db)),
+            inserter,
+            statement_executor,
+        };
+        let handle = common_runtime::spawn_bg(writer.run(receiver));
+        info!(
+            "Writing audit log to table {}.{}, spilling to directory {}",
+            opts.db, opts.table, opts.dir
+        );
+
+        Ok(Self {
+            classes: opts.classes.iter().copied().collect(),
+            sink: Sink::Table {
+                sender,
+                spill,
+                spilled,
+                handle: Mutex::new(Some(handle)),
+            },
+        })
+    }
+
+    pub fn is_audited(&self, class: StatementClass) -> bool {
+        self.classes.contains(&class)
+    }
+
+    pub(crate) fn record<E: ErrorExt>(
+        &self,
+        class: StatementClass,
+        statement: String,
+        result: std::result::Result<&Output, &E>,
+        elapsed: Duration,
+        query_ctx: &QueryContext,
+    ) {
+        let (affected_rows, status_code) = match result {
+            Ok(Output::AffectedRows(rows)) => (Some(*rows), StatusCode::Success),
+            Ok(_) => (None, StatusCode::Success),
+            Err(e) => (None, e.status_code()),
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as i64;
+
+        self.write(AuditRecord {
+            timestamp,
+            user: query_ctx
+                .current_user()
+                .map(|user| user.username().to_string())
+                .unwrap_or_default(),
+            client_addr: query_ctx.client_addr().map(|addr| addr.to_string()),
+            protocol: query_ctx.channel().to_string(),
+            db: query_ctx.get_db_string(),
+            class,
+            statement,
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            affected_rows,
+            status_code: status_code as u32,
+        });
+    }
+
+    fn write(&self, record: AuditRecord) {
+        match &self.sink {
+            Sink::File(writer) => writer.write(&record),
+            Sink::Table {
+                sender,
+                spill,
+                spilled,
+                ..
+            } => {
+                match sender.try_send(record) {
+                    Ok(()) => {}
+                    Err(TrySendError::Full(record)) => {
+                        // Statements must not wait for the table writer.
+                        spill.write(&record);
+                        counter!(METRIC_AUDIT_SPILLED_RECORDS, 1);
+                        // The table writer reports the number of spilled records, only
+                        // warns about the first one here.
+                        if spilled.fetch_add(1, Ordering::Relaxed) == 0 {
+                            warn!("Audit table writer falls behind, spilling records to files");
+                        }
+                    }
+                    Err(TrySendError::Closed(record)) => {
+                        spill.write(&record);
+                        counter!(METRIC_AUDIT_SPILLED_RECORDS, 1);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Flushes the audit log files and stops writing to the audit table.
+    pub fn stop(&self) {
+        match &self.sink {
+            Sink::File(writer) => writer.flush(),
+            Sink::Table { spill, handle, .. } => {
+                if let Some(handle) = handle.lock().unwrap().take() {
+                    handle.abort();
+                }
+                spill.flush();
+            }
+        }
+    }
+}
+
+/// Writes audit records as JSON lines to rotating files.
+struct FileWriter {
+    writer: NonBlocking,
+    /// Flushes the pending records on drop.
+    guard: Mutex<Option<WorkerGuard>>,
+}
+
+impl FileWriter {
+    fn try_new(opts: &AuditOptions) -> Result<Self> {
+        std::fs::create_dir_all(&opts.dir).context(CreateAuditLogDirSnafu {
+            dir: opts.dir.clone(),
+        })?;
+        let rotation = match opts.rotation {
+            AuditLogRotation::Minutely => Rotation::MINUTELY,
+            AuditLogRotation::Hourly => Rotation::HOURLY,
+            AuditLogRotation::Daily => Rotation::DAILY,
+            AuditLogRotation::Never => Rotation::NEVER,
+        };
+        let appender = RollingFileAppender::new(rotation, &opts.dir, AUDIT_LOG_FILE_PREFIX);
+        // Audit records must not be dropped when the writer falls behind.
+        let (writer, guard) = NonBlockingBuilder::default().lossy(false).finish(appender);
+
+        Ok(Self {
+            writer,
+            guard: Mutex::new(Some(guard)),
+        })
+    }
+
+    fn write(&self, record: &AuditRecord) {
+        let mut line = match serde_json::to_string(record) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("Failed to serialize audit record: {record:?}, error: {e}");
+                return;
+            }
+        };
+        line.push('\n');
+        if let Err(e) = self.writer.clone().write_all(line.as_bytes()) {
+            error!("Failed to write audit record: {record:?}, error: {e}");
+        }
+    }
+
+    fn flush(&self) {
+        drop(self.guard.lock().unwrap().take());
+    }
+}
+
+/// Writes audit records to the audit table in batches.
+struct TableWriter {
+    table: String,
+    spill: Arc<FileWriter>,
+    spilled: Arc<AtomicU64>,
+    query_ctx: QueryContextRef,
+    inserter: InserterRef,
+    statement_executor: Arc<StatementExecutor>,
+}
+
+impl TableWriter {
+    async fn run(self, mut receiver: mpsc::Receiver<AuditRecord>) {
+        let mut records = Vec::with_capacity(AUDIT_TABLE_BATCH_SIZE);
+        while let Some(record) = receiver.recv().await {
+            records.push(record);
+            while records.len() < AUDIT_TABLE_BATCH_SIZE {
+                match receiver.try_recv() {
+                    Ok(record) => records.push(record),
+                    Err(_) => break,
+                }
+            }
+
+            let requests = to_row_insert_requests(&self.table, records.iter().cloned());
+            if let Err(e) = self
+                .inserter
+                .handle_row_inserts(
+                    requests,
+                    self.query_ctx.clone(),
+                    self.statement_executor.as_ref(),
+                )
+                .await
+            {
+                error!(
+                    e; "Failed to write {} audit records to table {}, spilling them to files",
+                    records.len(),
+                    self.table
+                );
+                for record in &records {
+                    self.spill.write(record);
+                }
+                counter!(METRIC_AUDIT_SPILLED_RECORDS, records.len() as u64);
+            }
+            records.clear();
+
+            let spilled = self.spilled.swap(0, Ordering::Relaxed);
+            if spilled > 0 {
+                warn!(
+                    "Spilled {spilled} audit records to files since the writer of table {} fell behind",
+                    self.table
+                );
+            }
+        }
+    }
+}
+
+fn to_row_insert_requests(
+    table: &str,
+    records: impl Iterator<Item = AuditRecord>,
+) -> RowInsertRequests {
+    fn column(name: &str, datatype: ColumnDataType, semantic_type: SemanticType) -> ColumnSchema {
+        ColumnSchema {
+            column_name: name.to_string(),
+            datatype: datatype as i32,
+            semantic_type: semantic_type as i32,
+        }
+    }
+    fn value(value_data: Option<ValueData>) -> Value {
+        Value { value_data }
+    }
+
+    let schema = vec![
+        column(
+            "ts",
+            ColumnDataType::TimestampNanosecond,
+            SemanticType::Timestamp,
+        ),
+        column("user", ColumnDataType::String, SemanticType::Tag),
+        column("client_addr", ColumnDataType::String, SemanticType::Tag),
+        column("protocol", ColumnDataType::String, SemanticType::Tag),
+        column("db", ColumnDataType::String, SemanticType::Tag),
+        column("class", ColumnDataType::String, SemanticType::Tag),
+        column("statement", ColumnDataType::String, SemanticType::Field),
+        column("elapsed_ms", ColumnDataType::Float64, SemanticType::Field),
+        column("affected_rows", ColumnDataType::Uint64, SemanticType::Field),
+        column("status_code", ColumnDataType::Uint32, SemanticType::Field),
+    ];
+    let rows = records
+        .map(|record| Row {
+            values: vec![
+                value(Some(ValueData::TimestampNanosecondValue(record.timestamp))),
+                value(Some(ValueData::StringValue(record.user))),
+                value(record.client_addr.map(ValueData::StringValue)),
+                value(Some(ValueData::StringValue(record.protocol))),
+                value(Some(ValueData::StringValue(record.db))),
+                value(Some(ValueData::StringValue(
+                    class_name(record.class).to_string(),
+                ))),
+                value(Some(ValueData::StringValue(record.statement))),
+                value(Some(ValueData::F64Value(record.elapsed_ms))),
+                value(
+                    record
+                        .affected_rows
+                        .map(|rows| ValueData::U64Value(rows as u64)),
+                ),
+                value(Some(ValueData::U32Value(record.status_code))),
+            ],
+        })
+        .collect();
+
+    RowInsertRequests {
+        inserts: vec![RowInsertRequest {
+            table_name: table.to_string(),
+            rows: Some(Rows { schema, rows }),
+        }],
+    }
+}
+
+/// Describes the write of `protocol` into `tables` as the statement of the audit record.
+pub(crate) fn describe_write<'a>(protocol: &str, tables: impl Iterator<Item = &'a str>) -> String {
+    format!("{protocol}: {}", tables.collect::<Vec<_>>().join(", "))
+}
+
+fn class_name(class: StatementClass) -> &'static str {
+    match class {
+        StatementClass::Query => "query",
+        StatementClass::Write => "write",
+        StatementClass::Ddl => "ddl",
+    }
+}
+
+/// Returns the class of the SQL statement.
+fn statement_class(statement: &Statement) -> StatementClass {
+    match statement {
+        Statement::Insert(_)
+        | Statement::Delete(_)
+        | Statement::Update(_)
+        | Statement::Copy(CopyStatement::CopyTable(CopyTable::From(_))) => StatementClass::Write,
+        Statement::CreateTable(_)
+        | Statement::CreateExternalTable(_)
+        | Statement::CreateTableAs(_)
+        | Statement::DropTable(_)
+        | Statement::CreateDatabase(_)
+        | Statement::DropDatabase(_)
+        | Statement::AlterDatabase(_)
+        | Statement::CreateFlow(_)
+        | Statement::DropFlow(_)
+        | Statement::CreateView(_)
+        | Statement::DropView(_)
+        | Statement::Alter(_)
+        | Statement::TruncateTable(_)
+        | Statement::Kill(_)
+        | Statement::EvaluateFlow(_) => StatementClass::Ddl,
+        Statement::Query(_)
+        | Statement::ShowDatabases(_)
+        | Statement::ShowTables(_)
+        | Statement::ShowCreateTable(_)
+        | Statement::ShowCreateView(_)
+        | Statement::ShowProcesslist(_)
+        | Statement::DescribeTable(_)
+        | Statement::Explain(_)
+        | Statement::Copy(_)
+        | Statement::Tql(_) => StatementClass::Query,
+    }
+}
+
+/// Returns the class of the gRPC request by its description, or `None` for queries,
+/// which are audited by the SQL and PromQL interceptors.
+fn request_class(request_desc: &str) -> Option<StatementClass> {
+    if request_desc.starts_with("query.") {
+        None
+    } else if request_desc.starts_with("ddl.") {
+        Some(StatementClass::Ddl)
+    } else {
+        Some(StatementClass::Write)
+    }
+}
+
+/// Registers [AuditInterceptor] as the SQL, PromQL and gRPC interceptors, it calls
+/// the interceptors registered before.
+pub fn register_interceptors(plugins: &Plugins, audit_log: AuditLogRef) {
+    let interceptor = Arc::new(AuditInterceptor {
+        audit_log,
+        sql: plugins.get::<SqlQueryInterceptorRef<Error>>(),
+        grpc: plugins.get::<GrpcQueryInterceptorRef<Error>>(),
+        prom: plugins.get::<PromQueryInterceptorRef<server_error::Error>>(),
+    });
+
+    plugins.insert::<SqlQueryInterceptorRef<Error>>(interceptor.clone());
+    plugins.insert::<GrpcQueryInterceptorRef<Error>>(interceptor.clone());
+    plugins.insert::<PromQueryInterceptorRef<server_error::Error>>(interceptor);
+}
+
+pub struct AuditInterceptor {
+    audit_log: AuditLogRef,
+    sql: Option<SqlQueryInterceptorRef<Error>>,
+    grpc: Option<GrpcQueryInterceptorRef<Error>>,
+    prom: Option<PromQueryInterceptorRef<server_error::Error>>,
+}
+
+impl SqlQueryInterceptor for AuditInterceptor {
+    type Error = Error;
+
+    fn pre_parsing<'a>(&self, query: &'a str, query_ctx: QueryContextRef) -> Result<Cow<'a, str>> {
+        self.sql.as_ref().pre_parsing(query, query_ctx)
+    }
+
+    fn post_parsing(
+        &self,
+        statements: Vec<Statement>,
+        query_ctx: QueryContextRef,
+    ) -> Result<Vec<Statement>> {
+        self.sql.as_ref().post_parsing(statements, query_ctx)
+    }
+
+    fn pre_execute(
+        &self,
+        statement: &Statement,
+        plan: Option<&LogicalPlan>,
+        query_ctx: QueryContextRef,
+    ) -> Result<()> {
+        self.sql.as_ref().pre_execute(statement, plan, query_ctx)
+    }
+
+    fn post_execute(&self, output: Output, query_ctx: QueryContextRef) -> Result<Output> {
+        self.sql.as_ref().post_execute(output, query_ctx)
+    }
+
+    fn on_finish(
+        &self,
+        query: &str,
+        statement: Option<&Statement>,
+        result: std::result::Result<&Output, &Error>,
+        elapsed: Duration,
+        query_ctx: QueryContextRef,
+    ) {
+        self.sql
+            .as_ref()
+            .on_finish(query, statement, result, elapsed, query_ctx.clone());
+
+        // Queries failed to parse are audited as queries.
+        let class = statement
+            .map(statement_class)
+            .unwrap_or(StatementClass::Query);
+        if self.audit_log.is_audited(class) {
+            let statement = sql::util::redact_sql_secrets(query);
+            self.audit_log
+                .record(class, statement, result, elapsed, &query_ctx);
+        }
+    }
+}
+
+impl GrpcQueryInterceptor for AuditInterceptor {
+    type Error = Error;
+
+    fn pre_execute(&self, request: &Request, query_ctx: QueryContextRef) -> Result<()> {
+        self.grpc.as_ref().pre_execute(request, query_ctx)
+    }
+
+    fn post_execute(&self, output: Output, query_ctx: QueryContextRef) -> Result<Output> {
+        self.grpc.as_ref().post_execute(output, query_ctx)
+    }
+
+    fn on_finish(
+        &self,
+        request_desc: &str,
+        result: std::result::Result<&Output, &Error>,
+        elapsed: Duration,
+        query_ctx: QueryContextRef,
+    ) {
+        self.grpc
+            .as_ref()
+            .on_finish(request_desc, result, elapsed, query_ctx.clone());
+
+        if let Some(class) = request_class(request_desc) {
+            if self.audit_log.is_audited(class) {
+                self.audit_log
+                    .record(class, request_desc.to_string(), result, elapsed, &query_ctx);
+            }
+        }
+    }
+}
+
+impl PromQueryInterceptor for AuditInterceptor {
+    type Error = server_error::Error;
+
+    fn pre_execute(
+        &self,
+        query: &PromQuery,
+        query_ctx: QueryContextRef,
+    ) -> server_error::Result<()> {
+        self.prom.pre_execute(query, query_ctx)
+    }
+
+    fn post_execute(
+        &self,
+        output: Output,
+        query_ctx: QueryContextRef,
+    ) -> server_error::Result<Output> {
+        self.prom.post_execute(output, query_ctx)
+    }
+
+    fn on_finish(
+        &self,
+        query: &PromQuery,
+        result: std::result::Result<&Output, &server_error::Error>,
+        elapsed: Duration,
+        query_ctx: QueryContextRef,
+    ) {
+        self.prom
+            .on_finish(query, result, elapsed, query_ctx.clone());
+
+        if self.audit_log.is_audited(StatementClass::Query) {
+            self.audit_log.record(
+                StatementClass::Query,
+                query.query.clone(),
+                result,
+                elapsed,
+                &query_ctx,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_test_util::temp_dir::create_temp_dir;
+    use sql::dialect::GreptimeDbDialect;
+    use sql::parser::ParserContext;
+
+    use super::*;
+    use crate::error::NotSupportedSnafu;
+    use crate::service_config::audit::AuditSink;
+
+    fn parse(sql: &str) -> Statement {
+        ParserContext::create_with_dialect(sql, &GreptimeDbDialect {})
+            .unwrap()
+            .remove(0)
+    }
+
+    #[test]
+    fn test_statement_class() {
+        assert_eq!(
+            StatementClass::Query,
+            statement_class(&parse("SELECT * FROM monitor"))
+        );
+        assert_eq!(
+            StatementClass::Write,
+            statement_class(&parse("INSERT INTO monitor VALUES (1, 2)"))
+        );
+        assert_eq!(
+            StatementClass::Write,
+            statement_class(&parse("COPY monitor FROM '/tmp/monitor.parquet'"))
+        );
+        assert_eq!(
+            StatementClass::Query,
+            statement_class(&parse("COPY monitor TO '/tmp/monitor.parquet'"))
+        );
+        assert_eq!(
+            StatementClass::Ddl,
+            statement_class(&parse("DROP TABLE monitor"))
+        );
+
+        assert_eq!(Some(StatementClass::Write), request_class("row_inserts: a"));
+        assert_eq!(
+            Some(StatementClass::Ddl),
+            request_class("ddl.create_table: a")
+        );
+        assert_eq!(None, request_class("query.sql"));
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_file() {
+        let dir = create_temp_dir("test_audit_log_file");
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let opts = AuditOptions {
+            enable: true,
+            dir: dir_path.clone(),
+            rotation: AuditLogRotation::Never,
+            classes: vec![StatementClass::Write],
+            ..Default::default()
+        };
+        let audit_log = Arc::new(AuditLog::try_new_file(&opts).unwrap());
+        let plugins = Plugins::new();
+        register_interceptors(&plugins, audit_log.clone());
+        let interceptor = plugins.get::<SqlQueryInterceptorRef<Error>>();
+        let interceptor = interceptor.as_ref();
+
+        let query_ctx = QueryContext::arc();
+        let insert = parse("INSERT INTO monitor VALUES (1, 2)");
+        interceptor.on_finish(
+            "INSERT INTO monitor VALUES (1, 2)",
+            Some(&insert),
+            Ok(&Output::AffectedRows(1)),
+            Duration::from_millis(3),
+            query_ctx.clone(),
+        );
+        let select = parse("SELECT 1");
+        interceptor.on_finish(
+            "SELECT 1",
+            Some(&select),
+            Ok(&Output::AffectedRows(0)),
+            Duration::from_millis(1),
+            query_ctx.clone(),
+        );
+        let err = NotSupportedSnafu { feat: "test" }.build();
+        interceptor.on_finish(
+            "INSERT INTO t VALUES (1)",
+            Some(&insert),
+            Err(&err),
+            Duration::from_millis(1),
+            query_ctx,
+        );
+        audit_log.stop();
+
+        let content =
+            std::fs::read_to_string(format!("{dir_path}/{AUDIT_LOG_FILE_PREFIX}")).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        // The query is not audited.
+        assert_eq!(2, lines.len());
+
+        let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!("write", record["class"]);
+        assert_eq!("INSERT INTO monitor VALUES (1, 2)", record["statement"]);
+        assert_eq!(1, record["affected_rows"]);
+        assert_eq!(0, record["status_code"]);
+
+        let record: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(StatusCode::Unsupported as u32, record["status_code"]);
+        assert!(record["affected_rows"].is_null());
+    }
+
+    #[derive(serde::Deserialize)]
+    struct AuditLine {
+        statement: String,
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_spill() {
+        let dir = create_temp_dir("test_audit_log_spill");
+        let dir_path = dir.path().to_str().unwrap().to_string();
+        let opts = AuditOptions {
+            enable: true,
+            sink: AuditSink::Table,
+            dir: dir_path.clone(),
+            rotation: AuditLogRotation::Never,
+            classes: vec![StatementClass::Write],
+            ..Default::default()
+        };
+        // The table writer never takes the records, so the channel is full after the
+        // first record.
+        let (sender, mut receiver) = mpsc::channel(1);
+        let spilled = Arc::new(AtomicU64::new(0));
+        let audit_log = AuditLog {
+            classes: opts.classes.iter().copied().collect(),
+            sink: Sink::Table {
+                sender,
+                spill: Arc::new(FileWriter::try_new(&opts).unwrap()),
+                spilled: spilled.clone(),
+                handle: Mutex::new(None),
+            },
+        };
+
+        let query_ctx = QueryContext::arc();
+        for i in 0..3 {
+            audit_log.record::<Error>(
+                StatementClass::Write,
+                format!("INSERT INTO monitor VALUES ({i})"),
+                Ok(&Output::AffectedRows(1)),
+                Duration::from_millis(1),
+                &query_ctx,
+            );
+        }
+        audit_log.stop();
+
+        assert_eq!(
+            "INSERT INTO monitor VALUES (0)",
+            receiver.try_recv().unwrap().statement
+        );
+        assert_eq!(2, spilled.load(Ordering::Relaxed));
+        let content =
+            std::fs::read_to_string(format!("{dir_path}/{AUDIT_LOG_FILE_PREFIX}")).unwrap();
+        let statements = content
+            .lines()
+            .map(|line| serde_json::from_str::<AuditLine>(line).unwrap().statement)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "INSERT INTO monitor VALUES (1)",
+                "INSERT INTO monitor VALUES (2)"
+            ],
+            statements
+        );
+    }
+}
@@ -269,6 +269,100 @@ pub enum Error {
         source: operator::error::Error,
         location: Location,
     },
+
+    #[snafu(display("Failed to read rule file: {}", path))]
+    ReadRuleFile {
+        path: String,
+        source: std::io::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to parse rule file: {}", path))]
+    ParseRuleFile {
+        path: String,
+        source: serde_yaml::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Invalid rule in group {}, reason: {}", group, reason))]
+    InvalidRule {
+        group: String,
+        reason: String,
+        location: Location,
+    },
+
+    #[snafu(display("Invalid result of rule, reason: {}", reason))]
+    InvalidRuleResult { reason: String, location: Location },
+
+    #[snafu(display("Failed to write result of recording rule: {}", record))]
+    WriteRecordingRule {
+        record: String,
+        #[snafu(backtrace)]
+        source: servers::error::Error,
+    },
+
+    #[snafu(display("Failed to send alerts to {}", url))]
+    SendAlerts {
+        url: String,
+        source: reqwest::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Invalid flow {}, reason: {}", flow, reason))]
+    InvalidFlow {
+        flow: String,
+        reason: String,
+        location: Location,
+    },
+
+    #[snafu(display("Flow not found: {}", name))]
+    FlowNotFound { name: String, location: Location },
+
+    #[snafu(display("Flows are not enabled on this frontend"))]
+    FlowDisabled { location: Location },
+
+    #[snafu(display("Invalid result of flow, reason: {}", reason))]
+    InvalidFlowResult { reason: String, location: Location },
+
+    #[snafu(display("Failed to convert type of column {} in flow result", column))]
+    ConvertFlowColumnType {
+        column: String,
+        source: api::error::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to write result of flow {} to table {}", flow, table))]
+    WriteFlowResult {
+        flow: String,
+        table: String,
+        source: operator::error::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to collect recordbatch"))]
+    CollectRecordbatch {
+        source: common_recordbatch::error::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to create audit log directory: {}", dir))]
+    CreateAuditLogDir {
+        dir: String,
+        source: std::io::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to create record batches"))]
+    CreateRecordBatches {
+        source: common_recordbatch::error::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Query {} is killed", id))]
+    QueryCancelled { id: u64, location: Location },
+
+    #[snafu(display("Unknown process id: {}", id))]
+    ProcessNotFound { id: u64, location: Location },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -287,7 +381,10 @@ impl ErrorExt for Error {
             | Error::UnsupportedFormat { .. }
             | Error::EmptyData { .. }
             | Error::ColumnNoneDefaultValue { .. }
-            | Error::IncompleteGrpcRequest { .. } => StatusCode::InvalidArguments,
+            | Error::IncompleteGrpcRequest { .. }
+            | Error::ReadRuleFile { .. }
+            | Error::ParseRuleFile { .. }
+            | Error::InvalidRule { .. } => StatusCode::InvalidArguments,
 
             Error::NotSupported { .. } => StatusCode::Unsupported,
 
@@ -348,6 +445,21 @@ impl ErrorExt for Error {
             Error::StartScriptManager { source } => source.status_code(),
 
             Error::TableOperation { source, .. } => source.status_code(),
+
+            Error::InvalidRuleResult { .. } => StatusCode::Unexpected,
+            Error::WriteRecordingRule { source, .. } => source.status_code(),
+            Error::SendAlerts { .. } => StatusCode::Internal,
+            Error::InvalidFlow { .. } | Error::InvalidFlowResult { .. } => StatusCode::Unexpected,
+            Error::FlowNotFound { .. } => StatusCode::InvalidArguments,
+            Error::FlowDisabled { .. } => StatusCode::Unsupported,
+            Error::ConvertFlowColumnType { source, .. } => source.status_code(),
+            Error::WriteFlowResult { source, .. } => source.status_code(),
+            Error::CollectRecordbatch { source, .. } => source.status_code(),
+            Error::CreateAuditLogDir { .. } | Error::CreateRecordBatches { .. } => {
+                StatusCode::Internal
+            }
+            Error::QueryCancelled { .. } => StatusCode::Cancelled,
+            Error::ProcessNotFound { .. } => StatusCode::InvalidArguments,
         }
     }
 
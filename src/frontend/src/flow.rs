@@ -0,0 +1,594 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Continuous aggregation flows.
+//!
+//! A flow evaluates a range query periodically and writes results of complete time windows
+//! into its sink table. The progress of each flow is kept as a watermark in its metadata:
+//! windows whose align timestamp isn't after the watermark are complete and won't be
+//! evaluated again, so each round only scans rows that contribute to the new windows.
+//!
+//! A window is evaluated once the allowed lateness has passed after its align timestamp.
+//! Progress is tracked per flow rather than per source region: rows arriving after their
+//! windows are evaluated are not reflected in the sink table.
+//!
+//! Every frontend may evaluate flows, so a frontend claims a flow in its metadata before
+//! evaluating it and renews the claim until the evaluation is done; other frontends skip
+//! the flow until the claim is released or expires.
+//!
+//! `EVALUATE FLOW <name>` evaluates a flow immediately instead of waiting for the next round.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use api::helper::{value_to_grpc_value, ColumnDataTypeWrapper};
+use api::v1::{ColumnSchema, Row, RowInsertRequest, RowInsertRequests, Rows, SemanticType};
+use common_meta::key::flow_info::{FlowInfo, FlowInfoKey, FlowInfoValue};
+use common_query::Output;
+use common_recordbatch::RecordBatches;
+use common_runtime::JoinHandle;
+use common_telemetry::{debug, error, info};
+use common_time::timestamp::TimeUnit;
+use common_time::util::current_time_millis;
+use datatypes::value::Value;
+use futures::TryStreamExt;
+use operator::insert::InserterRef;
+use query::parser::QueryLanguageParser;
+use query::plan::LogicalPlan;
+use query::range_select::incremental::{
+    find_range_window, min_time_index_plan, restrict_range_windows, RangeWindow,
+};
+use query::QueryEngineRef;
+use session::context::{QueryContext, QueryContextRef};
+use snafu::{OptionExt, ResultExt};
+
+use crate::error::{
+    CollectRecordbatchSnafu, ConvertFlowColumnTypeSnafu, ExecLogicalPlanSnafu, FlowDisabledSnafu,
+    FlowNotFoundSnafu, InvalidFlowResultSnafu, InvalidFlowSnafu, PlanStatementSnafu, Result,
+    TableMetadataManagerSnafu, WriteFlowResultSnafu,
+};
+use crate::instance::StatementExecutorRef;
+use crate::service_config::FlowOptions;
+
+pub type FlowManagerRef = Arc<FlowManager>;
+
+/// Schedules the evaluation of all flows.
+pub struct FlowManager {
+    opts: FlowOptions,
+    evaluator: Mutex<Option<Arc<FlowEvaluator>>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl FlowManager {
+    pub fn new(opts: &FlowOptions) -> Self {
+        Self {
+            opts: opts.clone(),
+            evaluator: Mutex::new(None),
+            handle: Mutex::new(None),
+        }
+    }
+
+    /// Spawn the task that evaluates all flows every `evaluation_interval`.
+    pub fn start(
+        &self,
+        query_engine: QueryEngineRef,
+        statement_executor: StatementExecutorRef,
+        inserter: InserterRef,
+    ) {
+        let evaluator = Arc::new(FlowEvaluator {
+            query_engine,
+            statement_executor,
+            inserter,
+            allowed_lateness: self.opts.allowed_lateness.as_millis() as i64,
+            claim_ttl: self.opts.evaluation_interval.as_millis() as i64,
+            renew_interval: (self.opts.evaluation_interval / 2).max(Duration::from_millis(1)),
+            max_windows_per_query: self.opts.max_windows_per_query,
+        });
+        *self.evaluator.lock().unwrap() = Some(evaluator.clone());
+        let interval = self.opts.evaluation_interval;
+        let handle = common_runtime::spawn_bg(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                let _ = ticker.tick().await;
+                evaluator.eval(current_time_millis()).await;
+            }
+        });
+        *self.handle.lock().unwrap() = Some(handle);
+    }
+
+    pub fn stop(&self) {
+        if let Some(handle) = self.handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    /// Evaluates the complete windows of the flow now, e.g., for `EVALUATE FLOW`. Does
+    /// nothing if the flow is being evaluated by another frontend.
+    pub async fn evaluate(&self, catalog: &str, schema: &str, flow_name: &str) -> Result<()> {
+        let evaluator = self
+            .evaluator
+            .lock()
+            .unwrap()
+            .clone()
+            .context(FlowDisabledSnafu)?;
+        let key = FlowInfoKey::new(catalog, schema, flow_name);
+        let value = evaluator
+            .statement_executor
+            .table_metadata_manager()
+            .flow_info_manager()
+            .get(key)
+            .await
+            .context(TableMetadataManagerSnafu)?
+            .context(FlowNotFoundSnafu { name: flow_name })?;
+        let flow = FlowInfo {
+            catalog: catalog.to_string(),
+            schema: schema.to_string(),
+            flow_name: flow_name.to_string(),
+            value,
+        };
+
+        evaluator.eval_flow(&flow, current_time_millis()).await
+    }
+}
+
+struct FlowEvaluator {
+    query_engine: QueryEngineRef,
+    statement_executor: StatementExecutorRef,
+    inserter: InserterRef,
+    /// Allowed lateness of rows in milliseconds.
+    allowed_lateness: i64,
+    /// How long a claim of a flow lasts in milliseconds.
+    claim_ttl: i64,
+    /// How often the claim is renewed while the flow is evaluated.
+    renew_interval: Duration,
+    /// The maximum number of windows evaluated by one query.
+    max_windows_per_query: usize,
+}
+
+impl FlowEvaluator {
+    async fn eval(&self, now: i64) {
+        let flows = self
+            .statement_executor
+            .table_metadata_manager()
+            .flow_info_manager()
+            .flows()
+            .await
+            .try_collect::<Vec<_>>()
+            .await;
+        let flows = match flows {
+            Ok(flows) => flows,
+            Err(e) => {
+                error!(e; "Failed to list flows");
+                return;
+            }
+        };
+
+        // Flows are evaluated sequentially, so that a slow flow won't overload the cluster.
+        for flow in flows {
+            if let Err(e) = self.eval_flow(&flow, now).await {
+                let key = FlowInfoKey::new(&flow.catalog, &flow.schema, &flow.flow_name);
+                error!(e; "Failed to evaluate flow {key}");
+            }
+        }
+    }
+
+    async fn eval_flow(&self, flow: &FlowInfo, now: i64) -> Result<()> {
+        let key = FlowInfoKey::new(&flow.catalog, &flow.schema, &flow.flow_name);
+        let query_ctx = QueryContext::with(&flow.catalog, &flow.schema);
+        let stmt = QueryLanguageParser::parse_sql(&flow.value.query).context(PlanStatementSnafu)?;
+        let plan = self
+            .statement_executor
+            .plan(stmt, query_ctx.clone())
+            .await?;
+        let window = find_range_window(&plan).context(InvalidFlowSnafu {
+            flow: key.to_string(),
+            reason: "the query isn't a range query",
+        })?;
+
+        // A window is complete once its align timestamp and the allowed lateness are passed.
+        let align = (window.align.as_millis() as i64).max(1);
+        let watermark = (now - self.allowed_lateness).div_euclid(align) * align;
+        if flow.value.watermark.is_some_and(|w| w >= watermark) {
+            return Ok(());
+        }
+        if flow.value.claimed_until.is_some_and(|until| until > now) {
+            debug!("Flow {key} is being evaluated by another frontend");
+            return Ok(());
+        }
+
+        // Claims the flow, so other frontends won't evaluate the same windows.
+        let flow_info_manager = self
+            .statement_executor
+            .table_metadata_manager()
+            .flow_info_manager();
+        let mut current = FlowInfoValue {
+            claimed_until: Some(now + self.claim_ttl),
+            ..flow.value.clone()
+        };
+        if !flow_info_manager
+            .update(key, &flow.value, &current)
+            .await
+            .context(TableMetadataManagerSnafu)?
+        {
+            debug!("Flow {key} is claimed by another frontend");
+            return Ok(());
+        }
+
+        let result = self
+            .eval_claimed_flow(flow, &plan, &window, query_ctx, watermark, &mut current)
+            .await;
+        if result.is_err() {
+            // Releases the claim, the watermark is kept.
+            let released = FlowInfoValue {
+                claimed_until: None,
+                ..current.clone()
+            };
+            let _ = flow_info_manager
+                .update(key, &current, &released)
+                .await
+                .context(TableMetadataManagerSnafu)?;
+        }
+
+        result
+    }
+
+    /// Evaluates windows of the claimed flow until the `watermark`. `current` is the value
+    /// of the flow in its metadata.
+    ///
+    /// Evaluates at most `max_windows_per_query` windows in each query and advances the
+    /// watermark after each query, so the first evaluation of a flow over a long history
+    /// doesn't scan all rows at once. Windows without rows are skipped.
+    async fn eval_claimed_flow(
+        &self,
+        flow: &FlowInfo,
+        plan: &LogicalPlan,
+        window: &RangeWindow,
+        query_ctx: QueryContextRef,
+        watermark: i64,
+        current: &mut FlowInfoValue,
+    ) -> Result<()> {
+        let key = FlowInfoKey::new(&flow.catalog, &flow.schema, &flow.flow_name);
+        let flow_info_manager = self
+            .statement_executor
+            .table_metadata_manager()
+            .flow_info_manager();
+        let align = (window.align.as_millis() as i64).max(1);
+        let max_windows = self.max_windows_per_query.max(1) as i64;
+        let mut start = match current.watermark {
+            Some(start) => Some(start),
+            None => {
+                self.first_window_start(plan, window, query_ctx.clone(), None)
+                    .await?
+            }
+        };
+        let mut renew_ticker = tokio::time::interval_at(
+            tokio::time::Instant::now() + self.renew_interval,
+            self.renew_interval,
+        );
+        let mut num_rows = 0;
+        loop {
+            let end = match start {
+                Some(start) => watermark.min(start.saturating_add(max_windows * align)),
+                None => watermark,
+            };
+            let write =
+                self.write_windows(flow, plan.clone(), window, query_ctx.clone(), start, end);
+            tokio::pin!(write);
+            let written = loop {
+                tokio::select! {
+                    result = &mut write => break result?,
+                    _ = renew_ticker.tick() => {
+                        // Renews the claim while evaluating, other frontends may take
+                        // over the flow once the claim expires.
+                        let renewed = FlowInfoValue {
+                            claimed_until: Some(current_time_millis() + self.claim_ttl),
+                            ..current.clone()
+                        };
+                        if !flow_info_manager
+                            .update(key, current, &renewed)
+                            .await
+                            .context(TableMetadataManagerSnafu)?
+                        {
+                            info!("Flow {key} is changed during evaluation, stop evaluating it");
+                            return Ok(());
+                        }
+                        *current = renewed;
+                    }
+                }
+            };
+            num_rows += written;
+
+            // No windows in `(start, end]` have rows, skips to the next window that has.
+            let mut end = end;
+            if written == 0 && end < watermark {
+                let max_range = window.max_range.as_millis() as i64;
+                end = match self
+                    .first_window_start(plan, window, query_ctx.clone(), Some(end - max_range))
+                    .await?
+                {
+                    Some(next) => next.clamp(end, watermark),
+                    None => watermark,
+                };
+            }
+
+            // Advances the watermark, and releases the claim after the last query.
+            let done = end >= watermark;
+            let new_value = FlowInfoValue {
+                watermark: Some(end),
+                claimed_until: (!done).then(|| current_time_millis() + self.claim_ttl),
+                ..current.clone()
+            };
+            if !flow_info_manager
+                .update(key, current, &new_value)
+                .await
+                .context(TableMetadataManagerSnafu)?
+            {
+                info!("Flow {key} is changed during evaluation, its watermark is not advanced");
+                return Ok(());
+            }
+            *current = new_value;
+            if done {
+                break;
+            }
+            start = Some(end);
+        }
+        debug!("Flow {key} wrote {num_rows} rows, watermark is advanced to {watermark}");
+
+        Ok(())
+    }
+
+    /// Returns the exclusive start of the first window that has rows after `after`, `None`
+    /// if the source has no such rows.
+    async fn first_window_start(
+        &self,
+        plan: &LogicalPlan,
+        window: &RangeWindow,
+        query_ctx: QueryContextRef,
+        after: Option<i64>,
+    ) -> Result<Option<i64>> {
+        let Some(plan) = min_time_index_plan(plan, after).context(PlanStatementSnafu)? else {
+            return Ok(None);
+        };
+        let batches = self.execute(plan, query_ctx).await?;
+        let min_ts = batches
+            .iter()
+            .filter(|batch| batch.num_rows() > 0)
+            .find_map(|batch| match batch.column(0).get(0) {
+                Value::Timestamp(ts) => ts.convert_to(TimeUnit::Millisecond),
+                _ => None,
+            });
+        let Some(min_ts) = min_ts else {
+            return Ok(None);
+        };
+
+        // A row contributes to windows in `(ts - range, ts]`.
+        let align = (window.align.as_millis() as i64).max(1);
+        let max_range = window.max_range.as_millis() as i64;
+        Ok(Some(
+            (min_ts.value() - max_range).div_euclid(align) * align - align,
+        ))
+    }
+
+    async fn execute(
+        &self,
+        plan: LogicalPlan,
+        query_ctx: QueryContextRef,
+    ) -> Result<RecordBatches> {
+        let output = self
+            .query_engine
+            .execute(plan, query_ctx)
+            .await
+            .context(ExecLogicalPlanSnafu)?;
+        match output {
+            Output::RecordBatches(batches) => Ok(batches),
+            Output::Stream(stream) => RecordBatches::try_collect(stream)
+                .await
+                .context(CollectRecordbatchSnafu),
+            Output::AffectedRows(_) => InvalidFlowResultSnafu {
+                reason: "expected data result, but got affected rows",
+            }
+            .fail(),
+        }
+    }
+
+    /// Evaluates the windows of the flow in `(start, end]` and writes the results into the
+    /// sink table, returns the number of rows written.
+    async fn write_windows(
+        &self,
+        flow: &FlowInfo,
+        plan: LogicalPlan,
+        window: &RangeWindow,
+        query_ctx: QueryContextRef,
+        start: Option<i64>,
+        end: i64,
+    ) -> Result<usize> {
+        let plan = restrict_range_windows(plan, start, end).context(PlanStatementSnafu)?;
+        let batches = self.execute(plan, query_ctx.clone()).await?;
+
+        let request =
+            to_row_insert_request(&flow.value.sink_table_name, window, &batches, start, end)?;
+        let num_rows = request.rows.as_ref().map_or(0, |rows| rows.rows.len());
+        let _ = self
+            .inserter
+            .handle_row_inserts(
+                RowInsertRequests {
+                    inserts: vec![request],
+                },
+                query_ctx,
+                self.statement_executor.as_ref(),
+            )
+            .await
+            .context(WriteFlowResultSnafu {
+                flow: FlowInfoKey::new(&flow.catalog, &flow.schema, &flow.flow_name).to_string(),
+                table: &flow.value.sink_table_name,
+            })?;
+
+        Ok(num_rows)
+    }
+}
+
+/// Convert the results of windows in `(start, end]` to the insert request of the sink
+/// table. The time index is the time index of the sink table, `BY` columns are tags and
+/// other columns are fields.
+fn to_row_insert_request(
+    sink_table: &str,
+    window: &RangeWindow,
+    batches: &RecordBatches,
+    start: Option<i64>,
+    end: i64,
+) -> Result<RowInsertRequest> {
+    let schema = batches.schema();
+    let ts_index =
+        schema
+            .column_index_by_name(&window.time_index)
+            .context(InvalidFlowResultSnafu {
+                reason: format!("time index {} is not selected", window.time_index),
+            })?;
+
+    let columns = schema
+        .column_schemas()
+        .iter()
+        .map(|column| {
+            let datatype = ColumnDataTypeWrapper::try_from(column.data_type.clone())
+                .context(ConvertFlowColumnTypeSnafu {
+                    column: &column.name,
+                })?
+                .datatype();
+            let semantic_type = if column.name == window.time_index {
+                SemanticType::Timestamp
+            } else if window.by.contains(&column.name) {
+                SemanticType::Tag
+            } else {
+                SemanticType::Field
+            };
+            Ok(ColumnSchema {
+                column_name: column.name.clone(),
+                datatype: datatype as i32,
+                semantic_type: semantic_type as i32,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut rows = Vec::new();
+    for batch in batches.iter() {
+        let ts_column = batch.column(ts_index);
+        for i in 0..batch.num_rows() {
+            let Value::Timestamp(ts) = ts_column.get(i) else {
+                continue;
+            };
+            let Some(ts) = ts.convert_to(TimeUnit::Millisecond).map(|ts| ts.value()) else {
+                continue;
+            };
+            // Results of windows out of the range are partial.
+            if start.is_some_and(|start| ts <= start) || ts > end {
+                continue;
+            }
+            let values = batch
+                .columns()
+                .iter()
+                .zip(schema.column_schemas())
+                .map(|(column, column_schema)| {
+                    value_to_grpc_value(column.get(i)).context(ConvertFlowColumnTypeSnafu {
+                        column: &column_schema.name,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            rows.push(Row { values });
+        }
+    }
+
+    Ok(RowInsertRequest {
+        table_name: sink_table.to_string(),
+        rows: Some(Rows {
+            schema: columns,
+            rows,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use api::v1::value::ValueData;
+    use common_recordbatch::RecordBatch;
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::{ColumnSchema as DtColumnSchema, Schema};
+    use datatypes::vectors::{Float64Vector, StringVector, TimestampMillisecondVector};
+
+    use super::*;
+
+    #[test]
+    fn test_to_row_insert_request() {
+        let schema = Arc::new(Schema::new(vec![
+            DtColumnSchema::new(
+                "ts",
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                false,
+            ),
+            DtColumnSchema::new("host", ConcreteDataType::string_datatype(), true),
+            DtColumnSchema::new("avg_cpu", ConcreteDataType::float64_datatype(), true),
+        ]));
+        let batch = RecordBatch::new(
+            schema.clone(),
+            vec![
+                Arc::new(TimestampMillisecondVector::from_vec(vec![1000, 2000, 3000])) as _,
+                Arc::new(StringVector::from(vec!["a", "b", "a"])) as _,
+                Arc::new(Float64Vector::from(vec![Some(1.0), None, Some(3.0)])) as _,
+            ],
+        )
+        .unwrap();
+        let batches = RecordBatches::try_new(schema, vec![batch]).unwrap();
+        let window = RangeWindow {
+            time_index: "ts".to_string(),
+            align: Duration::from_secs(1),
+            max_range: Duration::from_secs(1),
+            by: vec!["host".to_string()],
+        };
+
+        let request = to_row_insert_request("sink", &window, &batches, Some(1000), 2000).unwrap();
+        assert_eq!("sink", request.table_name);
+        let rows = request.rows.unwrap();
+        let semantic_types = rows
+            .schema
+            .iter()
+            .map(|column| column.semantic_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                SemanticType::Timestamp as i32,
+                SemanticType::Tag as i32,
+                SemanticType::Field as i32
+            ],
+            semantic_types
+        );
+        // Only the window at 2000 is in (1000, 2000].
+        assert_eq!(1, rows.rows.len());
+        assert_eq!(
+            Some(ValueData::TimestampMillisecondValue(2000)),
+            rows.rows[0].values[0].value_data
+        );
+        assert_eq!(None, rows.rows[0].values[2].value_data);
+
+        let request = to_row_insert_request("sink", &window, &batches, None, 2000).unwrap();
+        assert_eq!(2, request.rows.unwrap().rows.len());
+
+        let window = RangeWindow {
+            time_index: "timestamp".to_string(),
+            ..window
+        };
+        assert!(to_row_insert_request("sink", &window, &batches, None, 2000).is_err());
+    }
+}
@@ -20,8 +20,9 @@ use servers::http::HttpOptions;
 use servers::Mode;
 
 use crate::service_config::{
-    DatanodeOptions, GrpcOptions, InfluxdbOptions, MysqlOptions, OpentsdbOptions, OtlpOptions,
-    PostgresOptions, PromStoreOptions,
+    AuditOptions, DatanodeOptions, FlowOptions, GrpcOptions, InfluxdbOptions, MysqlOptions,
+    OpentsdbOptions, OtlpOptions, PostgresOptions, PromStoreOptions, RulerOptions,
+    SlowQueryOptions,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -38,6 +39,10 @@ pub struct FrontendOptions {
     pub influxdb: InfluxdbOptions,
     pub prom_store: PromStoreOptions,
     pub otlp: OtlpOptions,
+    pub ruler: RulerOptions,
+    pub flow: FlowOptions,
+    pub audit: AuditOptions,
+    pub slow_query: SlowQueryOptions,
     pub meta_client: Option<MetaClientOptions>,
     pub logging: LoggingOptions,
     pub datanode: DatanodeOptions,
@@ -57,6 +62,10 @@ impl Default for FrontendOptions {
             influxdb: InfluxdbOptions::default(),
             prom_store: PromStoreOptions::default(),
             otlp: OtlpOptions::default(),
+            ruler: RulerOptions::default(),
+            flow: FlowOptions::default(),
+            audit: AuditOptions::default(),
+            slow_query: SlowQueryOptions::default(),
             meta_client: None,
             logging: LoggingOptions::default(),
             datanode: DatanodeOptions::default(),
@@ -22,7 +22,6 @@ use common_meta::heartbeat::handler::{
 };
 use common_meta::instruction::{Instruction, InstructionReply, SimpleReply};
 use common_telemetry::error;
-use futures::future::Either;
 
 #[derive(Clone)]
 pub struct InvalidateTableCacheHandler {
@@ -36,6 +35,7 @@ impl HeartbeatResponseHandler for InvalidateTableCacheHandler {
             ctx.incoming_message.as_ref(),
             Some((_, Instruction::InvalidateTableIdCache { .. }))
                 | Some((_, Instruction::InvalidateTableNameCache { .. }))
+                | Some((_, Instruction::InvalidateSchemaCache { .. }))
         )
     }
 
@@ -43,29 +43,32 @@ impl HeartbeatResponseHandler for InvalidateTableCacheHandler {
         let mailbox = ctx.mailbox.clone();
         let cache_invalidator = self.table_metadata_cache_invalidator.clone();
 
-        let (meta, invalidator) = match ctx.incoming_message.take() {
-            Some((meta, Instruction::InvalidateTableIdCache(table_id))) => (
-                meta,
-                Either::Left(async move {
+        let (meta, instruction) = ctx
+            .incoming_message
+            .take()
+            .expect("InvalidateTableCacheHandler: should be guarded by 'is_acceptable'");
+
+        let _handle = common_runtime::spawn_bg(async move {
+            let ctx = Context::default();
+            // Local cache invalidation always succeeds.
+            let _ = match instruction {
+                Instruction::InvalidateTableIdCache(table_id) => {
+                    cache_invalidator.invalidate_table_id(&ctx, table_id).await
+                }
+                Instruction::InvalidateTableNameCache(table_name) => {
                     cache_invalidator
-                        .invalidate_table_id(&Context::default(), table_id)
+                        .invalidate_table_name(&ctx, table_name)
                         .await
-                }),
-            ),
-            Some((meta, Instruction::InvalidateTableNameCache(table_name))) => (
-                meta,
-                Either::Right(async move {
+                }
+                Instruction::InvalidateSchemaCache { catalog, schema } => {
                     cache_invalidator
-                        .invalidate_table_name(&Context::default(), table_name)
+                        .invalidate_schema(&ctx, &catalog, &schema)
                         .await
-                }),
-            ),
-            _ => unreachable!("InvalidateTableCacheHandler: should be guarded by 'is_acceptable'"),
-        };
-
-        let _handle = common_runtime::spawn_bg(async move {
-            // Local cache invalidation always succeeds.
-            let _ = invalidator.await;
+                }
+                _ => unreachable!(
+                    "InvalidateTableCacheHandler: should be guarded by 'is_acceptable'"
+                ),
+            };
 
             if let Err(e) = mailbox
                 .send((
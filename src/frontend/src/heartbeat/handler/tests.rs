@@ -23,6 +23,7 @@ use common_meta::heartbeat::handler::{
 };
 use common_meta::heartbeat::mailbox::{HeartbeatMailbox, MessageMeta};
 use common_meta::instruction::{Instruction, InstructionReply, SimpleReply};
+use common_meta::key::schema_name::SchemaNameKey;
 use common_meta::key::table_info::TableInfoKey;
 use common_meta::key::TableMetaKey;
 use partition::manager::TableRouteCacheInvalidator;
@@ -99,6 +100,43 @@ async fn test_invalidate_table_cache_handler() {
     );
 }
 
+#[tokio::test]
+async fn test_invalidate_schema_cache_handler() {
+    let schema_key = SchemaNameKey::new("greptime", "my_schema");
+    let inner = HashMap::from([(schema_key.as_raw_key(), 1)]);
+    let backend = Arc::new(MockKvCacheInvalidator {
+        inner: Mutex::new(inner),
+    });
+
+    let executor = Arc::new(HandlerGroupExecutor::new(vec![Arc::new(
+        InvalidateTableCacheHandler::new(backend.clone()),
+    )]));
+
+    let (tx, mut rx) = mpsc::channel(8);
+    let mailbox = Arc::new(HeartbeatMailbox::new(tx));
+
+    handle_instruction(
+        executor,
+        mailbox,
+        Instruction::InvalidateSchemaCache {
+            catalog: "greptime".to_string(),
+            schema: "my_schema".to_string(),
+        },
+    )
+    .await;
+
+    let (_, reply) = rx.recv().await.unwrap();
+    assert_matches!(
+        reply,
+        InstructionReply::InvalidateTableCache(SimpleReply { result: true, .. })
+    );
+    assert!(!backend
+        .inner
+        .lock()
+        .unwrap()
+        .contains_key(&schema_key.as_raw_key()));
+}
+
 pub fn test_message_meta(id: u64, subject: &str, to: &str, from: &str) -> MessageMeta {
     MessageMeta {
         id,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod flight_sql;
 mod grpc;
 mod influxdb;
 mod opentsdb;
@@ -21,18 +22,22 @@ mod region_query;
 mod script;
 mod standalone;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use api::prom_store::remote::Query;
 use api::v1::meta::Role;
 use async_trait::async_trait;
 use auth::{PermissionChecker, PermissionCheckerRef, PermissionReq};
-use catalog::kvbackend::{CachedMetaKvBackend, KvBackendCatalogManager};
+use catalog::information_schema::SLOW_QUERY_OWNER;
+use catalog::kvbackend::{CachedMetaKvBackend, KvBackendCatalogManager, MetaRegionStatsProvider};
 use catalog::CatalogManagerRef;
 use client::client_manager::DatanodeClients;
 use common_base::Plugins;
 use common_config::KvStoreConfig;
-use common_error::ext::BoxedError;
+use common_error::ext::{BoxedError, ErrorExt};
+use common_error::status_code::StatusCode;
 use common_grpc::channel_manager::{ChannelConfig, ChannelManager};
 use common_meta::cache_invalidator::DummyCacheInvalidator;
 use common_meta::ddl_manager::DdlManager;
@@ -70,31 +75,39 @@ use servers::prometheus_handler::PrometheusHandler;
 use servers::query_handler::grpc::GrpcQueryHandler;
 use servers::query_handler::sql::SqlQueryHandler;
 use servers::query_handler::{
-    InfluxdbLineProtocolHandler, OpenTelemetryProtocolHandler, OpentsdbProtocolHandler,
-    PromStoreProtocolHandler, ScriptHandler,
+    FlightSqlIngestHandler, InfluxdbLineProtocolHandler, OpenTelemetryProtocolHandler,
+    OpentsdbProtocolHandler, PromStoreProtocolHandler, ScriptHandler,
 };
 use session::context::QueryContextRef;
 use snafu::prelude::*;
+use snafu::IntoError;
 use sql::dialect::Dialect;
 use sql::parser::ParserContext;
 use sql::statements::copy::CopyTable;
 use sql::statements::statement::Statement;
 use sqlparser::ast::ObjectName;
-pub use standalone::StandaloneDatanodeManager;
+pub use standalone::{StandaloneDatanodeManager, StandaloneRegionStatsProvider};
 
 use self::region_query::FrontendRegionQueryHandler;
 use self::standalone::StandaloneTableMetadataCreator;
+use crate::audit::{self, AuditLog, AuditLogRef};
 use crate::error::{
     self, Error, ExecLogicalPlanSnafu, ExecutePromqlSnafu, ExternalSnafu, MissingMetasrvOptsSnafu,
-    ParseSqlSnafu, PermissionSnafu, PlanStatementSnafu, Result, SqlExecInterceptedSnafu,
-    TableOperationSnafu,
+    ParseSqlSnafu, PermissionSnafu, PlanStatementSnafu, ProcessNotFoundSnafu, QueryCancelledSnafu,
+    Result, SqlExecInterceptedSnafu, TableOperationSnafu,
 };
+use crate::flow::{FlowManager, FlowManagerRef};
 use crate::frontend::FrontendOptions;
 use crate::heartbeat::handler::invalidate_table_cache::InvalidateTableCacheHandler;
 use crate::heartbeat::HeartbeatTask;
 use crate::metrics;
+use crate::on_finish::finish_output;
+use crate::process_list::{ProcessList, ProcessListRef};
+use crate::ruler::{RuleManager, RuleManagerRef};
 use crate::script::ScriptExecutor;
 use crate::server::{start_server, ServerHandlers, Services};
+use crate::service_config::audit::{AuditSink, StatementClass};
+use crate::slow_query::{SlowQueryLog, SlowQueryLogRef};
 
 #[async_trait]
 pub trait FrontendInstance:
@@ -104,6 +117,7 @@ pub trait FrontendInstance:
     + InfluxdbLineProtocolHandler
     + PromStoreProtocolHandler
     + OpenTelemetryProtocolHandler
+    + FlightSqlIngestHandler
     + ScriptHandler
     + PrometheusHandler
     + Send
@@ -129,6 +143,10 @@ pub struct Instance {
     heartbeat_task: Option<HeartbeatTask>,
     inserter: InserterRef,
     deleter: DeleterRef,
+    ruler: Option<RuleManagerRef>,
+    flow: Option<FlowManagerRef>,
+    audit_log: Option<AuditLogRef>,
+    process_list: ProcessListRef,
 }
 
 impl Instance {
@@ -155,6 +173,9 @@ impl Instance {
             meta_backend.clone(),
             meta_backend.clone(),
             datanode_clients.clone(),
+            Some(Arc::new(MetaRegionStatsProvider {
+                client: meta_client.clone(),
+            })),
         );
         let partition_manager = Arc::new(PartitionRuleManager::new(meta_backend.clone()));
 
@@ -220,6 +241,10 @@ impl Instance {
             heartbeat_task,
             inserter,
             deleter,
+            ruler: None,
+            flow: None,
+            audit_log: None,
+            process_list: Arc::new(ProcessList::default()),
         })
     }
 
@@ -248,6 +273,7 @@ impl Instance {
             .enable_store()
             .enable_heartbeat()
             .enable_ddl()
+            .enable_cluster()
             .channel_manager(channel_manager)
             .ddl_channel_manager(ddl_channel_manager)
             .build();
@@ -356,6 +382,10 @@ impl Instance {
             heartbeat_task: None,
             inserter,
             deleter,
+            ruler: None,
+            flow: None,
+            audit_log: None,
+            process_list: Arc::new(ProcessList::default()),
         })
     }
 
@@ -366,6 +396,57 @@ impl Instance {
         Ok(())
     }
 
+    /// Load rule groups if the rule engine is enabled. Rules are evaluated after the
+    /// instance is started.
+    pub fn build_ruler(&mut self, opts: &FrontendOptions) -> Result<()> {
+        if opts.ruler.enable {
+            self.ruler = Some(Arc::new(RuleManager::try_new(&opts.ruler)?));
+        }
+        Ok(())
+    }
+
+    /// Create the flow manager if flows are enabled. Flows are evaluated after the
+    /// instance is started.
+    pub fn build_flow(&mut self, opts: &FrontendOptions) {
+        if opts.flow.enable {
+            self.flow = Some(Arc::new(FlowManager::new(&opts.flow)));
+        }
+    }
+
+    /// Register the audit log interceptors if the audit log is enabled.
+    pub fn build_audit(&mut self, opts: &FrontendOptions) -> Result<()> {
+        if opts.audit.enable {
+            let audit_log = match opts.audit.sink {
+                AuditSink::File => AuditLog::try_new_file(&opts.audit)?,
+                AuditSink::Table => AuditLog::try_new_table(
+                    &opts.audit,
+                    self.inserter.clone(),
+                    self.statement_executor.clone(),
+                )?,
+            };
+            let audit_log = Arc::new(audit_log);
+            audit::register_interceptors(&self.plugins, audit_log.clone());
+            self.audit_log = Some(audit_log);
+        }
+        Ok(())
+    }
+
+    /// Register the slow query log if it's enabled, and serve its records in
+    /// `information_schema.slow_queries`.
+    pub fn build_slow_query_log(&self, opts: &FrontendOptions) {
+        if opts.slow_query.enable {
+            let slow_query_log = Arc::new(SlowQueryLog::new(&opts.slow_query));
+            if let Some(catalog_manager) = self
+                .catalog_manager
+                .as_any()
+                .downcast_ref::<KvBackendCatalogManager>()
+            {
+                catalog_manager.set_slow_query_provider(slow_query_log.clone());
+            }
+            self.plugins.insert::<SlowQueryLogRef>(slow_query_log);
+        }
+    }
+
     pub fn catalog_manager(&self) -> &CatalogManagerRef {
         &self.catalog_manager
     }
@@ -375,6 +456,16 @@ impl Instance {
     }
 
     pub async fn shutdown(&self) -> Result<()> {
+        if let Some(ruler) = &self.ruler {
+            ruler.stop();
+        }
+        if let Some(flow) = &self.flow {
+            flow.stop();
+        }
+        if let Some(audit_log) = &self.audit_log {
+            audit_log.stop();
+        }
+
         futures::future::try_join_all(self.servers.values().map(|server| server.0.shutdown()))
             .await
             .context(error::ShutdownServerSnafu)
@@ -395,6 +486,19 @@ impl FrontendInstance for Instance {
 
         self.script_executor.start(self)?;
 
+        if let Some(ruler) = &self.ruler {
+            let handler = Arc::new(self.clone());
+            ruler.start(handler.clone(), handler);
+        }
+
+        if let Some(flow) = &self.flow {
+            flow.start(
+                self.query_engine.clone(),
+                self.statement_executor.clone(),
+                self.inserter.clone(),
+            );
+        }
+
         futures::future::try_join_all(self.servers.values().map(start_server))
             .await
             .context(error::StartServerSnafu)
@@ -410,12 +514,151 @@ impl Instance {
     async fn query_statement(&self, stmt: Statement, query_ctx: QueryContextRef) -> Result<Output> {
         check_permission(self.plugins.clone(), &stmt, &query_ctx)?;
 
+        // The process list and the flow evaluator belong to this frontend, so these
+        // statements are not executed by the statement executor.
+        match stmt {
+            Statement::ShowProcesslist(show) => {
+                let owner = self.process_owner(&query_ctx)?;
+                return self
+                    .process_list
+                    .show_processlist(&show, owner.as_deref())
+                    .context(error::CreateRecordBatchesSnafu);
+            }
+            Statement::Kill(kill) => {
+                let owner = self.process_owner(&query_ctx)?;
+                // Queries of other users are not found by users without the privilege.
+                ensure!(
+                    self.process_list.kill(kill.id(), owner.as_deref()),
+                    ProcessNotFoundSnafu { id: kill.id() }
+                );
+                return Ok(Output::AffectedRows(0));
+            }
+            Statement::EvaluateFlow(evaluate) => {
+                let flow = self.flow.as_ref().context(error::FlowDisabledSnafu)?;
+                let (catalog, schema, flow_name) =
+                    table_idents_to_full_name(evaluate.flow_name(), query_ctx)
+                        .map_err(BoxedError::new)
+                        .context(ExternalSnafu)?;
+                flow.evaluate(&catalog, &schema, &flow_name).await?;
+                return Ok(Output::AffectedRows(0));
+            }
+            _ => {}
+        }
+
+        // Users without the privilege only read their own slow queries.
+        let owner = self.process_owner(&query_ctx)?;
         let stmt = QueryStatement::Sql(stmt);
-        self.statement_executor
-            .execute_stmt(stmt, query_ctx)
+        SLOW_QUERY_OWNER
+            .scope(owner, self.statement_executor.execute_stmt(stmt, query_ctx))
             .await
             .context(TableOperationSnafu)
     }
+
+    /// Returns the user whose queries are listed and killed by the current user, or
+    /// `None` if the current user manages the queries of all users.
+    fn process_owner(&self, query_ctx: &QueryContextRef) -> Result<Option<String>> {
+        match self
+            .plugins
+            .get::<PermissionCheckerRef>()
+            .as_ref()
+            .check_permission(query_ctx.current_user(), PermissionReq::ProcessAdmin)
+        {
+            Ok(_) => Ok(None),
+            Err(e) if e.status_code() == StatusCode::PermissionDenied => Ok(Some(
+                query_ctx
+                    .current_user()
+                    .map(|user| user.username().to_string())
+                    .unwrap_or_default(),
+            )),
+            Err(e) => Err(e).context(PermissionSnafu),
+        }
+    }
+
+    async fn execute_statement(
+        &self,
+        stmt: Statement,
+        query: &str,
+        query_interceptor: Option<&SqlQueryInterceptorRef<Error>>,
+        checker: Option<&PermissionCheckerRef>,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        // TODO(sunng87): figure out at which stage we can call
+        // this hook after ArrowFlight adoption. We need to provide
+        // LogicalPlan as to this hook.
+        query_interceptor.pre_execute(&stmt, None, query_ctx.clone())?;
+
+        checker
+            .check_permission(query_ctx.current_user(), PermissionReq::SqlStatement(&stmt))
+            .context(PermissionSnafu)?;
+
+        self.query_statement(stmt, query_ctx).await.map_err(|e| {
+            let redacted = sql::util::redact_sql_secrets(query);
+            error!(e; "Failed to execute query: {redacted}");
+            e
+        })
+    }
+
+    /// Executes a query with `fut`, listing it in the process list and timing it in the
+    /// slow query log. `killed` builds the error returned if the query is killed.
+    async fn track_query<F, E>(
+        &self,
+        query: String,
+        query_ctx: &QueryContextRef,
+        fut: F,
+        killed: impl FnOnce(u64) -> E,
+    ) -> std::result::Result<Output, E>
+    where
+        F: Future<Output = std::result::Result<Output, E>>,
+    {
+        let process = self.process_list.register(query.clone(), query_ctx.clone());
+        let tracker = self
+            .plugins
+            .get::<SlowQueryLogRef>()
+            .map(|log| log.start(query, query_ctx.clone()));
+
+        let id = process.id();
+        let result = match process.run(fut).await {
+            Some(result) => result,
+            None => Err(killed(id)),
+        };
+        let result = process.finish(result);
+        match tracker {
+            Some(tracker) => tracker.finish(result),
+            None => result,
+        }
+    }
+
+    /// Returns whether writes are audited.
+    fn is_write_audited(&self) -> bool {
+        self.audit_log
+            .as_ref()
+            .is_some_and(|audit_log| audit_log.is_audited(StatementClass::Write))
+    }
+
+    /// Executes `write` of a protocol that doesn't go through interceptors, and audits
+    /// it as `statement` if it's given.
+    async fn audit_write<F>(
+        &self,
+        statement: Option<String>,
+        query_ctx: &QueryContextRef,
+        write: F,
+    ) -> server_error::Result<Output>
+    where
+        F: Future<Output = server_error::Result<Output>>,
+    {
+        let start = Instant::now();
+        let result = write.await;
+        if let (Some(audit_log), Some(statement)) = (&self.audit_log, statement) {
+            audit_log.record(
+                StatementClass::Write,
+                statement,
+                result.as_ref(),
+                start.elapsed(),
+                query_ctx,
+            );
+        }
+        result
+    }
 }
 
 #[async_trait]
@@ -426,9 +669,19 @@ impl SqlQueryHandler for Instance {
         let _timer = timer!(metrics::METRIC_HANDLE_SQL_ELAPSED);
         let query_interceptor_opt = self.plugins.get::<SqlQueryInterceptorRef<Error>>();
         let query_interceptor = query_interceptor_opt.as_ref();
+        let start = Instant::now();
         let query = match query_interceptor.pre_parsing(query, query_ctx.clone()) {
             Ok(q) => q,
-            Err(e) => return vec![Err(e)],
+            Err(e) => {
+                query_interceptor.on_finish(
+                    query,
+                    None,
+                    Err(&e),
+                    start.elapsed(),
+                    query_ctx.clone(),
+                );
+                return vec![Err(e)];
+            }
         };
 
         let checker_ref = self.plugins.get::<PermissionCheckerRef>();
@@ -440,35 +693,49 @@ impl SqlQueryHandler for Instance {
             Ok(stmts) => {
                 let mut results = Vec::with_capacity(stmts.len());
                 for stmt in stmts {
-                    // TODO(sunng87): figure out at which stage we can call
-                    // this hook after ArrowFlight adoption. We need to provide
-                    // LogicalPlan as to this hook.
-                    if let Err(e) = query_interceptor.pre_execute(&stmt, None, query_ctx.clone()) {
-                        results.push(Err(e));
-                        break;
-                    }
-
-                    if let Err(e) = checker
-                        .check_permission(
-                            query_ctx.current_user(),
-                            PermissionReq::SqlStatement(&stmt),
+                    let start = Instant::now();
+                    // The statement is consumed by execution, keep a copy for `on_finish`
+                    // only if there is an interceptor.
+                    let finished_stmt = query_interceptor.map(|_| stmt.clone());
+                    let result = self
+                        .track_query(
+                            sql::util::redact_sql_secrets(query.as_ref()),
+                            &query_ctx,
+                            self.execute_statement(
+                                stmt,
+                                query.as_ref(),
+                                query_interceptor,
+                                checker,
+                                query_ctx.clone(),
+                            ),
+                            |id| QueryCancelledSnafu { id }.build(),
                         )
-                        .context(PermissionSnafu)
-                    {
-                        results.push(Err(e));
-                        break;
-                    }
+                        .await;
+                    let result = match &query_interceptor_opt {
+                        Some(interceptor) => {
+                            let interceptor = interceptor.clone();
+                            let query = query.to_string();
+                            let query_ctx = query_ctx.clone();
+                            finish_output(result, start, move |result, elapsed| {
+                                interceptor.on_finish(
+                                    &query,
+                                    finished_stmt.as_ref(),
+                                    result,
+                                    elapsed,
+                                    query_ctx,
+                                )
+                            })
+                        }
+                        None => result,
+                    };
 
-                    match self.query_statement(stmt, query_ctx.clone()).await {
+                    match result {
                         Ok(output) => {
                             let output_result =
                                 query_interceptor.post_execute(output, query_ctx.clone());
                             results.push(output_result);
                         }
                         Err(e) => {
-                            let redacted = sql::util::redact_sql_secrets(query.as_ref());
-                            error!(e; "Failed to execute query: {redacted}");
-
                             results.push(Err(e));
                             break;
                         }
@@ -477,6 +744,13 @@ impl SqlQueryHandler for Instance {
                 results
             }
             Err(e) => {
+                query_interceptor.on_finish(
+                    query.as_ref(),
+                    None,
+                    Err(&e),
+                    start.elapsed(),
+                    query_ctx.clone(),
+                );
                 vec![Err(e)]
             }
         }
@@ -486,8 +760,9 @@ impl SqlQueryHandler for Instance {
         let _timer = timer!(metrics::METRIC_EXEC_PLAN_ELAPSED);
         // plan should be prepared before exec
         // we'll do check there
-        self.query_engine
-            .execute(plan, query_ctx)
+        let owner = self.process_owner(&query_ctx)?;
+        SLOW_QUERY_OWNER
+            .scope(owner, self.query_engine.execute(plan, query_ctx))
             .await
             .context(ExecLogicalPlanSnafu)
     }
@@ -513,7 +788,10 @@ impl SqlQueryHandler for Instance {
     ) -> Result<Option<DescribeResult>> {
         if matches!(
             stmt,
-            Statement::Insert(_) | Statement::Query(_) | Statement::Delete(_)
+            Statement::Insert(_)
+                | Statement::Query(_)
+                | Statement::Delete(_)
+                | Statement::Update(_)
         ) {
             self.plugins
                 .get::<PermissionCheckerRef>()
@@ -556,6 +834,55 @@ impl PrometheusHandler for Instance {
         let interceptor = self
             .plugins
             .get::<PromQueryInterceptorRef<server_error::Error>>();
+        let start = Instant::now();
+        let result = self
+            .track_query(
+                query.query.clone(),
+                &query_ctx,
+                self.execute_promql(query, &interceptor, query_ctx.clone()),
+                |id| {
+                    ExecuteQuerySnafu {
+                        query: format!("{query:?}"),
+                    }
+                    .into_error(BoxedError::new(QueryCancelledSnafu { id }.build()))
+                },
+            )
+            .await;
+        let result = match &interceptor {
+            Some(on_finish_interceptor) => {
+                let on_finish_interceptor = on_finish_interceptor.clone();
+                let finished_query = query.clone();
+                let finished_ctx = query_ctx.clone();
+                finish_output(result, start, move |result, elapsed| {
+                    on_finish_interceptor.on_finish(&finished_query, result, elapsed, finished_ctx)
+                })
+            }
+            None => result,
+        };
+
+        Ok(interceptor.post_execute(result?, query_ctx)?)
+    }
+
+    async fn query_exemplars(
+        &self,
+        query: &Query,
+        query_ctx: QueryContextRef,
+    ) -> server_error::Result<Output> {
+        self.handle_exemplars_query(query, query_ctx).await
+    }
+
+    fn catalog_manager(&self) -> CatalogManagerRef {
+        self.catalog_manager.clone()
+    }
+}
+
+impl Instance {
+    async fn execute_promql(
+        &self,
+        query: &PromQuery,
+        interceptor: &Option<PromQueryInterceptorRef<server_error::Error>>,
+        query_ctx: QueryContextRef,
+    ) -> server_error::Result<Output> {
         interceptor.pre_execute(query, query_ctx.clone())?;
 
         self.plugins
@@ -568,20 +895,13 @@ impl PrometheusHandler for Instance {
             query: query.clone(),
         })?;
 
-        let output = self
-            .statement_executor
-            .execute_stmt(stmt, query_ctx.clone())
+        self.statement_executor
+            .execute_stmt(stmt, query_ctx)
             .await
             .map_err(BoxedError::new)
             .with_context(|_| ExecuteQuerySnafu {
                 query: format!("{query:?}"),
-            })?;
-
-        Ok(interceptor.post_execute(output, query_ctx)?)
-    }
-
-    fn catalog_manager(&self) -> CatalogManagerRef {
-        self.catalog_manager.clone()
+            })
     }
 }
 
@@ -601,9 +921,19 @@ pub fn check_permission(
 
     match stmt {
         // These are executed by query engine, and will be checked there.
-        Statement::Query(_) | Statement::Explain(_) | Statement::Tql(_) | Statement::Delete(_) => {}
+        Statement::Query(_)
+        | Statement::Explain(_)
+        | Statement::Tql(_)
+        | Statement::Delete(_)
+        | Statement::Update(_) => {}
         // database ops won't be checked
-        Statement::CreateDatabase(_) | Statement::ShowDatabases(_) => {}
+        Statement::CreateDatabase(_)
+        | Statement::DropDatabase(_)
+        | Statement::AlterDatabase(_)
+        | Statement::ShowDatabases(_) => {}
+        // process ops are not bound to any schema, the processes are checked against
+        // their owners on execution
+        Statement::ShowProcesslist(_) | Statement::Kill(_) => {}
         // show create table and alter are not supported yet
         Statement::ShowCreateTable(_) | Statement::CreateExternalTable(_) | Statement::Alter(_) => {
         }
@@ -614,9 +944,31 @@ pub fn check_permission(
         Statement::CreateTable(stmt) => {
             validate_param(&stmt.name, query_ctx)?;
         }
+        Statement::CreateTableAs(stmt) => {
+            validate_param(&stmt.name, query_ctx)?;
+        }
         Statement::DropTable(drop_stmt) => {
             validate_param(drop_stmt.table_name(), query_ctx)?;
         }
+        Statement::CreateFlow(stmt) => {
+            validate_param(&stmt.flow_name, query_ctx)?;
+            validate_param(&stmt.sink_table_name, query_ctx)?;
+        }
+        Statement::DropFlow(stmt) => {
+            validate_param(stmt.flow_name(), query_ctx)?;
+        }
+        Statement::EvaluateFlow(stmt) => {
+            validate_param(stmt.flow_name(), query_ctx)?;
+        }
+        Statement::CreateView(stmt) => {
+            validate_param(&stmt.name, query_ctx)?;
+        }
+        Statement::DropView(stmt) => {
+            validate_param(stmt.view_name(), query_ctx)?;
+        }
+        Statement::ShowCreateView(stmt) => {
+            validate_param(&stmt.view_name, query_ctx)?;
+        }
         Statement::ShowTables(stmt) => {
             if let Some(database) = &stmt.database {
                 validate_catalog_and_schema(query_ctx.current_catalog(), database, query_ctx)
@@ -0,0 +1,40 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use async_trait::async_trait;
+use auth::{PermissionChecker, PermissionCheckerRef, PermissionReq};
+use common_error::ext::BoxedError;
+use servers::error::{self, AuthSnafu, Result as ServerResult};
+use servers::query_handler::FlightSqlIngestHandler;
+use session::context::QueryContextRef;
+use snafu::ResultExt;
+use table::requests::InsertRequest;
+
+use crate::instance::Instance;
+
+#[async_trait]
+impl FlightSqlIngestHandler for Instance {
+    async fn ingest(&self, request: InsertRequest, ctx: QueryContextRef) -> ServerResult<usize> {
+        self.plugins
+            .get::<PermissionCheckerRef>()
+            .as_ref()
+            .check_permission(ctx.current_user(), PermissionReq::FlightSqlIngest)
+            .context(AuthSnafu)?;
+
+        self.inserter
+            .handle_table_insert(request, ctx)
+            .await
+            .map_err(BoxedError::new)
+            .context(error::ExecuteGrpcRequestSnafu)
+    }
+}
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::time::Instant;
+
+use api::helper::request_type;
 use api::v1::ddl_request::{Expr as DdlExpr, Expr};
 use api::v1::greptime_request::Request;
 use api::v1::query_request::Query;
@@ -28,10 +32,11 @@ use session::context::QueryContextRef;
 use snafu::{ensure, OptionExt, ResultExt};
 
 use crate::error::{
-    Error, IncompleteGrpcRequestSnafu, NotSupportedSnafu, PermissionSnafu, Result,
-    TableOperationSnafu,
+    Error, IncompleteGrpcRequestSnafu, NotSupportedSnafu, PermissionSnafu, QueryCancelledSnafu,
+    Result, TableOperationSnafu,
 };
 use crate::instance::Instance;
+use crate::on_finish::finish_output;
 
 #[async_trait]
 impl GrpcQueryHandler for Instance {
@@ -40,6 +45,47 @@ impl GrpcQueryHandler for Instance {
     async fn do_query(&self, request: Request, ctx: QueryContextRef) -> Result<Output> {
         let interceptor_ref = self.plugins.get::<GrpcQueryInterceptorRef<Error>>();
         let interceptor = interceptor_ref.as_ref();
+        let start = Instant::now();
+        // The request is consumed by execution, describe it for `on_finish` only
+        // if there is an interceptor.
+        let request_desc = interceptor.map(|_| describe_request(&request));
+        // SQL and PromQL queries are tracked by their own handlers.
+        let result = if matches!(request, Request::Query(_)) {
+            self.execute_request(request, interceptor, ctx.clone())
+                .await
+        } else {
+            let query = describe_request(&request);
+            self.track_query(
+                query,
+                &ctx,
+                self.execute_request(request, interceptor, ctx.clone()),
+                |id| QueryCancelledSnafu { id }.build(),
+            )
+            .await
+        };
+        let result = match (interceptor, request_desc) {
+            (Some(interceptor), Some(request_desc)) => {
+                let interceptor = interceptor.clone();
+                let finished_ctx = ctx.clone();
+                finish_output(result, start, move |result, elapsed| {
+                    interceptor.on_finish(&request_desc, result, elapsed, finished_ctx)
+                })
+            }
+            _ => result,
+        };
+
+        let output = interceptor.post_execute(result?, ctx)?;
+        Ok(output)
+    }
+}
+
+impl Instance {
+    async fn execute_request(
+        &self,
+        request: Request,
+        interceptor: Option<&GrpcQueryInterceptorRef<Error>>,
+        ctx: QueryContextRef,
+    ) -> Result<Output> {
         interceptor.pre_execute(&request, ctx.clone())?;
 
         self.plugins
@@ -116,6 +162,7 @@ impl GrpcQueryHandler for Instance {
                                 ctx.current_catalog(),
                                 &expr.database_name,
                                 expr.create_if_not_exists,
+                                &HashMap::new(),
                             )
                             .await?
                     }
@@ -133,11 +180,53 @@ impl GrpcQueryHandler for Instance {
             }
         };
 
-        let output = interceptor.post_execute(output, ctx)?;
         Ok(output)
     }
 }
 
+/// Describes the request by its type and the tables it writes to, e.g.
+/// `row_inserts: monitor, cpu`.
+fn describe_request(request: &Request) -> String {
+    let tables = match request {
+        Request::Inserts(requests) => requests
+            .inserts
+            .iter()
+            .map(|insert| insert.table_name.as_str())
+            .collect(),
+        Request::RowInserts(requests) => requests
+            .inserts
+            .iter()
+            .map(|insert| insert.table_name.as_str())
+            .collect(),
+        Request::Deletes(requests) => requests
+            .deletes
+            .iter()
+            .map(|delete| delete.table_name.as_str())
+            .collect(),
+        Request::RowDeletes(requests) => requests
+            .deletes
+            .iter()
+            .map(|delete| delete.table_name.as_str())
+            .collect(),
+        Request::Ddl(request) => match &request.expr {
+            Some(Expr::CreateDatabase(expr)) => vec![expr.database_name.as_str()],
+            Some(Expr::CreateTable(expr)) => vec![expr.table_name.as_str()],
+            Some(Expr::Alter(expr)) => vec![expr.table_name.as_str()],
+            Some(Expr::DropTable(expr)) => vec![expr.table_name.as_str()],
+            Some(Expr::TruncateTable(expr)) => vec![expr.table_name.as_str()],
+            None => vec![],
+        },
+        Request::Query(_) => vec![],
+    };
+
+    let request_type = request_type(request);
+    if tables.is_empty() {
+        request_type.to_string()
+    } else {
+        format!("{request_type}: {}", tables.join(", "))
+    }
+}
+
 fn fill_catalog_and_schema_from_context(ddl_expr: &mut DdlExpr, ctx: &QueryContextRef) {
     let catalog = ctx.current_catalog();
     let schema = ctx.current_schema();
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use api::v1::RowInsertRequests;
 use async_trait::async_trait;
 use auth::{PermissionChecker, PermissionCheckerRef, PermissionReq};
 use common_error::ext::BoxedError;
@@ -21,6 +22,7 @@ use servers::query_handler::InfluxdbLineProtocolHandler;
 use session::context::QueryContextRef;
 use snafu::ResultExt;
 
+use crate::audit;
 use crate::instance::Instance;
 
 #[async_trait]
@@ -30,18 +32,31 @@ impl InfluxdbLineProtocolHandler for Instance {
         request: InfluxdbRequest,
         ctx: QueryContextRef,
     ) -> servers::error::Result<()> {
-        self.plugins
-            .get::<PermissionCheckerRef>()
-            .as_ref()
-            .check_permission(ctx.current_user(), PermissionReq::LineProtocol)
-            .context(AuthSnafu)?;
+        let requests: RowInsertRequests = request.try_into()?;
+        let statement = self.is_write_audited().then(|| {
+            audit::describe_write(
+                "influxdb",
+                requests
+                    .inserts
+                    .iter()
+                    .map(|insert| insert.table_name.as_str()),
+            )
+        });
 
-        let requests = request.try_into()?;
         let _ = self
-            .handle_row_inserts(requests, ctx)
-            .await
-            .map_err(BoxedError::new)
-            .context(servers::error::ExecuteGrpcQuerySnafu)?;
+            .audit_write(statement, &ctx, async {
+                self.plugins
+                    .get::<PermissionCheckerRef>()
+                    .as_ref()
+                    .check_permission(ctx.current_user(), PermissionReq::LineProtocol)
+                    .context(AuthSnafu)?;
+
+                self.handle_row_inserts(requests, ctx.clone())
+                    .await
+                    .map_err(BoxedError::new)
+                    .context(servers::error::ExecuteGrpcQuerySnafu)
+            })
+            .await?;
         Ok(())
     }
 }
@@ -23,27 +23,35 @@ use servers::query_handler::OpentsdbProtocolHandler;
 use session::context::QueryContextRef;
 use snafu::prelude::*;
 
+use crate::audit;
 use crate::instance::Instance;
 
 #[async_trait]
 impl OpentsdbProtocolHandler for Instance {
     async fn exec(&self, data_point: &DataPoint, ctx: QueryContextRef) -> server_error::Result<()> {
-        self.plugins
-            .get::<PermissionCheckerRef>()
-            .as_ref()
-            .check_permission(ctx.current_user(), PermissionReq::Opentsdb)
-            .context(AuthSnafu)?;
+        let statement = self
+            .is_write_audited()
+            .then(|| audit::describe_write("opentsdb", std::iter::once(data_point.metric())));
 
-        let requests = InsertRequests {
-            inserts: vec![data_point.as_grpc_insert()],
-        };
         let _ = self
-            .handle_inserts(requests, ctx)
-            .await
-            .map_err(BoxedError::new)
-            .with_context(|_| server_error::ExecuteQuerySnafu {
-                query: format!("{data_point:?}"),
-            })?;
+            .audit_write(statement, &ctx, async {
+                self.plugins
+                    .get::<PermissionCheckerRef>()
+                    .as_ref()
+                    .check_permission(ctx.current_user(), PermissionReq::Opentsdb)
+                    .context(AuthSnafu)?;
+
+                let requests = InsertRequests {
+                    inserts: vec![data_point.as_grpc_insert()],
+                };
+                self.handle_inserts(requests, ctx.clone())
+                    .await
+                    .map_err(BoxedError::new)
+                    .with_context(|_| server_error::ExecuteQuerySnafu {
+                        query: format!("{data_point:?}"),
+                    })
+            })
+            .await?;
         Ok(())
     }
 }
@@ -25,6 +25,7 @@ use servers::query_handler::OpenTelemetryProtocolHandler;
 use session::context::QueryContextRef;
 use snafu::ResultExt;
 
+use crate::audit;
 use crate::instance::Instance;
 use crate::metrics::OTLP_METRICS_ROWS;
 
@@ -35,17 +36,30 @@ impl OpenTelemetryProtocolHandler for Instance {
         request: ExportMetricsServiceRequest,
         ctx: QueryContextRef,
     ) -> ServerResult<ExportMetricsServiceResponse> {
-        self.plugins
-            .get::<PermissionCheckerRef>()
-            .as_ref()
-            .check_permission(ctx.current_user(), PermissionReq::Otlp)
-            .context(AuthSnafu)?;
         let (requests, rows) = otlp::to_grpc_insert_requests(request)?;
+        let statement = self.is_write_audited().then(|| {
+            audit::describe_write(
+                "otlp",
+                requests
+                    .inserts
+                    .iter()
+                    .map(|insert| insert.table_name.as_str()),
+            )
+        });
         let _ = self
-            .handle_inserts(requests, ctx)
-            .await
-            .map_err(BoxedError::new)
-            .context(error::ExecuteGrpcQuerySnafu)?;
+            .audit_write(statement, &ctx, async {
+                self.plugins
+                    .get::<PermissionCheckerRef>()
+                    .as_ref()
+                    .check_permission(ctx.current_user(), PermissionReq::Otlp)
+                    .context(AuthSnafu)?;
+
+                self.handle_inserts(requests, ctx.clone())
+                    .await
+                    .map_err(BoxedError::new)
+                    .context(error::ExecuteGrpcQuerySnafu)
+            })
+            .await?;
 
         counter!(OTLP_METRICS_ROWS, rows as u64);
 
@@ -14,21 +14,28 @@
 
 use api::prom_store::remote::read_request::ResponseType;
 use api::prom_store::remote::{Query, QueryResult, ReadRequest, ReadResponse, WriteRequest};
+use async_stream::try_stream;
 use async_trait::async_trait;
 use auth::{PermissionChecker, PermissionCheckerRef, PermissionReq};
 use common_catalog::format_full_table_name;
 use common_error::ext::BoxedError;
+use common_meta::table_name::TableName;
 use common_query::Output;
 use common_recordbatch::RecordBatches;
 use common_telemetry::logging;
+use futures::stream::BoxStream;
+use futures::StreamExt;
 use metrics::counter;
 use prost::Message;
 use servers::error::{self, AuthSnafu, Result as ServerResult};
-use servers::prom_store::{self, Metrics};
-use servers::query_handler::{PromStoreProtocolHandler, PromStoreResponse};
+use servers::prom_store::chunk::{ChunkedSeriesBuilder, STREAMED_XOR_CHUNKS_CONTENT_TYPE};
+use servers::prom_store::{self, MetricMetadata, Metrics};
+use servers::query_handler::{PromStoreProtocolHandler, PromStoreResponse, PromStoreResponseBody};
 use session::context::QueryContextRef;
 use snafu::{OptionExt, ResultExt};
+use table::requests::PROM_METRIC_METADATA_KEY;
 
+use crate::audit;
 use crate::error::{
     CatalogSnafu, ExecLogicalPlanSnafu, PromStoreRemoteQueryPlanSnafu, ReadTableSnafu, Result,
     TableNotFoundSnafu,
@@ -37,11 +44,11 @@ use crate::instance::Instance;
 use crate::metrics::PROM_STORE_REMOTE_WRITE_SAMPLES;
 
 const SAMPLES_RESPONSE_TYPE: i32 = ResponseType::Samples as i32;
+const STREAMED_XOR_CHUNKS_RESPONSE_TYPE: i32 = ResponseType::StreamedXorChunks as i32;
 
 #[inline]
 fn is_supported(response_type: i32) -> bool {
-    // Only supports samples response right now
-    response_type == SAMPLES_RESPONSE_TYPE
+    response_type == SAMPLES_RESPONSE_TYPE || response_type == STREAMED_XOR_CHUNKS_RESPONSE_TYPE
 }
 
 /// Negotiating the content type of the remote read response.
@@ -63,7 +70,7 @@ fn negotiate_response_type(accepted_response_types: &[i32]) -> ServerResult<Resp
             ),
         })?;
 
-    // It's safe to unwrap here, we known that it should be SAMPLES_RESPONSE_TYPE
+    // It's safe to unwrap here, we known that it should be one of the supported types
     Ok(ResponseType::from_i32(*response_type).unwrap())
 }
 
@@ -79,6 +86,31 @@ async fn to_query_result(table_name: &str, output: Output) -> ServerResult<Query
     })
 }
 
+/// Streams the frames of the `STREAMED_XOR_CHUNKS` response, the record batches of each
+/// query are encoded as soon as they are polled.
+fn to_chunked_read_response_stream(
+    results: Vec<(String, Output)>,
+) -> BoxStream<'static, ServerResult<Vec<u8>>> {
+    Box::pin(try_stream! {
+        for (query_index, (table_name, output)) in results.into_iter().enumerate() {
+            let Output::Stream(mut stream) = output else {
+                unreachable!()
+            };
+
+            let mut builder = ChunkedSeriesBuilder::new(table_name, query_index as i64);
+            while let Some(recordbatch) = stream.next().await {
+                let recordbatch = recordbatch.context(error::CollectRecordbatchSnafu)?;
+                for frame in builder.push(&recordbatch)? {
+                    yield frame;
+                }
+            }
+            if let Some(frame) = builder.finish() {
+                yield frame;
+            }
+        }
+    })
+}
+
 impl Instance {
     async fn handle_remote_query(
         &self,
@@ -87,6 +119,7 @@ impl Instance {
         schema_name: &str,
         table_name: &str,
         query: &Query,
+        response_type: ResponseType,
     ) -> Result<Output> {
         let table = self
             .catalog_manager
@@ -96,6 +129,12 @@ impl Instance {
             .with_context(|| TableNotFoundSnafu {
                 table_name: format_full_table_name(catalog_name, schema_name, table_name),
             })?;
+        let primary_keys = table
+            .table_info()
+            .meta
+            .row_key_column_names()
+            .cloned()
+            .collect::<Vec<_>>();
 
         let dataframe = self
             .query_engine
@@ -104,8 +143,14 @@ impl Instance {
                 table_name: format_full_table_name(catalog_name, schema_name, table_name),
             })?;
 
-        let logical_plan =
-            prom_store::query_to_plan(dataframe, query).context(PromStoreRemoteQueryPlanSnafu)?;
+        let logical_plan = match response_type {
+            ResponseType::Samples => prom_store::query_to_plan(dataframe, query),
+            // Chunks of a series are built from consecutive rows
+            ResponseType::StreamedXorChunks => {
+                prom_store::query_to_sorted_plan(dataframe, query, &primary_keys)
+            }
+        }
+        .context(PromStoreRemoteQueryPlanSnafu)?;
 
         logging::debug!(
             "Prometheus remote read, table: {}, logical plan: {}",
@@ -123,6 +168,7 @@ impl Instance {
         &self,
         ctx: QueryContextRef,
         queries: &[Query],
+        response_type: ResponseType,
     ) -> ServerResult<Vec<(String, Output)>> {
         let mut results = Vec::with_capacity(queries.len());
 
@@ -133,7 +179,14 @@ impl Instance {
             let table_name = prom_store::table_name(query)?;
 
             let output = self
-                .handle_remote_query(&ctx, catalog_name, schema_name, &table_name, query)
+                .handle_remote_query(
+                    &ctx,
+                    catalog_name,
+                    schema_name,
+                    &table_name,
+                    query,
+                    response_type,
+                )
                 .await
                 .map_err(BoxedError::new)
                 .with_context(|_| error::ExecuteQuerySnafu {
@@ -144,22 +197,111 @@ impl Instance {
         }
         Ok(results)
     }
-}
 
-#[async_trait]
-impl PromStoreProtocolHandler for Instance {
-    async fn write(&self, request: WriteRequest, ctx: QueryContextRef) -> ServerResult<()> {
+    /// Reads the exemplars of the series selected by `query` from the exemplar table of
+    /// the metric.
+    pub(crate) async fn handle_exemplars_query(
+        &self,
+        query: &Query,
+        ctx: QueryContextRef,
+    ) -> ServerResult<Output> {
         self.plugins
             .get::<PermissionCheckerRef>()
             .as_ref()
-            .check_permission(ctx.current_user(), PermissionReq::PromStoreWrite)
+            .check_permission(ctx.current_user(), PermissionReq::PromQuery)
             .context(AuthSnafu)?;
+
+        let table_name = prom_store::exemplar_table_name(&prom_store::table_name(query)?);
+        self.handle_remote_query(
+            &ctx,
+            ctx.current_catalog(),
+            ctx.current_schema(),
+            &table_name,
+            query,
+            ResponseType::Samples,
+        )
+        .await
+        .map_err(BoxedError::new)
+        .with_context(|_| error::ExecuteQuerySnafu {
+            query: format!("{query:#?}"),
+        })
+    }
+
+    /// Persists the metric metadata as the comments of metric tables. It's best-effort, metadata
+    /// of metrics without tables is skipped, since Prometheus sends metadata periodically.
+    async fn write_metric_metadata(
+        &self,
+        metadata: Vec<(String, MetricMetadata)>,
+        ctx: &QueryContextRef,
+    ) {
+        let catalog_name = ctx.current_catalog();
+        let schema_name = ctx.current_schema();
+
+        for (table_name, metadata) in metadata {
+            let table = match self
+                .catalog_manager
+                .table(catalog_name, schema_name, &table_name)
+                .await
+            {
+                Ok(Some(table)) => table,
+                Ok(None) => continue,
+                Err(e) => {
+                    logging::warn!(e; "Failed to get table {table_name} to write metric metadata");
+                    continue;
+                }
+            };
+
+            let value = metadata.to_table_option();
+            let options = &table.table_info().meta.options;
+            if options.extra_options.get(PROM_METRIC_METADATA_KEY) == Some(&value) {
+                continue;
+            }
+
+            if let Err(e) = self
+                .statement_executor
+                .update_table_option(
+                    TableName::new(catalog_name, schema_name, &table_name),
+                    PROM_METRIC_METADATA_KEY,
+                    value,
+                )
+                .await
+            {
+                logging::warn!(e; "Failed to write metric metadata of table {table_name}");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PromStoreProtocolHandler for Instance {
+    async fn write(&self, request: WriteRequest, ctx: QueryContextRef) -> ServerResult<()> {
+        let metadata = prom_store::to_metric_metadata(&request);
         let (requests, samples) = prom_store::to_grpc_row_insert_requests(request)?;
+        let statement = self.is_write_audited().then(|| {
+            audit::describe_write(
+                "prometheus remote write",
+                requests
+                    .inserts
+                    .iter()
+                    .map(|insert| insert.table_name.as_str()),
+            )
+        });
         let _ = self
-            .handle_row_inserts(requests, ctx)
-            .await
-            .map_err(BoxedError::new)
-            .context(error::ExecuteGrpcQuerySnafu)?;
+            .audit_write(statement, &ctx, async {
+                self.plugins
+                    .get::<PermissionCheckerRef>()
+                    .as_ref()
+                    .check_permission(ctx.current_user(), PermissionReq::PromStoreWrite)
+                    .context(AuthSnafu)?;
+
+                self.handle_row_inserts(requests, ctx.clone())
+                    .await
+                    .map_err(BoxedError::new)
+                    .context(error::ExecuteGrpcQuerySnafu)
+            })
+            .await?;
+
+        self.write_metric_metadata(metadata, &ctx).await;
 
         counter!(PROM_STORE_REMOTE_WRITE_SAMPLES, samples as u64);
         Ok(())
@@ -179,7 +321,9 @@ impl PromStoreProtocolHandler for Instance {
         let response_type = negotiate_response_type(&request.accepted_response_types)?;
 
         // TODO(dennis): use read_hints to speedup query if possible
-        let results = self.handle_remote_queries(ctx, &request.queries).await?;
+        let results = self
+            .handle_remote_queries(ctx, &request.queries, response_type)
+            .await?;
 
         match response_type {
             ResponseType::Samples => {
@@ -196,13 +340,17 @@ impl PromStoreProtocolHandler for Instance {
                 Ok(PromStoreResponse {
                     content_type: "application/x-protobuf".to_string(),
                     content_encoding: "snappy".to_string(),
-                    body: prom_store::snappy_compress(&response.encode_to_vec())?,
+                    body: PromStoreResponseBody::Full(prom_store::snappy_compress(
+                        &response.encode_to_vec(),
+                    )?),
                 })
             }
-            ResponseType::StreamedXorChunks => error::NotSupportedSnafu {
-                feat: "streamed remote read",
-            }
-            .fail(),
+            ResponseType::StreamedXorChunks => Ok(PromStoreResponse {
+                content_type: STREAMED_XOR_CHUNKS_CONTENT_TYPE.to_string(),
+                // The frames are not compressed.
+                content_encoding: String::new(),
+                body: PromStoreResponseBody::Stream(to_chunked_read_response_stream(results)),
+            }),
         }
     }
 
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use api::v1::meta::Partition;
 use api::v1::region::{QueryRequest, RegionRequest, RegionResponse};
 use async_trait::async_trait;
+use catalog::information_schema::{RegionStatsEntry, RegionStatsProvider};
 use client::region::check_response_header;
 use common_error::ext::BoxedError;
 use common_meta::datanode_manager::{AffectedRows, Datanode, DatanodeManager, DatanodeRef};
@@ -46,6 +47,32 @@ impl DatanodeManager for StandaloneDatanodeManager {
     }
 }
 
+/// Provides the statistics of the regions opened in the standalone region server.
+pub struct StandaloneRegionStatsProvider(pub RegionServer);
+
+#[async_trait]
+impl RegionStatsProvider for StandaloneRegionStatsProvider {
+    async fn list_region_stats(&self) -> std::result::Result<Vec<RegionStatsEntry>, BoxedError> {
+        let stats = self
+            .0
+            .opened_regions()
+            .into_iter()
+            .map(|(region_id, engine)| {
+                let stat = self.0.region_statistic(region_id).unwrap_or_default();
+                RegionStatsEntry {
+                    region_id,
+                    engine,
+                    num_rows: stat.num_rows,
+                    disk_size: stat.approximate_bytes(),
+                    memtable_size: Some(stat.memtable_size),
+                    sst_size: Some(stat.sst_size),
+                }
+            })
+            .collect();
+        Ok(stats)
+    }
+}
+
 /// Relative to [client::region::RegionRequester]
 struct RegionInvoker {
     region_server: RegionServer,
@@ -15,11 +15,17 @@
 #![feature(assert_matches)]
 #![feature(trait_upcasting)]
 
+pub mod audit;
 pub mod error;
+mod flow;
 pub mod frontend;
 pub mod heartbeat;
 pub mod instance;
 pub(crate) mod metrics;
+mod on_finish;
+pub mod process_list;
+mod ruler;
 mod script;
 mod server;
 pub mod service_config;
+pub mod slow_query;
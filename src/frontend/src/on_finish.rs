@@ -0,0 +1,219 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Calls `on_finish` of interceptors once the output is consumed.
+//!
+//! Streamed outputs are executed while they are polled, so a statement returning a
+//! stream is finished when the stream is exhausted, fails or is dropped, not when the
+//! handler returns it.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use common_error::ext::BoxedError;
+use common_query::Output;
+use common_recordbatch::error::{Error as RecordBatchError, ExternalSnafu};
+use common_recordbatch::{
+    OrderOption, RecordBatch, RecordBatchStream, RecordBatches, SendableRecordBatchStream,
+};
+use datatypes::schema::SchemaRef;
+use futures::Stream;
+use servers::error as server_error;
+use snafu::IntoError;
+
+use crate::error::{CollectRecordbatchSnafu, Error};
+
+type OnFinish<E> = Box<dyn FnOnce(Result<&Output, &E>, Duration) + Send>;
+
+/// Errors of handlers that can carry the errors of streamed outputs.
+pub(crate) trait StreamError: Sized {
+    fn from_stream_error(error: RecordBatchError) -> Self;
+
+    /// Returns the error of the streamed output wrapped by [StreamError::from_stream_error].
+    fn into_stream_error(self) -> RecordBatchError;
+}
+
+impl StreamError for Error {
+    fn from_stream_error(error: RecordBatchError) -> Self {
+        CollectRecordbatchSnafu.into_error(error)
+    }
+
+    fn into_stream_error(self) -> RecordBatchError {
+        match self {
+            Error::CollectRecordbatch { source, .. } => source,
+            error => ExternalSnafu.into_error(BoxedError::new(error)),
+        }
+    }
+}
+
+impl StreamError for server_error::Error {
+    fn from_stream_error(error: RecordBatchError) -> Self {
+        server_error::CollectRecordbatchSnafu.into_error(error)
+    }
+
+    fn into_stream_error(self) -> RecordBatchError {
+        match self {
+            server_error::Error::CollectRecordbatch { source, .. } => source,
+            error => ExternalSnafu.into_error(BoxedError::new(error)),
+        }
+    }
+}
+
+/// Calls `on_finish` with `result` now, or when the streamed output is exhausted,
+/// fails or is dropped. A consumed stream is passed to `on_finish` as empty record
+/// batches.
+pub(crate) fn finish_output<E, F>(
+    result: Result<Output, E>,
+    start: Instant,
+    on_finish: F,
+) -> Result<Output, E>
+where
+    E: StreamError + 'static,
+    F: FnOnce(Result<&Output, &E>, Duration) + Send + 'static,
+{
+    match result {
+        Ok(Output::Stream(stream)) => Ok(Output::Stream(Box::pin(FinishStream {
+            stream,
+            start,
+            on_finish: Some(Box::new(on_finish)),
+        }))),
+        result => {
+            on_finish(result.as_ref(), start.elapsed());
+            result
+        }
+    }
+}
+
+struct FinishStream<E> {
+    stream: SendableRecordBatchStream,
+    start: Instant,
+    on_finish: Option<OnFinish<E>>,
+}
+
+impl<E: StreamError> FinishStream<E> {
+    /// Calls `on_finish` if it's not called yet, returns `error` back.
+    fn finish(&mut self, error: Option<RecordBatchError>) -> Option<RecordBatchError> {
+        let Some(on_finish) = self.on_finish.take() else {
+            return error;
+        };
+        let elapsed = self.start.elapsed();
+        match error {
+            Some(error) => {
+                let error = E::from_stream_error(error);
+                on_finish(Err(&error), elapsed);
+                Some(error.into_stream_error())
+            }
+            None => {
+                on_finish(Ok(&Output::RecordBatches(RecordBatches::empty())), elapsed);
+                None
+            }
+        }
+    }
+}
+
+impl<E: StreamError> RecordBatchStream for FinishStream<E> {
+    fn schema(&self) -> SchemaRef {
+        self.stream.schema()
+    }
+
+    fn output_ordering(&self) -> Option<&[OrderOption]> {
+        self.stream.output_ordering()
+    }
+}
+
+impl<E: StreamError> Stream for FinishStream<E> {
+    type Item = common_recordbatch::error::Result<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.stream).poll_next(cx) {
+            Poll::Ready(None) => {
+                let _ = self.finish(None);
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(Err(e))) => Poll::Ready(self.finish(Some(e)).map(Err)),
+            poll => poll,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<E> Drop for FinishStream<E> {
+    fn drop(&mut self) {
+        if let Some(on_finish) = self.on_finish.take() {
+            on_finish(
+                Ok(&Output::RecordBatches(RecordBatches::empty())),
+                self.start.elapsed(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use common_recordbatch::util;
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::{ColumnSchema, Schema};
+    use datatypes::vectors::UInt32Vector;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_finish_stream_output() {
+        let finished = Arc::new(Mutex::new(Vec::new()));
+        let on_finish = |finished: Arc<Mutex<Vec<bool>>>| {
+            move |result: Result<&Output, &Error>, _| finished.lock().unwrap().push(result.is_ok())
+        };
+
+        let _ = finish_output(
+            Ok(Output::AffectedRows(1)),
+            Instant::now(),
+            on_finish(finished.clone()),
+        )
+        .unwrap();
+        assert_eq!(vec![true], *finished.lock().unwrap());
+
+        let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+            "n",
+            ConcreteDataType::uint32_datatype(),
+            false,
+        )]));
+        let batch = RecordBatch::new(
+            schema.clone(),
+            vec![Arc::new(UInt32Vector::from_slice([1, 2, 3])) as _],
+        )
+        .unwrap();
+        let batches = RecordBatches::try_new(schema, vec![batch]).unwrap();
+        let output = finish_output(
+            Ok(Output::Stream(batches.as_stream())),
+            Instant::now(),
+            on_finish(finished.clone()),
+        )
+        .unwrap();
+        // The statement is not finished until its output is consumed.
+        assert_eq!(1, finished.lock().unwrap().len());
+
+        let Output::Stream(stream) = output else {
+            unreachable!()
+        };
+        let batches = util::collect(stream).await.unwrap();
+        assert_eq!(3, batches[0].num_rows());
+        assert_eq!(vec![true, true], *finished.lock().unwrap());
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The queries running in this frontend, for `SHOW PROCESSLIST` and `KILL QUERY`.
+//!
+//! A query is listed from the moment its handler starts executing it until its output
+//! is consumed. Killing it cancels its execution: the pending future or output stream
+//! is dropped, which in turn drops the DataFusion streams and the sub-queries on the
+//! datanodes.
+//!
+//! Queries are owned by the user running them. Users without the privilege to manage
+//! the queries of others only see and kill their own.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_stream::stream;
+use common_query::Output;
+use common_recordbatch::error::{QueryCancelledSnafu, Result as RecordBatchResult};
+use common_recordbatch::{RecordBatchStreamAdaptor, RecordBatches, SendableRecordBatchStream};
+use common_time::util::current_time_millis;
+use datatypes::prelude::{ConcreteDataType, VectorRef};
+use datatypes::schema::{ColumnSchema, Schema};
+use datatypes::vectors::{StringVector, TimestampMillisecondVector, UInt64Vector};
+use futures::StreamExt;
+use session::context::QueryContextRef;
+use sql::statements::show::ShowProcesslist;
+use tokio_util::sync::CancellationToken;
+
+/// Statements longer than this are truncated in `SHOW PROCESSLIST`, but not in
+/// `SHOW FULL PROCESSLIST`.
+const MAX_INFO_LEN: usize = 100;
+
+pub type ProcessListRef = Arc<ProcessList>;
+
+/// A query in the [ProcessList].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessInfo {
+    pub id: u64,
+    pub user: String,
+    pub client_addr: Option<String>,
+    /// The protocol the query comes from, e.g. `mysql` or `http`.
+    pub protocol: String,
+    pub db: String,
+    /// Start time of the query, in milliseconds since the unix epoch.
+    pub start_time: i64,
+    pub query: String,
+}
+
+struct ProcessEntry {
+    info: ProcessInfo,
+    cancel_token: CancellationToken,
+}
+
+/// The running queries of this frontend.
+#[derive(Default)]
+pub struct ProcessList {
+    next_id: AtomicU64,
+    processes: Mutex<BTreeMap<u64, ProcessEntry>>,
+}
+
+impl ProcessList {
+    /// Lists `query`, which is about to be executed in `query_ctx`, until the returned
+    /// [Process] is dropped.
+    pub fn register(self: &Arc<Self>, query: String, query_ctx: QueryContextRef) -> Process {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let info = ProcessInfo {
+            id,
+            user: query_ctx
+                .current_user()
+                .map(|user| user.username().to_string())
+                .unwrap_or_default(),
+            client_addr: query_ctx.client_addr().map(|addr| addr.to_string()),
+            protocol: query_ctx.channel().to_string(),
+            db: query_ctx.get_db_string(),
+            start_time: current_time_millis(),
+            query,
+        };
+        let cancel_token = CancellationToken::new();
+        query_ctx.set_cancel_token(Some(cancel_token.clone()));
+
+        let _ = self.processes.lock().unwrap().insert(
+            id,
+            ProcessEntry {
+                info,
+                cancel_token: cancel_token.clone(),
+            },
+        );
+
+        Process {
+            list: self.clone(),
+            id,
+            cancel_token,
+            query_ctx,
+        }
+    }
+
+    /// Returns the running queries of `owner`, or of all users if `owner` is `None`,
+    /// ordered by id.
+    pub fn processes(&self, owner: Option<&str>) -> Vec<ProcessInfo> {
+        self.processes
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|entry| is_owned_by(&entry.info, owner))
+            .map(|entry| entry.info.clone())
+            .collect()
+    }
+
+    /// Cancels the query with `id` of `owner`, or of any user if `owner` is `None`.
+    /// Returns false if there is no such query.
+    pub fn kill(&self, id: u64, owner: Option<&str>) -> bool {
+        match self.processes.lock().unwrap().get(&id) {
+            Some(entry) if is_owned_by(&entry.info, owner) => {
+                entry.cancel_token.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Builds the output of `SHOW [FULL] PROCESSLIST` with the queries of `owner`, or
+    /// of all users if `owner` is `None`.
+    pub fn show_processlist(
+        &self,
+        stmt: &ShowProcesslist,
+        owner: Option<&str>,
+    ) -> RecordBatchResult<Output> {
+        let processes = self.processes(owner);
+        let now = current_time_millis();
+
+        let schema = Arc::new(Schema::new(vec![
+            ColumnSchema::new("Id", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("User", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("Host", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new("db", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new("Protocol", ConcreteDataType::string_datatype(), false),
+            ColumnSchema::new(
+                "Start_time",
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                false,
+            ),
+            ColumnSchema::new("Time", ConcreteDataType::uint64_datatype(), false),
+            ColumnSchema::new("Info", ConcreteDataType::string_datatype(), false),
+        ]));
+        let columns: Vec<VectorRef> = vec![
+            Arc::new(UInt64Vector::from_vec(
+                processes.iter().map(|p| p.id).collect(),
+            )),
+            Arc::new(StringVector::from(
+                processes
+                    .iter()
+                    .map(|p| p.user.as_str())
+                    .collect::<Vec<_>>(),
+            )),
+            Arc::new(StringVector::from(
+                processes
+                    .iter()
+                    .map(|p| p.client_addr.as_deref())
+                    .collect::<Vec<_>>(),
+            )),
+            Arc::new(StringVector::from(
+                processes.iter().map(|p| p.db.as_str()).collect::<Vec<_>>(),
+            )),
+            Arc::new(StringVector::from(
+                processes
+                    .iter()
+                    .map(|p| p.protocol.as_str())
+                    .collect::<Vec<_>>(),
+            )),
+            Arc::new(TimestampMillisecondVector::from_vec(
+                processes.iter().map(|p| p.start_time).collect(),
+            )),
+            Arc::new(UInt64Vector::from_vec(
+                processes
+                    .iter()
+                    .map(|p| ((now - p.start_time).max(0) / 1000) as u64)
+                    .collect(),
+            )),
+            Arc::new(StringVector::from(
+                processes
+                    .iter()
+                    .map(|p| {
+                        if stmt.full {
+                            p.query.clone()
+                        } else {
+                            p.query.chars().take(MAX_INFO_LEN).collect()
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            )),
+        ];
+        RecordBatches::try_from_columns(schema, columns).map(Output::RecordBatches)
+    }
+}
+
+fn is_owned_by(info: &ProcessInfo, owner: Option<&str>) -> bool {
+    owner.map_or(true, |owner| info.user == owner)
+}
+
+/// A running query in the [ProcessList], unlisted when dropped.
+pub struct Process {
+    list: ProcessListRef,
+    id: u64,
+    cancel_token: CancellationToken,
+    query_ctx: QueryContextRef,
+}
+
+impl Process {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Runs `fut` until it completes, returns `None` if the query is killed before that.
+    pub async fn run<F: Future>(&self, fut: F) -> Option<F::Output> {
+        tokio::select! {
+            output = fut => Some(output),
+            _ = self.cancel_token.cancelled() => None,
+        }
+    }
+
+    /// Finishes executing the query. Streamed outputs stay listed until they are
+    /// exhausted or dropped, and end with an error once the query is killed.
+    pub fn finish<E>(self, result: Result<Output, E>) -> Result<Output, E> {
+        // Operators have captured the token on execution, later statements in this
+        // context must not be cancelled by it.
+        self.query_ctx.set_cancel_token(None);
+
+        match result {
+            Ok(Output::Stream(stream)) => Ok(Output::Stream(self.cancellable(stream))),
+            result => result,
+        }
+    }
+
+    fn cancellable(self, mut stream: SendableRecordBatchStream) -> SendableRecordBatchStream {
+        let schema = stream.schema();
+        let output_ordering = stream.output_ordering().map(|ordering| ordering.to_vec());
+        let stream = Box::pin(stream!({
+            loop {
+                let batch = tokio::select! {
+                    batch = stream.next() => Ok(batch),
+                    _ = self.cancel_token.cancelled() => QueryCancelledSnafu.fail(),
+                };
+                match batch {
+                    Ok(Some(batch)) => yield batch,
+                    Ok(None) => break,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
+        }));
+        Box::pin(RecordBatchStreamAdaptor {
+            schema,
+            stream,
+            output_ordering,
+        })
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        let _ = self.list.processes.lock().unwrap().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_recordbatch::util;
+    use session::context::QueryContext;
+
+    use super::*;
+
+    #[test]
+    fn test_process_list() {
+        let list = Arc::new(ProcessList::default());
+        let query_ctx = QueryContext::arc();
+        let first = list.register("SELECT 1".to_string(), query_ctx.clone());
+        assert!(query_ctx.cancel_token().is_some());
+        let second = list.register("SELECT 2".to_string(), QueryContext::arc());
+
+        let processes = list.processes(None);
+        assert_eq!(2, processes.len());
+        assert_eq!(first.id(), processes[0].id);
+        assert_eq!("SELECT 2", processes[1].query);
+
+        assert!(list.kill(second.id(), None));
+        assert!(!list.kill(42, None));
+
+        let _ = first.finish::<()>(Ok(Output::AffectedRows(1)));
+        assert!(query_ctx.cancel_token().is_none());
+        drop(second);
+        assert!(list.processes(None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_kill_process() {
+        let list = Arc::new(ProcessList::default());
+        let process = list.register("SELECT 1".to_string(), QueryContext::arc());
+        assert!(list.kill(process.id(), None));
+        let output = process.run(futures::future::pending::<()>()).await;
+        assert!(output.is_none());
+
+        let process = list.register("SELECT 1".to_string(), QueryContext::arc());
+        let id = process.id();
+        let output = process
+            .finish::<()>(Ok(Output::Stream(RecordBatches::empty().as_stream())))
+            .unwrap();
+        assert_eq!(1, list.processes(None).len());
+        assert!(list.kill(id, None));
+        let Output::Stream(stream) = output else {
+            unreachable!()
+        };
+        let err = util::collect(stream).await.unwrap_err();
+        assert!(matches!(
+            err,
+            common_recordbatch::error::Error::QueryCancelled { .. }
+        ));
+        assert!(list.processes(None).is_empty());
+    }
+
+    #[test]
+    fn test_process_owner() {
+        let list = Arc::new(ProcessList::default());
+        let alice = QueryContext::arc();
+        alice.set_current_user(Some(auth::userinfo_by_name(Some("alice".to_string()))));
+        let alice_process = list.register("SELECT 1".to_string(), alice);
+        let bob = QueryContext::arc();
+        bob.set_current_user(Some(auth::userinfo_by_name(Some("bob".to_string()))));
+        let bob_process = list.register("SELECT 2".to_string(), bob);
+
+        assert_eq!(2, list.processes(None).len());
+        let processes = list.processes(Some("alice"));
+        assert_eq!(1, processes.len());
+        assert_eq!(alice_process.id(), processes[0].id);
+
+        // Users can't kill the queries of others.
+        assert!(!list.kill(bob_process.id(), Some("alice")));
+        assert!(list.kill(alice_process.id(), Some("alice")));
+        assert!(list.kill(bob_process.id(), None));
+    }
+}
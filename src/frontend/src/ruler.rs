@@ -0,0 +1,382 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus-compatible rule engine.
+//!
+//! Rule groups are loaded from YAML files and evaluated periodically through
+//! [PrometheusHandler]. Results of recording rules are written back via Prometheus remote
+//! write, and firing alerts are posted to an Alertmanager-compatible webhook.
+
+mod alert;
+mod notifier;
+mod rule;
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use api::prom_store::remote::{Label, Sample as PromSample, TimeSeries, WriteRequest};
+use common_query::Output;
+use common_recordbatch::RecordBatches;
+use common_runtime::JoinHandle;
+use common_telemetry::{error, info};
+use datatypes::prelude::ConcreteDataType;
+use datatypes::scalars::ScalarVector;
+use datatypes::vectors::{Float64Vector, StringVector};
+use query::parser::PromQuery;
+use servers::prom_store::METRIC_NAME_LABEL;
+use servers::prometheus_handler::PrometheusHandlerRef;
+use servers::query_handler::PromStoreProtocolHandlerRef;
+use session::context::{QueryContext, QueryContextRef};
+use snafu::{ensure, OptionExt, ResultExt};
+
+use self::alert::AlertingRuleState;
+use self::notifier::Notifier;
+use self::rule::{Rule, RuleGroup, RuleGroups};
+use crate::error::{
+    CollectRecordbatchSnafu, ExecutePromqlSnafu, InvalidRuleResultSnafu, Result,
+    WriteRecordingRuleSnafu,
+};
+use crate::service_config::RulerOptions;
+
+/// A sample in the instant vector returned by rule expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+}
+
+pub type RuleManagerRef = Arc<RuleManager>;
+
+/// Schedules the evaluation of all rule groups.
+pub struct RuleManager {
+    opts: RulerOptions,
+    groups: Vec<RuleGroup>,
+    notifier: Option<Notifier>,
+    handles: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl RuleManager {
+    pub fn try_new(opts: &RulerOptions) -> Result<Self> {
+        let mut groups = Vec::new();
+        for path in &opts.rule_files {
+            let rule_groups = RuleGroups::from_file(path)?;
+            info!(
+                "Loaded {} rule groups from file {path}",
+                rule_groups.groups.len()
+            );
+            groups.extend(rule_groups.groups);
+        }
+
+        Ok(Self {
+            opts: opts.clone(),
+            groups,
+            notifier: opts.alertmanager_url.as_deref().map(Notifier::new),
+            handles: Mutex::new(vec![]),
+        })
+    }
+
+    /// Spawn one evaluation task for each rule group.
+    pub fn start(
+        &self,
+        prom_handler: PrometheusHandlerRef,
+        prom_store_handler: PromStoreProtocolHandlerRef,
+    ) {
+        let query_ctx = QueryContext::with_db_name(Some(&self.opts.db));
+        let mut handles = self.handles.lock().unwrap();
+        for group in &self.groups {
+            let interval = group.interval.unwrap_or(self.opts.evaluation_interval);
+            let evaluator = GroupEvaluator {
+                alert_states: group
+                    .rules
+                    .iter()
+                    .map(|_| AlertingRuleState::default())
+                    .collect(),
+                group: group.clone(),
+                query_ctx: query_ctx.clone(),
+                prom_handler: prom_handler.clone(),
+                prom_store_handler: prom_store_handler.clone(),
+                notifier: self.notifier.clone(),
+            };
+
+            handles.push(common_runtime::spawn_bg(async move {
+                let mut ticker = tokio::time::interval(interval);
+                ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                let mut evaluator = evaluator;
+                loop {
+                    let _ = ticker.tick().await;
+                    evaluator.eval(SystemTime::now()).await;
+                }
+            }));
+        }
+    }
+
+    pub fn stop(&self) {
+        for handle in self.handles.lock().unwrap().drain(..) {
+            handle.abort();
+        }
+    }
+}
+
+struct GroupEvaluator {
+    group: RuleGroup,
+    /// States of alerting rules, in the same order as `group.rules`
+    alert_states: Vec<AlertingRuleState>,
+    query_ctx: QueryContextRef,
+    prom_handler: PrometheusHandlerRef,
+    prom_store_handler: PromStoreProtocolHandlerRef,
+    notifier: Option<Notifier>,
+}
+
+impl GroupEvaluator {
+    async fn eval(&mut self, now: SystemTime) {
+        let mut alert_states = std::mem::take(&mut self.alert_states);
+        // rules in the same group are evaluated sequentially, so that results of
+        // recording rules can be used by later rules
+        for (rule, state) in self.group.rules.iter().zip(alert_states.iter_mut()) {
+            if let Err(e) = self.eval_rule(rule, state, now).await {
+                error!(e; "Failed to evaluate rule {:?} in group {}", rule.expr, self.group.name);
+            }
+        }
+        self.alert_states = alert_states;
+    }
+
+    async fn eval_rule(
+        &self,
+        rule: &Rule,
+        state: &mut AlertingRuleState,
+        now: SystemTime,
+    ) -> Result<()> {
+        let timestamp = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let time = format!("{:.3}", timestamp.as_secs_f64());
+        let query = PromQuery {
+            query: rule.expr.clone(),
+            start: time.clone(),
+            end: time,
+            step: "1s".to_string(),
+        };
+        let output = self
+            .prom_handler
+            .do_query(&query, self.query_ctx.clone())
+            .await
+            .with_context(|_| ExecutePromqlSnafu {
+                query: rule.expr.clone(),
+            })?;
+        let samples = output_to_samples(output).await?;
+        let limit = self.group.limit;
+        ensure!(
+            limit == 0 || samples.len() <= limit,
+            InvalidRuleResultSnafu {
+                reason: format!(
+                    "expr {} exceeded limit {limit} with {} series",
+                    rule.expr,
+                    samples.len()
+                ),
+            }
+        );
+
+        match &rule.record {
+            Some(record) => {
+                let request = recording_to_write_request(
+                    record,
+                    &rule.labels,
+                    samples,
+                    timestamp.as_millis() as i64,
+                );
+                self.prom_store_handler
+                    .write(request, self.query_ctx.clone())
+                    .await
+                    .context(WriteRecordingRuleSnafu { record })?;
+            }
+            None => {
+                let alerts = state.update(rule, samples, now);
+                if let Some(notifier) = &self.notifier {
+                    notifier.send(&alerts).await?;
+                }
+                // resolved alerts are kept to be resent if they are not delivered
+                state.remove_resolved();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convert the result of an instant query to [Sample]s. If there are multiple points in
+/// one series, the last one is used.
+async fn output_to_samples(output: Output) -> Result<Vec<Sample>> {
+    let batches = match output {
+        Output::RecordBatches(batches) => batches,
+        Output::Stream(stream) => RecordBatches::try_collect(stream)
+            .await
+            .context(CollectRecordbatchSnafu)?,
+        Output::AffectedRows(_) => {
+            return InvalidRuleResultSnafu {
+                reason: "expected data result, but got affected rows",
+            }
+            .fail()
+        }
+    };
+
+    let schema = batches.schema();
+    let mut tag_columns = Vec::new();
+    let mut field_column = None;
+    for (i, column) in schema.column_schemas().iter().enumerate() {
+        match column.data_type {
+            ConcreteDataType::String(_) => tag_columns.push((i, column.name.clone())),
+            ConcreteDataType::Float64(_) if field_column.is_none() => field_column = Some(i),
+            _ => {}
+        }
+    }
+    let field_column = field_column.context(InvalidRuleResultSnafu {
+        reason: "no value column found",
+    })?;
+
+    let mut series = BTreeMap::new();
+    for batch in batches.iter() {
+        let values = batch
+            .column(field_column)
+            .as_any()
+            .downcast_ref::<Float64Vector>()
+            .unwrap();
+        let tags = tag_columns
+            .iter()
+            .map(|(i, name)| {
+                let column = batch
+                    .column(*i)
+                    .as_any()
+                    .downcast_ref::<StringVector>()
+                    .unwrap();
+                (name, column)
+            })
+            .collect::<Vec<_>>();
+
+        for row in 0..batch.num_rows() {
+            let Some(value) = values.get_data(row) else {
+                continue;
+            };
+            let labels = tags
+                .iter()
+                .filter_map(|(name, column)| {
+                    column
+                        .get_data(row)
+                        .map(|v| (name.to_string(), v.to_string()))
+                })
+                .collect::<BTreeMap<_, _>>();
+            let _ = series.insert(labels, value);
+        }
+    }
+
+    Ok(series
+        .into_iter()
+        .map(|(labels, value)| Sample { labels, value })
+        .collect())
+}
+
+/// Build the remote write request of recording rule `record` from its result.
+fn recording_to_write_request(
+    record: &str,
+    rule_labels: &BTreeMap<String, String>,
+    samples: Vec<Sample>,
+    timestamp: i64,
+) -> WriteRequest {
+    let timeseries = samples
+        .into_iter()
+        .map(|sample| {
+            let mut labels = sample.labels;
+            labels.extend(rule_labels.clone());
+            let _ = labels.insert(METRIC_NAME_LABEL.to_string(), record.to_string());
+            TimeSeries {
+                labels: labels
+                    .into_iter()
+                    .map(|(name, value)| Label { name, value })
+                    .collect(),
+                samples: vec![PromSample {
+                    value: sample.value,
+                    timestamp,
+                }],
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    WriteRequest {
+        timeseries,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_recordbatch::RecordBatch;
+    use datatypes::schema::{ColumnSchema, Schema};
+    use datatypes::vectors::TimestampMillisecondVector;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_output_to_samples() {
+        let schema = Arc::new(Schema::new(vec![
+            ColumnSchema::new(
+                "ts",
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                false,
+            ),
+            ColumnSchema::new("value", ConcreteDataType::float64_datatype(), true),
+            ColumnSchema::new("job", ConcreteDataType::string_datatype(), true),
+        ]));
+        let batch = RecordBatch::new(
+            schema.clone(),
+            vec![
+                Arc::new(TimestampMillisecondVector::from_vec(vec![1000, 2000, 1000])) as _,
+                Arc::new(Float64Vector::from(vec![Some(1.0), Some(2.0), None])) as _,
+                Arc::new(StringVector::from(vec!["a", "a", "b"])) as _,
+            ],
+        )
+        .unwrap();
+        let output = Output::RecordBatches(RecordBatches::try_new(schema, vec![batch]).unwrap());
+
+        let samples = output_to_samples(output).await.unwrap();
+        assert_eq!(
+            vec![Sample {
+                labels: BTreeMap::from([("job".to_string(), "a".to_string())]),
+                value: 2.0,
+            }],
+            samples
+        );
+    }
+
+    #[test]
+    fn test_recording_to_write_request() {
+        let samples = vec![Sample {
+            labels: BTreeMap::from([("job".to_string(), "a".to_string())]),
+            value: 2.0,
+        }];
+        let rule_labels = BTreeMap::from([("env".to_string(), "prod".to_string())]);
+        let request = recording_to_write_request("job:up:sum", &rule_labels, samples, 1000);
+
+        assert_eq!(1, request.timeseries.len());
+        let timeseries = &request.timeseries[0];
+        assert_eq!(
+            vec![("__name__", "job:up:sum"), ("env", "prod"), ("job", "a")],
+            timeseries
+                .labels
+                .iter()
+                .map(|l| (l.name.as_str(), l.value.as_str()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1000, timeseries.samples[0].timestamp);
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! States of alerting rules.
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, SystemTime};
+
+use serde::Serialize;
+
+use crate::ruler::rule::Rule;
+use crate::ruler::Sample;
+
+/// Label that carries the alert's name.
+const ALERT_NAME_LABEL: &str = "alertname";
+
+/// How long a resolved alert is kept to be resent if it can't be delivered.
+const RESOLVED_RETENTION: Duration = Duration::from_secs(15 * 60);
+
+pub type Labels = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertState {
+    /// The alert is active but not long enough to reach `for` duration.
+    Pending,
+    Firing,
+    /// The alert is resolved. It's sent to Alertmanager until it's delivered, then removed.
+    Inactive,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub state: AlertState,
+    pub labels: Labels,
+    pub annotations: Labels,
+    pub active_at: SystemTime,
+    pub fired_at: Option<SystemTime>,
+    pub resolved_at: Option<SystemTime>,
+}
+
+/// Alert posted to Alertmanager's `/api/v2/alerts`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostableAlert {
+    pub labels: Labels,
+    pub annotations: Labels,
+    /// RFC3339 timestamp
+    pub starts_at: String,
+    /// RFC3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<String>,
+}
+
+impl Alert {
+    fn to_postable(&self) -> PostableAlert {
+        PostableAlert {
+            labels: self.labels.clone(),
+            annotations: self.annotations.clone(),
+            starts_at: rfc3339(self.fired_at.unwrap_or(self.active_at)),
+            ends_at: self.resolved_at.map(rfc3339),
+        }
+    }
+}
+
+fn rfc3339(time: SystemTime) -> String {
+    chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339()
+}
+
+/// In-memory states of one alerting rule, keyed by the labels of the alert.
+#[derive(Debug, Default)]
+pub struct AlertingRuleState {
+    active: HashMap<Labels, Alert>,
+}
+
+impl AlertingRuleState {
+    /// Update the states with the result of one evaluation. Returns alerts that need
+    /// to be sent to Alertmanager, which are all firing alerts and resolved alerts not
+    /// delivered yet.
+    ///
+    /// Resolved alerts are kept until [AlertingRuleState::remove_resolved] is called after
+    /// they are delivered, or they are older than [RESOLVED_RETENTION].
+    pub fn update(
+        &mut self,
+        rule: &Rule,
+        samples: Vec<Sample>,
+        now: SystemTime,
+    ) -> Vec<PostableAlert> {
+        let alert_name = rule.alert.clone().unwrap_or_default();
+        let for_duration = rule.for_duration.unwrap_or(Duration::ZERO);
+
+        let mut seen = Vec::with_capacity(samples.len());
+        for sample in samples {
+            let mut labels = sample.labels;
+            // the metric name is dropped like Prometheus does
+            let _ = labels.remove(servers::prom_store::METRIC_NAME_LABEL);
+            labels.extend(rule.labels.clone());
+            let _ = labels.insert(ALERT_NAME_LABEL.to_string(), alert_name.clone());
+            let annotations = rule
+                .annotations
+                .iter()
+                .map(|(k, v)| (k.clone(), expand_template(v, &labels, sample.value)))
+                .collect();
+
+            let new_alert = || Alert {
+                state: AlertState::Pending,
+                labels: labels.clone(),
+                annotations: Labels::new(),
+                active_at: now,
+                fired_at: None,
+                resolved_at: None,
+            };
+            let alert = self.active.entry(labels.clone()).or_insert_with(new_alert);
+            if alert.state == AlertState::Inactive {
+                // the resolved alert becomes active again
+                *alert = new_alert();
+            }
+            alert.annotations = annotations;
+            if alert.state == AlertState::Pending
+                && now.duration_since(alert.active_at).unwrap_or_default() >= for_duration
+            {
+                alert.state = AlertState::Firing;
+                alert.fired_at = Some(now);
+            }
+            seen.push(labels);
+        }
+
+        let mut to_send = Vec::new();
+        self.active.retain(|labels, alert| {
+            if seen.contains(labels) {
+                if alert.state == AlertState::Firing {
+                    to_send.push(alert.to_postable());
+                }
+                return true;
+            }
+
+            // pending alerts are dropped silently, firing alerts are resolved
+            match alert.state {
+                AlertState::Pending => false,
+                AlertState::Firing => {
+                    alert.state = AlertState::Inactive;
+                    alert.resolved_at = Some(now);
+                    to_send.push(alert.to_postable());
+                    true
+                }
+                AlertState::Inactive => {
+                    let resolved_at = alert.resolved_at.unwrap_or(now);
+                    if now.duration_since(resolved_at).unwrap_or_default() >= RESOLVED_RETENTION {
+                        return false;
+                    }
+                    to_send.push(alert.to_postable());
+                    true
+                }
+            }
+        });
+
+        to_send
+    }
+
+    /// Removes resolved alerts, it should be called after the alerts returned by
+    /// [AlertingRuleState::update] are delivered.
+    pub fn remove_resolved(&mut self) {
+        self.active
+            .retain(|_, alert| alert.state != AlertState::Inactive);
+    }
+
+    #[cfg(test)]
+    fn alerts(&self) -> impl Iterator<Item = &Alert> {
+        self.active.values()
+    }
+}
+
+/// Expand `{{ $labels.<name> }}` and `{{ $value }}` in annotation templates.
+fn expand_template(template: &str, labels: &Labels, value: f64) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let var = rest[start + 2..start + end].trim();
+        if var == "$value" {
+            result.push_str(&value.to_string());
+        } else if let Some(name) = var.strip_prefix("$labels.") {
+            result.push_str(labels.get(name).map(String::as_str).unwrap_or_default());
+        } else {
+            // keep unknown expressions as is
+            result.push_str(&rest[start..start + end + 2]);
+        }
+        rest = &rest[start + end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(instance: &str, value: f64) -> Sample {
+        Sample {
+            labels: Labels::from([
+                ("__name__".to_string(), "up".to_string()),
+                ("instance".to_string(), instance.to_string()),
+            ]),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_expand_template() {
+        let labels = Labels::from([("instance".to_string(), "host1".to_string())]);
+        assert_eq!(
+            "host1 is down, value: 0",
+            expand_template(
+                "{{ $labels.instance }} is down, value: {{$value}}",
+                &labels,
+                0.0
+            )
+        );
+        assert_eq!("{{ .Foo }} ", expand_template("{{ .Foo }} ", &labels, 0.0));
+        assert_eq!("{{ open", expand_template("{{ open", &labels, 0.0));
+    }
+
+    #[test]
+    fn test_alert_state_transition() {
+        let rule = Rule {
+            alert: Some("InstanceDown".to_string()),
+            expr: "up == 0".to_string(),
+            for_duration: Some(Duration::from_secs(60)),
+            annotations: Labels::from([(
+                "summary".to_string(),
+                "{{ $labels.instance }} down".to_string(),
+            )]),
+            ..Default::default()
+        };
+        let mut state = AlertingRuleState::default();
+        let t0 = SystemTime::UNIX_EPOCH;
+
+        // pending
+        assert!(state
+            .update(&rule, vec![sample("host1", 0.0)], t0)
+            .is_empty());
+        assert_eq!(AlertState::Pending, state.alerts().next().unwrap().state);
+
+        // firing after `for`
+        let t1 = t0 + Duration::from_secs(60);
+        let alerts = state.update(&rule, vec![sample("host1", 0.0)], t1);
+        assert_eq!(1, alerts.len());
+        assert_eq!("InstanceDown", alerts[0].labels["alertname"]);
+        assert!(!alerts[0].labels.contains_key("__name__"));
+        assert_eq!("host1 down", alerts[0].annotations["summary"]);
+        assert!(alerts[0].ends_at.is_none());
+
+        // resolved, and resent until delivered
+        let t2 = t1 + Duration::from_secs(60);
+        let alerts = state.update(&rule, vec![], t2);
+        assert_eq!(1, alerts.len());
+        assert!(alerts[0].ends_at.is_some());
+        assert_eq!(AlertState::Inactive, state.alerts().next().unwrap().state);
+        let resent = state.update(&rule, vec![], t2 + Duration::from_secs(60));
+        assert_eq!(alerts, resent);
+        state.remove_resolved();
+        assert_eq!(0, state.alerts().count());
+
+        // pending alerts are dropped silently
+        assert!(state
+            .update(&rule, vec![sample("host2", 0.0)], t2)
+            .is_empty());
+        assert!(state.update(&rule, vec![], t2).is_empty());
+    }
+
+    #[test]
+    fn test_resolved_alert() {
+        let rule = Rule {
+            alert: Some("InstanceDown".to_string()),
+            expr: "up == 0".to_string(),
+            ..Default::default()
+        };
+        let mut state = AlertingRuleState::default();
+        let t0 = SystemTime::UNIX_EPOCH;
+        assert_eq!(1, state.update(&rule, vec![sample("host1", 0.0)], t0).len());
+        assert_eq!(1, state.update(&rule, vec![], t0).len());
+
+        // the resolved alert fires again
+        let t1 = t0 + Duration::from_secs(60);
+        let alerts = state.update(&rule, vec![sample("host1", 0.0)], t1);
+        assert_eq!(1, alerts.len());
+        assert!(alerts[0].ends_at.is_none());
+        assert_eq!(AlertState::Firing, state.alerts().next().unwrap().state);
+
+        // resolved alerts are dropped after the retention
+        assert_eq!(1, state.update(&rule, vec![], t1).len());
+        let t2 = t1 + RESOLVED_RETENTION;
+        assert!(state.update(&rule, vec![], t2).is_empty());
+        assert_eq!(0, state.alerts().count());
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sends alerts to Alertmanager-compatible webhooks.
+
+use snafu::ResultExt;
+
+use crate::error::{Result, SendAlertsSnafu};
+use crate::ruler::alert::PostableAlert;
+
+const ALERTS_API_PATH: &str = "/api/v2/alerts";
+
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl Notifier {
+    pub fn new(alertmanager_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: format!(
+                "{}{ALERTS_API_PATH}",
+                alertmanager_url.trim_end_matches('/')
+            ),
+        }
+    }
+
+    pub async fn send(&self, alerts: &[PostableAlert]) -> Result<()> {
+        if alerts.is_empty() {
+            return Ok(());
+        }
+
+        let _ = self
+            .client
+            .post(&self.url)
+            .json(alerts)
+            .send()
+            .await
+            .and_then(|resp| resp.error_for_status())
+            .context(SendAlertsSnafu { url: &self.url })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerts_url() {
+        assert_eq!(
+            "http://127.0.0.1:9093/api/v2/alerts",
+            Notifier::new("http://127.0.0.1:9093/").url
+        );
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Definitions of Prometheus rule group files.
+//!
+//! See <https://prometheus.io/docs/prometheus/latest/configuration/recording_rules/>.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use snafu::{ensure, ResultExt};
+
+use crate::error::{InvalidRuleSnafu, ParseRuleFileSnafu, ReadRuleFileSnafu, Result};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleGroups {
+    pub groups: Vec<RuleGroup>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleGroup {
+    pub name: String,
+    /// Evaluation interval of this group, falls back to the global one if not set.
+    #[serde(default, with = "humantime_serde")]
+    pub interval: Option<Duration>,
+    /// Limit the number of alerts or series a rule can produce. 0 is no limit.
+    #[serde(default)]
+    pub limit: usize,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Name of the time series to output to. Only set for recording rules.
+    pub record: Option<String>,
+    /// Name of the alert. Only set for alerting rules.
+    pub alert: Option<String>,
+    pub expr: String,
+    /// Alerts are considered firing once they have been returned for this long.
+    #[serde(default, rename = "for", with = "humantime_serde")]
+    pub for_duration: Option<Duration>,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+impl RuleGroups {
+    /// Load rule groups from YAML file and validate them.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path).context(ReadRuleFileSnafu { path })?;
+        let groups: RuleGroups =
+            serde_yaml::from_str(&content).context(ParseRuleFileSnafu { path })?;
+        groups.validate()?;
+        Ok(groups)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let mut names = std::collections::HashSet::with_capacity(self.groups.len());
+        for group in &self.groups {
+            ensure!(
+                names.insert(&group.name),
+                InvalidRuleSnafu {
+                    group: &group.name,
+                    reason: "duplicated group name",
+                }
+            );
+
+            for rule in &group.rules {
+                match (&rule.record, &rule.alert) {
+                    (Some(_), None) => ensure!(
+                        rule.for_duration.is_none() && rule.annotations.is_empty(),
+                        InvalidRuleSnafu {
+                            group: &group.name,
+                            reason: format!(
+                                "recording rule {:?} can't have `for` or annotations",
+                                rule.record
+                            ),
+                        }
+                    ),
+                    (None, Some(_)) => {}
+                    _ => {
+                        return InvalidRuleSnafu {
+                            group: &group.name,
+                            reason: format!(
+                                "exactly one of `record` and `alert` must be set, expr: {}",
+                                rule.expr
+                            ),
+                        }
+                        .fail()
+                    }
+                }
+
+                if let Err(reason) = promql_parser::parser::parse(&rule.expr) {
+                    return InvalidRuleSnafu {
+                        group: &group.name,
+                        reason: format!("invalid expr {}: {reason}", rule.expr),
+                    }
+                    .fail();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule_groups() {
+        let yaml = r#"
+groups:
+  - name: example
+    interval: 30s
+    rules:
+      - record: job:http_inprogress_requests:sum
+        expr: sum by (job) (http_inprogress_requests)
+      - alert: HighRequestLatency
+        expr: job:request_latency_seconds:mean5m{job="myjob"} > 0.5
+        for: 10m
+        labels:
+          severity: page
+        annotations:
+          summary: High request latency on {{ $labels.instance }}
+"#;
+        let groups: RuleGroups = serde_yaml::from_str(yaml).unwrap();
+        groups.validate().unwrap();
+
+        let group = &groups.groups[0];
+        assert_eq!(Some(Duration::from_secs(30)), group.interval);
+        assert!(group.rules[0].alert.is_none());
+        assert!(group.rules[1].alert.is_some());
+        assert_eq!(Some(Duration::from_secs(600)), group.rules[1].for_duration);
+        assert_eq!("page", group.rules[1].labels["severity"]);
+    }
+
+    #[test]
+    fn test_validate_rule_groups() {
+        let yaml = r#"
+groups:
+  - name: example
+    rules:
+      - record: a
+        alert: b
+        expr: up
+"#;
+        let groups: RuleGroups = serde_yaml::from_str(yaml).unwrap();
+        assert!(groups.validate().is_err());
+
+        let yaml = r#"
+groups:
+  - name: example
+    rules:
+      - record: a
+        expr: sum(up
+"#;
+        let groups: RuleGroups = serde_yaml::from_str(yaml).unwrap();
+        assert!(groups.validate().is_err());
+    }
+}
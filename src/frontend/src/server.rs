@@ -22,6 +22,7 @@ use common_runtime::Builder as RuntimeBuilder;
 use common_telemetry::info;
 use servers::configurator::ConfiguratorRef;
 use servers::error::Error::InternalIo;
+use servers::grpc::flight_sql::FlightSqlHandler;
 use servers::grpc::GrpcServer;
 use servers::http::HttpServerBuilder;
 use servers::metrics_handler::MetricsHandler;
@@ -76,7 +77,12 @@ impl Services {
                 None,
                 user_provider.clone(),
                 grpc_runtime,
-            );
+            )
+            .with_flight_sql_handler(FlightSqlHandler::new(
+                ServerSqlQueryHandlerAdaptor::arc(instance.clone()),
+                instance.clone(),
+                user_provider.clone(),
+            ));
 
             result.push((Box::new(grpc_server), grpc_addr));
         }
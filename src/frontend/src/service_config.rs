@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod audit;
 pub mod datanode;
+pub mod flow;
 pub mod grpc;
 pub mod influxdb;
 pub mod mysql;
@@ -20,7 +22,11 @@ pub mod opentsdb;
 pub mod otlp;
 pub mod postgres;
 pub mod prom_store;
+pub mod ruler;
+pub mod slow_query;
 
+pub use audit::AuditOptions;
+pub use flow::FlowOptions;
 pub use grpc::GrpcOptions;
 pub use influxdb::InfluxdbOptions;
 pub use mysql::MysqlOptions;
@@ -28,5 +34,7 @@ pub use opentsdb::OpentsdbOptions;
 pub use otlp::OtlpOptions;
 pub use postgres::PostgresOptions;
 pub use prom_store::PromStoreOptions;
+pub use ruler::RulerOptions;
+pub use slow_query::SlowQueryOptions;
 
 pub use self::datanode::DatanodeOptions;
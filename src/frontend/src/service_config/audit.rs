@@ -0,0 +1,114 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// Options of the audit log.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditOptions {
+    pub enable: bool,
+    /// Where the audit records are written to.
+    pub sink: AuditSink,
+    /// Directory of the audit log files. The `table` sink spills records it can't
+    /// write to the table to the files.
+    pub dir: String,
+    /// How often a new audit log file is created.
+    pub rotation: AuditLogRotation,
+    /// Database of the audit table, only used by the `table` sink.
+    pub db: String,
+    /// Name of the audit table, only used by the `table` sink. The table is created
+    /// on the first write.
+    pub table: String,
+    /// Classes of statements to audit.
+    pub classes: Vec<StatementClass>,
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            sink: AuditSink::File,
+            dir: "/tmp/greptimedb/audit".to_string(),
+            rotation: AuditLogRotation::Daily,
+            db: "public".to_string(),
+            table: "audit_log".to_string(),
+            classes: vec![
+                StatementClass::Query,
+                StatementClass::Write,
+                StatementClass::Ddl,
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSink {
+    /// JSON lines in rotating files.
+    File,
+    /// Rows in a table of this database.
+    Table,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditLogRotation {
+    Minutely,
+    Hourly,
+    Daily,
+    Never,
+}
+
+/// Class of the audited statements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatementClass {
+    /// Reads, e.g. `SELECT`, `SHOW`, `DESCRIBE`, `TQL` and PromQL queries.
+    Query,
+    /// Writes, e.g. `INSERT`, `DELETE`, `COPY FROM` and gRPC inserts and deletes.
+    Write,
+    /// Schema changes, e.g. `CREATE`, `ALTER`, `DROP` and `TRUNCATE`, and administrative
+    /// statements such as `KILL`.
+    Ddl,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_options() {
+        let default = AuditOptions::default();
+        assert!(!default.enable);
+        assert_eq!(AuditSink::File, default.sink);
+        assert_eq!(3, default.classes.len());
+
+        let toml_string = r#"
+            enable = true
+            sink = "table"
+            rotation = "hourly"
+            classes = ["write", "ddl"]
+        "#;
+        let opts: AuditOptions = toml::from_str(toml_string).unwrap();
+        assert!(opts.enable);
+        assert_eq!(AuditSink::Table, opts.sink);
+        assert_eq!(AuditLogRotation::Hourly, opts.rotation);
+        assert_eq!(
+            vec![StatementClass::Write, StatementClass::Ddl],
+            opts.classes
+        );
+        assert_eq!("audit_log", opts.table);
+    }
+}
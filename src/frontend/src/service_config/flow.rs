@@ -0,0 +1,72 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Options of continuous aggregation flows.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlowOptions {
+    /// Whether this frontend evaluates flows.
+    pub enable: bool,
+    /// How often all flows are evaluated.
+    #[serde(with = "humantime_serde")]
+    pub evaluation_interval: Duration,
+    /// How long a window waits for late rows after its align timestamp before it's
+    /// evaluated. Rows arriving later are not included in the results.
+    #[serde(with = "humantime_serde")]
+    pub allowed_lateness: Duration,
+    /// The maximum number of windows a flow evaluates in one query. Windows behind are
+    /// evaluated by more queries, e.g., the first evaluation over existing rows.
+    pub max_windows_per_query: usize,
+}
+
+impl Default for FlowOptions {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            evaluation_interval: Duration::from_secs(60),
+            allowed_lateness: Duration::from_secs(0),
+            max_windows_per_query: 100,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_options() {
+        let default = FlowOptions::default();
+        assert!(default.enable);
+        assert_eq!(Duration::from_secs(60), default.evaluation_interval);
+        assert_eq!(Duration::from_secs(0), default.allowed_lateness);
+        assert_eq!(100, default.max_windows_per_query);
+
+        let toml_string = r#"
+            enable = false
+            evaluation_interval = "10s"
+            allowed_lateness = "30s"
+            max_windows_per_query = 24
+        "#;
+        let opts: FlowOptions = toml::from_str(toml_string).unwrap();
+        assert!(!opts.enable);
+        assert_eq!(Duration::from_secs(10), opts.evaluation_interval);
+        assert_eq!(Duration::from_secs(30), opts.allowed_lateness);
+        assert_eq!(24, opts.max_windows_per_query);
+    }
+}
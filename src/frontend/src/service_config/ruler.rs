@@ -0,0 +1,69 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Options of the Prometheus-compatible rule engine.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RulerOptions {
+    pub enable: bool,
+    /// Paths of Prometheus rule group files to load.
+    pub rule_files: Vec<String>,
+    /// Default evaluation interval of rule groups without `interval`.
+    #[serde(with = "humantime_serde")]
+    pub evaluation_interval: Duration,
+    /// Database the rules are evaluated in, recording rule results are written to it too.
+    pub db: String,
+    /// Base URL of the Alertmanager-compatible webhook that receives alerts, e.g.
+    /// `http://127.0.0.1:9093`. Alerts are only kept in memory if it's not set.
+    pub alertmanager_url: Option<String>,
+}
+
+impl Default for RulerOptions {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            rule_files: vec![],
+            evaluation_interval: Duration::from_secs(60),
+            db: "public".to_string(),
+            alertmanager_url: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ruler_options() {
+        let default = RulerOptions::default();
+        assert!(!default.enable);
+        assert_eq!(Duration::from_secs(60), default.evaluation_interval);
+
+        let toml_string = r#"
+            enable = true
+            rule_files = ["/etc/greptimedb/rules.yml"]
+            evaluation_interval = "30s"
+            alertmanager_url = "http://127.0.0.1:9093"
+        "#;
+        let opts: RulerOptions = toml::from_str(toml_string).unwrap();
+        assert!(opts.enable);
+        assert_eq!(Duration::from_secs(30), opts.evaluation_interval);
+        assert_eq!("public", opts.db);
+    }
+}
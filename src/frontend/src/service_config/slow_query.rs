@@ -0,0 +1,61 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Options of the slow query log.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlowQueryOptions {
+    pub enable: bool,
+    /// Queries that take at least this long are logged.
+    #[serde(with = "humantime_serde")]
+    pub threshold: Duration,
+    /// How many of the most recent slow queries are kept for
+    /// `information_schema.slow_queries`.
+    pub max_records: usize,
+}
+
+impl Default for SlowQueryOptions {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            threshold: Duration::from_secs(5),
+            max_records: 1000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_query_options() {
+        let default = SlowQueryOptions::default();
+        assert!(!default.enable);
+        assert_eq!(Duration::from_secs(5), default.threshold);
+
+        let toml_string = r#"
+            enable = true
+            threshold = "300ms"
+        "#;
+        let opts: SlowQueryOptions = toml::from_str(toml_string).unwrap();
+        assert!(opts.enable);
+        assert_eq!(Duration::from_millis(300), opts.threshold);
+        assert_eq!(1000, opts.max_records);
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The slow query log.
+//!
+//! Every query is timed from the moment its handler starts executing it until its
+//! output is consumed. Queries that take at least the configured threshold are
+//! logged and kept in memory for `information_schema.slow_queries`.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use catalog::information_schema::{SlowQueryEntry, SlowQueryProvider};
+use common_query::Output;
+use common_recordbatch::{OrderOption, RecordBatch, RecordBatchStream, SendableRecordBatchStream};
+use common_telemetry::warn;
+use common_time::util::current_time_millis;
+use datatypes::schema::SchemaRef;
+use futures::Stream;
+use session::context::QueryContextRef;
+use session::resource_usage::{QueryResourceUsage, QueryResourceUsageRef};
+
+use crate::service_config::SlowQueryOptions;
+
+pub type SlowQueryLogRef = Arc<SlowQueryLog>;
+
+/// Keeps the most recent slow queries.
+pub struct SlowQueryLog {
+    threshold: Duration,
+    max_records: usize,
+    records: Mutex<VecDeque<SlowQueryEntry>>,
+}
+
+impl SlowQueryLog {
+    pub fn new(opts: &SlowQueryOptions) -> Self {
+        Self {
+            threshold: opts.threshold,
+            max_records: opts.max_records,
+            records: Mutex::new(VecDeque::with_capacity(opts.max_records)),
+        }
+    }
+
+    /// Starts tracking `query`, which is about to be executed in `query_ctx`.
+    pub fn start(self: &Arc<Self>, query: String, query_ctx: QueryContextRef) -> QueryTracker {
+        let usage = Arc::new(QueryResourceUsage::default());
+        query_ctx.set_resource_usage(Some(usage.clone()));
+        QueryTracker {
+            log: self.clone(),
+            query,
+            query_ctx,
+            usage,
+            start_time: current_time_millis(),
+            start: Instant::now(),
+        }
+    }
+
+    fn record(&self, entry: SlowQueryEntry) {
+        warn!(
+            "Slow query took {:?}, user: {}, protocol: {}, db: {}, region rows: {}, peak memory: {}, query: {}",
+            entry.elapsed,
+            entry.user,
+            entry.protocol,
+            entry.db,
+            entry
+                .region_scans
+                .iter()
+                .map(|(_, usage)| usage.rows)
+                .sum::<usize>(),
+            entry.peak_memory,
+            entry.query
+        );
+
+        if self.max_records == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap();
+        if records.len() >= self.max_records {
+            let _ = records.pop_front();
+        }
+        records.push_back(entry);
+    }
+}
+
+impl SlowQueryProvider for SlowQueryLog {
+    fn slow_queries(&self) -> Vec<SlowQueryEntry> {
+        self.records.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// Tracks one query from its start until its output is consumed.
+pub struct QueryTracker {
+    log: SlowQueryLogRef,
+    query: String,
+    query_ctx: QueryContextRef,
+    usage: QueryResourceUsageRef,
+    start_time: i64,
+    start: Instant,
+}
+
+impl QueryTracker {
+    /// Finishes executing the query. Streamed outputs are tracked until they are
+    /// exhausted or dropped, other results are checked against the threshold now.
+    pub fn finish<E>(self, result: Result<Output, E>) -> Result<Output, E> {
+        // Operators have captured the usage on execution, later statements in this
+        // context must not account to it.
+        self.query_ctx.set_resource_usage(None);
+
+        match result {
+            Ok(Output::Stream(stream)) => Ok(Output::Stream(Box::pin(TrackedStream {
+                stream,
+                tracker: Some(self),
+            }))),
+            result => {
+                self.done();
+                result
+            }
+        }
+    }
+
+    fn done(self) {
+        let elapsed = self.start.elapsed();
+        if elapsed < self.log.threshold {
+            return;
+        }
+
+        let entry = SlowQueryEntry {
+            start_time: self.start_time,
+            elapsed,
+            user: self
+                .query_ctx
+                .current_user()
+                .map(|user| user.username().to_string())
+                .unwrap_or_default(),
+            client_addr: self.query_ctx.client_addr().map(|addr| addr.to_string()),
+            protocol: self.query_ctx.channel().to_string(),
+            db: self.query_ctx.get_db_string(),
+            query: self.query,
+            plan: self.usage.plan(),
+            region_scans: self.usage.region_scans(),
+            peak_memory: self.usage.peak_memory(),
+        };
+        self.log.record(entry);
+    }
+}
+
+/// Finishes the [QueryTracker] when the stream is exhausted or dropped.
+struct TrackedStream {
+    stream: SendableRecordBatchStream,
+    tracker: Option<QueryTracker>,
+}
+
+impl RecordBatchStream for TrackedStream {
+    fn schema(&self) -> SchemaRef {
+        self.stream.schema()
+    }
+
+    fn output_ordering(&self) -> Option<&[OrderOption]> {
+        self.stream.output_ordering()
+    }
+}
+
+impl Stream for TrackedStream {
+    type Item = common_recordbatch::error::Result<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.stream).poll_next(cx);
+        if let Poll::Ready(None) = poll {
+            if let Some(tracker) = self.tracker.take() {
+                tracker.done();
+            }
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl Drop for TrackedStream {
+    fn drop(&mut self) {
+        if let Some(tracker) = self.tracker.take() {
+            tracker.done();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_recordbatch::{util, RecordBatches};
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::{ColumnSchema, Schema};
+    use datatypes::vectors::UInt32Vector;
+    use session::context::QueryContext;
+
+    use super::*;
+
+    fn new_log(threshold: Duration, max_records: usize) -> SlowQueryLogRef {
+        Arc::new(SlowQueryLog::new(&SlowQueryOptions {
+            enable: true,
+            threshold,
+            max_records,
+        }))
+    }
+
+    #[test]
+    fn test_slow_query_log_threshold() {
+        let log = new_log(Duration::from_secs(3600), 10);
+        let query_ctx = QueryContext::arc();
+        let tracker = log.start("SELECT 1".to_string(), query_ctx.clone());
+        assert!(query_ctx.resource_usage().is_some());
+        let _ = tracker.finish::<()>(Ok(Output::AffectedRows(1)));
+        assert!(query_ctx.resource_usage().is_none());
+        assert!(log.slow_queries().is_empty());
+    }
+
+    #[test]
+    fn test_slow_query_log_max_records() {
+        let log = new_log(Duration::ZERO, 2);
+        let query_ctx = QueryContext::arc();
+        for i in 0..3 {
+            let tracker = log.start(format!("INSERT {i}"), query_ctx.clone());
+            let _ = tracker.finish::<()>(Ok(Output::AffectedRows(1)));
+        }
+
+        let queries = log
+            .slow_queries()
+            .into_iter()
+            .map(|entry| entry.query)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["INSERT 1", "INSERT 2"], queries);
+    }
+
+    #[tokio::test]
+    async fn test_slow_query_log_stream() {
+        let log = new_log(Duration::ZERO, 10);
+        let query_ctx = QueryContext::arc();
+        let tracker = log.start("SELECT n FROM t".to_string(), query_ctx.clone());
+        let usage = query_ctx.resource_usage().unwrap();
+        usage.set_plan("TableScan: t".to_string());
+        usage.record_region_scan(1, 3, 12);
+        usage.grow_memory(1024);
+
+        let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+            "n",
+            ConcreteDataType::uint32_datatype(),
+            false,
+        )]));
+        let batch = RecordBatch::new(
+            schema.clone(),
+            vec![Arc::new(UInt32Vector::from_slice([1, 2, 3])) as _],
+        )
+        .unwrap();
+        let batches = RecordBatches::try_new(schema, vec![batch]).unwrap();
+        let output = tracker
+            .finish::<()>(Ok(Output::Stream(batches.as_stream())))
+            .unwrap();
+        // The query is not finished until its output is consumed.
+        assert!(log.slow_queries().is_empty());
+
+        let Output::Stream(stream) = output else {
+            unreachable!()
+        };
+        let batches = util::collect(stream).await.unwrap();
+        assert_eq!(3, batches[0].num_rows());
+
+        let entries = log.slow_queries();
+        assert_eq!(1, entries.len());
+        let entry = &entries[0];
+        assert_eq!("SELECT n FROM t", entry.query);
+        assert_eq!("unknown", entry.protocol);
+        assert_eq!(Some("TableScan: t".to_string()), entry.plan);
+        assert_eq!(1, entry.region_scans.len());
+        assert_eq!(3, entry.region_scans[0].1.rows);
+        assert_eq!(1024, entry.peak_memory);
+    }
+}
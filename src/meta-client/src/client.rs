@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod ask_leader;
+mod cluster;
 mod ddl;
 mod heartbeat;
 mod load_balance;
@@ -21,6 +22,7 @@ mod router;
 mod store;
 
 use api::v1::meta::Role;
+use cluster::Client as ClusterClient;
 use common_error::ext::BoxedError;
 use common_grpc::channel_manager::{ChannelConfig, ChannelManager};
 use common_meta::ddl::{DdlTaskExecutor, ExecutorContext};
@@ -42,6 +44,7 @@ use router::Client as RouterClient;
 use snafu::{OptionExt, ResultExt};
 use store::Client as StoreClient;
 
+pub use self::cluster::DatanodeRegionStat;
 pub use self::heartbeat::{HeartbeatSender, HeartbeatStream};
 use crate::error;
 use crate::error::{ConvertMetaResponseSnafu, Result};
@@ -50,6 +53,7 @@ pub type Id = (u64, u64);
 
 const DEFAULT_ASK_LEADER_MAX_RETRY: usize = 3;
 const DEFAULT_SUBMIT_DDL_MAX_RETRY: usize = 3;
+const DEFAULT_CLUSTER_CLIENT_MAX_RETRY: usize = 3;
 
 #[derive(Clone, Debug, Default)]
 pub struct MetaClientBuilder {
@@ -60,6 +64,7 @@ pub struct MetaClientBuilder {
     enable_store: bool,
     enable_lock: bool,
     enable_ddl: bool,
+    enable_cluster: bool,
     channel_manager: Option<ChannelManager>,
     ddl_channel_manager: Option<ChannelManager>,
 }
@@ -108,6 +113,13 @@ impl MetaClientBuilder {
         }
     }
 
+    pub fn enable_cluster(self) -> Self {
+        Self {
+            enable_cluster: true,
+            ..self
+        }
+    }
+
     pub fn channel_manager(self, channel_manager: ChannelManager) -> Self {
         Self {
             channel_manager: Some(channel_manager),
@@ -152,6 +164,14 @@ impl MetaClientBuilder {
         if self.enable_lock {
             client.lock = Some(LockClient::new(self.id, self.role, mgr.clone()));
         }
+        if self.enable_cluster {
+            client.cluster = Some(ClusterClient::new(
+                self.id,
+                self.role,
+                mgr.clone(),
+                DEFAULT_CLUSTER_CLIENT_MAX_RETRY,
+            ));
+        }
         if self.enable_ddl {
             let mgr = self.ddl_channel_manager.unwrap_or(mgr);
             client.ddl = Some(DdlClient::new(
@@ -175,6 +195,7 @@ pub struct MetaClient {
     store: Option<StoreClient>,
     lock: Option<LockClient>,
     ddl: Option<DdlClient>,
+    cluster: Option<ClusterClient>,
 }
 
 #[async_trait::async_trait]
@@ -230,6 +251,10 @@ impl MetaClient {
             client.start(urls.clone()).await?;
             info!("Lock client started");
         }
+        if let Some(client) = &mut self.cluster {
+            client.start(urls.clone()).await?;
+            info!("Cluster client started");
+        }
         if let Some(client) = &mut self.ddl {
             client.start(urls).await?;
             info!("DDL client started");
@@ -281,6 +306,24 @@ impl MetaClient {
             .context(ConvertMetaResponseSnafu)
     }
 
+    /// Lists the region stats reported by the latest heartbeats of all datanodes.
+    pub async fn list_region_stats(&self) -> Result<Vec<DatanodeRegionStat>> {
+        let key = format!("{}-", cluster::DN_STAT_PREFIX).into_bytes();
+        let range_end = common_meta::util::get_prefix_end_key(&key);
+        let req = api::v1::meta::RangeRequest {
+            key,
+            range_end,
+            ..Default::default()
+        };
+        let res = self.cluster_client()?.range(req).await?;
+
+        let mut region_stats = Vec::new();
+        for kv in res.kvs {
+            region_stats.extend(cluster::decode_region_stats(&kv.value)?);
+        }
+        Ok(region_stats)
+    }
+
     /// Range gets the keys in the range from the key-value store.
     pub async fn range(&self, req: RangeRequest) -> Result<RangeResponse> {
         self.store_client()?
@@ -415,6 +458,13 @@ impl MetaClient {
             .context(error::NotStartedSnafu { name: "ddl_client" })
     }
 
+    #[inline]
+    pub fn cluster_client(&self) -> Result<ClusterClient> {
+        self.cluster.clone().context(error::NotStartedSnafu {
+            name: "cluster_client",
+        })
+    }
+
     #[inline]
     pub fn channel_config(&self) -> &ChannelConfig {
         self.channel_manager.config()
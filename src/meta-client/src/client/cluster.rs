@@ -0,0 +1,270 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use api::v1::meta::cluster_client::ClusterClient;
+use api::v1::meta::{ErrorCode, RangeRequest, RangeResponse, ResponseHeader, Role};
+use common_grpc::channel_manager::ChannelManager;
+use common_telemetry::{info, warn};
+use serde::Deserialize;
+use snafu::{ensure, ResultExt};
+use tokio::sync::RwLock;
+use tonic::transport::Channel;
+use tonic::{Code, Status};
+
+use crate::client::ask_leader::AskLeader;
+use crate::client::Id;
+use crate::error;
+use crate::error::Result;
+
+/// Prefix of the keys of datanode stats in the in-memory kv store of `metasrv`.
+///
+/// Keep it in sync with `meta_srv::keys::DN_STAT_PREFIX`.
+pub(crate) const DN_STAT_PREFIX: &str = "__meta_dnstat";
+
+/// Statistics of a region carried by the heartbeat of its datanode.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct DatanodeRegionStat {
+    #[serde(rename = "id")]
+    pub region_id: u64,
+    /// Approximate bytes of this region.
+    pub approximate_bytes: i64,
+    /// Approximate number of rows in this region.
+    pub approximate_rows: i64,
+    /// Name of the engine the region belongs to.
+    #[serde(default)]
+    pub engine: String,
+}
+
+/// The part of a datanode stat (`meta_srv::handler::node_stat::Stat`) we care about.
+#[derive(Debug, Deserialize)]
+struct DatanodeStat {
+    region_stats: Vec<DatanodeRegionStat>,
+}
+
+/// Decodes the region stats from the value of a datanode stat key, which holds the
+/// stats of the most recent heartbeats.
+pub(crate) fn decode_region_stats(value: &[u8]) -> Result<Vec<DatanodeRegionStat>> {
+    let stats: Vec<DatanodeStat> =
+        serde_json::from_slice(value).context(error::DecodeDatanodeStatSnafu)?;
+    Ok(stats
+        .into_iter()
+        .last()
+        .map(|stat| stat.region_stats)
+        .unwrap_or_default())
+}
+
+/// Client of the cluster service, which reads the in-memory kv store (e.g. the
+/// heartbeat stats of datanodes) of the `metasrv` leader.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: Arc<RwLock<Inner>>,
+}
+
+impl Client {
+    pub fn new(id: Id, role: Role, channel_manager: ChannelManager, max_retry: usize) -> Self {
+        let inner = Arc::new(RwLock::new(Inner {
+            id,
+            role,
+            channel_manager,
+            ask_leader: None,
+            max_retry,
+        }));
+
+        Self { inner }
+    }
+
+    pub async fn start<U, A>(&mut self, urls: A) -> Result<()>
+    where
+        U: AsRef<str>,
+        A: AsRef<[U]>,
+    {
+        let mut inner = self.inner.write().await;
+        inner.start(urls).await
+    }
+
+    pub async fn is_started(&self) -> bool {
+        let inner = self.inner.read().await;
+        inner.is_started()
+    }
+
+    pub async fn range(&self, req: RangeRequest) -> Result<RangeResponse> {
+        let inner = self.inner.read().await;
+        inner.range(req).await
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    id: Id,
+    role: Role,
+    channel_manager: ChannelManager,
+    ask_leader: Option<AskLeader>,
+    max_retry: usize,
+}
+
+impl Inner {
+    async fn start<U, A>(&mut self, urls: A) -> Result<()>
+    where
+        U: AsRef<str>,
+        A: AsRef<[U]>,
+    {
+        ensure!(
+            !self.is_started(),
+            error::IllegalGrpcClientStateSnafu {
+                err_msg: "Cluster client already started",
+            }
+        );
+
+        let peers = urls
+            .as_ref()
+            .iter()
+            .map(|url| url.as_ref().to_string())
+            .collect::<Vec<_>>();
+        self.ask_leader = Some(AskLeader::new(
+            self.id,
+            self.role,
+            peers,
+            self.channel_manager.clone(),
+            self.max_retry,
+        ));
+
+        Ok(())
+    }
+
+    fn make_client(&self, addr: impl AsRef<str>) -> Result<ClusterClient<Channel>> {
+        let channel = self
+            .channel_manager
+            .get(addr)
+            .context(error::CreateChannelSnafu)?;
+
+        Ok(ClusterClient::new(channel))
+    }
+
+    #[inline]
+    fn is_started(&self) -> bool {
+        self.ask_leader.is_some()
+    }
+
+    async fn range(&self, mut req: RangeRequest) -> Result<RangeResponse> {
+        ensure!(
+            self.is_started(),
+            error::IllegalGrpcClientStateSnafu {
+                err_msg: "Cluster client not start"
+            }
+        );
+
+        req.set_header(self.id, self.role);
+        let ask_leader = self.ask_leader.as_ref().unwrap();
+        let mut times = 0;
+
+        while times < self.max_retry {
+            if let Some(leader) = &ask_leader.get_leader() {
+                let mut client = self.make_client(leader)?;
+                match client.range(req.clone()).await {
+                    Ok(res) => {
+                        let res = res.into_inner();
+                        if is_not_leader(&res.header) {
+                            warn!("Failed to range cluster kvs from {leader}, not a leader");
+                            let leader = ask_leader.ask_leader().await?;
+                            info!("Cluster client updated to new leader addr: {leader}");
+                            times += 1;
+                            continue;
+                        }
+                        return Ok(res);
+                    }
+                    Err(status) => {
+                        // The leader may be unreachable.
+                        if is_unreachable(&status) {
+                            warn!("Failed to range cluster kvs from {leader}, source: {status}");
+                            let leader = ask_leader.ask_leader().await?;
+                            info!("Cluster client updated to new leader addr: {leader}");
+                            times += 1;
+                            continue;
+                        } else {
+                            return Err(error::Error::from(status));
+                        }
+                    }
+                }
+            } else if let Err(err) = ask_leader.ask_leader().await {
+                return Err(err);
+            }
+        }
+
+        error::RetryTimesExceededSnafu {
+            msg: "Failed to range cluster kvs",
+            times: self.max_retry,
+        }
+        .fail()
+    }
+}
+
+fn is_unreachable(status: &Status) -> bool {
+    status.code() == Code::Unavailable || status.code() == Code::DeadlineExceeded
+}
+
+fn is_not_leader(header: &Option<ResponseHeader>) -> bool {
+    if let Some(header) = header {
+        if let Some(err) = header.error.as_ref() {
+            return err.code == ErrorCode::NotLeader as i32;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use meta_srv::handler::node_stat::{RegionStat, Stat};
+    use meta_srv::keys::StatValue;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_region_stats() {
+        assert_eq!(meta_srv::keys::DN_STAT_PREFIX, DN_STAT_PREFIX);
+
+        let new_stat = |rows: i64| Stat {
+            id: 1,
+            region_stats: vec![RegionStat {
+                id: 42,
+                approximate_bytes: rows * 10,
+                approximate_rows: rows,
+                engine: "mito".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let value: Vec<u8> = StatValue {
+            stats: vec![new_stat(1), new_stat(2)],
+        }
+        .try_into()
+        .unwrap();
+
+        let stats = decode_region_stats(&value).unwrap();
+        assert_eq!(
+            vec![DatanodeRegionStat {
+                region_id: 42,
+                approximate_bytes: 20,
+                approximate_rows: 2,
+                engine: "mito".to_string(),
+            }],
+            stats
+        );
+
+        assert!(decode_region_stats(b"[]").unwrap().is_empty());
+        assert!(decode_region_stats(b"invalid").is_err());
+    }
+}
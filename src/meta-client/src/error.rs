@@ -68,6 +68,12 @@ pub enum Error {
 
     #[snafu(display("Retry exceeded max times({}), message: {}", times, msg))]
     RetryTimesExceeded { times: usize, msg: String },
+
+    #[snafu(display("Failed to decode datanode stat"))]
+    DecodeDatanodeStat {
+        location: Location,
+        source: serde_json::Error,
+    },
 }
 
 #[allow(dead_code)]
@@ -87,7 +93,8 @@ impl ErrorExt for Error {
             | Error::SendHeartbeat { .. }
             | Error::CreateHeartbeatStream { .. }
             | Error::CreateChannel { .. }
-            | Error::RetryTimesExceeded { .. } => StatusCode::Internal,
+            | Error::RetryTimesExceeded { .. }
+            | Error::DecodeDatanodeStat { .. } => StatusCode::Internal,
 
             Error::MetaServer { code, .. } => *code,
 
@@ -74,4 +74,17 @@ impl CacheInvalidator for MetasrvCacheInvalidator {
         let instruction = Instruction::InvalidateTableNameCache(table_name);
         self.broadcast(ctx, instruction).await
     }
+
+    async fn invalidate_schema(
+        &self,
+        ctx: &Context,
+        catalog: &str,
+        schema: &str,
+    ) -> MetaResult<()> {
+        let instruction = Instruction::InvalidateSchemaCache {
+            catalog: catalog.to_string(),
+            schema: schema.to_string(),
+        };
+        self.broadcast(ctx, instruction).await
+    }
 }
@@ -129,6 +129,7 @@ mod tests {
                 wcus: 0,
                 approximate_bytes: 0,
                 approximate_rows: 0,
+                engine: String::new(),
             }
         }
         acc.stat = Some(Stat {
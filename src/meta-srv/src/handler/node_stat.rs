@@ -49,6 +49,9 @@ pub struct RegionStat {
     pub approximate_bytes: i64,
     /// Approximate number of rows in this region
     pub approximate_rows: i64,
+    /// Name of the engine the region belongs to
+    #[serde(default)]
+    pub engine: String,
 }
 
 impl Stat {
@@ -132,6 +135,7 @@ impl TryFrom<api::v1::meta::RegionStat> for RegionStat {
             wcus: value.wcus,
             approximate_bytes: value.approximate_bytes,
             approximate_rows: value.approximate_rows,
+            engine: value.engine,
         })
     }
 }
@@ -21,6 +21,7 @@ use crate::error;
 use crate::read::projection::ProjectionMapper;
 use crate::read::seq_scan::SeqScan;
 use crate::read::{BoxedBatchReader, Source};
+use crate::region::options::MergeMode;
 use crate::sst::file::{FileHandle, FileId, FileMeta, Level};
 use crate::sst::parquet::{SstInfo, WriteOptions};
 
@@ -44,8 +45,10 @@ impl CompactionOutput {
         schema: RegionMetadataRef,
         sst_layer: AccessLayerRef,
         sst_write_buffer_size: ReadableSize,
+        merge_mode: MergeMode,
     ) -> error::Result<Option<FileMeta>> {
-        let reader = build_sst_reader(schema.clone(), sst_layer.clone(), &self.inputs).await?;
+        let reader =
+            build_sst_reader(schema.clone(), sst_layer.clone(), &self.inputs, merge_mode).await?;
 
         let opts = WriteOptions {
             write_buffer_size: sst_write_buffer_size,
@@ -59,7 +62,7 @@ impl CompactionOutput {
             |SstInfo {
                  time_range,
                  file_size,
-                 ..
+                 num_rows,
              }| {
                 FileMeta {
                     region_id,
@@ -67,6 +70,7 @@ impl CompactionOutput {
                     time_range,
                     level: self.output_level,
                     file_size,
+                    num_rows: num_rows as u64,
                 }
             },
         );
@@ -80,9 +84,11 @@ async fn build_sst_reader(
     schema: RegionMetadataRef,
     sst_layer: AccessLayerRef,
     inputs: &[FileHandle],
+    merge_mode: MergeMode,
 ) -> error::Result<BoxedBatchReader> {
     SeqScan::new(sst_layer, ProjectionMapper::all(&schema)?)
         .with_files(inputs.to_vec())
+        .with_merge_mode(merge_mode)
         .build_reader()
         .await
 }
@@ -34,6 +34,7 @@ use crate::compaction::picker::{CompactionTask, Picker};
 use crate::compaction::CompactionRequest;
 use crate::error;
 use crate::error::CompactRegionSnafu;
+use crate::region::options::MergeMode;
 use crate::request::{
     BackgroundNotify, CompactionFailed, CompactionFinished, OutputTx, WorkerRequest,
 };
@@ -170,6 +171,7 @@ impl Picker for TwcsPicker {
             expired_ssts,
             sst_write_buffer_size: ReadableSize::mb(4),
             compaction_time_window: None,
+            merge_mode: current_version.options.merge_mode,
             request_sender,
             waiters,
             file_purger,
@@ -226,6 +228,8 @@ pub(crate) struct TwcsCompactionTask {
     pub expired_ssts: Vec<FileHandle>,
     pub sst_write_buffer_size: ReadableSize,
     pub compaction_time_window: Option<i64>,
+    /// Mode to merge rows with the same key.
+    pub merge_mode: MergeMode,
     pub file_purger: FilePurgerRef,
     /// Request sender to notify the worker.
     pub(crate) request_sender: mpsc::Sender<WorkerRequest>,
@@ -269,6 +273,7 @@ impl TwcsCompactionTask {
             let schema = self.schema.clone();
             let sst_layer = self.sst_layer.clone();
             let sst_write_buffer_size = self.sst_write_buffer_size;
+            let merge_mode = self.merge_mode;
             compacted_inputs.extend(output.inputs.iter().map(FileHandle::meta));
 
             info!(
@@ -286,7 +291,13 @@ impl TwcsCompactionTask {
             // TODO(hl): Maybe spawn to runtime to exploit in-job parallelism.
             futs.push(async move {
                 output
-                    .build(region_id, schema, sst_layer, sst_write_buffer_size)
+                    .build(
+                        region_id,
+                        schema,
+                        sst_layer,
+                        sst_write_buffer_size,
+                        merge_mode,
+                    )
                     .await
             });
         }
@@ -47,7 +47,7 @@ use object_store::ObjectStore;
 use snafu::{OptionExt, ResultExt};
 use store_api::logstore::LogStore;
 use store_api::metadata::RegionMetadataRef;
-use store_api::region_engine::RegionEngine;
+use store_api::region_engine::{RegionEngine, RegionStatistic};
 use store_api::region_request::RegionRequest;
 use store_api::storage::{RegionId, ScanRequest};
 
@@ -213,6 +213,13 @@ impl RegionEngine for MitoEngine {
             .set_writable(region_id, writable)
             .map_err(BoxedError::new)
     }
+
+    fn region_statistic(&self, region_id: RegionId) -> Option<RegionStatistic> {
+        self.inner
+            .workers
+            .get_region(region_id)
+            .map(|region| region.region_statistic())
+    }
 }
 
 // Tests methods.
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use api::v1::value::ValueData;
 use api::v1::{ColumnDataType, Row, Rows, SemanticType};
@@ -22,12 +23,14 @@ use datatypes::schema::ColumnSchema;
 use store_api::metadata::ColumnMetadata;
 use store_api::region_engine::RegionEngine;
 use store_api::region_request::{
-    AddColumn, AddColumnLocation, AlterKind, RegionAlterRequest, RegionOpenRequest, RegionRequest,
+    AddColumn, AddColumnLocation, AlterKind, ModifyColumnType, RegionAlterRequest,
+    RegionOpenRequest, RegionRequest,
 };
 use store_api::storage::{RegionId, ScanRequest};
 
 use crate::config::MitoConfig;
 use crate::engine::MitoEngine;
+use crate::region::options::CompactionOptions;
 use crate::test_util::{
     build_rows, build_rows_for_key, put_rows, rows_schema, CreateRequestBuilder, TestEnv,
 };
@@ -294,3 +297,180 @@ async fn test_alter_region_retry() {
     assert_eq!(1, version_data.version.flushed_entry_id);
     assert_eq!(2, version_data.version.flushed_sequence);
 }
+
+#[tokio::test]
+async fn test_alter_column_type() {
+    common_telemetry::init_default_ut_logging();
+
+    let mut env = TestEnv::new();
+    let engine = env.create_engine(MitoConfig::default()).await;
+
+    let region_id = RegionId::new(1, 1);
+    let mut request = CreateRequestBuilder::new().build();
+    // Creates field_0 as f32.
+    request.column_metadatas[1].column_schema.data_type = ConcreteDataType::float32_datatype();
+
+    let column_schemas = rows_schema(&request);
+    engine
+        .handle_request(region_id, RegionRequest::Create(request))
+        .await
+        .unwrap();
+
+    let mut rows = build_rows(0, 3);
+    for (i, row) in rows.iter_mut().enumerate() {
+        row.values[1].value_data = Some(ValueData::F32Value(i as f32));
+    }
+    let rows = Rows {
+        schema: column_schemas,
+        rows,
+    };
+    put_rows(&engine, region_id, rows).await;
+
+    let modify_field = |target_type| RegionAlterRequest {
+        schema_version: 0,
+        kind: AlterKind::ModifyColumnTypes {
+            columns: vec![ModifyColumnType {
+                column_name: "field_0".to_string(),
+                target_type,
+            }],
+        },
+    };
+    // Narrowing field_0 from f32 to i32 loses values.
+    engine
+        .handle_request(
+            region_id,
+            RegionRequest::Alter(modify_field(ConcreteDataType::int32_datatype())),
+        )
+        .await
+        .unwrap_err();
+
+    // Widens field_0 from f32 to f64.
+    engine
+        .handle_request(
+            region_id,
+            RegionRequest::Alter(modify_field(ConcreteDataType::float64_datatype())),
+        )
+        .await
+        .unwrap();
+
+    let region = engine.get_region(region_id).unwrap();
+    let metadata = region.metadata();
+    assert_eq!(1, metadata.schema_version);
+    assert_eq!(
+        ConcreteDataType::float64_datatype(),
+        metadata
+            .column_by_name("field_0")
+            .unwrap()
+            .column_schema
+            .data_type
+    );
+
+    // The flushed file still stores f32 values, the reader casts them to f64.
+    let expected = "\
++-------+---------+---------------------+
+| tag_0 | field_0 | ts                  |
++-------+---------+---------------------+
+| 0     | 0.0     | 1970-01-01T00:00:00 |
+| 1     | 1.0     | 1970-01-01T00:00:01 |
+| 2     | 2.0     | 1970-01-01T00:00:02 |
++-------+---------+---------------------+";
+    scan_check_after_alter(&engine, region_id, expected).await;
+}
+
+#[tokio::test]
+async fn test_alter_region_options() {
+    let mut env = TestEnv::new();
+    let engine = env.create_engine(MitoConfig::default()).await;
+
+    let region_id = RegionId::new(1, 1);
+    let request = CreateRequestBuilder::new().build();
+    let region_dir = request.region_dir.clone();
+    engine
+        .handle_request(region_id, RegionRequest::Create(request))
+        .await
+        .unwrap();
+
+    let request = RegionAlterRequest {
+        schema_version: 0,
+        kind: AlterKind::SetRegionOptions {
+            options: HashMap::from([("ttl".to_string(), "7d".to_string())]),
+        },
+    };
+    engine
+        .handle_request(region_id, RegionRequest::Alter(request))
+        .await
+        .unwrap();
+    let region = engine.get_region(region_id).unwrap();
+    let version = region.version();
+    assert_eq!(1, version.metadata.schema_version);
+    assert_eq!(
+        Some(Duration::from_secs(3600 * 24 * 7)),
+        version.options.ttl
+    );
+
+    // Unknown option.
+    let request = RegionAlterRequest {
+        schema_version: 1,
+        kind: AlterKind::SetRegionOptions {
+            options: HashMap::from([("unknown".to_string(), "1".to_string())]),
+        },
+    };
+    engine
+        .handle_request(region_id, RegionRequest::Alter(request))
+        .await
+        .unwrap_err();
+
+    let request = RegionAlterRequest {
+        schema_version: 1,
+        kind: AlterKind::UnsetRegionOptions {
+            keys: vec!["ttl".to_string()],
+        },
+    };
+    engine
+        .handle_request(region_id, RegionRequest::Alter(request))
+        .await
+        .unwrap();
+    let version = region.version();
+    assert_eq!(2, version.metadata.schema_version);
+    assert_eq!(None, version.options.ttl);
+
+    let request = RegionAlterRequest {
+        schema_version: 2,
+        kind: AlterKind::SetRegionOptions {
+            options: HashMap::from([(
+                "compaction.twcs.max_active_window_files".to_string(),
+                "8".to_string(),
+            )]),
+        },
+    };
+    engine
+        .handle_request(region_id, RegionRequest::Alter(request))
+        .await
+        .unwrap();
+
+    // Altered options are persisted and override the options to open the region.
+    let engine = env.reopen_engine(engine, MitoConfig::default()).await;
+    engine
+        .handle_request(
+            region_id,
+            RegionRequest::Open(RegionOpenRequest {
+                engine: String::new(),
+                region_dir,
+                options: HashMap::from([
+                    ("ttl".to_string(), "1d".to_string()),
+                    ("compaction.type".to_string(), "twcs".to_string()),
+                    (
+                        "compaction.twcs.max_active_window_files".to_string(),
+                        "2".to_string(),
+                    ),
+                ]),
+            }),
+        )
+        .await
+        .unwrap();
+    let version = engine.get_region(region_id).unwrap().version();
+    assert_eq!(3, version.metadata.schema_version);
+    assert_eq!(None, version.options.ttl);
+    let CompactionOptions::Twcs(twcs) = &version.options.compaction;
+    assert_eq!(8, twcs.max_active_window_files);
+}
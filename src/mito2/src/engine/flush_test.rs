@@ -68,6 +68,47 @@ async fn test_manual_flush() {
     assert_eq!(expected, batches.pretty_print().unwrap());
 }
 
+#[tokio::test]
+async fn test_region_statistic() {
+    let mut env = TestEnv::new();
+    let engine = env.create_engine(MitoConfig::default()).await;
+
+    let region_id = RegionId::new(1, 1);
+    let request = CreateRequestBuilder::new().build();
+
+    let column_schemas = rows_schema(&request);
+    engine
+        .handle_request(region_id, RegionRequest::Create(request))
+        .await
+        .unwrap();
+
+    let stat = engine.region_statistic(region_id).unwrap();
+    assert_eq!(0, stat.num_rows);
+    assert_eq!(0, stat.sst_size);
+
+    let rows = Rows {
+        schema: column_schemas.clone(),
+        rows: build_rows(0, 3),
+    };
+    put_rows(&engine, region_id, rows).await;
+    let stat = engine.region_statistic(region_id).unwrap();
+    assert_eq!(3, stat.num_rows);
+    assert!(stat.memtable_size > 0);
+    assert_eq!(0, stat.sst_size);
+
+    flush_region(&engine, region_id).await;
+    let rows = Rows {
+        schema: column_schemas,
+        rows: build_rows(3, 5),
+    };
+    put_rows(&engine, region_id, rows).await;
+    let stat = engine.region_statistic(region_id).unwrap();
+    assert_eq!(5, stat.num_rows);
+    assert!(stat.sst_size > 0);
+
+    assert!(engine.region_statistic(RegionId::new(1, 2)).is_none());
+}
+
 #[tokio::test]
 async fn test_flush_engine() {
     let mut env = TestEnv::new();
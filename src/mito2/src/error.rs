@@ -364,6 +364,9 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Invalid region options, reason: {}", reason))]
+    InvalidRegionOptions { reason: String, location: Location },
+
     #[snafu(display(
         "Empty region directory, region_id: {}, region_dir: {}",
         region_id,
@@ -443,7 +446,7 @@ impl ErrorExt for Error {
             CompatReader { .. } => StatusCode::Unexpected,
             InvalidRegionRequest { source, .. } => source.status_code(),
             RegionReadonly { .. } => StatusCode::RegionReadonly,
-            JsonOptions { .. } => StatusCode::InvalidArguments,
+            JsonOptions { .. } | InvalidRegionOptions { .. } => StatusCode::InvalidArguments,
             EmptyRegionDir { .. } => StatusCode::RegionNotFound,
         }
     }
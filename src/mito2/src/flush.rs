@@ -297,6 +297,7 @@ impl RegionFlushTask {
                 time_range: sst_info.time_range,
                 level: 0,
                 file_size: sst_info.file_size,
+                num_rows: sst_info.num_rows as u64,
             });
         }
 
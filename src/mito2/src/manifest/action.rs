@@ -39,10 +39,17 @@ pub enum RegionMetaAction {
     Truncate(RegionTruncate),
 }
 
+/// Region options altered after the region is created, in the order they are altered.
+/// A `None` value resets the option to its default value.
+pub type AlteredOptions = Vec<(String, Option<String>)>;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RegionChange {
     /// The metadata after changed.
     pub metadata: RegionMetadataRef,
+    /// Options altered by this change.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub altered_options: AlteredOptions,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -84,6 +91,10 @@ pub struct RegionManifest {
     pub manifest_version: ManifestVersion,
     /// Last WAL entry id of truncated data.
     pub truncated_entry_id: Option<EntryId>,
+    /// Options altered after the region is created, which override the options
+    /// to open the region.
+    #[serde(default)]
+    pub altered_options: AlteredOptions,
 }
 
 #[derive(Debug, Default)]
@@ -94,6 +105,7 @@ pub struct RegionManifestBuilder {
     flushed_sequence: SequenceNumber,
     manifest_version: ManifestVersion,
     truncated_entry_id: Option<EntryId>,
+    altered_options: AlteredOptions,
 }
 
 impl RegionManifestBuilder {
@@ -107,6 +119,7 @@ impl RegionManifestBuilder {
                 manifest_version: s.manifest_version,
                 flushed_sequence: s.flushed_sequence,
                 truncated_entry_id: s.truncated_entry_id,
+                altered_options: s.altered_options,
             }
         } else {
             Default::default()
@@ -116,6 +129,12 @@ impl RegionManifestBuilder {
     pub fn apply_change(&mut self, manifest_version: ManifestVersion, change: RegionChange) {
         self.metadata = Some(change.metadata);
         self.manifest_version = manifest_version;
+        // Only keeps the last alteration of each option.
+        for (key, value) in change.altered_options {
+            self.altered_options
+                .retain(|(altered_key, _)| *altered_key != key);
+            self.altered_options.push((key, value));
+        }
     }
 
     pub fn apply_edit(&mut self, manifest_version: ManifestVersion, edit: RegionEdit) {
@@ -156,6 +175,7 @@ impl RegionManifestBuilder {
             flushed_sequence: self.flushed_sequence,
             manifest_version: self.manifest_version,
             truncated_entry_id: self.truncated_entry_id,
+            altered_options: self.altered_options,
         })
     }
 }
@@ -273,6 +293,19 @@ mod tests {
             }"#;
         let _ = serde_json::from_str::<RegionChange>(region_change).unwrap();
 
+        let region_change = r#" {
+            "metadata":{
+                "column_metadatas":[
+                {"column_schema":{"name":"a","data_type":{"Int64":{}},"is_nullable":false,"is_time_index":false,"default_constraint":null,"metadata":{}},"semantic_type":"Tag","column_id":1},{"column_schema":{"name":"b","data_type":{"Float64":{}},"is_nullable":false,"is_time_index":false,"default_constraint":null,"metadata":{}},"semantic_type":"Field","column_id":2},{"column_schema":{"name":"c","data_type":{"Timestamp":{"Millisecond":null}},"is_nullable":false,"is_time_index":false,"default_constraint":null,"metadata":{}},"semantic_type":"Timestamp","column_id":3}
+                ],
+                "primary_key":[1],
+                "region_id":5299989648942,
+                "schema_version":1
+            },
+            "altered_options":[["ttl","7d"],["compaction.twcs.time_window",null]]
+            }"#;
+        let _ = serde_json::from_str::<RegionChange>(region_change).unwrap();
+
         let region_remove = r#"{"region_id":42}"#;
         let _ = serde_json::from_str::<RegionRemove>(region_remove).unwrap();
     }
@@ -66,6 +66,7 @@ pub struct RegionManifestOptions {
 /// }
 /// class RegionChange {
 ///     -RegionMetadataRef metadata
+///     -AlteredOptions altered_options
 /// }
 /// class RegionEdit {
 ///     -VersionNumber regoin_version
@@ -204,6 +205,7 @@ impl RegionManifestManagerInner {
             version,
             RegionChange {
                 metadata: metadata.clone(),
+                altered_options: Vec::new(),
             },
         );
         let manifest = manifest_builder.try_build()?;
@@ -215,7 +217,10 @@ impl RegionManifestManagerInner {
 
         // Persist region change.
         let action_list =
-            RegionMetaActionList::with_action(RegionMetaAction::Change(RegionChange { metadata }));
+            RegionMetaActionList::with_action(RegionMetaAction::Change(RegionChange {
+                metadata,
+                altered_options: Vec::new(),
+            }));
         store.save(version, &action_list.encode()?).await?;
 
         Ok(Self {
@@ -531,6 +536,7 @@ mod test {
         let action_list =
             RegionMetaActionList::with_action(RegionMetaAction::Change(RegionChange {
                 metadata: new_metadata.clone(),
+                altered_options: Vec::new(),
             }));
 
         let current_version = manager.update(action_list).await.unwrap();
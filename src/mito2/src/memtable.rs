@@ -34,6 +34,7 @@ use crate::flush::WriteBufferManagerRef;
 pub use crate::memtable::key_values::KeyValues;
 use crate::metrics::WRITE_BUFFER_BYTES;
 use crate::read::Batch;
+use crate::region::options::MergeMode;
 
 /// Id for memtables.
 ///
@@ -46,12 +47,18 @@ pub struct MemtableStats {
     estimated_bytes: usize,
     /// The time range that this memtable contains.
     time_range: Option<(Timestamp, Timestamp)>,
+    /// Number of rows written to this memtable.
+    num_rows: usize,
 }
 
 impl MemtableStats {
     pub fn bytes_allocated(&self) -> usize {
         self.estimated_bytes
     }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
 }
 
 pub type BoxedBatchIterator = Box<dyn Iterator<Item = Result<Batch>> + Send + Sync>;
@@ -83,8 +90,8 @@ pub type MemtableRef = Arc<dyn Memtable>;
 
 /// Builder to build a new [Memtable].
 pub trait MemtableBuilder: Send + Sync + fmt::Debug {
-    /// Builds a new memtable instance.
-    fn build(&self, metadata: &RegionMetadataRef) -> MemtableRef;
+    /// Builds a new memtable instance, rows with the same key are merged by the `merge_mode`.
+    fn build(&self, metadata: &RegionMetadataRef, merge_mode: MergeMode) -> MemtableRef;
 }
 
 pub type MemtableBuilderRef = Arc<dyn MemtableBuilder>;
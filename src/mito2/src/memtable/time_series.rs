@@ -15,7 +15,7 @@
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, Bound, HashSet};
 use std::fmt::{Debug, Formatter};
-use std::sync::atomic::{AtomicI64, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU32, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 
 use api::v1::OpType;
@@ -39,6 +39,7 @@ use crate::memtable::{
     MemtableRef, MemtableStats,
 };
 use crate::read::{Batch, BatchBuilder, BatchColumn};
+use crate::region::options::MergeMode;
 use crate::row_converter::{McmpRowCodec, RowCodec, SortField};
 
 /// Initial vector builder capacity.
@@ -62,12 +63,13 @@ impl TimeSeriesMemtableBuilder {
 }
 
 impl MemtableBuilder for TimeSeriesMemtableBuilder {
-    fn build(&self, metadata: &RegionMetadataRef) -> MemtableRef {
+    fn build(&self, metadata: &RegionMetadataRef, merge_mode: MergeMode) -> MemtableRef {
         let id = self.id.fetch_add(1, Ordering::Relaxed);
         Arc::new(TimeSeriesMemtable::new(
             metadata.clone(),
             id,
             self.write_buffer_manager.clone(),
+            merge_mode,
         ))
     }
 }
@@ -81,6 +83,7 @@ pub struct TimeSeriesMemtable {
     alloc_tracker: AllocTracker,
     max_timestamp: AtomicI64,
     min_timestamp: AtomicI64,
+    num_rows: AtomicUsize,
 }
 
 impl TimeSeriesMemtable {
@@ -88,6 +91,7 @@ impl TimeSeriesMemtable {
         region_metadata: RegionMetadataRef,
         id: MemtableId,
         write_buffer_manager: Option<WriteBufferManagerRef>,
+        merge_mode: MergeMode,
     ) -> Self {
         let row_codec = McmpRowCodec::new(
             region_metadata
@@ -95,7 +99,7 @@ impl TimeSeriesMemtable {
                 .map(|c| SortField::new(c.column_schema.data_type.clone()))
                 .collect(),
         );
-        let series_set = SeriesSet::new(region_metadata.clone());
+        let series_set = SeriesSet::new(region_metadata.clone(), merge_mode);
         Self {
             id,
             region_metadata,
@@ -104,12 +108,14 @@ impl TimeSeriesMemtable {
             alloc_tracker: AllocTracker::new(write_buffer_manager),
             max_timestamp: AtomicI64::new(i64::MIN),
             min_timestamp: AtomicI64::new(i64::MAX),
+            num_rows: AtomicUsize::new(0),
         }
     }
 
     /// Updates memtable stats.
-    fn update_stats(&self, request_size: usize, min: i64, max: i64) {
+    fn update_stats(&self, request_size: usize, num_rows: usize, min: i64, max: i64) {
         self.alloc_tracker.on_allocation(request_size);
+        self.num_rows.fetch_add(num_rows, Ordering::Relaxed);
 
         loop {
             let current_min = self.min_timestamp.load(Ordering::Relaxed);
@@ -196,7 +202,7 @@ impl Memtable for TimeSeriesMemtable {
         // TODO(hl): this maybe inaccurate since for-iteration may return early.
         // We may lift the primary key length check out of Memtable::write
         // so that we can ensure writing to memtable will succeed.
-        self.update_stats(allocated, min_ts, max_ts);
+        self.update_stats(allocated, kvs.num_rows(), min_ts, max_ts);
         Ok(())
     }
 
@@ -229,6 +235,7 @@ impl Memtable for TimeSeriesMemtable {
             return MemtableStats {
                 estimated_bytes,
                 time_range: None,
+                num_rows: 0,
             };
         }
         let ts_type = self
@@ -244,6 +251,7 @@ impl Memtable for TimeSeriesMemtable {
         MemtableStats {
             estimated_bytes,
             time_range: Some((max_timestamp, min_timestamp)),
+            num_rows: self.num_rows.load(Ordering::Relaxed),
         }
     }
 }
@@ -253,13 +261,15 @@ type SeriesRwLockMap = RwLock<BTreeMap<Vec<u8>, Arc<RwLock<Series>>>>;
 struct SeriesSet {
     region_metadata: RegionMetadataRef,
     series: Arc<SeriesRwLockMap>,
+    merge_mode: MergeMode,
 }
 
 impl SeriesSet {
-    fn new(region_metadata: RegionMetadataRef) -> Self {
+    fn new(region_metadata: RegionMetadataRef, merge_mode: MergeMode) -> Self {
         Self {
             region_metadata,
             series: Default::default(),
+            merge_mode,
         }
     }
 }
@@ -291,6 +301,7 @@ impl SeriesSet {
             series: self.series.clone(),
             projection,
             last_key: None,
+            merge_mode: self.merge_mode,
         }
     }
 
@@ -305,6 +316,7 @@ struct Iter {
     series: Arc<SeriesRwLockMap>,
     projection: HashSet<ColumnId>,
     last_key: Option<Vec<u8>>,
+    merge_mode: MergeMode,
 }
 
 impl Iterator for Iter {
@@ -322,7 +334,14 @@ impl Iterator for Iter {
         if let Some((primary_key, series)) = range.next() {
             self.last_key = Some(primary_key.clone());
             let values = series.write().unwrap().compact(&self.metadata);
-            Some(values.and_then(|v| v.to_batch(primary_key, &self.metadata, &self.projection)))
+            Some(values.and_then(|v| {
+                v.to_batch(
+                    primary_key,
+                    &self.metadata,
+                    &self.projection,
+                    self.merge_mode,
+                )
+            }))
         } else {
             None
         }
@@ -494,12 +513,13 @@ struct Values {
 
 impl Values {
     /// Converts [Values] to `Batch`, sorts the batch according to `timestamp, sequence` desc and
-    /// keeps only the latest row for the same timestamp.
+    /// merges rows with the same timestamp by the `merge_mode`.
     pub fn to_batch(
         &self,
         primary_key: &[u8],
         metadata: &RegionMetadataRef,
         projection: &HashSet<ColumnId>,
+        merge_mode: MergeMode,
     ) -> Result<Batch> {
         let builder = BatchBuilder::with_required_columns(
             primary_key.to_vec(),
@@ -520,7 +540,7 @@ impl Values {
             .collect();
 
         let mut batch = builder.with_fields(fields).build()?;
-        batch.sort_and_dedup()?;
+        batch.sort_and_merge(merge_mode)?;
         Ok(batch)
     }
 
@@ -735,7 +755,12 @@ mod tests {
         };
 
         let batch = values
-            .to_batch(b"test", &schema, &[0, 1, 2, 3, 4].into_iter().collect())
+            .to_batch(
+                b"test",
+                &schema,
+                &[0, 1, 2, 3, 4].into_iter().collect(),
+                MergeMode::LastRow,
+            )
             .unwrap();
         check_value(
             &batch,
@@ -894,7 +919,7 @@ mod tests {
         common_telemetry::init_default_ut_logging();
         let schema = schema_for_test();
         let kvs = build_key_values(&schema, "hello".to_string(), 42, 100);
-        let memtable = TimeSeriesMemtable::new(schema, 42, None);
+        let memtable = TimeSeriesMemtable::new(schema, 42, None, MergeMode::LastRow);
         memtable.write(&kvs).unwrap();
 
         let expected_ts = kvs
@@ -925,7 +950,7 @@ mod tests {
         common_telemetry::init_default_ut_logging();
         let schema = schema_for_test();
         let kvs = build_key_values(&schema, "hello".to_string(), 42, 100);
-        let memtable = TimeSeriesMemtable::new(schema, 42, None);
+        let memtable = TimeSeriesMemtable::new(schema, 42, None, MergeMode::LastRow);
         memtable.write(&kvs).unwrap();
 
         let iter = memtable.iter(Some(&[3]), &[]);
@@ -43,6 +43,7 @@ use crate::error::{
     ComputeArrowSnafu, ComputeVectorSnafu, ConvertVectorSnafu, InvalidBatchSnafu, Result,
 };
 use crate::memtable::BoxedBatchIterator;
+use crate::region::options::MergeMode;
 
 /// Storage internal representation of a batch of rows for a primary key (time series).
 ///
@@ -302,6 +303,76 @@ impl Batch {
     /// row for the same timestamp. It doesn't consider op type as sequence
     /// should already provide uniqueness for a row.
     pub fn sort_and_dedup(&mut self) -> Result<()> {
+        self.sort(true)
+    }
+
+    /// Sorts rows in the batch and merges rows with the same timestamp by the `merge_mode`.
+    pub fn sort_and_merge(&mut self, merge_mode: MergeMode) -> Result<()> {
+        match merge_mode {
+            MergeMode::LastRow => self.sort_and_dedup(),
+            MergeMode::LastNonNull => {
+                self.sort(false)?;
+                self.merge_last_non_null()
+            }
+        }
+    }
+
+    /// Merges rows with the same timestamp into the latest row. Null fields of the latest row
+    /// are filled by the latest non-null values of older rows, rows older than a delete are
+    /// ignored as they are deleted.
+    ///
+    /// Rows in the batch must be ordered by timestamp, sequence desc.
+    pub(crate) fn merge_last_non_null(&mut self) -> Result<()> {
+        let num_rows = self.num_rows();
+        // Index of the latest row for each timestamp.
+        let mut indices = Vec::with_capacity(num_rows);
+        // Indices of rows to take values from for each field.
+        let mut field_indices = vec![Vec::with_capacity(num_rows); self.fields.len()];
+        let mut has_duplications = false;
+        let mut start = 0;
+        while start < num_rows {
+            let timestamp = self.get_timestamp(start);
+            let mut end = start + 1;
+            while end < num_rows && self.get_timestamp(end) == timestamp {
+                end += 1;
+            }
+            has_duplications |= end - start > 1;
+
+            indices.push(start as u32);
+            for (column, field_index) in self.fields.iter().zip(field_indices.iter_mut()) {
+                let index = (start..end)
+                    .take_while(|i| !self.is_deleted(*i))
+                    .find(|i| !column.data.is_null(*i))
+                    .unwrap_or(start);
+                field_index.push(index as u32);
+            }
+            start = end;
+        }
+        if !has_duplications {
+            return Ok(());
+        }
+
+        let fields = std::mem::take(&mut self.fields)
+            .into_iter()
+            .zip(field_indices)
+            .map(|(column, field_index)| {
+                let field_index = UInt32Vector::from_vec(field_index);
+                Ok(BatchColumn {
+                    column_id: column.column_id,
+                    data: column.data.take(&field_index).context(ComputeVectorSnafu)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Fields are taken, so only the required columns are taken here.
+        self.take_in_place(&UInt32Vector::from_vec(indices))?;
+        self.fields = fields;
+
+        Ok(())
+    }
+
+    /// Sorts rows in the batch by timestamp, sequence desc. Only keeps the latest row for
+    /// the same timestamp if `dedup` is true.
+    fn sort(&mut self, dedup: bool) -> Result<()> {
         // If building a converter each time is costly, we may allow passing a
         // converter.
         let mut converter = RowConverter::new(vec![
@@ -324,14 +395,16 @@ impl Batch {
         let mut to_sort: Vec<_> = rows.iter().enumerate().collect();
         to_sort.sort_unstable_by(|left, right| left.1.cmp(&right.1));
 
-        // Dedup by timestamps.
-        to_sort.dedup_by(|left, right| {
-            debug_assert_eq!(18, left.1.as_ref().len());
-            debug_assert_eq!(18, right.1.as_ref().len());
-            let (left_key, right_key) = (left.1.as_ref(), right.1.as_ref());
-            // We only compare the timestamp part and ignore sequence.
-            left_key[..TIMESTAMP_KEY_LEN] == right_key[..TIMESTAMP_KEY_LEN]
-        });
+        if dedup {
+            // Dedup by timestamps.
+            to_sort.dedup_by(|left, right| {
+                debug_assert_eq!(18, left.1.as_ref().len());
+                debug_assert_eq!(18, right.1.as_ref().len());
+                let (left_key, right_key) = (left.1.as_ref(), right.1.as_ref());
+                // We only compare the timestamp part and ignore sequence.
+                left_key[..TIMESTAMP_KEY_LEN] == right_key[..TIMESTAMP_KEY_LEN]
+            });
+        }
 
         let indices = UInt32Vector::from_iter_values(to_sort.iter().map(|v| v.0 as u32));
         self.take_in_place(&indices)
@@ -398,6 +471,14 @@ impl Batch {
         // Safety: sequences is not null so it actually returns Some.
         self.sequences.get_data(index).unwrap()
     }
+
+    /// Returns whether the row at given `index` is a delete.
+    ///
+    /// # Panics
+    /// Panics if `index` is out-of-bound.
+    fn is_deleted(&self, index: usize) -> bool {
+        self.op_types.get_data(index) == Some(OpType::Delete as u8)
+    }
 }
 
 /// Len of timestamp in arrow row format.
@@ -632,6 +713,8 @@ impl<T: BatchReader + ?Sized> BatchReader for Box<T> {
 
 #[cfg(test)]
 mod tests {
+    use datatypes::arrow::array::{TimestampMillisecondArray, UInt64Array, UInt8Array};
+
     use super::*;
     use crate::error::Error;
     use crate::test_util::new_batch_builder;
@@ -872,4 +955,100 @@ mod tests {
         );
         assert_eq!(expect, batch);
     }
+
+    fn new_nullable_batch(
+        timestamps: &[i64],
+        sequences: &[u64],
+        op_types: &[OpType],
+        fields: &[&[Option<u64>]],
+    ) -> Batch {
+        let mut builder = BatchBuilder::new(b"test".to_vec());
+        builder
+            .timestamps_array(Arc::new(TimestampMillisecondArray::from_iter_values(
+                timestamps.iter().copied(),
+            )))
+            .unwrap()
+            .sequences_array(Arc::new(UInt64Array::from_iter_values(
+                sequences.iter().copied(),
+            )))
+            .unwrap()
+            .op_types_array(Arc::new(UInt8Array::from_iter_values(
+                op_types.iter().map(|v| *v as u8),
+            )))
+            .unwrap();
+        for (i, field) in fields.iter().enumerate() {
+            builder
+                .push_field_array(
+                    i as ColumnId + 1,
+                    Arc::new(UInt64Array::from(field.to_vec())),
+                )
+                .unwrap();
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_sort_and_merge_last_non_null() {
+        let mut batch = new_nullable_batch(
+            &[2, 1, 2, 2, 3],
+            &[1, 2, 3, 4, 5],
+            &[OpType::Put; 5],
+            &[
+                &[Some(21), Some(11), None, None, Some(31)],
+                &[Some(22), None, Some(23), None, None],
+            ],
+        );
+        batch.sort_and_merge(MergeMode::LastNonNull).unwrap();
+        // Timestamp 2 keeps the latest row and fills its null fields by older rows.
+        let expect = new_nullable_batch(
+            &[1, 2, 3],
+            &[2, 4, 5],
+            &[OpType::Put; 3],
+            &[&[Some(11), Some(21), Some(31)], &[None, Some(23), None]],
+        );
+        assert_eq!(expect, batch);
+
+        let mut batch = new_nullable_batch(
+            &[2, 1, 2, 2, 3],
+            &[1, 2, 3, 4, 5],
+            &[OpType::Put; 5],
+            &[
+                &[Some(21), Some(11), None, None, Some(31)],
+                &[Some(22), None, Some(23), None, None],
+            ],
+        );
+        batch.sort_and_merge(MergeMode::LastRow).unwrap();
+        let expect = new_nullable_batch(
+            &[1, 2, 3],
+            &[2, 4, 5],
+            &[OpType::Put; 3],
+            &[&[Some(11), None, Some(31)], &[None, None, None]],
+        );
+        assert_eq!(expect, batch);
+    }
+
+    #[test]
+    fn test_merge_last_non_null_with_delete() {
+        // Rows of timestamp 1 are ordered by sequence desc, the put at sequence 1 is deleted.
+        let mut batch = new_nullable_batch(
+            &[1, 1, 1, 2, 2],
+            &[3, 2, 1, 5, 4],
+            &[
+                OpType::Put,
+                OpType::Delete,
+                OpType::Put,
+                OpType::Delete,
+                OpType::Put,
+            ],
+            &[&[None, None, Some(11), None, Some(21)]],
+        );
+        batch.merge_last_non_null().unwrap();
+        let expect = new_nullable_batch(
+            &[1, 2],
+            &[3, 5],
+            &[OpType::Put, OpType::Delete],
+            &[&[None, None]],
+        );
+        assert_eq!(expect, batch);
+    }
 }
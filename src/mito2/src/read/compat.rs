@@ -16,13 +16,14 @@
 
 use std::collections::HashMap;
 
+use datatypes::prelude::ConcreteDataType;
 use datatypes::value::Value;
 use datatypes::vectors::VectorRef;
 use snafu::{ensure, OptionExt, ResultExt};
 use store_api::metadata::{RegionMetadata, RegionMetadataRef};
 use store_api::storage::ColumnId;
 
-use crate::error::{CompatReaderSnafu, CreateDefaultSnafu, Result};
+use crate::error::{CompatReaderSnafu, ComputeVectorSnafu, CreateDefaultSnafu, Result};
 use crate::read::projection::ProjectionMapper;
 use crate::read::{Batch, BatchColumn, BatchReader};
 use crate::row_converter::{McmpRowCodec, RowCodec, SortField};
@@ -69,7 +70,7 @@ impl<R: BatchReader> BatchReader for CompatReader<R> {
             batch = compat_pk.compat(batch)?;
         }
         if let Some(compat_fields) = &self.compat_fields {
-            batch = compat_fields.compat(batch);
+            batch = compat_fields.compat(batch)?;
         }
 
         Ok(Some(batch))
@@ -78,20 +79,18 @@ impl<R: BatchReader> BatchReader for CompatReader<R> {
 
 /// Returns true if `left` and `right` have same columns to read.
 ///
-/// It only consider column ids.
+/// It considers column ids and data types as the type of a field column might be modified.
 pub(crate) fn has_same_columns(left: &RegionMetadata, right: &RegionMetadata) -> bool {
     if left.column_metadatas.len() != right.column_metadatas.len() {
         return false;
     }
 
     for (left_col, right_col) in left.column_metadatas.iter().zip(&right.column_metadatas) {
-        if left_col.column_id != right_col.column_id {
+        if left_col.column_id != right_col.column_id
+            || left_col.column_schema.data_type != right_col.column_schema.data_type
+        {
             return false;
         }
-        debug_assert_eq!(
-            left_col.column_schema.data_type,
-            right_col.column_schema.data_type
-        );
         debug_assert_eq!(left_col.semantic_type, right_col.semantic_type);
     }
 
@@ -134,8 +133,7 @@ struct CompatFields {
 
 impl CompatFields {
     /// Make fields of the `batch` compatible.
-    #[must_use]
-    fn compat(&self, batch: Batch) -> Batch {
+    fn compat(&self, batch: Batch) -> Result<Batch> {
         debug_assert_eq!(self.actual_fields.len(), batch.fields().len());
         debug_assert!(self
             .actual_fields
@@ -148,22 +146,30 @@ impl CompatFields {
             .index_or_defaults
             .iter()
             .map(|index_or_default| match index_or_default {
-                IndexOrDefault::Index(index) => batch.fields()[*index].clone(),
+                IndexOrDefault::Index(index) => Ok(batch.fields()[*index].clone()),
+                IndexOrDefault::Cast { index, to_type } => {
+                    let column = &batch.fields()[*index];
+                    let data = column.data.cast(to_type).context(ComputeVectorSnafu)?;
+                    Ok(BatchColumn {
+                        column_id: column.column_id,
+                        data,
+                    })
+                }
                 IndexOrDefault::DefaultValue {
                     column_id,
                     default_vector,
                 } => {
                     let data = default_vector.replicate(&[len]);
-                    BatchColumn {
+                    Ok(BatchColumn {
                         column_id: *column_id,
                         data,
-                    }
+                    })
                 }
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
 
         // Safety: We ensure all columns have the same length and the new batch should be valid.
-        batch.with_fields(fields).unwrap()
+        Ok(batch.with_fields(fields).unwrap())
     }
 }
 
@@ -233,7 +239,11 @@ fn may_compat_fields(
 ) -> Result<Option<CompatFields>> {
     let expect_fields = mapper.batch_fields();
     let actual_fields = Batch::projected_fields(actual, mapper.column_ids());
-    if expect_fields == actual_fields {
+    if expect_fields == actual_fields
+        && expect_fields
+            .iter()
+            .all(|column_id| field_type_to_cast(mapper, actual, *column_id).is_none())
+    {
         return Ok(None);
     }
 
@@ -248,7 +258,13 @@ fn may_compat_fields(
         .map(|column_id| {
             if let Some(index) = source_field_index.get(column_id) {
                 // Source has this field.
-                Ok(IndexOrDefault::Index(*index))
+                match field_type_to_cast(mapper, actual, *column_id) {
+                    Some(to_type) => Ok(IndexOrDefault::Cast {
+                        index: *index,
+                        to_type,
+                    }),
+                    None => Ok(IndexOrDefault::Index(*index)),
+                }
             } else {
                 // Safety: mapper must have this column.
                 let column = mapper.metadata().column_by_id(*column_id).unwrap();
@@ -281,11 +297,36 @@ fn may_compat_fields(
     }))
 }
 
+/// Returns the expected type of the field if its type in `actual` is different.
+fn field_type_to_cast(
+    mapper: &ProjectionMapper,
+    actual: &RegionMetadata,
+    column_id: ColumnId,
+) -> Option<ConcreteDataType> {
+    // Safety: mapper must have this column.
+    let expect_type = &mapper
+        .metadata()
+        .column_by_id(column_id)
+        .unwrap()
+        .column_schema
+        .data_type;
+    let actual_type = &actual.column_by_id(column_id)?.column_schema.data_type;
+    (expect_type != actual_type).then(|| expect_type.clone())
+}
+
 /// Index in source batch or a default value to fill a column.
 #[derive(Debug)]
 enum IndexOrDefault {
     /// Index of the column in source batch.
     Index(usize),
+    /// Index of the column in source batch whose type is modified, the column
+    /// needs to be cast to the expected type.
+    Cast {
+        /// Index of the column in source batch.
+        index: usize,
+        /// Type to cast to.
+        to_type: ConcreteDataType,
+    },
     /// Default value for the column.
     DefaultValue {
         /// Id of the column.
@@ -303,8 +344,11 @@ mod tests {
     use datatypes::prelude::ConcreteDataType;
     use datatypes::schema::ColumnSchema;
     use datatypes::value::ValueRef;
-    use datatypes::vectors::{Int64Vector, TimestampMillisecondVector, UInt64Vector, UInt8Vector};
+    use datatypes::vectors::{
+        Float64Vector, Int64Vector, TimestampMillisecondVector, UInt64Vector, UInt8Vector,
+    };
     use store_api::metadata::{ColumnMetadata, RegionMetadataBuilder};
+    use store_api::region_request::{AlterKind, ModifyColumnType};
     use store_api::storage::RegionId;
 
     use super::*;
@@ -597,4 +641,41 @@ mod tests {
         )
         .await;
     }
+
+    #[tokio::test]
+    async fn test_compat_reader_modify_column_type() {
+        let reader_meta = new_metadata(
+            &[
+                (0, SemanticType::Timestamp),
+                (1, SemanticType::Tag),
+                (2, SemanticType::Field),
+            ],
+            &[1],
+        );
+        let mut builder = RegionMetadataBuilder::from_existing(reader_meta.clone());
+        builder
+            .alter(AlterKind::ModifyColumnTypes {
+                columns: vec![ModifyColumnType {
+                    column_name: "field_2".to_string(),
+                    target_type: ConcreteDataType::float64_datatype(),
+                }],
+            })
+            .unwrap();
+        let expect_meta = Arc::new(builder.build().unwrap());
+        let reader_meta = Arc::new(reader_meta);
+        assert!(!has_same_columns(&expect_meta, &reader_meta));
+
+        let mapper = ProjectionMapper::all(&expect_meta).unwrap();
+        let k1 = encode_key(&[Some("a")]);
+        let source_reader = VecBatchReader::new(&[new_batch(&k1, &[(2, false)], 1000, 3)]);
+
+        let mut compat_reader = CompatReader::new(&mapper, reader_meta, source_reader).unwrap();
+        let expect_batch = new_batch(&k1, &[], 1000, 3)
+            .with_fields(vec![BatchColumn {
+                column_id: 2,
+                data: Arc::new(Float64Vector::from_vec(vec![2.0; 3])),
+            }])
+            .unwrap();
+        check_reader_result(&mut compat_reader, &[expect_batch]).await;
+    }
 }
@@ -23,6 +23,7 @@ use async_trait::async_trait;
 use crate::error::Result;
 use crate::memtable::BoxedBatchIterator;
 use crate::read::{Batch, BatchReader, BoxedBatchReader, Source};
+use crate::region::options::MergeMode;
 
 /// Reader to merge sorted batches.
 ///
@@ -67,8 +68,8 @@ impl BatchReader for MergeReader {
 }
 
 impl MergeReader {
-    /// Creates a new [MergeReader].
-    pub async fn new(sources: Vec<Source>) -> Result<MergeReader> {
+    /// Creates a new [MergeReader] that merges rows with the same key by the `merge_mode`.
+    pub async fn new(sources: Vec<Source>, merge_mode: MergeMode) -> Result<MergeReader> {
         let mut nodes = BinaryHeap::with_capacity(sources.len());
         for source in sources {
             let node = Node::new(source).await?;
@@ -80,7 +81,7 @@ impl MergeReader {
 
         Ok(MergeReader {
             nodes,
-            batch_merger: BatchMerger::new(),
+            batch_merger: BatchMerger::new(merge_mode),
         })
     }
 
@@ -108,6 +109,8 @@ pub struct MergeReaderBuilder {
     ///
     /// All source must yield batches with the same schema.
     sources: Vec<Source>,
+    /// Mode to merge rows with the same key.
+    merge_mode: MergeMode,
 }
 
 impl MergeReaderBuilder {
@@ -128,10 +131,16 @@ impl MergeReaderBuilder {
         self
     }
 
+    /// Sets the mode to merge rows with the same key.
+    pub fn merge_mode(&mut self, merge_mode: MergeMode) -> &mut Self {
+        self.merge_mode = merge_mode;
+        self
+    }
+
     /// Builds and initializes the reader, then resets the builder.
     pub async fn build(&mut self) -> Result<MergeReader> {
         let sources = mem::take(&mut self.sources);
-        MergeReader::new(sources).await
+        MergeReader::new(sources, self.merge_mode).await
     }
 }
 
@@ -141,14 +150,17 @@ struct BatchMerger {
     batches: Vec<Batch>,
     /// Whether the batch buffer is still sorted.
     is_sorted: bool,
+    /// Mode to merge rows with the same key.
+    merge_mode: MergeMode,
 }
 
 impl BatchMerger {
     /// Returns a empty merger.
-    fn new() -> BatchMerger {
+    fn new(merge_mode: MergeMode) -> BatchMerger {
         BatchMerger {
             batches: Vec::new(),
             is_sorted: true, // An empty merger is always sorted.
+            merge_mode,
         }
     }
 
@@ -214,9 +226,13 @@ impl BatchMerger {
         if !self.is_sorted {
             // Slow path. We need to merge overlapping batches. For simplicity, we
             // just sort the all batches and remove duplications.
-            batch.sort_and_dedup()?;
+            batch.sort_and_merge(self.merge_mode)?;
             // We don't need to remove duplications if timestamps of batches
             // are not overlapping.
+        } else if self.merge_mode == MergeMode::LastNonNull {
+            // Sorted batches may still have rows with the same timestamp, we need to
+            // fill their null fields before removing duplications.
+            batch.merge_last_non_null()?;
         }
 
         // Filter rows by op type. Currently, the reader only removes deleted rows but doesn't filter
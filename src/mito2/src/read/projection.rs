@@ -20,7 +20,8 @@ use std::sync::Arc;
 use api::v1::SemanticType;
 use common_error::ext::BoxedError;
 use common_recordbatch::error::ExternalSnafu;
-use common_recordbatch::RecordBatch;
+use common_recordbatch::{OrderOption, RecordBatch};
+use datatypes::arrow::compute::SortOptions;
 use datatypes::prelude::{ConcreteDataType, DataType};
 use datatypes::schema::{Schema, SchemaRef};
 use datatypes::value::ValueRef;
@@ -149,6 +150,37 @@ impl ProjectionMapper {
         self.output_schema.clone()
     }
 
+    /// Returns the ordering of converted [RecordBatch]es if rows are read in
+    /// `primary key, time index` order.
+    ///
+    /// The primary key is encoded in memcomparable format so rows are ordered by
+    /// the tag columns in ascending order with nulls first. The ordering stops at
+    /// the first column not in the projection.
+    ///
+    /// Only string tags are advertised. Their memcomparable bytes are ordered the
+    /// same as the strings, which isn't checked for other types (e.g. `-0.0` and
+    /// `NaN` of floats), so the ordering also stops at the first non-string tag.
+    pub(crate) fn output_ordering(&self) -> Option<Vec<OrderOption>> {
+        let ordering: Vec<_> = self
+            .metadata
+            .primary_key_columns()
+            .chain(std::iter::once(self.metadata.time_index_column()))
+            .map_while(|column| {
+                let comparable = column.semantic_type == SemanticType::Timestamp
+                    || column.column_schema.data_type.is_string();
+                (comparable && self.column_ids.contains(&column.column_id)).then(|| OrderOption {
+                    name: column.column_schema.name.clone(),
+                    options: SortOptions {
+                        descending: false,
+                        nulls_first: true,
+                    },
+                })
+            })
+            .collect();
+
+        (!ordering.is_empty()).then_some(ordering)
+    }
+
     /// Converts a [Batch] to a [RecordBatch].
     ///
     /// The batch must match the `projection` using to build the mapper.
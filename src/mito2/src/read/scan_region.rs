@@ -181,7 +181,8 @@ impl ScanRegion {
             .with_time_range(Some(time_range))
             .with_predicate(Some(predicate))
             .with_memtables(memtables)
-            .with_files(files);
+            .with_files(files)
+            .with_merge_mode(self.version.options.merge_mode);
 
         Ok(seq_scan)
     }
@@ -31,6 +31,7 @@ use crate::read::compat::{self, CompatReader};
 use crate::read::merge::MergeReaderBuilder;
 use crate::read::projection::ProjectionMapper;
 use crate::read::{BatchReader, BoxedBatchReader};
+use crate::region::options::MergeMode;
 use crate::sst::file::FileHandle;
 
 /// Scans a region and returns rows in a sorted sequence.
@@ -49,6 +50,8 @@ pub struct SeqScan {
     memtables: Vec<MemtableRef>,
     /// Handles to SST files to scan.
     files: Vec<FileHandle>,
+    /// Mode to merge rows with the same key.
+    merge_mode: MergeMode,
 }
 
 impl SeqScan {
@@ -62,6 +65,7 @@ impl SeqScan {
             predicate: None,
             memtables: Vec::new(),
             files: Vec::new(),
+            merge_mode: MergeMode::default(),
         }
     }
 
@@ -93,6 +97,13 @@ impl SeqScan {
         self
     }
 
+    /// Set the mode to merge rows with the same key.
+    #[must_use]
+    pub(crate) fn with_merge_mode(mut self, merge_mode: MergeMode) -> Self {
+        self.merge_mode = merge_mode;
+        self
+    }
+
     /// Builds a stream for the query.
     pub async fn build_stream(&self) -> Result<SendableRecordBatchStream> {
         // Scans all memtables and SSTs. Builds a merge reader to merge results.
@@ -105,10 +116,11 @@ impl SeqScan {
                 yield mapper.convert(&batch)?;
             }
         };
-        let stream = Box::pin(RecordBatchStreamAdaptor::new(
-            self.mapper.output_schema(),
-            Box::pin(stream),
-        ));
+        let stream = Box::pin(RecordBatchStreamAdaptor {
+            schema: self.mapper.output_schema(),
+            stream: Box::pin(stream),
+            output_ordering: self.mapper.output_ordering(),
+        });
 
         Ok(stream)
     }
@@ -117,6 +129,7 @@ impl SeqScan {
     pub async fn build_reader(&self) -> Result<BoxedBatchReader> {
         // Scans all memtables and SSTs. Builds a merge reader to merge results.
         let mut builder = MergeReaderBuilder::new();
+        builder.merge_mode(self.merge_mode);
         for mem in &self.memtables {
             // TODO(hl): pass filters once memtable supports filter pushdown.
             let iter = mem.iter(Some(self.mapper.column_ids()), &[]);
@@ -26,6 +26,7 @@ use common_telemetry::info;
 use common_time::util::current_time_millis;
 use snafu::{ensure, OptionExt};
 use store_api::metadata::RegionMetadataRef;
+use store_api::region_engine::RegionStatistic;
 use store_api::storage::RegionId;
 
 use crate::access_layer::AccessLayerRef;
@@ -109,6 +110,38 @@ impl MitoRegion {
     pub(crate) fn set_writable(&self, writable: bool) {
         self.writable.store(writable, Ordering::Relaxed);
     }
+
+    /// Returns the statistic of the region.
+    ///
+    /// The number of rows is approximate as rows in memtables and SSTs may overlap.
+    pub(crate) fn region_statistic(&self) -> RegionStatistic {
+        let version = self.version();
+        let memtables = version.memtables.list_memtables();
+        let (memtable_rows, memtable_size) = memtables
+            .iter()
+            .map(|memtable| memtable.stats())
+            .fold((0, 0), |(rows, bytes), stats| {
+                (
+                    rows + stats.num_rows() as u64,
+                    bytes + stats.bytes_allocated() as u64,
+                )
+            });
+        let (sst_rows, sst_size) = version
+            .ssts
+            .levels()
+            .iter()
+            .flat_map(|level| level.files())
+            .map(|file| file.meta())
+            .fold((0, 0), |(rows, bytes), meta| {
+                (rows + meta.num_rows, bytes + meta.file_size)
+            });
+
+        RegionStatistic {
+            num_rows: memtable_rows + sst_rows,
+            memtable_size,
+            sst_size,
+        }
+    }
 }
 
 /// Regions indexed by ids.
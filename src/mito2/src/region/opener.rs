@@ -136,9 +136,8 @@ impl RegionOpener {
         // Create a manifest manager for this region and writes regions to the manifest file.
         let manifest_manager = RegionManifestManager::new(metadata.clone(), options).await?;
 
-        let mutable = self.memtable_builder.build(&metadata);
-
         let options = RegionOptions::try_from(&self.options)?;
+        let mutable = self.memtable_builder.build(&metadata, options.merge_mode);
         let version = VersionBuilder::new(metadata, mutable)
             .options(options)
             .build();
@@ -211,8 +210,9 @@ impl RegionOpener {
             self.scheduler.clone(),
             access_layer.clone(),
         ));
-        let mutable = self.memtable_builder.build(&metadata);
-        let options = RegionOptions::try_from(&self.options)?;
+        let mut options = RegionOptions::try_from(&self.options)?;
+        options.apply_altered_options(&manifest.altered_options)?;
+        let mutable = self.memtable_builder.build(&metadata, options.merge_mode);
         let version = VersionBuilder::new(metadata, mutable)
             .add_files(file_purger.clone(), manifest.files.values().cloned())
             .flushed_entry_id(manifest.flushed_entry_id)
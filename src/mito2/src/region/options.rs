@@ -20,9 +20,9 @@ use std::time::Duration;
 use serde::Deserialize;
 use serde_json::Value;
 use serde_with::{serde_as, with_prefix, DisplayFromStr};
-use snafu::ResultExt;
+use snafu::{ensure, ResultExt};
 
-use crate::error::{Error, JsonOptionsSnafu, Result};
+use crate::error::{Error, InvalidRegionOptionsSnafu, JsonOptionsSnafu, Result};
 
 /// Options that affect the entire region.
 ///
@@ -35,6 +35,8 @@ pub struct RegionOptions {
     pub ttl: Option<Duration>,
     /// Compaction options.
     pub compaction: CompactionOptions,
+    /// Mode to merge rows with the same primary key and timestamp.
+    pub merge_mode: MergeMode,
 }
 
 impl TryFrom<&HashMap<String, String>> for RegionOptions {
@@ -54,10 +56,131 @@ impl TryFrom<&HashMap<String, String>> for RegionOptions {
         Ok(RegionOptions {
             ttl: options.ttl,
             compaction,
+            merge_mode: options.merge_mode,
         })
     }
 }
 
+impl RegionOptions {
+    /// Sets the option `key` to `value`.
+    ///
+    /// Returns an error if the key is unknown or the value is invalid.
+    pub fn set_option(&mut self, key: &str, value: &str) -> Result<()> {
+        let (key, value) = (key.to_lowercase(), value.to_lowercase());
+        let CompactionOptions::Twcs(twcs) = &mut self.compaction;
+        match key.as_str() {
+            "ttl" => self.ttl = parse_duration(&key, &value)?,
+            "compaction.type" => ensure!(
+                value == "twcs",
+                InvalidRegionOptionsSnafu {
+                    reason: format!("unknown compaction type {value}"),
+                }
+            ),
+            "compaction.twcs.max_active_window_files" => {
+                twcs.max_active_window_files = parse_usize(&key, &value)?
+            }
+            "compaction.twcs.max_inactive_window_files" => {
+                twcs.max_inactive_window_files = parse_usize(&key, &value)?
+            }
+            "compaction.twcs.time_window" => twcs.time_window = parse_duration(&key, &value)?,
+            "merge_mode" => self.merge_mode = parse_merge_mode(&key, &value)?,
+            _ => {
+                return InvalidRegionOptionsSnafu {
+                    reason: format!("unknown option {key}"),
+                }
+                .fail()
+            }
+        }
+        Ok(())
+    }
+
+    /// Resets the option `key` to its default value.
+    ///
+    /// Returns an error if the key is unknown.
+    pub fn unset_option(&mut self, key: &str) -> Result<()> {
+        let key = key.to_lowercase();
+        let defaults = RegionOptions::default();
+        let CompactionOptions::Twcs(default_twcs) = defaults.compaction;
+        let CompactionOptions::Twcs(twcs) = &mut self.compaction;
+        match key.as_str() {
+            "ttl" => self.ttl = defaults.ttl,
+            "compaction.type" => self.compaction = CompactionOptions::default(),
+            "compaction.twcs.max_active_window_files" => {
+                twcs.max_active_window_files = default_twcs.max_active_window_files
+            }
+            "compaction.twcs.max_inactive_window_files" => {
+                twcs.max_inactive_window_files = default_twcs.max_inactive_window_files
+            }
+            "compaction.twcs.time_window" => twcs.time_window = default_twcs.time_window,
+            "merge_mode" => self.merge_mode = defaults.merge_mode,
+            _ => {
+                return InvalidRegionOptionsSnafu {
+                    reason: format!("unknown option {key}"),
+                }
+                .fail()
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the `altered` options in order, a `None` value unsets the option.
+    pub fn apply_altered_options(&mut self, altered: &[(String, Option<String>)]) -> Result<()> {
+        for (key, value) in altered {
+            match value {
+                Some(value) => self.set_option(key, value)?,
+                None => self.unset_option(key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a duration option, "null" means no duration.
+fn parse_duration(key: &str, value: &str) -> Result<Option<Duration>> {
+    if value == "null" {
+        return Ok(None);
+    }
+    humantime_serde::re::humantime::parse_duration(value)
+        .map(Some)
+        .map_err(|e| {
+            InvalidRegionOptionsSnafu {
+                reason: format!("invalid value {value} for option {key}: {e}"),
+            }
+            .build()
+        })
+}
+
+fn parse_usize(key: &str, value: &str) -> Result<usize> {
+    value.parse().map_err(|e| {
+        InvalidRegionOptionsSnafu {
+            reason: format!("invalid value {value} for option {key}: {e}"),
+        }
+        .build()
+    })
+}
+
+fn parse_merge_mode(key: &str, value: &str) -> Result<MergeMode> {
+    serde_json::from_value(Value::from(value)).map_err(|e| {
+        InvalidRegionOptionsSnafu {
+            reason: format!("invalid value {value} for option {key}: {e}"),
+        }
+        .build()
+    })
+}
+
+/// Mode to merge rows with the same primary key and timestamp.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMode {
+    /// Keeps the last row, fields of older rows are overwritten even if the last row
+    /// has null fields.
+    #[default]
+    LastRow,
+    /// Keeps the last non-null value of each field, so a row that only writes some fields
+    /// doesn't overwrite other fields with nulls.
+    LastNonNull,
+}
+
 /// Options for compactions
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(tag = "compaction.type")]
@@ -124,12 +247,16 @@ struct RegionOptionsWithoutEnum {
     /// Region SST files TTL.
     #[serde(with = "humantime_serde")]
     ttl: Option<Duration>,
+    merge_mode: MergeMode,
 }
 
 impl Default for RegionOptionsWithoutEnum {
     fn default() -> Self {
         let options = RegionOptions::default();
-        RegionOptionsWithoutEnum { ttl: options.ttl }
+        RegionOptionsWithoutEnum {
+            ttl: options.ttl,
+            merge_mode: options.merge_mode,
+        }
     }
 }
 
@@ -170,6 +297,20 @@ mod tests {
         assert_eq!(RegionOptions::default(), options);
     }
 
+    #[test]
+    fn test_with_merge_mode() {
+        let map = make_map(&[("merge_mode", "last_non_null")]);
+        let options = RegionOptions::try_from(&map).unwrap();
+        assert_eq!(MergeMode::LastNonNull, options.merge_mode);
+
+        let map = make_map(&[("merge_mode", "LAST_ROW")]);
+        let options = RegionOptions::try_from(&map).unwrap();
+        assert_eq!(MergeMode::LastRow, options.merge_mode);
+
+        let map = make_map(&[("merge_mode", "first_row")]);
+        RegionOptions::try_from(&map).unwrap_err();
+    }
+
     #[test]
     fn test_with_ttl() {
         let map = make_map(&[("ttl", "7d")]);
@@ -222,6 +363,7 @@ mod tests {
             ("compaction.twcs.max_inactive_window_files", "2"),
             ("compaction.twcs.time_window", "2h"),
             ("compaction.type", "twcs"),
+            ("merge_mode", "last_non_null"),
         ]);
         let options = RegionOptions::try_from(&map).unwrap();
         let expect = RegionOptions {
@@ -231,7 +373,63 @@ mod tests {
                 max_inactive_window_files: 2,
                 time_window: Some(Duration::from_secs(3600 * 2)),
             }),
+            merge_mode: MergeMode::LastNonNull,
+        };
+        assert_eq!(expect, options);
+    }
+
+    #[test]
+    fn test_set_unset_option() {
+        let mut options = RegionOptions::default();
+        options.set_option("TTL", "7d").unwrap();
+        options
+            .set_option("compaction.twcs.max_active_window_files", "8")
+            .unwrap();
+        options
+            .set_option("compaction.twcs.time_window", "2h")
+            .unwrap();
+        options.set_option("compaction.type", "twcs").unwrap();
+        options.set_option("merge_mode", "last_non_null").unwrap();
+        let expect = RegionOptions {
+            ttl: Some(Duration::from_secs(3600 * 24 * 7)),
+            compaction: CompactionOptions::Twcs(TwcsOptions {
+                max_active_window_files: 8,
+                time_window: Some(Duration::from_secs(3600 * 2)),
+                ..Default::default()
+            }),
+            merge_mode: MergeMode::LastNonNull,
         };
         assert_eq!(expect, options);
+
+        options.unset_option("ttl").unwrap();
+        options.unset_option("merge_mode").unwrap();
+        options
+            .unset_option("compaction.twcs.max_active_window_files")
+            .unwrap();
+        let expect = RegionOptions {
+            compaction: CompactionOptions::Twcs(TwcsOptions {
+                time_window: Some(Duration::from_secs(3600 * 2)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(expect, options);
+
+        options.unset_option("compaction.type").unwrap();
+        assert_eq!(RegionOptions::default(), options);
+    }
+
+    #[test]
+    fn test_set_invalid_option() {
+        let mut options = RegionOptions::default();
+        options.set_option("unknown", "1").unwrap_err();
+        options.unset_option("unknown").unwrap_err();
+        options.set_option("ttl", "abc").unwrap_err();
+        options.set_option("compaction.type", "lcs").unwrap_err();
+        options.set_option("merge_mode", "first_row").unwrap_err();
+        options
+            .set_option("compaction.twcs.max_inactive_window_files", "-1")
+            .unwrap_err();
+        assert_eq!(RegionOptions::default(), options);
     }
 }
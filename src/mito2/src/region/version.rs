@@ -80,7 +80,7 @@ impl VersionControl {
         if version.memtables.mutable.is_empty() {
             return;
         }
-        let new_mutable = builder.build(&version.metadata);
+        let new_mutable = builder.build(&version.metadata, version.options.merge_mode);
         // Safety: Immutable memtable is None.
         let new_memtables = version.memtables.freeze_mutable(new_mutable).unwrap();
         // Create a new version with memtable switched.
@@ -123,15 +123,22 @@ impl VersionControl {
     /// Alter schema of the region.
     ///
     /// It replaces existing mutable memtable with a memtable that uses the
-    /// new schema. Memtables of the version must be empty.
-    pub(crate) fn alter_schema(&self, metadata: RegionMetadataRef, builder: &MemtableBuilderRef) {
-        let new_mutable = builder.build(&metadata);
+    /// new schema and applies new `options` to the region. Memtables of the
+    /// version must be empty.
+    pub(crate) fn alter_schema(
+        &self,
+        metadata: RegionMetadataRef,
+        options: RegionOptions,
+        builder: &MemtableBuilderRef,
+    ) {
+        let new_mutable = builder.build(&metadata, options.merge_mode);
         let version = self.current().version;
         debug_assert!(version.memtables.mutable.is_empty());
         debug_assert!(version.memtables.immutables().is_empty());
         let new_version = Arc::new(
             VersionBuilder::from_version(version)
                 .metadata(metadata)
+                .options(options)
                 .memtables(MemtableVersion::new(new_mutable))
                 .build(),
         );
@@ -149,12 +156,13 @@ impl VersionControl {
     ) {
         let version = self.current().version;
 
-        let new_mutable = memtable_builder.build(&version.metadata);
+        let new_mutable = memtable_builder.build(&version.metadata, version.options.merge_mode);
         let new_version = Arc::new(
             VersionBuilder::new(version.metadata.clone(), new_mutable)
                 .flushed_entry_id(truncated_entry_id)
                 .flushed_sequence(truncated_sequence)
                 .truncated_entry_id(Some(truncated_entry_id))
+                .options(version.options.clone())
                 .build(),
         );
 
@@ -14,6 +14,7 @@
 
 use bytes::Buf;
 use common_base::bytes::Bytes;
+use common_decimal::Decimal128;
 use common_time::time::Time;
 use common_time::{Date, Duration, Interval};
 use datatypes::data_type::ConcreteDataType;
@@ -66,7 +67,7 @@ impl SortField {
             ConcreteDataType::Int64(_) | ConcreteDataType::UInt64(_) => 9,
             ConcreteDataType::Float32(_) => 5,
             ConcreteDataType::Float64(_) => 9,
-            ConcreteDataType::Binary(_) => 11,
+            ConcreteDataType::Binary(_) | ConcreteDataType::Json(_) => 11,
             ConcreteDataType::String(_) => 11, // a non-empty string takes at least 11 bytes.
             ConcreteDataType::Date(_) => 5,
             ConcreteDataType::DateTime(_) => 9,
@@ -74,6 +75,7 @@ impl SortField {
             ConcreteDataType::Time(_) => 10,
             ConcreteDataType::Duration(_) => 10,
             ConcreteDataType::Interval(_) => 18,
+            ConcreteDataType::Decimal128(_) => 18,
             ConcreteDataType::Null(_)
             | ConcreteDataType::List(_)
             | ConcreteDataType::Dictionary(_) => 0,
@@ -110,6 +112,18 @@ impl SortField {
                             .serialize($serializer)
                             .context(SerializeFieldSnafu)?;
                     }
+                    ConcreteDataType::Decimal128(_) => {
+                        // Encodes the high and low 64 bits separately so the encoded
+                        // bytes keep the order of the decimals in the same column.
+                        let decimal = value.as_decimal128().context(FieldTypeMismatchSnafu)?;
+                        decimal
+                            .map(|d| {
+                                let (hi, lo) = d.split_value();
+                                (hi, lo as u64)
+                            })
+                            .serialize($serializer)
+                            .context(SerializeFieldSnafu)?;
+                    }
                     ConcreteDataType::List(_) |
                     ConcreteDataType::Dictionary(_) |
                     ConcreteDataType::Null(_) => {
@@ -123,6 +137,7 @@ impl SortField {
         cast_value_and_serialize!(self; serializer;
             Boolean, boolean,
             Binary, binary,
+            Json, binary,
             Int8, i8,
             UInt8, u8,
             Int16, i16,
@@ -161,7 +176,7 @@ impl SortField {
                             Ok(Value::from(Option::<$f>::deserialize(deserializer).context(error::DeserializeFieldSnafu)?))
                         }
                     )*
-                    ConcreteDataType::Binary(_) => Ok(Value::from(
+                    ConcreteDataType::Binary(_) | ConcreteDataType::Json(_) => Ok(Value::from(
                         Option::<Vec<u8>>::deserialize(deserializer)
                             .context(error::DeserializeFieldSnafu)?
                             .map(Bytes::from),
@@ -172,6 +187,19 @@ impl SortField {
                             .map(|t|ty.create_timestamp(t));
                         Ok(Value::from(timestamp))
                     }
+                    ConcreteDataType::Decimal128(d) => {
+                        let decimal = Option::<(i64, u64)>::deserialize(deserializer)
+                            .context(error::DeserializeFieldSnafu)?
+                            .map(|(hi, lo)| {
+                                Decimal128::from_value_precision_scale(
+                                    hi,
+                                    lo as i64,
+                                    d.precision(),
+                                    d.scale(),
+                                )
+                            });
+                        Ok(Value::from(decimal))
+                    }
                     ConcreteDataType::List(l) => NotSupportedFieldSnafu {
                         data_type: ConcreteDataType::List(l.clone()),
                     }
@@ -328,6 +356,31 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_memcmp_decimal128() {
+        check_encode_and_decode(
+            &[
+                ConcreteDataType::decimal128_datatype(10, 2),
+                ConcreteDataType::int64_datatype(),
+            ],
+            vec![
+                Value::Decimal128(Decimal128::new(-12345, 10, 2)),
+                Value::Int64(46),
+            ],
+        );
+
+        let encoder = McmpRowCodec::new(vec![SortField::new(
+            ConcreteDataType::decimal128_datatype(10, 2),
+        )]);
+        let values =
+            [-12345, -1, 0, 1, 12345].map(|v| Value::Decimal128(Decimal128::new(v, 10, 2)));
+        let encoded = values
+            .iter()
+            .map(|v| encoder.encode(std::iter::once(v.as_value_ref())).unwrap())
+            .collect::<Vec<_>>();
+        assert!(encoded.windows(2).all(|w| w[0] < w[1]));
+    }
+
     #[test]
     fn test_memcmp_binary() {
         check_encode_and_decode(
@@ -90,6 +90,8 @@ pub struct FileMeta {
     pub level: Level,
     /// Size of the file.
     pub file_size: u64,
+    /// Number of rows in the file.
+    pub num_rows: u64,
 }
 
 /// Handle to a SST file.
@@ -225,6 +227,7 @@ mod tests {
             time_range: FileTimeRange::default(),
             level,
             file_size: 0,
+            num_rows: 0,
         }
     }
 
@@ -123,6 +123,7 @@ mod tests {
                     time_range: FileTimeRange::default(),
                     level: 0,
                     file_size: 4096,
+                    num_rows: 0,
                 },
                 file_purger,
             );
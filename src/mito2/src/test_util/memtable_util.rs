@@ -26,6 +26,7 @@ use crate::memtable::{
     BoxedBatchIterator, KeyValues, Memtable, MemtableBuilder, MemtableId, MemtableRef,
     MemtableStats,
 };
+use crate::region::options::MergeMode;
 
 /// Empty memtable for test.
 #[derive(Debug, Default)]
@@ -73,7 +74,7 @@ pub(crate) struct EmptyMemtableBuilder {
 }
 
 impl MemtableBuilder for EmptyMemtableBuilder {
-    fn build(&self, _metadata: &RegionMetadataRef) -> MemtableRef {
+    fn build(&self, _metadata: &RegionMetadataRef, _merge_mode: MergeMode) -> MemtableRef {
         Arc::new(EmptyMemtable::new(
             self.next_id.fetch_add(1, Ordering::Relaxed),
         ))
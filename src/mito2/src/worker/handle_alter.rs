@@ -20,12 +20,14 @@ use common_query::Output;
 use common_telemetry::{debug, error, info, warn};
 use snafu::ResultExt;
 use store_api::metadata::{RegionMetadata, RegionMetadataBuilder, RegionMetadataRef};
-use store_api::region_request::RegionAlterRequest;
+use store_api::region_request::{AlterKind, RegionAlterRequest};
 use store_api::storage::RegionId;
 
 use crate::error::{InvalidMetadataSnafu, InvalidRegionRequestSnafu, Result};
 use crate::flush::FlushReason;
-use crate::manifest::action::{RegionChange, RegionMetaAction, RegionMetaActionList};
+use crate::manifest::action::{
+    AlteredOptions, RegionChange, RegionMetaAction, RegionMetaActionList,
+};
 use crate::memtable::MemtableBuilderRef;
 use crate::region::version::Version;
 use crate::region::MitoRegionRef;
@@ -129,19 +131,43 @@ async fn alter_region_schema(
     request: RegionAlterRequest,
     builder: &MemtableBuilderRef,
 ) -> Result<()> {
+    let altered_options = altered_options(&request.kind);
+    let mut new_options = version.options.clone();
+    new_options.apply_altered_options(&altered_options)?;
     let new_meta = metadata_after_alteration(&version.metadata, request)?;
-    // Persist the metadata to region's manifest.
+    // Persist the metadata and altered options to region's manifest.
     let change = RegionChange {
         metadata: new_meta.clone(),
+        altered_options,
     };
     let action_list = RegionMetaActionList::with_action(RegionMetaAction::Change(change));
     region.manifest_manager.update(action_list).await?;
 
-    // Apply the metadata to region's version.
-    region.version_control.alter_schema(new_meta, builder);
+    // Apply the metadata and options to region's version.
+    region
+        .version_control
+        .alter_schema(new_meta, new_options, builder);
     Ok(())
 }
 
+/// Returns the region options the alter `kind` changes.
+///
+/// They are persisted in the manifest and override the options to open the region.
+fn altered_options(kind: &AlterKind) -> AlteredOptions {
+    match kind {
+        AlterKind::SetRegionOptions { options } => options
+            .iter()
+            .map(|(key, value)| (key.to_lowercase(), Some(value.clone())))
+            .collect(),
+        AlterKind::UnsetRegionOptions { keys } => {
+            keys.iter().map(|key| (key.to_lowercase(), None)).collect()
+        }
+        AlterKind::AddColumns { .. }
+        | AlterKind::DropColumns { .. }
+        | AlterKind::ModifyColumnTypes { .. } => Vec::new(),
+    }
+}
+
 /// Creates a metadata after applying the alter `request` to the old `metadata`.
 ///
 /// Returns an error if the `request` is invalid.
@@ -36,6 +36,7 @@ use crate::error::{
 };
 use crate::region_req_factory::RegionRequestFactory;
 use crate::req_convert::delete::{ColumnToRow, RowToRegion, TableToRegion};
+use crate::table::ensure_not_view;
 
 pub struct Deleter {
     catalog_manager: CatalogManagerRef,
@@ -222,14 +223,18 @@ impl Deleter {
         Ok(key_column_names)
     }
 
+    /// Gets the table to delete from, views can't be deleted from.
     async fn get_table(&self, catalog: &str, schema: &str, table: &str) -> Result<TableRef> {
-        self.catalog_manager
+        let table = self
+            .catalog_manager
             .table(catalog, schema, table)
             .await
             .context(CatalogSnafu)?
             .with_context(|| TableNotFoundSnafu {
                 table_name: common_catalog::format_full_table_name(catalog, schema, table),
-            })
+            })?;
+        ensure_not_view(&table.table_info(), "delete from")?;
+        Ok(table)
     }
 }
 
@@ -32,6 +32,12 @@ pub enum Error {
         source: common_meta::error::Error,
     },
 
+    #[snafu(display("Failed to invalidate schema cache"))]
+    InvalidateSchemaCache {
+        location: Location,
+        source: common_meta::error::Error,
+    },
+
     #[snafu(display("Failed to execute ddl"))]
     ExecuteDdl {
         location: Location,
@@ -165,6 +171,35 @@ pub enum Error {
     #[snafu(display("Schema {} already exists", name))]
     SchemaExists { name: String, location: Location },
 
+    #[snafu(display("Database {} is reserved and can't be dropped", name))]
+    DropReservedDatabase { name: String, location: Location },
+
+    #[snafu(display("Table already exists, table: {}", table))]
+    TableAlreadyExists { table: String, location: Location },
+
+    #[snafu(display("Flow {} already exists", name))]
+    FlowExists { name: String, location: Location },
+
+    #[snafu(display("Flow not found: {}", name))]
+    FlowNotFound { name: String, location: Location },
+
+    #[snafu(display("View not found: {}", name))]
+    ViewNotFound { name: String, location: Location },
+
+    #[snafu(display("Cannot {} view {}", operation, name))]
+    UnsupportedViewOperation {
+        name: String,
+        operation: String,
+        location: Location,
+    },
+
+    #[snafu(display("Invalid flow {}, reason: {}", name, reason))]
+    InvalidFlow {
+        name: String,
+        reason: String,
+        location: Location,
+    },
+
     #[snafu(display("Table occurs error"))]
     Table {
         #[snafu(backtrace)]
@@ -485,6 +520,10 @@ impl ErrorExt for Error {
             | Error::IllegalPrimaryKeysDef { .. }
             | Error::SchemaNotFound { .. }
             | Error::SchemaExists { .. }
+            | Error::DropReservedDatabase { .. }
+            | Error::FlowExists { .. }
+            | Error::FlowNotFound { .. }
+            | Error::InvalidFlow { .. }
             | Error::ColumnNotFound { .. }
             | Error::BuildRegex { .. }
             | Error::InvalidSchema { .. }
@@ -496,7 +535,8 @@ impl ErrorExt for Error {
             | Error::InvalidPartitionColumns { .. }
             | Error::PrepareFileTable { .. }
             | Error::InferFileTableSchema { .. }
-            | Error::SchemaIncompatible { .. } => StatusCode::InvalidArguments,
+            | Error::SchemaIncompatible { .. }
+            | Error::UnsupportedViewOperation { .. } => StatusCode::InvalidArguments,
 
             Error::NotSupported { .. } => StatusCode::Unsupported,
 
@@ -506,7 +546,8 @@ impl ErrorExt for Error {
                 source.status_code()
             }
 
-            Error::InvalidateTableCache { source, .. } => source.status_code(),
+            Error::InvalidateTableCache { source, .. }
+            | Error::InvalidateSchemaCache { source, .. } => source.status_code(),
 
             Error::ParseFileFormat { source, .. } | Error::InferSchema { source, .. } => {
                 source.status_code()
@@ -535,7 +576,8 @@ impl ErrorExt for Error {
 
             Error::EncodeJson { .. } => StatusCode::Unexpected,
 
-            Error::TableNotFound { .. } => StatusCode::TableNotFound,
+            Error::TableNotFound { .. } | Error::ViewNotFound { .. } => StatusCode::TableNotFound,
+            Error::TableAlreadyExists { .. } => StatusCode::TableAlreadyExists,
 
             Error::JoinTask { .. }
             | Error::BuildParquetRecordBatchStream { .. }
@@ -22,21 +22,24 @@ use api::v1::{
 };
 use common_error::ext::BoxedError;
 use common_grpc_expr::util::ColumnExpr;
-use datatypes::schema::{ColumnSchema, COMMENT_KEY};
+use datatypes::data_type::ConcreteDataType;
+use datatypes::schema::{ColumnSchema, Schema, COMMENT_KEY};
 use file_engine::FileOptions;
 use query::sql::{
     check_file_to_table_schema_compatibility, file_column_schemas_to_table,
     infer_file_table_schema, prepare_file_table_files,
 };
 use session::context::QueryContextRef;
-use snafu::{ensure, ResultExt};
+use snafu::{ensure, OptionExt, ResultExt};
 use sql::ast::{ColumnDef, ColumnOption, TableConstraint};
 use sql::statements::alter::{AlterTable, AlterTableOperation};
-use sql::statements::create::{CreateExternalTable, CreateTable, TIME_INDEX};
+use sql::statements::create::{
+    CreateExternalTable, CreateTable, CreateTableAs, CreateView, TIME_INDEX,
+};
 use sql::statements::{column_def_to_schema, sql_column_def_to_grpc_column_def};
 use sql::util::to_lowercase_options_map;
 use table::engine::TableReference;
-use table::requests::{TableOptions, FILE_TABLE_META_KEY};
+use table::requests::{TableOptions, FILE_TABLE_META_KEY, VIEW_DEFINITION_KEY};
 
 use crate::error::{
     BuildCreateExprOnInsertionSnafu, ColumnDataTypeSnafu, ConvertColumnDefaultConstraintSnafu,
@@ -210,6 +213,148 @@ pub fn create_to_expr(create: &CreateTable, query_ctx: QueryContextRef) -> Resul
     Ok(expr)
 }
 
+/// Convert `CreateTableAs` statement to `CreateExpr` gRPC request, columns of the table are
+/// the output columns of the query.
+///
+/// Without the `primary_key` option, the table inherits `source_primary_keys`, the output
+/// columns that are primary keys of the tables they're selected from. Otherwise rows sharing
+/// a timestamp would overwrite each other, so the option is required if the query outputs
+/// other columns than the time index.
+pub fn create_as_to_expr(
+    create: &CreateTableAs,
+    query_schema: &Schema,
+    source_primary_keys: &[String],
+    query_ctx: QueryContextRef,
+) -> Result<CreateTableExpr> {
+    let (catalog_name, schema_name, table_name) =
+        table_idents_to_full_name(&create.name, query_ctx)
+            .map_err(BoxedError::new)
+            .context(ExternalSnafu)?;
+
+    let is_timestamp =
+        |column: &ColumnSchema| matches!(column.data_type, ConcreteDataType::Timestamp(_));
+    let time_index = match &create.time_index {
+        Some(time_index) => {
+            let column = query_schema
+                .column_schema_by_name(time_index)
+                .with_context(|| InvalidSqlSnafu {
+                    err_msg: format!("time index column {time_index} is not in the query"),
+                })?;
+            ensure!(
+                is_timestamp(column),
+                InvalidSqlSnafu {
+                    err_msg: format!("time index column {time_index} must be a timestamp"),
+                }
+            );
+            time_index.clone()
+        }
+        None => {
+            let timestamps = query_schema
+                .column_schemas()
+                .iter()
+                .filter(|column| is_timestamp(column))
+                .collect::<Vec<_>>();
+            ensure!(
+                timestamps.len() == 1,
+                InvalidSqlSnafu {
+                    err_msg: "unable to infer the time index, specify it by option 'time_index'",
+                }
+            );
+            timestamps[0].name.clone()
+        }
+    };
+
+    let primary_keys = match &create.primary_keys {
+        Some(primary_keys) => primary_keys.clone(),
+        None if !source_primary_keys.is_empty() => source_primary_keys.to_vec(),
+        None => {
+            ensure!(
+                query_schema.num_columns() == 1,
+                InvalidSqlSnafu {
+                    err_msg: "unable to infer the primary key, specify it by option 'primary_key', or set the option to '' if rows are identified by the time index",
+                }
+            );
+            vec![]
+        }
+    };
+    for primary_key in &primary_keys {
+        ensure!(
+            primary_key != &time_index && query_schema.contains_column(primary_key),
+            IllegalPrimaryKeysDefSnafu {
+                msg: format!("{primary_key} is not a non-time-index column of the query"),
+            }
+        );
+    }
+
+    let column_schemas = query_schema
+        .column_schemas()
+        .iter()
+        .map(|column| {
+            if column.name == time_index {
+                ColumnSchema::new(&column.name, column.data_type.clone(), false)
+                    .with_time_index(true)
+            } else {
+                ColumnSchema::new(&column.name, column.data_type.clone(), true)
+            }
+        })
+        .collect();
+    let table_options = HashMap::from(
+        &TableOptions::try_from(&to_lowercase_options_map(&create.options))
+            .context(UnrecognizedTableOptionSnafu)?,
+    );
+
+    let expr = CreateTableExpr {
+        catalog_name,
+        schema_name,
+        table_name,
+        desc: "".to_string(),
+        column_defs: column_schemas_to_defs(column_schemas, &primary_keys)?,
+        time_index,
+        primary_keys,
+        create_if_not_exists: create.if_not_exists,
+        table_options,
+        table_id: None,
+        engine: create.engine.to_string(),
+    };
+    Ok(expr)
+}
+
+/// Converts a CREATE VIEW statement to a [CreateTableExpr] whose columns are the output columns
+/// of the view's query. The query is kept as SQL text in the table options.
+pub fn create_view_to_expr(
+    create: &CreateView,
+    query_schema: &Schema,
+    query_ctx: QueryContextRef,
+) -> Result<CreateTableExpr> {
+    let (catalog_name, schema_name, table_name) =
+        table_idents_to_full_name(&create.name, query_ctx)
+            .map_err(BoxedError::new)
+            .context(ExternalSnafu)?;
+
+    let column_schemas = query_schema
+        .column_schemas()
+        .iter()
+        .map(|column| ColumnSchema::new(&column.name, column.data_type.clone(), true))
+        .collect();
+    let table_options =
+        HashMap::from([(VIEW_DEFINITION_KEY.to_string(), create.query.to_string())]);
+
+    let expr = CreateTableExpr {
+        catalog_name,
+        schema_name,
+        table_name,
+        desc: "".to_string(),
+        column_defs: column_schemas_to_defs(column_schemas, &[])?,
+        time_index: "".to_string(),
+        primary_keys: vec![],
+        create_if_not_exists: create.if_not_exists,
+        table_options,
+        table_id: None,
+        engine: "".to_string(),
+    };
+    Ok(expr)
+}
+
 fn find_primary_keys(
     columns: &[ColumnDef],
     constraints: &[TableConstraint],
@@ -396,6 +541,27 @@ pub(crate) fn to_alter_expr(
         AlterTableOperation::RenameTable { new_table_name } => Kind::RenameTable(RenameTable {
             new_table_name: new_table_name.to_string(),
         }),
+        // Regions support these operations via `store_api::region_request::AlterKind`, but
+        // the `AlterExpr` of the pinned greptime-proto can't carry them to the regions yet.
+        // Maps them to new alter expr kinds once the proto has them.
+        AlterTableOperation::ModifyColumnType { .. } => {
+            return NotSupportedSnafu {
+                feat: "MODIFY COLUMN",
+            }
+            .fail();
+        }
+        AlterTableOperation::SetTableOptions { .. } => {
+            return NotSupportedSnafu {
+                feat: "SET table options",
+            }
+            .fail();
+        }
+        AlterTableOperation::UnsetTableOptions { .. } => {
+            return NotSupportedSnafu {
+                feat: "UNSET table options",
+            }
+            .fail();
+        }
     };
 
     Ok(AlterExpr {
@@ -433,4 +599,109 @@ mod tests {
             expr.table_options.get("write_buffer_size").unwrap()
         );
     }
+
+    #[test]
+    fn test_create_as_to_expr() {
+        let query_schema = Schema::new(vec![
+            ColumnSchema::new("host", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new(
+                "ts",
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                true,
+            ),
+            ColumnSchema::new("cpu", ConcreteDataType::float64_datatype(), true),
+        ]);
+        let create_as = |sql: &str| {
+            let stmt = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {})
+                .unwrap()
+                .pop()
+                .unwrap();
+            let Statement::CreateTableAs(create_as) = stmt else {
+                unreachable!()
+            };
+            create_as
+        };
+
+        let stmt = create_as(
+            "CREATE TABLE cpu WITH (primary_key = 'host', ttl = '7d') AS SELECT host, ts, cpu FROM monitor",
+        );
+        let expr = create_as_to_expr(&stmt, &query_schema, &[], QueryContext::arc()).unwrap();
+        assert_eq!("cpu", expr.table_name);
+        assert_eq!("ts", expr.time_index);
+        assert_eq!(vec!["host".to_string()], expr.primary_keys);
+        assert_eq!("7days", expr.table_options.get("ttl").unwrap());
+        let semantic_types = expr
+            .column_defs
+            .iter()
+            .map(|c| (c.name.as_str(), c.semantic_type, c.is_nullable))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("host", SemanticType::Tag as i32, true),
+                ("ts", SemanticType::Timestamp as i32, false),
+                ("cpu", SemanticType::Field as i32, true),
+            ],
+            semantic_types
+        );
+
+        // Inherits the primary key of the source table.
+        let source_primary_keys = vec!["host".to_string()];
+        let stmt = create_as("CREATE TABLE cpu AS SELECT * FROM monitor");
+        let expr = create_as_to_expr(
+            &stmt,
+            &query_schema,
+            &source_primary_keys,
+            QueryContext::arc(),
+        )
+        .unwrap();
+        assert_eq!(vec!["host".to_string()], expr.primary_keys);
+        // The option overrides the primary key of the source table.
+        let stmt = create_as("CREATE TABLE cpu WITH (primary_key = '') AS SELECT * FROM monitor");
+        let expr = create_as_to_expr(
+            &stmt,
+            &query_schema,
+            &source_primary_keys,
+            QueryContext::arc(),
+        )
+        .unwrap();
+        assert!(expr.primary_keys.is_empty());
+        // Rows may share timestamps if the primary key is neither given nor inherited.
+        let stmt = create_as("CREATE TABLE cpu AS SELECT * FROM monitor");
+        assert!(create_as_to_expr(&stmt, &query_schema, &[], QueryContext::arc()).is_err());
+
+        let stmt = create_as("CREATE TABLE cpu WITH (time_index = 'cpu') AS SELECT * FROM monitor");
+        assert!(create_as_to_expr(&stmt, &query_schema, &[], QueryContext::arc()).is_err());
+        let stmt = create_as("CREATE TABLE cpu WITH (primary_key = 'ts') AS SELECT * FROM monitor");
+        assert!(create_as_to_expr(&stmt, &query_schema, &[], QueryContext::arc()).is_err());
+    }
+
+    #[test]
+    fn test_alter_to_expr() {
+        let alter = |sql: &str| {
+            let stmt = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {})
+                .unwrap()
+                .pop()
+                .unwrap();
+            let Statement::Alter(alter) = stmt else {
+                unreachable!()
+            };
+            to_alter_expr(alter, QueryContext::arc())
+        };
+
+        let expr = alter("ALTER TABLE monitor ADD COLUMN cpu DOUBLE").unwrap();
+        assert_eq!("monitor", expr.table_name);
+        assert!(matches!(expr.kind, Some(Kind::AddColumns(_))));
+
+        for sql in [
+            "ALTER TABLE monitor MODIFY COLUMN cpu DOUBLE",
+            "ALTER TABLE monitor SET 'ttl'='7d'",
+            "ALTER TABLE monitor UNSET 'ttl'",
+        ] {
+            let err = alter(sql).unwrap_err();
+            assert!(
+                matches!(err, crate::error::Error::NotSupported { .. }),
+                "{err:?}"
+            );
+        }
+    }
 }
@@ -19,6 +19,7 @@ use api::v1::alter_expr::Kind;
 use api::v1::region::{InsertRequests as RegionInsertRequests, RegionRequestHeader};
 use api::v1::{
     AlterExpr, ColumnSchema, CreateTableExpr, InsertRequests, RowInsertRequest, RowInsertRequests,
+    Rows,
 };
 use catalog::CatalogManagerRef;
 use common_catalog::consts::default_engine;
@@ -35,6 +36,7 @@ use session::context::QueryContextRef;
 use snafu::prelude::*;
 use sql::statements::insert::Insert;
 use table::engine::TableReference;
+use table::metadata::TableInfo;
 use table::requests::InsertRequest as TableInsertRequest;
 use table::TableRef;
 
@@ -44,8 +46,10 @@ use crate::error::{
 };
 use crate::expr_factory::CreateExprFactory;
 use crate::region_req_factory::RegionRequestFactory;
+use crate::req_convert::common::partitioner::Partitioner;
 use crate::req_convert::insert::{ColumnToRow, RowToRegion, StatementToRegion, TableToRegion};
 use crate::statement::StatementExecutor;
+use crate::table::ensure_not_view;
 
 pub struct Inserter {
     catalog_manager: CatalogManagerRef,
@@ -121,6 +125,7 @@ impl Inserter {
             table_name: common_catalog::format_full_table_name(catalog, schema, table_name),
         })?;
         let table_info = table.table_info();
+        ensure_not_view(&table_info, "insert into")?;
 
         let inserts = TableToRegion::new(&table_info, &self.partition_manager)
             .convert(request)
@@ -130,6 +135,24 @@ impl Inserter {
         Ok(affected_rows as _)
     }
 
+    /// Inserts `rows` into the table directly, without creating or altering the table on
+    /// demand. The schema of `rows` must be a subset of the table's.
+    pub async fn handle_table_rows(
+        &self,
+        table_info: &TableInfo,
+        rows: Rows,
+        ctx: &QueryContextRef,
+    ) -> Result<usize> {
+        let requests = Partitioner::new(&self.partition_manager)
+            .partition_insert_requests(table_info.table_id(), rows)
+            .await?;
+
+        let affected_rows = self
+            .do_request(RegionInsertRequests { requests }, ctx.trace_id(), 0)
+            .await?;
+        Ok(affected_rows as _)
+    }
+
     pub async fn handle_statement_insert(
         &self,
         insert: &Insert,
@@ -212,6 +235,7 @@ impl Inserter {
             let table = self.get_table(catalog, schema, &req.table_name).await?;
             match table {
                 Some(table) => {
+                    ensure_not_view(&table.table_info(), "insert into")?;
                     validate_request_with_table(req, &table)?;
                     self.alter_table_on_demand(req, table, ctx, statement_executor)
                         .await?
@@ -27,10 +27,12 @@ use table::TableRef;
 
 use super::{data_type, semantic_type};
 use crate::error::{
-    CatalogSnafu, ColumnDefaultValueSnafu, ColumnNoneDefaultValueSnafu, ColumnNotFoundSnafu,
-    InvalidSqlSnafu, MissingInsertBodySnafu, ParseSqlSnafu, Result, TableNotFoundSnafu,
+    CatalogSnafu, ColumnDataTypeSnafu, ColumnDefaultValueSnafu, ColumnNoneDefaultValueSnafu,
+    ColumnNotFoundSnafu, InvalidSqlSnafu, MissingInsertBodySnafu, ParseSqlSnafu, Result,
+    TableNotFoundSnafu,
 };
 use crate::req_convert::common::partitioner::Partitioner;
+use crate::table::ensure_not_view;
 
 const DEFAULT_PLACEHOLDER_VALUE: &str = "default";
 
@@ -58,6 +60,7 @@ impl<'a> StatementToRegion<'a> {
         let table = self.get_table(&catalog, &schema, &table_name).await?;
         let table_schema = table.schema();
         let table_info = table.table_info();
+        ensure_not_view(&table_info, "insert into")?;
 
         let column_names = column_names(stmt, &table_schema);
         let column_count = column_names.len();
@@ -181,8 +184,7 @@ fn sql_value_to_grpc_value(column_schema: &ColumnSchema, sql_val: &SqlValue) ->
             .context(ParseSqlSnafu)?
     };
 
-    let grpc_value = value_to_grpc_value(value);
-    Ok(grpc_value)
+    value_to_grpc_value(value).context(ColumnDataTypeSnafu)
 }
 
 fn replace_default(sql_val: &SqlValue) -> bool {
@@ -112,6 +112,8 @@ impl StatementExecutor {
 
             Statement::Delete(delete) => self.delete(delete, query_ctx).await,
 
+            Statement::Update(update) => self.update(update, query_ctx).await,
+
             Statement::Tql(tql) => self.execute_tql(tql, query_ctx).await,
 
             Statement::DescribeTable(stmt) => self.describe_table(stmt, query_ctx).await,
@@ -143,6 +145,7 @@ impl StatementExecutor {
                 let _ = self.create_table(stmt, query_ctx).await?;
                 Ok(Output::AffectedRows(0))
             }
+            Statement::CreateTableAs(stmt) => self.create_table_as(stmt, query_ctx).await,
             Statement::CreateExternalTable(stmt) => {
                 let _ = self.create_external_table(stmt, query_ctx).await?;
                 Ok(Output::AffectedRows(0))
@@ -170,6 +173,20 @@ impl StatementExecutor {
                     query_ctx.current_catalog(),
                     &stmt.name.to_string(),
                     stmt.if_not_exists,
+                    stmt.options.as_ref(),
+                )
+                .await
+            }
+            Statement::AlterDatabase(stmt) => self.alter_database(stmt, query_ctx).await,
+            Statement::CreateFlow(stmt) => self.create_flow(stmt, query_ctx).await,
+            Statement::DropFlow(stmt) => self.drop_flow(stmt, query_ctx).await,
+            Statement::CreateView(stmt) => self.create_view(stmt, query_ctx).await,
+            Statement::DropView(stmt) => self.drop_view(stmt, query_ctx).await,
+            Statement::DropDatabase(stmt) => {
+                self.drop_database(
+                    query_ctx.current_catalog(),
+                    &stmt.name().to_string(),
+                    stmt.drop_if_exists(),
                 )
                 .await
             }
@@ -186,11 +203,41 @@ impl StatementExecutor {
                     .await
                     .context(error::CatalogSnafu)?
                     .context(error::TableNotFoundSnafu { table_name: &table })?;
+                if table_ref.table_info().view_definition().is_some() {
+                    return self.show_create_view(table_ref, query_ctx);
+                }
                 let table_name = TableName::new(catalog, schema, table);
 
                 self.show_create_table(table_name, table_ref, query_ctx)
                     .await
             }
+            Statement::ShowCreateView(show) => {
+                let (catalog, schema, view) =
+                    table_idents_to_full_name(&show.view_name, query_ctx.clone())
+                        .map_err(BoxedError::new)
+                        .context(error::ExternalSnafu)?;
+
+                let view_ref = self
+                    .catalog_manager
+                    .table(&catalog, &schema, &view)
+                    .await
+                    .context(error::CatalogSnafu)?
+                    .filter(|table| table.table_info().view_definition().is_some())
+                    .context(error::ViewNotFoundSnafu { name: &view })?;
+
+                self.show_create_view(view_ref, query_ctx)
+            }
+
+            // The process list and flows are kept by the frontend, which handles these
+            // statements.
+            Statement::ShowProcesslist(_) | Statement::Kill(_) => error::NotSupportedSnafu {
+                feat: "SHOW PROCESSLIST and KILL outside of the frontend",
+            }
+            .fail(),
+            Statement::EvaluateFlow(_) => error::NotSupportedSnafu {
+                feat: "EVALUATE FLOW outside of the frontend",
+            }
+            .fail(),
         }
     }
 
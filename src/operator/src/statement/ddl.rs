@@ -21,23 +21,34 @@ use catalog::CatalogManagerRef;
 use chrono::DateTime;
 use common_catalog::consts::{DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME};
 use common_catalog::format_full_table_name;
+use common_error::ext::BoxedError;
 use common_meta::cache_invalidator::Context;
+use common_meta::ddl::drop_database::is_reserved_schema;
 use common_meta::ddl::ExecutorContext;
+use common_meta::key::flow_info::{FlowInfoKey, FlowInfoValue};
 use common_meta::key::schema_name::{SchemaNameKey, SchemaNameValue};
+use common_meta::key::TableMetadataManagerRef;
 use common_meta::rpc::ddl::{DdlTask, SubmitDdlTaskRequest, SubmitDdlTaskResponse};
 use common_meta::rpc::router::{Partition, Partition as MetaPartition};
 use common_meta::table_name::TableName;
 use common_query::Output;
-use common_telemetry::info;
+use common_telemetry::{info, warn};
 use datatypes::prelude::ConcreteDataType;
 use datatypes::schema::RawSchema;
 use partition::partition::{PartitionBound, PartitionDef};
-use session::context::QueryContextRef;
+use query::parser::QueryStatement;
+use query::plan::LogicalPlan;
+use query::range_select::incremental::find_range_window;
+use session::context::{QueryContext, QueryContextRef};
 use snafu::{ensure, OptionExt, ResultExt};
 use sql::ast::Value as SqlValue;
-use sql::statements::alter::AlterTable;
-use sql::statements::create::{CreateExternalTable, CreateTable, Partitions};
+use sql::statements::alter::{AlterDatabase, AlterDatabaseOperation, AlterTable};
+use sql::statements::create::{
+    CreateExternalTable, CreateFlow, CreateTable, CreateTableAs, CreateView, Partitions,
+};
+use sql::statements::drop::{DropFlow, DropView};
 use sql::statements::sql_value_to_value;
+use sql::statements::statement::Statement;
 use sql::MAXVALUE;
 use table::dist_table::DistTable;
 use table::metadata::{self, RawTableInfo, RawTableMeta, TableId, TableInfo, TableType};
@@ -52,12 +63,17 @@ use crate::error::{
     UnrecognizedTableOptionSnafu,
 };
 use crate::expr_factory;
+use crate::table::{ensure_not_view, table_idents_to_full_name};
 
 impl StatementExecutor {
     pub fn catalog_manager(&self) -> CatalogManagerRef {
         self.catalog_manager.clone()
     }
 
+    pub fn table_metadata_manager(&self) -> &TableMetadataManagerRef {
+        &self.table_metadata_manager
+    }
+
     pub async fn create_table(&self, stmt: CreateTable, ctx: QueryContextRef) -> Result<TableRef> {
         let create_expr = &mut expr_factory::create_to_expr(&stmt, ctx)?;
         self.create_table_inner(create_expr, stmt.partitions).await
@@ -72,6 +88,161 @@ impl StatementExecutor {
         self.create_table_inner(create_expr, None).await
     }
 
+    /// Creates a table from the output schema of the query, then inserts the query results
+    /// into it. The table is dropped if the results can't be inserted.
+    pub async fn create_table_as(
+        &self,
+        stmt: CreateTableAs,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        let (catalog, schema, table) = table_idents_to_full_name(&stmt.name, query_ctx.clone())
+            .map_err(BoxedError::new)
+            .context(error::ExternalSnafu)?;
+        let exists = self
+            .catalog_manager
+            .table_exists(&catalog, &schema, &table)
+            .await
+            .context(CatalogSnafu)?;
+        if exists {
+            ensure!(
+                stmt.if_not_exists,
+                error::TableAlreadyExistsSnafu {
+                    table: format_full_table_name(&catalog, &schema, &table),
+                }
+            );
+            return Ok(Output::AffectedRows(0));
+        }
+
+        let plan = self
+            .plan(
+                QueryStatement::Sql(Statement::Query(stmt.query.clone())),
+                query_ctx.clone(),
+            )
+            .await?;
+        let query_schema = plan.schema().context(error::PlanStatementSnafu)?;
+        let source_primary_keys = self.source_primary_keys(&plan, &query_ctx).await?;
+        let create_expr = &mut expr_factory::create_as_to_expr(
+            &stmt,
+            &query_schema,
+            &source_primary_keys,
+            query_ctx.clone(),
+        )?;
+        let table_name = TableName::new(&catalog, &schema, &table);
+        let table = self.create_table_inner(create_expr, None).await?;
+
+        let result = match self.execute_plan_to_stream(plan, query_ctx.clone()).await {
+            Ok(stream) => self.insert_stream(&table, stream, &query_ctx).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(affected_rows) => Ok(Output::AffectedRows(affected_rows)),
+            Err(e) => {
+                // Doesn't leave a partial table behind the failed statement.
+                if let Err(drop_err) = self.drop_table(table_name.clone()).await {
+                    warn!(drop_err; "Failed to drop table {table_name} after CREATE TABLE AS failed");
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Returns output columns of the plan that are primary keys of the tables they're
+    /// selected from.
+    async fn source_primary_keys(
+        &self,
+        plan: &LogicalPlan,
+        query_ctx: &QueryContextRef,
+    ) -> Result<Vec<String>> {
+        let LogicalPlan::DfPlan(plan) = plan;
+        let mut primary_keys = Vec::new();
+        for field in plan.schema().fields() {
+            let Some(qualifier) = field.qualifier() else {
+                continue;
+            };
+            let table_ref = qualifier
+                .clone()
+                .resolve(query_ctx.current_catalog(), query_ctx.current_schema());
+            let Some(source) = self
+                .catalog_manager
+                .table(&table_ref.catalog, &table_ref.schema, &table_ref.table)
+                .await
+                .context(CatalogSnafu)?
+            else {
+                continue;
+            };
+            let is_primary_key = source
+                .table_info()
+                .meta
+                .row_key_column_names()
+                .any(|name| name == field.name());
+            if is_primary_key && !primary_keys.contains(field.name()) {
+                primary_keys.push(field.name().clone());
+            }
+        }
+        Ok(primary_keys)
+    }
+
+    /// Creates a view, which is a table without regions whose SQL definition is stored in
+    /// the table options. The query is planned here to validate it and derive the columns.
+    pub async fn create_view(
+        &self,
+        stmt: CreateView,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        let (catalog, schema, view) = table_idents_to_full_name(&stmt.name, query_ctx.clone())
+            .map_err(BoxedError::new)
+            .context(error::ExternalSnafu)?;
+        let exists = self
+            .catalog_manager
+            .table_exists(&catalog, &schema, &view)
+            .await
+            .context(CatalogSnafu)?;
+        if exists {
+            ensure!(
+                stmt.if_not_exists,
+                error::TableAlreadyExistsSnafu {
+                    table: format_full_table_name(&catalog, &schema, &view),
+                }
+            );
+            return Ok(Output::AffectedRows(0));
+        }
+
+        // The view is resolved in its own schema when it's queried, so is the validation.
+        let plan = self
+            .plan(
+                QueryStatement::Sql(Statement::Query(stmt.query.clone())),
+                QueryContext::with(&catalog, &schema),
+            )
+            .await?;
+        let query_schema = plan.schema().context(error::PlanStatementSnafu)?;
+        let create_expr = expr_factory::create_view_to_expr(&stmt, &query_schema, query_ctx)?;
+
+        let schema_opts = self
+            .table_metadata_manager
+            .schema_manager()
+            .get(SchemaNameKey::new(&catalog, &schema))
+            .await
+            .context(TableMetadataManagerSnafu)?
+            .context(SchemaNotFoundSnafu {
+                schema_info: &schema,
+            })?;
+        let mut table_info = create_table_info(&create_expr, vec![], schema_opts)?;
+        table_info.table_type = TableType::View;
+
+        let resp = self
+            .create_table_procedure(&create_expr, vec![], table_info)
+            .await?;
+        let view_id = resp.table_id.context(error::UnexpectedSnafu {
+            violated: "expected table_id",
+        })?;
+        info!(
+            "Successfully created view '{}' with table id {view_id}",
+            format_full_table_name(&catalog, &schema, &view)
+        );
+
+        Ok(Output::AffectedRows(0))
+    }
+
     pub async fn create_table_inner(
         &self,
         create_table: &mut CreateTableExpr,
@@ -139,7 +310,54 @@ impl StatementExecutor {
             .with_context(|| TableNotFoundSnafu {
                 table_name: table_name.to_string(),
             })?;
-        let table_id = table.table_info().table_id();
+        ensure!(
+            table.table_info().table_type != TableType::View,
+            error::InvalidSqlSnafu {
+                err_msg: format!("{table_name} is a view, use DROP VIEW instead"),
+            }
+        );
+        self.drop_table_and_invalidate(table_name, table.table_info().table_id())
+            .await
+    }
+
+    pub async fn drop_view(&self, stmt: DropView, query_ctx: QueryContextRef) -> Result<Output> {
+        let (catalog, schema, view) = table_idents_to_full_name(stmt.view_name(), query_ctx)
+            .map_err(BoxedError::new)
+            .context(error::ExternalSnafu)?;
+        let view_name = TableName::new(catalog, schema, view);
+        let table = self
+            .catalog_manager
+            .table(
+                &view_name.catalog_name,
+                &view_name.schema_name,
+                &view_name.table_name,
+            )
+            .await
+            .context(CatalogSnafu)?;
+        let Some(table) = table else {
+            ensure!(
+                stmt.drop_if_exists(),
+                error::ViewNotFoundSnafu {
+                    name: view_name.to_string(),
+                }
+            );
+            return Ok(Output::AffectedRows(0));
+        };
+        ensure!(
+            table.table_info().table_type == TableType::View,
+            error::InvalidSqlSnafu {
+                err_msg: format!("{view_name} is not a view"),
+            }
+        );
+        self.drop_table_and_invalidate(view_name, table.table_info().table_id())
+            .await
+    }
+
+    async fn drop_table_and_invalidate(
+        &self,
+        table_name: TableName,
+        table_id: TableId,
+    ) -> Result<Output> {
         self.drop_table_procedure(&table_name, table_id).await?;
 
         // Invalidates local cache ASAP.
@@ -169,6 +387,7 @@ impl StatementExecutor {
             .with_context(|| TableNotFoundSnafu {
                 table_name: table_name.to_string(),
             })?;
+        ensure_not_view(&table.table_info(), "truncate")?;
         let table_id = table.table_info().ident.table_id;
 
         let expr = TruncateTableExpr {
@@ -182,6 +401,67 @@ impl StatementExecutor {
         Ok(Output::AffectedRows(0))
     }
 
+    /// Updates an extra option of a table. Only the table info in metadata is changed, the
+    /// update is guarded by the compare-and-put of `update_table_info`.
+    pub async fn update_table_option(
+        &self,
+        table_name: TableName,
+        key: &str,
+        value: String,
+    ) -> Result<()> {
+        let table = self
+            .catalog_manager
+            .table(
+                &table_name.catalog_name,
+                &table_name.schema_name,
+                &table_name.table_name,
+            )
+            .await
+            .context(CatalogSnafu)?
+            .with_context(|| TableNotFoundSnafu {
+                table_name: table_name.to_string(),
+            })?;
+        let table_id = table.table_info().ident.table_id;
+
+        let table_info_value = self
+            .table_metadata_manager
+            .table_info_manager()
+            .get(table_id)
+            .await
+            .context(TableMetadataManagerSnafu)?
+            .with_context(|| TableNotFoundSnafu {
+                table_name: table_name.to_string(),
+            })?;
+        let options = &table_info_value.table_info.meta.options;
+        if options.extra_options.get(key) == Some(&value) {
+            return Ok(());
+        }
+
+        let mut new_table_info = table_info_value.table_info.clone();
+        let _ = new_table_info
+            .meta
+            .options
+            .extra_options
+            .insert(key.to_string(), value);
+        self.table_metadata_manager
+            .update_table_info(table_info_value, new_table_info)
+            .await
+            .context(TableMetadataManagerSnafu)?;
+
+        // Invalidates local cache ASAP.
+        self.cache_invalidator
+            .invalidate_table_id(&Context::default(), table_id)
+            .await
+            .context(error::InvalidateTableCacheSnafu)?;
+
+        self.cache_invalidator
+            .invalidate_table_name(&Context::default(), table_name)
+            .await
+            .context(error::InvalidateTableCacheSnafu)?;
+
+        Ok(())
+    }
+
     fn verify_alter(
         &self,
         table_id: TableId,
@@ -236,6 +516,7 @@ impl StatementExecutor {
             .with_context(|| TableNotFoundSnafu {
                 table_name: format_full_table_name(catalog_name, schema_name, table_name),
             })?;
+        ensure_not_view(&table.table_info(), "alter")?;
 
         let table_id = table.table_info().ident.table_id;
         self.verify_alter(table_id, table.table_info(), expr.clone())?;
@@ -310,6 +591,26 @@ impl StatementExecutor {
             .context(error::ExecuteDdlSnafu)
     }
 
+    async fn drop_database_procedure(
+        &self,
+        catalog: &str,
+        database: &str,
+        drop_if_exists: bool,
+    ) -> Result<SubmitDdlTaskResponse> {
+        let request = SubmitDdlTaskRequest {
+            task: DdlTask::new_drop_database(
+                catalog.to_string(),
+                database.to_string(),
+                drop_if_exists,
+            ),
+        };
+
+        self.ddl_executor
+            .submit_ddl_task(&ExecutorContext::default(), request)
+            .await
+            .context(error::ExecuteDdlSnafu)
+    }
+
     async fn truncate_table_procedure(
         &self,
         truncate_table: &TruncateTableExpr,
@@ -329,6 +630,7 @@ impl StatementExecutor {
         catalog: &str,
         database: &str,
         create_if_not_exists: bool,
+        options: &HashMap<String, String>,
     ) -> Result<Output> {
         // TODO(weny): considers executing it in the procedures.
         let schema_key = SchemaNameKey::new(catalog, database);
@@ -347,14 +649,249 @@ impl StatementExecutor {
             };
         }
 
+        let schema_value = SchemaNameValue::try_from(options).context(TableMetadataManagerSnafu)?;
         self.table_metadata_manager
             .schema_manager()
-            .create(schema_key, None, false)
+            .create(schema_key, Some(schema_value), false)
             .await
             .context(TableMetadataManagerSnafu)?;
 
         Ok(Output::AffectedRows(1))
     }
+
+    /// Alters options of the database.
+    ///
+    /// The options are defaults of tables created in the database afterwards, existing
+    /// tables are not changed.
+    pub async fn alter_database(
+        &self,
+        alter_database: AlterDatabase,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        let catalog = query_ctx.current_catalog();
+        let database = alter_database.database_name().to_string();
+        let schema_key = SchemaNameKey::new(catalog, &database);
+        let schema_manager = self.table_metadata_manager.schema_manager();
+
+        let Some(mut schema_value) = schema_manager
+            .get(schema_key)
+            .await
+            .context(TableMetadataManagerSnafu)?
+        else {
+            return SchemaNotFoundSnafu {
+                schema_info: &database,
+            }
+            .fail();
+        };
+
+        match alter_database.alter_operation() {
+            AlterDatabaseOperation::SetDatabaseOptions { options } => {
+                for (key, value) in options {
+                    schema_value
+                        .set_option(key, value)
+                        .context(TableMetadataManagerSnafu)?;
+                }
+            }
+            AlterDatabaseOperation::UnsetDatabaseOptions { keys } => {
+                for key in keys {
+                    schema_value
+                        .unset_option(key)
+                        .context(TableMetadataManagerSnafu)?;
+                }
+            }
+        }
+
+        schema_manager
+            .update(schema_key, &schema_value)
+            .await
+            .context(TableMetadataManagerSnafu)?;
+
+        // Invalidates local cache ASAP.
+        self.cache_invalidator
+            .invalidate_schema(&Context::default(), catalog, &database)
+            .await
+            .context(error::InvalidateSchemaCacheSnafu)?;
+
+        Ok(Output::AffectedRows(0))
+    }
+
+    /// Drops the database and all tables in it.
+    ///
+    /// If the DDL executor can't run the drop database procedure, e.g., the meta protocol
+    /// has no such task yet, the tables are dropped one by one by their own procedures
+    /// before the database is removed. Executing the statement again resumes from the
+    /// remaining tables if it fails midway.
+    pub async fn drop_database(
+        &self,
+        catalog: &str,
+        database: &str,
+        drop_if_exists: bool,
+    ) -> Result<Output> {
+        ensure!(
+            !is_reserved_schema(database),
+            error::DropReservedDatabaseSnafu { name: database }
+        );
+
+        let exists = self
+            .table_metadata_manager
+            .schema_manager()
+            .exists(SchemaNameKey::new(catalog, database))
+            .await
+            .context(TableMetadataManagerSnafu)?;
+
+        if !exists {
+            return if drop_if_exists {
+                Ok(Output::AffectedRows(0))
+            } else {
+                SchemaNotFoundSnafu {
+                    schema_info: database,
+                }
+                .fail()
+            };
+        }
+
+        if self.ddl_executor.supports_drop_database() {
+            self.drop_database_procedure(catalog, database, drop_if_exists)
+                .await?;
+        } else {
+            self.drop_database_by_tables(catalog, database).await?;
+        }
+
+        // Invalidates local cache ASAP.
+        self.cache_invalidator
+            .invalidate_schema(&Context::default(), catalog, database)
+            .await
+            .context(error::InvalidateSchemaCacheSnafu)?;
+
+        Ok(Output::AffectedRows(0))
+    }
+
+    async fn drop_database_by_tables(&self, catalog: &str, database: &str) -> Result<()> {
+        let tables = self
+            .table_metadata_manager
+            .table_name_manager()
+            .tables(catalog, database)
+            .await
+            .context(TableMetadataManagerSnafu)?;
+        let num_tables = tables.len();
+
+        for (table, value) in tables {
+            let table_name = TableName::new(catalog, database, &table);
+            let _ = self
+                .drop_table_and_invalidate(table_name, value.table_id())
+                .await?;
+        }
+
+        let schema_key = SchemaNameKey::new(catalog, database);
+        self.table_metadata_manager
+            .flow_info_manager()
+            .delete_schema_flows(catalog, database)
+            .await
+            .context(TableMetadataManagerSnafu)?;
+        self.table_metadata_manager
+            .schema_manager()
+            .delete(schema_key)
+            .await
+            .context(TableMetadataManagerSnafu)?;
+
+        info!("Dropped database {catalog}.{database} and its {num_tables} tables");
+
+        Ok(())
+    }
+
+    /// Creates a flow, which continuously evaluates the range query and writes results
+    /// into the sink table.
+    pub async fn create_flow(
+        &self,
+        create_flow: CreateFlow,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        let (catalog, schema, flow_name) =
+            table_idents_to_full_name(&create_flow.flow_name, query_ctx.clone())
+                .map_err(BoxedError::new)
+                .context(error::ExternalSnafu)?;
+        let (sink_catalog, sink_schema, sink_table_name) =
+            table_idents_to_full_name(&create_flow.sink_table_name, query_ctx)
+                .map_err(BoxedError::new)
+                .context(error::ExternalSnafu)?;
+        ensure!(
+            sink_catalog == catalog && sink_schema == schema,
+            error::InvalidFlowSnafu {
+                name: &flow_name,
+                reason: "the sink table must be in the same database as the flow",
+            }
+        );
+
+        // The flow is evaluated in its database, validates the query there.
+        let query = create_flow.query.to_string();
+        let plan = self
+            .plan(
+                QueryStatement::Sql(Statement::Query(create_flow.query)),
+                QueryContext::with(&catalog, &schema),
+            )
+            .await?;
+        let window = find_range_window(&plan).context(error::InvalidFlowSnafu {
+            name: &flow_name,
+            reason: "the query must be a range query",
+        })?;
+        let output_schema = plan.schema().context(error::PlanStatementSnafu)?;
+        ensure!(
+            output_schema
+                .column_schema_by_name(&window.time_index)
+                .is_some_and(|column| column.data_type.as_timestamp().is_some()),
+            error::InvalidFlowSnafu {
+                name: &flow_name,
+                reason: format!("the query must select the time index {}", window.time_index),
+            }
+        );
+
+        let key = FlowInfoKey::new(&catalog, &schema, &flow_name);
+        let created = self
+            .table_metadata_manager
+            .flow_info_manager()
+            .create(key, &FlowInfoValue::new(sink_table_name, query))
+            .await
+            .context(TableMetadataManagerSnafu)?;
+        if created {
+            info!("Flow {key} is created");
+        } else {
+            ensure!(
+                create_flow.if_not_exists,
+                error::FlowExistsSnafu { name: flow_name }
+            );
+        }
+
+        Ok(Output::AffectedRows(0))
+    }
+
+    pub async fn drop_flow(
+        &self,
+        drop_flow: DropFlow,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        let (catalog, schema, flow_name) =
+            table_idents_to_full_name(drop_flow.flow_name(), query_ctx)
+                .map_err(BoxedError::new)
+                .context(error::ExternalSnafu)?;
+
+        let key = FlowInfoKey::new(&catalog, &schema, &flow_name);
+        let deleted = self
+            .table_metadata_manager
+            .flow_info_manager()
+            .delete(key)
+            .await
+            .context(TableMetadataManagerSnafu)?;
+        if deleted {
+            info!("Flow {key} is dropped");
+        } else {
+            ensure!(
+                drop_flow.drop_if_exists(),
+                error::FlowNotFoundSnafu { name: flow_name }
+            );
+        }
+
+        Ok(Output::AffectedRows(0))
+    }
 }
 
 fn validate_partition_columns(
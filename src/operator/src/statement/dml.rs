@@ -14,10 +14,15 @@
 
 use std::collections::HashMap;
 
+use api::v1::Rows;
 use common_query::Output;
 use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
-use datafusion_expr::{DmlStatement, LogicalPlan as DfLogicalPlan, WriteOp};
+use datafusion_common::Result as DfResult;
+use datafusion_expr::{
+    DmlStatement, Expr, LogicalPlan as DfLogicalPlan, LogicalPlanBuilder, WriteOp,
+};
 use datatypes::schema::SchemaRef;
+use datatypes::value::Value;
 use futures_util::StreamExt;
 use query::parser::QueryStatement;
 use query::plan::LogicalPlan;
@@ -26,16 +31,22 @@ use snafu::{ensure, OptionExt, ResultExt};
 use sql::statements::delete::Delete;
 use sql::statements::insert::Insert;
 use sql::statements::statement::Statement;
+use sql::statements::update::Update;
 use table::engine::TableReference;
 use table::metadata::TableInfoRef;
-use table::requests::{DeleteRequest, InsertRequest};
+use table::requests::{DeleteRequest, MERGE_MODE_KEY};
 use table::TableRef;
 
 use super::StatementExecutor;
 use crate::error::{
-    BuildColumnVectorsSnafu, ExecLogicalPlanSnafu, MissingTimeIndexColumnSnafu,
-    ReadRecordBatchSnafu, Result, UnexpectedSnafu,
+    BuildColumnVectorsSnafu, BuildDfLogicalPlanSnafu, ColumnDataTypeSnafu, ExecLogicalPlanSnafu,
+    InvalidSqlSnafu, MissingTimeIndexColumnSnafu, ReadRecordBatchSnafu, Result, UnexpectedSnafu,
 };
+use crate::req_convert::common::{column_schema, row_count};
+use crate::table::ensure_not_view;
+
+/// Max number of rows buffered before sending the results of a query to the table.
+const INSERT_SELECT_BATCH_SIZE: usize = 8192;
 
 impl StatementExecutor {
     pub async fn insert(&self, insert: Box<Insert>, query_ctx: QueryContextRef) -> Result<Output> {
@@ -61,23 +72,16 @@ impl StatementExecutor {
                     violated: "expected an INSERT plan"
                 }
             );
-            let mut stream = self
+            let table = self
+                .get_table_from_dml(dml_statement.clone(), &query_ctx)
+                .await?;
+            ensure_not_view(&table.table_info(), "insert into")?;
+            let stream = self
                 .execute_dml_subquery(&dml_statement, query_ctx.clone())
                 .await?;
 
             // 3. Send insert requests.
-            let mut affected_rows = 0;
-            let table = self.get_table_from_dml(dml_statement, &query_ctx).await?;
-            let table_info = table.table_info();
-            while let Some(batch) = stream.next().await {
-                let record_batch = batch.context(ReadRecordBatchSnafu)?;
-                let insert_request =
-                    build_insert_request(record_batch, table.schema(), &table_info)?;
-                affected_rows += self
-                    .inserter
-                    .handle_table_insert(insert_request, query_ctx.clone())
-                    .await?;
-            }
+            let affected_rows = self.insert_stream(&table, stream, &query_ctx).await?;
 
             Ok(Output::AffectedRows(affected_rows))
         }
@@ -97,14 +101,17 @@ impl StatementExecutor {
                 violated: "expected a DELETE plan"
             }
         );
+        let table = self
+            .get_table_from_dml(dml_statement.clone(), &query_ctx)
+            .await?;
+        let table_info = table.table_info();
+        ensure_not_view(&table_info, "delete from")?;
         let mut stream = self
             .execute_dml_subquery(&dml_statement, query_ctx.clone())
             .await?;
 
         // 3. Send delete requests.
         let mut affected_rows = 0;
-        let table = self.get_table_from_dml(dml_statement, &query_ctx).await?;
-        let table_info = table.table_info();
         while let Some(batch) = stream.next().await {
             let record_batch = batch.context(ReadRecordBatchSnafu)?;
             let request = build_delete_request(record_batch, table.schema(), &table_info)?;
@@ -117,15 +124,229 @@ impl StatementExecutor {
         Ok(Output::AffectedRows(affected_rows as _))
     }
 
+    /// Updates the rows matching the filter by reading them with the new values assigned, then
+    /// putting them back to the table. The keys of the rows are unchanged, so the new values
+    /// overwrite the old ones.
+    ///
+    /// The read and the put are not atomic, rows written between them may be overwritten by
+    /// the values read before, and an error in the middle leaves the rows put so far updated.
+    /// In tables whose merge mode is `last_non_null`, the new values are checked before
+    /// putting any row back, as NULLs can't overwrite the old values.
+    pub async fn update(&self, update: Box<Update>, query_ctx: QueryContextRef) -> Result<Output> {
+        let assigned_columns = update
+            .assigned_columns()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let null_assigned_columns = update
+            .null_assigned_columns()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // 1. Plan the whole update statement into a logical plan, its input reads all columns of
+        //    the matching rows with the assigned columns replaced by the new values.
+        let statement = QueryStatement::Sql(Statement::Update(update));
+        let logical_plan = self.plan(statement, query_ctx.clone()).await?;
+        let dml_statement = extract_dml_statement(logical_plan)?;
+        ensure!(
+            dml_statement.op == WriteOp::Update,
+            UnexpectedSnafu {
+                violated: "expected an UPDATE plan"
+            }
+        );
+
+        // 2. Only fields can be updated, tags and the time index identify the rows.
+        let table = self
+            .get_table_from_dml(dml_statement.clone(), &query_ctx)
+            .await?;
+        let table_info = table.table_info();
+        ensure_not_view(&table_info, "update")?;
+        let field_columns = table_info.meta.field_column_names().collect::<Vec<_>>();
+        for column in &assigned_columns {
+            ensure!(
+                field_columns.contains(&column),
+                InvalidSqlSnafu {
+                    err_msg: format!("column {column} is not a field column, it can't be updated"),
+                }
+            );
+        }
+        // Tables in the `last_non_null` merge mode keep the old value of a field if the new
+        // value is null, so assigning NULL changes nothing.
+        let last_non_null = table_info
+            .meta
+            .options
+            .extra_options
+            .get(MERGE_MODE_KEY)
+            .is_some_and(|mode| mode.eq_ignore_ascii_case("last_non_null"));
+        if last_non_null {
+            // Besides `NULL` literals, values may evaluate to NULL, e.g., `NULLIF(v, v)` or a
+            // nullable column, so the rows to update are checked as well.
+            let null_assigned_column = match null_assigned_columns.first() {
+                Some(column) => Some(column.clone()),
+                None => {
+                    self.find_null_assigned_column(
+                        &dml_statement,
+                        &assigned_columns,
+                        query_ctx.clone(),
+                    )
+                    .await?
+                }
+            };
+            if let Some(column) = null_assigned_column {
+                return InvalidSqlSnafu {
+                    err_msg: format!(
+                        "column {column} can't be set to NULL in a table with merge_mode 'last_non_null'"
+                    ),
+                }
+                .fail();
+            }
+        }
+
+        // 3. Execute the subquery and put the rows back.
+        let stream = self
+            .execute_dml_subquery(&dml_statement, query_ctx.clone())
+            .await?;
+        let affected_rows = self.insert_stream(&table, stream, &query_ctx).await?;
+
+        Ok(Output::AffectedRows(affected_rows))
+    }
+
+    /// Inserts the record batches of `stream` into `table`. Rows are buffered and sent in
+    /// batches of [INSERT_SELECT_BATCH_SIZE] rows, returns the number of affected rows.
+    pub(super) async fn insert_stream(
+        &self,
+        table: &TableRef,
+        mut stream: SendableRecordBatchStream,
+        query_ctx: &QueryContextRef,
+    ) -> Result<usize> {
+        let table_info = table.table_info();
+        let mut affected_rows = 0;
+        let mut pending: Option<Rows> = None;
+
+        while let Some(batch) = stream.next().await {
+            let record_batch = batch.context(ReadRecordBatchSnafu)?;
+            let columns = record_batch
+                .column_vectors(&table_info.name, table.schema())
+                .context(BuildColumnVectorsSnafu)?;
+            let num_rows = row_count(&columns)?;
+            if num_rows == 0 {
+                continue;
+            }
+
+            let rows = match &mut pending {
+                Some(rows) => rows,
+                None => pending.insert(Rows {
+                    schema: column_schema(&table_info, &columns)?,
+                    rows: Vec::with_capacity(INSERT_SELECT_BATCH_SIZE),
+                }),
+            };
+            // Orders the columns of each batch by the schema of the pending rows.
+            let vectors = rows
+                .schema
+                .iter()
+                .map(|column| {
+                    columns
+                        .get(&column.column_name)
+                        .with_context(|| UnexpectedSnafu {
+                            violated: format!(
+                                "column {} is missing in the query results",
+                                column.column_name
+                            ),
+                        })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            rows.rows.extend(
+                api::helper::vectors_to_rows(vectors.into_iter(), num_rows)
+                    .context(ColumnDataTypeSnafu)?,
+            );
+
+            if rows.rows.len() >= INSERT_SELECT_BATCH_SIZE {
+                let rows = Rows {
+                    schema: rows.schema.clone(),
+                    rows: std::mem::take(&mut rows.rows),
+                };
+                affected_rows += self
+                    .inserter
+                    .handle_table_rows(&table_info, rows, query_ctx)
+                    .await?;
+            }
+        }
+
+        if let Some(rows) = pending.filter(|rows| !rows.rows.is_empty()) {
+            affected_rows += self
+                .inserter
+                .handle_table_rows(&table_info, rows, query_ctx)
+                .await?;
+        }
+
+        Ok(affected_rows)
+    }
+
+    /// Returns the first of `assigned_columns` whose new value is NULL in any row to update.
+    async fn find_null_assigned_column(
+        &self,
+        dml_statement: &DmlStatement,
+        assigned_columns: &[String],
+        query_ctx: QueryContextRef,
+    ) -> Result<Option<String>> {
+        let input = dml_statement.input.as_ref();
+        let is_nulls = assigned_columns
+            .iter()
+            .map(|column| {
+                input
+                    .schema()
+                    .field_with_unqualified_name(column)
+                    .map(|field| Expr::Column(field.qualified_column()).is_null())
+            })
+            .collect::<DfResult<Vec<_>>>()
+            .context(BuildDfLogicalPlanSnafu)?;
+        let Some(predicate) = is_nulls.iter().cloned().reduce(Expr::or) else {
+            return Ok(None);
+        };
+        let plan = LogicalPlanBuilder::from(input.clone())
+            .filter(predicate)
+            .and_then(|builder| builder.project(is_nulls))
+            .and_then(|builder| builder.limit(0, Some(1)))
+            .and_then(|builder| builder.build())
+            .context(BuildDfLogicalPlanSnafu)?;
+
+        let mut stream = self
+            .execute_plan_to_stream(LogicalPlan::from(plan), query_ctx)
+            .await?;
+        while let Some(batch) = stream.next().await {
+            let batch = batch.context(ReadRecordBatchSnafu)?;
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let column = assigned_columns
+                .iter()
+                .enumerate()
+                .find(|(i, _)| batch.column(*i).get(0) == Value::Boolean(true))
+                .map(|(_, column)| column.clone());
+            return Ok(column);
+        }
+        Ok(None)
+    }
+
     async fn execute_dml_subquery(
         &self,
         dml_statement: &DmlStatement,
         query_ctx: QueryContextRef,
     ) -> Result<SendableRecordBatchStream> {
         let subquery_plan = LogicalPlan::from(dml_statement.input.as_ref().clone());
+        self.execute_plan_to_stream(subquery_plan, query_ctx).await
+    }
+
+    /// Executes the query plan and returns its results as a record batch stream.
+    pub(super) async fn execute_plan_to_stream(
+        &self,
+        plan: LogicalPlan,
+        query_ctx: QueryContextRef,
+    ) -> Result<SendableRecordBatchStream> {
         let output = self
             .query_engine
-            .execute(subquery_plan, query_ctx)
+            .execute(plan, query_ctx)
             .await
             .context(ExecLogicalPlanSnafu)?;
         match output {
@@ -168,24 +389,6 @@ fn extract_dml_statement(logical_plan: LogicalPlan) -> Result<DmlStatement> {
     }
 }
 
-fn build_insert_request(
-    record_batch: RecordBatch,
-    table_schema: SchemaRef,
-    table_info: &TableInfoRef,
-) -> Result<InsertRequest> {
-    let columns_values = record_batch
-        .column_vectors(&table_info.name, table_schema)
-        .context(BuildColumnVectorsSnafu)?;
-
-    Ok(InsertRequest {
-        catalog_name: table_info.catalog_name.clone(),
-        schema_name: table_info.schema_name.clone(),
-        table_name: table_info.name.clone(),
-        columns_values,
-        region_number: 0,
-    })
-}
-
 fn build_delete_request(
     record_batch: RecordBatch,
     table_schema: SchemaRef,
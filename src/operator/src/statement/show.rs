@@ -17,7 +17,7 @@ use common_query::Output;
 use partition::manager::PartitionInfo;
 use partition::partition::PartitionBound;
 use session::context::QueryContextRef;
-use snafu::ResultExt;
+use snafu::{OptionExt, ResultExt};
 use sql::ast::{Ident, Value as SqlValue};
 use sql::statements::create::{PartitionEntry, Partitions};
 use sql::statements::show::{ShowDatabases, ShowTables};
@@ -67,6 +67,18 @@ impl StatementExecutor {
         query::sql::show_create_table(table, partitions, query_ctx)
             .context(error::ExecuteStatementSnafu)
     }
+
+    pub fn show_create_view(&self, view: TableRef, query_ctx: QueryContextRef) -> Result<Output> {
+        let view_info = view.table_info();
+        let definition = view_info
+            .view_definition()
+            .context(error::ViewNotFoundSnafu {
+                name: &view_info.name,
+            })?;
+
+        query::sql::show_create_view(&view_info.name, definition, query_ctx)
+            .context(error::ExecuteStatementSnafu)
+    }
 }
 
 fn create_partitions_stmt(partitions: Vec<PartitionInfo>) -> Result<Option<Partitions>> {
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use session::context::QueryContextRef;
+use snafu::ensure;
 use sqlparser::ast::ObjectName;
+use table::metadata::{TableInfo, TableType};
 
-use crate::error::{InvalidSqlSnafu, Result};
+use crate::error::{InvalidSqlSnafu, Result, UnsupportedViewOperationSnafu};
 
 // TODO(LFC): Refactor consideration: move this function to some helper mod,
 // could be done together or after `TableReference`'s refactoring, when issue #559 is resolved.
@@ -47,3 +49,16 @@ pub fn table_idents_to_full_name(
         }.fail(),
     }
 }
+
+/// Returns an error if the table is a view, views have no data of their own, so they
+/// can't be written, altered or truncated.
+pub fn ensure_not_view(table_info: &TableInfo, operation: &str) -> Result<()> {
+    ensure!(
+        table_info.table_type != TableType::View,
+        UnsupportedViewOperationSnafu {
+            name: table_info.full_table_name(),
+            operation,
+        }
+    );
+    Ok(())
+}
@@ -35,15 +35,15 @@ use datafusion::sql::TableReference;
 use datatypes::arrow::datatypes::DataType as ArrowDataType;
 use promql_parser::label::{MatchOp, Matcher, Matchers, METRIC_NAME};
 use promql_parser::parser::{
-    token, AggregateExpr, BinaryExpr as PromBinaryExpr, Call, EvalStmt, Expr as PromExpr, Function,
-    LabelModifier, MatrixSelector, NumberLiteral, Offset, ParenExpr, StringLiteral, SubqueryExpr,
-    TokenType, UnaryExpr, VectorSelector,
+    token, AggregateExpr, AtModifier, BinaryExpr as PromBinaryExpr, Call, EvalStmt,
+    Expr as PromExpr, Function, LabelModifier, MatrixSelector, NumberLiteral, Offset, ParenExpr,
+    StringLiteral, SubqueryExpr, TokenType, UnaryExpr, VectorSelector,
 };
 use snafu::{ensure, OptionExt, ResultExt};
 use table::table::adapter::DfTableProviderAdapter;
 
 use crate::error::{
-    CatalogSnafu, ColumnNotFoundSnafu, DataFusionPlanningSnafu, ExpectExprSnafu,
+    CatalogSnafu, ColumnNotFoundSnafu, DataFusionPlanningSnafu, Error, ExpectExprSnafu,
     ExpectRangeSelectorSnafu, MultipleMetricMatchersSnafu, MultipleVectorSnafu,
     NoMetricMatcherSnafu, Result, TableNameNotFoundSnafu, TimeIndexNotFoundSnafu,
     UnexpectedPlanExprSnafu, UnexpectedTokenSnafu, UnknownTableSnafu, UnsupportedExprSnafu,
@@ -70,6 +70,12 @@ const DEFAULT_FIELD_COLUMN: &str = "value";
 /// Special modifier to project field columns under multi-field mode
 const FIELD_COLUMN_MATCHER: &str = "__field__";
 
+/// `absent()` function in PromQL.
+const SPECIAL_ABSENT_FUNCTION: &str = "absent";
+
+/// Alias of the input plan of `absent()`, to distinguish its columns from the generated ones.
+const ABSENT_INPUT_ALIAS: &str = "__absent_input";
+
 #[derive(Default, Debug, Clone)]
 struct PromPlannerContext {
     // query parameters
@@ -86,6 +92,9 @@ struct PromPlannerContext {
     field_column_matcher: Option<Vec<Matcher>>,
     /// The range in millisecond of range selector. None if there is no range selector.
     range: Option<Millisecond>,
+    /// Whether the range selector is pinned to a single timestamp by `@` modifier. The
+    /// result needs to be expanded to every evaluation step after range function is applied.
+    pinned_by_at: bool,
 }
 
 impl PromPlannerContext {
@@ -107,6 +116,7 @@ impl PromPlannerContext {
         self.tag_columns = vec![];
         self.field_column_matcher = None;
         self.range = None;
+        self.pinned_by_at = false;
     }
 }
 
@@ -358,10 +368,11 @@ impl PromPlanner {
                 name,
                 offset,
                 matchers,
-                at: _,
+                at,
             }) => {
                 let matchers = self.preprocess_label_matchers(matchers, name)?;
                 self.setup_context().await?;
+                let origin_range = self.pin_evaluation_time(at);
                 let normalize = self
                     .selector_to_series_normalize_plan(offset, matchers, false)
                     .await?;
@@ -377,19 +388,28 @@ impl PromPlanner {
                     self.ctx.field_columns.get(0).cloned(),
                     normalize,
                 );
-                LogicalPlan::Extension(Extension {
+                let plan = LogicalPlan::Extension(Extension {
                     node: Arc::new(manipulate),
-                })
+                });
+
+                if let Some((start, end)) = origin_range {
+                    self.ctx.start = start;
+                    self.ctx.end = end;
+                    self.expand_pinned_plan(plan)?
+                } else {
+                    plan
+                }
             }
             PromExpr::MatrixSelector(MatrixSelector { vs, range }) => {
                 let VectorSelector {
                     name,
                     offset,
                     matchers,
-                    ..
+                    at,
                 } = vs;
                 let matchers = self.preprocess_label_matchers(matchers, name)?;
                 self.setup_context().await?;
+                let origin_range = self.pin_evaluation_time(at);
 
                 ensure!(!range.is_zero(), ZeroRangeSelectorSnafu);
                 let range_ms = range.as_millis() as _;
@@ -413,6 +433,14 @@ impl PromPlanner {
                 )
                 .context(DataFusionPlanningSnafu)?;
 
+                // the range function is evaluated at the pinned timestamp, the result will be
+                // expanded to all steps after the function call
+                if let Some((start, end)) = origin_range {
+                    self.ctx.start = start;
+                    self.ctx.end = end;
+                    self.ctx.pinned_by_at = true;
+                }
+
                 LogicalPlan::Extension(Extension {
                     node: Arc::new(manipulate),
                 })
@@ -440,6 +468,10 @@ impl PromPlanner {
                     }));
                 }
 
+                if func.name == SPECIAL_ABSENT_FUNCTION {
+                    return self.create_absent_plan(&args.args, &prom_expr).await;
+                }
+
                 let args = self.create_function_args(&args.args)?;
                 let input = self
                     .prom_expr_to_plan(args.input.with_context(|| ExpectExprSnafu {
@@ -450,13 +482,19 @@ impl PromPlanner {
                 func_exprs.insert(0, self.create_time_index_column_expr()?);
                 func_exprs.extend_from_slice(&self.create_tag_column_exprs()?);
 
-                LogicalPlanBuilder::from(input)
+                let plan = LogicalPlanBuilder::from(input)
                     .project(func_exprs)
                     .context(DataFusionPlanningSnafu)?
                     .filter(self.create_empty_values_filter_expr()?)
                     .context(DataFusionPlanningSnafu)?
                     .build()
-                    .context(DataFusionPlanningSnafu)?
+                    .context(DataFusionPlanningSnafu)?;
+
+                if std::mem::take(&mut self.ctx.pinned_by_at) {
+                    self.expand_pinned_plan(plan)?
+                } else {
+                    plan
+                }
             }
             PromExpr::Extension(promql_parser::parser::ast::Extension { expr }) => {
                 let children = expr.children();
@@ -678,6 +716,221 @@ impl PromPlanner {
         Ok(logical_plan)
     }
 
+    /// Pin the evaluation time range to the timestamp specified by `@` modifier.
+    ///
+    /// Returns the original `(start, end)` range if the time is pinned. Caller should
+    /// restore it to context after the selector plan is built.
+    fn pin_evaluation_time(
+        &mut self,
+        at: &Option<AtModifier>,
+    ) -> Option<(Millisecond, Millisecond)> {
+        let timestamp = match at.as_ref()? {
+            AtModifier::Start => self.ctx.start,
+            AtModifier::End => self.ctx.end,
+            // timestamp in `@` modifier can be earlier than UNIX_EPOCH
+            AtModifier::At(time) => match time.duration_since(UNIX_EPOCH) {
+                Ok(duration) => duration.as_millis() as Millisecond,
+                Err(e) => -(e.duration().as_millis() as Millisecond),
+            },
+        };
+
+        let origin_range = (self.ctx.start, self.ctx.end);
+        self.ctx.start = timestamp;
+        self.ctx.end = timestamp;
+        Some(origin_range)
+    }
+
+    /// Expand the result of a pinned selector (which only contains one sample per series)
+    /// to every evaluation step in the range of context.
+    ///
+    /// This is done by cross joining the non-time-index columns of the input with a timestamp
+    /// series generated by [EmptyMetric].
+    fn expand_pinned_plan(&self, input: LogicalPlan) -> Result<LogicalPlan> {
+        let time_index_column = self
+            .ctx
+            .time_index_column
+            .clone()
+            .with_context(|| TimeIndexNotFoundSnafu { table: "unknown" })?;
+
+        let non_time_index_exprs = self
+            .create_tag_column_exprs()?
+            .into_iter()
+            .chain(
+                self.ctx
+                    .field_columns
+                    .iter()
+                    .map(|col| DfExpr::Column(Column::from_name(col))),
+            )
+            .collect::<Vec<_>>();
+        let pinned = LogicalPlanBuilder::from(input)
+            .project(non_time_index_exprs.clone())
+            .context(DataFusionPlanningSnafu)?
+            .build()
+            .context(DataFusionPlanningSnafu)?;
+
+        let steps = LogicalPlan::Extension(Extension {
+            node: Arc::new(
+                EmptyMetric::new(
+                    self.ctx.start,
+                    self.ctx.end,
+                    self.ctx.interval,
+                    time_index_column,
+                    DEFAULT_FIELD_COLUMN.to_string(),
+                    df_prelude::lit(0.0f64),
+                )
+                .context(DataFusionPlanningSnafu)?,
+            ),
+        });
+
+        let mut exprs = vec![self.create_time_index_column_expr()?];
+        exprs.extend(non_time_index_exprs);
+        LogicalPlanBuilder::from(steps)
+            .project(vec![self.create_time_index_column_expr()?])
+            .context(DataFusionPlanningSnafu)?
+            .cross_join(pinned)
+            .context(DataFusionPlanningSnafu)?
+            .project(exprs)
+            .context(DataFusionPlanningSnafu)?
+            .sort(self.create_tag_and_time_index_column_sort_exprs()?)
+            .context(DataFusionPlanningSnafu)?
+            .build()
+            .context(DataFusionPlanningSnafu)
+    }
+
+    /// Build plan for `absent()` function.
+    ///
+    /// The result contains value `1` at every step where the input vector has no sample.
+    /// Like Prometheus, labels of the result are inferred from the equality matchers of
+    /// the input selector.
+    async fn create_absent_plan(
+        &mut self,
+        args: &[Box<PromExpr>],
+        prom_expr: &PromExpr,
+    ) -> Result<LogicalPlan> {
+        let input_expr = match args {
+            [arg] => *arg.clone(),
+            _ => UnexpectedPlanExprSnafu {
+                desc: format!("expect exactly one argument for absent, but found {prom_expr:?}"),
+            }
+            .fail()?,
+        };
+        let labels = Self::absent_labels(&input_expr);
+
+        let input = match self.prom_expr_to_plan(input_expr).await {
+            Ok(input) => Some(input),
+            // A metric that doesn't exist has no sample at any step.
+            Err(Error::Catalog {
+                source: catalog::error::Error::TableNotExist { .. },
+                ..
+            }) => None,
+            Err(e) => return Err(e),
+        };
+        let input_table_ref = OwnedTableReference::bare(ABSENT_INPUT_ALIAS);
+        let time_index_column = if input.is_some() {
+            self.ctx
+                .time_index_column
+                .clone()
+                .with_context(|| TimeIndexNotFoundSnafu { table: "unknown" })?
+        } else {
+            DEFAULT_TIME_INDEX_COLUMN.to_string()
+        };
+
+        // existing timestamps in the input vector
+        let present = match input {
+            Some(input) => Some(
+                LogicalPlanBuilder::from(input)
+                    .project(vec![self.create_time_index_column_expr()?])
+                    .context(DataFusionPlanningSnafu)?
+                    .distinct()
+                    .context(DataFusionPlanningSnafu)?
+                    .alias(input_table_ref.clone())
+                    .context(DataFusionPlanningSnafu)?
+                    .build()
+                    .context(DataFusionPlanningSnafu)?,
+            ),
+            None => None,
+        };
+
+        let steps = LogicalPlan::Extension(Extension {
+            node: Arc::new(
+                EmptyMetric::new(
+                    self.ctx.start,
+                    self.ctx.end,
+                    self.ctx.interval,
+                    time_index_column.clone(),
+                    DEFAULT_FIELD_COLUMN.to_string(),
+                    df_prelude::lit(1.0f64),
+                )
+                .context(DataFusionPlanningSnafu)?,
+            ),
+        });
+
+        let mut exprs = vec![
+            DfExpr::Column(Column::new(Some(""), &time_index_column)),
+            DfExpr::Column(Column::new(Some(""), DEFAULT_FIELD_COLUMN)),
+        ];
+        exprs.extend(
+            labels
+                .iter()
+                .map(|(name, value)| df_prelude::lit(value.clone()).alias(name)),
+        );
+        let mut builder = LogicalPlanBuilder::from(steps);
+        if let Some(present) = present {
+            builder = builder
+                .join(
+                    present,
+                    JoinType::LeftAnti,
+                    (
+                        vec![Column::new(Some(""), &time_index_column)],
+                        vec![Column::new(Some(input_table_ref), &time_index_column)],
+                    ),
+                    None,
+                )
+                .context(DataFusionPlanningSnafu)?;
+        }
+        let plan = builder
+            .project(exprs)
+            .context(DataFusionPlanningSnafu)?
+            .build()
+            .context(DataFusionPlanningSnafu)?;
+
+        self.ctx.table_name = Some(String::new());
+        self.ctx.time_index_column = Some(time_index_column);
+        self.ctx.field_columns = vec![DEFAULT_FIELD_COLUMN.to_string()];
+        self.ctx.tag_columns = labels.into_iter().map(|(name, _)| name).collect();
+
+        Ok(plan)
+    }
+
+    /// Infer labels of `absent()`'s result from the equality matchers of the selector.
+    /// Label that appears in multiple equality matchers is ignored.
+    fn absent_labels(expr: &PromExpr) -> Vec<(String, String)> {
+        let matchers = match expr {
+            PromExpr::VectorSelector(vs) => &vs.matchers,
+            PromExpr::MatrixSelector(ms) => &ms.vs.matchers,
+            _ => return vec![],
+        };
+
+        let mut labels: Vec<(String, String)> = vec![];
+        let mut duplicated = HashSet::new();
+        for matcher in &matchers.matchers {
+            if !matches!(matcher.op, MatchOp::Equal)
+                || matcher.name == METRIC_NAME
+                || matcher.name == FIELD_COLUMN_MATCHER
+            {
+                continue;
+            }
+            if labels.iter().any(|(name, _)| name == &matcher.name) {
+                let _ = duplicated.insert(matcher.name.clone());
+            } else {
+                labels.push((matcher.name.clone(), matcher.value.clone()));
+            }
+        }
+        labels.retain(|(name, _)| !duplicated.contains(name));
+        labels.sort();
+        labels
+    }
+
     /// Convert [LabelModifier] to [Column] exprs for aggregation.
     /// Timestamp column and tag columns will be included.
     ///
@@ -1496,9 +1749,135 @@ mod test {
     }
 
     #[tokio::test]
-    #[should_panic]
     async fn single_absent() {
-        do_single_instant_function_call("absent", "").await;
+        let prom_expr =
+            parser::parse("absent(some_metric{tag_0=\"foo\", tag_1!=\"bar\"})").unwrap();
+        let eval_stmt = EvalStmt {
+            expr: prom_expr,
+            start: UNIX_EPOCH,
+            end: UNIX_EPOCH
+                .checked_add(Duration::from_secs(100_000))
+                .unwrap(),
+            interval: Duration::from_secs(5),
+            lookback_delta: Duration::from_secs(1),
+        };
+
+        let table_provider = build_test_table_provider("some_metric".to_string(), 2, 1).await;
+        let plan = PromPlanner::stmt_to_plan(table_provider, eval_stmt)
+            .await
+            .unwrap();
+        let plan = plan.display_indent_schema().to_string();
+
+        // only equality matchers are turned into labels
+        assert!(
+            plan.starts_with("Projection: .timestamp, .value, Utf8(\"foo\") AS tag_0 ["),
+            "{plan}"
+        );
+        assert!(
+            plan.contains("LeftAnti Join: .timestamp = __absent_input.timestamp"),
+            "{plan}"
+        );
+        assert!(
+            plan.contains("EmptyMetric: range=[0..100000000], interval=[5000]"),
+            "{plan}"
+        );
+    }
+
+    #[tokio::test]
+    async fn absent_of_missing_metric() {
+        let prom_expr = parser::parse("absent(missing_metric{tag_0=\"foo\"})").unwrap();
+        let eval_stmt = EvalStmt {
+            expr: prom_expr,
+            start: UNIX_EPOCH,
+            end: UNIX_EPOCH
+                .checked_add(Duration::from_secs(100_000))
+                .unwrap(),
+            interval: Duration::from_secs(5),
+            lookback_delta: Duration::from_secs(1),
+        };
+
+        let table_provider = build_test_table_provider("some_metric".to_string(), 1, 1).await;
+        let plan = PromPlanner::stmt_to_plan(table_provider, eval_stmt)
+            .await
+            .unwrap();
+        let plan = plan.display_indent_schema().to_string();
+
+        assert!(
+            plan.starts_with("Projection: .time, .value, Utf8(\"foo\") AS tag_0 ["),
+            "{plan}"
+        );
+        assert!(!plan.contains("Join"), "{plan}");
+        assert!(
+            plan.contains("EmptyMetric: range=[0..100000000], interval=[5000]"),
+            "{plan}"
+        );
+    }
+
+    #[test]
+    fn absent_labels_ignore_duplicated_matchers() {
+        let expr = parser::parse("some_metric{job=\"a\", job=\"b\", instance=\"c\"}").unwrap();
+        assert_eq!(
+            PromPlanner::absent_labels(&expr),
+            vec![("instance".to_string(), "c".to_string())]
+        );
+
+        let expr = parser::parse("sum(some_metric{job=\"a\"})").unwrap();
+        assert!(PromPlanner::absent_labels(&expr).is_empty());
+    }
+
+    async fn do_at_modifier_plan(query: &str) -> String {
+        let prom_expr = parser::parse(query).unwrap();
+        let eval_stmt = EvalStmt {
+            expr: prom_expr,
+            start: UNIX_EPOCH,
+            end: UNIX_EPOCH
+                .checked_add(Duration::from_secs(100_000))
+                .unwrap(),
+            interval: Duration::from_secs(5),
+            lookback_delta: Duration::from_secs(1),
+        };
+
+        let table_provider = build_test_table_provider("some_metric".to_string(), 1, 1).await;
+        PromPlanner::stmt_to_plan(table_provider, eval_stmt)
+            .await
+            .unwrap()
+            .display_indent_schema()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn vector_selector_at_modifier() {
+        let plan = do_at_modifier_plan("some_metric @ 100 offset 10s").await;
+        assert!(plan.starts_with("Sort: "), "{plan}");
+        assert!(plan.contains("CrossJoin:"), "{plan}");
+        assert!(
+            plan.contains("EmptyMetric: range=[0..100000000], interval=[5000]"),
+            "{plan}"
+        );
+        assert!(
+            plan.contains("PromInstantManipulate: range=[100000..100000]"),
+            "{plan}"
+        );
+        assert!(
+            plan.contains("PromSeriesNormalize: offset=[10000]"),
+            "{plan}"
+        );
+
+        let plan = do_at_modifier_plan("some_metric @ end()").await;
+        assert!(
+            plan.contains("PromInstantManipulate: range=[100000000..100000000]"),
+            "{plan}"
+        );
+    }
+
+    #[tokio::test]
+    async fn matrix_selector_at_modifier() {
+        let plan = do_at_modifier_plan("rate(some_metric[5m] @ start())").await;
+        assert!(plan.contains("CrossJoin:"), "{plan}");
+        assert!(
+            plan.contains("PromRangeManipulate: req range=[0..0]"),
+            "{plan}"
+        );
     }
 
     #[tokio::test]
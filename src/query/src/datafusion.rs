@@ -82,6 +82,10 @@ impl DatafusionQueryEngine {
         plan: LogicalPlan,
         query_ctx: QueryContextRef,
     ) -> Result<Output> {
+        if let Some(usage) = query_ctx.resource_usage() {
+            usage.set_plan(plan.display_indent().to_string());
+        }
+
         let mut ctx = QueryEngineContext::new(self.state.session_state(), query_ctx.clone());
 
         // `create_physical_plan` will optimize logical plan internally
@@ -30,7 +30,6 @@ use datafusion_common::{DataFusionError, OwnedTableReference};
 use datafusion_expr::{TableSource, WindowUDF};
 use datafusion_physical_expr::var_provider::{is_system_variables, VarType};
 use datafusion_sql::parser::Statement as DfStatement;
-use session::context::QueryContextRef;
 use snafu::ResultExt;
 
 use crate::error::{CatalogSnafu, DataFusionSnafu, Result};
@@ -48,18 +47,12 @@ impl DfContextProviderAdapter {
         engine_state: Arc<QueryEngineState>,
         session_state: SessionState,
         df_stmt: &DfStatement,
-        query_ctx: QueryContextRef,
+        mut table_provider: DfTableSourceProvider,
     ) -> Result<Self> {
         let table_names = session_state
             .resolve_table_references(df_stmt)
             .context(DataFusionSnafu)?;
 
-        let mut table_provider = DfTableSourceProvider::new(
-            engine_state.catalog_manager().clone(),
-            engine_state.disallow_cross_schema_query(),
-            query_ctx.as_ref(),
-        );
-
         let tables = resolve_tables(table_names, &mut table_provider).await?;
 
         Ok(Self {
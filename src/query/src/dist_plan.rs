@@ -16,6 +16,7 @@ mod analyzer;
 mod commutativity;
 mod merge_scan;
 mod planner;
+mod step_aggr;
 
 pub use analyzer::DistPlannerAnalyzer;
 pub use merge_scan::MergeScanLogicalPlan;
@@ -21,7 +21,7 @@ use datafusion_common::config::ConfigOptions;
 use datafusion_common::tree_node::{RewriteRecursion, Transformed, TreeNode, TreeNodeRewriter};
 use datafusion_expr::expr::{Exists, InSubquery};
 use datafusion_expr::utils::from_plan;
-use datafusion_expr::{col, Expr, LogicalPlan, LogicalPlanBuilder, Subquery};
+use datafusion_expr::{col, Expr, Limit, LogicalPlan, LogicalPlanBuilder, Subquery};
 use datafusion_optimizer::analyzer::AnalyzerRule;
 use substrait::{DFLogicalSubstraitConvertor, SubstraitPlan};
 use table::metadata::TableType;
@@ -123,6 +123,11 @@ struct PlanRewriter {
     status: RewriterStatus,
     /// Partition columns of the table in current pass
     partition_cols: Option<Vec<String>>,
+    /// Replaces the node to be expanded, i.e. the transformed parent of the last node
+    new_child_plan: Option<LogicalPlan>,
+    /// Expand on the next call of `should_expand`, as plans above a transformed one
+    /// can't be executed on datanodes
+    expand_on_next_call: bool,
 }
 
 impl PlanRewriter {
@@ -137,7 +142,32 @@ impl PlanRewriter {
 
     /// Return true if should stop and expand. The input plan is the parent node of current node
     fn should_expand(&mut self, plan: &LogicalPlan) -> bool {
-        if DFLogicalSubstraitConvertor.encode(plan).is_err() {
+        if self.expand_on_next_call {
+            // A limit over a pushed down sort is a top-k, each region only needs to
+            // return its first `skip + fetch` rows.
+            if let LogicalPlan::Limit(limit) = plan
+                && let Some(fetch) = limit.fetch
+                && matches!(self.stage.last(), Some(LogicalPlan::Sort(_)))
+                && self.new_child_plan.is_none()
+            {
+                self.stage.push(plan.clone());
+                self.new_child_plan = Some(LogicalPlan::Limit(Limit {
+                    skip: 0,
+                    fetch: Some(limit.skip + fetch),
+                    input: limit.input.clone(),
+                }));
+                return false;
+            }
+            self.expand_on_next_call = false;
+            return true;
+        }
+
+        let commutativity = Categorizer::check_plan(plan);
+        // Transformed plans are checked after transformation, only the transformed one is
+        // sent to datanodes.
+        if !matches!(commutativity, Commutativity::TransformedCommutative(_))
+            && DFLogicalSubstraitConvertor.encode(plan).is_err()
+        {
             info!(
                 "substrait error: {:?}",
                 DFLogicalSubstraitConvertor.encode(plan)
@@ -145,7 +175,7 @@ impl PlanRewriter {
             return true;
         }
 
-        match Categorizer::check_plan(plan) {
+        match commutativity {
             Commutativity::Commutative => {}
             Commutativity::PartialCommutative => {
                 if let Some(plan) = partial_commutative_transformer(plan) {
@@ -159,10 +189,20 @@ impl PlanRewriter {
                 }
             },
             Commutativity::TransformedCommutative(transformer) => {
-                if let Some(transformer) = transformer
-                    && let Some(plan) = transformer(plan) {
-                    self.stage.push(plan)
+                // stages of the plans below must be applied before the transformed ones
+                if !self.stage.is_empty() {
+                    return true;
+                }
+                let Some(action) = transformer.and_then(|transformer| transformer(plan)) else {
+                    return true;
+                };
+                if let Some(new_child_plan) = &action.new_child_plan
+                    && DFLogicalSubstraitConvertor.encode(new_child_plan).is_err() {
+                    return true;
                 }
+                self.stage.extend(action.extra_parent_plans);
+                self.new_child_plan = action.new_child_plan;
+                self.expand_on_next_call = true;
             },
             Commutativity::CheckPartition
             | Commutativity::NonCommutative
@@ -224,6 +264,24 @@ impl PlanRewriter {
         self.level -= 1;
         self.stack.pop();
     }
+
+    /// Put the node under a merge scan and apply the stages on top of it
+    fn expand(&mut self, mut node: LogicalPlan) -> DfResult<LogicalPlan> {
+        self.expand_on_next_call = false;
+        if let Some(new_child_plan) = self.new_child_plan.take() {
+            let inputs = node.inputs().into_iter().cloned().collect::<Vec<_>>();
+            node = new_child_plan.with_new_inputs(&inputs)?;
+        }
+
+        let mut node = MergeScanLogicalPlan::new(node, false).into_logical_plan();
+        // expand stages
+        for new_stage in self.stage.drain(..) {
+            node = new_stage.with_new_inputs(&[node])?
+        }
+        self.set_expanded();
+
+        Ok(node)
+    }
 }
 
 impl TreeNodeRewriter for PlanRewriter {
@@ -237,6 +295,8 @@ impl TreeNodeRewriter for PlanRewriter {
         self.stage.clear();
         self.set_unexpanded();
         self.partition_cols = None;
+        self.new_child_plan = None;
+        self.expand_on_next_call = false;
 
         Ok(RewriteRecursion::Continue)
     }
@@ -255,12 +315,7 @@ impl TreeNodeRewriter for PlanRewriter {
 
         let Some(parent) = self.get_parent() else {
             // add merge scan as the new root
-            let mut node = MergeScanLogicalPlan::new(node, false).into_logical_plan();
-            // expand stages
-            for new_stage in self.stage.drain(..) {
-                node = new_stage.with_new_inputs(&[node])?
-            }
-            self.set_expanded();
+            let node = self.expand(node)?;
 
             self.pop_stack();
             return Ok(node);
@@ -270,12 +325,7 @@ impl TreeNodeRewriter for PlanRewriter {
         if self.should_expand(&parent.clone()) {
             // TODO(ruihang): does this work for nodes with multiple children?;
             // replace the current node with expanded one
-            let mut node = MergeScanLogicalPlan::new(node, false).into_logical_plan();
-            // expand stages
-            for new_stage in self.stage.drain(..) {
-                node = new_stage.with_new_inputs(&[node])?
-            }
-            self.set_expanded();
+            let node = self.expand(node)?;
 
             self.pop_stack();
             return Ok(node);
@@ -348,8 +398,9 @@ mod test {
         let config = ConfigOptions::default();
         let result = DistPlannerAnalyzer {}.analyze(plan, &config).unwrap();
         let expected = [
-            "Aggregate: groupBy=[[]], aggr=[[AVG(t.number)]]",
-            "  MergeScan [is_placeholder=false]",
+            "Projection: CAST(SUM(SUM(t.number)) AS Float64) / CAST(SUM(COUNT(t.number)) AS Float64) AS AVG(t.number)",
+            "  Aggregate: groupBy=[[]], aggr=[[SUM(SUM(t.number)), SUM(COUNT(t.number))]]",
+            "    MergeScan [is_placeholder=false]",
         ]
         .join("\n");
         assert_eq!(expected, format!("{:?}", result));
@@ -406,6 +457,56 @@ mod test {
         assert_eq!(expected, format!("{:?}", result));
     }
 
+    #[test]
+    fn transform_sort_limit() {
+        let numbers_table = NumbersTable::table(0);
+        let table_source = Arc::new(DefaultTableSource::new(Arc::new(
+            DfTableProviderAdapter::new(numbers_table),
+        )));
+
+        let plan = LogicalPlanBuilder::scan_with_filters("t", table_source, None, vec![])
+            .unwrap()
+            .sort(vec![col("number").sort(false, true)])
+            .unwrap()
+            .limit(1, Some(10))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let config = ConfigOptions::default();
+        let result = DistPlannerAnalyzer {}.analyze(plan, &config).unwrap();
+        let expected = [
+            "Limit: skip=1, fetch=10",
+            "  Sort: t.number DESC NULLS FIRST",
+            "    MergeScan [is_placeholder=false]",
+        ]
+        .join("\n");
+        assert_eq!(expected, format!("{:?}", result));
+
+        // each region returns its top 11 rows
+        let LogicalPlan::Limit(limit) = &result else {
+            unreachable!()
+        };
+        let LogicalPlan::Sort(sort) = limit.input.as_ref() else {
+            unreachable!()
+        };
+        let LogicalPlan::Extension(extension) = sort.input.as_ref() else {
+            unreachable!()
+        };
+        let merge_scan = extension
+            .node
+            .as_any()
+            .downcast_ref::<MergeScanLogicalPlan>()
+            .unwrap();
+        let expected = [
+            "Limit: skip=0, fetch=11",
+            "  Sort: t.number DESC NULLS FIRST",
+            "    TableScan: t",
+        ]
+        .join("\n");
+        assert_eq!(expected, format!("{:?}", merge_scan.input()));
+    }
+
     #[test]
     fn transform_unalighed_join_with_alias() {
         let left = NumbersTable::table(0);
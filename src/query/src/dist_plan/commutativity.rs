@@ -19,6 +19,7 @@ use promql::extension_plan::{
     EmptyMetric, InstantManipulate, RangeManipulate, SeriesDivide, SeriesNormalize,
 };
 
+use crate::dist_plan::step_aggr::step_aggr_transformer;
 use crate::dist_plan::MergeScanLogicalPlan;
 
 #[allow(dead_code)]
@@ -26,7 +27,7 @@ pub enum Commutativity {
     Commutative,
     PartialCommutative,
     ConditionalCommutative(Option<Transformer>),
-    TransformedCommutative(Option<Transformer>),
+    TransformedCommutative(Option<StageTransformer>),
     NonCommutative,
     Unimplemented,
     CheckPartition,
@@ -52,10 +53,13 @@ impl Categorizer {
             LogicalPlan::Filter(filter) => Self::check_expr(&filter.predicate),
             LogicalPlan::Window(_) => Commutativity::Unimplemented,
             LogicalPlan::Aggregate(_) => {
-                // check all children exprs and uses the strictest level
-                Commutativity::Unimplemented
+                // split into a partial aggregate on datanodes and a final one on frontend
+                Commutativity::TransformedCommutative(Some(Arc::new(step_aggr_transformer)))
+            }
+            LogicalPlan::Sort(_) => {
+                // sort in each region, and merge the sorted streams on frontend
+                Commutativity::TransformedCommutative(Some(Arc::new(sort_transformer)))
             }
-            LogicalPlan::Sort(_) => Commutativity::Unimplemented,
             LogicalPlan::Join(_) => Commutativity::NonCommutative,
             LogicalPlan::CrossJoin(_) => Commutativity::NonCommutative,
             LogicalPlan::Repartition(_) => {
@@ -146,6 +150,28 @@ impl Categorizer {
 
 pub type Transformer = Arc<dyn Fn(&LogicalPlan) -> Option<LogicalPlan>>;
 
+/// The result of transforming a [Commutativity::TransformedCommutative] plan.
+pub struct TransformerAction {
+    /// Plans to stack on top of the merge scan, from bottom to top.
+    pub extra_parent_plans: Vec<LogicalPlan>,
+    /// The plan that replaces the transformed one in the sub-plan executed on datanodes.
+    pub new_child_plan: Option<LogicalPlan>,
+}
+
+pub type StageTransformer = Arc<dyn Fn(&LogicalPlan) -> Option<TransformerAction>>;
+
 pub fn partial_commutative_transformer(plan: &LogicalPlan) -> Option<LogicalPlan> {
     Some(plan.clone())
 }
+
+/// Pushes the sort down to the regions and keeps a copy of it on top of the merge scan,
+/// which is then satisfied by merging the sorted streams of the regions.
+pub fn sort_transformer(plan: &LogicalPlan) -> Option<TransformerAction> {
+    if !matches!(plan, LogicalPlan::Sort(_)) {
+        return None;
+    }
+    Some(TransformerAction {
+        extra_parent_plans: vec![plan.clone()],
+        new_child_plan: None,
+    })
+}
@@ -22,10 +22,16 @@ use common_error::ext::BoxedError;
 use common_meta::table_name::TableName;
 use common_query::physical_plan::TaskContext;
 use common_recordbatch::adapter::DfRecordBatchStreamAdapter;
-use common_recordbatch::error::ExternalSnafu;
+use common_recordbatch::error::{
+    ExternalSnafu, MergeSortedStreamsSnafu, NewDfRecordBatchSnafu, QueryCancelledSnafu,
+    Result as RecordBatchResult,
+};
 use common_recordbatch::{
-    DfSendableRecordBatchStream, RecordBatch, RecordBatchStreamAdaptor, SendableRecordBatchStream,
+    DfRecordBatch, DfSendableRecordBatchStream, RecordBatch, RecordBatchStreamAdaptor,
+    SendableRecordBatchStream,
 };
+use datafusion::arrow::compute::interleave;
+use datafusion::arrow::row::{Row, RowConverter, Rows, SortField};
 use datafusion::physical_plan::metrics::{
     Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet, Time,
 };
@@ -34,10 +40,13 @@ use datafusion_common::{DataFusionError, Result, Statistics};
 use datafusion_expr::{Extension, LogicalPlan, UserDefinedLogicalNodeCore};
 use datafusion_physical_expr::PhysicalSortExpr;
 use datatypes::schema::{Schema, SchemaRef};
+use futures_util::stream::BoxStream;
 use futures_util::StreamExt;
 use greptime_proto::v1::region::QueryRequest;
-use snafu::ResultExt;
+use session::resource_usage::QueryResourceUsage;
+use snafu::{OptionExt, ResultExt};
 use store_api::storage::RegionId;
+use tokio_util::sync::CancellationToken;
 
 use crate::error::ConvertSchemaSnafu;
 use crate::region_query::RegionQueryHandlerRef;
@@ -115,6 +124,8 @@ pub struct MergeScanExec {
     arrow_schema: ArrowSchemaRef,
     region_query_handler: RegionQueryHandlerRef,
     metric: ExecutionPlanMetricsSet,
+    /// Order of the rows in each region, `None` if they are not sorted
+    output_ordering: Option<Vec<PhysicalSortExpr>>,
 }
 
 impl std::fmt::Debug for MergeScanExec {
@@ -146,42 +157,67 @@ impl MergeScanExec {
             arrow_schema: arrow_schema_without_metadata,
             region_query_handler,
             metric: ExecutionPlanMetricsSet::new(),
+            output_ordering: None,
         })
     }
 
-    pub fn to_stream(&self, _context: Arc<TaskContext>) -> Result<SendableRecordBatchStream> {
-        let substrait_plan = self.substrait_plan.to_vec();
-        let regions = self.regions.clone();
-        let region_query_handler = self.region_query_handler.clone();
+    /// Sets the order of the rows returned by each region. The streams of the regions
+    /// are merged to keep this order.
+    pub fn with_output_ordering(mut self, output_ordering: Vec<PhysicalSortExpr>) -> Self {
+        self.output_ordering = Some(output_ordering);
+        self
+    }
+
+    pub fn to_stream(&self, context: Arc<TaskContext>) -> Result<SendableRecordBatchStream> {
         let metric = MergeScanMetric::new(&self.metric);
+        let resource_usage = context
+            .session_config()
+            .get_extension::<QueryResourceUsage>();
+        let cancel_token = context
+            .session_config()
+            .get_extension::<CancellationToken>()
+            .map(|token| token.as_ref().clone())
+            .unwrap_or_default();
+
+        let region_streams = self
+            .regions
+            .iter()
+            .map(|region_id| {
+                Self::region_stream(
+                    *region_id,
+                    self.substrait_plan.to_vec(),
+                    self.region_query_handler.clone(),
+                    resource_usage.clone(),
+                    cancel_token.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut merged = match &self.output_ordering {
+            Some(ordering) => SortedStreamsMerger::merge(
+                region_streams,
+                ordering.clone(),
+                self.arrow_schema.clone(),
+                context.session_config().batch_size(),
+            ),
+            // the regions are scanned one by one
+            None => futures_util::stream::iter(region_streams).flatten().boxed(),
+        };
 
         let stream = Box::pin(stream!({
             let _finish_timer = metric.finish_time().timer();
-            let mut ready_timer = metric.ready_time().timer();
+            let mut ready_timer = Some(metric.ready_time().timer());
             let mut first_consume_timer = Some(metric.first_consume_time().timer());
 
-            for region_id in regions {
-                let request = QueryRequest {
-                    header: None,
-                    region_id: region_id.into(),
-                    plan: substrait_plan.clone(),
-                };
-                let mut stream = region_query_handler
-                    .do_get(request)
-                    .await
-                    .map_err(BoxedError::new)
-                    .context(ExternalSnafu)?;
-
-                ready_timer.stop();
-
-                while let Some(batch) = stream.next().await {
-                    let batch = batch?;
-                    metric.record_output_batch_rows(batch.num_rows());
-                    yield Ok(Self::remove_metadata_from_record_batch(batch));
-
-                    if let Some(first_consume_timer) = first_consume_timer.as_mut().take() {
-                        first_consume_timer.stop();
-                    }
+            while let Some(batch) = merged.next().await {
+                if let Some(mut ready_timer) = ready_timer.take() {
+                    ready_timer.stop();
+                }
+                let batch = batch?;
+                metric.record_output_batch_rows(batch.num_rows());
+                yield Ok(batch);
+
+                if let Some(mut first_consume_timer) = first_consume_timer.take() {
+                    first_consume_timer.stop();
                 }
             }
         }));
@@ -193,6 +229,52 @@ impl MergeScanExec {
         }))
     }
 
+    /// Queries one region, the stream is cancelled along with the query.
+    fn region_stream(
+        region_id: RegionId,
+        substrait_plan: Vec<u8>,
+        region_query_handler: RegionQueryHandlerRef,
+        resource_usage: Option<Arc<QueryResourceUsage>>,
+        cancel_token: CancellationToken,
+    ) -> BoxStream<'static, RecordBatchResult<RecordBatch>> {
+        Box::pin(stream!({
+            let request = QueryRequest {
+                header: None,
+                region_id: region_id.into(),
+                plan: substrait_plan,
+            };
+            // Dropping the sub-query stream cancels it on the datanode.
+            let stream = tokio::select! {
+                stream = region_query_handler.do_get(request) => Some(stream),
+                _ = cancel_token.cancelled() => None,
+            };
+            let mut stream = stream
+                .context(QueryCancelledSnafu)?
+                .map_err(BoxedError::new)
+                .context(ExternalSnafu)?;
+
+            loop {
+                let batch = tokio::select! {
+                    batch = stream.next() => Some(batch),
+                    _ = cancel_token.cancelled() => None,
+                };
+                let Some(batch) = batch.context(QueryCancelledSnafu)? else {
+                    break;
+                };
+                let batch = batch?;
+                if let Some(usage) = &resource_usage {
+                    let memory_size = batch
+                        .columns()
+                        .iter()
+                        .map(|column| column.memory_size())
+                        .sum();
+                    usage.record_region_scan(region_id.as_u64(), batch.num_rows(), memory_size);
+                }
+                yield Ok(Self::remove_metadata_from_record_batch(batch));
+            }
+        }))
+    }
+
     fn remove_metadata_from_record_batch(batch: RecordBatch) -> RecordBatch {
         let arrow_schema = batch.schema.arrow_schema().as_ref();
         let arrow_schema_without_metadata = Self::arrow_schema_without_metadata(arrow_schema);
@@ -235,7 +317,7 @@ impl ExecutionPlan for MergeScanExec {
     }
 
     fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
-        None
+        self.output_ordering.as_deref()
     }
 
     fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
@@ -280,6 +362,178 @@ impl DisplayAs for MergeScanExec {
     }
 }
 
+/// Merges the sorted streams of the regions into one sorted stream.
+///
+/// The next row is picked by comparing the current row of each stream, which is cheap
+/// enough for the number of regions a table has. Picked rows are buffered as indices
+/// and copied out in batches of `batch_size` rows.
+struct SortedStreamsMerger {
+    streams: Vec<BoxStream<'static, RecordBatchResult<RecordBatch>>>,
+    ordering: Vec<PhysicalSortExpr>,
+    converter: RowConverter,
+    batch_size: usize,
+    /// Current batch of each stream, `None` if the stream is exhausted
+    cursors: Vec<Option<Cursor>>,
+    /// Batches the buffered rows come from
+    batches: Vec<RecordBatch>,
+    /// `(batch, row)` indices of the buffered rows, in output order
+    indices: Vec<(usize, usize)>,
+}
+
+struct Cursor {
+    /// Index of the batch in [SortedStreamsMerger::batches]
+    batch: usize,
+    /// Sort keys of the batch
+    rows: Rows,
+    /// The next row to output
+    row: usize,
+}
+
+impl Cursor {
+    fn current(&self) -> Row<'_> {
+        self.rows.row(self.row)
+    }
+}
+
+impl SortedStreamsMerger {
+    fn merge(
+        streams: Vec<BoxStream<'static, RecordBatchResult<RecordBatch>>>,
+        ordering: Vec<PhysicalSortExpr>,
+        schema: ArrowSchemaRef,
+        batch_size: usize,
+    ) -> BoxStream<'static, RecordBatchResult<RecordBatch>> {
+        Box::pin(stream!({
+            let mut merger = Self::try_new(streams, ordering, &schema, batch_size)?;
+            for i in 0..merger.streams.len() {
+                merger.advance(i).await?;
+            }
+
+            while let Some(i) = merger.min_cursor() {
+                merger.push_row(i).await?;
+                if merger.indices.len() >= merger.batch_size {
+                    yield merger.build_output();
+                }
+            }
+            if !merger.indices.is_empty() {
+                yield merger.build_output();
+            }
+        }))
+    }
+
+    fn try_new(
+        streams: Vec<BoxStream<'static, RecordBatchResult<RecordBatch>>>,
+        ordering: Vec<PhysicalSortExpr>,
+        schema: &ArrowSchema,
+        batch_size: usize,
+    ) -> RecordBatchResult<Self> {
+        let sort_fields = ordering
+            .iter()
+            .map(|expr| {
+                let data_type = expr.expr.data_type(schema)?;
+                Ok(SortField::new_with_options(data_type, expr.options))
+            })
+            .collect::<Result<Vec<_>>>()
+            .context(MergeSortedStreamsSnafu)?;
+        let converter = RowConverter::new(sort_fields)
+            .map_err(DataFusionError::from)
+            .context(MergeSortedStreamsSnafu)?;
+        let cursors = streams.iter().map(|_| None).collect();
+        Ok(Self {
+            streams,
+            ordering,
+            converter,
+            batch_size: batch_size.max(1),
+            cursors,
+            batches: vec![],
+            indices: vec![],
+        })
+    }
+
+    /// Returns the stream whose current row comes first, `None` if all streams are
+    /// exhausted. Ties are broken by the order of the streams.
+    fn min_cursor(&self) -> Option<usize> {
+        self.cursors
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cursor)| cursor.as_ref().map(|cursor| (i, cursor.current())))
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(i, _)| i)
+    }
+
+    /// Buffers the current row of the `i`-th stream and moves to its next row.
+    async fn push_row(&mut self, i: usize) -> RecordBatchResult<()> {
+        let cursor = self.cursors[i].as_mut().unwrap();
+        self.indices.push((cursor.batch, cursor.row));
+        cursor.row += 1;
+        if cursor.row == cursor.rows.num_rows() {
+            self.advance(i).await?;
+        }
+        Ok(())
+    }
+
+    /// Moves the cursor of the `i`-th stream to its next non-empty batch.
+    async fn advance(&mut self, i: usize) -> RecordBatchResult<()> {
+        self.cursors[i] = None;
+        while let Some(batch) = self.streams[i].next().await {
+            let batch = batch?;
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let rows = self.sort_keys(&batch).context(MergeSortedStreamsSnafu)?;
+            self.batches.push(batch);
+            self.cursors[i] = Some(Cursor {
+                batch: self.batches.len() - 1,
+                rows,
+                row: 0,
+            });
+            break;
+        }
+        Ok(())
+    }
+
+    fn sort_keys(&self, batch: &RecordBatch) -> Result<Rows> {
+        let batch = batch.df_record_batch();
+        let columns = self
+            .ordering
+            .iter()
+            .map(|expr| {
+                expr.expr
+                    .evaluate(batch)
+                    .map(|value| value.into_array(batch.num_rows()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.converter.convert_columns(&columns)?)
+    }
+
+    /// Copies the buffered rows out, and drops the batches no cursor points to.
+    fn build_output(&mut self) -> RecordBatchResult<RecordBatch> {
+        let schema = self.batches[0].schema.clone();
+        let columns = (0..schema.num_columns())
+            .map(|i| {
+                let arrays = self
+                    .batches
+                    .iter()
+                    .map(|batch| batch.df_record_batch().column(i).as_ref())
+                    .collect::<Vec<_>>();
+                interleave(&arrays, &self.indices)
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context(NewDfRecordBatchSnafu)?;
+        let df_record_batch = DfRecordBatch::try_new(schema.arrow_schema().clone(), columns)
+            .context(NewDfRecordBatchSnafu)?;
+        self.indices.clear();
+
+        let mut batches = Vec::with_capacity(self.cursors.len());
+        for cursor in self.cursors.iter_mut().flatten() {
+            batches.push(self.batches[cursor.batch].clone());
+            cursor.batch = batches.len() - 1;
+        }
+        self.batches = batches;
+
+        RecordBatch::try_from_df_record_batch(schema, df_record_batch)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct MergeScanMetric {
     /// Nanosecond elapsed till the scan operator is ready to emit data
@@ -318,3 +572,58 @@ impl MergeScanMetric {
         self.output_rows.add(num_rows);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use arrow_schema::SortOptions;
+    use datafusion_physical_expr::expressions::Column;
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::ColumnSchema;
+    use datatypes::vectors::{Int32Vector, VectorRef};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_merge_sorted_streams() {
+        let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+            "a",
+            ConcreteDataType::int32_datatype(),
+            false,
+        )]));
+        let region_stream = |batches: Vec<Vec<i32>>| {
+            let batches = batches
+                .into_iter()
+                .map(|values| {
+                    let column: VectorRef = Arc::new(Int32Vector::from_vec(values));
+                    RecordBatch::new(schema.clone(), vec![column])
+                })
+                .collect::<Vec<_>>();
+            futures_util::stream::iter(batches).boxed()
+        };
+        let streams = vec![
+            region_stream(vec![vec![9, 5], vec![], vec![5, 1]]),
+            region_stream(vec![]),
+            region_stream(vec![vec![8, 5, 2]]),
+        ];
+        let ordering = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("a", 0)),
+            options: SortOptions {
+                descending: true,
+                nulls_first: true,
+            },
+        }];
+
+        let batches =
+            SortedStreamsMerger::merge(streams, ordering, schema.arrow_schema().clone(), 3)
+                .map(|batch| {
+                    let batch = batch.unwrap();
+                    let column = batch.column(0);
+                    (0..batch.num_rows())
+                        .map(|i| column.get_ref(i).as_i32().unwrap().unwrap())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+                .await;
+        assert_eq!(vec![vec![9, 8, 5], vec![5, 5, 2], vec![1]], batches);
+    }
+}
@@ -16,6 +16,7 @@
 
 use std::sync::Arc;
 
+use arrow_schema::{Schema as ArrowSchema, SortOptions};
 use async_trait::async_trait;
 use catalog::CatalogManagerRef;
 use common_catalog::consts::{DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME};
@@ -26,9 +27,11 @@ use datafusion::execution::context::SessionState;
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::physical_planner::{ExtensionPlanner, PhysicalPlanner};
 use datafusion_common::tree_node::{Transformed, TreeNode, TreeNodeVisitor, VisitRecursion};
-use datafusion_common::TableReference;
-use datafusion_expr::{LogicalPlan, UserDefinedLogicalNode};
+use datafusion_common::{DataFusionError, TableReference};
+use datafusion_expr::expr::Sort as SortExpr;
+use datafusion_expr::{Expr, LogicalPlan, UserDefinedLogicalNode};
 use datafusion_optimizer::analyzer::Analyzer;
+use datafusion_physical_expr::{create_physical_expr, PhysicalSortExpr};
 use snafu::{OptionExt, ResultExt};
 use store_api::storage::RegionId;
 use substrait::{DFLogicalSubstraitConvertor, SubstraitPlan};
@@ -103,13 +106,16 @@ impl ExtensionPlanner for DistExtensionPlanner {
             .encode(&amended_plan)
             .context(error::EncodeSubstraitLogicalPlanSnafu)?
             .into();
-        let merge_scan_plan = MergeScanExec::new(
+        let mut merge_scan_plan = MergeScanExec::new(
             table_name,
             regions,
             substrait_plan,
             &schema,
             self.region_query_handler.clone(),
         )?;
+        if let Some(ordering) = Self::output_ordering(&optimized_plan, &schema, session_state)? {
+            merge_scan_plan = merge_scan_plan.with_output_ordering(ordering);
+        }
         Ok(Some(Arc::new(merge_scan_plan) as _))
     }
 }
@@ -127,6 +133,52 @@ impl DistExtensionPlanner {
         plan.transform(&|plan| TableNameRewriter::rewrite_table_name(plan, name))
     }
 
+    /// Returns the order of the rows returned by each region, if the input plan ends
+    /// with a sort.
+    fn output_ordering(
+        plan: &LogicalPlan,
+        schema: &ArrowSchema,
+        session_state: &SessionState,
+    ) -> Result<Option<Vec<PhysicalSortExpr>>> {
+        let sort = match plan {
+            LogicalPlan::Sort(sort) => sort,
+            LogicalPlan::Limit(limit) => match limit.input.as_ref() {
+                LogicalPlan::Sort(sort) => sort,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        sort.expr
+            .iter()
+            .map(|expr| {
+                let Expr::Sort(SortExpr {
+                    expr,
+                    asc,
+                    nulls_first,
+                }) = expr
+                else {
+                    return Err(DataFusionError::Internal(format!(
+                        "Expect a sort expression, got {expr}"
+                    )));
+                };
+                Ok(PhysicalSortExpr {
+                    expr: create_physical_expr(
+                        expr,
+                        plan.schema(),
+                        schema,
+                        session_state.execution_props(),
+                    )?,
+                    options: SortOptions {
+                        descending: !asc,
+                        nulls_first: *nulls_first,
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
     async fn get_regions(&self, table_name: &TableName) -> Result<Vec<RegionId>> {
         let table = self
             .catalog_manager
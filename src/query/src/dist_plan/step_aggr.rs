@@ -0,0 +1,266 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Splits an aggregate into a partial aggregate, executed by each region, and a final
+//! aggregate that merges the partial states in the frontend.
+
+use std::sync::Arc;
+
+use arrow_schema::DataType;
+use datafusion_common::{Column, Result as DfResult};
+use datafusion_expr::expr::{AggregateFunction, Cast};
+use datafusion_expr::{
+    Aggregate, AggregateFunction as AggregateFn, Expr, ExprSchemable, LogicalPlan, Projection,
+};
+
+use crate::dist_plan::commutativity::TransformerAction;
+
+/// Transforms an aggregate plan into its partial and final phases. Returns `None` if
+/// the plan is not an aggregate, or it has any function whose partial results can't
+/// be merged.
+pub fn step_aggr_transformer(plan: &LogicalPlan) -> Option<TransformerAction> {
+    let LogicalPlan::Aggregate(aggr) = plan else {
+        return None;
+    };
+    StepAggrBuilder::default().build(aggr).ok().flatten()
+}
+
+/// How an original aggregate function is computed from the final aggregate.
+enum Merge {
+    /// The output of a final aggregate function.
+    Single(usize),
+    /// The merged sum divided by the merged count.
+    Avg { sum: usize, count: usize },
+}
+
+#[derive(Default)]
+struct StepAggrBuilder {
+    /// Aggregate functions executed by the regions.
+    partial_exprs: Vec<Expr>,
+    /// Aggregate functions merging the output of `partial_exprs`.
+    final_exprs: Vec<Expr>,
+}
+
+impl StepAggrBuilder {
+    fn build(mut self, aggr: &Aggregate) -> DfResult<Option<TransformerAction>> {
+        if aggr
+            .group_expr
+            .iter()
+            .any(|expr| matches!(expr, Expr::GroupingSet(_)))
+        {
+            return Ok(None);
+        }
+
+        let group_len = aggr.group_expr.len();
+        let aggr_fields = &aggr.schema.fields()[group_len..];
+        let mut merges = Vec::with_capacity(aggr.aggr_expr.len());
+        for (expr, field) in aggr.aggr_expr.iter().zip(aggr_fields) {
+            let Expr::AggregateFunction(AggregateFunction {
+                fun,
+                args,
+                distinct: false,
+                filter: None,
+                order_by: None,
+            }) = expr
+            else {
+                return Ok(None);
+            };
+            // TODO: support `COUNT(*)`. It's expanded by an analyzer rule after this one,
+            // which renames the references to it but not the alias restoring its name.
+            if args.iter().any(|arg| matches!(arg, Expr::Wildcard)) {
+                return Ok(None);
+            }
+            let args = args.clone();
+
+            let merge = match fun {
+                AggregateFn::Sum | AggregateFn::Count => {
+                    Merge::Single(self.add(fun.clone(), args, AggregateFn::Sum)?)
+                }
+                AggregateFn::Min | AggregateFn::Max => {
+                    Merge::Single(self.add(fun.clone(), args, fun.clone())?)
+                }
+                // The average of decimals is decimal, which can't be computed exactly
+                // from the merged states.
+                AggregateFn::Avg if *field.data_type() == DataType::Float64 => Merge::Avg {
+                    sum: self.add(AggregateFn::Sum, args.clone(), AggregateFn::Sum)?,
+                    count: self.add(AggregateFn::Count, args, AggregateFn::Sum)?,
+                },
+                _ => return Ok(None),
+            };
+            merges.push(merge);
+        }
+
+        let partial_plan = LogicalPlan::Aggregate(Aggregate::try_new(
+            aggr.input.clone(),
+            aggr.group_expr.clone(),
+            self.partial_exprs,
+        )?);
+
+        let group_exprs = partial_plan.schema().fields()[..group_len]
+            .iter()
+            .map(|field| Expr::Column(field.qualified_column()))
+            .collect();
+        let final_plan = LogicalPlan::Aggregate(Aggregate::try_new(
+            Arc::new(partial_plan.clone()),
+            group_exprs,
+            self.final_exprs,
+        )?);
+
+        // Restores the output of the original aggregate.
+        let final_schema = final_plan.schema();
+        let final_columns = final_schema
+            .fields()
+            .iter()
+            .map(|field| Expr::Column(field.qualified_column()))
+            .collect::<Vec<_>>();
+        let mut exprs = final_columns[..group_len].to_vec();
+        for (merge, field) in merges.into_iter().zip(aggr_fields) {
+            let expr = match merge {
+                Merge::Single(i) => final_columns[group_len + i].clone(),
+                Merge::Avg { sum, count } => {
+                    cast(final_columns[group_len + sum].clone(), DataType::Float64)
+                        / cast(final_columns[group_len + count].clone(), DataType::Float64)
+                }
+            };
+            let expr = if expr.get_type(final_schema)? == *field.data_type() {
+                expr
+            } else {
+                cast(expr, field.data_type().clone())
+            };
+            exprs.push(expr.alias(field.name()));
+        }
+        let projection =
+            LogicalPlan::Projection(Projection::try_new(exprs, Arc::new(final_plan.clone()))?);
+
+        Ok(Some(TransformerAction {
+            extra_parent_plans: vec![final_plan, projection],
+            new_child_plan: Some(partial_plan),
+        }))
+    }
+
+    /// Adds `partial_fun(args)` to the partial aggregate and `final_fun` over its output
+    /// to the final aggregate, returns the index of the latter.
+    fn add(
+        &mut self,
+        partial_fun: AggregateFn,
+        args: Vec<Expr>,
+        final_fun: AggregateFn,
+    ) -> DfResult<usize> {
+        let partial_expr = aggregate(partial_fun, args);
+        let partial_name = partial_expr.display_name()?;
+        let _ = push_unique(&mut self.partial_exprs, partial_expr);
+
+        let final_expr = aggregate(
+            final_fun,
+            vec![Expr::Column(Column::from_name(partial_name))],
+        );
+        Ok(push_unique(&mut self.final_exprs, final_expr))
+    }
+}
+
+fn aggregate(fun: AggregateFn, args: Vec<Expr>) -> Expr {
+    Expr::AggregateFunction(AggregateFunction::new(fun, args, false, None, None))
+}
+
+fn cast(expr: Expr, data_type: DataType) -> Expr {
+    Expr::Cast(Cast::new(Box::new(expr), data_type))
+}
+
+/// Returns the index of `expr` in `exprs`, pushing it if it's not there.
+fn push_unique(exprs: &mut Vec<Expr>, expr: Expr) -> usize {
+    match exprs.iter().position(|e| *e == expr) {
+        Some(i) => i,
+        None => {
+            exprs.push(expr);
+            exprs.len() - 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::datasource::DefaultTableSource;
+    use datafusion_expr::{avg, col, count, max, LogicalPlanBuilder};
+    use table::table::adapter::DfTableProviderAdapter;
+    use table::table::numbers::NumbersTable;
+
+    use super::*;
+
+    fn numbers_scan() -> LogicalPlanBuilder {
+        let table_source = Arc::new(DefaultTableSource::new(Arc::new(
+            DfTableProviderAdapter::new(NumbersTable::table(0)),
+        )));
+        LogicalPlanBuilder::scan_with_filters("t", table_source, None, vec![]).unwrap()
+    }
+
+    #[test]
+    fn test_step_aggr() {
+        let plan = numbers_scan()
+            .aggregate(
+                vec![col("number")],
+                vec![avg(col("number")), count(col("number")), max(col("number"))],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let action = step_aggr_transformer(&plan).unwrap();
+        let partial_plan = action.new_child_plan.unwrap();
+        assert_eq!(
+            "Aggregate: groupBy=[[t.number]], aggr=[[SUM(t.number), COUNT(t.number), MAX(t.number)]]\
+            \n  TableScan: t",
+            format!("{partial_plan:?}")
+        );
+
+        let [final_plan, projection] = &action.extra_parent_plans[..] else {
+            unreachable!()
+        };
+        assert_eq!(
+            "Aggregate: groupBy=[[t.number]], aggr=[[SUM(SUM(t.number)), SUM(COUNT(t.number)), MAX(MAX(t.number))]]",
+            format!("{final_plan:?}").lines().next().unwrap()
+        );
+        assert_eq!(
+            "Projection: t.number, CAST(SUM(SUM(t.number)) AS Float64) / CAST(SUM(COUNT(t.number)) AS Float64) AS AVG(t.number), SUM(COUNT(t.number)) AS COUNT(t.number), MAX(MAX(t.number)) AS MAX(t.number)",
+            format!("{projection:?}").lines().next().unwrap()
+        );
+        // The transformed plans produce the same output as the original one.
+        let output_fields = |plan: &LogicalPlan| {
+            plan.schema()
+                .fields()
+                .iter()
+                .map(|field| (field.qualified_name(), field.data_type().clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(output_fields(&plan), output_fields(projection));
+    }
+
+    #[test]
+    fn test_unsupported_step_aggr() {
+        let plan = numbers_scan()
+            .aggregate(
+                Vec::<Expr>::new(),
+                vec![Expr::AggregateFunction(AggregateFunction::new(
+                    AggregateFn::Count,
+                    vec![col("number")],
+                    true,
+                    None,
+                    None,
+                ))],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(step_aggr_transformer(&plan).is_none());
+    }
+}
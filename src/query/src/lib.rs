@@ -29,7 +29,7 @@ pub mod physical_wrapper;
 pub mod plan;
 pub mod planner;
 pub mod query_engine;
-mod range_select;
+pub mod range_select;
 pub mod region_query;
 pub mod sql;
 
@@ -15,17 +15,24 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use catalog::table_source::DfTableSourceProvider;
+use catalog::table_source::{DfTableSourceProvider, ViewPlanner};
 use common_error::ext::BoxedError;
 use datafusion::execution::context::SessionState;
+use datafusion_expr::LogicalPlan as DfLogicalPlan;
+use datafusion_optimizer::analyzer::inline_table_scan::InlineTableScan;
+use datafusion_optimizer::analyzer::AnalyzerRule;
 use datafusion_sql::planner::{ParserOptions, SqlToRel};
 use promql::planner::PromPlanner;
 use promql_parser::parser::EvalStmt;
 use session::context::QueryContextRef;
-use snafu::ResultExt;
+use snafu::{ensure, ResultExt};
+use sql::dialect::GreptimeDbDialect;
+use sql::parser::ParserContext;
 use sql::statements::statement::Statement;
 
-use crate::error::{PlanSqlSnafu, QueryPlanSnafu, Result, SqlSnafu};
+use crate::error::{
+    DataFusionSnafu, MultipleStatementsSnafu, PlanSqlSnafu, QueryPlanSnafu, Result, SqlSnafu,
+};
 use crate::parser::QueryStatement;
 use crate::plan::LogicalPlan;
 use crate::query_engine::QueryEngineState;
@@ -51,20 +58,26 @@ impl DfLogicalPlanner {
         }
     }
 
-    async fn plan_sql(&self, stmt: Statement, query_ctx: QueryContextRef) -> Result<LogicalPlan> {
-        let df_stmt = (&stmt).try_into().context(SqlSnafu)?;
-
-        let table_provider = DfTableSourceProvider::new(
+    /// Creates a table provider which resolves views by this planner.
+    fn table_provider(&self, query_ctx: &QueryContextRef) -> DfTableSourceProvider {
+        DfTableSourceProvider::new(
             self.engine_state.catalog_manager().clone(),
             self.engine_state.disallow_cross_schema_query(),
             query_ctx.as_ref(),
-        );
+        )
+        .with_view_planner(Arc::new(DfLogicalPlanner::new(self.engine_state.clone())))
+    }
+
+    async fn plan_sql(&self, stmt: Statement, query_ctx: QueryContextRef) -> Result<LogicalPlan> {
+        let df_stmt = (&stmt).try_into().context(SqlSnafu)?;
+
+        let table_provider = self.table_provider(&query_ctx);
 
         let context_provider = DfContextProviderAdapter::try_new(
             self.engine_state.clone(),
             self.session_state.clone(),
             &df_stmt,
-            query_ctx,
+            self.table_provider(&query_ctx),
         )
         .await?;
 
@@ -91,17 +104,46 @@ impl DfLogicalPlanner {
     }
 
     async fn plan_pql(&self, stmt: EvalStmt, query_ctx: QueryContextRef) -> Result<LogicalPlan> {
-        let table_provider = DfTableSourceProvider::new(
-            self.engine_state.catalog_manager().clone(),
-            self.engine_state.disallow_cross_schema_query(),
-            query_ctx.as_ref(),
-        );
+        let table_provider = self.table_provider(&query_ctx);
         PromPlanner::stmt_to_plan(table_provider, stmt)
             .await
             .map(LogicalPlan::DfPlan)
             .map_err(BoxedError::new)
             .context(QueryPlanSnafu)
     }
+
+    async fn plan_view_definition(
+        &self,
+        definition: &str,
+        query_ctx: QueryContextRef,
+    ) -> Result<DfLogicalPlan> {
+        let mut stmts = ParserContext::create_with_dialect(definition, &GreptimeDbDialect {})
+            .context(SqlSnafu)?;
+        ensure!(
+            stmts.len() == 1,
+            MultipleStatementsSnafu { query: definition }
+        );
+
+        let LogicalPlan::DfPlan(plan) = self.plan_sql(stmts.remove(0), query_ctx).await?;
+        // Inlines the views referenced by this view, so the plan of a view never contains
+        // other views.
+        InlineTableScan::new()
+            .analyze(plan, self.session_state.config().options())
+            .context(DataFusionSnafu)
+    }
+}
+
+#[async_trait]
+impl ViewPlanner for DfLogicalPlanner {
+    async fn plan_view(
+        &self,
+        definition: &str,
+        query_ctx: QueryContextRef,
+    ) -> std::result::Result<DfLogicalPlan, BoxedError> {
+        self.plan_view_definition(definition, query_ctx)
+            .await
+            .map_err(BoxedError::new)
+    }
 }
 
 #[async_trait]
@@ -14,8 +14,12 @@
 
 use std::sync::Arc;
 
+use datafusion::error::Result as DfResult;
 use datafusion::execution::context::{SessionState, TaskContext};
+use datafusion::execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use datafusion::execution::runtime_env::RuntimeEnv;
 use session::context::QueryContextRef;
+use session::resource_usage::QueryResourceUsageRef;
 
 #[derive(Debug)]
 pub struct QueryEngineContext {
@@ -41,14 +45,70 @@ impl QueryEngineContext {
     pub fn build_task_ctx(&self) -> Arc<TaskContext> {
         let task_id = self.query_ctx.trace_id().to_string();
         let state = &self.state;
+        let mut config = state.config().clone();
+        let mut runtime_env = state.runtime_env().clone();
+        // Exposes the usage to the operators and accounts the memory they reserve.
+        if let Some(usage) = self.query_ctx.resource_usage() {
+            runtime_env = Arc::new(RuntimeEnv {
+                memory_pool: Arc::new(TrackedMemoryPool {
+                    inner: runtime_env.memory_pool.clone(),
+                    usage: usage.clone(),
+                }),
+                disk_manager: runtime_env.disk_manager.clone(),
+                object_store_registry: runtime_env.object_store_registry.clone(),
+            });
+            config = config.with_extension(usage);
+        }
+        // Lets the operators that wait on remote regions stop once the query is cancelled.
+        if let Some(cancel_token) = self.query_ctx.cancel_token() {
+            config = config.with_extension(Arc::new(cancel_token));
+        }
         Arc::new(TaskContext::new(
             Some(task_id),
             state.session_id().to_string(),
-            state.config().clone(),
+            config,
             state.scalar_functions().clone(),
             state.aggregate_functions().clone(),
             state.window_functions().clone(),
-            state.runtime_env().clone(),
+            runtime_env,
         ))
     }
 }
+
+/// A [MemoryPool] that records the memory reserved by one query in its
+/// [QueryResourceUsage](session::resource_usage::QueryResourceUsage).
+#[derive(Debug)]
+struct TrackedMemoryPool {
+    inner: Arc<dyn MemoryPool>,
+    usage: QueryResourceUsageRef,
+}
+
+impl MemoryPool for TrackedMemoryPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        self.inner.register(consumer)
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        self.inner.unregister(consumer)
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.inner.grow(reservation, additional);
+        self.usage.grow_memory(additional);
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.inner.shrink(reservation, shrink);
+        self.usage.shrink_memory(shrink);
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> DfResult<()> {
+        self.inner.try_grow(reservation, additional)?;
+        self.usage.grow_memory(additional);
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        self.inner.reserved()
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_catalog::consts::INFORMATION_SCHEMA_NAME;
+use common_catalog::consts::{INFORMATION_SCHEMA_NAME, PG_CATALOG_NAME};
 use session::context::QueryContextRef;
 use snafu::ensure;
 
@@ -29,8 +29,10 @@ pub fn validate_catalog_and_schema(
     schema: &str,
     query_ctx: &QueryContextRef,
 ) -> Result<()> {
-    // information_schema is an exception
-    if schema.eq_ignore_ascii_case(INFORMATION_SCHEMA_NAME) {
+    // information_schema and pg_catalog are exceptions
+    if schema.eq_ignore_ascii_case(INFORMATION_SCHEMA_NAME)
+        || schema.eq_ignore_ascii_case(PG_CATALOG_NAME)
+    {
         return Ok(());
     }
 
@@ -65,5 +67,6 @@ mod tests {
         assert!(re.is_err());
 
         validate_catalog_and_schema("greptime", "information_schema", &context).unwrap();
+        validate_catalog_and_schema("greptime", "pg_catalog", &context).unwrap();
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod incremental;
 pub mod plan;
 pub mod plan_rewrite;
 pub mod planner;
@@ -0,0 +1,280 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to evaluate a range query incrementally, window by window.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_schema::DataType;
+use datafusion_common::tree_node::{Transformed, TreeNode, VisitRecursion};
+use datafusion_common::{Result as DfResult, ScalarValue};
+use datafusion_expr::expr::Cast;
+use datafusion_expr::{
+    min, Expr, Extension, Filter, LogicalPlan as DfLogicalPlan, LogicalPlanBuilder,
+    UserDefinedLogicalNodeCore,
+};
+use snafu::ResultExt;
+
+use crate::error::{DataFusionSnafu, Result};
+use crate::plan::LogicalPlan;
+use crate::range_select::plan::RangeSelect;
+
+/// Time windows of a range query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeWindow {
+    /// Name of the time index column, it holds the align timestamp of each window.
+    pub time_index: String,
+    pub align: Duration,
+    /// The longest range of all range expressions.
+    pub max_range: Duration,
+    /// Names of the `BY` columns.
+    pub by: Vec<String>,
+}
+
+fn as_range_select(plan: &DfLogicalPlan) -> Option<&RangeSelect> {
+    match plan {
+        DfLogicalPlan::Extension(Extension { node }) => node.as_any().downcast_ref::<RangeSelect>(),
+        _ => None,
+    }
+}
+
+/// Returns the windows of the first range select in the plan, `None` if the plan isn't
+/// a range query.
+pub fn find_range_window(plan: &LogicalPlan) -> Option<RangeWindow> {
+    let LogicalPlan::DfPlan(plan) = plan;
+    let mut window = None;
+    let _ = plan.apply(&mut |plan| {
+        let Some(range_select) = as_range_select(plan) else {
+            return Ok(VisitRecursion::Continue);
+        };
+        window = Some(RangeWindow {
+            time_index: range_select.time_index.clone(),
+            align: range_select.align,
+            max_range: range_select
+                .range_expr
+                .iter()
+                .map(|range_fn| range_fn.range)
+                .max()
+                .unwrap_or_default(),
+            by: range_select
+                .by_schema
+                .fields()
+                .iter()
+                .map(|field| field.name().clone())
+                .collect(),
+        });
+        Ok(VisitRecursion::Stop)
+    });
+    window
+}
+
+/// Returns a plan that selects the minimum time index (after `after` in milliseconds if
+/// present) of rows the first range select in the plan aggregates, `None` if the plan isn't
+/// a range query.
+pub fn min_time_index_plan(plan: &LogicalPlan, after: Option<i64>) -> Result<Option<LogicalPlan>> {
+    let LogicalPlan::DfPlan(plan) = plan;
+    let mut input = None;
+    let _ = plan.apply(&mut |plan| {
+        let Some(range_select) = as_range_select(plan) else {
+            return Ok(VisitRecursion::Continue);
+        };
+        input = Some((range_select.input.clone(), range_select.time_index.clone()));
+        Ok(VisitRecursion::Stop)
+    });
+    let Some((input, time_index)) = input else {
+        return Ok(None);
+    };
+
+    let field = input
+        .schema()
+        .field_with_unqualified_name(&time_index)
+        .context(DataFusionSnafu)?;
+    let column = Expr::Column(field.qualified_column());
+    let mut builder = LogicalPlanBuilder::from(input.as_ref().clone());
+    if let Some(after) = after {
+        builder = builder
+            .filter(
+                column
+                    .clone()
+                    .gt(timestamp_literal(after, field.data_type())),
+            )
+            .context(DataFusionSnafu)?;
+    }
+    let plan = builder
+        .aggregate(Vec::<Expr>::new(), vec![min(column)])
+        .and_then(|builder| builder.build())
+        .context(DataFusionSnafu)?;
+    Ok(Some(LogicalPlan::DfPlan(plan)))
+}
+
+/// Restricts the range query to rows that may contribute to windows whose align timestamp
+/// (in milliseconds) is in `(start, end]`. `start` is unbounded if it's `None`.
+///
+/// Windows out of `(start, end]` may still be returned with partial results, callers
+/// should discard them.
+pub fn restrict_range_windows(
+    plan: LogicalPlan,
+    start: Option<i64>,
+    end: i64,
+) -> Result<LogicalPlan> {
+    let LogicalPlan::DfPlan(plan) = plan;
+    let plan = plan
+        .transform_down(&|plan| {
+            let Some(range_select) = as_range_select(&plan) else {
+                return Ok(Transformed::No(plan));
+            };
+            let max_range = range_select
+                .range_expr
+                .iter()
+                .map(|range_fn| range_fn.range)
+                .max()
+                .unwrap_or_default();
+            // A row at `ts` contributes to windows in `[ts, ts + range)`.
+            let start = start.map(|start| start - max_range.as_millis() as i64);
+            let range_select = filter_range_input(range_select, start, end)?;
+            Ok(Transformed::Yes(DfLogicalPlan::Extension(Extension {
+                node: Arc::new(range_select),
+            })))
+        })
+        .context(DataFusionSnafu)?;
+    Ok(LogicalPlan::DfPlan(plan))
+}
+
+/// Filters the input of `range_select` by `start < time index <= end`.
+fn filter_range_input(
+    range_select: &RangeSelect,
+    start: Option<i64>,
+    end: i64,
+) -> DfResult<RangeSelect> {
+    let field = range_select
+        .input
+        .schema()
+        .field_with_unqualified_name(&range_select.time_index)?;
+    let column = Expr::Column(field.qualified_column());
+    let timestamp = |millis: i64| timestamp_literal(millis, field.data_type());
+
+    let mut predicate = column.clone().lt_eq(timestamp(end));
+    if let Some(start) = start {
+        predicate = column.gt(timestamp(start)).and(predicate);
+    }
+    let input = DfLogicalPlan::Filter(Filter::try_new(predicate, range_select.input.clone())?);
+
+    Ok(range_select.from_template(&[], &[input]))
+}
+
+/// Returns the timestamp in milliseconds as a literal of the time index type.
+fn timestamp_literal(millis: i64, data_type: &DataType) -> Expr {
+    Expr::Cast(Cast::new(
+        Box::new(Expr::Literal(ScalarValue::TimestampMillisecond(
+            Some(millis),
+            None,
+        ))),
+        data_type.clone(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use session::context::QueryContext;
+
+    use super::*;
+    use crate::parser::QueryLanguageParser;
+    use crate::range_select::plan_rewrite::test::create_test_engine;
+
+    async fn plan_sql(sql: &str) -> LogicalPlan {
+        let stmt = QueryLanguageParser::parse_sql(sql).unwrap();
+        create_test_engine()
+            .await
+            .planner()
+            .plan(stmt, QueryContext::arc())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_find_range_window() {
+        let plan = plan_sql(
+            "SELECT timestamp, tag_0, avg(field_0) RANGE '5m', max(field_1) RANGE '10m' FROM test ALIGN '1h' by (tag_0)",
+        )
+        .await;
+        assert_eq!(
+            Some(RangeWindow {
+                time_index: "timestamp".to_string(),
+                align: Duration::from_secs(3600),
+                max_range: Duration::from_secs(600),
+                by: vec!["tag_0".to_string()],
+            }),
+            find_range_window(&plan)
+        );
+
+        let plan = plan_sql("SELECT timestamp, field_0 FROM test").await;
+        assert!(find_range_window(&plan).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_min_time_index_plan() {
+        let plan = plan_sql(
+            "SELECT timestamp, tag_0, avg(field_0) RANGE '5m' FROM test ALIGN '1h' by (tag_0)",
+        )
+        .await;
+        let LogicalPlan::DfPlan(min_plan) = min_time_index_plan(&plan, None).unwrap().unwrap();
+        let display = min_plan.display_indent().to_string();
+        assert!(
+            display.contains("Aggregate: groupBy=[[]], aggr=[[MIN(test.timestamp)]]"),
+            "{display}"
+        );
+        assert!(!display.contains("Filter:"), "{display}");
+
+        let LogicalPlan::DfPlan(min_plan) = min_time_index_plan(&plan, Some(3_600_000))
+            .unwrap()
+            .unwrap();
+        let display = min_plan.display_indent().to_string();
+        assert!(
+            display.contains("Filter: test.timestamp > CAST(TimestampMillisecond(3600000, None)"),
+            "{display}"
+        );
+
+        let plan = plan_sql("SELECT timestamp, field_0 FROM test").await;
+        assert!(min_time_index_plan(&plan, None).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_restrict_range_windows() {
+        let plan = plan_sql(
+            "SELECT timestamp, tag_0, avg(field_0) RANGE '5m' FROM test ALIGN '1h' by (tag_0)",
+        )
+        .await;
+
+        let LogicalPlan::DfPlan(restricted) =
+            restrict_range_windows(plan.clone(), Some(3_600_000), 7_200_000).unwrap();
+        let display = restricted.display_indent().to_string();
+        assert!(
+            display.contains("Filter: test.timestamp > CAST(TimestampMillisecond(3300000, None)"),
+            "{display}"
+        );
+        assert!(
+            display.contains("test.timestamp <= CAST(TimestampMillisecond(7200000, None)"),
+            "{display}"
+        );
+
+        let LogicalPlan::DfPlan(restricted) =
+            restrict_range_windows(plan, None, 7_200_000).unwrap();
+        let display = restricted.display_indent().to_string();
+        assert!(
+            display.contains("Filter: test.timestamp <= CAST(TimestampMillisecond(7200000, None)"),
+            "{display}"
+        );
+    }
+}
@@ -49,16 +49,142 @@ use datatypes::arrow::row::{OwnedRow, RowConverter, SortField};
 use futures::{ready, Stream};
 use futures_util::StreamExt;
 use snafu::ResultExt;
+use table::predicate::TimeRangePredicateBuilder;
 
 use crate::error::{DataFusionSnafu, Result};
 
 type Millisecond = <TimestampMillisecondType as ArrowPrimitiveType>::Native;
 
+/// The maximum number of rows a RANGE query with FILL may output, counting the
+/// filled time slots of all series.
+const MAX_FILL_ROWS: usize = 1_000_000;
+
+/// How to fill the null values of a range expression, including the ones of the time
+/// slots a series has no data in.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub enum Fill {
+    /// Leaves them null.
+    Null,
+    /// Uses the previous non-null value of the series.
+    Prev,
+    /// Interpolates linearly between the previous and the next non-null values of the series.
+    Linear,
+    /// Uses a constant.
+    Const(ScalarValue),
+}
+
+impl Display for Fill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fill::Null => write!(f, "NULL"),
+            Fill::Prev => write!(f, "PREV"),
+            Fill::Linear => write!(f, "LINEAR"),
+            Fill::Const(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Fill {
+    /// Parses the `FILL` option of a range expression whose output type is `data_type`.
+    /// Returns `None` if the option is empty.
+    pub fn try_from_str(value: &str, data_type: &DataType) -> DfResult<Option<Self>> {
+        match value.to_uppercase().as_str() {
+            "" => Ok(None),
+            "NULL" => Ok(Some(Fill::Null)),
+            "PREV" => Ok(Some(Fill::Prev)),
+            "LINEAR" => {
+                if data_type.is_numeric() {
+                    Ok(Some(Fill::Linear))
+                } else {
+                    Err(DataFusionError::Plan(format!(
+                        "Use FILL LINEAR on non-numeric type {}",
+                        data_type
+                    )))
+                }
+            }
+            _ => ScalarValue::try_from_string(value.to_string(), data_type)
+                .ok()
+                .filter(|value| !value.is_null())
+                .map(|value| Some(Fill::Const(value)))
+                .ok_or_else(|| {
+                    DataFusionError::Plan(format!(
+                        "{} is not a valid fill option, expect NULL, PREV, LINEAR or a constant of type {}",
+                        value, data_type
+                    ))
+                }),
+        }
+    }
+
+    /// Fills the null values of a series, `ts` are the time slots of `values` in
+    /// ascending order.
+    pub fn apply_fill_strategy(
+        &self,
+        ts: &[Millisecond],
+        values: &mut [ScalarValue],
+    ) -> DfResult<()> {
+        match self {
+            Fill::Null => {}
+            Fill::Prev => {
+                let mut prev = None;
+                for value in values.iter_mut() {
+                    if value.is_null() {
+                        if let Some(prev) = &prev {
+                            *value = prev.clone();
+                        }
+                    } else {
+                        prev = Some(value.clone());
+                    }
+                }
+            }
+            Fill::Linear => {
+                // nulls before the first value and after the last value are kept
+                let mut prev: Option<usize> = None;
+                for i in 0..values.len() {
+                    if values[i].is_null() {
+                        continue;
+                    }
+                    if let Some(prev) = prev
+                        && prev + 1 < i
+                    {
+                        let data_type = values[i].get_datatype();
+                        let (x0, y0) = (ts[prev], scalar_to_f64(&values[prev])?);
+                        let (x1, y1) = (ts[i], scalar_to_f64(&values[i])?);
+                        for j in prev + 1..i {
+                            let y = y0 + (y1 - y0) * (ts[j] - x0) as f64 / (x1 - x0) as f64;
+                            values[j] = ScalarValue::Float64(Some(y)).cast_to(&data_type)?;
+                        }
+                    }
+                    prev = Some(i);
+                }
+            }
+            Fill::Const(constant) => {
+                for value in values.iter_mut() {
+                    if value.is_null() {
+                        *value = constant.clone();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn scalar_to_f64(value: &ScalarValue) -> DfResult<f64> {
+    match value.cast_to(&DataType::Float64)? {
+        ScalarValue::Float64(Some(value)) => Ok(value),
+        _ => Err(DataFusionError::Execution(format!(
+            "Cannot interpolate value {}",
+            value
+        ))),
+    }
+}
+
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub struct RangeFn {
     pub expr: Expr,
     pub range: Duration,
-    pub fill: String,
+    /// `None` if no `FILL` is given, then only the time slots with data are returned
+    pub fill: Option<Fill>,
 }
 
 impl Display for RangeFn {
@@ -68,7 +194,10 @@ impl Display for RangeFn {
             "RangeFn {{ expr:{} range:{}s fill:{} }}",
             self.expr.display_name().unwrap_or("?".into()),
             self.range.as_secs(),
-            self.fill,
+            self.fill
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
         )
     }
 }
@@ -92,6 +221,9 @@ pub struct RangeSelect {
     /// `schema_before_project  ----  schema_project ----> schema`
     /// if `schema_project==None` then `schema_before_project==schema`
     pub schema_before_project: DFSchemaRef,
+    /// The `[start, end)` millisecond range the filters of the input restrict the time index to,
+    /// `None` on an unbounded side.
+    pub query_range: (Option<Millisecond>, Option<Millisecond>),
 }
 
 impl RangeSelect {
@@ -109,9 +241,8 @@ impl RangeSelect {
                 Ok(DFField::new_unqualified(
                     &expr.display_name()?,
                     expr.get_type(input.schema())?,
-                    // TODO(Taylor-lagrange): We have not implemented fill currently,
-                    // it is possible that some columns may not be able to aggregate data,
-                    // so we temporarily set that all data is nullable
+                    // Time slots may have no data to aggregate, and not all fill
+                    // strategies fill all nulls, e.g. `FILL PREV` at the start of a series
                     true,
                 ))
             })
@@ -122,6 +253,7 @@ impl RangeSelect {
             .to_field(input.schema().as_ref())
             .context(DataFusionSnafu)?;
         let time_index_name = ts_field.name().clone();
+        let query_range = query_time_range(&input, &time_index_name);
         fields.push(ts_field);
         // add by
         let by_fields =
@@ -175,10 +307,44 @@ impl RangeSelect {
             by,
             schema_project,
             schema_before_project,
+            query_range,
         })
     }
 }
 
+/// Extracts the millisecond range of the time index from the filters of `input`,
+/// i.e. the `Filter` plans and the filters pushed down to the `TableScan` below it.
+fn query_time_range(
+    input: &LogicalPlan,
+    time_index: &str,
+) -> (Option<Millisecond>, Option<Millisecond>) {
+    let mut filters: Vec<common_query::logical_plan::Expr> = vec![];
+    let mut plan = input;
+    loop {
+        match plan {
+            LogicalPlan::Filter(filter) => {
+                filters.push(filter.predicate.clone().into());
+                plan = &filter.input;
+            }
+            LogicalPlan::TableScan(scan) => {
+                filters.extend(scan.filters.iter().cloned().map(Into::into));
+                break;
+            }
+            _ => break,
+        }
+    }
+    let range = TimeRangePredicateBuilder::new(
+        time_index,
+        common_time::timestamp::TimeUnit::Millisecond,
+        &filters,
+    )
+    .build();
+    (
+        range.start().as_ref().map(|ts| ts.value()),
+        range.end().as_ref().map(|ts| ts.value()),
+    )
+}
+
 impl UserDefinedLogicalNodeCore for RangeSelect {
     fn name(&self) -> &str {
         "RangeSelect"
@@ -226,6 +392,7 @@ impl UserDefinedLogicalNodeCore for RangeSelect {
             by_schema: self.by_schema.clone(),
             schema_project: self.schema_project.clone(),
             schema_before_project: self.schema_before_project.clone(),
+            query_range: self.query_range,
         }
     }
 }
@@ -314,6 +481,7 @@ impl RangeSelect {
                     expr,
                     args,
                     range: range_fn.range.as_millis() as Millisecond,
+                    fill: range_fn.fill.clone(),
                 })
             })
             .collect::<DfResult<Vec<_>>>()?;
@@ -339,6 +507,7 @@ impl RangeSelect {
             metric: ExecutionPlanMetricsSet::new(),
             schema_before_project,
             schema_project: self.schema_project.clone(),
+            query_range: self.query_range,
         }))
     }
 }
@@ -348,6 +517,7 @@ struct RangeFnExec {
     pub expr: Arc<dyn AggregateExpr>,
     pub args: Vec<Arc<dyn PhysicalExpr>>,
     pub range: Millisecond,
+    pub fill: Option<Fill>,
 }
 
 #[derive(Debug)]
@@ -362,6 +532,7 @@ pub struct RangeSelectExec {
     metric: ExecutionPlanMetricsSet,
     schema_project: Option<Vec<usize>>,
     schema_before_project: SchemaRef,
+    query_range: (Option<Millisecond>, Option<Millisecond>),
 }
 
 impl DisplayAs for RangeSelectExec {
@@ -426,6 +597,7 @@ impl ExecutionPlan for RangeSelectExec {
             metric: self.metric.clone(),
             schema_before_project: self.schema_before_project.clone(),
             schema_project: self.schema_project.clone(),
+            query_range: self.query_range,
         }))
     }
 
@@ -466,6 +638,7 @@ impl ExecutionPlan for RangeSelectExec {
             metric: baseline_metric,
             schema_project: self.schema_project.clone(),
             schema_before_project: self.schema_before_project.clone(),
+            query_range: self.query_range,
         }))
     }
 
@@ -504,6 +677,8 @@ struct RangeSelectStream {
     metric: BaselineMetrics,
     schema_project: Option<Vec<usize>>,
     schema_before_project: SchemaRef,
+    /// The `[start, end)` millisecond range of the query, `None` on an unbounded side
+    query_range: (Option<Millisecond>, Option<Millisecond>),
 }
 
 struct SeriesState {
@@ -650,17 +825,83 @@ impl RangeSelectStream {
         let mut ts_builder = TimestampMillisecondBuilder::with_capacity(self.output_num_rows);
         let mut all_scalar = vec![vec![]; self.range_exec.len()];
         let mut by_rows = Vec::with_capacity(self.output_num_rows);
+        // If any range expression needs filling, every series outputs all the time slots
+        // the query covers. A bounded side of the query's time range decides the first or
+        // the last slot, otherwise the first or the last slot with data does.
+        let all_slots = if self.range_exec.iter().any(|range| range.fill.is_some()) {
+            let slots = self
+                .series_map
+                .values()
+                .flat_map(|series| series.align_ts_accumulator.keys());
+            let align = self.align;
+            let (query_start, query_end) = self.query_range;
+            let start = match query_start {
+                Some(start) => (start + align - 1).div_euclid(align) * align,
+                None => *slots.clone().min().unwrap(),
+            };
+            let end = match query_end {
+                // The last slot is the last one whose range still covers `end - 1`,
+                // see `align_to_calendar`
+                Some(end) => {
+                    let max_range = self.range_exec.iter().map(|range| range.range).max();
+                    (end - 2 + max_range.unwrap_or_default()).div_euclid(align) * align
+                }
+                None => *slots.max().unwrap(),
+            };
+            // Refuse to materialize the slots if a wide query range with a small align
+            // would make every series output too many rows
+            let num_slots = if end < start {
+                0
+            } else {
+                ((end as i128 - start as i128) / align as i128 + 1) as u128
+            };
+            let num_rows = num_slots.saturating_mul(self.series_map.len() as u128);
+            if num_rows > MAX_FILL_ROWS as u128 {
+                return Err(DataFusionError::Execution(format!(
+                    "RANGE query with FILL outputs {num_slots} time slots for each of {} series, \
+                    exceeding the limit of {MAX_FILL_ROWS} rows, \
+                    please narrow the query's time range or use a larger ALIGN",
+                    self.series_map.len()
+                )));
+            }
+            Some(
+                (start..=end)
+                    .step_by(self.align as usize)
+                    .collect::<Vec<_>>(),
+            )
+        } else {
+            None
+        };
+        let null_values = self
+            .range_exec
+            .iter()
+            .map(|range| ScalarValue::try_from(range.expr.field()?.data_type()))
+            .collect::<DfResult<Vec<_>>>()?;
         for SeriesState {
             row,
             align_ts_accumulator,
         } in self.series_map.values()
         {
-            for (ts, accumulators) in align_ts_accumulator {
-                for (i, accumulator) in accumulators.iter().enumerate() {
-                    all_scalar[i].push(accumulator.evaluate()?);
+            let slots = match &all_slots {
+                Some(slots) => slots.clone(),
+                None => align_ts_accumulator.keys().copied().collect(),
+            };
+            for (i, range) in self.range_exec.iter().enumerate() {
+                let mut values = slots
+                    .iter()
+                    .map(|ts| match align_ts_accumulator.get(ts) {
+                        Some(accumulators) => accumulators[i].evaluate(),
+                        None => Ok(null_values[i].clone()),
+                    })
+                    .collect::<DfResult<Vec<_>>>()?;
+                if let Some(fill) = &range.fill {
+                    fill.apply_fill_strategy(&slots, &mut values)?;
                 }
+                all_scalar[i].extend(values);
+            }
+            for ts in slots {
                 by_rows.push(row.row());
-                ts_builder.append_value(*ts);
+                ts_builder.append_value(ts);
             }
         }
         for column_scalar in all_scalar {
@@ -783,12 +1024,13 @@ mod test {
         MemoryExec::try_new(&[vec![data]], schema, None).unwrap()
     }
 
-    async fn do_range_select_test(
+    async fn do_range_select(
         range1: Millisecond,
         range2: Millisecond,
         align: Millisecond,
-        expected: String,
-    ) {
+        fill: Option<Fill>,
+        query_range: (Option<Millisecond>, Option<Millisecond>),
+    ) -> DfResult<Vec<RecordBatch>> {
         let memory_exec = Arc::new(prepare_test_data());
         let schema = Arc::new(Schema::new(vec![
             Field::new("MIN(value)", DataType::Int64, true),
@@ -807,6 +1049,7 @@ mod test {
                     )),
                     args: vec![Arc::new(Column::new("value", 1))],
                     range: range1,
+                    fill: fill.clone(),
                 },
                 RangeFnExec {
                     expr: Arc::new(expressions::Max::new(
@@ -816,6 +1059,7 @@ mod test {
                     )),
                     args: vec![Arc::new(Column::new("value", 1))],
                     range: range2,
+                    fill,
                 },
             ],
             align,
@@ -826,6 +1070,7 @@ mod test {
             schema_project: None,
             by_schema: Arc::new(Schema::new(vec![Field::new("host", DataType::Utf8, true)])),
             metric: ExecutionPlanMetricsSet::new(),
+            query_range,
         });
         let sort_exec = SortExec::new(
             vec![
@@ -847,10 +1092,20 @@ mod test {
             range_select_exec,
         );
         let session_context = SessionContext::default();
-        let result =
-            datafusion::physical_plan::collect(Arc::new(sort_exec), session_context.task_ctx())
-                .await
-                .unwrap();
+        datafusion::physical_plan::collect(Arc::new(sort_exec), session_context.task_ctx()).await
+    }
+
+    async fn do_range_select_test(
+        range1: Millisecond,
+        range2: Millisecond,
+        align: Millisecond,
+        fill: Option<Fill>,
+        query_range: (Option<Millisecond>, Option<Millisecond>),
+        expected: String,
+    ) {
+        let result = do_range_select(range1, range2, align, fill, query_range)
+            .await
+            .unwrap();
 
         let result_literal = datatypes::arrow::util::pretty::pretty_format_batches(&result)
             .unwrap()
@@ -887,7 +1142,7 @@ mod test {
             \n| 17         | 17         | 1970-01-01T00:00:45 | host2 |\
             \n+------------+------------+---------------------+-------+",
         );
-        do_range_select_test(10_000, 10_000, 5_000, expected).await;
+        do_range_select_test(10_000, 10_000, 5_000, None, (None, None), expected).await;
     }
 
     #[tokio::test]
@@ -900,7 +1155,7 @@ mod test {
             \n| 9          | 9          | 1970-01-01T00:00:00 | host2 |\
             \n+------------+------------+---------------------+-------+",
         );
-        do_range_select_test(10_000, 10_000, 1_000_000, expected).await;
+        do_range_select_test(10_000, 10_000, 1_000_000, None, (None, None), expected).await;
     }
 
     #[tokio::test]
@@ -931,6 +1186,200 @@ mod test {
             \n| 17         |            | 1970-01-01T00:00:45 | host2 |\
             \n+------------+------------+---------------------+-------+",
         );
-        do_range_select_test(10_000, 5_000, 5_000, expected).await;
+        do_range_select_test(10_000, 5_000, 5_000, None, (None, None), expected).await;
+    }
+
+    #[tokio::test]
+    async fn range_fill_prev() {
+        let expected = String::from(
+            "+------------+------------+---------------------+-------+\
+            \n| MIN(value) | MAX(value) | timestamp           | host  |\
+            \n+------------+------------+---------------------+-------+\
+            \n| 0          | 0          | 1970-01-01T00:00:00 | host1 |\
+            \n| 0          | 1          | 1970-01-01T00:00:05 | host1 |\
+            \n| 1          | 2          | 1970-01-01T00:00:10 | host1 |\
+            \n| 2          | 3          | 1970-01-01T00:00:15 | host1 |\
+            \n| 3          | 4          | 1970-01-01T00:00:20 | host1 |\
+            \n| 4          | 5          | 1970-01-01T00:00:25 | host1 |\
+            \n| 5          | 6          | 1970-01-01T00:00:30 | host1 |\
+            \n| 6          | 7          | 1970-01-01T00:00:35 | host1 |\
+            \n| 7          | 8          | 1970-01-01T00:00:40 | host1 |\
+            \n| 8          | 8          | 1970-01-01T00:00:45 | host1 |\
+            \n| 9          | 9          | 1970-01-01T00:00:00 | host2 |\
+            \n| 9          | 10         | 1970-01-01T00:00:05 | host2 |\
+            \n| 10         | 11         | 1970-01-01T00:00:10 | host2 |\
+            \n| 11         | 12         | 1970-01-01T00:00:15 | host2 |\
+            \n| 12         | 13         | 1970-01-01T00:00:20 | host2 |\
+            \n| 13         | 14         | 1970-01-01T00:00:25 | host2 |\
+            \n| 14         | 15         | 1970-01-01T00:00:30 | host2 |\
+            \n| 15         | 16         | 1970-01-01T00:00:35 | host2 |\
+            \n| 16         | 17         | 1970-01-01T00:00:40 | host2 |\
+            \n| 17         | 17         | 1970-01-01T00:00:45 | host2 |\
+            \n+------------+------------+---------------------+-------+",
+        );
+        do_range_select_test(
+            10_000,
+            5_000,
+            5_000,
+            Some(Fill::Prev),
+            (None, None),
+            expected,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn range_fill_const() {
+        let expected = String::from(
+            "+------------+------------+---------------------+-------+\
+            \n| MIN(value) | MAX(value) | timestamp           | host  |\
+            \n+------------+------------+---------------------+-------+\
+            \n| 0          | 0          | 1970-01-01T00:00:00 | host1 |\
+            \n| 0          | 1          | 1970-01-01T00:00:05 | host1 |\
+            \n| 1          | 2          | 1970-01-01T00:00:10 | host1 |\
+            \n| 2          | 3          | 1970-01-01T00:00:15 | host1 |\
+            \n| 3          | 4          | 1970-01-01T00:00:20 | host1 |\
+            \n| 4          | 5          | 1970-01-01T00:00:25 | host1 |\
+            \n| 5          | 6          | 1970-01-01T00:00:30 | host1 |\
+            \n| 6          | 7          | 1970-01-01T00:00:35 | host1 |\
+            \n| 7          | 8          | 1970-01-01T00:00:40 | host1 |\
+            \n| 8          | 6          | 1970-01-01T00:00:45 | host1 |\
+            \n| 9          | 9          | 1970-01-01T00:00:00 | host2 |\
+            \n| 9          | 10         | 1970-01-01T00:00:05 | host2 |\
+            \n| 10         | 11         | 1970-01-01T00:00:10 | host2 |\
+            \n| 11         | 12         | 1970-01-01T00:00:15 | host2 |\
+            \n| 12         | 13         | 1970-01-01T00:00:20 | host2 |\
+            \n| 13         | 14         | 1970-01-01T00:00:25 | host2 |\
+            \n| 14         | 15         | 1970-01-01T00:00:30 | host2 |\
+            \n| 15         | 16         | 1970-01-01T00:00:35 | host2 |\
+            \n| 16         | 17         | 1970-01-01T00:00:40 | host2 |\
+            \n| 17         | 6          | 1970-01-01T00:00:45 | host2 |\
+            \n+------------+------------+---------------------+-------+",
+        );
+        do_range_select_test(
+            10_000,
+            5_000,
+            5_000,
+            Some(Fill::Const(ScalarValue::Int64(Some(6)))),
+            (None, None),
+            expected,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn range_fill_query_range() {
+        // The query range `[-10s, 60s)` outputs the slots from -10s to 65s
+        // although the data only spans from 0s to 40s.
+        let mut expected = String::from(
+            "+------------+------------+---------------------+-------+\
+            \n| MIN(value) | MAX(value) | timestamp           | host  |\
+            \n+------------+------------+---------------------+-------+",
+        );
+        for (host, offset) in [("host1", 0), ("host2", 9)] {
+            for ts in (-10..=65).step_by(5) {
+                let (min, max) = match ts {
+                    0 => (offset, offset),
+                    5..=40 => (offset + ts / 5 - 1, offset + ts / 5),
+                    45 => (offset + 8, 6),
+                    _ => (6, 6),
+                };
+                let time = chrono::NaiveDateTime::from_timestamp_opt(ts, 0).unwrap();
+                expected.push_str(&format!(
+                    "\n| {:<10} | {:<10} | {:<19} | {host} |",
+                    min,
+                    max,
+                    time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                ));
+            }
+        }
+        expected.push_str("\n+------------+------------+---------------------+-------+");
+        do_range_select_test(
+            10_000,
+            5_000,
+            5_000,
+            Some(Fill::Const(ScalarValue::Int64(Some(6)))),
+            (Some(-10_000), Some(60_000)),
+            expected,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn range_fill_too_many_slots() {
+        // Two series of 500_001 slots each exceed the limit
+        let end = (MAX_FILL_ROWS as i64 / 2) * 5_000;
+        let err = do_range_select(5_000, 5_000, 5_000, Some(Fill::Null), (Some(0), Some(end)))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("exceeding the limit"), "{err}");
+
+        // Without FILL only the slots with data are output
+        let result = do_range_select(5_000, 5_000, 5_000, None, (Some(0), Some(end)))
+            .await
+            .unwrap();
+        assert_eq!(
+            result.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            18
+        );
+    }
+
+    #[test]
+    fn fill_test() {
+        assert!(Fill::try_from_str("", &DataType::UInt8).unwrap().is_none());
+        assert_eq!(
+            Some(Fill::Linear),
+            Fill::try_from_str("Linear", &DataType::UInt8).unwrap()
+        );
+        assert_eq!(
+            Some(Fill::Const(ScalarValue::UInt8(Some(8)))),
+            Fill::try_from_str("8", &DataType::UInt8).unwrap()
+        );
+        assert!(Fill::try_from_str("linear", &DataType::Utf8).is_err());
+        assert!(Fill::try_from_str("not_a_number", &DataType::UInt8).is_err());
+
+        let ts = vec![0, 5, 10, 15, 20, 25];
+        let values = vec![
+            ScalarValue::Float64(None),
+            ScalarValue::Float64(Some(1.0)),
+            ScalarValue::Float64(None),
+            ScalarValue::Float64(None),
+            ScalarValue::Float64(Some(4.0)),
+            ScalarValue::Float64(None),
+        ];
+        let fill = |fill: Fill| {
+            let mut values = values.clone();
+            fill.apply_fill_strategy(&ts, &mut values).unwrap();
+            values
+                .into_iter()
+                .map(|value| match value {
+                    ScalarValue::Float64(value) => value,
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![None, Some(1.0), None, None, Some(4.0), None],
+            fill(Fill::Null)
+        );
+        assert_eq!(
+            vec![None, Some(1.0), Some(1.0), Some(1.0), Some(4.0), Some(4.0)],
+            fill(Fill::Prev)
+        );
+        assert_eq!(
+            vec![None, Some(1.0), Some(2.0), Some(3.0), Some(4.0), None],
+            fill(Fill::Linear)
+        );
+        assert_eq!(
+            vec![
+                Some(0.5),
+                Some(1.0),
+                Some(0.5),
+                Some(0.5),
+                Some(4.0),
+                Some(0.5)
+            ],
+            fill(Fill::Const(ScalarValue::Float64(Some(0.5))))
+        );
     }
 }
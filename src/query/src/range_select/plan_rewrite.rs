@@ -25,7 +25,8 @@ use datafusion_common::tree_node::{TreeNode, TreeNodeRewriter, VisitRecursion};
 use datafusion_common::{DFSchema, DataFusionError, Result as DFResult};
 use datafusion_expr::expr::{AggregateFunction, AggregateUDF, ScalarUDF};
 use datafusion_expr::{
-    AggregateFunction as AggregateFn, Expr, Extension, LogicalPlan, LogicalPlanBuilder, Projection,
+    AggregateFunction as AggregateFn, Expr, ExprSchemable, Extension, LogicalPlan,
+    LogicalPlanBuilder, Projection,
 };
 use datafusion_sql::planner::ContextProvider;
 use datatypes::prelude::ConcreteDataType;
@@ -36,7 +37,7 @@ use table::table::adapter::DfTableProviderAdapter;
 use crate::error::{
     CatalogSnafu, DataFusionSnafu, Result, TimeIndexNotFoundSnafu, UnknownTableSnafu,
 };
-use crate::range_select::plan::{RangeFn, RangeSelect};
+use crate::range_select::plan::{Fill, RangeFn, RangeSelect};
 use crate::DfContextProviderAdapter;
 
 /// `RangeExprRewriter` will recursively search certain `Expr`, find all `range_fn` scalar udf contained in `Expr`,
@@ -47,6 +48,8 @@ pub struct RangeExprRewriter<'a> {
     by: Vec<Expr>,
     range_fn: Vec<RangeFn>,
     context_provider: &'a DfContextProviderAdapter,
+    /// The input plan of the range select, to get the type of the range expressions
+    input_plan: &'a Arc<LogicalPlan>,
 }
 
 impl<'a> RangeExprRewriter<'a> {
@@ -111,12 +114,9 @@ impl<'a> TreeNodeRewriter for RangeExprRewriter<'a> {
                     .map_err(|e| DataFusionError::Plan(e.to_string()))?;
                 let byc = str::parse::<usize>(parse_str_expr(&func.args, argc + 4)?)
                     .map_err(|e| DataFusionError::Plan(e.to_string()))?;
-                let mut range_fn = RangeFn {
-                    expr: Expr::Wildcard,
-                    range: parse_duration(parse_str_expr(&func.args, argc + 2)?)
-                        .map_err(DataFusionError::Plan)?,
-                    fill: parse_str_expr(&func.args, argc + 3)?.to_string(),
-                };
+                let range = parse_duration(parse_str_expr(&func.args, argc + 2)?)
+                    .map_err(DataFusionError::Plan)?;
+                let fill = parse_str_expr(&func.args, argc + 3)?;
                 let args = parse_expr_list(&func.args, 2, argc)?;
                 let by = parse_expr_list(&func.args, argc + 5, byc)?;
                 let align = parse_duration(parse_str_expr(&func.args, argc + byc + 5)?)
@@ -135,7 +135,13 @@ impl<'a> TreeNodeRewriter for RangeExprRewriter<'a> {
                 } else {
                     self.align = align;
                 }
-                range_fn.expr = self.gen_range_expr(func_name, args)?;
+                let expr = self.gen_range_expr(func_name, args)?;
+                let data_type = expr.get_type(self.input_plan.schema())?;
+                let range_fn = RangeFn {
+                    fill: Fill::try_from_str(fill, &data_type)?,
+                    expr,
+                    range,
+                };
                 let alias = Expr::Column(Column::from_name(range_fn.expr.display_name()?));
                 self.range_fn.push(range_fn);
                 return Ok(alias);
@@ -196,6 +202,7 @@ impl RangePlanRewriter {
                     by: vec![],
                     range_fn: vec![],
                     context_provider: &self.context_provider,
+                    input_plan: &input,
                 };
                 let new_expr = expr
                     .iter()
@@ -323,7 +330,7 @@ fn have_range_in_exprs(exprs: &Vec<Expr>) -> bool {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
 
     use catalog::memory::MemoryCatalogManager;
     use catalog::RegisterTableRequest;
@@ -339,7 +346,7 @@ mod test {
     use crate::plan::LogicalPlan as GreptimeLogicalPlan;
     use crate::{QueryEngineFactory, QueryEngineRef};
 
-    async fn create_test_engine() -> QueryEngineRef {
+    pub(crate) async fn create_test_engine() -> QueryEngineRef {
         let table_name = "test".to_string();
         let mut columns = vec![];
         for i in 0..5 {
@@ -36,6 +36,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use session::context::QueryContextRef;
 use snafu::{ensure, OptionExt, ResultExt};
+use sql::ast::Ident;
 use sql::statements::create::Partitions;
 use sql::statements::show::{ShowDatabases, ShowKind, ShowTables};
 use table::requests::{FILE_TABLE_LOCATION_KEY, FILE_TABLE_PATTERN_KEY};
@@ -97,6 +98,13 @@ static SHOW_CREATE_TABLE_OUTPUT_SCHEMA: Lazy<Arc<Schema>> = Lazy::new(|| {
     ]))
 });
 
+static SHOW_CREATE_VIEW_OUTPUT_SCHEMA: Lazy<Arc<Schema>> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        ColumnSchema::new("View", ConcreteDataType::string_datatype(), false),
+        ColumnSchema::new("Create View", ConcreteDataType::string_datatype(), false),
+    ]))
+});
+
 pub async fn show_databases(
     stmt: ShowDatabases,
     catalog_manager: CatalogManagerRef,
@@ -193,15 +201,7 @@ pub fn show_create_table(
 ) -> Result<Output> {
     let table_info = table.table_info();
     let table_name = &table_info.name;
-
-    // Default to double quote and fallback to back quote
-    let quote_style = if query_ctx.sql_dialect().is_delimited_identifier_start('"') {
-        '"'
-    } else if query_ctx.sql_dialect().is_delimited_identifier_start('\'') {
-        '\''
-    } else {
-        '`'
-    };
+    let quote_style = quote_style(&query_ctx);
 
     let mut stmt = show_create_table::create_table_stmt(&table_info, quote_style)?;
     stmt.partitions = partitions.map(|mut p| {
@@ -219,6 +219,35 @@ pub fn show_create_table(
     Ok(Output::RecordBatches(records))
 }
 
+pub fn show_create_view(
+    view_name: &str,
+    definition: &str,
+    query_ctx: QueryContextRef,
+) -> Result<Output> {
+    let name = Ident::with_quote(quote_style(&query_ctx), view_name);
+    let sql = format!("CREATE VIEW {name} AS {definition}");
+    let columns = vec![
+        Arc::new(StringVector::from(vec![view_name.to_string()])) as _,
+        Arc::new(StringVector::from(vec![sql])) as _,
+    ];
+    let records = RecordBatches::try_from_columns(SHOW_CREATE_VIEW_OUTPUT_SCHEMA.clone(), columns)
+        .context(error::CreateRecordBatchSnafu)?;
+
+    Ok(Output::RecordBatches(records))
+}
+
+/// Returns the quote style of identifiers in the dialect of the query context.
+fn quote_style(query_ctx: &QueryContextRef) -> char {
+    // Default to double quote and fallback to back quote
+    if query_ctx.sql_dialect().is_delimited_identifier_start('"') {
+        '"'
+    } else if query_ctx.sql_dialect().is_delimited_identifier_start('\'') {
+        '\''
+    } else {
+        '`'
+    }
+}
+
 pub fn describe_table(table: TableRef) -> Result<Output> {
     let table_info = table.table_info();
     let columns_schemas = table_info.meta.schema.column_schemas();
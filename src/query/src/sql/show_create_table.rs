@@ -28,7 +28,7 @@ use sql::parser::ParserContext;
 use sql::statements::create::{CreateTable, TIME_INDEX};
 use sql::statements::{self};
 use table::metadata::{TableInfoRef, TableMeta};
-use table::requests::FILE_TABLE_META_KEY;
+use table::requests::{FILE_TABLE_META_KEY, PROM_METRIC_METADATA_KEY};
 
 use crate::error::{ConvertSqlTypeSnafu, ConvertSqlValueSnafu, Result, SqlSnafu};
 
@@ -77,7 +77,7 @@ fn create_sql_options(table_meta: &TableMeta) -> Vec<SqlOption> {
     for (k, v) in table_opts
         .extra_options
         .iter()
-        .filter(|(k, _)| k != &FILE_TABLE_META_KEY)
+        .filter(|(k, _)| k != &FILE_TABLE_META_KEY && k != &PROM_METRIC_METADATA_KEY)
     {
         options.push(sql_option(k, string_value(v)));
     }
@@ -122,6 +122,9 @@ pub enum Error {
     #[snafu(display("Invalid query: {}", reason))]
     InvalidQuery { reason: String, location: Location },
 
+    #[snafu(display("Cursor not found: {}", name))]
+    CursorNotFound { name: String, location: Location },
+
     #[snafu(display("Failed to parse InfluxDB line protocol"))]
     InfluxdbLineProtocol {
         location: Location,
@@ -197,6 +200,30 @@ pub enum Error {
         location: Location,
     },
 
+    #[snafu(display("Invalid Flight SQL command"))]
+    InvalidFlightSqlCommand {
+        source: api::DecodeError,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to receive Flight data"))]
+    ReceiveFlightData {
+        source: tonic::Status,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to decode Flight data"))]
+    DecodeFlightData {
+        source: common_grpc::error::Error,
+        location: Location,
+    },
+
+    #[snafu(display("Failed to encode Flight SQL result"))]
+    EncodeFlightSqlResult {
+        source: datatypes::arrow::error::ArrowError,
+        location: Location,
+    },
+
     #[snafu(display("Tls is required for {}, plain connection is rejected", server))]
     TlsRequired { server: String },
 
@@ -355,6 +382,8 @@ impl ErrorExt for Error {
             | InternalIo { .. }
             | TokioIo { .. }
             | CollectRecordbatch { .. }
+            | ReceiveFlightData { .. }
+            | EncodeFlightSqlResult { .. }
             | StartHttp { .. }
             | StartGrpc { .. }
             | AlreadyStarted { .. }
@@ -375,6 +404,7 @@ impl ErrorExt for Error {
             NotSupported { .. }
             | InvalidParameter { .. }
             | InvalidQuery { .. }
+            | CursorNotFound { .. }
             | InfluxdbLineProtocol { .. }
             | ConnResetByPeer { .. }
             | InvalidOpentsdbLine { .. }
@@ -384,6 +414,7 @@ impl ErrorExt for Error {
             | DecompressPromRemoteRequest { .. }
             | InvalidPromRemoteRequest { .. }
             | InvalidFlightTicket { .. }
+            | InvalidFlightSqlCommand { .. }
             | InvalidPrepareStatement { .. }
             | DataFrame { .. }
             | PreparedStmtTypeMismatch { .. }
@@ -391,6 +422,7 @@ impl ErrorExt for Error {
             | IncompatibleSchema { .. } => StatusCode::InvalidArguments,
 
             InfluxdbLinesWrite { source, .. }
+            | DecodeFlightData { source, .. }
             | PromSeriesWrite { source, .. }
             | OtlpMetricsWrite { source, .. } => source.status_code(),
 
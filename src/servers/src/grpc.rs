@@ -14,6 +14,7 @@
 
 mod database;
 pub mod flight;
+pub mod flight_sql;
 pub mod greptime_handler;
 pub mod prom_query_gateway;
 pub mod region_server;
@@ -47,6 +48,7 @@ use tonic::{Request, Response, Status};
 use tonic_reflection::server::{ServerReflection, ServerReflectionServer};
 
 use self::flight::{FlightCraftRef, FlightCraftWrapper};
+use self::flight_sql::{FlightSqlHandler, FlightSqlServiceWrapper};
 use self::prom_query_gateway::PrometheusGatewayService;
 use self::region_server::{RegionServerHandlerRef, RegionServerRequestHandler};
 use crate::error::{AlreadyStartedSnafu, InternalSnafu, Result, StartGrpcSnafu, TcpBindSnafu};
@@ -75,6 +77,8 @@ pub struct GrpcServer {
     prometheus_handler: Option<PrometheusHandlerRef>,
     /// Handler for [FlightService](arrow_flight::flight_service_server::FlightService).
     flight_handler: Option<FlightCraftRef>,
+    /// Handler for Flight SQL requests, served along with [DatabaseService]'s Flight tickets.
+    flight_sql_handler: Option<FlightSqlHandler>,
     /// Handler for [RegionServer].
     region_server_handler: Option<RegionServerRequestHandler>,
 }
@@ -117,10 +121,17 @@ impl GrpcServer {
             database_handler,
             prometheus_handler,
             flight_handler,
+            flight_sql_handler: None,
             region_server_handler,
         }
     }
 
+    /// Serves Flight SQL requests by `handler`.
+    pub fn with_flight_sql_handler(mut self, handler: FlightSqlHandler) -> Self {
+        self.flight_sql_handler = Some(handler);
+        self
+    }
+
     #[cfg(feature = "testing")]
     pub fn create_flight_service(&self) -> FlightServiceServer<impl FlightService> {
         FlightServiceServer::new(FlightCraftWrapper(self.flight_handler.clone().unwrap()))
@@ -239,6 +250,14 @@ impl Server for GrpcServer {
                 FlightServiceServer::new(FlightCraftWrapper(flight_handler.clone()))
                     .max_decoding_message_size(max_message_size),
             )
+        } else if let Some(flight_sql_handler) = &self.flight_sql_handler {
+            builder = builder.add_service(
+                FlightServiceServer::new(FlightSqlServiceWrapper::new(
+                    self.database_handler.clone().unwrap(),
+                    flight_sql_handler.clone(),
+                ))
+                .max_decoding_message_size(max_message_size),
+            )
         } else {
             // TODO(ruihang): this is a temporary workaround before region server is ready.
             builder = builder.add_service(FlightServiceServer::new(FlightCraftWrapper(
@@ -147,6 +147,7 @@ impl FlightCraft for GreptimeRequestHandler {
         &self,
         request: Request<Ticket>,
     ) -> TonicResult<Response<TonicStream<FlightData>>> {
+        let client_addr = request.remote_addr();
         let ticket = request.into_inner().ticket;
         let request =
             GreptimeRequest::decode(ticket.as_ref()).context(error::InvalidFlightTicketSnafu)?;
@@ -156,7 +157,7 @@ impl FlightCraft for GreptimeRequestHandler {
             .map(|h| h.trace_id)
             .unwrap_or_default();
 
-        let output = self.handle_request(request).await?;
+        let output = self.handle_request(request, client_addr).await?;
 
         let stream: Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + Sync>> =
             to_flight_data_stream(output, trace_id);
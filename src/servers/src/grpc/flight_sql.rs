@@ -0,0 +1,1407 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [Arrow Flight SQL](https://arrow.apache.org/docs/format/FlightSql.html) service.
+//!
+//! Flight SQL requests share the Flight service with GreptimeDB's own tickets, they
+//! are told apart by the `Any` wrapping every Flight SQL command. Statements are
+//! executed by the [SqlQueryHandler](crate::query_handler::sql::SqlQueryHandler),
+//! and catalog metadata is read from `information_schema`.
+//!
+//! Prepared statements are kept by the handler until they are closed or expire, and
+//! only the user who created a statement can use it. Parameters bound by `DoPut` are
+//! replaced in the plan of the statement before executing it.
+//! Record batches put to a `PATH` descriptor, which names the table, are inserted
+//! into the table by the [FlightSqlIngestHandler].
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, Any, CommandGetCatalogs, CommandGetDbSchemas,
+    CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery,
+    CommandPreparedStatementUpdate, CommandStatementQuery, CommandStatementUpdate,
+    DoPutUpdateResult, ProstMessageExt, SqlInfo, TicketStatementQuery,
+};
+use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, IpcMessage, PutResult, SchemaAsIpc, SchemaResult, Ticket,
+};
+use async_trait::async_trait;
+use auth::{Identity, Password, UserProviderRef};
+use common_catalog::consts::{DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME};
+use common_catalog::parse_catalog_and_schema_from_db_string;
+use common_grpc::flight::{FlightDecoder, FlightMessage};
+use common_query::Output;
+use common_recordbatch::{util, RecordBatch, RecordBatches};
+use datafusion_common::ScalarValue;
+use datatypes::arrow::array::{ArrayRef, BooleanArray, StringArray, UInt32Array, UnionArray};
+use datatypes::arrow::buffer::Buffer;
+use datatypes::arrow::datatypes::{DataType, Field, Schema as ArrowSchema, SchemaRef};
+use datatypes::arrow::ipc::writer::IpcWriteOptions;
+use datatypes::arrow::record_batch::RecordBatch as DfRecordBatch;
+use datatypes::prelude::{ConcreteDataType, ScalarVectorBuilder, Value};
+use datatypes::schema::{ColumnSchema, Schema};
+use datatypes::vectors::{BinaryVectorBuilder, StringVector, UInt64Vector};
+use futures::stream;
+use parking_lot::Mutex;
+use prost::Message;
+use query::plan::LogicalPlan;
+use query::query_engine::DescribeResult;
+use session::context::{Channel, QueryContextBuilder, QueryContextRef};
+use snafu::{ensure, OptionExt, ResultExt};
+use sql::dialect::GreptimeDbDialect;
+use sql::parser::ParserContext;
+use table::requests::InsertRequest;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::error::{
+    self, AuthSnafu, CollectRecordbatchSnafu, DataFrameSnafu, DecodeFlightDataSnafu,
+    EncodeFlightSqlResultSnafu, GetPreparedStmtParamsSnafu, InvalidAuthorizationHeaderSnafu,
+    InvalidFlightSqlCommandSnafu, InvalidQuerySnafu, NotFoundAuthHeaderSnafu, NotSupportedSnafu,
+    ReceiveFlightDataSnafu, ReplacePreparedStmtParamsSnafu, Result,
+};
+use crate::grpc::flight::{FlightCraft, FlightRecordBatchStream, TonicStream};
+use crate::grpc::TonicResult;
+use crate::http::authorize::AuthScheme;
+use crate::http::header::GREPTIME_DB_NAME_HEADER_NAME;
+use crate::query_handler::sql::ServerSqlQueryHandlerRef;
+use crate::query_handler::FlightSqlIngestHandlerRef;
+
+const AUTHORIZATION: &str = "authorization";
+/// Prefix of the type url of every Flight SQL message in `Any`.
+const FLIGHT_SQL_TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+
+const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+/// Table types in `information_schema.tables`.
+const TABLE_TYPES: [&str; 3] = ["BASE TABLE", "LOCAL TEMPORARY", "VIEW"];
+
+/// Flight SQL commands that return record batches.
+#[derive(Debug, PartialEq)]
+enum QueryCommand {
+    Statement(String),
+    /// The handle of a prepared statement.
+    PreparedStatement(Vec<u8>),
+    Metadata(MetadataCommand),
+    SqlInfo(Vec<u32>),
+}
+
+/// Flight SQL commands that query the catalog metadata.
+#[derive(Debug, PartialEq)]
+enum MetadataCommand {
+    Catalogs,
+    DbSchemas(CommandGetDbSchemas),
+    Tables(CommandGetTables),
+    TableTypes,
+}
+
+impl QueryCommand {
+    fn try_from_any(any: &Any) -> Result<Self> {
+        if let Some(command) = decode_command::<CommandStatementQuery>(any)? {
+            return Ok(Self::Statement(command.query));
+        }
+        if let Some(command) = decode_command::<CommandPreparedStatementQuery>(any)? {
+            return Ok(Self::PreparedStatement(
+                command.prepared_statement_handle.to_vec(),
+            ));
+        }
+        if let Some(ticket) = decode_command::<TicketStatementQuery>(any)? {
+            let query = handle_to_query(&ticket.statement_handle)?;
+            return Ok(Self::Statement(query));
+        }
+        if decode_command::<CommandGetCatalogs>(any)?.is_some() {
+            return Ok(Self::Metadata(MetadataCommand::Catalogs));
+        }
+        if let Some(command) = decode_command::<CommandGetDbSchemas>(any)? {
+            return Ok(Self::Metadata(MetadataCommand::DbSchemas(command)));
+        }
+        if let Some(command) = decode_command::<CommandGetTables>(any)? {
+            return Ok(Self::Metadata(MetadataCommand::Tables(command)));
+        }
+        if decode_command::<CommandGetTableTypes>(any)?.is_some() {
+            return Ok(Self::Metadata(MetadataCommand::TableTypes));
+        }
+        if let Some(command) = decode_command::<CommandGetSqlInfo>(any)? {
+            return Ok(Self::SqlInfo(command.info));
+        }
+        NotSupportedSnafu {
+            feat: format!("Flight SQL command {}", any.type_url),
+        }
+        .fail()
+    }
+}
+
+/// Decodes the Flight SQL message `M` from `any`, returns `None` if `any` holds
+/// another message.
+fn decode_command<M: ProstMessageExt>(any: &Any) -> Result<Option<M>> {
+    if any.type_url != M::type_url() {
+        return Ok(None);
+    }
+    M::decode(any.value.as_ref())
+        .map(Some)
+        .context(InvalidFlightSqlCommandSnafu)
+}
+
+fn pack<M: ProstMessageExt>(message: &M) -> Any {
+    Any {
+        type_url: M::type_url().to_string(),
+        value: message.encode_to_vec().into(),
+    }
+}
+
+fn handle_to_query(handle: &[u8]) -> Result<String> {
+    String::from_utf8(handle.to_vec())
+        .ok()
+        .context(InvalidQuerySnafu {
+            reason: "Invalid prepared statement handle",
+        })
+}
+
+/// Length of the handles of prepared statements, which are random bytes so they
+/// can't be guessed.
+const PREPARED_STATEMENT_HANDLE_LEN: usize = 16;
+/// Prepared statements unused for longer than this are closed.
+const PREPARED_STATEMENT_TTL: Duration = Duration::from_secs(30 * 60);
+/// The most prepared statements a user can keep open.
+const MAX_PREPARED_STATEMENTS_PER_USER: usize = 128;
+
+/// A prepared statement and the parameters bound to it.
+#[derive(Clone)]
+struct PreparedStatement {
+    query: String,
+    /// Each row is a set of parameters, the statement is executed once per row.
+    parameters: Vec<RecordBatch>,
+    /// The user who created the statement, `None` if authentication is disabled.
+    owner: Option<String>,
+    last_used: Instant,
+}
+
+/// Prepared statements created by `CreatePreparedStatement`.
+///
+/// A statement is only visible to the user who created it, and is closed once it's
+/// unused for the TTL.
+struct PreparedStatements {
+    statements: Mutex<HashMap<Vec<u8>, PreparedStatement>>,
+    ttl: Duration,
+    max_per_user: usize,
+}
+
+impl Default for PreparedStatements {
+    fn default() -> Self {
+        Self::new(PREPARED_STATEMENT_TTL, MAX_PREPARED_STATEMENTS_PER_USER)
+    }
+}
+
+impl PreparedStatements {
+    fn new(ttl: Duration, max_per_user: usize) -> Self {
+        Self {
+            statements: Mutex::new(HashMap::new()),
+            ttl,
+            max_per_user,
+        }
+    }
+
+    fn create(&self, query: String, owner: Option<String>) -> Result<Vec<u8>> {
+        let mut statements = self.statements.lock();
+        statements.retain(|_, statement| statement.last_used.elapsed() < self.ttl);
+        let owned = statements
+            .values()
+            .filter(|statement| statement.owner == owner)
+            .count();
+        ensure!(
+            owned < self.max_per_user,
+            InvalidQuerySnafu {
+                reason: format!(
+                    "Too many prepared statements, at most {} can be open",
+                    self.max_per_user
+                ),
+            }
+        );
+
+        let handle = loop {
+            let handle = rand::random::<[u8; PREPARED_STATEMENT_HANDLE_LEN]>().to_vec();
+            if !statements.contains_key(&handle) {
+                break handle;
+            }
+        };
+        let statement = PreparedStatement {
+            query,
+            parameters: vec![],
+            owner,
+            last_used: Instant::now(),
+        };
+        let _ = statements.insert(handle.clone(), statement);
+        Ok(handle)
+    }
+
+    fn get(&self, handle: &[u8], owner: Option<&str>) -> Result<PreparedStatement> {
+        let mut statements = self.statements.lock();
+        self.get_mut(&mut statements, handle, owner)
+            .map(|statement| statement.clone())
+    }
+
+    fn bind(&self, handle: &[u8], owner: Option<&str>, parameters: Vec<RecordBatch>) -> Result<()> {
+        let mut statements = self.statements.lock();
+        self.get_mut(&mut statements, handle, owner)?.parameters = parameters;
+        Ok(())
+    }
+
+    fn close(&self, handle: &[u8], owner: Option<&str>) -> Result<()> {
+        let mut statements = self.statements.lock();
+        let _ = self.get_mut(&mut statements, handle, owner)?;
+        let _ = statements.remove(handle);
+        Ok(())
+    }
+
+    /// Returns the statement of `handle` and refreshes its last used time. Expired
+    /// statements and statements of other users are reported as not found.
+    fn get_mut<'a>(
+        &self,
+        statements: &'a mut HashMap<Vec<u8>, PreparedStatement>,
+        handle: &[u8],
+        owner: Option<&str>,
+    ) -> Result<&'a mut PreparedStatement> {
+        if statements
+            .get(handle)
+            .is_some_and(|statement| statement.last_used.elapsed() >= self.ttl)
+        {
+            let _ = statements.remove(handle);
+        }
+        let statement = statements
+            .get_mut(handle)
+            .filter(|statement| statement.owner.as_deref() == owner)
+            .context(InvalidQuerySnafu {
+                reason: "Prepared statement not found",
+            })?;
+        statement.last_used = Instant::now();
+        Ok(statement)
+    }
+}
+
+/// Returns the user prepared statements are bound to.
+fn statement_owner(query_ctx: &QueryContextRef) -> Option<String> {
+    query_ctx
+        .current_user()
+        .map(|user| user.username().to_string())
+}
+
+/// Returns the schema of the parameters of `plan`, the fields are named `$1`, `$2`,
+/// etc. Parameters whose types can't be inferred are of the null type.
+fn parameter_schema(plan: &LogicalPlan) -> Result<ArrowSchema> {
+    let mut parameters = plan
+        .get_param_types()
+        .context(GetPreparedStmtParamsSnafu)?
+        .into_iter()
+        .map(|(name, datatype)| {
+            let index = name
+                .trim_start_matches('$')
+                .parse::<usize>()
+                .unwrap_or(usize::MAX);
+            let datatype = datatype
+                .map(|datatype| datatype.as_arrow_type())
+                .unwrap_or(DataType::Null);
+            (index, Field::new(name, datatype, true))
+        })
+        .collect::<Vec<_>>();
+    parameters.sort_by_key(|(index, _)| *index);
+    Ok(ArrowSchema::new(
+        parameters
+            .into_iter()
+            .map(|(_, field)| field)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn quote_identifier(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+/// Returns the query on `information_schema` that answers the metadata command.
+fn metadata_sql(command: &MetadataCommand) -> String {
+    let mut filters = Vec::new();
+    let sql = match command {
+        MetadataCommand::Catalogs => {
+            "SELECT DISTINCT catalog_name FROM information_schema.schemata \
+             ORDER BY catalog_name"
+        }
+        MetadataCommand::DbSchemas(command) => {
+            if let Some(catalog) = &command.catalog {
+                filters.push(format!("catalog_name = {}", quote_literal(catalog)));
+            }
+            if let Some(pattern) = &command.db_schema_filter_pattern {
+                filters.push(format!("schema_name LIKE {}", quote_literal(pattern)));
+            }
+            "SELECT catalog_name, schema_name AS db_schema_name \
+             FROM information_schema.schemata{filters} \
+             ORDER BY catalog_name, db_schema_name"
+        }
+        MetadataCommand::Tables(command) => {
+            if let Some(catalog) = &command.catalog {
+                filters.push(format!("table_catalog = {}", quote_literal(catalog)));
+            }
+            if let Some(pattern) = &command.db_schema_filter_pattern {
+                filters.push(format!("table_schema LIKE {}", quote_literal(pattern)));
+            }
+            if let Some(pattern) = &command.table_name_filter_pattern {
+                filters.push(format!("table_name LIKE {}", quote_literal(pattern)));
+            }
+            if !command.table_types.is_empty() {
+                let table_types = command
+                    .table_types
+                    .iter()
+                    .map(|t| quote_literal(t))
+                    .collect::<Vec<_>>();
+                filters.push(format!("table_type IN ({})", table_types.join(", ")));
+            }
+            "SELECT table_catalog AS catalog_name, table_schema AS db_schema_name, \
+             table_name, table_type FROM information_schema.tables{filters} \
+             ORDER BY catalog_name, db_schema_name, table_name"
+        }
+        MetadataCommand::TableTypes => unreachable!("table types are not in information_schema"),
+    };
+
+    let filters = if filters.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", filters.join(" AND "))
+    };
+    sql.replace("{filters}", &filters)
+}
+
+enum SqlInfoValue {
+    String(&'static str),
+    Bool(bool),
+}
+
+const SQL_INFOS: [(SqlInfo, SqlInfoValue); 3] = [
+    (
+        SqlInfo::FlightSqlServerName,
+        SqlInfoValue::String("GreptimeDB"),
+    ),
+    (
+        SqlInfo::FlightSqlServerVersion,
+        SqlInfoValue::String(env!("CARGO_PKG_VERSION")),
+    ),
+    (SqlInfo::FlightSqlServerReadOnly, SqlInfoValue::Bool(false)),
+];
+
+/// Builds the result of [CommandGetSqlInfo]. Values are in a dense union as the
+/// Flight SQL specification requires, so it's built in arrow directly.
+fn sql_info_batch(info: &[u32]) -> Result<DfRecordBatch> {
+    let mut names = Vec::new();
+    let mut type_ids = Vec::new();
+    let mut offsets = Vec::new();
+    let mut strings = Vec::new();
+    let mut bools = Vec::new();
+    for (name, value) in SQL_INFOS
+        .iter()
+        .filter(|(name, _)| info.is_empty() || info.contains(&(*name as u32)))
+    {
+        names.push(*name as u32);
+        match value {
+            SqlInfoValue::String(s) => {
+                type_ids.push(0i8);
+                offsets.push(strings.len() as i32);
+                strings.push(*s);
+            }
+            SqlInfoValue::Bool(b) => {
+                type_ids.push(1i8);
+                offsets.push(bools.len() as i32);
+                bools.push(*b);
+            }
+        }
+    }
+
+    let values = UnionArray::try_new(
+        &[0, 1],
+        Buffer::from_slice_ref(&type_ids),
+        Some(Buffer::from_slice_ref(&offsets)),
+        vec![
+            (
+                Field::new("string_value", DataType::Utf8, false),
+                Arc::new(StringArray::from(strings)) as ArrayRef,
+            ),
+            (
+                Field::new("bool_value", DataType::Boolean, false),
+                Arc::new(BooleanArray::from(bools)) as ArrayRef,
+            ),
+        ],
+    )
+    .context(EncodeFlightSqlResultSnafu)?;
+
+    let schema = ArrowSchema::new(vec![
+        Field::new("info_name", DataType::UInt32, false),
+        Field::new("value", values.data_type().clone(), false),
+    ]);
+    DfRecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(UInt32Array::from(names)), Arc::new(values)],
+    )
+    .context(EncodeFlightSqlResultSnafu)
+}
+
+fn schema_to_ipc(schema: &ArrowSchema) -> Result<Vec<u8>> {
+    let IpcMessage(message) = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+        .try_into()
+        .context(EncodeFlightSqlResultSnafu)?;
+    Ok(message.to_vec())
+}
+
+/// Returns `rows` as a record batch, since Flight SQL clients only expect
+/// record batches from queries.
+fn affected_rows_to_recordbatches(rows: usize) -> Result<RecordBatches> {
+    let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+        "affected_rows",
+        ConcreteDataType::uint64_datatype(),
+        false,
+    )]));
+    RecordBatches::try_from_columns(
+        schema,
+        vec![Arc::new(UInt64Vector::from_slice([rows as u64])) as _],
+    )
+    .context(CollectRecordbatchSnafu)
+}
+
+fn output_to_flight_data(output: Output, trace_id: u64) -> Result<TonicStream<FlightData>> {
+    let stream = match output {
+        Output::Stream(stream) => stream,
+        Output::RecordBatches(recordbatches) => recordbatches.as_stream(),
+        Output::AffectedRows(rows) => affected_rows_to_recordbatches(rows)?.as_stream(),
+    };
+    Ok(Box::pin(FlightRecordBatchStream::new(stream, trace_id)))
+}
+
+/// Handler for Flight SQL requests.
+#[derive(Clone)]
+pub struct FlightSqlHandler {
+    handler: ServerSqlQueryHandlerRef,
+    ingest_handler: FlightSqlIngestHandlerRef,
+    user_provider: Option<UserProviderRef>,
+    prepared_statements: Arc<PreparedStatements>,
+}
+
+impl FlightSqlHandler {
+    pub fn new(
+        handler: ServerSqlQueryHandlerRef,
+        ingest_handler: FlightSqlIngestHandlerRef,
+        user_provider: Option<UserProviderRef>,
+    ) -> Self {
+        Self {
+            handler,
+            ingest_handler,
+            user_provider,
+            prepared_statements: Arc::new(PreparedStatements::default()),
+        }
+    }
+
+    /// Creates the query context from the request metadata, and authenticates the
+    /// user by the basic `authorization` header.
+    async fn query_context(
+        &self,
+        metadata: &MetadataMap,
+        client_addr: Option<SocketAddr>,
+    ) -> Result<QueryContextRef> {
+        let (catalog, schema) = metadata
+            .get(GREPTIME_DB_NAME_HEADER_NAME.as_str())
+            .and_then(|db| db.to_str().ok())
+            .map(parse_catalog_and_schema_from_db_string)
+            .unwrap_or((DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME));
+
+        let query_ctx = QueryContextBuilder::default()
+            .current_catalog(catalog.to_string())
+            .current_schema(schema.to_string())
+            .channel(Channel::Grpc)
+            .client_addr(client_addr)
+            .build();
+
+        if let Some(user_provider) = &self.user_provider {
+            let authorization = metadata
+                .get(AUTHORIZATION)
+                .context(NotFoundAuthHeaderSnafu)?
+                .to_str()
+                .ok()
+                .context(InvalidAuthorizationHeaderSnafu)?;
+            let AuthScheme::Basic(username, password) = AuthScheme::try_from(authorization)?;
+            let user_info = user_provider
+                .auth(
+                    Identity::UserId(&username, None),
+                    Password::PlainText(password),
+                    catalog,
+                    schema,
+                )
+                .await
+                .context(AuthSnafu)?;
+            query_ctx.set_current_user(Some(user_info));
+        }
+
+        Ok(query_ctx)
+    }
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<TonicStream<HandshakeResponse>>> {
+        let _ = self
+            .query_context(request.metadata(), request.remote_addr())
+            .await?;
+
+        let stream = tokio_stream::once(Ok(HandshakeResponse {
+            protocol_version: 0,
+            payload: Default::default(),
+        }));
+        let mut response = Response::new(Box::pin(stream) as TonicStream<_>);
+        // Clients send the returned authorization in the following requests.
+        if let Some(authorization) = request.metadata().get(AUTHORIZATION) {
+            let _ = response
+                .metadata_mut()
+                .insert(AUTHORIZATION, authorization.clone());
+        }
+        Ok(response)
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>> {
+        let query_ctx = self
+            .query_context(request.metadata(), request.remote_addr())
+            .await?;
+        let descriptor = request.into_inner();
+        ensure!(
+            descriptor.r#type() == DescriptorType::Cmd,
+            NotSupportedSnafu {
+                feat: "Flight info of PATH descriptors"
+            }
+        );
+
+        let any = Any::decode(descriptor.cmd.as_ref()).context(InvalidFlightSqlCommandSnafu)?;
+        let command = QueryCommand::try_from_any(&any)?;
+        let schema = self.schema(command, query_ctx).await?;
+
+        // The ticket is the command itself, which is executed in `do_get`.
+        let endpoint = FlightEndpoint::new().with_ticket(Ticket {
+            ticket: descriptor.cmd.clone(),
+        });
+        let flight_info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .context(EncodeFlightSqlResultSnafu)?
+            .with_endpoint(endpoint)
+            .with_descriptor(descriptor);
+        Ok(Response::new(flight_info))
+    }
+
+    async fn do_get(&self, request: Request<Ticket>) -> Result<Response<TonicStream<FlightData>>> {
+        let query_ctx = self
+            .query_context(request.metadata(), request.remote_addr())
+            .await?;
+        let any = Any::decode(request.into_inner().ticket.as_ref())
+            .context(InvalidFlightSqlCommandSnafu)?;
+        let command = QueryCommand::try_from_any(&any)?;
+
+        let trace_id = query_ctx.trace_id();
+        let stream = match command {
+            QueryCommand::Statement(query) => {
+                let output = self.do_query(&query, query_ctx).await?;
+                output_to_flight_data(output, trace_id)?
+            }
+            QueryCommand::PreparedStatement(handle) => {
+                let owner = statement_owner(&query_ctx);
+                let statement = self.prepared_statements.get(&handle, owner.as_deref())?;
+                let mut outputs = self.execute_prepared(&statement, query_ctx).await?;
+                ensure!(
+                    outputs.len() == 1,
+                    InvalidQuerySnafu {
+                        reason: format!(
+                            "Expecting one set of parameters for a query, found {}",
+                            outputs.len()
+                        ),
+                    }
+                );
+                output_to_flight_data(outputs.remove(0), trace_id)?
+            }
+            QueryCommand::Metadata(command) => {
+                let recordbatches = self.metadata(command, query_ctx).await?;
+                output_to_flight_data(Output::RecordBatches(recordbatches), trace_id)?
+            }
+            QueryCommand::SqlInfo(info) => {
+                let batch = sql_info_batch(&info)?;
+                let flight_data =
+                    batches_to_flight_data(batch.schema().as_ref().clone(), vec![batch])
+                        .context(EncodeFlightSqlResultSnafu)?;
+                Box::pin(stream::iter(flight_data.into_iter().map(Ok))) as _
+            }
+        };
+        Ok(Response::new(stream))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<TonicStream<PutResult>>> {
+        let query_ctx = self
+            .query_context(request.metadata(), request.remote_addr())
+            .await?;
+        let mut flight_data = request.into_inner();
+        let first = flight_data
+            .message()
+            .await
+            .context(ReceiveFlightDataSnafu)?
+            .context(InvalidQuerySnafu {
+                reason: "Expecting non-empty Flight data",
+            })?;
+        let descriptor = first.flight_descriptor.clone().context(InvalidQuerySnafu {
+            reason: "Expecting Flight descriptor in the first Flight data",
+        })?;
+
+        let owner = statement_owner(&query_ctx);
+        let record_count = match descriptor.r#type() {
+            DescriptorType::Path => {
+                self.ingest(&descriptor.path, first, flight_data, query_ctx)
+                    .await?
+            }
+            _ => {
+                let any =
+                    Any::decode(descriptor.cmd.as_ref()).context(InvalidFlightSqlCommandSnafu)?;
+                let outputs = if let Some(command) = decode_command::<CommandStatementUpdate>(&any)?
+                {
+                    vec![self.do_query(&command.query, query_ctx).await?]
+                } else if let Some(command) =
+                    decode_command::<CommandPreparedStatementUpdate>(&any)?
+                {
+                    let handle = command.prepared_statement_handle;
+                    let parameters = collect_record_batches(first, flight_data).await?;
+                    if !parameters.is_empty() {
+                        self.prepared_statements
+                            .bind(&handle, owner.as_deref(), parameters)?;
+                    }
+                    let statement = self.prepared_statements.get(&handle, owner.as_deref())?;
+                    self.execute_prepared(&statement, query_ctx).await?
+                } else if let Some(command) = decode_command::<CommandPreparedStatementQuery>(&any)?
+                {
+                    // Binds the parameters, the query is executed by the following `DoGet`.
+                    let parameters = collect_record_batches(first, flight_data).await?;
+                    self.prepared_statements.bind(
+                        &command.prepared_statement_handle,
+                        owner.as_deref(),
+                        parameters,
+                    )?;
+                    return Ok(Response::new(Box::pin(stream::empty()) as _));
+                } else {
+                    return NotSupportedSnafu {
+                        feat: format!("Flight SQL command {} in DoPut", any.type_url),
+                    }
+                    .fail();
+                };
+
+                outputs
+                    .into_iter()
+                    .map(|output| match output {
+                        Output::AffectedRows(rows) => rows,
+                        _ => 0,
+                    })
+                    .sum()
+            }
+        };
+
+        let result = PutResult {
+            app_metadata: DoPutUpdateResult {
+                record_count: record_count as i64,
+            }
+            .encode_to_vec()
+            .into(),
+        };
+        Ok(Response::new(Box::pin(tokio_stream::once(Ok(result))) as _))
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<TonicStream<arrow_flight::Result>>> {
+        let query_ctx = self
+            .query_context(request.metadata(), request.remote_addr())
+            .await?;
+        let action = request.into_inner();
+        let any = Any::decode(action.body.as_ref()).context(InvalidFlightSqlCommandSnafu)?;
+        let owner = statement_owner(&query_ctx);
+
+        let results = match action.r#type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let request = decode_command::<ActionCreatePreparedStatementRequest>(&any)?
+                    .context(InvalidQuerySnafu {
+                        reason: "Expecting ActionCreatePreparedStatementRequest",
+                    })?;
+                let (dataset_schema, parameter_schema) =
+                    match self.plan(&request.query, query_ctx.clone()).await? {
+                        Some(result) => (
+                            result.schema.arrow_schema().clone(),
+                            parameter_schema(&result.logical_plan)?,
+                        ),
+                        None => (Arc::new(ArrowSchema::empty()), ArrowSchema::empty()),
+                    };
+                let handle = self.prepared_statements.create(request.query, owner)?;
+                let result = ActionCreatePreparedStatementResult {
+                    prepared_statement_handle: handle.into(),
+                    dataset_schema: schema_to_ipc(&dataset_schema)?.into(),
+                    parameter_schema: schema_to_ipc(&parameter_schema)?.into(),
+                };
+                vec![arrow_flight::Result {
+                    body: pack(&result).encode_to_vec().into(),
+                }]
+            }
+            CLOSE_PREPARED_STATEMENT => {
+                let request = decode_command::<ActionClosePreparedStatementRequest>(&any)?
+                    .context(InvalidQuerySnafu {
+                        reason: "Expecting ActionClosePreparedStatementRequest",
+                    })?;
+                self.prepared_statements
+                    .close(&request.prepared_statement_handle, owner.as_deref())?;
+                vec![]
+            }
+            other => {
+                return NotSupportedSnafu {
+                    feat: format!("Flight SQL action {other}"),
+                }
+                .fail()
+            }
+        };
+        Ok(Response::new(
+            Box::pin(stream::iter(results.into_iter().map(Ok))) as _,
+        ))
+    }
+
+    fn list_actions(&self) -> Response<TonicStream<ActionType>> {
+        let actions = [
+            (
+                CREATE_PREPARED_STATEMENT,
+                "Creates a reusable prepared statement resource on the server.",
+            ),
+            (
+                CLOSE_PREPARED_STATEMENT,
+                "Closes a reusable prepared statement resource on the server.",
+            ),
+        ]
+        .into_iter()
+        .map(|(r#type, description)| {
+            Ok(ActionType {
+                r#type: r#type.to_string(),
+                description: description.to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+        Response::new(Box::pin(stream::iter(actions)) as _)
+    }
+
+    async fn do_query(&self, query: &str, query_ctx: QueryContextRef) -> Result<Output> {
+        self.handler
+            .do_query(query, query_ctx)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
+            .pop()
+            .context(InvalidQuerySnafu {
+                reason: "Expecting at least one statement",
+            })
+    }
+
+    /// Executes the prepared statement once per row of its parameters, or once if
+    /// no parameters are bound.
+    async fn execute_prepared(
+        &self,
+        statement: &PreparedStatement,
+        query_ctx: QueryContextRef,
+    ) -> Result<Vec<Output>> {
+        if statement
+            .parameters
+            .iter()
+            .all(|batch| batch.num_rows() == 0)
+        {
+            return Ok(vec![self.do_query(&statement.query, query_ctx).await?]);
+        }
+
+        let plan = self
+            .plan(&statement.query, query_ctx.clone())
+            .await?
+            .context(InvalidQuerySnafu {
+                reason: "Parameters are only supported by queries and DML statements",
+            })?
+            .logical_plan;
+        let mut outputs = Vec::new();
+        for batch in &statement.parameters {
+            for row in 0..batch.num_rows() {
+                let values = batch
+                    .df_record_batch()
+                    .columns()
+                    .iter()
+                    .map(|column| ScalarValue::try_from_array(column, row))
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .context(DataFrameSnafu)?;
+                let plan = plan
+                    .replace_params_with_values(&values)
+                    .context(ReplacePreparedStmtParamsSnafu)?;
+                outputs.push(self.handler.do_exec_plan(plan, query_ctx.clone()).await?);
+            }
+        }
+        Ok(outputs)
+    }
+
+    /// Returns the schema of the result of `query` without executing it.
+    async fn describe(&self, query: &str, query_ctx: QueryContextRef) -> Result<SchemaRef> {
+        let schema = self
+            .plan(query, query_ctx)
+            .await?
+            .map(|result| result.schema.arrow_schema().clone())
+            // Statements other than queries have no result set.
+            .unwrap_or_else(|| Arc::new(ArrowSchema::empty()));
+        Ok(schema)
+    }
+
+    /// Plans `query` without executing it, returns `None` if the statement can't be
+    /// planned, e.g. DDL.
+    async fn plan(
+        &self,
+        query: &str,
+        query_ctx: QueryContextRef,
+    ) -> Result<Option<DescribeResult>> {
+        let mut stmts =
+            ParserContext::create_with_dialect(query, &GreptimeDbDialect {}).map_err(|e| {
+                InvalidQuerySnafu {
+                    reason: e.to_string(),
+                }
+                .build()
+            })?;
+        ensure!(
+            stmts.len() == 1,
+            InvalidQuerySnafu {
+                reason: "Expecting exactly one statement",
+            }
+        );
+
+        self.handler.do_describe(stmts.remove(0), query_ctx).await
+    }
+
+    async fn schema(&self, command: QueryCommand, query_ctx: QueryContextRef) -> Result<SchemaRef> {
+        match command {
+            QueryCommand::Statement(query) => self.describe(&query, query_ctx).await,
+            QueryCommand::PreparedStatement(handle) => {
+                let owner = statement_owner(&query_ctx);
+                let statement = self.prepared_statements.get(&handle, owner.as_deref())?;
+                self.describe(&statement.query, query_ctx).await
+            }
+            // Metadata queries are cheap, runs them to get the schema.
+            QueryCommand::Metadata(command) => Ok(self
+                .metadata(command, query_ctx)
+                .await?
+                .schema()
+                .arrow_schema()
+                .clone()),
+            QueryCommand::SqlInfo(info) => Ok(sql_info_batch(&info)?.schema()),
+        }
+    }
+
+    async fn metadata(
+        &self,
+        command: MetadataCommand,
+        query_ctx: QueryContextRef,
+    ) -> Result<RecordBatches> {
+        if command == MetadataCommand::TableTypes {
+            let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+                "table_type",
+                ConcreteDataType::string_datatype(),
+                false,
+            )]));
+            return RecordBatches::try_from_columns(
+                schema,
+                vec![Arc::new(StringVector::from(TABLE_TYPES.to_vec())) as _],
+            )
+            .context(CollectRecordbatchSnafu);
+        }
+
+        let output = self
+            .do_query(&metadata_sql(&command), query_ctx.clone())
+            .await?;
+        let recordbatches = match output {
+            Output::Stream(stream) => util::collect_batches(stream)
+                .await
+                .context(CollectRecordbatchSnafu)?,
+            Output::RecordBatches(recordbatches) => recordbatches,
+            Output::AffectedRows(_) => unreachable!("metadata queries return record batches"),
+        };
+
+        match command {
+            MetadataCommand::Tables(command) if command.include_schema => {
+                self.with_table_schemas(recordbatches, query_ctx).await
+            }
+            _ => Ok(recordbatches),
+        }
+    }
+
+    /// Appends the `table_schema` column, the IPC encoded schema of each table, to
+    /// the result of [CommandGetTables].
+    async fn with_table_schemas(
+        &self,
+        tables: RecordBatches,
+        query_ctx: QueryContextRef,
+    ) -> Result<RecordBatches> {
+        let mut column_schemas = tables.schema().column_schemas().to_vec();
+        column_schemas.push(ColumnSchema::new(
+            "table_schema",
+            ConcreteDataType::binary_datatype(),
+            false,
+        ));
+        let schema = Arc::new(Schema::new(column_schemas));
+
+        let mut batches = Vec::new();
+        for batch in tables.take() {
+            let mut table_schemas = BinaryVectorBuilder::with_capacity(batch.num_rows());
+            for row in batch.rows() {
+                let [Value::String(catalog), Value::String(db_schema), Value::String(table), ..] =
+                    row.as_slice()
+                else {
+                    return error::InternalSnafu {
+                        err_msg: format!("Unexpected row of tables: {row:?}"),
+                    }
+                    .fail();
+                };
+                let query = format!(
+                    "SELECT * FROM {}.{}.{}",
+                    quote_identifier(catalog.as_utf8()),
+                    quote_identifier(db_schema.as_utf8()),
+                    quote_identifier(table.as_utf8())
+                );
+                let table_schema = self.describe(&query, query_ctx.clone()).await?;
+                table_schemas.push(Some(&schema_to_ipc(&table_schema)?));
+            }
+
+            let mut columns = batch.columns().to_vec();
+            columns.push(Arc::new(table_schemas.finish()));
+            batches
+                .push(RecordBatch::new(schema.clone(), columns).context(CollectRecordbatchSnafu)?);
+        }
+        RecordBatches::try_new(schema, batches).context(CollectRecordbatchSnafu)
+    }
+
+    /// Inserts the record batches in `flight_data` into the table at `path`, which
+    /// is `[table]`, `[schema, table]` or `[catalog, schema, table]`.
+    async fn ingest(
+        &self,
+        path: &[String],
+        first: FlightData,
+        mut flight_data: Streaming<FlightData>,
+        query_ctx: QueryContextRef,
+    ) -> Result<usize> {
+        let mut decoder = FlightDecoder::default();
+        let mut affected_rows = 0;
+        let mut next = Some(first);
+        while let Some(data) = next {
+            if let FlightMessage::Recordbatch(batch) =
+                decoder.try_decode(data).context(DecodeFlightDataSnafu)?
+            {
+                if batch.num_rows() > 0 {
+                    let request = insert_request(path, batch, &query_ctx)?;
+                    affected_rows += self
+                        .ingest_handler
+                        .ingest(request, query_ctx.clone())
+                        .await?;
+                }
+            }
+            next = flight_data
+                .message()
+                .await
+                .context(ReceiveFlightDataSnafu)?;
+        }
+        Ok(affected_rows)
+    }
+}
+
+/// Decodes the record batches in `first` and the rest of `flight_data`.
+async fn collect_record_batches(
+    first: FlightData,
+    mut flight_data: Streaming<FlightData>,
+) -> Result<Vec<RecordBatch>> {
+    let mut decoder = FlightDecoder::default();
+    let mut batches = Vec::new();
+    let mut next = Some(first);
+    while let Some(data) = next {
+        if let FlightMessage::Recordbatch(batch) =
+            decoder.try_decode(data).context(DecodeFlightDataSnafu)?
+        {
+            batches.push(batch);
+        }
+        next = flight_data
+            .message()
+            .await
+            .context(ReceiveFlightDataSnafu)?;
+    }
+    Ok(batches)
+}
+
+/// Returns the request that inserts `batch` into the table at `path`, the catalog
+/// and schema default to the ones of `query_ctx`.
+fn insert_request(
+    path: &[String],
+    batch: RecordBatch,
+    query_ctx: &QueryContextRef,
+) -> Result<InsertRequest> {
+    let (catalog_name, schema_name, table_name) = match path {
+        [table] => (
+            query_ctx.current_catalog().to_string(),
+            query_ctx.current_schema().to_string(),
+            table.clone(),
+        ),
+        [schema, table] => (
+            query_ctx.current_catalog().to_string(),
+            schema.clone(),
+            table.clone(),
+        ),
+        [catalog, schema, table] => (catalog.clone(), schema.clone(), table.clone()),
+        _ => {
+            return InvalidQuerySnafu {
+                reason: format!("Invalid table path: {path:?}"),
+            }
+            .fail()
+        }
+    };
+
+    let columns_values = batch
+        .schema
+        .column_schemas()
+        .iter()
+        .map(|column| column.name.clone())
+        .zip(batch.columns().iter().cloned())
+        .collect();
+    Ok(InsertRequest {
+        catalog_name,
+        schema_name,
+        table_name,
+        columns_values,
+        region_number: 0,
+    })
+}
+
+/// A [FlightService] serves Flight SQL requests, and delegates GreptimeDB's own
+/// tickets to the [FlightCraft].
+pub struct FlightSqlServiceWrapper<T: FlightCraft> {
+    craft: T,
+    handler: FlightSqlHandler,
+}
+
+impl<T: FlightCraft> FlightSqlServiceWrapper<T> {
+    pub fn new(craft: T, handler: FlightSqlHandler) -> Self {
+        Self { craft, handler }
+    }
+}
+
+fn is_flight_sql_ticket(ticket: &Ticket) -> bool {
+    Any::decode(ticket.ticket.as_ref())
+        .map(|any| any.type_url.starts_with(FLIGHT_SQL_TYPE_URL_PREFIX))
+        .unwrap_or(false)
+}
+
+#[async_trait]
+impl<T: FlightCraft> FlightService for FlightSqlServiceWrapper<T> {
+    type HandshakeStream = TonicStream<HandshakeResponse>;
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> TonicResult<Response<Self::HandshakeStream>> {
+        Ok(self.handler.handshake(request).await?)
+    }
+
+    type ListFlightsStream = TonicStream<FlightInfo>;
+
+    async fn list_flights(
+        &self,
+        _: Request<Criteria>,
+    ) -> TonicResult<Response<Self::ListFlightsStream>> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> TonicResult<Response<FlightInfo>> {
+        Ok(self.handler.get_flight_info(request).await?)
+    }
+
+    async fn get_schema(
+        &self,
+        _: Request<FlightDescriptor>,
+    ) -> TonicResult<Response<SchemaResult>> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    type DoGetStream = TonicStream<FlightData>;
+
+    async fn do_get(&self, request: Request<Ticket>) -> TonicResult<Response<Self::DoGetStream>> {
+        if is_flight_sql_ticket(request.get_ref()) {
+            Ok(self.handler.do_get(request).await?)
+        } else {
+            self.craft.do_get(request).await
+        }
+    }
+
+    type DoPutStream = TonicStream<PutResult>;
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> TonicResult<Response<Self::DoPutStream>> {
+        Ok(self.handler.do_put(request).await?)
+    }
+
+    type DoExchangeStream = TonicStream<FlightData>;
+
+    async fn do_exchange(
+        &self,
+        _: Request<Streaming<FlightData>>,
+    ) -> TonicResult<Response<Self::DoExchangeStream>> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    type DoActionStream = TonicStream<arrow_flight::Result>;
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> TonicResult<Response<Self::DoActionStream>> {
+        Ok(self.handler.do_action(request).await?)
+    }
+
+    type ListActionsStream = TonicStream<ActionType>;
+
+    async fn list_actions(
+        &self,
+        _: Request<Empty>,
+    ) -> TonicResult<Response<Self::ListActionsStream>> {
+        Ok(self.handler.list_actions())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::arrow::array::Array;
+    use datatypes::vectors::{Int64Vector, TimestampMillisecondVector};
+
+    use super::*;
+
+    #[test]
+    fn test_query_command() {
+        let any = pack(&CommandStatementQuery {
+            query: "SELECT 1".to_string(),
+            transaction_id: None,
+        });
+        assert_eq!(
+            QueryCommand::Statement("SELECT 1".to_string()),
+            QueryCommand::try_from_any(&any).unwrap()
+        );
+
+        let any = pack(&CommandPreparedStatementQuery {
+            prepared_statement_handle: 2u64.to_be_bytes().to_vec().into(),
+        });
+        assert_eq!(
+            QueryCommand::PreparedStatement(2u64.to_be_bytes().to_vec()),
+            QueryCommand::try_from_any(&any).unwrap()
+        );
+
+        let any = pack(&CommandGetCatalogs {});
+        assert_eq!(
+            QueryCommand::Metadata(MetadataCommand::Catalogs),
+            QueryCommand::try_from_any(&any).unwrap()
+        );
+        assert!(is_flight_sql_ticket(&Ticket {
+            ticket: any.encode_to_vec().into()
+        }));
+
+        let any = pack(&CommandStatementUpdate {
+            query: "DELETE FROM t".to_string(),
+            transaction_id: None,
+        });
+        assert!(QueryCommand::try_from_any(&any).is_err());
+    }
+
+    #[test]
+    fn test_metadata_sql() {
+        assert_eq!(
+            "SELECT catalog_name, schema_name AS db_schema_name \
+             FROM information_schema.schemata \
+             ORDER BY catalog_name, db_schema_name",
+            metadata_sql(&MetadataCommand::DbSchemas(CommandGetDbSchemas {
+                catalog: None,
+                db_schema_filter_pattern: None,
+            }))
+        );
+
+        assert_eq!(
+            "SELECT table_catalog AS catalog_name, table_schema AS db_schema_name, \
+             table_name, table_type FROM information_schema.tables \
+             WHERE table_catalog = 'greptime' AND table_name LIKE 'it''s%' \
+             AND table_type IN ('BASE TABLE', 'VIEW') \
+             ORDER BY catalog_name, db_schema_name, table_name",
+            metadata_sql(&MetadataCommand::Tables(CommandGetTables {
+                catalog: Some("greptime".to_string()),
+                db_schema_filter_pattern: None,
+                table_name_filter_pattern: Some("it's%".to_string()),
+                table_types: vec!["BASE TABLE".to_string(), "VIEW".to_string()],
+                include_schema: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_sql_info_batch() {
+        let batch = sql_info_batch(&[]).unwrap();
+        assert_eq!(SQL_INFOS.len(), batch.num_rows());
+
+        let batch = sql_info_batch(&[SqlInfo::FlightSqlServerName as u32]).unwrap();
+        assert_eq!(1, batch.num_rows());
+        assert_eq!(
+            &UInt32Array::from(vec![SqlInfo::FlightSqlServerName as u32]),
+            batch
+                .column(0)
+                .as_any()
+                .downca    #[test]
+    fn test_prepared_statements() {
+        let statements = PreparedStatements::default();
+        let handle = statements.create("SELECT $1".to_string(), None).unwrap();
+        assert_eq!(PREPARED_STATEMENT_HANDLE_LEN, handle.len());
+        assert_ne!(
+            handle,
+            statements.create("SELECT $1".to_string(), None).unwrap()
+        );
+        assert_eq!("SELECT $1", statements.get(&handle, None).unwrap().query);
+
+        let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+            "$1",
+            ConcreteDataType::int64_datatype(),
+            true,
+        )]));
+        let batch = RecordBatch::new(
+            schema,
+            vec![Arc::new(Int64Vector::from(vec![Some(1), Some(2)])) as _],
+        )
+        .unwrap();
+        statements.bind(&handle, None, vec![batch]).unwrap();
+        assert_eq!(
+            2,
+            statements.get(&handle, None).unwrap().parameters[0].num_rows()
+        );
+
+        statements.close(&handle, None).unwrap();
+        assert!(statements.get(&handle, None).is_err());
+        assert!(statements.bind(&handle, None, vec![]).is_err());
+        assert!(statements.get(b"SELECT 1", None).is_err());
+    }
+
+    #[test]
+    fn test_prepared_statements_of_users() {
+        let statements = PreparedStatements::default();
+        let handle = statements
+            .create("SELECT 1".to_string(), Some("alice".to_string()))
+            .unwrap();
+        assert!(statements.get(&handle, Some("alice")).is_ok());
+
+        // Other users can't see, bind or close the statement.
+        assert!(statements.get(&handle, Some("bob")).is_err());
+        assert!(statements.get(&handle, None).is_err());
+        assert!(statements.bind(&handle, Some("bob"), vec![]).is_err());
+        assert!(statements.close(&handle, Some("bob")).is_err());
+        assert!(statements.get(&handle, Some("alice")).is_ok());
+
+        statements.close(&handle, Some("alice")).unwrap();
+        assert!(statements.get(&handle, Some("alice")).is_err());
+    }
+
+    #[test]
+    fn test_prepared_statements_limits() {
+        let statements = PreparedStatements::new(PREPARED_STATEMENT_TTL, 2);
+        let alice = Some("alice".to_string());
+        let first = statements.create("SELECT 1".to_string(), alice.clone()).unwrap();
+        let _ = statements.create("SELECT 2".to_string(), alice.clone()).unwrap();
+        assert!(statements.create("SELECT 3".to_string(), alice.clone()).is_err());
+        // The limit is per user.
+        assert!(statements
+            .create("SELECT 3".to_string(), Some("bob".to_string()))
+            .is_ok());
+        statements.close(&first, alice.as_deref()).unwrap();
+        assert!(statements.create("SELECT 3".to_string(), alice).is_ok());
+
+        // Statements expire once they are unused for the TTL.
+        let statements = PreparedStatements::new(Duration::ZERO, 1);
+        let handle = statements.create("SELECT 1".to_string(), None).unwrap();
+        assert!(statements.get(&handle, None).is_err());
+        // Expired statements don't count towards the limit.
+        assert!(statements.create("SELECT 2".to_string(), None).is_ok());
+    }
+
+r());
+        assert!(statements.get(b"SELECT 1").is_err());
+    }
+
+    #[test]
+    fn test_insert_request() {
+        let schema = Arc::new(Schema::new(vec![
+            ColumnSchema::new("host", ConcreteDataType::string_datatype(), true),
+            ColumnSchema::new(
+                "ts",
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                false,
+            ),
+        ]));
+        let batch = RecordBatch::new(
+            schema,
+            vec![
+                Arc::new(StringVector::from(vec![Some("a"), None])) as _,
+                Arc::new(TimestampMillisecondVector::from_vec(vec![0, 1000])) as _,
+            ],
+        )
+        .unwrap();
+        let query_ctx = QueryContextBuilder::default()
+            .current_catalog("greptime".to_string())
+            .current_schema("public".to_string())
+            .build();
+
+        let path = vec!["metrics".to_string()];
+        let request = insert_request(&path, batch.clone(), &query_ctx).unwrap();
+        assert_eq!(
+            ("greptime", "public", "metrics"),
+            (
+                request.catalog_name.as_str(),
+                request.schema_name.as_str(),
+                request.table_name.as_str()
+            )
+        );
+        assert_eq!(2, request.columns_values.len());
+        assert_eq!(2, request.columns_values["ts"].len());
+
+        let path = vec!["db".to_string(), "metrics".to_string()];
+        let request = insert_request(&path, batch.clone(), &query_ctx).unwrap();
+        assert_eq!("db", request.schema_name);
+
+        assert!(insert_request(&[], batch, &query_ctx).is_err());
+    }
+}
@@ -14,6 +14,7 @@
 
 //! Handler for Greptime Database service. It's implemented by frontend.
 
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -29,7 +30,7 @@ use common_query::Output;
 use common_runtime::Runtime;
 use common_telemetry::{logging, TRACE_ID};
 use metrics::{histogram, increment_counter};
-use session::context::{QueryContextBuilder, QueryContextRef};
+use session::context::{Channel, QueryContextBuilder, QueryContextRef};
 use snafu::{OptionExt, ResultExt};
 
 use crate::error::Error::UnsupportedAuthScheme;
@@ -60,13 +61,17 @@ impl GreptimeRequestHandler {
         }
     }
 
-    pub(crate) async fn handle_request(&self, request: GreptimeRequest) -> Result<Output> {
+    pub(crate) async fn handle_request(
+        &self,
+        request: GreptimeRequest,
+        client_addr: Option<SocketAddr>,
+    ) -> Result<Output> {
         let query = request.request.context(InvalidQuerySnafu {
             reason: "Expecting non-empty GreptimeRequest.",
         })?;
 
         let header = request.header.as_ref();
-        let query_ctx = create_query_context(header);
+        let query_ctx = create_query_context(header, client_addr);
         let user_info = auth(self.user_provider.clone(), header, &query_ctx).await?;
         query_ctx.set_current_user(user_info);
 
@@ -144,7 +149,10 @@ pub(crate) async fn auth(
     })
 }
 
-pub(crate) fn create_query_context(header: Option<&RequestHeader>) -> QueryContextRef {
+pub(crate) fn create_query_context(
+    header: Option<&RequestHeader>,
+    client_addr: Option<SocketAddr>,
+) -> QueryContextRef {
     let (catalog, schema) = header
         .map(|header| {
             // We provide dbname field in newer versions of protos/sdks
@@ -172,6 +180,8 @@ pub(crate) fn create_query_context(header: Option<&RequestHeader>) -> QueryConte
         .current_catalog(catalog.to_string())
         .current_schema(schema.to_string())
         .try_trace_id(header.map(|h| h.trace_id))
+        .channel(Channel::Grpc)
+        .client_addr(client_addr)
         .build()
 }
 
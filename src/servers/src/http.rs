@@ -65,7 +65,8 @@ use self::influxdb::{influxdb_health, influxdb_ping, influxdb_write_v1, influxdb
 use crate::configurator::ConfiguratorRef;
 use crate::error::{AlreadyStartedSnafu, Result, StartHttpSnafu};
 use crate::http::prometheus::{
-    instant_query, label_values_query, labels_query, range_query, series_query,
+    build_info_query, exemplars_query, format_query, instant_query, label_values_query,
+    labels_query, metadata_query, range_query, series_query,
 };
 use crate::metrics::{
     METRIC_CODE_LABEL, METRIC_HTTP_REQUESTS_ELAPSED, METRIC_HTTP_REQUESTS_TOTAL,
@@ -634,6 +635,16 @@ impl HttpServer {
                 "/label/:label_name/values",
                 routing::get(label_values_query),
             )
+            .route("/metadata", routing::get(metadata_query))
+            .route(
+                "/query_exemplars",
+                routing::post(exemplars_query).get(exemplars_query),
+            )
+            .route(
+                "/format_query",
+                routing::post(format_query).get(format_query),
+            )
+            .route("/status/buildinfo", routing::get(build_info_query))
             .with_state(prometheus_handler)
     }
 
@@ -731,7 +742,8 @@ impl Server for HttpServer {
                 app = configurator.config_http(app);
             }
             let app = self.build(app);
-            let server = axum::Server::bind(&listening).serve(app.into_make_service());
+            let server = axum::Server::bind(&listening)
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>());
 
             *shutdown_tx = Some(tx);
 
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::marker::PhantomData;
+use std::net::SocketAddr;
 
 use ::auth::UserProviderRef;
+use axum::extract::ConnectInfo;
 use axum::http::{self, Request, StatusCode};
 use axum::response::Response;
 use common_catalog::consts::DEFAULT_SCHEMA_NAME;
@@ -26,7 +28,7 @@ use headers::Header;
 use http_body::Body;
 use metrics::increment_counter;
 use secrecy::SecretString;
-use session::context::QueryContext;
+use session::context::{Channel, QueryContextBuilder};
 use snafu::{ensure, OptionExt, ResultExt};
 use tower_http::auth::AsyncAuthorizeRequest;
 
@@ -74,7 +76,16 @@ where
         let user_provider = self.user_provider.clone();
         Box::pin(async move {
             let (catalog, schema) = extract_catalog_and_schema(&request);
-            let query_ctx = QueryContext::with(catalog, schema);
+            let client_addr = request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|info| info.0);
+            let query_ctx = QueryContextBuilder::default()
+                .current_catalog(catalog.to_string())
+                .current_schema(schema.to_string())
+                .channel(Channel::Http)
+                .client_addr(client_addr)
+                .build();
             let need_auth = need_auth(&request);
 
             let user_provider = if let Some(user_provider) = user_provider.filter(|_| need_auth) {
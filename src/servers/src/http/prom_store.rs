@@ -14,7 +14,7 @@
 
 use api::prom_store::remote::{ReadRequest, WriteRequest};
 use axum::extract::{Query, RawBody, State};
-use axum::http::{header, StatusCode};
+use axum::http::{header, HeaderValue, StatusCode};
 use axum::response::IntoResponse;
 use axum::Extension;
 use common_catalog::consts::DEFAULT_SCHEMA_NAME;
@@ -28,7 +28,7 @@ use snafu::prelude::*;
 
 use crate::error::{self, Result};
 use crate::prom_store::snappy_decompress;
-use crate::query_handler::{PromStoreProtocolHandlerRef, PromStoreResponse};
+use crate::query_handler::{PromStoreProtocolHandlerRef, PromStoreResponse, PromStoreResponseBody};
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct DatabaseQuery {
@@ -66,14 +66,20 @@ pub async fn remote_write(
 
 impl IntoResponse for PromStoreResponse {
     fn into_response(self) -> axum::response::Response {
-        (
-            [
-                (header::CONTENT_TYPE, self.content_type),
-                (header::CONTENT_ENCODING, self.content_encoding),
-            ],
-            self.body,
-        )
-            .into_response()
+        let body = match self.body {
+            PromStoreResponseBody::Full(body) => Body::from(body),
+            PromStoreResponseBody::Stream(stream) => Body::wrap_stream(stream),
+        };
+
+        let mut response = ([(header::CONTENT_TYPE, self.content_type)], body).into_response();
+        if !self.content_encoding.is_empty() {
+            if let Ok(value) = HeaderValue::from_str(&self.content_encoding) {
+                let _ = response
+                    .headers_mut()
+                    .insert(header::CONTENT_ENCODING, value);
+            }
+        }
+        response
     }
 }
 
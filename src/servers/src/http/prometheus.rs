@@ -14,7 +14,10 @@
 
 //! prom supply the prometheus HTTP API Server compliance
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::UNIX_EPOCH;
 
+use api::prom_store::remote::label_matcher::Type as MatcherType;
+use api::prom_store::remote::{LabelMatcher, Query as RemoteQuery};
 use axum::extract::{Path, Query, State};
 use axum::{Extension, Form, Json};
 use catalog::CatalogManagerRef;
@@ -25,16 +28,18 @@ use common_error::status_code::StatusCode;
 use common_query::Output;
 use common_recordbatch::RecordBatches;
 use common_telemetry::timer;
+use common_time::timestamp::TimeUnit;
 use common_time::util::{current_time_rfc3339, yesterday_rfc3339};
 use datatypes::prelude::ConcreteDataType;
 use datatypes::scalars::ScalarVector;
+use datatypes::value::Value;
 use datatypes::vectors::{Float64Vector, StringVector, TimestampMillisecondVector};
-use promql_parser::label::METRIC_NAME;
+use promql_parser::label::{MatchOp, Matchers, METRIC_NAME};
 use promql_parser::parser::{
     AggregateExpr, BinaryExpr, Call, Expr as PromqlExpr, MatrixSelector, ParenExpr, SubqueryExpr,
     UnaryExpr, ValueType, VectorSelector,
 };
-use query::parser::{PromQuery, DEFAULT_LOOKBACK_STRING};
+use query::parser::{PromQuery, QueryLanguageParser, DEFAULT_LOOKBACK_STRING};
 use schemars::JsonSchema;
 use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Serialize};
@@ -42,9 +47,13 @@ use session::context::QueryContextRef;
 use snafu::{Location, OptionExt, ResultExt};
 
 use crate::error::{
-    CollectRecordbatchSnafu, Error, InternalSnafu, InvalidQuerySnafu, Result, UnexpectedResultSnafu,
+    CatalogSnafu, CollectRecordbatchSnafu, Error, InternalSnafu, InvalidQuerySnafu, Result,
+    UnexpectedResultSnafu,
+};
+use crate::prom_store::{
+    exemplar_table_name, MetricMetadata, EXEMPLAR_TABLE_SUFFIX, FIELD_COLUMN_NAME,
+    METRIC_NAME_LABEL, TIMESTAMP_COLUMN_NAME,
 };
-use crate::prom_store::{FIELD_COLUMN_NAME, METRIC_NAME_LABEL, TIMESTAMP_COLUMN_NAME};
 use crate::prometheus_handler::PrometheusHandlerRef;
 
 #[derive(Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -64,6 +73,34 @@ pub struct PromData {
     pub result: Vec<PromSeries>,
 }
 
+/// Exemplar of a series, in the format of `/api/v1/query_exemplars`.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PromExemplar {
+    pub labels: HashMap<String, String>,
+    pub value: String,
+    /// Timestamp in seconds
+    pub timestamp: f64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PromExemplars {
+    #[serde(rename = "seriesLabels")]
+    pub series_labels: HashMap<String, String>,
+    pub exemplars: Vec<PromExemplar>,
+}
+
+/// Build information in the format of `/api/v1/status/buildinfo`.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PromBuildInfo {
+    pub version: String,
+    pub revision: String,
+    pub branch: String,
+    #[serde(rename = "buildDate")]
+    pub build_date: String,
+    #[serde(rename = "rustcVersion")]
+    pub rustc_version: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(untagged)]
 pub enum PrometheusResponse {
@@ -71,6 +108,10 @@ pub enum PrometheusResponse {
     Labels(Vec<String>),
     Series(Vec<HashMap<String, String>>),
     LabelValues(Vec<String>),
+    Metadata(HashMap<String, Vec<MetricMetadata>>),
+    Exemplars(Vec<PromExemplars>),
+    FormatQuery(String),
+    BuildInfo(PromBuildInfo),
 }
 
 impl Default for PrometheusResponse {
@@ -842,3 +883,390 @@ pub async fn series_query(
     }
     PrometheusJsonResponse::success(PrometheusResponse::Series(series))
 }
+
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct MetadataQuery {
+    metric: Option<String>,
+    limit: Option<usize>,
+    db: Option<String>,
+}
+
+/// Handler of `/api/v1/metadata`.
+///
+/// Metadata is derived from tables in the database. If the table is written by Prometheus
+/// protocols, its comment carries the original metadata. Otherwise the type is inferred from
+/// the table name and schema, and the whole comment is used as help text.
+#[axum_macros::debug_handler]
+pub async fn metadata_query(
+    State(handler): State<PrometheusHandlerRef>,
+    Query(params): Query<MetadataQuery>,
+) -> Json<PrometheusJsonResponse> {
+    let _timer = timer!(crate::metrics::METRIC_HTTP_PROMQL_METADATA_QUERY_ELAPSED);
+
+    let db = &params.db.unwrap_or(DEFAULT_SCHEMA_NAME.to_string());
+    let (catalog, schema) = parse_catalog_and_schema_from_db_string(db);
+
+    match retrieve_metric_metadata(
+        catalog,
+        schema,
+        params.metric.as_deref(),
+        params.limit,
+        &handler.catalog_manager(),
+    )
+    .await
+    {
+        Ok(metadata) => PrometheusJsonResponse::success(PrometheusResponse::Metadata(metadata)),
+        Err(e) => PrometheusJsonResponse::error(e.status_code().to_string(), e.to_string()),
+    }
+}
+
+async fn retrieve_metric_metadata(
+    catalog: &str,
+    schema: &str,
+    metric: Option<&str>,
+    limit: Option<usize>,
+    manager: &CatalogManagerRef,
+) -> std::result::Result<HashMap<String, Vec<MetricMetadata>>, catalog::error::Error> {
+    let mut table_names = match metric {
+        Some(metric) => vec![metric.to_string()],
+        None => manager.table_names(catalog, schema).await?,
+    };
+    table_names.sort_unstable();
+
+    let mut result = HashMap::new();
+    for table_name in table_names {
+        if limit.is_some_and(|limit| result.len() >= limit) {
+            break;
+        }
+        // exemplars are not metrics
+        if table_name.ends_with(EXEMPLAR_TABLE_SUFFIX) {
+            continue;
+        }
+        let Some(table) = manager.table(catalog, schema, &table_name).await? else {
+            continue;
+        };
+        // only tables with time index can be queried as metrics
+        let schema = table.schema();
+        if schema.timestamp_column().is_none() {
+            continue;
+        }
+
+        let table_info = table.table_info();
+        let metadata =
+            MetricMetadata::from_table_options(&table_info.meta.options).unwrap_or_else(|| {
+                let tags = schema
+                    .column_schemas()
+                    .iter()
+                    .map(|column| column.name.as_str())
+                    .collect::<Vec<_>>();
+                MetricMetadata {
+                    metric_type: infer_metric_type(&table_name, &tags).to_string(),
+                    help: table_info.desc.clone().unwrap_or_default(),
+                    unit: String::new(),
+                }
+            });
+        let _ = result.insert(table_name, vec![metadata]);
+    }
+
+    Ok(result)
+}
+
+/// Infer Prometheus metric type from the naming convention of metric and its labels.
+fn infer_metric_type(metric: &str, labels: &[&str]) -> &'static str {
+    if metric.ends_with("_bucket") && labels.contains(&"le") {
+        "histogram"
+    } else if labels.contains(&"quantile") {
+        "summary"
+    } else if metric.ends_with("_total") {
+        "counter"
+    } else {
+        "unknown"
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ExemplarsQuery {
+    query: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    db: Option<String>,
+}
+
+/// Handler of `/api/v1/query_exemplars`.
+///
+/// Exemplars of each selector in the query are read from the exemplar table of its metric,
+/// which is written by Prometheus remote write.
+#[axum_macros::debug_handler]
+pub async fn exemplars_query(
+    State(handler): State<PrometheusHandlerRef>,
+    Query(params): Query<ExemplarsQuery>,
+    Extension(query_ctx): Extension<QueryContextRef>,
+    Form(form_params): Form<ExemplarsQuery>,
+) -> Json<PrometheusJsonResponse> {
+    let _timer = timer!(crate::metrics::METRIC_HTTP_PROMQL_EXEMPLARS_QUERY_ELAPSED);
+
+    let query = params.query.or(form_params.query).unwrap_or_default();
+    let start = params
+        .start
+        .or(form_params.start)
+        .unwrap_or_else(yesterday_rfc3339);
+    let end = params
+        .end
+        .or(form_params.end)
+        .unwrap_or_else(current_time_rfc3339);
+
+    match retrieve_exemplars(&handler, &query, &start, &end, query_ctx).await {
+        Ok(exemplars) => PrometheusJsonResponse::success(PrometheusResponse::Exemplars(exemplars)),
+        Err(err) => PrometheusJsonResponse::error(err.status_code().to_string(), err.to_string()),
+    }
+}
+
+async fn retrieve_exemplars(
+    handler: &PrometheusHandlerRef,
+    query: &str,
+    start: &str,
+    end: &str,
+    query_ctx: QueryContextRef,
+) -> Result<Vec<PromExemplars>> {
+    let expr = promql_parser::parser::parse(query)
+        .map_err(|reason| InvalidQuerySnafu { reason }.build())?;
+    let start_timestamp_ms = parse_timestamp_millis(start)?;
+    let end_timestamp_ms = parse_timestamp_millis(end)?;
+
+    let mut selectors = Vec::new();
+    collect_vector_selectors(&expr, &mut selectors);
+
+    let mut exemplars = Vec::new();
+    for selector in selectors {
+        let Some(metric) = selector
+            .name
+            .clone()
+            .or(selector.matchers.find_matcher(METRIC_NAME))
+        else {
+            continue;
+        };
+        let remote_query = RemoteQuery {
+            start_timestamp_ms,
+            end_timestamp_ms,
+            matchers: to_remote_matchers(&metric, &selector.matchers),
+            hints: None,
+        };
+        let output = match handler
+            .query_exemplars(&remote_query, query_ctx.clone())
+            .await
+        {
+            Ok(output) => output,
+            // The metric doesn't have any exemplar.
+            Err(err) if err.status_code() == StatusCode::TableNotFound => continue,
+            Err(err) => return Err(err),
+        };
+        let batches = match output {
+            Output::RecordBatches(batches) => batches,
+            Output::Stream(stream) => RecordBatches::try_collect(stream)
+                .await
+                .context(CollectRecordbatchSnafu)?,
+            Output::AffectedRows(_) => {
+                return UnexpectedResultSnafu {
+                    reason: "expected data result, but got affected rows".to_string(),
+                }
+                .fail()
+            }
+        };
+
+        let table = handler
+            .catalog_manager()
+            .table(
+                query_ctx.current_catalog(),
+                query_ctx.current_schema(),
+                &exemplar_table_name(&metric),
+            )
+            .await
+            .context(CatalogSnafu)?;
+        let Some(table) = table else {
+            continue;
+        };
+        let tags = table
+            .table_info()
+            .meta
+            .row_key_column_names()
+            .cloned()
+            .collect::<HashSet<_>>();
+        exemplars.extend(record_batches_to_exemplars(&metric, &tags, batches)?);
+    }
+
+    Ok(exemplars)
+}
+
+/// Parses timestamp of the HTTP API into milliseconds.
+fn parse_timestamp_millis(timestamp: &str) -> Result<i64> {
+    let time = QueryLanguageParser::parse_promql_timestamp(timestamp).map_err(|e| {
+        InvalidQuerySnafu {
+            reason: e.to_string(),
+        }
+        .build()
+    })?;
+    // RFC3339 timestamps can be earlier than the epoch.
+    let millis = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_millis() as i64,
+        Err(e) => -(e.duration().as_millis() as i64),
+    };
+    Ok(millis)
+}
+
+/// Collects all vector selectors in the `expr`, including the ones in matrix selectors.
+fn collect_vector_selectors<'a>(expr: &'a PromqlExpr, selectors: &mut Vec<&'a VectorSelector>) {
+    match expr {
+        PromqlExpr::Aggregate(AggregateExpr { expr, .. })
+        | PromqlExpr::Unary(UnaryExpr { expr })
+        | PromqlExpr::Paren(ParenExpr { expr })
+        | PromqlExpr::Subquery(SubqueryExpr { expr, .. }) => {
+            collect_vector_selectors(expr, selectors)
+        }
+        PromqlExpr::Binary(BinaryExpr { lhs, rhs, .. }) => {
+            collect_vector_selectors(lhs, selectors);
+            collect_vector_selectors(rhs, selectors);
+        }
+        PromqlExpr::Call(Call { args, .. }) => {
+            for arg in &args.args {
+                collect_vector_selectors(arg, selectors);
+            }
+        }
+        PromqlExpr::VectorSelector(vs) => selectors.push(vs),
+        PromqlExpr::MatrixSelector(MatrixSelector { vs, .. }) => selectors.push(vs),
+        PromqlExpr::NumberLiteral(_) | PromqlExpr::StringLiteral(_) | PromqlExpr::Extension(_) => {}
+    }
+}
+
+/// Converts the matchers of a selector to remote read matchers of the `metric`.
+fn to_remote_matchers(metric: &str, matchers: &Matchers) -> Vec<LabelMatcher> {
+    let mut result = vec![LabelMatcher {
+        r#type: MatcherType::Eq as i32,
+        name: METRIC_NAME_LABEL.to_string(),
+        value: metric.to_string(),
+    }];
+    for matcher in &matchers.matchers {
+        if matcher.name == METRIC_NAME {
+            continue;
+        }
+        let matcher_type = match matcher.op {
+            MatchOp::Equal => MatcherType::Eq,
+            MatchOp::NotEqual => MatcherType::Neq,
+            MatchOp::Re(_) => MatcherType::Re,
+            MatchOp::NotRe(_) => MatcherType::Nre,
+        };
+        result.push(LabelMatcher {
+            r#type: matcher_type as i32,
+            name: matcher.name.clone(),
+            value: matcher.value.clone(),
+        });
+    }
+    result
+}
+
+/// Groups rows of the exemplar table by series. Columns in `tags` are labels of the series,
+/// other string columns are labels of the exemplars.
+fn record_batches_to_exemplars(
+    metric: &str,
+    tags: &HashSet<String>,
+    batches: RecordBatches,
+) -> Result<Vec<PromExemplars>> {
+    let mut series: BTreeMap<Vec<(String, String)>, Vec<PromExemplar>> = BTreeMap::new();
+    for batch in batches.iter() {
+        for row in batch.rows() {
+            let mut series_labels = vec![(METRIC_NAME_LABEL.to_string(), metric.to_string())];
+            let mut exemplar = PromExemplar::default();
+            for (idx, value) in row.into_iter().enumerate() {
+                let column_name = batch.schema.column_name_by_index(idx);
+                match value {
+                    Value::String(value) if tags.contains(column_name) => {
+                        series_labels.push((column_name.to_string(), value.as_utf8().to_string()))
+                    }
+                    Value::String(value) => {
+                        let _ = exemplar
+                            .labels
+                            .insert(column_name.to_string(), value.as_utf8().to_string());
+                    }
+                    Value::Float64(value) if column_name == FIELD_COLUMN_NAME => {
+                        exemplar.value = value.to_string();
+                    }
+                    Value::Timestamp(ts) if column_name == TIMESTAMP_COLUMN_NAME => {
+                        let ts = ts.convert_to(TimeUnit::Millisecond).with_context(|| {
+                            UnexpectedResultSnafu {
+                                reason: format!("invalid exemplar timestamp: {ts:?}"),
+                            }
+                        })?;
+                        exemplar.timestamp = ts.value() as f64 / 1000.0;
+                    }
+                    _ => {}
+                }
+            }
+            series.entry(series_labels).or_default().push(exemplar);
+        }
+    }
+
+    Ok(series
+        .into_iter()
+        .map(|(labels, exemplars)| PromExemplars {
+            series_labels: labels.into_iter().collect(),
+            exemplars,
+        })
+        .collect())
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FormatQuery {
+    query: Option<String>,
+}
+
+/// Handler of `/api/v1/format_query`.
+#[axum_macros::debug_handler]
+pub async fn format_query(
+    Query(params): Query<FormatQuery>,
+    Form(form_params): Form<FormatQuery>,
+) -> Json<PrometheusJsonResponse> {
+    let query = params.query.or(form_params.query).unwrap_or_default();
+    match promql_parser::parser::parse(&query) {
+        Ok(expr) => {
+            PrometheusJsonResponse::success(PrometheusResponse::FormatQuery(expr.to_string()))
+        }
+        Err(reason) => {
+            let err = InvalidQuerySnafu { reason }.build();
+            PrometheusJsonResponse::error(err.status_code().to_string(), err.to_string())
+        }
+    }
+}
+
+/// Handler of `/api/v1/status/buildinfo`.
+#[axum_macros::debug_handler]
+pub async fn build_info_query() -> Json<PrometheusJsonResponse> {
+    let build_info = common_version::build_info();
+    PrometheusJsonResponse::success(PrometheusResponse::BuildInfo(PromBuildInfo {
+        version: build_info.version.to_string(),
+        revision: build_info.commit.to_string(),
+        branch: build_info.branch.to_string(),
+        build_date: build_info.source_time.to_string(),
+        rustc_version: build_info.rustc.to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp_millis() {
+        let cases = [
+            ("0", 0),
+            ("1.5", 1500),
+            ("1970-01-01T00:00:01Z", 1000),
+            ("1969-12-31T23:59:59Z", -1000),
+            ("1969-12-31T23:59:59.500Z", -500),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(expected, parse_timestamp_millis(input).unwrap(), "{input}");
+        }
+
+        let err = parse_timestamp_millis("not a timestamp").unwrap_err();
+        assert_eq!(StatusCode::InvalidArguments, err.status_code());
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::borrow::Cow;
 use std::sync::Arc;
+use std::time::Duration;
 
 use api::v1::greptime_request::Request;
 use common_error::ext::ErrorExt;
@@ -68,6 +69,20 @@ pub trait SqlQueryInterceptor {
     ) -> Result<Output, Self::Error> {
         Ok(output)
     }
+
+    /// Called when a statement in the query string finished, no matter it
+    /// succeeded or failed. `statement` is `None` if the query failed before
+    /// it's parsed into statements. The result can't be changed here, this hook
+    /// is meant for tracking queries, e.g. auditing.
+    fn on_finish(
+        &self,
+        _query: &str,
+        _statement: Option<&Statement>,
+        _result: Result<&Output, &Self::Error>,
+        _elapsed: Duration,
+        _query_ctx: QueryContextRef,
+    ) {
+    }
 }
 
 pub type SqlQueryInterceptorRef<E> =
@@ -127,6 +142,19 @@ where
             Ok(output)
         }
     }
+
+    fn on_finish(
+        &self,
+        query: &str,
+        statement: Option<&Statement>,
+        result: Result<&Output, &Self::Error>,
+        elapsed: Duration,
+        query_ctx: QueryContextRef,
+    ) {
+        if let Some(this) = self {
+            this.on_finish(query, statement, result, elapsed, query_ctx)
+        }
+    }
 }
 
 /// GrpcQueryInterceptor can track life cycle of a grpc request and customize or
@@ -152,6 +180,18 @@ pub trait GrpcQueryInterceptor {
     ) -> Result<Output, Self::Error> {
         Ok(output)
     }
+
+    /// Called when the request finished, no matter it succeeded or failed.
+    /// The request itself is consumed by execution, so only its description,
+    /// e.g. `row_inserts: monitor, cpu`, is provided.
+    fn on_finish(
+        &self,
+        _request_desc: &str,
+        _result: Result<&Output, &Self::Error>,
+        _elapsed: Duration,
+        _query_ctx: QueryContextRef,
+    ) {
+    }
 }
 
 pub type GrpcQueryInterceptorRef<E> =
@@ -186,6 +226,18 @@ where
             Ok(output)
         }
     }
+
+    fn on_finish(
+        &self,
+        request_desc: &str,
+        result: Result<&Output, &Self::Error>,
+        elapsed: Duration,
+        query_ctx: QueryContextRef,
+    ) {
+        if let Some(this) = self {
+            this.on_finish(request_desc, result, elapsed, query_ctx)
+        }
+    }
 }
 
 /// PromQueryInterceptor can track life cycle of a prometheus request and customize or
@@ -211,6 +263,16 @@ pub trait PromQueryInterceptor {
     ) -> Result<Output, Self::Error> {
         Ok(output)
     }
+
+    /// Called when the query finished, no matter it succeeded or failed.
+    fn on_finish(
+        &self,
+        _query: &PromQuery,
+        _result: Result<&Output, &Self::Error>,
+        _elapsed: Duration,
+        _query_ctx: QueryContextRef,
+    ) {
+    }
 }
 
 pub type PromQueryInterceptorRef<E> =
@@ -245,4 +307,16 @@ where
             Ok(output)
         }
     }
+
+    fn on_finish(
+        &self,
+        query: &PromQuery,
+        result: Result<&Output, &Self::Error>,
+        elapsed: Duration,
+        query_ctx: QueryContextRef,
+    ) {
+        if let Some(this) = self {
+            this.on_finish(query, result, elapsed, query_ctx)
+        }
+    }
 }
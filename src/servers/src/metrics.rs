@@ -50,6 +50,10 @@ pub(crate) const METRIC_HTTP_PROMQL_SERIES_QUERY_ELAPSED: &str =
     "servers.http_promql_series_query_elapsed";
 pub(crate) const METRIC_HTTP_PROMQL_LABEL_VALUE_QUERY_ELAPSED: &str =
     "servers.http_promql_label_value_query_elapsed";
+pub(crate) const METRIC_HTTP_PROMQL_METADATA_QUERY_ELAPSED: &str =
+    "servers.http_promql_metadata_query_elapsed";
+pub(crate) const METRIC_HTTP_PROMQL_EXEMPLARS_QUERY_ELAPSED: &str =
+    "servers.http_promql_exemplars_query_elapsed";
 
 pub(crate) const METRIC_MYSQL_CONNECTIONS: &str = "servers.mysql_connection_count";
 pub(crate) const METRIC_MYSQL_QUERY_TIMER: &str = "servers.mysql_query_elapsed";
@@ -17,6 +17,7 @@ use std::time::Duration;
 use chrono::{NaiveDate, NaiveDateTime};
 use common_query::prelude::ScalarValue;
 use datatypes::prelude::ConcreteDataType;
+use datatypes::types::parse_string_to_jsonb;
 use datatypes::value::{self, Value};
 use itertools::Itertools;
 use opensrv_mysql::{ParamValue, ValueInner};
@@ -54,7 +55,7 @@ pub fn replace_placeholders(query: &str) -> (String, usize) {
 }
 
 /// Transform all the "?" placeholder into "$i".
-/// Only works for Insert,Query and Delete statements.
+/// Only works for Insert, Query, Delete and Update statements.
 pub fn transform_placeholders(stmt: Statement) -> Statement {
     match stmt {
         Statement::Query(mut query) => {
@@ -69,6 +70,10 @@ pub fn transform_placeholders(stmt: Statement) -> Statement {
             visit_placeholders(&mut delete.inner);
             Statement::Delete(delete)
         }
+        Statement::Update(mut update) => {
+            visit_placeholders(&mut update.inner);
+            Statement::Update(update)
+        }
         stmt => stmt,
     }
 }
@@ -159,6 +164,11 @@ pub fn convert_value(param: &ParamValue, t: &ConcreteDataType) -> Result<ScalarV
                 String::from_utf8_lossy(b).to_string(),
             ))),
             ConcreteDataType::Binary(_) => Ok(ScalarValue::LargeBinary(Some(b.to_vec()))),
+            ConcreteDataType::Json(_) => {
+                let json = parse_string_to_jsonb(&String::from_utf8_lossy(b))
+                    .context(error::ConvertScalarValueSnafu)?;
+                Ok(ScalarValue::LargeBinary(Some(json)))
+            }
 
             _ => error::PreparedStmtTypeMismatchSnafu {
                 expected: t,
@@ -235,6 +245,15 @@ mod tests {
             delete.inner.to_string()
         );
 
+        let update = parse_sql("update demo set cpu=? where host=?");
+        let Statement::Update(update) = transform_placeholders(update) else {
+            unreachable!()
+        };
+        assert_eq!(
+            "UPDATE demo SET cpu = $1 WHERE host = $2",
+            update.inner.to_string()
+        );
+
         let select = parse_sql("select from demo where host=? and idc in (select idc from idcs where name=?) and cpu>?");
         let Statement::Query(select) = transform_placeholders(select) else {
             unreachable!()
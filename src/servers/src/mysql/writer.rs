@@ -19,6 +19,7 @@ use common_query::Output;
 use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
 use datatypes::prelude::{ConcreteDataType, Value};
 use datatypes::schema::SchemaRef;
+use datatypes::types::jsonb_to_string;
 use futures::StreamExt;
 use metrics::increment_counter;
 use opensrv_mysql::{
@@ -164,8 +165,9 @@ impl<'a, W: AsyncWrite + Unpin> MysqlResultWriter<'a, W> {
         recordbatch: &RecordBatch,
         query_context: QueryContextRef,
     ) -> Result<()> {
+        let column_schemas = recordbatch.schema.column_schemas();
         for row in recordbatch.rows() {
-            for value in row.into_iter() {
+            for (value, column) in row.into_iter().zip(column_schemas) {
                 match value {
                     Value::Null => row_writer.write_col(None::<u8>)?,
                     Value::Boolean(v) => row_writer.write_col(v as i8)?,
@@ -180,6 +182,9 @@ impl<'a, W: AsyncWrite + Unpin> MysqlResultWriter<'a, W> {
                     Value::Float32(v) => row_writer.write_col(v.0)?,
                     Value::Float64(v) => row_writer.write_col(v.0)?,
                     Value::String(v) => row_writer.write_col(v.as_utf8())?,
+                    Value::Binary(v) if column.data_type.is_json() => {
+                        row_writer.write_col(jsonb_to_string(&v))?
+                    }
                     Value::Binary(v) => row_writer.write_col(v.deref())?,
                     Value::Date(v) => row_writer.write_col(v.to_chrono_date())?,
                     Value::DateTime(v) => row_writer.write_col(v.to_chrono_datetime())?,
@@ -187,6 +192,7 @@ impl<'a, W: AsyncWrite + Unpin> MysqlResultWriter<'a, W> {
                         .write_col(v.to_timezone_aware_string(query_context.time_zone()))?,
                     Value::Interval(v) => row_writer.write_col(v.to_iso8601_string())?,
                     Value::Duration(v) => row_writer.write_col(v.to_std_duration())?,
+                    Value::Decimal128(v) => row_writer.write_col(v.to_string())?,
                     Value::List(_) => {
                         return Err(Error::Internal {
                             err_msg: format!(
@@ -243,6 +249,8 @@ pub(crate) fn create_mysql_column(
         ConcreteDataType::DateTime(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
         ConcreteDataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
         ConcreteDataType::Duration(_) => Ok(ColumnType::MYSQL_TYPE_TIME),
+        ConcreteDataType::Decimal128(_) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
+        ConcreteDataType::Json(_) => Ok(ColumnType::MYSQL_TYPE_JSON),
         _ => error::InternalSnafu {
             err_msg: format!("not implemented for column datatype {:?}", data_type),
         }
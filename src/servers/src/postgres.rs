@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod auth_handler;
+mod cursor;
 mod handler;
 mod server;
 mod types;
@@ -38,6 +39,7 @@ use session::context::Channel;
 use session::Session;
 
 use self::auth_handler::PgLoginVerifier;
+use self::cursor::Cursors;
 use self::handler::DefaultQueryParser;
 use crate::query_handler::sql::ServerSqlQueryHandlerRef;
 use crate::SqlPlan;
@@ -78,6 +80,7 @@ pub struct PostgresServerHandler {
     session: Arc<Session>,
     portal_store: Arc<MemPortalStore<SqlPlan>>,
     query_parser: Arc<DefaultQueryParser>,
+    cursors: Cursors,
 }
 
 #[derive(Builder)]
@@ -101,6 +104,7 @@ impl MakePostgresServerHandler {
             session: session.clone(),
             portal_store: Arc::new(MemPortalStore::new()),
             query_parser: Arc::new(DefaultQueryParser::new(self.query_handler.clone(), session)),
+            cursors: Cursors::default(),
         }
     }
 }
@@ -0,0 +1,428 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cursors of a PostgreSQL connection, which let clients pull the rows of a query
+//! in batches instead of receiving them all at once.
+//!
+//! A cursor is either a portal executed with a row limit, or declared by
+//! `DECLARE ... CURSOR FOR ...` and read by `FETCH`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_query::Output;
+use common_recordbatch::error::Result as RecordBatchResult;
+use common_recordbatch::{
+    EmptyRecordBatchStream, RecordBatch, RecordBatchStreamAdaptor, RecordBatches,
+    SendableRecordBatchStream,
+};
+use datatypes::schema::SchemaRef;
+use futures::{stream, StreamExt, TryStreamExt};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use pgwire::api::portal::Portal;
+use regex::Regex;
+use snafu::{OptionExt, ResultExt};
+
+use crate::error::{CollectRecordbatchSnafu, CursorNotFoundSnafu, Result};
+use crate::SqlPlan;
+
+static DECLARE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?is)^\s*DECLARE\s+(\w+)\s+(?:(?:INSENSITIVE|ASENSITIVE|NO\s+SCROLL)\s+)*CURSOR\s+(?:(WITH|WITHOUT)\s+HOLD\s+)?FOR\s+(.+?)[\s;]*$",
+    )
+    .unwrap()
+});
+static FETCH_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\s*FETCH\s+(?:(NEXT|ALL|FORWARD\s+ALL|FORWARD\s+\d+|FORWARD|\d+)\s+)?(?:(?:FROM|IN)\s+)?(\w+)[\s;]*$",
+    )
+    .unwrap()
+});
+static CLOSE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^\s*CLOSE\s+(\w+)[\s;]*$").unwrap());
+static BEGIN_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*(?:BEGIN|START\s+TRANSACTION)(?:\s+(?:WORK|TRANSACTION))?[\s;]*$").unwrap()
+});
+static COMMIT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*(?:COMMIT|END|ROLLBACK|ABORT)(?:\s+(?:WORK|TRANSACTION))?[\s;]*$").unwrap()
+});
+
+/// Statements handled by the PostgreSQL server itself, since the cursors they
+/// work on belong to the connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CursorStatement {
+    /// `DECLARE name CURSOR [WITH HOLD] FOR query`
+    Declare {
+        name: String,
+        hold: bool,
+        query: String,
+    },
+    /// `FETCH [count | ALL] FROM name`, `count` is `None` for `ALL`.
+    Fetch { name: String, count: Option<usize> },
+    /// `CLOSE name`, `name` is `None` for `CLOSE ALL`.
+    Close { name: Option<String> },
+    /// `BEGIN`. There are no transactions in GreptimeDB, it's accepted so that
+    /// clients can declare cursors in a transaction block.
+    Begin,
+    /// `COMMIT` or `ROLLBACK`, which close the cursors that are not declared
+    /// `WITH HOLD`.
+    Commit,
+}
+
+impl CursorStatement {
+    /// Parses `query` into a [CursorStatement], returns `None` if it's not one.
+    pub(crate) fn parse(query: &str) -> Option<Self> {
+        if let Some(captures) = DECLARE_PATTERN.captures(query) {
+            return Some(CursorStatement::Declare {
+                name: captures[1].to_lowercase(),
+                hold: captures
+                    .get(2)
+                    .map(|m| m.as_str().eq_ignore_ascii_case("WITH"))
+                    .unwrap_or(false),
+                query: captures[3].to_string(),
+            });
+        }
+
+        if let Some(captures) = FETCH_PATTERN.captures(query) {
+            let count = match captures.get(1).map(|m| m.as_str().to_uppercase()) {
+                None => Some(1),
+                Some(direction) => match direction.split_whitespace().last() {
+                    Some("NEXT") | Some("FORWARD") => Some(1),
+                    Some("ALL") => None,
+                    Some(count) => Some(count.parse().ok()?),
+                    None => Some(1),
+                },
+            };
+            return Some(CursorStatement::Fetch {
+                name: captures[2].to_lowercase(),
+                count,
+            });
+        }
+
+        if let Some(captures) = CLOSE_PATTERN.captures(query) {
+            let name = captures[1].to_lowercase();
+            return Some(CursorStatement::Close {
+                name: (name != "all").then_some(name),
+            });
+        }
+
+        if BEGIN_PATTERN.is_match(query) {
+            return Some(CursorStatement::Begin);
+        }
+
+        if COMMIT_PATTERN.is_match(query) {
+            return Some(CursorStatement::Commit);
+        }
+
+        None
+    }
+}
+
+/// The rows of a query that are not sent to the client yet.
+pub(crate) struct Cursor {
+    stream: SendableRecordBatchStream,
+    /// Rows of the last polled batch that are not fetched yet.
+    pending: Option<RecordBatch>,
+    /// Whether the cursor outlives the transaction, see `DECLARE ... WITH HOLD`.
+    hold: bool,
+}
+
+impl Cursor {
+    pub(crate) fn new(stream: SendableRecordBatchStream, hold: bool) -> Self {
+        Self {
+            stream,
+            pending: None,
+            hold,
+        }
+    }
+
+    pub(crate) fn schema(&self) -> SchemaRef {
+        self.stream.schema()
+    }
+
+    /// Returns the next batch of rows, which has at most `max_rows` rows unless
+    /// `max_rows` is 0. Returns `None` if all the rows are fetched.
+    pub(crate) async fn next_batch(
+        &mut self,
+        max_rows: usize,
+    ) -> RecordBatchResult<Option<RecordBatch>> {
+        let batch = match self.pending.take() {
+            Some(batch) => batch,
+            None => match self.stream.try_next().await? {
+                Some(batch) => batch,
+                None => return Ok(None),
+            },
+        };
+        if max_rows == 0 || batch.num_rows() <= max_rows {
+            return Ok(Some(batch));
+        }
+
+        let df_batch = batch.df_record_batch();
+        let rest = df_batch.slice(max_rows, df_batch.num_rows() - max_rows);
+        self.pending = Some(RecordBatch::try_from_df_record_batch(
+            batch.schema.clone(),
+            rest,
+        )?);
+        RecordBatch::try_from_df_record_batch(batch.schema.clone(), df_batch.slice(0, max_rows))
+            .map(Some)
+    }
+
+    /// Fetches at most `count` rows.
+    async fn fetch(&mut self, count: usize) -> RecordBatchResult<RecordBatches> {
+        let mut batches = Vec::new();
+        let mut remaining = count;
+        while remaining > 0 {
+            let Some(batch) = self.next_batch(remaining).await? else {
+                break;
+            };
+            remaining -= batch.num_rows();
+            batches.push(batch);
+        }
+        RecordBatches::try_new(self.schema(), batches)
+    }
+
+    /// Takes all the remaining rows as a stream, leaving the cursor exhausted.
+    fn take_remaining(&mut self) -> SendableRecordBatchStream {
+        let schema = self.schema();
+        let stream = std::mem::replace(
+            &mut self.stream,
+            Box::pin(EmptyRecordBatchStream::new(schema.clone())),
+        );
+        let pending = stream::iter(self.pending.take().map(Ok));
+        Box::pin(RecordBatchStreamAdaptor::new(schema, pending.chain(stream)))
+    }
+}
+
+/// State of a portal executed before.
+pub(crate) enum PortalState {
+    /// A previous execute stopped at the row limit, the cursor has the remaining rows.
+    Suspended(Cursor),
+    /// All the rows have been returned, executing it again returns no rows.
+    Completed,
+}
+
+/// All the cursors of a PostgreSQL connection.
+#[derive(Default)]
+pub(crate) struct Cursors {
+    /// States of the executed portals, by portal name. The portal is kept to tell
+    /// whether the name is bound to another portal since.
+    portals: Mutex<HashMap<String, (Arc<Portal<SqlPlan>>, PortalState)>>,
+    /// Cursors created by `DECLARE`, by cursor name.
+    declared: Mutex<HashMap<String, Cursor>>,
+}
+
+impl Cursors {
+    /// Keeps the remaining rows of `portal` for the next execute of it.
+    pub(crate) fn suspend_portal(&self, name: &str, portal: Arc<Portal<SqlPlan>>, cursor: Cursor) {
+        let _ = self
+            .portals
+            .lock()
+            .insert(name.to_string(), (portal, PortalState::Suspended(cursor)));
+    }
+
+    /// Marks `portal` as completed, the rows of it are released.
+    pub(crate) fn complete_portal(&self, name: &str, portal: Arc<Portal<SqlPlan>>) {
+        let _ = self
+            .portals
+            .lock()
+            .insert(name.to_string(), (portal, PortalState::Completed));
+    }
+
+    /// Takes the state of `portal` if it has been executed before.
+    pub(crate) fn resume_portal(
+        &self,
+        name: &str,
+        portal: &Arc<Portal<SqlPlan>>,
+    ) -> Option<PortalState> {
+        let (executed, state) = self.portals.lock().remove(name)?;
+        Arc::ptr_eq(&executed, portal).then_some(state)
+    }
+
+    /// Forgets the portal `name` once the client closes it.
+    pub(crate) fn close_portal(&self, name: &str) {
+        let _ = self.portals.lock().remove(name);
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.declared.lock().contains_key(name)
+    }
+
+    pub(crate) fn declare(&self, name: String, cursor: Cursor) {
+        let _ = self.declared.lock().insert(name, cursor);
+    }
+
+    /// Returns the schema of the rows of the cursor `name`.
+    pub(crate) fn schema(&self, name: &str) -> Option<SchemaRef> {
+        self.declared.lock().get(name).map(Cursor::schema)
+    }
+
+    /// Fetches at most `count` rows from the cursor `name`, or all the remaining
+    /// rows if `count` is `None`.
+    pub(crate) async fn fetch(&self, name: &str, count: Option<usize>) -> Result<Output> {
+        let mut cursor = self
+            .declared
+            .lock()
+            .remove(name)
+            .context(CursorNotFoundSnafu { name })?;
+        let output = match count {
+            Some(count) => cursor
+                .fetch(count)
+                .await
+                .map(Output::RecordBatches)
+                .context(CollectRecordbatchSnafu),
+            None => Ok(Output::Stream(cursor.take_remaining())),
+        };
+        self.declare(name.to_string(), cursor);
+        output
+    }
+
+    /// Closes the cursor `name`, returns false if there is no such cursor.
+    pub(crate) fn close(&self, name: &str) -> bool {
+        self.declared.lock().remove(name).is_some()
+    }
+
+    pub(crate) fn close_all(&self) {
+        self.declared.lock().clear();
+    }
+
+    /// Closes the portals and the cursors that don't outlive the transaction.
+    pub(crate) fn end_transaction(&self) {
+        self.portals.lock().clear();
+        self.declared.lock().retain(|_, cursor| cursor.hold);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::{ColumnSchema, Schema};
+    use datatypes::vectors::UInt32Vector;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_cursor_statement() {
+        assert_eq!(
+            Some(CursorStatement::Declare {
+                name: "c".to_string(),
+                hold: false,
+                query: "SELECT * FROM numbers".to_string(),
+            }),
+            CursorStatement::parse("DECLARE C CURSOR FOR SELECT * FROM numbers;")
+        );
+        assert_eq!(
+            Some(CursorStatement::Declare {
+                name: "c".to_string(),
+                hold: true,
+                query: "SELECT *\nFROM numbers".to_string(),
+            }),
+            CursorStatement::parse(
+                "declare c no scroll cursor with hold for SELECT *\nFROM numbers"
+            )
+        );
+        assert_eq!(
+            Some(CursorStatement::Fetch {
+                name: "c".to_string(),
+                count: Some(1),
+            }),
+            CursorStatement::parse("FETCH c")
+        );
+        assert_eq!(
+            Some(CursorStatement::Fetch {
+                name: "c".to_string(),
+                count: Some(100),
+            }),
+            CursorStatement::parse("FETCH FORWARD 100 FROM c")
+        );
+        assert_eq!(
+            Some(CursorStatement::Fetch {
+                name: "c".to_string(),
+                count: None,
+            }),
+            CursorStatement::parse("fetch all in c;")
+        );
+        assert_eq!(
+            Some(CursorStatement::Close { name: None }),
+            CursorStatement::parse("CLOSE ALL")
+        );
+        assert_eq!(
+            Some(CursorStatement::Begin),
+            CursorStatement::parse("begin")
+        );
+        assert_eq!(
+            Some(CursorStatement::Commit),
+            CursorStatement::parse("ROLLBACK;")
+        );
+        assert_eq!(None, CursorStatement::parse("SELECT * FROM numbers"));
+        assert_eq!(
+            None,
+            CursorStatement::parse("DECLARE c BINARY CURSOR FOR SELECT 1")
+        );
+    }
+
+    fn numbers_stream(batches: &[&[u32]]) -> SendableRecordBatchStream {
+        let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+            "number",
+            ConcreteDataType::uint32_datatype(),
+            false,
+        )]));
+        let batches = batches
+            .iter()
+            .map(|numbers| {
+                RecordBatch::new(
+                    schema.clone(),
+                    vec![Arc::new(UInt32Vector::from_slice(*numbers)) as _],
+                )
+                .unwrap()
+            })
+            .collect();
+        RecordBatches::try_new(schema, batches).unwrap().as_stream()
+    }
+
+    async fn fetch_numbers(cursors: &Cursors, count: Option<usize>) -> Vec<u32> {
+        let output = cursors.fetch("c", count).await.unwrap();
+        let batches = match output {
+            Output::RecordBatches(batches) => batches,
+            Output::Stream(stream) => RecordBatches::try_collect(stream).await.unwrap(),
+            Output::AffectedRows(_) => unreachable!(),
+        };
+        batches
+            .iter()
+            .flat_map(|batch| batch.rows())
+            .map(|row| match row[0] {
+                datatypes::value::Value::UInt32(v) => v,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_cursor() {
+        let cursors = Cursors::default();
+        cursors.declare(
+            "c".to_string(),
+            Cursor::new(numbers_stream(&[&[1, 2, 3], &[4, 5]]), false),
+        );
+
+        assert_eq!(vec![1, 2], fetch_numbers(&cursors, Some(2)).await);
+        assert_eq!(vec![3, 4], fetch_numbers(&cursors, Some(2)).await);
+        assert_eq!(vec![5], fetch_numbers(&cursors, None).await);
+        assert!(fetch_numbers(&cursors, Some(2)).await.is_empty());
+
+        cursors.end_transaction();
+        assert!(!cursors.contains("c"));
+        assert!(cursors.fetch("c", None).await.is_err());
+    }
+}
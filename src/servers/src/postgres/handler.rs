@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Debug;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -19,24 +20,36 @@ use common_query::Output;
 use common_recordbatch::error::Result as RecordBatchResult;
 use common_recordbatch::RecordBatch;
 use common_telemetry::timer;
-use datatypes::schema::SchemaRef;
-use futures::{future, stream, Stream, StreamExt};
+use datatypes::schema::{Schema, SchemaRef};
+use datatypes::value::Value;
+use futures::{future, stream, Sink, SinkExt, Stream, StreamExt};
 use metrics::increment_counter;
 use pgwire::api::portal::{Format, Portal};
 use pgwire::api::query::{ExtendedQueryHandler, SimpleQueryHandler, StatementOrPortal};
-use pgwire::api::results::{DataRowEncoder, DescribeResponse, QueryResponse, Response, Tag};
+use pgwire::api::results::{
+    DataRowEncoder, DescribeResponse, FieldInfo, QueryResponse, Response, Tag,
+};
 use pgwire::api::stmt::QueryParser;
-use pgwire::api::store::MemPortalStore;
-use pgwire::api::{ClientInfo, Type};
+use pgwire::api::store::{MemPortalStore, PortalStore};
+use pgwire::api::{ClientInfo, Type, DEFAULT_NAME};
 use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
+use pgwire::messages::data::DataRow;
+use pgwire::messages::extendedquery::{
+    Close, CloseComplete, Execute, PortalSuspended, TARGET_TYPE_BYTE_PORTAL,
+    TARGET_TYPE_BYTE_STATEMENT,
+};
+use pgwire::messages::PgWireBackendMessage;
 use query::query_engine::DescribeResult;
+use session::context::QueryContextRef;
 use session::Session;
+use snafu::ensure;
 use sql::dialect::PostgreSqlDialect;
 use sql::parser::ParserContext;
 
+use super::cursor::{Cursor, CursorStatement, PortalState};
 use super::types::*;
 use super::PostgresServerHandler;
-use crate::error::Result;
+use crate::error::{CursorNotFoundSnafu, InvalidQuerySnafu, Result};
 use crate::query_handler::sql::ServerSqlQueryHandlerRef;
 use crate::SqlPlan;
 
@@ -57,7 +70,7 @@ impl SimpleQueryHandler for PostgresServerHandler {
                 (crate::metrics::METRIC_DB_LABEL, query_ctx.get_db_string())
             ]
         );
-        let outputs = self.query_handler.do_query(query, query_ctx).await;
+        let outputs = self.do_sql_query(query, query_ctx).await;
 
         let mut results = Vec::with_capacity(outputs.len());
 
@@ -87,14 +100,14 @@ fn output_to_query_response<'a>(
             let schema = recordbatches.schema();
             recordbatches_to_query_response(recordbatches.as_stream(), schema, field_format)
         }
-        Err(e) => Ok(Response::Error(Box::new(ErrorInfo::new(
-            "ERROR".to_string(),
-            "XX000".to_string(),
-            e.to_string(),
-        )))),
+        Err(e) => Ok(Response::Error(Box::new(output_error(e)))),
     }
 }
 
+fn output_error(e: crate::error::Error) -> ErrorInfo {
+    ErrorInfo::new("ERROR".to_string(), "XX000".to_string(), e.to_string())
+}
+
 fn recordbatches_to_query_response<'a, S>(
     recordbatches_stream: S,
     schema: SchemaRef,
@@ -108,6 +121,7 @@ where
             .map_err(|e| PgWireError::ApiError(Box::new(e)))?,
     );
     let pg_schema_ref = pg_schema.clone();
+    let schema_ref = schema.clone();
     let data_row_stream = recordbatches_stream
         .map(|record_batch_result| match record_batch_result {
             Ok(rb) => stream::iter(
@@ -119,15 +133,7 @@ where
             Err(e) => stream::once(future::err(PgWireError::ApiError(Box::new(e)))).boxed(),
         })
         .flatten() // flatten into stream<result<row>>
-        .map(move |row| {
-            row.and_then(|row| {
-                let mut encoder = DataRowEncoder::new(pg_schema_ref.clone());
-                for value in row.iter() {
-                    encode_value(value, &mut encoder)?;
-                }
-                encoder.finish()
-            })
-        });
+        .map(move |row| row.and_then(|row| encode_row(&row, &schema_ref, pg_schema_ref.clone())));
 
     Ok(Response::Query(QueryResponse::new(
         pg_schema,
@@ -135,6 +141,110 @@ where
     )))
 }
 
+fn encode_row(
+    row: &[Value],
+    schema: &Schema,
+    pg_schema: Arc<Vec<FieldInfo>>,
+) -> PgWireResult<DataRow> {
+    let mut encoder = DataRowEncoder::new(pg_schema);
+    for (value, column) in row.iter().zip(schema.column_schemas()) {
+        encode_value(value, &column.data_type, &mut encoder)?;
+    }
+    encoder.finish()
+}
+
+impl PostgresServerHandler {
+    /// Executes `query`, the cursor statements are executed by the server itself
+    /// since cursors belong to the connection.
+    async fn do_sql_query(&self, query: &str, query_ctx: QueryContextRef) -> Vec<Result<Output>> {
+        match CursorStatement::parse(query) {
+            Some(stmt) => vec![self.do_cursor_statement(stmt, query_ctx).await],
+            None => self.query_handler.do_query(query, query_ctx).await,
+        }
+    }
+
+    async fn do_cursor_statement(
+        &self,
+        stmt: CursorStatement,
+        query_ctx: QueryContextRef,
+    ) -> Result<Output> {
+        match stmt {
+            CursorStatement::Declare { name, hold, query } => {
+                ensure!(
+                    !self.cursors.contains(&name),
+                    InvalidQuerySnafu {
+                        reason: format!("Cursor {name} already exists"),
+                    }
+                );
+                let stream = match self
+                    .query_handler
+                    .do_query(&query, query_ctx)
+                    .await
+                    .remove(0)?
+                {
+                    Output::Stream(stream) => stream,
+                    Output::RecordBatches(recordbatches) => recordbatches.as_stream(),
+                    Output::AffectedRows(_) => {
+                        return InvalidQuerySnafu {
+                            reason: format!("Cursor {name} must be declared for a query"),
+                        }
+                        .fail()
+                    }
+                };
+                self.cursors.declare(name, Cursor::new(stream, hold));
+                Ok(Output::AffectedRows(0))
+            }
+            CursorStatement::Fetch { name, count } => self.cursors.fetch(&name, count).await,
+            CursorStatement::Close { name: Some(name) } => {
+                ensure!(self.cursors.close(&name), CursorNotFoundSnafu { name });
+                Ok(Output::AffectedRows(0))
+            }
+            CursorStatement::Close { name: None } => {
+                self.cursors.close_all();
+                Ok(Output::AffectedRows(0))
+            }
+            CursorStatement::Begin => Ok(Output::AffectedRows(0)),
+            CursorStatement::Commit => {
+                self.cursors.end_transaction();
+                Ok(Output::AffectedRows(0))
+            }
+        }
+    }
+
+    async fn execute_portal(&self, portal: &Portal<SqlPlan>) -> PgWireResult<Result<Output>> {
+        let query_ctx = self.session.new_query_context();
+        let _timer = timer!(
+            crate::metrics::METRIC_POSTGRES_QUERY_TIMER,
+            &[
+                (
+                    crate::metrics::METRIC_POSTGRES_SUBPROTOCOL_LABEL,
+                    crate::metrics::METRIC_POSTGRES_EXTENDED_QUERY.to_string()
+                ),
+                (crate::metrics::METRIC_DB_LABEL, query_ctx.get_db_string())
+            ]
+        );
+        let sql_plan = portal.statement().statement();
+
+        let output = if let Some(plan) = &sql_plan.plan {
+            let plan = plan
+                .replace_params_with_values(parameters_to_scalar_values(plan, portal)?.as_ref())
+                .map_err(|e| PgWireError::ApiError(Box::new(e)))?;
+            self.query_handler.do_exec_plan(plan, query_ctx).await
+        } else {
+            // manually replace variables in prepared statement when no
+            // logical_plan is generated. This happens when logical plan is not
+            // supported for certain statements.
+            let mut sql = sql_plan.query.clone();
+            for i in 0..portal.parameter_len() {
+                sql = sql.replace(&format!("${}", i + 1), &parameter_to_string(portal, i)?);
+            }
+
+            self.do_sql_query(&sql, query_ctx).await.remove(0)
+        };
+        Ok(output)
+    }
+}
+
 pub struct DefaultQueryParser {
     query_handler: ServerSqlQueryHandlerRef,
     session: Arc<Session>,
@@ -155,6 +265,15 @@ impl QueryParser for DefaultQueryParser {
 
     async fn parse_sql(&self, sql: &str, _types: &[Type]) -> PgWireResult<Self::Statement> {
         increment_counter!(crate::metrics::METRIC_POSTGRES_PREPARED_COUNT);
+        // Cursor statements are executed by the server, there is nothing to describe.
+        if CursorStatement::parse(sql).is_some() {
+            return Ok(SqlPlan {
+                query: sql.to_owned(),
+                plan: None,
+                schema: None,
+            });
+        }
+
         let query_ctx = self.session.new_query_context();
         let mut stmts = ParserContext::create_with_dialect(sql, &PostgreSqlDialect {})
             .map_err(|e| PgWireError::ApiError(Box::new(e)))?;
@@ -205,6 +324,119 @@ impl ExtendedQueryHandler for PostgresServerHandler {
         self.query_parser.clone()
     }
 
+    async fn on_execute<C>(&self, client: &mut C, message: Execute) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send + Sync,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        let portal_name = message.name().as_deref().unwrap_or(DEFAULT_NAME);
+        let portal = self
+            .portal_store
+            .get_portal(portal_name)
+            .ok_or_else(|| PgWireError::PortalNotFound(portal_name.to_owned()))?;
+        // 0 means no limit.
+        let max_rows = (*message.max_rows()).max(0) as usize;
+
+        // Resumes the portal if a previous execute has suspended it.
+        let mut cursor = match self.cursors.resume_portal(portal_name, &portal) {
+            Some(PortalState::Suspended(cursor)) => cursor,
+            Some(PortalState::Completed) => {
+                self.cursors.complete_portal(portal_name, portal);
+                client
+                    .feed(PgWireBackendMessage::CommandComplete(
+                        Tag::new_for_query(0).into(),
+                    ))
+                    .await?;
+                return Ok(());
+            }
+            None => match self.execute_portal(&portal).await? {
+                Ok(Output::AffectedRows(rows)) => {
+                    let tag = Tag::new_for_execution("OK", Some(rows));
+                    client
+                        .feed(PgWireBackendMessage::CommandComplete(tag.into()))
+                        .await?;
+                    return Ok(());
+                }
+                Ok(Output::Stream(stream)) => Cursor::new(stream, false),
+                Ok(Output::RecordBatches(recordbatches)) => {
+                    Cursor::new(recordbatches.as_stream(), false)
+                }
+                Err(e) => {
+                    client
+                        .feed(PgWireBackendMessage::ErrorResponse(output_error(e).into()))
+                        .await?;
+                    return Ok(());
+                }
+            },
+        };
+
+        let schema = cursor.schema();
+        let pg_schema = Arc::new(
+            schema_to_pg(schema.as_ref(), portal.result_column_format())
+                .map_err(|e| PgWireError::ApiError(Box::new(e)))?,
+        );
+        let mut sent_rows = 0;
+        loop {
+            if max_rows > 0 && sent_rows == max_rows {
+                // Keeps the remaining rows for the next execute of the portal.
+                self.cursors.suspend_portal(portal_name, portal, cursor);
+                client
+                    .feed(PgWireBackendMessage::PortalSuspended(PortalSuspended::new()))
+                    .await?;
+                return Ok(());
+            }
+
+            // Still 0 if there is no limit.
+            let batch = cursor
+                .next_batch(max_rows.saturating_sub(sent_rows))
+                .await
+                .map_err(|e| PgWireError::ApiError(Box::new(e)))?;
+            let Some(batch) = batch else {
+                break;
+            };
+            for row in batch.rows() {
+                let data_row = encode_row(&row, &schema, pg_schema.clone())?;
+                client.feed(PgWireBackendMessage::DataRow(data_row)).await?;
+            }
+            sent_rows += batch.num_rows();
+        }
+        // Executing the portal again returns no rows. The cursor is dropped here so
+        // the stream is released even if the client never closes the portal.
+        drop(cursor);
+        self.cursors.complete_portal(portal_name, portal);
+
+        client
+            .feed(PgWireBackendMessage::CommandComplete(
+                Tag::new_for_query(sent_rows).into(),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn on_close<C>(&self, client: &mut C, message: Close) -> PgWireResult<()>
+    where
+        C: ClientInfo + Sink<PgWireBackendMessage> + Unpin + Send + Sync,
+        C::Error: Debug,
+        PgWireError: From<<C as Sink<PgWireBackendMessage>>::Error>,
+    {
+        let name = message.name().as_deref().unwrap_or(DEFAULT_NAME);
+        match message.target_type() {
+            TARGET_TYPE_BYTE_STATEMENT => {
+                self.portal_store.rm_statement(name);
+            }
+            TARGET_TYPE_BYTE_PORTAL => {
+                self.portal_store.rm_portal(name);
+                self.cursors.close_portal(name);
+            }
+            _ => {}
+        }
+        client
+            .send(PgWireBackendMessage::CloseComplete(CloseComplete::new()))
+            .await?;
+        Ok(())
+    }
+
     async fn do_query<'a, C>(
         &self,
         _client: &mut C,
@@ -214,36 +446,7 @@ impl ExtendedQueryHandler for PostgresServerHandler {
     where
         C: ClientInfo + Unpin + Send + Sync,
     {
-        let query_ctx = self.session.new_query_context();
-        let _timer = timer!(
-            crate::metrics::METRIC_POSTGRES_QUERY_TIMER,
-            &[
-                (
-                    crate::metrics::METRIC_POSTGRES_SUBPROTOCOL_LABEL,
-                    crate::metrics::METRIC_POSTGRES_EXTENDED_QUERY.to_string()
-                ),
-                (crate::metrics::METRIC_DB_LABEL, query_ctx.get_db_string())
-            ]
-        );
-        let sql_plan = portal.statement().statement();
-
-        let output = if let Some(plan) = &sql_plan.plan {
-            let plan = plan
-                .replace_params_with_values(parameters_to_scalar_values(plan, portal)?.as_ref())
-                .map_err(|e| PgWireError::ApiError(Box::new(e)))?;
-            self.query_handler.do_exec_plan(plan, query_ctx).await
-        } else {
-            // manually replace variables in prepared statement when no
-            // logical_plan is generated. This happens when logical plan is not
-            // supported for certain statements.
-            let mut sql = sql_plan.query.clone();
-            for i in 0..portal.parameter_len() {
-                sql = sql.replace(&format!("${}", i + 1), &parameter_to_string(portal, i)?);
-            }
-
-            self.query_handler.do_query(&sql, query_ctx).await.remove(0)
-        };
-
+        let output = self.execute_portal(portal).await?;
         output_to_query_response(output, portal.result_column_format())
     }
 
@@ -263,7 +466,7 @@ impl ExtendedQueryHandler for PostgresServerHandler {
                         .get_param_types()
                         .map_err(|e| PgWireError::ApiError(Box::new(e)))?;
 
-                    let types = param_types_to_pg_types(&param_types)
+                    let types = param_types_to_pg_types(&param_types, stmt.parameter_types())
                         .map_err(|e| PgWireError::ApiError(Box::new(e)))?;
 
                     (Some(types), sql_plan, &Format::UnifiedBinary)
@@ -279,7 +482,14 @@ impl ExtendedQueryHandler for PostgresServerHandler {
             ),
         };
 
-        if let Some(schema) = &sql_plan.schema {
+        // The rows of `FETCH` come from the cursor.
+        let fetch_schema = match CursorStatement::parse(&sql_plan.query) {
+            Some(CursorStatement::Fetch { name, .. }) if sql_plan.plan.is_none() => {
+                self.cursors.schema(&name)
+            }
+            _ => None,
+        };
+        if let Some(schema) = sql_plan.schema.as_ref().or(fetch_schema.as_ref()) {
             schema_to_pg(schema, format)
                 .map(|fields| DescribeResponse::new(param_types, fields))
                 .map_err(|e| PgWireError::ApiError(Box::new(e)))
@@ -12,23 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod decimal;
 mod interval;
 
 use std::collections::HashMap;
 use std::ops::Deref;
+use std::str::FromStr;
 
 use chrono::{NaiveDate, NaiveDateTime};
+use common_decimal::Decimal128;
 use common_time::Interval;
 use datafusion_common::ScalarValue;
 use datatypes::prelude::{ConcreteDataType, Value};
 use datatypes::schema::Schema;
-use datatypes::types::TimestampType;
+use datatypes::types::{jsonb_to_string, parse_string_to_jsonb, TimestampType};
 use pgwire::api::portal::{Format, Portal};
-use pgwire::api::results::{DataRowEncoder, FieldInfo};
+use pgwire::api::results::{DataRowEncoder, FieldFormat, FieldInfo};
 use pgwire::api::Type;
 use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
+use postgres_types::FromSql;
 use query::plan::LogicalPlan;
 
+use self::decimal::PgDecimal;
 use self::interval::PgInterval;
 use crate::error::{self, Error, Result};
 use crate::SqlPlan;
@@ -50,7 +55,11 @@ pub(super) fn schema_to_pg(origin: &Schema, field_formats: &Format) -> Result<Ve
         .collect::<Result<Vec<FieldInfo>>>()
 }
 
-pub(super) fn encode_value(value: &Value, builder: &mut DataRowEncoder) -> PgWireResult<()> {
+pub(super) fn encode_value(
+    value: &Value,
+    datatype: &ConcreteDataType,
+    builder: &mut DataRowEncoder,
+) -> PgWireResult<()> {
     match value {
         Value::Null => builder.encode_field(&None::<&i8>),
         Value::Boolean(v) => builder.encode_field(v),
@@ -65,7 +74,13 @@ pub(super) fn encode_value(value: &Value, builder: &mut DataRowEncoder) -> PgWir
         Value::Float32(v) => builder.encode_field(&v.0),
         Value::Float64(v) => builder.encode_field(&v.0),
         Value::String(v) => builder.encode_field(&v.as_utf8()),
-        Value::Binary(v) => builder.encode_field(&v.deref()),
+        Value::Binary(v) => {
+            if datatype.is_json() {
+                builder.encode_field(&jsonb_to_string(v))
+            } else {
+                builder.encode_field(&v.deref())
+            }
+        }
         Value::Date(v) => {
             if let Some(date) = v.to_chrono_date() {
                 builder.encode_field(&date)
@@ -103,6 +118,7 @@ pub(super) fn encode_value(value: &Value, builder: &mut DataRowEncoder) -> PgWir
             }
         }
         Value::Interval(v) => builder.encode_field(&PgInterval::from(*v)),
+        Value::Decimal128(v) => builder.encode_field(&PgDecimal(*v)),
         Value::List(_) | Value::Duration(_) => {
             Err(PgWireError::ApiError(Box::new(Error::Internal {
                 err_msg: format!(
@@ -131,6 +147,8 @@ pub(super) fn type_gt_to_pg(origin: &ConcreteDataType) -> Result<Type> {
         &ConcreteDataType::Timestamp(_) => Ok(Type::TIMESTAMP),
         &ConcreteDataType::Time(_) => Ok(Type::TIME),
         &ConcreteDataType::Interval(_) => Ok(Type::INTERVAL),
+        &ConcreteDataType::Decimal128(_) => Ok(Type::NUMERIC),
+        &ConcreteDataType::Json(_) => Ok(Type::JSON),
         &ConcreteDataType::Duration(_)
         | &ConcreteDataType::List(_)
         | &ConcreteDataType::Dictionary(_) => error::InternalSnafu {
@@ -140,7 +158,6 @@ pub(super) fn type_gt_to_pg(origin: &ConcreteDataType) -> Result<Type> {
     }
 }
 
-#[allow(dead_code)]
 pub(super) fn type_pg_to_gt(origin: &Type) -> Result<ConcreteDataType> {
     // Note that we only support a small amount of pg data types
     match origin {
@@ -149,12 +166,17 @@ pub(super) fn type_pg_to_gt(origin: &Type) -> Result<ConcreteDataType> {
         &Type::INT2 => Ok(ConcreteDataType::int16_datatype()),
         &Type::INT4 => Ok(ConcreteDataType::int32_datatype()),
         &Type::INT8 => Ok(ConcreteDataType::int64_datatype()),
+        &Type::FLOAT4 => Ok(ConcreteDataType::float32_datatype()),
+        &Type::FLOAT8 => Ok(ConcreteDataType::float64_datatype()),
+        &Type::NUMERIC => Ok(ConcreteDataType::decimal128_default_datatype()),
         &Type::VARCHAR | &Type::TEXT => Ok(ConcreteDataType::string_datatype()),
+        &Type::BYTEA => Ok(ConcreteDataType::binary_datatype()),
         &Type::TIMESTAMP => Ok(ConcreteDataType::timestamp_datatype(
             common_time::timestamp::TimeUnit::Millisecond,
         )),
         &Type::DATE => Ok(ConcreteDataType::date_datatype()),
         &Type::TIME => Ok(ConcreteDataType::datetime_datatype()),
+        &Type::INTERVAL => Ok(ConcreteDataType::interval_month_day_nano_datatype()),
         _ => error::InternalSnafu {
             err_msg: format!("unimplemented datatype {origin:?}"),
         }
@@ -162,6 +184,105 @@ pub(super) fn type_pg_to_gt(origin: &Type) -> Result<ConcreteDataType> {
     }
 }
 
+/// Parameter types that clients can send in both the text and the binary format.
+trait FromParameter: Sized + for<'a> FromSql<'a> {
+    /// Parses the parameter from the text format.
+    fn from_text(text: &str) -> Option<Self>;
+}
+
+macro_rules! impl_from_parameter_by_parse {
+    ($($ty: ty),*) => {
+        $(
+            impl FromParameter for $ty {
+                fn from_text(text: &str) -> Option<Self> {
+                    text.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_parameter_by_parse!(String, i16, i32, i64, f32, f64);
+
+impl FromParameter for bool {
+    fn from_text(text: &str) -> Option<Self> {
+        match text.to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Some(true),
+            "f" | "false" | "n" | "no" | "off" | "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl FromParameter for NaiveDate {
+    fn from_text(text: &str) -> Option<Self> {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+    }
+}
+
+impl FromParameter for NaiveDateTime {
+    fn from_text(text: &str) -> Option<Self> {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+            .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+            .ok()
+    }
+}
+
+impl FromParameter for Vec<u8> {
+    fn from_text(text: &str) -> Option<Self> {
+        // bytea in the hex format, e.g. `\x0a0b`
+        match text.strip_prefix("\\x") {
+            Some(hex) => hex::decode(hex).ok(),
+            None => Some(text.as_bytes().to_vec()),
+        }
+    }
+}
+
+impl FromParameter for PgInterval {
+    fn from_text(_text: &str) -> Option<Self> {
+        // parsing the postgres interval literal is not supported yet
+        None
+    }
+}
+
+impl FromParameter for PgDecimal {
+    fn from_text(text: &str) -> Option<Self> {
+        Decimal128::from_str(text).ok().map(PgDecimal)
+    }
+}
+
+/// Reads the parameter `idx` of the portal as `pg_type`, in the format the
+/// client sends it.
+fn parameter<T: FromParameter>(
+    portal: &Portal<SqlPlan>,
+    idx: usize,
+    pg_type: &Type,
+) -> PgWireResult<Option<T>> {
+    let Some(Some(raw)) = portal.parameters().get(idx) else {
+        return Ok(None);
+    };
+
+    let value = match portal.parameter_format().format_for(idx) {
+        FieldFormat::Text => std::str::from_utf8(raw)
+            .ok()
+            .and_then(T::from_text)
+            .ok_or_else(|| {
+                invalid_parameter_error(
+                    "invalid_parameter_value",
+                    Some(&format!(
+                        "Expected: {}, found: {}",
+                        pg_type,
+                        String::from_utf8_lossy(raw)
+                    )),
+                )
+            })?,
+        FieldFormat::Binary => T::from_sql(pg_type, raw).map_err(|e| {
+            invalid_parameter_error("invalid_parameter_value", Some(&e.to_string()))
+        })?,
+    };
+    Ok(Some(value))
+}
+
 pub(super) fn parameter_to_string(portal: &Portal<SqlPlan>, idx: usize) -> PgWireResult<String> {
     // the index is managed from portal's parameters count so it's safe to
     // unwrap here.
@@ -169,41 +290,35 @@ pub(super) fn parameter_to_string(portal: &Portal<SqlPlan>, idx: usize) -> PgWir
     match param_type {
         &Type::VARCHAR | &Type::TEXT => Ok(format!(
             "'{}'",
-            portal
-                .parameter::<String>(idx, param_type)?
+            parameter::<String>(portal, idx, param_type)?
                 .as_deref()
                 .unwrap_or("")
         )),
-        &Type::BOOL => Ok(portal
-            .parameter::<bool>(idx, param_type)?
+        &Type::BOOL => Ok(parameter::<bool>(portal, idx, param_type)?
             .map(|v| v.to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::INT4 => Ok(portal
-            .parameter::<i32>(idx, param_type)?
+        &Type::INT4 => Ok(parameter::<i32>(portal, idx, param_type)?
             .map(|v| v.to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::INT8 => Ok(portal
-            .parameter::<i64>(idx, param_type)?
+        &Type::INT8 => Ok(parameter::<i64>(portal, idx, param_type)?
             .map(|v| v.to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::FLOAT4 => Ok(portal
-            .parameter::<f32>(idx, param_type)?
+        &Type::FLOAT4 => Ok(parameter::<f32>(portal, idx, param_type)?
             .map(|v| v.to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::FLOAT8 => Ok(portal
-            .parameter::<f64>(idx, param_type)?
+        &Type::FLOAT8 => Ok(parameter::<f64>(portal, idx, param_type)?
             .map(|v| v.to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::DATE => Ok(portal
-            .parameter::<NaiveDate>(idx, param_type)?
+        &Type::DATE => Ok(parameter::<NaiveDate>(portal, idx, param_type)?
             .map(|v| v.format("%Y-%m-%d").to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::TIMESTAMP => Ok(portal
-            .parameter::<NaiveDateTime>(idx, param_type)?
+        &Type::TIMESTAMP => Ok(parameter::<NaiveDateTime>(portal, idx, param_type)?
             .map(|v| v.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
             .unwrap_or_else(|| "".to_owned())),
-        &Type::INTERVAL => Ok(portal
-            .parameter::<PgInterval>(idx, param_type)?
+        &Type::INTERVAL => Ok(parameter::<PgInterval>(portal, idx, param_type)?
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "".to_owned())),
+        &Type::NUMERIC => Ok(parameter::<PgDecimal>(portal, idx, param_type)?
             .map(|v| v.to_string())
             .unwrap_or_else(|| "".to_owned())),
         _ => Err(invalid_parameter_error(
@@ -262,20 +377,24 @@ pub(super) fn parameters_to_scalar_values(
     }
 
     for idx in 0..param_count {
-        let server_type =
-            if let Some(Some(server_infer_type)) = param_types.get(&format!("${}", idx + 1)) {
-                server_infer_type
-            } else {
-                // at the moment we require type information inferenced by
-                // server so here we return error if the type is unknown from
-                // server-side.
-                //
-                // It might be possible to parse the parameter just using client
-                // specified type, we will implement that if there is a case.
-                return Err(invalid_parameter_error("unknown_parameter_type", None));
-            };
-
-        let client_type = if let Some(client_given_type) = client_param_types.get(idx) {
+        // clients may leave the type of a parameter unspecified by `UNKNOWN`
+        let client_given_type = client_param_types
+            .get(idx)
+            .filter(|client_given_type| **client_given_type != Type::UNKNOWN);
+
+        let server_type = match param_types.get(&format!("${}", idx + 1)) {
+            Some(Some(server_infer_type)) => server_infer_type.clone(),
+            // the server can't infer the type, e.g. `SELECT $1`, so we use the
+            // type specified by the client
+            _ => match client_given_type {
+                Some(client_given_type) => type_pg_to_gt(client_given_type)
+                    .map_err(|e| PgWireError::ApiError(Box::new(e)))?,
+                None => return Err(invalid_parameter_error("unknown_parameter_type", None)),
+            },
+        };
+        let server_type = &server_type;
+
+        let client_type = if let Some(client_given_type) = client_given_type {
             client_given_type.clone()
         } else {
             type_gt_to_pg(server_type).map_err(|e| PgWireError::ApiError(Box::new(e)))?
@@ -283,9 +402,19 @@ pub(super) fn parameters_to_scalar_values(
 
         let value = match &client_type {
             &Type::VARCHAR | &Type::TEXT => {
-                let data = portal.parameter::<String>(idx, &client_type)?;
+                let data = parameter::<String>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::String(_) => ScalarValue::Utf8(data),
+                    ConcreteDataType::Json(_) => ScalarValue::Binary(
+                        data.map(|d| parse_string_to_jsonb(&d))
+                            .transpose()
+                            .map_err(|e| {
+                                invalid_parameter_error(
+                                    "invalid_parameter_value",
+                                    Some(&e.to_string()),
+                                )
+                            })?,
+                    ),
                     _ => {
                         return Err(invalid_parameter_error(
                             "invalid_parameter_type",
@@ -298,7 +427,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::BOOL => {
-                let data = portal.parameter::<bool>(idx, &client_type)?;
+                let data = parameter::<bool>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Boolean(_) => ScalarValue::Boolean(data),
                     _ => {
@@ -313,7 +442,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::INT2 => {
-                let data = portal.parameter::<i16>(idx, &client_type)?;
+                let data = parameter::<i16>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Int8(_) => ScalarValue::Int8(data.map(|n| n as i8)),
                     ConcreteDataType::Int16(_) => ScalarValue::Int16(data),
@@ -339,7 +468,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::INT4 => {
-                let data = portal.parameter::<i32>(idx, &client_type)?;
+                let data = parameter::<i32>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Int8(_) => ScalarValue::Int8(data.map(|n| n as i8)),
                     ConcreteDataType::Int16(_) => ScalarValue::Int16(data.map(|n| n as i16)),
@@ -365,7 +494,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::INT8 => {
-                let data = portal.parameter::<i64>(idx, &client_type)?;
+                let data = parameter::<i64>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Int8(_) => ScalarValue::Int8(data.map(|n| n as i8)),
                     ConcreteDataType::Int16(_) => ScalarValue::Int16(data.map(|n| n as i16)),
@@ -391,7 +520,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::FLOAT4 => {
-                let data = portal.parameter::<f32>(idx, &client_type)?;
+                let data = parameter::<f32>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Int8(_) => ScalarValue::Int8(data.map(|n| n as i8)),
                     ConcreteDataType::Int16(_) => ScalarValue::Int16(data.map(|n| n as i16)),
@@ -415,7 +544,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::FLOAT8 => {
-                let data = portal.parameter::<f64>(idx, &client_type)?;
+                let data = parameter::<f64>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Int8(_) => ScalarValue::Int8(data.map(|n| n as i8)),
                     ConcreteDataType::Int16(_) => ScalarValue::Int16(data.map(|n| n as i16)),
@@ -439,7 +568,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::TIMESTAMP => {
-                let data = portal.parameter::<NaiveDateTime>(idx, &client_type)?;
+                let data = parameter::<NaiveDateTime>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Timestamp(unit) => match *unit {
                         TimestampType::Second(_) => {
@@ -473,7 +602,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::DATE => {
-                let data = portal.parameter::<NaiveDate>(idx, &client_type)?;
+                let data = parameter::<NaiveDate>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Date(_) => ScalarValue::Date32(data.map(|d| {
                         (d - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
@@ -490,7 +619,7 @@ pub(super) fn parameters_to_scalar_values(
                 }
             }
             &Type::INTERVAL => {
-                let data = portal.parameter::<PgInterval>(idx, &client_type)?;
+                let data = parameter::<PgInterval>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::Interval(_) => {
                         ScalarValue::IntervalMonthDayNano(data.map(|i| Interval::from(i).to_i128()))
@@ -506,8 +635,38 @@ pub(super) fn parameters_to_scalar_values(
                     }
                 }
             }
+            &Type::NUMERIC => {
+                let data = parameter::<PgDecimal>(portal, idx, &client_type)?;
+                match server_type {
+                    ConcreteDataType::Decimal128(decimal_type) => {
+                        let (precision, scale) = (decimal_type.precision(), decimal_type.scale());
+                        let data = data
+                            .map(|d| d.0.rescale(precision, scale))
+                            .transpose()
+                            .map_err(|e| {
+                                invalid_parameter_error(
+                                    "invalid_parameter_value",
+                                    Some(&e.to_string()),
+                                )
+                            })?;
+                        ScalarValue::Decimal128(data.map(|d| d.val()), precision, scale)
+                    }
+                    ConcreteDataType::Float64(_) => {
+                        ScalarValue::Float64(data.map(|d| d.0.as_f64()))
+                    }
+                    _ => {
+                        return Err(invalid_parameter_error(
+                            "invalid_parameter_type",
+                            Some(&format!(
+                                "Expected: {}, found: {}",
+                                server_type, client_type
+                            )),
+                        ));
+                    }
+                }
+            }
             &Type::BYTEA => {
-                let data = portal.parameter::<Vec<u8>>(idx, &client_type)?;
+                let data = parameter::<Vec<u8>>(portal, idx, &client_type)?;
                 match server_type {
                     ConcreteDataType::String(_) => {
                         ScalarValue::Utf8(data.map(|d| String::from_utf8_lossy(&d).to_string()))
@@ -536,8 +695,11 @@ pub(super) fn parameters_to_scalar_values(
     Ok(results)
 }
 
+/// Returns the types of the parameters. Types inferred by the server take
+/// precedence over the types specified by the client.
 pub(super) fn param_types_to_pg_types(
     param_types: &HashMap<String, Option<ConcreteDataType>>,
+    client_param_types: &[Type],
 ) -> Result<Vec<Type>> {
     let param_count = param_types.len();
     let mut types = Vec::with_capacity(param_count);
@@ -545,6 +707,8 @@ pub(super) fn param_types_to_pg_types(
         if let Some(Some(param_type)) = param_types.get(&format!("${}", i + 1)) {
             let pg_type = type_gt_to_pg(param_type)?;
             types.push(pg_type);
+        } else if let Some(client_param_type) = client_param_types.get(i) {
+            types.push(client_param_type.clone());
         } else {
             types.push(Type::UNKNOWN);
         }
@@ -556,10 +720,12 @@ pub(super) fn param_types_to_pg_types(
 mod test {
     use std::sync::Arc;
 
+    use bytes::{BufMut, BytesMut};
     use datatypes::schema::{ColumnSchema, Schema};
     use datatypes::value::ListValue;
     use pgwire::api::results::{FieldFormat, FieldInfo};
     use pgwire::api::Type;
+    use pgwire::messages::Message;
 
     use super::*;
 
@@ -783,7 +949,7 @@ mod test {
         ];
         let mut builder = DataRowEncoder::new(Arc::new(schema));
         for i in values.iter() {
-            encode_value(i, &mut builder).unwrap();
+            encode_value(i, &i.data_type(), &mut builder).unwrap();
         }
 
         let err = encode_value(
@@ -791,6 +957,7 @@ mod test {
                 Some(Box::default()),
                 ConcreteDataType::int16_datatype(),
             )),
+            &ConcreteDataType::list_datatype(ConcreteDataType::int16_datatype()),
             &mut builder,
         )
         .unwrap_err();
@@ -803,4 +970,89 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_encode_binary_format_data() {
+        let field = |name: &str, pg_type| {
+            FieldInfo::new(name.into(), None, None, pg_type, FieldFormat::Binary)
+        };
+        let schema = vec![
+            field("decimal", Type::NUMERIC),
+            field("negative_decimal", Type::NUMERIC),
+            field("integral_decimal", Type::NUMERIC),
+            field("int", Type::INT4),
+            field("bigint", Type::INT8),
+            field("double", Type::FLOAT8),
+            field("timestamp", Type::TIMESTAMP),
+            field("binary", Type::BYTEA),
+            field("null", Type::INT4),
+        ];
+        let values = vec![
+            Value::Decimal128(Decimal128::new(12345, 10, 2)),
+            Value::Decimal128(Decimal128::new(-5, 10, 1)),
+            Value::Decimal128(Decimal128::new(100000000, 10, 0)),
+            Value::Int32(42),
+            Value::Int64(-1),
+            Value::Float64(1.5f64.into()),
+            Value::Timestamp(1000001i64.into()),
+            Value::Binary("greptime".as_bytes().into()),
+            Value::Null,
+        ];
+        let mut builder = DataRowEncoder::new(Arc::new(schema));
+        for i in values.iter() {
+            encode_value(i, &i.data_type(), &mut builder).unwrap();
+        }
+        let mut encoded = BytesMut::new();
+        builder.finish().unwrap().encode(&mut encoded).unwrap();
+
+        let fields: Vec<Option<Vec<u8>>> = vec![
+            // 123.45: 2 digits, weight 0, positive, scale 2, digits [123, 4500].
+            Some(vec![0, 2, 0, 0, 0, 0, 0, 2, 0, 123, 0x11, 0x94]),
+            // -0.5: 1 digit, weight -1, negative, scale 1, digits [5000].
+            Some(vec![0, 1, 0xff, 0xff, 0x40, 0, 0, 1, 0x13, 0x88]),
+            // 100000000: 1 digit, weight 2, positive, scale 0, digits [1].
+            Some(vec![0, 1, 0, 2, 0, 0, 0, 0, 0, 1]),
+            Some(42i32.to_be_bytes().to_vec()),
+            Some((-1i64).to_be_bytes().to_vec()),
+            Some(1.5f64.to_be_bytes().to_vec()),
+            // Microseconds since 2000-01-01.
+            Some((-946683799999000i64).to_be_bytes().to_vec()),
+            Some(b"greptime".to_vec()),
+            None,
+        ];
+        let mut body = BytesMut::new();
+        body.put_i16(fields.len() as i16);
+        for field in fields {
+            match field {
+                Some(bytes) => {
+                    body.put_i32(bytes.len() as i32);
+                    body.put_slice(&bytes);
+                }
+                None => body.put_i32(-1),
+            }
+        }
+        let mut expected = BytesMut::new();
+        expected.put_u8(b'D');
+        expected.put_i32(body.len() as i32 + 4);
+        expected.put_slice(&body);
+        assert_eq!(expected, encoded);
+    }
+
+    #[test]
+    fn test_parameter_from_text() {
+        assert_eq!(Some(true), bool::from_text("on"));
+        assert_eq!(Some(-42), i32::from_text("-42"));
+        assert_eq!(Some(vec![0x0a, 0x0b]), Vec::<u8>::from_text("\\x0a0b"));
+        assert_eq!(
+            Some(Decimal128::new(12345, 5, 2)),
+            PgDecimal::from_text("123.45").map(|d| d.0)
+        );
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2023, 1, 2)
+                .unwrap()
+                .and_hms_opt(3, 4, 5),
+            NaiveDateTime::from_text("2023-01-02 03:04:05")
+        );
+        assert!(i64::from_text("abc").is_none());
+    }
 }
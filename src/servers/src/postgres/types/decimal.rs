@@ -0,0 +1,269 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut};
+use common_decimal::decimal128::DECIMAL128_MAX_SCALE;
+use common_decimal::Decimal128;
+use pgwire::types::ToSqlText;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/numeric.c#L168-L170
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+/// Digits of the binary numeric format are in base 10000.
+const NBASE: i16 = 10000;
+const NBASE_DIGITS: usize = 4;
+/// The header has the number of digits, the weight, the sign and the display scale.
+const NUMERIC_HEADER_LEN: usize = 8;
+
+/// A [Decimal128] in the PostgreSQL `numeric` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgDecimal(pub Decimal128);
+
+impl PgDecimal {
+    /// Returns the base 10000 digits of the absolute value and the weight of the
+    /// first digit, without leading and trailing zero digits.
+    fn nbase_digits(&self) -> (Vec<i16>, i16) {
+        let mut digits = self.0.val().unsigned_abs().to_string();
+        let scale = self.0.scale();
+        if scale < 0 {
+            digits.push_str(&"0".repeat(scale.unsigned_abs() as usize));
+        }
+        let scale = scale.max(0) as usize;
+        let digits = format!("{digits:0>width$}", width = scale + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+
+        // Pads both parts to whole base 10000 digits.
+        let int_width = (int_part.len() + NBASE_DIGITS - 1) / NBASE_DIGITS * NBASE_DIGITS;
+        let frac_width = (frac_part.len() + NBASE_DIGITS - 1) / NBASE_DIGITS * NBASE_DIGITS;
+        let int_part = format!("{int_part:0>int_width$}");
+        let frac_part = format!("{frac_part:0<frac_width$}");
+
+        let mut weight = (int_part.len() / NBASE_DIGITS) as i16 - 1;
+        let mut nbase_digits = int_part
+            .as_bytes()
+            .chunks(NBASE_DIGITS)
+            .chain(frac_part.as_bytes().chunks(NBASE_DIGITS))
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0i16, |digit, c| digit * 10 + (c - b'0') as i16)
+            })
+            .collect::<Vec<_>>();
+
+        let leading_zeros = nbase_digits.iter().take_while(|d| **d == 0).count();
+        let _ = nbase_digits.drain(..leading_zeros);
+        weight -= leading_zeros as i16;
+        while nbase_digits.last() == Some(&0) {
+            let _ = nbase_digits.pop();
+        }
+        if nbase_digits.is_empty() {
+            weight = 0;
+        }
+
+        (nbase_digits, weight)
+    }
+}
+
+impl Display for PgDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ToSql for PgDecimal {
+    to_sql_checked!();
+
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut bytes::BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn snafu::Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        // https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/numeric.c#L1087-L1103
+        let (digits, weight) = self.nbase_digits();
+        out.put_i16(digits.len() as i16);
+        out.put_i16(weight);
+        out.put_u16(if self.0.val() < 0 {
+            NUMERIC_NEG
+        } else {
+            NUMERIC_POS
+        });
+        out.put_u16(self.0.scale().max(0) as u16);
+        for digit in digits {
+            out.put_i16(digit);
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(ty, &Type::NUMERIC)
+    }
+}
+
+impl<'a> FromSql<'a> for PgDecimal {
+    fn from_sql(
+        _: &Type,
+        mut raw: &'a [u8],
+    ) -> std::result::Result<Self, Box<dyn snafu::Error + Sync + Send>> {
+        // https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/numeric.c#L1011-L1030
+        if raw.len() < NUMERIC_HEADER_LEN {
+            return Err(format!("invalid numeric header length {}", raw.len()).into());
+        }
+        let ndigits = raw.get_i16();
+        let weight = raw.get_i16();
+        let sign = raw.get_u16();
+        let dscale = raw.get_u16();
+        if sign == NUMERIC_NAN {
+            return Err("NaN is not supported by decimal".into());
+        }
+        if sign != NUMERIC_POS && sign != NUMERIC_NEG {
+            return Err(format!("invalid numeric sign 0x{sign:04x}").into());
+        }
+        if dscale > DECIMAL128_MAX_SCALE as u16 {
+            return Err(format!("numeric scale {dscale} is out of range").into());
+        }
+        if ndigits < 0 || raw.len() != ndigits as usize * 2 {
+            return Err(format!(
+                "invalid numeric length {} for {ndigits} digits",
+                raw.len() + NUMERIC_HEADER_LEN
+            )
+            .into());
+        }
+
+        let mut digits = String::new();
+        for _ in 0..ndigits {
+            let digit = raw.get_i16();
+            if !(0..NBASE).contains(&digit) {
+                return Err(format!("invalid numeric digit {digit}").into());
+            }
+            digits.push_str(&format!("{digit:04}"));
+        }
+        // The first digit is at 10000^weight, so there are `weight + 1` integral
+        // digits in base 10000.
+        let int_width = (weight as i32 + 1) * NBASE_DIGITS as i32;
+        let (int_part, frac_part) = if int_width <= 0 {
+            let zeros = "0".repeat(int_width.unsigned_abs() as usize);
+            (String::new(), format!("{zeros}{digits}"))
+        } else if int_width as usize >= digits.len() {
+            (
+                format!("{digits:0<width$}", width = int_width as usize),
+                String::new(),
+            )
+        } else {
+            let (int_part, frac_part) = digits.split_at(int_width as usize);
+            (int_part.to_string(), frac_part.to_string())
+        };
+        let frac_part = format!("{frac_part:0<width$}", width = dscale as usize);
+        let frac_part = &frac_part[..dscale as usize];
+
+        let sign = if sign == NUMERIC_NEG { "-" } else { "" };
+        let literal = format!("{sign}{int_part}.{frac_part}");
+        let literal = literal.strip_suffix('.').unwrap_or(&literal);
+        Decimal128::from_str(literal)
+            .map(PgDecimal)
+            .map_err(|e| e.to_string().into())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty, &Type::NUMERIC)
+    }
+}
+
+impl ToSqlText for PgDecimal {
+    fn to_sql_text(
+        &self,
+        ty: &Type,
+        out: &mut bytes::BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn snafu::Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        let fmt = match ty {
+            &Type::NUMERIC => self.to_string(),
+            _ => return Err("unsupported type".into()),
+        };
+
+        out.put_slice(fmt.as_bytes());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+
+    fn assert_roundtrip(value: i128, precision: u8, scale: i8, digits: &[i16], weight: i16) {
+        let decimal = PgDecimal(Decimal128::new(value, precision, scale));
+        assert_eq!((digits.to_vec(), weight), decimal.nbase_digits());
+
+        let mut buf = BytesMut::new();
+        let _ = decimal.to_sql(&Type::NUMERIC, &mut buf).unwrap();
+        let decoded = PgDecimal::from_sql(&Type::NUMERIC, &buf).unwrap();
+        assert_eq!(decimal.to_string(), decoded.to_string());
+    }
+
+    #[test]
+    fn test_numeric_binary_format() {
+        assert_roundtrip(12345, 5, 2, &[123, 4500], 0);
+        assert_roundtrip(-12345, 5, 2, &[123, 4500], 0);
+        assert_roundtrip(12, 4, 4, &[12], -1);
+        assert_roundtrip(1234567890, 10, 0, &[12, 3456, 7890], 2);
+        assert_roundtrip(0, 10, 2, &[], 0);
+        assert_roundtrip(100000000, 10, 4, &[1], 1);
+    }
+
+    #[test]
+    fn test_invalid_numeric_binary_format() {
+        fn numeric(ndigits: i16, weight: i16, sign: u16, dscale: u16, digits: &[i16]) -> BytesMut {
+            let mut buf = BytesMut::new();
+            buf.put_i16(ndigits);
+            buf.put_i16(weight);
+            buf.put_u16(sign);
+            buf.put_u16(dscale);
+            for digit in digits {
+                buf.put_i16(*digit);
+            }
+            buf
+        }
+
+        let valid = numeric(2, 0, NUMERIC_POS, 2, &[123, 4500]);
+        assert!(PgDecimal::from_sql(&Type::NUMERIC, &valid).is_ok());
+        // Short header.
+        assert!(PgDecimal::from_sql(&Type::NUMERIC, &valid[..6]).is_err());
+        // Fewer digits than `ndigits`.
+        assert!(PgDecimal::from_sql(&Type::NUMERIC, &valid[..10]).is_err());
+        let invalid = [
+            numeric(-1, 0, NUMERIC_POS, 2, &[]),
+            numeric(1, 0, 0x1234, 2, &[123]),
+            numeric(1, 0, NUMERIC_POS, 0x3fff, &[123]),
+            numeric(1, 0, NUMERIC_POS, 2, &[10000]),
+            numeric(1, 0, NUMERIC_POS, 2, &[-1]),
+        ];
+        for raw in invalid {
+            assert!(PgDecimal::from_sql(&Type::NUMERIC, &raw).is_err());
+        }
+    }
+}
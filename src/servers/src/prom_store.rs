@@ -14,23 +14,32 @@
 
 //! prometheus protocol supportings
 //! handles prometheus remote_write, remote_read logic
+
+pub mod chunk;
+
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher};
 
 use api::prom_store::remote::label_matcher::Type as MatcherType;
+use api::prom_store::remote::metric_metadata::MetricType;
 use api::prom_store::remote::{Label, Query, Sample, TimeSeries, WriteRequest};
-use api::v1::RowInsertRequests;
+use api::v1::value::ValueData;
+use api::v1::{ColumnDataType, RowInsertRequests};
 use common_recordbatch::{RecordBatch, RecordBatches};
 use common_time::timestamp::TimeUnit;
 use datafusion::prelude::{col, lit, regexp_match, Expr};
-use datafusion_common::ScalarValue;
+use datafusion_common::{Column, ScalarValue};
 use datatypes::prelude::{ConcreteDataType, Value};
+use datatypes::vectors::VectorRef;
 use openmetrics_parser::{MetricsExposition, PrometheusType, PrometheusValue};
 use query::dataframe::DataFrame;
 use query::plan::LogicalPlan;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use snafu::{ensure, OptionExt, ResultExt};
 use snap::raw::{Decoder, Encoder};
+use table::requests::{TableOptions, PROM_METRIC_METADATA_KEY};
 
 use crate::error::{self, Result};
 use crate::row_writer::{self, MultiTableData};
@@ -38,6 +47,37 @@ use crate::row_writer::{self, MultiTableData};
 pub const TIMESTAMP_COLUMN_NAME: &str = "greptime_timestamp";
 pub const FIELD_COLUMN_NAME: &str = "greptime_value";
 pub const METRIC_NAME_LABEL: &str = "__name__";
+/// Suffix of the companion tables storing exemplars.
+pub const EXEMPLAR_TABLE_SUFFIX: &str = "__exemplars";
+
+/// Metadata of a Prometheus metric.
+///
+/// It is persisted in JSON format as the [PROM_METRIC_METADATA_KEY] option of the
+/// metric's table, so the comment of the table is left to users.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MetricMetadata {
+    #[serde(rename = "type")]
+    pub metric_type: String,
+    pub help: String,
+    pub unit: String,
+}
+
+impl MetricMetadata {
+    /// Parse [MetricMetadata] from the options of a table. Returns `None` if the
+    /// metadata is not written by Prometheus protocols.
+    pub fn from_table_options(options: &TableOptions) -> Option<Self> {
+        options
+            .extra_options
+            .get(PROM_METRIC_METADATA_KEY)
+            .and_then(|value| serde_json::from_str(value).ok())
+    }
+
+    /// Returns the value of the [PROM_METRIC_METADATA_KEY] table option.
+    pub fn to_table_option(&self) -> String {
+        // safety: serializing a struct of strings never fails
+        serde_json::to_string(self).unwrap()
+    }
+}
 
 /// Metrics for push gateway protocol
 pub struct Metrics {
@@ -64,6 +104,36 @@ pub fn table_name(q: &Query) -> Result<String> {
 
 /// Create a DataFrame from a remote Query
 pub fn query_to_plan(dataframe: DataFrame, q: &Query) -> Result<LogicalPlan> {
+    let dataframe = filter_dataframe(dataframe, q)?;
+
+    Ok(LogicalPlan::DfPlan(dataframe.into_parts().1))
+}
+
+/// Create a DataFrame from a remote Query, the result is ordered by the `primary_keys` (the
+/// labels) and then by timestamp, which is required by the streamed remote read response.
+///
+/// Regions are scanned in the same order, so the sort is satisfied by merging the region
+/// streams instead of buffering the whole result.
+pub fn query_to_sorted_plan(
+    dataframe: DataFrame,
+    q: &Query,
+    primary_keys: &[String],
+) -> Result<LogicalPlan> {
+    let dataframe = filter_dataframe(dataframe, q)?;
+
+    let sort_exprs = primary_keys
+        .iter()
+        .map(|name| name.as_str())
+        .chain(std::iter::once(TIMESTAMP_COLUMN_NAME))
+        .map(|name| Expr::Column(Column::from_name(name)).sort(true, true))
+        .collect::<Vec<_>>();
+
+    let dataframe = dataframe.sort(sort_exprs).context(error::DataFrameSnafu)?;
+
+    Ok(LogicalPlan::DfPlan(dataframe.into_parts().1))
+}
+
+fn filter_dataframe(dataframe: DataFrame, q: &Query) -> Result<datafusion::dataframe::DataFrame> {
     let DataFrame::DataFusion(dataframe) = dataframe;
 
     let start_timestamp_ms = q.start_timestamp_ms;
@@ -111,11 +181,7 @@ pub fn query_to_plan(dataframe: DataFrame, q: &Query) -> Result<LogicalPlan> {
     // Safety: conditions MUST not be empty, reduce always return Some(expr).
     let conditions = conditions.into_iter().reduce(Expr::and).unwrap();
 
-    let dataframe = dataframe
-        .filter(conditions)
-        .context(error::DataFrameSnafu)?;
-
-    Ok(LogicalPlan::DfPlan(dataframe.into_parts().1))
+    dataframe.filter(conditions).context(error::DataFrameSnafu)
 }
 
 #[inline]
@@ -236,7 +302,8 @@ pub fn recordbatches_to_timeseries(
         .collect())
 }
 
-fn recordbatch_to_timeseries(table: &str, recordbatch: RecordBatch) -> Result<Vec<TimeSeries>> {
+/// Checks and returns the timestamp and value columns of a remote read query result.
+fn check_recordbatch_columns(recordbatch: &RecordBatch) -> Result<(&VectorRef, &VectorRef)> {
     let ts_column = recordbatch.column_by_name(TIMESTAMP_COLUMN_NAME).context(
         error::InvalidPromRemoteReadQueryResultSnafu {
             msg: "missing greptime_timestamp column in query result",
@@ -267,6 +334,47 @@ fn recordbatch_to_timeseries(table: &str, recordbatch: RecordBatch) -> Result<Ve
         }
     );
 
+    Ok((ts_column, field_column))
+}
+
+/// Returns the `(timestamp, value)` of a row, or `None` if any of them is null.
+/// The columns should be checked by [check_recordbatch_columns].
+fn row_sample(
+    ts_column: &VectorRef,
+    field_column: &VectorRef,
+    row: usize,
+) -> Result<Option<(i64, f64)>> {
+    if ts_column.is_null(row) || field_column.is_null(row) {
+        return Ok(None);
+    }
+
+    let value: f64 = match field_column.get(row) {
+        Value::Float64(value) => value.into(),
+        other => {
+            return error::InvalidPromRemoteReadQueryResultSnafu {
+                msg: format!("Expect Float64 value, actual {:?}", other.data_type()),
+            }
+            .fail()
+        }
+    };
+    let timestamp = match ts_column.get(row) {
+        Value::Timestamp(t) if t.unit() == TimeUnit::Millisecond => t.value(),
+        other => {
+            return error::InvalidPromRemoteReadQueryResultSnafu {
+                msg: format!(
+                    "Expect Timestamp(Millisecond) value, actual {:?}",
+                    other.data_type()
+                ),
+            }
+            .fail()
+        }
+    };
+    Ok(Some((timestamp, value)))
+}
+
+fn recordbatch_to_timeseries(table: &str, recordbatch: RecordBatch) -> Result<Vec<TimeSeries>> {
+    let (ts_column, field_column) = check_recordbatch_columns(&recordbatch)?;
+
     // First, collect each row's timeseries id
     let timeseries_ids = collect_timeseries_ids(table, &recordbatch);
     // Then, group timeseries by it's id.
@@ -280,41 +388,65 @@ fn recordbatch_to_timeseries(table: &str, recordbatch: RecordBatch) -> Result<Ve
                 ..Default::default()
             });
 
-        if ts_column.is_null(row) || field_column.is_null(row) {
+        let Some((timestamp, value)) = row_sample(ts_column, field_column, row)? else {
             continue;
-        }
-
-        let value: f64 = match field_column.get(row) {
-            Value::Float64(value) => value.into(),
-            _ => unreachable!("checked by the \"ensure\" above"),
         };
-        let timestamp = match ts_column.get(row) {
-            Value::Timestamp(t) if t.unit() == TimeUnit::Millisecond => t.value(),
-            _ => unreachable!("checked by the \"ensure\" above"),
-        };
-        let sample = Sample { value, timestamp };
-
-        timeseries.samples.push(sample);
+        timeseries.samples.push(Sample { value, timestamp });
     }
 
     Ok(timeseries_map.into_values().collect())
 }
 
+/// Returns the name of the table storing the exemplars of `metric`.
+pub fn exemplar_table_name(metric: &str) -> String {
+    format!("{metric}{EXEMPLAR_TABLE_SUFFIX}")
+}
+
+fn metric_name(series: &TimeSeries) -> Result<&str> {
+    series
+        .labels
+        .iter()
+        .find(|label| {
+            // The metric name is a special label
+            label.name == METRIC_NAME_LABEL
+        })
+        .map(|label| label.value.as_str())
+        .context(error::InvalidPromRemoteRequestSnafu {
+            msg: "missing '__name__' label in time-series",
+        })
+}
+
+fn series_tags(series: &TimeSeries) -> impl Iterator<Item = (&str, &str)> {
+    series.labels.iter().filter_map(|label| {
+        if label.name == METRIC_NAME_LABEL {
+            None
+        } else {
+            Some((label.name.as_str(), label.value.as_str()))
+        }
+    })
+}
+
+/// Converts a remote write request to row insert requests, returns the requests and the number
+/// of samples in them.
+///
+/// Exemplars are written into the companion table of their metric (see [exemplar_table_name]),
+/// which are linked to the samples by the series labels and the timestamp. The exemplar labels,
+/// such as `trace_id`, are stored as string fields.
 pub fn to_grpc_row_insert_requests(request: WriteRequest) -> Result<(RowInsertRequests, usize)> {
+    // The table data borrows the names, so they are collected ahead.
+    let exemplar_table_names = request
+        .timeseries
+        .iter()
+        .filter(|series| !series.exemplars.is_empty())
+        .map(|series| metric_name(series).map(exemplar_table_name))
+        .collect::<Result<Vec<_>>>()?;
+    let mut exemplar_table_names_iter = exemplar_table_names.iter();
+
     let mut multi_table_data = MultiTableData::new();
+    let mut exemplar_table_data = MultiTableData::new();
 
     for series in &request.timeseries {
-        let table_name = &series
-            .labels
-            .iter()
-            .find(|label| {
-                // The metric name is a special label
-                label.name == METRIC_NAME_LABEL
-            })
-            .context(error::InvalidPromRemoteRequestSnafu {
-                msg: "missing '__name__' label in time-series",
-            })?
-            .value;
+        let table_name = metric_name(series)?;
 
         // The metric name is a special label,
         // num_columns = labels.len() - 1 + 1 (value) + 1 (timestamp)
@@ -330,14 +462,7 @@ pub fn to_grpc_row_insert_requests(request: WriteRequest) -> Result<(RowInsertRe
             let mut one_row = table_data.alloc_one_row();
 
             // labels
-            let kvs = series.labels.iter().filter_map(|label| {
-                if label.name == METRIC_NAME_LABEL {
-                    None
-                } else {
-                    Some((label.name.as_str(), label.value.as_str()))
-                }
-            });
-            row_writer::write_tags(table_data, kvs, &mut one_row)?;
+            row_writer::write_tags(table_data, series_tags(series), &mut one_row)?;
             // value
             row_writer::write_f64(table_data, FIELD_COLUMN_NAME, *value, &mut one_row)?;
             // timestamp
@@ -350,9 +475,89 @@ pub fn to_grpc_row_insert_requests(request: WriteRequest) -> Result<(RowInsertRe
 
             table_data.add_row(one_row);
         }
+
+        if series.exemplars.is_empty() {
+            continue;
+        }
+        // safety: a name is collected for each series with exemplars.
+        let exemplar_table_name = exemplar_table_names_iter.next().unwrap();
+        let table_data = exemplar_table_data.get_or_default_table_data(
+            exemplar_table_name,
+            num_columns + series.exemplars[0].labels.len(),
+            series.exemplars.len(),
+        );
+
+        for exemplar in &series.exemplars {
+            let mut one_row = table_data.alloc_one_row();
+
+            row_writer::write_tags(table_data, series_tags(series), &mut one_row)?;
+            // Exemplar labels conflicting with the columns of the series are dropped.
+            let fields = exemplar
+                .labels
+                .iter()
+                .filter(|label| {
+                    label.name != FIELD_COLUMN_NAME
+                        && label.name != TIMESTAMP_COLUMN_NAME
+                        && series.labels.iter().all(|l| l.name != label.name)
+                })
+                .map(|label| {
+                    (
+                        label.name.as_str(),
+                        ColumnDataType::String,
+                        ValueData::StringValue(label.value.clone()),
+                    )
+                });
+            row_writer::write_fields(table_data, fields, &mut one_row)?;
+            row_writer::write_f64(table_data, FIELD_COLUMN_NAME, exemplar.value, &mut one_row)?;
+            row_writer::write_ts_millis(
+                table_data,
+                TIMESTAMP_COLUMN_NAME,
+                Some(exemplar.timestamp),
+                &mut one_row,
+            )?;
+
+            table_data.add_row(one_row);
+        }
+    }
+
+    let (mut requests, samples) = multi_table_data.into_row_insert_requests();
+    let (exemplar_requests, _) = exemplar_table_data.into_row_insert_requests();
+    requests.inserts.extend(exemplar_requests.inserts);
+
+    Ok((requests, samples))
+}
+
+/// Collects the metric metadata in a remote write request, along with the names of the tables
+/// they apply to.
+///
+/// A metric family may be stored in several tables, e.g. the `_bucket`, `_sum` and `_count`
+/// tables of a histogram, each of them gets a copy of the metadata.
+pub fn to_metric_metadata(request: &WriteRequest) -> Vec<(String, MetricMetadata)> {
+    let mut result = Vec::with_capacity(request.metadata.len());
+
+    for metadata in &request.metadata {
+        let metric_type = MetricType::from_i32(metadata.r#type).unwrap_or(MetricType::Unknown);
+        let suffixes: &[&str] = match metric_type {
+            MetricType::Counter => &["", "_total"],
+            MetricType::Histogram | MetricType::Gaugehistogram => &["_bucket", "_sum", "_count"],
+            MetricType::Summary => &["", "_sum", "_count"],
+            _ => &[""],
+        };
+
+        let metric_metadata = MetricMetadata {
+            metric_type: metric_type.as_str_name().to_lowercase(),
+            help: metadata.help.clone(),
+            unit: metadata.unit.clone(),
+        };
+        for suffix in suffixes {
+            result.push((
+                format!("{}{suffix}", metadata.metric_family_name),
+                metric_metadata.clone(),
+            ));
+        }
     }
 
-    Ok(multi_table_data.into_row_insert_requests())
+    result
 }
 
 #[inline]
@@ -439,8 +644,8 @@ pub fn mock_timeseries() -> Vec<TimeSeries> {
 mod tests {
     use std::sync::Arc;
 
-    use api::prom_store::remote::LabelMatcher;
-    use api::v1::{ColumnDataType, Row, SemanticType};
+    use api::prom_store::remote::{Exemplar, LabelMatcher};
+    use api::v1::{Row, SemanticType};
     use datafusion::prelude::SessionContext;
     use datatypes::schema::{ColumnSchema, Schema};
     use datatypes::vectors::{Float64Vector, StringVector, TimestampMillisecondVector};
@@ -548,11 +753,19 @@ mod tests {
             ..Default::default()
         };
 
-        let dataframe = ctx.read_table(table_provider).unwrap();
+        let dataframe = ctx.read_table(table_provider.clone()).unwrap();
         let plan = query_to_plan(DataFrame::DataFusion(dataframe), &q).unwrap();
         let display_string = format!("{}", plan.display_indent());
 
         assert_eq!("Filter: ?table?.greptime_timestamp >= TimestampMillisecond(1000, None) AND ?table?.greptime_timestamp <= TimestampMillisecond(2000, None) AND regexp_match(?table?.job, Utf8(\"*prom*\")) IS NOT NULL AND ?table?.instance != Utf8(\"localhost\")\n  TableScan: ?table?", display_string);
+
+        let dataframe = ctx.read_table(table_provider).unwrap();
+        let primary_keys = vec!["instance".to_string(), "job".to_string()];
+        let plan =
+            query_to_sorted_plan(DataFrame::DataFusion(dataframe), &q, &primary_keys).unwrap();
+        let display_string = format!("{}", plan.display_indent());
+
+        assert!(display_string.starts_with("Sort: ?table?.instance ASC NULLS FIRST, ?table?.job ASC NULLS FIRST, ?table?.greptime_timestamp ASC NULLS FIRST\n  Filter: "), "{display_string}");
     }
 
     fn column_schemas_with(
@@ -694,6 +907,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_write_request_with_exemplars() {
+        let mut timeseries = mock_timeseries();
+        timeseries[0].exemplars = vec![Exemplar {
+            labels: vec![
+                new_label("trace_id".to_string(), "abc".to_string()),
+                // conflicts with the series label
+                new_label("job".to_string(), "flink".to_string()),
+            ],
+            value: 1.5,
+            timestamp: 1500,
+        }];
+        let write_request = WriteRequest {
+            timeseries,
+            ..Default::default()
+        };
+
+        let (requests, samples) = to_grpc_row_insert_requests(write_request).unwrap();
+        // exemplars are not counted as samples
+        assert_eq!(7, samples);
+        let mut exprs = requests.inserts;
+        exprs.sort_unstable_by(|l, r| l.table_name.cmp(&r.table_name));
+        assert_eq!(4, exprs.len());
+        assert_eq!("metric1__exemplars", exprs[1].table_name);
+
+        let rows = exprs[1].rows.as_ref().unwrap();
+        assert_eq!(
+            vec![
+                api::v1::ColumnSchema {
+                    column_name: "job".to_string(),
+                    datatype: ColumnDataType::String as i32,
+                    semantic_type: SemanticType::Tag as i32,
+                },
+                api::v1::ColumnSchema {
+                    column_name: "trace_id".to_string(),
+                    datatype: ColumnDataType::String as i32,
+                    semantic_type: SemanticType::Field as i32,
+                },
+                api::v1::ColumnSchema {
+                    column_name: FIELD_COLUMN_NAME.to_string(),
+                    datatype: ColumnDataType::Float64 as i32,
+                    semantic_type: SemanticType::Field as i32,
+                },
+                api::v1::ColumnSchema {
+                    column_name: TIMESTAMP_COLUMN_NAME.to_string(),
+                    datatype: ColumnDataType::TimestampMillisecond as i32,
+                    semantic_type: SemanticType::Timestamp as i32,
+                },
+            ],
+            rows.schema
+        );
+        assert_eq!(
+            vec![make_row_with_2_labels("spark", "abc", 1.5, 1500)],
+            rows.rows
+        );
+    }
+
+    #[test]
+    fn test_to_metric_metadata() {
+        let write_request = WriteRequest {
+            metadata: vec![
+                api::prom_store::remote::MetricMetadata {
+                    r#type: MetricType::Histogram as i32,
+                    metric_family_name: "http_latency".to_string(),
+                    help: "latency of http requests".to_string(),
+                    unit: "seconds".to_string(),
+                },
+                api::prom_store::remote::MetricMetadata {
+                    r#type: MetricType::Gauge as i32,
+                    metric_family_name: "memory_usage".to_string(),
+                    help: "memory usage".to_string(),
+                    unit: "".to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let metadata = to_metric_metadata(&write_request);
+        let table_names = metadata
+            .iter()
+            .map(|(table_name, _)| table_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "http_latency_bucket",
+                "http_latency_sum",
+                "http_latency_count",
+                "memory_usage"
+            ],
+            table_names
+        );
+        assert_eq!(
+            MetricMetadata {
+                metric_type: "histogram".to_string(),
+                help: "latency of http requests".to_string(),
+                unit: "seconds".to_string(),
+            },
+            metadata[0].1
+        );
+        assert_eq!("gauge", metadata[3].1.metric_type);
+    }
+
     #[test]
     fn test_recordbatches_to_timeseries() {
         let schema = Arc::new(Schema::new(vec![
@@ -781,4 +1096,25 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn test_metric_metadata_table_option() {
+        let metadata = MetricMetadata {
+            metric_type: "counter".to_string(),
+            help: "Total number of requests".to_string(),
+            unit: "".to_string(),
+        };
+        let value = metadata.to_table_option();
+        assert_eq!(
+            r#"{"type":"counter","help":"Total number of requests","unit":""}"#,
+            value
+        );
+
+        let mut options = TableOptions::default();
+        assert!(MetricMetadata::from_table_options(&options).is_none());
+        let _ = options
+            .extra_options
+            .insert(PROM_METRIC_METADATA_KEY.to_string(), value);
+        assert_eq!(Some(metadata), MetricMetadata::from_table_options(&options));
+    }
 }
@@ -0,0 +1,481 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding of the `STREAMED_XOR_CHUNKS` remote read response.
+//!
+//! Samples are encoded in the XOR chunk format of Prometheus (see `tsdb/chunkenc/xor.go`),
+//! grouped into [ChunkedSeries] and written as length-delimited, checksummed
+//! [ChunkedReadResponse] frames.
+
+use api::prom_store::remote::chunk::Encoding;
+use api::prom_store::remote::{Chunk, ChunkedReadResponse, ChunkedSeries};
+use common_recordbatch::RecordBatch;
+use prost::Message;
+
+use super::{check_recordbatch_columns, collect_timeseries_ids, row_sample, TimeSeriesId};
+use crate::error::Result;
+
+/// Content type of the streamed remote read response.
+pub const STREAMED_XOR_CHUNKS_CONTENT_TYPE: &str =
+    "application/x-streamed-protobuf; proto=prometheus.ChunkedReadResponse";
+
+/// Max samples in a chunk, the same as the target of Prometheus head chunks.
+const MAX_SAMPLES_PER_CHUNK: u16 = 120;
+/// Soft limit of the encoded size of a frame, the default of Prometheus is 1MB.
+const MAX_FRAME_BYTES: usize = 1024 * 1024;
+
+/// A bit-level writer which fills bytes from the most significant bit.
+#[derive(Debug, Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    /// Number of bits still available in the last byte.
+    remaining: u8,
+}
+
+impl BitWriter {
+    fn write_bit(&mut self, bit: bool) {
+        if self.remaining == 0 {
+            self.bytes.push(0);
+            self.remaining = 8;
+        }
+        if bit {
+            // safety: a byte is pushed above if there is no room left.
+            *self.bytes.last_mut().unwrap() |= 1 << (self.remaining - 1);
+        }
+        self.remaining -= 1;
+    }
+
+    /// Writes the lowest `nbits` bits of `value`, from the most significant one.
+    fn write_bits(&mut self, value: u64, nbits: u8) {
+        for i in (0..nbits).rev() {
+            self.write_bit((value >> i) & 1 == 1);
+        }
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        self.write_bits(byte as u64, 8);
+    }
+
+    fn write_uvarint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.write_byte(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.write_byte(value as u8);
+    }
+
+    fn write_varint(&mut self, value: i64) {
+        // zig-zag encoding, the same as `binary.PutVarint` in golang.
+        self.write_uvarint(((value << 1) ^ (value >> 63)) as u64);
+    }
+}
+
+/// Encoder of a single Prometheus XOR chunk.
+#[derive(Debug)]
+pub struct XorChunkEncoder {
+    writer: BitWriter,
+    num_samples: u16,
+    min_time: i64,
+    prev_time: i64,
+    prev_delta: i64,
+    prev_value: u64,
+    leading: u8,
+    trailing: u8,
+}
+
+impl Default for XorChunkEncoder {
+    fn default() -> Self {
+        Self {
+            // The first two bytes hold the number of samples.
+            writer: BitWriter {
+                bytes: vec![0, 0],
+                remaining: 0,
+            },
+            num_samples: 0,
+            min_time: 0,
+            prev_time: 0,
+            prev_delta: 0,
+            prev_value: 0,
+            leading: u8::MAX,
+            trailing: 0,
+        }
+    }
+}
+
+impl XorChunkEncoder {
+    pub fn num_samples(&self) -> u16 {
+        self.num_samples
+    }
+
+    /// Appends a sample, timestamps are expected to be appended in ascending order.
+    pub fn append(&mut self, timestamp: i64, value: f64) {
+        let value = value.to_bits();
+        match self.num_samples {
+            0 => {
+                self.writer.write_varint(timestamp);
+                self.writer.write_bits(value, 64);
+                self.min_time = timestamp;
+            }
+            1 => {
+                let delta = timestamp - self.prev_time;
+                self.writer.write_uvarint(delta as u64);
+                self.write_value(value);
+                self.prev_delta = delta;
+            }
+            _ => {
+                let delta = timestamp - self.prev_time;
+                self.write_delta_of_delta(delta - self.prev_delta);
+                self.write_value(value);
+                self.prev_delta = delta;
+            }
+        }
+
+        self.prev_time = timestamp;
+        self.prev_value = value;
+        self.num_samples += 1;
+    }
+
+    fn write_delta_of_delta(&mut self, dod: i64) {
+        let writer = &mut self.writer;
+        if dod == 0 {
+            writer.write_bit(false);
+        } else if bit_range(dod, 14) {
+            writer.write_bits(0b10, 2);
+            writer.write_bits(dod as u64, 14);
+        } else if bit_range(dod, 17) {
+            writer.write_bits(0b110, 3);
+            writer.write_bits(dod as u64, 17);
+        } else if bit_range(dod, 20) {
+            writer.write_bits(0b1110, 4);
+            writer.write_bits(dod as u64, 20);
+        } else {
+            writer.write_bits(0b1111, 4);
+            writer.write_bits(dod as u64, 64);
+        }
+    }
+
+    fn write_value(&mut self, value: u64) {
+        let delta = value ^ self.prev_value;
+        if delta == 0 {
+            self.writer.write_bit(false);
+            return;
+        }
+        self.writer.write_bit(true);
+
+        // Only 5 bits are reserved for the leading zeros.
+        let leading = (delta.leading_zeros() as u8).min(31);
+        let trailing = delta.trailing_zeros() as u8;
+
+        if self.leading != u8::MAX && leading >= self.leading && trailing >= self.trailing {
+            // The meaningful bits fall into the previous window, reuse it.
+            self.writer.write_bit(false);
+            self.writer
+                .write_bits(delta >> self.trailing, 64 - self.leading - self.trailing);
+            return;
+        }
+
+        self.leading = leading;
+        self.trailing = trailing;
+        let sigbits = 64 - leading - trailing;
+        self.writer.write_bit(true);
+        self.writer.write_bits(leading as u64, 5);
+        // 64 significant bits overflows to 0 in 6 bits, which is impossible otherwise.
+        self.writer.write_bits(sigbits as u64, 6);
+        self.writer.write_bits(delta >> trailing, sigbits);
+    }
+
+    /// Finishes the chunk, returns `None` if no sample was appended.
+    pub fn finish(self) -> Option<Chunk> {
+        if self.num_samples == 0 {
+            return None;
+        }
+        let mut data = self.writer.bytes;
+        data[..2].copy_from_slice(&self.num_samples.to_be_bytes());
+        Some(Chunk {
+            min_time_ms: self.min_time,
+            max_time_ms: self.prev_time,
+            r#type: Encoding::Xor as i32,
+            data,
+        })
+    }
+}
+
+/// Whether `value` can be represented in `nbits` bits.
+#[inline]
+fn bit_range(value: i64, nbits: u8) -> bool {
+    -((1 << (nbits - 1)) - 1) <= value && value <= 1 << (nbits - 1)
+}
+
+/// Encodes a [ChunkedReadResponse] into a frame: the uvarint length of the message, the
+/// big-endian CRC32 (Castagnoli) checksum of the message and the message itself.
+pub fn encode_frame(response: &ChunkedReadResponse) -> Vec<u8> {
+    let message = response.encode_to_vec();
+    let mut frame = Vec::with_capacity(message.len() + 14);
+    prost::encoding::encode_varint(message.len() as u64, &mut frame);
+    frame.extend_from_slice(&crc32c::crc32c(&message).to_be_bytes());
+    frame.extend_from_slice(&message);
+    frame
+}
+
+/// Builds the frames of a remote read query from its record batches.
+///
+/// The record batches must be sorted by labels and then by timestamp, so that the
+/// samples of a series are consecutive, even across record batches.
+pub struct ChunkedSeriesBuilder {
+    table_name: String,
+    query_index: i64,
+    current: Option<(TimeSeriesId, Vec<Chunk>, XorChunkEncoder)>,
+    finished: Vec<ChunkedSeries>,
+    finished_bytes: usize,
+}
+
+impl ChunkedSeriesBuilder {
+    pub fn new(table_name: String, query_index: i64) -> Self {
+        Self {
+            table_name,
+            query_index,
+            current: None,
+            finished: vec![],
+            finished_bytes: 0,
+        }
+    }
+
+    /// Appends a record batch, returns the frames that are ready to be sent.
+    pub fn push(&mut self, recordbatch: &RecordBatch) -> Result<Vec<Vec<u8>>> {
+        let (ts_column, field_column) = check_recordbatch_columns(recordbatch)?;
+        let timeseries_ids = collect_timeseries_ids(&self.table_name, recordbatch);
+
+        let mut frames = vec![];
+        for (row, timeseries_id) in timeseries_ids.into_iter().enumerate() {
+            let is_new_series = self
+                .current
+                .as_ref()
+                .map(|(id, _, _)| *id != timeseries_id)
+                .unwrap_or(true);
+            if is_new_series {
+                self.finish_series();
+                if self.finished_bytes >= MAX_FRAME_BYTES {
+                    frames.push(self.take_frame());
+                }
+                self.current = Some((timeseries_id, vec![], XorChunkEncoder::default()));
+            }
+
+            let Some((timestamp, value)) = row_sample(ts_column, field_column, row)? else {
+                continue;
+            };
+
+            // safety: `current` is set above.
+            let (_, chunks, encoder) = self.current.as_mut().unwrap();
+            if encoder.num_samples() >= MAX_SAMPLES_PER_CHUNK {
+                let full = std::mem::take(encoder);
+                // safety: the encoder is full, not empty.
+                chunks.push(full.finish().unwrap());
+            }
+            encoder.append(timestamp, value);
+        }
+
+        if !self.finished.is_empty() {
+            frames.push(self.take_frame());
+        }
+        Ok(frames)
+    }
+
+    /// Finishes the builder, returns the last frame if there is any series left.
+    pub fn finish(mut self) -> Option<Vec<u8>> {
+        self.finish_series();
+        if self.finished.is_empty() {
+            None
+        } else {
+            Some(self.take_frame())
+        }
+    }
+
+    fn finish_series(&mut self) {
+        let Some((id, mut chunks, encoder)) = self.current.take() else {
+            return;
+        };
+        chunks.extend(encoder.finish());
+        // Series without samples are omitted, the same as the samples response.
+        if chunks.is_empty() {
+            return;
+        }
+
+        let series = ChunkedSeries {
+            labels: id.labels,
+            chunks,
+        };
+        self.finished_bytes += series.encoded_len();
+        self.finished.push(series);
+    }
+
+    fn take_frame(&mut self) -> Vec<u8> {
+        self.finished_bytes = 0;
+        encode_frame(&ChunkedReadResponse {
+            chunked_series: std::mem::take(&mut self.finished),
+            query_index: self.query_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use api::prom_store::remote::Label;
+    use datatypes::prelude::ConcreteDataType;
+    use datatypes::schema::{ColumnSchema, Schema};
+    use datatypes::vectors::{Float64Vector, StringVector, TimestampMillisecondVector};
+
+    use super::*;
+    use crate::prom_store::{FIELD_COLUMN_NAME, METRIC_NAME_LABEL, TIMESTAMP_COLUMN_NAME};
+
+    fn decode_frame(frame: &[u8]) -> ChunkedReadResponse {
+        let len = prost::encoding::decode_varint(&mut &frame[..]).unwrap() as usize;
+        let message = &frame[frame.len() - len..];
+        assert_eq!(
+            crc32c::crc32c(message).to_be_bytes(),
+            frame[frame.len() - len - 4..frame.len() - len]
+        );
+        ChunkedReadResponse::decode(message).unwrap()
+    }
+
+    #[test]
+    fn test_bit_writer() {
+        let mut writer = BitWriter::default();
+        writer.write_bit(true);
+        writer.write_bits(0b01, 2);
+        writer.write_byte(0xff);
+        assert_eq!(vec![0b1011_1111, 0b1110_0000], writer.bytes);
+
+        let mut writer = BitWriter::default();
+        writer.write_varint(-1);
+        writer.write_uvarint(300);
+        assert_eq!(vec![0x01, 0xac, 0x02], writer.bytes);
+    }
+
+    #[test]
+    fn test_bit_range() {
+        assert!(bit_range(8192, 14));
+        assert!(bit_range(-8191, 14));
+        assert!(!bit_range(8193, 14));
+        assert!(!bit_range(-8192, 14));
+    }
+
+    #[test]
+    fn test_xor_chunk_encoder() {
+        assert!(XorChunkEncoder::default().finish().is_none());
+
+        let mut encoder = XorChunkEncoder::default();
+        encoder.append(1000, 1.0);
+        encoder.append(2000, 1.0);
+        encoder.append(3000, 2.0);
+        assert_eq!(3, encoder.num_samples());
+        let chunk = encoder.finish().unwrap();
+
+        assert_eq!(1000, chunk.min_time_ms);
+        assert_eq!(3000, chunk.max_time_ms);
+        assert_eq!(Encoding::Xor as i32, chunk.r#type);
+        // number of samples
+        assert_eq!([0, 3], chunk.data[..2]);
+        // varint(1000)
+        assert_eq!([0xd0, 0x0f], chunk.data[2..4]);
+        // bits of 1.0
+        assert_eq!(1.0f64.to_be_bytes(), chunk.data[4..12]);
+        // uvarint(1000), then `0` for the unchanged value, `0` for the zero delta of
+        // delta, `1` for the changed value and `1` for a new xor window ...
+        assert_eq!([0xe8, 0x07], chunk.data[12..14]);
+        assert_eq!(0b0011, chunk.data[14] >> 4);
+    }
+
+    #[test]
+    fn test_encode_frame() {
+        let response = ChunkedReadResponse {
+            chunked_series: vec![],
+            query_index: 1,
+        };
+        let message = response.encode_to_vec();
+        let frame = encode_frame(&response);
+
+        assert_eq!(message.len() as u8, frame[0]);
+        assert_eq!(crc32c::crc32c(&message).to_be_bytes(), frame[1..5]);
+        assert_eq!(message, frame[5..]);
+    }
+
+    #[test]
+    fn test_chunked_series_builder() {
+        let schema = Arc::new(Schema::new(vec![
+            ColumnSchema::new(
+                TIMESTAMP_COLUMN_NAME,
+                ConcreteDataType::timestamp_millisecond_datatype(),
+                true,
+            ),
+            ColumnSchema::new(
+                FIELD_COLUMN_NAME,
+                ConcreteDataType::float64_datatype(),
+                true,
+            ),
+            ColumnSchema::new("instance", ConcreteDataType::string_datatype(), true),
+        ]));
+        let batch1 = RecordBatch::new(
+            schema.clone(),
+            vec![
+                Arc::new(TimestampMillisecondVector::from_vec(vec![1000, 2000])) as _,
+                Arc::new(Float64Vector::from_vec(vec![1.0, 2.0])) as _,
+                Arc::new(StringVector::from(vec!["host1", "host1"])) as _,
+            ],
+        )
+        .unwrap();
+        let batch2 = RecordBatch::new(
+            schema,
+            vec![
+                Arc::new(TimestampMillisecondVector::from_vec(vec![3000, 1000])) as _,
+                Arc::new(Float64Vector::from_vec(vec![3.0, 4.0])) as _,
+                Arc::new(StringVector::from(vec!["host1", "host2"])) as _,
+            ],
+        )
+        .unwrap();
+
+        let mut builder = ChunkedSeriesBuilder::new("metric1".to_string(), 1);
+        // host1 is not finished yet.
+        assert!(builder.push(&batch1).unwrap().is_empty());
+
+        let frames = builder.push(&batch2).unwrap();
+        assert_eq!(1, frames.len());
+        let response = decode_frame(&frames[0]);
+        assert_eq!(1, response.query_index);
+        assert_eq!(1, response.chunked_series.len());
+        let series = &response.chunked_series[0];
+        assert_eq!(
+            vec![
+                Label {
+                    name: METRIC_NAME_LABEL.to_string(),
+                    value: "metric1".to_string(),
+                },
+                Label {
+                    name: "instance".to_string(),
+                    value: "host1".to_string(),
+                },
+            ],
+            series.labels
+        );
+        assert_eq!(1, series.chunks.len());
+        assert_eq!(1000, series.chunks[0].min_time_ms);
+        assert_eq!(3000, series.chunks[0].max_time_ms);
+        assert_eq!([0, 3], series.chunks[0].data[..2]);
+
+        let response = decode_frame(&builder.finish().unwrap());
+        assert_eq!(1, response.chunked_series.len());
+        assert_eq!("host2", response.chunked_series[0].labels[1].value);
+    }
+}
@@ -16,6 +16,7 @@
 
 use std::sync::Arc;
 
+use api::prom_store::remote::Query;
 use async_trait::async_trait;
 use catalog::CatalogManagerRef;
 use common_query::Output;
@@ -32,5 +33,9 @@ pub type PrometheusHandlerRef = Arc<dyn PrometheusHandler + Send + Sync>;
 pub trait PrometheusHandler {
     async fn do_query(&self, query: &PromQuery, query_ctx: QueryContextRef) -> Result<Output>;
 
+    /// Reads the exemplars of the series selected by the remote read `query`. The output
+    /// contains all columns of the exemplar table of the metric.
+    async fn query_exemplars(&self, query: &Query, query_ctx: QueryContextRef) -> Result<Output>;
+
     fn catalog_manager(&self) -> CatalogManagerRef;
 }
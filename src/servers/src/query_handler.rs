@@ -31,10 +31,12 @@ use std::sync::Arc;
 use api::prom_store::remote::{ReadRequest, WriteRequest};
 use async_trait::async_trait;
 use common_query::Output;
+use futures::stream::BoxStream;
 use opentelemetry_proto::tonic::collector::metrics::v1::{
     ExportMetricsServiceRequest, ExportMetricsServiceResponse,
 };
 use session::context::QueryContextRef;
+use table::requests::InsertRequest;
 
 use crate::error::Result;
 use crate::influxdb::InfluxdbRequest;
@@ -45,6 +47,7 @@ pub type OpentsdbProtocolHandlerRef = Arc<dyn OpentsdbProtocolHandler + Send + S
 pub type InfluxdbLineProtocolHandlerRef = Arc<dyn InfluxdbLineProtocolHandler + Send + Sync>;
 pub type PromStoreProtocolHandlerRef = Arc<dyn PromStoreProtocolHandler + Send + Sync>;
 pub type OpenTelemetryProtocolHandlerRef = Arc<dyn OpenTelemetryProtocolHandler + Send + Sync>;
+pub type FlightSqlIngestHandlerRef = Arc<dyn FlightSqlIngestHandler + Send + Sync>;
 pub type ScriptHandlerRef = Arc<dyn ScriptHandler + Send + Sync>;
 
 #[async_trait]
@@ -79,8 +82,15 @@ pub trait OpentsdbProtocolHandler {
 
 pub struct PromStoreResponse {
     pub content_type: String,
+    /// Omitted from the response headers if empty.
     pub content_encoding: String,
-    pub body: Vec<u8>,
+    pub body: PromStoreResponseBody,
+}
+
+pub enum PromStoreResponseBody {
+    Full(Vec<u8>),
+    /// Frames of the streamed remote read response, sent as soon as they are ready.
+    Stream(BoxStream<'static, Result<Vec<u8>>>),
 }
 
 #[async_trait]
@@ -102,3 +112,10 @@ pub trait OpenTelemetryProtocolHandler {
         ctx: QueryContextRef,
     ) -> Result<ExportMetricsServiceResponse>;
 }
+
+#[async_trait]
+pub trait FlightSqlIngestHandler {
+    /// Inserts the rows ingested by Flight SQL into an existing table, returns the
+    /// number of affected rows.
+    async fn ingest(&self, request: InsertRequest, ctx: QueryContextRef) -> Result<usize>;
+}
@@ -33,7 +33,7 @@ use servers::prom_store;
 use servers::prom_store::{snappy_compress, Metrics};
 use servers::query_handler::grpc::GrpcQueryHandler;
 use servers::query_handler::sql::SqlQueryHandler;
-use servers::query_handler::{PromStoreProtocolHandler, PromStoreResponse};
+use servers::query_handler::{PromStoreProtocolHandler, PromStoreResponse, PromStoreResponseBody};
 use session::context::QueryContextRef;
 use tokio::sync::mpsc;
 
@@ -79,7 +79,7 @@ impl PromStoreProtocolHandler for DummyInstance {
         Ok(PromStoreResponse {
             content_type: "application/x-protobuf".to_string(),
             content_encoding: "snappy".to_string(),
-            body: response.encode_to_vec(),
+            body: PromStoreResponseBody::Full(response.encode_to_vec()),
         })
     }
 
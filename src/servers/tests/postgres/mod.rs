@@ -348,6 +348,84 @@ async fn test_extended_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_extended_query_with_client_param_types() -> Result<()> {
+    let server_port = start_test_server(TlsOption::default()).await?;
+    let client = create_connection_with_given_db(server_port, DEFAULT_SCHEMA_NAME)
+        .await
+        .unwrap();
+    // the server can't infer the type of `$1`, so the client specified type is used
+    let stmt = client
+        .prepare_typed("SELECT $1", &[Type::INT8])
+        .await
+        .unwrap();
+    let rows = client.query(&stmt, &[&42i64]).await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<usize, i64>(0usize), 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_portal_with_max_rows() -> Result<()> {
+    let server_port = start_test_server(TlsOption::default()).await?;
+    let mut client = create_connection_with_given_db(server_port, DEFAULT_SCHEMA_NAME)
+        .await
+        .unwrap();
+    let transaction = client.transaction().await.unwrap();
+    let stmt = transaction
+        .prepare("SELECT uint32s FROM numbers")
+        .await
+        .unwrap();
+    let portal = transaction.bind(&stmt, &[]).await.unwrap();
+
+    let mut numbers = Vec::new();
+    loop {
+        let rows = transaction.query_portal(&portal, 30).await.unwrap();
+        if rows.is_empty() {
+            break;
+        }
+        assert!(rows.len() <= 30);
+        numbers.extend(rows.iter().map(|row| row.get::<usize, i32>(0)));
+    }
+    assert_eq!((0..100).collect::<Vec<_>>(), numbers);
+    transaction.commit().await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_declare_and_fetch_cursor() -> Result<()> {
+    let server_port = start_test_server(TlsOption::default()).await?;
+    let client = create_connection_with_given_db(server_port, DEFAULT_SCHEMA_NAME)
+        .await
+        .unwrap();
+    let _ = client.simple_query("BEGIN").await.unwrap();
+    let _ = client
+        .simple_query("DECLARE c CURSOR FOR SELECT uint32s FROM numbers")
+        .await
+        .unwrap();
+
+    let result = client.simple_query("FETCH 30 FROM c").await.unwrap();
+    assert_eq!(
+        (0..30).map(|n| n.to_string()).collect::<Vec<_>>(),
+        unwrap_results(&result)
+    );
+    let result = client.simple_query("FETCH ALL FROM c").await.unwrap();
+    assert_eq!(
+        (30..100).map(|n| n.to_string()).collect::<Vec<_>>(),
+        unwrap_results(&result)
+    );
+    let result = client.simple_query("FETCH c").await.unwrap();
+    assert!(unwrap_results(&result).is_empty());
+
+    // The cursor is closed at the end of the transaction.
+    let _ = client.simple_query("COMMIT").await.unwrap();
+    assert!(client.simple_query("FETCH c").await.is_err());
+
+    Ok(())
+}
+
 async fn start_test_server(server_tls: TlsOption) -> Result<u16> {
     common_telemetry::init_default_ut_logging();
     let table = MemTable::default_numbers_table();
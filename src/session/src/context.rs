@@ -23,6 +23,9 @@ use common_catalog::{build_db_string, parse_catalog_and_schema_from_db_string};
 use common_time::TimeZone;
 use derive_builder::Builder;
 use sql::dialect::{Dialect, GreptimeDbDialect, MySqlDialect, PostgreSqlDialect};
+use tokio_util::sync::CancellationToken;
+
+use crate::resource_usage::QueryResourceUsageRef;
 
 pub type QueryContextRef = Arc<QueryContext>;
 pub type ConnInfoRef = Arc<ConnInfo>;
@@ -37,6 +40,14 @@ pub struct QueryContext {
     time_zone: ArcSwap<Option<TimeZone>>,
     sql_dialect: Box<dyn Dialect + Send + Sync>,
     trace_id: u64,
+    /// The protocol this query comes from.
+    channel: Channel,
+    /// Address of the client that sends the query, if known.
+    client_addr: Option<SocketAddr>,
+    /// Resources consumed by the statement that is executing in this context.
+    resource_usage: ArcSwap<Option<QueryResourceUsageRef>>,
+    /// Cancels the statement that is executing in this context, e.g. on `KILL QUERY`.
+    cancel_token: ArcSwap<Option<CancellationToken>>,
 }
 
 impl Display for QueryContext {
@@ -125,6 +136,36 @@ impl QueryContext {
     pub fn trace_id(&self) -> u64 {
         self.trace_id
     }
+
+    #[inline]
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    #[inline]
+    pub fn client_addr(&self) -> Option<SocketAddr> {
+        self.client_addr
+    }
+
+    #[inline]
+    pub fn resource_usage(&self) -> Option<QueryResourceUsageRef> {
+        self.resource_usage.load().as_ref().clone()
+    }
+
+    #[inline]
+    pub fn set_resource_usage(&self, usage: Option<QueryResourceUsageRef>) {
+        let _ = self.resource_usage.swap(Arc::new(usage));
+    }
+
+    #[inline]
+    pub fn cancel_token(&self) -> Option<CancellationToken> {
+        self.cancel_token.load().as_ref().clone()
+    }
+
+    #[inline]
+    pub fn set_cancel_token(&self, token: Option<CancellationToken>) {
+        let _ = self.cancel_token.swap(Arc::new(token));
+    }
 }
 
 impl QueryContextBuilder {
@@ -146,6 +187,14 @@ impl QueryContextBuilder {
                 .sql_dialect
                 .unwrap_or_else(|| Box::new(GreptimeDbDialect {})),
             trace_id: self.trace_id.unwrap_or_else(common_telemetry::gen_trace_id),
+            channel: self.channel.unwrap_or_default(),
+            client_addr: self.client_addr.flatten(),
+            resource_usage: self
+                .resource_usage
+                .unwrap_or_else(|| ArcSwap::new(Arc::new(None))),
+            cancel_token: self
+                .cancel_token
+                .unwrap_or_else(|| ArcSwap::new(Arc::new(None))),
         })
     }
 
@@ -184,10 +233,14 @@ impl ConnInfo {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Channel {
+    #[default]
+    Unknown,
     Mysql,
     Postgres,
+    Http,
+    Grpc,
 }
 
 impl Channel {
@@ -195,6 +248,7 @@ impl Channel {
         match self {
             Channel::Mysql => Box::new(MySqlDialect {}),
             Channel::Postgres => Box::new(PostgreSqlDialect {}),
+            Channel::Unknown | Channel::Http | Channel::Grpc => Box::new(GreptimeDbDialect {}),
         }
     }
 }
@@ -202,8 +256,11 @@ impl Channel {
 impl Display for Channel {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
+            Channel::Unknown => write!(f, "unknown"),
             Channel::Mysql => write!(f, "mysql"),
             Channel::Postgres => write!(f, "postgres"),
+            Channel::Http => write!(f, "http"),
+            Channel::Grpc => write!(f, "grpc"),
         }
     }
 }
@@ -229,6 +286,11 @@ mod test {
         assert_eq!(client_addr.port(), 9000);
 
         assert_eq!("mysql[127.0.0.1:9000]", session.conn_info().to_string());
+
+        // test query context
+        let query_ctx = session.new_query_context();
+        assert_eq!(Channel::Mysql, query_ctx.channel());
+        assert_eq!(Some(*client_addr), query_ctx.client_addr());
     }
 
     #[test]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod context;
+pub mod resource_usage;
 
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -55,6 +56,8 @@ impl Session {
             .current_catalog(self.catalog.load().to_string())
             .current_schema(self.schema.load().to_string())
             .sql_dialect(self.conn_info.channel.dialect())
+            .channel(self.conn_info.channel)
+            .client_addr(self.conn_info.client_addr)
             .build()
     }
 
@@ -0,0 +1,120 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+pub type QueryResourceUsageRef = Arc<QueryResourceUsage>;
+
+/// Rows a query has received from one region.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RegionScanUsage {
+    pub rows: usize,
+    /// Size of the received record batches in memory. It's not the number of bytes
+    /// read from the storage, which may be much larger or smaller.
+    pub memory_size: usize,
+}
+
+/// Resources consumed by one query, filled in by the query engine while the
+/// query runs.
+#[derive(Debug, Default)]
+pub struct QueryResourceUsage {
+    plan: Mutex<Option<String>>,
+    region_scans: Mutex<BTreeMap<u64, RegionScanUsage>>,
+    memory_used: AtomicUsize,
+    peak_memory: AtomicUsize,
+}
+
+impl QueryResourceUsage {
+    pub fn set_plan(&self, plan: String) {
+        *self.plan.lock().unwrap() = Some(plan);
+    }
+
+    /// Summary of the logical plan of the query, if it has one.
+    pub fn plan(&self) -> Option<String> {
+        self.plan.lock().unwrap().clone()
+    }
+
+    pub fn record_region_scan(&self, region_id: u64, rows: usize, memory_size: usize) {
+        let mut region_scans = self.region_scans.lock().unwrap();
+        let usage = region_scans.entry(region_id).or_default();
+        usage.rows += rows;
+        usage.memory_size += memory_size;
+    }
+
+    /// Rows received from each region, ordered by region id.
+    pub fn region_scans(&self) -> Vec<(u64, RegionScanUsage)> {
+        self.region_scans
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(region_id, usage)| (*region_id, *usage))
+            .collect()
+    }
+
+    pub fn grow_memory(&self, bytes: usize) {
+        let used = self.memory_used.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        let _ = self.peak_memory.fetch_max(used, Ordering::Relaxed);
+    }
+
+    pub fn shrink_memory(&self, bytes: usize) {
+        let _ = self.memory_used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    /// The largest amount of memory reserved by the query at any moment.
+    pub fn peak_memory(&self) -> usize {
+        self.peak_memory.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_resource_usage() {
+        let usage = QueryResourceUsage::default();
+        assert!(usage.plan().is_none());
+
+        usage.record_region_scan(2, 10, 100);
+        usage.record_region_scan(1, 5, 50);
+        usage.record_region_scan(2, 1, 10);
+        assert_eq!(
+            vec![
+                (
+                    1,
+                    RegionScanUsage {
+                        rows: 5,
+                        memory_size: 50
+                    }
+                ),
+                (
+                    2,
+                    RegionScanUsage {
+                        rows: 11,
+                        memory_size: 110
+                    }
+                )
+            ],
+            usage.region_scans()
+        );
+
+        usage.grow_memory(100);
+        usage.grow_memory(50);
+        usage.shrink_memory(120);
+        usage.grow_memory(10);
+        assert_eq!(150, usage.peak_memory());
+    }
+}
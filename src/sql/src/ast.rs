@@ -14,6 +14,7 @@
 
 pub use sqlparser::ast::{
     visit_expressions_mut, visit_statements_mut, BinaryOperator, ColumnDef, ColumnOption,
-    ColumnOptionDef, DataType, Expr, Function, FunctionArg, FunctionArgExpr, Ident, ObjectName,
-    SqlOption, TableConstraint, TimezoneInfo, Value, Visit, VisitMut, Visitor, VisitorMut,
+    ColumnOptionDef, DataType, ExactNumberInfo, Expr, Function, FunctionArg, FunctionArgExpr,
+    Ident, ObjectName, SqlOption, TableConstraint, TimezoneInfo, Value, Visit, VisitMut, Visitor,
+    VisitorMut,
 };
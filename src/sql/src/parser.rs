@@ -20,7 +20,7 @@ use sqlparser::tokenizer::{Token, TokenWithLocation};
 
 use crate::ast::{Expr, ObjectName};
 use crate::error::{self, Result, SyntaxSnafu};
-use crate::parsers::tql_parser;
+use crate::parsers::{evaluate_parser, tql_parser};
 use crate::statements::statement::Statement;
 use crate::statements::transform_statements;
 
@@ -97,6 +97,8 @@ impl<'a> ParserContext<'a> {
 
                     Keyword::DELETE => self.parse_delete(),
 
+                    Keyword::UPDATE => self.parse_update(),
+
                     Keyword::DESCRIBE | Keyword::DESC => {
                         let _ = self.parser.next_token();
                         self.parse_describe()
@@ -114,6 +116,12 @@ impl<'a> ParserContext<'a> {
 
                     Keyword::TRUNCATE => self.parse_truncate(),
 
+                    Keyword::KILL => self.parse_kill(),
+
+                    _ if w.value.eq_ignore_ascii_case(evaluate_parser::EVALUATE) => {
+                        self.parse_evaluate()
+                    }
+
                     Keyword::NoKeyword
                         if w.value.to_uppercase() == tql_parser::TQL && w.quote_style.is_none() =>
                     {
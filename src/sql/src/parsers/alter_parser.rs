@@ -15,16 +15,31 @@
 use common_query::AddColumnLocation;
 use snafu::ResultExt;
 use sqlparser::keywords::Keyword;
-use sqlparser::parser::ParserError;
+use sqlparser::parser::{Parser, ParserError};
 use sqlparser::tokenizer::Token;
 
 use crate::error::{self, Result};
 use crate::parser::ParserContext;
-use crate::statements::alter::{AlterTable, AlterTableOperation};
+use crate::statements::alter::{
+    AlterDatabase, AlterDatabaseOperation, AlterTable, AlterTableOperation,
+};
 use crate::statements::statement::Statement;
 
 impl<'a> ParserContext<'a> {
     pub(crate) fn parse_alter(&mut self) -> Result<Statement> {
+        if self
+            .parser
+            .parse_keywords(&[Keyword::ALTER, Keyword::DATABASE])
+            || self
+                .parser
+                .parse_keywords(&[Keyword::ALTER, Keyword::SCHEMA])
+        {
+            let alter_database = self
+                .parse_alter_database()
+                .context(error::SyntaxSnafu { sql: self.sql })?;
+            return Ok(Statement::AlterDatabase(alter_database));
+        }
+
         let alter_table = self
             .parse_alter_table()
             .context(error::SyntaxSnafu { sql: self.sql })?;
@@ -73,6 +88,20 @@ impl<'a> ParserContext<'a> {
                     parser.peek_token()
                 )));
             }
+        } else if parser.parse_keyword(Keyword::SET) {
+            let options = Self::parse_set_options(parser)?;
+            AlterTableOperation::SetTableOptions { options }
+        } else if Self::parse_word(parser, "MODIFY") {
+            let _ = parser.parse_keyword(Keyword::COLUMN);
+            let column_name = parser.parse_identifier()?;
+            let target_type = parser.parse_data_type()?;
+            AlterTableOperation::ModifyColumnType {
+                column_name,
+                target_type,
+            }
+        } else if Self::parse_word(parser, "UNSET") {
+            let keys = Self::parse_unset_options(parser)?;
+            AlterTableOperation::UnsetTableOptions { keys }
         } else if parser.parse_keyword(Keyword::RENAME) {
             let new_table_name_obj = parser.parse_object_name()?;
             let new_table_name = match &new_table_name_obj.0[..] {
@@ -86,12 +115,61 @@ impl<'a> ParserContext<'a> {
             AlterTableOperation::RenameTable { new_table_name }
         } else {
             return Err(ParserError::ParserError(format!(
-                "expect keyword ADD or DROP or RENAME after ALTER TABLE, found {}",
+                "expect keyword ADD, DROP, MODIFY, SET, UNSET or RENAME after ALTER TABLE, found {}",
                 parser.peek_token()
             )));
         };
         Ok(AlterTable::new(table_name, alter_operation))
     }
+
+    fn parse_alter_database(&mut self) -> std::result::Result<AlterDatabase, ParserError> {
+        let parser = &mut self.parser;
+        let database_name = parser.parse_object_name()?;
+
+        let alter_operation = if parser.parse_keyword(Keyword::SET) {
+            let options = Self::parse_set_options(parser)?;
+            AlterDatabaseOperation::SetDatabaseOptions { options }
+        } else if Self::parse_word(parser, "UNSET") {
+            let keys = Self::parse_unset_options(parser)?;
+            AlterDatabaseOperation::UnsetDatabaseOptions { keys }
+        } else {
+            return Err(ParserError::ParserError(format!(
+                "expect keyword SET or UNSET after ALTER DATABASE, found {}",
+                parser.peek_token()
+            )));
+        };
+        Ok(AlterDatabase::new(database_name, alter_operation))
+    }
+
+    /// Parses `<option_name> = <option_value> [, ...]`.
+    fn parse_set_options(
+        parser: &mut Parser<'_>,
+    ) -> std::result::Result<Vec<(String, String)>, ParserError> {
+        parser.parse_comma_separated(|parser| {
+            let key = parser.parse_literal_string()?;
+            parser.expect_token(&Token::Eq)?;
+            let value = parser.parse_literal_string()?;
+            Ok((key, value))
+        })
+    }
+
+    /// Parses `<option_name> [, ...]`.
+    fn parse_unset_options(
+        parser: &mut Parser<'_>,
+    ) -> std::result::Result<Vec<String>, ParserError> {
+        parser.parse_comma_separated(|parser| parser.parse_literal_string())
+    }
+
+    /// Consumes the next token if it is the non-keyword `word`, ignoring case.
+    fn parse_word(parser: &mut Parser<'_>, word: &str) -> bool {
+        if let Token::Word(w) = parser.peek_token().token {
+            if w.value.eq_ignore_ascii_case(word) {
+                let _ = parser.next_token();
+                return true;
+            }
+        }
+        false
+    }
 }
 
 #[cfg(test)]
@@ -246,7 +324,8 @@ mod tests {
         let sql = "ALTER TABLE test_table table_t";
         let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap_err();
         let err = result.iter_chain().last().unwrap().to_string();
-        assert!(err.contains("expect keyword ADD or DROP or RENAME after ALTER TABLE"));
+        assert!(err
+            .contains("expect keyword ADD, DROP, MODIFY, SET, UNSET or RENAME after ALTER TABLE"));
 
         let sql = "ALTER TABLE test_table RENAME table_t";
         let mut result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
@@ -270,4 +349,123 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_parse_alter_modify_column_type() {
+        let sql = "ALTER TABLE test_table MODIFY COLUMN a BIGINT";
+        let mut result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+
+        let statement = result.remove(0);
+        match statement {
+            Statement::Alter(alter_table) => {
+                assert_eq!("test_table", alter_table.table_name().0[0].value);
+                match alter_table.alter_operation() {
+                    AlterTableOperation::ModifyColumnType {
+                        column_name,
+                        target_type,
+                    } => {
+                        assert_eq!("a", column_name.value);
+                        assert_eq!(&DataType::BigInt(None), target_type);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        // COLUMN is optional.
+        let sql = "ALTER TABLE test_table MODIFY a DOUBLE";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+
+        let sql = "ALTER TABLE test_table MODIFY COLUMN a";
+        ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap_err();
+    }
+
+    #[test]
+    fn test_parse_alter_set_unset_options() {
+        let sql = "ALTER TABLE test_table SET 'ttl'='7d', 'compaction.twcs.time_window'='2h'";
+        let mut result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+
+        let statement = result.remove(0);
+        match statement {
+            Statement::Alter(alter_table) => match alter_table.alter_operation() {
+                AlterTableOperation::SetTableOptions { options } => {
+                    assert_eq!(
+                        &vec![
+                            ("ttl".to_string(), "7d".to_string()),
+                            ("compaction.twcs.time_window".to_string(), "2h".to_string()),
+                        ],
+                        options
+                    );
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+
+        let sql = "ALTER TABLE test_table UNSET 'ttl', 'compaction.twcs.time_window'";
+        let mut result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+
+        let statement = result.remove(0);
+        match statement {
+            Statement::Alter(alter_table) => match alter_table.alter_operation() {
+                AlterTableOperation::UnsetTableOptions { keys } => {
+                    assert_eq!(
+                        &vec!["ttl".to_string(), "compaction.twcs.time_window".to_string()],
+                        keys
+                    );
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_parse_alter_database() {
+        let sql = "ALTER DATABASE test_db SET 'ttl'='30d'";
+        let mut result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+
+        let statement = result.remove(0);
+        match statement {
+            Statement::AlterDatabase(alter_database) => {
+                assert_eq!("test_db", alter_database.database_name().0[0].value);
+                assert_eq!(
+                    &AlterDatabaseOperation::SetDatabaseOptions {
+                        options: vec![("ttl".to_string(), "30d".to_string())],
+                    },
+                    alter_database.alter_operation()
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "ALTER SCHEMA test_db UNSET 'ttl'";
+        let mut result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+
+        let statement = result.remove(0);
+        match statement {
+            Statement::AlterDatabase(alter_database) => {
+                assert_eq!("test_db", alter_database.database_name().0[0].value);
+                assert_eq!(
+                    &AlterDatabaseOperation::UnsetDatabaseOptions {
+                        keys: vec!["ttl".to_string()],
+                    },
+                    alter_database.alter_operation()
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "ALTER DATABASE test_db RENAME db";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap_err();
+        let err = result.iter_chain().last().unwrap().to_string();
+        assert!(err.contains("expect keyword SET or UNSET after ALTER DATABASE"));
+    }
 }
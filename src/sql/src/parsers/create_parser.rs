@@ -19,7 +19,7 @@ use common_catalog::consts::default_engine;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use snafu::{ensure, OptionExt, ResultExt};
-use sqlparser::ast::{ColumnOption, ColumnOptionDef, DataType, Value};
+use sqlparser::ast::{ColumnOption, ColumnOptionDef, DataType, ObjectName, Value};
 use sqlparser::dialect::keywords::Keyword;
 use sqlparser::keywords::ALL_KEYWORDS;
 use sqlparser::parser::IsOptional::Mandatory;
@@ -34,8 +34,10 @@ use crate::error::{
 };
 use crate::parser::ParserContext;
 use crate::statements::create::{
-    CreateDatabase, CreateExternalTable, CreateTable, PartitionEntry, Partitions, TIME_INDEX,
+    CreateDatabase, CreateExternalTable, CreateFlow, CreateTable, CreateTableAs, CreateView,
+    PartitionEntry, Partitions, TIME_INDEX,
 };
+use crate::statements::query::Query;
 use crate::statements::statement::Statement;
 use crate::statements::{
     get_data_type_by_alias_name, sql_data_type_to_concrete_data_type, sql_value_to_value,
@@ -44,6 +46,12 @@ use crate::util::parse_option_string;
 
 pub const ENGINE: &str = "ENGINE";
 pub const MAXVALUE: &str = "MAXVALUE";
+pub const FLOW: &str = "FLOW";
+/// Option of `CREATE TABLE ... AS SELECT` to specify the time index column.
+pub const TIME_INDEX_OPTION: &str = "time_index";
+/// Option of `CREATE TABLE ... AS SELECT` to specify primary keys, separated by commas.
+pub const PRIMARY_KEY_OPTION: &str = "primary_key";
+pub const SINK: &str = "SINK";
 
 static LESS: Lazy<Token> = Lazy::new(|| Token::make_keyword("LESS"));
 static THAN: Lazy<Token> = Lazy::new(|| Token::make_keyword("THAN"));
@@ -59,6 +67,10 @@ impl<'a> ParserContext<'a> {
 
                 Keyword::EXTERNAL => self.parse_create_external_table(),
 
+                Keyword::VIEW => self.parse_create_view(),
+
+                _ if w.value.eq_ignore_ascii_case(FLOW) => self.parse_create_flow(),
+
                 _ => self.unsupported(w.to_string()),
             },
             unexpected => self.unsupported(unexpected.to_string()),
@@ -130,9 +142,103 @@ impl<'a> ParserContext<'a> {
                 actual: self.peek_token_as_string(),
             })?;
 
+        let options = self
+            .parser
+            .parse_options(Keyword::WITH)
+            .context(error::SyntaxSnafu { sql: self.sql })?
+            .into_iter()
+            .filter_map(|option| {
+                parse_option_string(option.value).map(|v| (option.name.value.to_lowercase(), v))
+            })
+            .collect::<HashMap<String, String>>();
+
         Ok(Statement::CreateDatabase(CreateDatabase {
             name: database_name,
             if_not_exists,
+            options: options.into(),
+        }))
+    }
+
+    /// Parses `CREATE FLOW [IF NOT EXISTS] <flow> SINK TO <table> AS <query>`.
+    fn parse_create_flow(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+
+        let flow_name = self
+            .parser
+            .parse_object_name()
+            .context(error::UnexpectedSnafu {
+                sql: self.sql,
+                expected: "a flow name",
+                actual: self.peek_token_as_string(),
+            })?;
+
+        let token = self.parser.next_token();
+        match &token.token {
+            Token::Word(w) if w.value.eq_ignore_ascii_case(SINK) => {}
+            _ => return self.expected("SINK TO", token),
+        }
+        self.parser
+            .expect_keyword(Keyword::TO)
+            .context(SyntaxSnafu { sql: self.sql })?;
+
+        let sink_table_name = self
+            .parser
+            .parse_object_name()
+            .context(error::UnexpectedSnafu {
+                sql: self.sql,
+                expected: "a sink table name",
+                actual: self.peek_token_as_string(),
+            })?;
+
+        self.parser
+            .expect_keyword(Keyword::AS)
+            .context(SyntaxSnafu { sql: self.sql })?;
+        let query = self
+            .parser
+            .parse_query()
+            .context(SyntaxSnafu { sql: self.sql })?;
+
+        Ok(Statement::CreateFlow(CreateFlow {
+            flow_name,
+            sink_table_name,
+            if_not_exists,
+            query: Box::new(Query::try_from(query)?),
+        }))
+    }
+
+    /// Parses `CREATE VIEW [IF NOT EXISTS] <view> AS <query>`.
+    fn parse_create_view(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+
+        let name = self
+            .parser
+            .parse_object_name()
+            .context(error::UnexpectedSnafu {
+                sql: self.sql,
+                expected: "a view name",
+                actual: self.peek_token_as_string(),
+            })?;
+
+        self.parser
+            .expect_keyword(Keyword::AS)
+            .context(SyntaxSnafu { sql: self.sql })?;
+        let query = self
+            .parser
+            .parse_query()
+            .context(SyntaxSnafu { sql: self.sql })?;
+
+        Ok(Statement::CreateView(CreateView {
+            name,
+            if_not_exists,
+            query: Box::new(Query::try_from(query)?),
         }))
     }
 
@@ -151,6 +257,10 @@ impl<'a> ParserContext<'a> {
                 actual: self.peek_token_as_string(),
             })?;
 
+        if self.parser.peek_token().token != Token::LParen {
+            return self.parse_create_table_as(if_not_exists, table_name);
+        }
+
         let (columns, constraints) = self.parse_columns()?;
 
         let partitions = self.parse_partitions()?;
@@ -183,6 +293,68 @@ impl<'a> ParserContext<'a> {
         Ok(Statement::CreateTable(create_table))
     }
 
+    /// Parses the rest of `CREATE TABLE <name> [ENGINE = <engine>] [WITH (...)] AS <query>`.
+    fn parse_create_table_as(
+        &mut self,
+        if_not_exists: bool,
+        table_name: ObjectName,
+    ) -> Result<Statement> {
+        let engine = self.parse_table_engine(default_engine())?;
+
+        let mut time_index = None;
+        let mut primary_keys = None;
+        let mut options = Vec::new();
+        for option in self
+            .parser
+            .parse_options(Keyword::WITH)
+            .context(error::SyntaxSnafu { sql: self.sql })?
+        {
+            let key = option.name.value.to_lowercase();
+            match key.as_str() {
+                TIME_INDEX_OPTION | PRIMARY_KEY_OPTION => {
+                    let value = parse_option_string(option.value)
+                        .context(InvalidTableOptionSnafu { key: &key })?;
+                    if key == TIME_INDEX_OPTION {
+                        time_index = Some(value);
+                    } else {
+                        primary_keys = Some(
+                            value
+                                .split(',')
+                                .map(|column| column.trim().to_string())
+                                .filter(|column| !column.is_empty())
+                                .collect(),
+                        );
+                    }
+                }
+                _ => {
+                    ensure!(
+                        valid_table_option(&key),
+                        InvalidTableOptionSnafu { key: &key }
+                    );
+                    options.push(option);
+                }
+            }
+        }
+
+        self.parser
+            .expect_keyword(Keyword::AS)
+            .context(error::SyntaxSnafu { sql: self.sql })?;
+        let query = self
+            .parser
+            .parse_query()
+            .context(error::SyntaxSnafu { sql: self.sql })?;
+
+        Ok(Statement::CreateTableAs(CreateTableAs {
+            if_not_exists,
+            name: table_name,
+            engine,
+            time_index,
+            primary_keys,
+            options,
+            query: Box::new(Query::try_from(query)?),
+        }))
+    }
+
     // "PARTITION BY ..." syntax:
     // https://dev.mysql.com/doc/refman/8.0/en/partitioning-columns-range.html
     fn parse_partitions(&mut self) -> Result<Option<Partitions>> {
@@ -988,9 +1160,116 @@ mod tests {
             Statement::CreateDatabase(c) => {
                 assert_eq!(c.name.to_string(), "prometheus");
                 assert!(c.if_not_exists);
+                assert!(c.options.map.is_empty());
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "create database prometheus with (TTL = '30d')";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+
+        assert_eq!(1, stmts.len());
+        match &stmts[0] {
+            Statement::CreateDatabase(c) => {
+                assert_eq!(c.name.to_string(), "prometheus");
+                assert_eq!(Some(&"30d".to_string()), c.options.get("ttl"));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_parse_create_flow() {
+        let sql = r"create flow if not exists my_flow sink to my_sink as
+select ts, host, avg(cpu) range '5m' from monitor align '1m' by (host)";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, stmts.len());
+        match &stmts[0] {
+            Statement::CreateFlow(c) => {
+                assert_eq!(c.flow_name.to_string(), "my_flow");
+                assert_eq!(c.sink_table_name.to_string(), "my_sink");
+                assert!(c.if_not_exists);
+                assert!(c.query.to_string().starts_with("SELECT ts, host"));
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "create flow my_flow as select 1";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.unwrap_err().to_string().contains("SINK TO"));
+
+        let sql = "create flow my_flow sink to my_sink";
+        assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
+    }
+
+    #[test]
+    fn test_parse_create_view() {
+        let sql = "create view if not exists my_schema.cpu_view as select ts, host, cpu from monitor where host = 'host1'";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, stmts.len());
+        match &stmts[0] {
+            Statement::CreateView(c) => {
+                assert_eq!(c.name.to_string(), "my_schema.cpu_view");
+                assert!(c.if_not_exists);
+                assert_eq!(
+                    c.query.to_string(),
+                    "SELECT ts, host, cpu FROM monitor WHERE host = 'host1'"
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "create view cpu_view select 1";
+        assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
+    }
+
+    #[test]
+    fn test_parse_create_table_as() {
+        let sql = r"create table if not exists cpu_1h with (time_index = 'ts', primary_key = 'host, idc', ttl = '7d') as
+select ts, host, idc, avg(cpu) from monitor group by ts, host, idc";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, stmts.len());
+        match &stmts[0] {
+            Statement::CreateTableAs(c) => {
+                assert_eq!(c.name.to_string(), "cpu_1h");
+                assert!(c.if_not_exists);
+                assert_eq!(c.engine, default_engine());
+                assert_eq!(c.time_index.as_deref(), Some("ts"));
+                assert_eq!(
+                    c.primary_keys,
+                    Some(vec!["host".to_string(), "idc".to_string()])
+                );
+                assert_eq!(1, c.options.len());
+                assert_eq!("ttl", c.options[0].name.value);
+                assert!(c.query.to_string().starts_with("SELECT ts, host, idc"));
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "create table t as select * from monitor";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        match &stmts[0] {
+            Statement::CreateTableAs(c) => {
+                assert!(!c.if_not_exists);
+                assert!(c.time_index.is_none());
+                assert!(c.primary_keys.is_none());
+                assert!(c.options.is_empty());
             }
             _ => unreachable!(),
         }
+
+        let sql = "create table t with (primary_key = '') as select * from monitor";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        match &stmts[0] {
+            Statement::CreateTableAs(c) => assert_eq!(c.primary_keys, Some(vec![])),
+            _ => unreachable!(),
+        }
+
+        let sql = "create table t with (foo = 'bar') as select * from monitor";
+        assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
+
+        let sql = "create table t with (ttl = '7d')";
+        assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
     }
 
     #[test]
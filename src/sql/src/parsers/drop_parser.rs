@@ -14,16 +14,28 @@
 
 use snafu::{ensure, ResultExt};
 use sqlparser::keywords::Keyword;
+use sqlparser::tokenizer::Token;
 
 use crate::error::{self, InvalidTableNameSnafu, Result};
 use crate::parser::ParserContext;
-use crate::statements::drop::DropTable;
+use crate::parsers::create_parser::FLOW;
+use crate::statements::drop::{DropDatabase, DropFlow, DropTable, DropView};
 use crate::statements::statement::Statement;
 
 /// DROP statement parser implementation
 impl<'a> ParserContext<'a> {
     pub(crate) fn parse_drop(&mut self) -> Result<Statement> {
         let _ = self.parser.next_token();
+        if self.matches_keyword(Keyword::DATABASE) || self.matches_keyword(Keyword::SCHEMA) {
+            return self.parse_drop_database();
+        }
+        if matches!(&self.parser.peek_token().token, Token::Word(w) if w.value.eq_ignore_ascii_case(FLOW))
+        {
+            return self.parse_drop_flow();
+        }
+        if self.matches_keyword(Keyword::VIEW) {
+            return self.parse_drop_view();
+        }
         if !self.matches_keyword(Keyword::TABLE) {
             return self.unsupported(self.peek_token_as_string());
         }
@@ -46,6 +58,66 @@ impl<'a> ParserContext<'a> {
 
         Ok(Statement::DropTable(DropTable::new(table_ident)))
     }
+
+    fn parse_drop_database(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+
+        let drop_if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+
+        let database_name =
+            self.parser
+                .parse_object_name()
+                .with_context(|_| error::UnexpectedSnafu {
+                    sql: self.sql,
+                    expected: "a database name",
+                    actual: self.peek_token_as_string(),
+                })?;
+
+        Ok(Statement::DropDatabase(DropDatabase::new(
+            database_name,
+            drop_if_exists,
+        )))
+    }
+
+    fn parse_drop_view(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+
+        let drop_if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+
+        let view_name =
+            self.parser
+                .parse_object_name()
+                .with_context(|_| error::UnexpectedSnafu {
+                    sql: self.sql,
+                    expected: "a view name",
+                    actual: self.peek_token_as_string(),
+                })?;
+
+        Ok(Statement::DropView(DropView::new(
+            view_name,
+            drop_if_exists,
+        )))
+    }
+
+    fn parse_drop_flow(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+
+        let drop_if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+
+        let flow_name =
+            self.parser
+                .parse_object_name()
+                .with_context(|_| error::UnexpectedSnafu {
+                    sql: self.sql,
+                    expected: "a flow name",
+                    actual: self.peek_token_as_string(),
+                })?;
+
+        Ok(Statement::DropFlow(DropFlow::new(
+            flow_name,
+            drop_if_exists,
+        )))
+    }
 }
 
 #[cfg(test)]
@@ -88,4 +160,91 @@ mod tests {
             ])))
         )
     }
+
+    #[test]
+    pub fn test_drop_database() {
+        let sql = "DROP DATABASE public";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        let mut stmts = result.unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::DropDatabase(DropDatabase::new(
+                ObjectName(vec![Ident::new("public")]),
+                false
+            ))
+        );
+
+        let sql = "DROP DATABASE IF EXISTS public";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        let mut stmts = result.unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::DropDatabase(DropDatabase::new(
+                ObjectName(vec![Ident::new("public")]),
+                true
+            ))
+        );
+
+        let sql = "DROP SCHEMA IF EXISTS public";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        let mut stmts = result.unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::DropDatabase(DropDatabase::new(
+                ObjectName(vec![Ident::new("public")]),
+                true
+            ))
+        );
+
+        let sql = "DROP DATABASE";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_drop_flow() {
+        let sql = "DROP FLOW my_flow";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        let mut stmts = result.unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::DropFlow(DropFlow::new(
+                ObjectName(vec![Ident::new("my_flow")]),
+                false
+            ))
+        );
+
+        let sql = "DROP FLOW IF EXISTS my_schema.my_flow";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        let mut stmts = result.unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::DropFlow(DropFlow::new(
+                ObjectName(vec![Ident::new("my_schema"), Ident::new("my_flow")]),
+                true
+            ))
+        );
+
+        let sql = "DROP FLOW";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_drop_view() {
+        let sql = "DROP VIEW IF EXISTS my_schema.my_view";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        let mut stmts = result.unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::DropView(DropView::new(
+                ObjectName(vec![Ident::new("my_schema"), Ident::new("my_view")]),
+                true
+            ))
+        );
+
+        let sql = "DROP VIEW";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,86 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snafu::ResultExt;
+use sqlparser::tokenizer::Token;
+
+use crate::error::{self, Result};
+use crate::parser::ParserContext;
+use crate::parsers::create_parser::FLOW;
+use crate::statements::evaluate::EvaluateFlow;
+use crate::statements::statement::Statement;
+
+pub(crate) const EVALUATE: &str = "EVALUATE";
+
+/// `EVALUATE FLOW <name>;`
+impl<'a> ParserContext<'a> {
+    pub(crate) fn parse_evaluate(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+        match self.parser.next_token().token {
+            Token::Word(w) if w.value.eq_ignore_ascii_case(FLOW) => {}
+            unexpected => return self.unsupported(unexpected.to_string()),
+        }
+
+        let flow_name =
+            self.parser
+                .parse_object_name()
+                .with_context(|_| error::UnexpectedSnafu {
+                    sql: self.sql,
+                    expected: "a flow name",
+                    actual: self.peek_token_as_string(),
+                })?;
+
+        Ok(Statement::EvaluateFlow(EvaluateFlow::new(flow_name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::ast::{Ident, ObjectName};
+
+    use super::*;
+    use crate::dialect::GreptimeDbDialect;
+
+    #[test]
+    pub fn test_parse_evaluate_flow() {
+        let sql = "EVALUATE FLOW cpu_flow";
+        let mut stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::EvaluateFlow(EvaluateFlow::new(ObjectName(vec![Ident::new("cpu_flow")])))
+        );
+
+        let sql = "evaluate flow my_db.cpu_flow;";
+        let mut stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(
+            stmts.pop().unwrap(),
+            Statement::EvaluateFlow(EvaluateFlow::new(ObjectName(vec![
+                Ident::new("my_db"),
+                Ident::new("cpu_flow")
+            ])))
+        );
+    }
+
+    #[test]
+    pub fn test_parse_invalid_evaluate_flow() {
+        for sql in [
+            "EVALUATE FLOW",
+            "EVALUATE cpu_flow",
+            "EVALUATE TABLE cpu_flow",
+        ] {
+            let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+            assert!(result.is_err(), "result is: {result:?}");
+        }
+    }
+}
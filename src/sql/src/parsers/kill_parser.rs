@@ -0,0 +1,72 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snafu::ResultExt;
+use sqlparser::keywords::Keyword;
+
+use crate::error::{self, Result};
+use crate::parser::ParserContext;
+use crate::statements::kill::Kill;
+use crate::statements::statement::Statement;
+
+/// `KILL [QUERY] id;`
+impl<'a> ParserContext<'a> {
+    pub(crate) fn parse_kill(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+        let _ = self.parser.parse_keyword(Keyword::QUERY);
+
+        let id = self
+            .parser
+            .parse_literal_uint()
+            .with_context(|_| error::UnexpectedSnafu {
+                sql: self.sql,
+                expected: "a process id",
+                actual: self.peek_token_as_string(),
+            })?;
+
+        Ok(Statement::Kill(Kill::new(id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::GreptimeDbDialect;
+
+    #[test]
+    pub fn test_parse_kill() {
+        let sql = "KILL 42";
+        let mut stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(stmts.pop().unwrap(), Statement::Kill(Kill::new(42)));
+
+        let sql = "KILL QUERY 7;";
+        let mut stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(stmts.pop().unwrap(), Statement::Kill(Kill::new(7)));
+    }
+
+    #[test]
+    pub fn test_parse_invalid_kill() {
+        let sql = "KILL";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err(), "result is: {result:?}");
+
+        let sql = "KILL CONNECTION 1";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err(), "result is: {result:?}");
+
+        let sql = "KILL QUERY foo";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err(), "result is: {result:?}");
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snafu::ResultExt;
+use sqlparser::ast::Statement as SpStatement;
+
+use crate::error::{self, Result};
+use crate::parser::ParserContext;
+use crate::statements::statement::Statement;
+use crate::statements::update::Update;
+
+/// UPDATE statement parser implementation
+impl<'a> ParserContext<'a> {
+    pub(crate) fn parse_update(&mut self) -> Result<Statement> {
+        let _ = self.parser.next_token();
+        let spstatement = self
+            .parser
+            .parse_update()
+            .context(error::SyntaxSnafu { sql: self.sql })?;
+
+        match spstatement {
+            SpStatement::Update { .. } => {
+                Ok(Statement::Update(Box::new(Update { inner: spstatement })))
+            }
+            unexp => error::UnsupportedSnafu {
+                sql: self.sql.to_string(),
+                keyword: unexp.to_string(),
+            }
+            .fail(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches::assert_matches;
+
+    use super::*;
+    use crate::dialect::GreptimeDbDialect;
+
+    #[test]
+    pub fn test_parse_update() {
+        let sql = r"update my_table set cpu = cpu * 2, memory = 1 where host = 'host1';";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(1, result.len());
+        assert_matches!(result[0], Statement::Update { .. });
+        let Statement::Update(update) = &result[0] else {
+            unreachable!()
+        };
+        assert_eq!(vec!["cpu", "memory"], update.assigned_columns());
+        assert!(update.null_assigned_columns().is_empty());
+
+        let sql = r"update my_table set cpu = NULL, memory = 1";
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        let Statement::Update(update) = &result[0] else {
+            unreachable!()
+        };
+        assert_eq!(vec!["cpu"], update.null_assigned_columns());
+    }
+
+    #[test]
+    pub fn test_parse_invalid_update() {
+        let sql = r"update my_table where host = 'host1'"; // intentionally a bad sql
+        let result = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {});
+        assert!(result.is_err(), "result is: {result:?}");
+    }
+}
@@ -18,8 +18,10 @@ pub mod create;
 pub mod delete;
 pub mod describe;
 pub mod drop;
+pub mod evaluate;
 pub mod explain;
 pub mod insert;
+pub mod kill;
 mod option_map;
 pub mod query;
 pub mod show;
@@ -27,6 +29,7 @@ pub mod statement;
 pub mod tql;
 mod transform;
 pub mod truncate;
+pub mod update;
 
 use std::str::FromStr;
 
@@ -34,20 +37,22 @@ use api::helper::ColumnDataTypeWrapper;
 use api::v1::add_column_location::LocationType;
 use api::v1::{AddColumnLocation as Location, SemanticType};
 use common_base::bytes::Bytes;
+use common_decimal::decimal128::{valid_precision_and_scale, DECIMAL128_MAX_PRECISION};
+use common_decimal::Decimal128;
 use common_query::AddColumnLocation;
 use common_time::Timestamp;
 use datatypes::prelude::ConcreteDataType;
 use datatypes::schema::{ColumnDefaultConstraint, ColumnSchema, COMMENT_KEY};
 use datatypes::types::cast::CastOption;
-use datatypes::types::{cast, TimestampType};
+use datatypes::types::{cast, parse_string_to_jsonb, TimestampType};
 use datatypes::value::{OrderedF32, OrderedF64, Value};
 pub use option_map::OptionMap;
 use snafu::{ensure, OptionExt, ResultExt};
 pub use transform::{get_data_type_by_alias_name, transform_statements};
 
 use crate::ast::{
-    ColumnDef, ColumnOption, ColumnOptionDef, DataType as SqlDataType, Expr, TimezoneInfo,
-    Value as SqlValue,
+    ColumnDef, ColumnOption, ColumnOptionDef, DataType as SqlDataType, ExactNumberInfo, Expr,
+    TimezoneInfo, Value as SqlValue,
 };
 use crate::error::{
     self, ColumnTypeMismatchSnafu, ConvertSqlValueSnafu, ConvertToGrpcDataTypeSnafu,
@@ -106,12 +111,36 @@ fn parse_string_to_value(
                 .fail()
             }
         }
+        ConcreteDataType::Decimal128(_) => parse_decimal128(&s, data_type),
+        ConcreteDataType::Json(_) => parse_string_to_jsonb(&s)
+            .map(|json| Value::Binary(Bytes::from(json)))
+            .map_err(|e| {
+                ParseSqlValueSnafu {
+                    msg: format!("Failed to parse {s} to Json value, {e}"),
+                }
+                .build()
+            }),
         _ => {
             unreachable!()
         }
     }
 }
 
+/// Parses `s` into a decimal with the precision and scale of `data_type`.
+fn parse_decimal128(s: &str, data_type: &ConcreteDataType) -> Result<Value> {
+    // Safety: callers ensure the data type is decimal128.
+    let decimal_type = data_type.as_decimal128().unwrap();
+    s.parse::<Decimal128>()
+        .and_then(|d| d.rescale(decimal_type.precision(), decimal_type.scale()))
+        .map(Value::Decimal128)
+        .map_err(|e| {
+            ParseSqlValueSnafu {
+                msg: format!("Failed to parse {s} to {data_type} value, {e}"),
+            }
+            .build()
+        })
+}
+
 fn parse_hex_string(s: &str) -> Result<Value> {
     match hex::decode(s) {
         Ok(b) => Ok(Value::Binary(Bytes::from(b))),
@@ -145,6 +174,7 @@ macro_rules! parse_number_to_value {
                 let n  = parse_sql_number::<i64>($n)?;
                 Ok(Value::Timestamp(Timestamp::new(n, t.unit())))
             },
+            ConcreteDataType::Decimal128(_) => parse_decimal128($n, $data_type),
 
             _ => ParseSqlValueSnafu {
                 msg: format!("Fail to parse number {}, invalid column type: {:?}",
@@ -245,6 +275,7 @@ pub fn value_to_sql_value(val: &Value) -> Result<SqlValue> {
         Value::UInt64(v) => SqlValue::Number(v.to_string(), false),
         Value::Float32(v) => SqlValue::Number(v.to_string(), false),
         Value::Float64(v) => SqlValue::Number(v.to_string(), false),
+        Value::Decimal128(v) => SqlValue::Number(v.to_string(), false),
         Value::Boolean(b) => SqlValue::Boolean(*b),
         Value::Date(d) => SqlValue::SingleQuotedString(d.to_string()),
         Value::DateTime(d) => SqlValue::SingleQuotedString(d.to_string()),
@@ -404,6 +435,26 @@ pub fn sql_data_type_to_concrete_data_type(data_type: &SqlDataType) -> Result<Co
             .map(|t| ConcreteDataType::timestamp_datatype(t.unit()))
             .unwrap_or(ConcreteDataType::timestamp_millisecond_datatype())),
         SqlDataType::Interval => Ok(ConcreteDataType::interval_month_day_nano_datatype()),
+        SqlDataType::JSON => Ok(ConcreteDataType::json_datatype()),
+        SqlDataType::Decimal(exact_info) | SqlDataType::Numeric(exact_info) => {
+            let (precision, scale) = match exact_info {
+                ExactNumberInfo::None => return Ok(ConcreteDataType::decimal128_default_datatype()),
+                ExactNumberInfo::Precision(p) => (*p, 0),
+                ExactNumberInfo::PrecisionAndScale(p, s) => (*p, *s),
+            };
+            ensure!(
+                precision <= DECIMAL128_MAX_PRECISION as u64
+                    && scale <= precision
+                    && valid_precision_and_scale(precision as u8, scale as i8).is_ok(),
+                error::SqlTypeNotSupportedSnafu {
+                    t: data_type.clone(),
+                }
+            );
+            Ok(ConcreteDataType::decimal128_datatype(
+                precision as u8,
+                scale as i8,
+            ))
+        }
         _ => error::SqlTypeNotSupportedSnafu {
             t: data_type.clone(),
         }
@@ -437,6 +488,10 @@ pub fn concrete_data_type_to_sql_data_type(data_type: &ConcreteDataType) -> Resu
         )),
         ConcreteDataType::Interval(_) => Ok(SqlDataType::Interval),
         ConcreteDataType::Binary(_) => Ok(SqlDataType::Varbinary(None)),
+        ConcreteDataType::Json(_) => Ok(SqlDataType::JSON),
+        ConcreteDataType::Decimal128(d) => Ok(SqlDataType::Decimal(
+            ExactNumberInfo::PrecisionAndScale(d.precision() as u64, d.scale() as u64),
+        )),
         ConcreteDataType::Duration(_)
         | ConcreteDataType::Null(_)
         | ConcreteDataType::List(_)
@@ -467,6 +522,8 @@ mod tests {
     use std::assert_matches::assert_matches;
 
     use api::v1::ColumnDataType;
+    use common_error::ext::ErrorExt;
+    use common_error::status_code::StatusCode;
     use common_time::timestamp::TimeUnit;
     use datatypes::types::BooleanType;
     use datatypes::value::OrderedFloat;
@@ -543,6 +600,27 @@ mod tests {
             SqlDataType::Interval,
             ConcreteDataType::interval_month_day_nano_datatype(),
         );
+        check_type(
+            SqlDataType::Decimal(ExactNumberInfo::PrecisionAndScale(10, 2)),
+            ConcreteDataType::decimal128_datatype(10, 2),
+        );
+        check_type(
+            SqlDataType::Numeric(ExactNumberInfo::Precision(5)),
+            ConcreteDataType::decimal128_datatype(5, 0),
+        );
+        check_type(
+            SqlDataType::Decimal(ExactNumberInfo::None),
+            ConcreteDataType::decimal128_default_datatype(),
+        );
+        check_type(SqlDataType::JSON, ConcreteDataType::json_datatype());
+        assert!(sql_data_type_to_concrete_data_type(&SqlDataType::Decimal(
+            ExactNumberInfo::PrecisionAndScale(39, 2)
+        ))
+        .is_err());
+        assert!(sql_data_type_to_concrete_data_type(&SqlDataType::Decimal(
+            ExactNumberInfo::PrecisionAndScale(5, 6)
+        ))
+        .is_err());
     }
 
     #[test]
@@ -567,6 +645,12 @@ mod tests {
         .unwrap();
         assert_eq!(Value::Timestamp(Timestamp::new_millisecond(999999)), v);
 
+        let v = sql_number_to_value(&ConcreteDataType::decimal128_datatype(10, 2), "3.1").unwrap();
+        assert_eq!(Value::Decimal128(Decimal128::new(310, 10, 2)), v);
+
+        let v = sql_number_to_value(&ConcreteDataType::decimal128_datatype(3, 2), "123.4");
+        assert!(v.is_err(), "parse value error is: {v:?}");
+
         let v = sql_number_to_value(&ConcreteDataType::string_datatype(), "999");
         assert!(v.is_err(), "parse value error is: {v:?}");
     }
@@ -641,6 +725,35 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_parse_json_literal() {
+        let value = sql_value_to_value(
+            "json_col",
+            &ConcreteDataType::json_datatype(),
+            &SqlValue::SingleQuotedString(r#"{"a": 1, "b": [true, null]}"#.to_string()),
+        )
+        .unwrap();
+        if let Value::Binary(b) = value {
+            assert_eq!(
+                r#"{"a":1,"b":[true,null]}"#,
+                datatypes::types::jsonb_to_string(&b)
+            );
+        } else {
+            unreachable!()
+        }
+
+        let v = sql_value_to_value(
+            "json_col",
+            &ConcreteDataType::json_datatype(),
+            &SqlValue::SingleQuotedString("{not json".to_string()),
+        );
+        assert!(v.is_err());
+        assert!(
+            format!("{v:?}").contains("Failed to parse {not json to Json value"),
+            "v is {v:?}"
+        );
+    }
+
     #[test]
     pub fn test_parse_datetime_literal() {
         std::env::set_var("TZ", "Asia/Shanghai");
@@ -799,6 +912,21 @@ mod tests {
         assert!(!grpc_column_def.is_nullable);
     }
 
+    #[test]
+    pub fn test_json_column_def_to_grpc_column_def() {
+        // The protocol can't mark a binary column as JSON yet.
+        let column_def = ColumnDef {
+            name: "col".into(),
+            data_type: SqlDataType::JSON,
+            collation: None,
+            options: vec![],
+        };
+
+        let err = sql_column_def_to_grpc_column_def(&column_def).unwrap_err();
+        assert_matches!(err, error::Error::ConvertToGrpcDataType { .. });
+        assert_eq!(StatusCode::Unsupported, err.status_code());
+    }
+
     #[test]
     pub fn test_has_primary_key_option() {
         let column_def = ColumnDef {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use common_query::AddColumnLocation;
-use sqlparser::ast::{ColumnDef, Ident, ObjectName, TableConstraint};
+use sqlparser::ast::{ColumnDef, DataType, Ident, ObjectName, TableConstraint};
 use sqlparser_derive::{Visit, VisitMut};
 
 #[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
@@ -50,6 +50,46 @@ pub enum AlterTableOperation {
     },
     /// `DROP COLUMN <name>`
     DropColumn { name: Ident },
+    /// `MODIFY [ COLUMN ] <column_name> <target_type>`
+    ModifyColumnType {
+        column_name: Ident,
+        target_type: DataType,
+    },
+    /// `SET <option_name> = <option_value> [, ...]`
+    SetTableOptions { options: Vec<(String, String)> },
+    /// `UNSET <option_name> [, ...]`
+    UnsetTableOptions { keys: Vec<String> },
     /// `RENAME <new_table_name>`
     RenameTable { new_table_name: String },
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct AlterDatabase {
+    database_name: ObjectName,
+    alter_operation: AlterDatabaseOperation,
+}
+
+impl AlterDatabase {
+    pub(crate) fn new(database_name: ObjectName, alter_operation: AlterDatabaseOperation) -> Self {
+        Self {
+            database_name,
+            alter_operation,
+        }
+    }
+
+    pub fn database_name(&self) -> &ObjectName {
+        &self.database_name
+    }
+
+    pub fn alter_operation(&self) -> &AlterDatabaseOperation {
+        &self.alter_operation
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub enum AlterDatabaseOperation {
+    /// `SET <option_name> = <option_value> [, ...]`
+    SetDatabaseOptions { options: Vec<(String, String)> },
+    /// `UNSET <option_name> [, ...]`
+    UnsetDatabaseOptions { keys: Vec<String> },
+}
@@ -19,6 +19,7 @@ use itertools::Itertools;
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::ast::{ColumnDef, Ident, ObjectName, SqlOption, TableConstraint, Value as SqlValue};
+use crate::statements::query::Query;
 use crate::statements::OptionMap;
 
 const LINE_SEP: &str = ",\n";
@@ -203,6 +204,48 @@ pub struct CreateDatabase {
     pub name: ObjectName,
     /// Create if not exists
     pub if_not_exists: bool,
+    /// Database options in `WITH`, they are defaults of tables in the database.
+    pub options: OptionMap,
+}
+
+/// CREATE TABLE ... AS SELECT statement, columns of the table are the output columns of
+/// the query.
+#[derive(Debug, PartialEq, Eq, Clone, Visit, VisitMut)]
+pub struct CreateTableAs {
+    /// Create if not exists
+    pub if_not_exists: bool,
+    /// Table name
+    pub name: ObjectName,
+    pub engine: String,
+    /// Time index column given by option `time_index`, it's inferred from the query
+    /// if there is only one timestamp column in the output.
+    pub time_index: Option<String>,
+    /// Primary key columns given by option `primary_key`, `None` if the option is absent.
+    /// An empty option means the table has no primary key.
+    pub primary_keys: Option<Vec<String>>,
+    /// Table options in `WITH`, except `time_index` and `primary_key`.
+    pub options: Vec<SqlOption>,
+    pub query: Box<Query>,
+}
+
+/// CREATE FLOW statement, the flow continuously evaluates the query and writes results
+/// into the sink table.
+#[derive(Debug, PartialEq, Eq, Clone, Visit, VisitMut)]
+pub struct CreateFlow {
+    pub flow_name: ObjectName,
+    pub sink_table_name: ObjectName,
+    /// Create if not exists
+    pub if_not_exists: bool,
+    pub query: Box<Query>,
+}
+
+/// CREATE VIEW statement, the view is a named query which is stored as SQL text.
+#[derive(Debug, PartialEq, Eq, Clone, Visit, VisitMut)]
+pub struct CreateView {
+    pub name: ObjectName,
+    /// Create if not exists
+    pub if_not_exists: bool,
+    pub query: Box<Query>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Visit, VisitMut)]
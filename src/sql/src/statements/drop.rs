@@ -31,3 +31,81 @@ impl DropTable {
         &self.table_name
     }
 }
+
+/// DROP DATABASE statement.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct DropDatabase {
+    name: ObjectName,
+    /// Drop if exists
+    drop_if_exists: bool,
+}
+
+impl DropDatabase {
+    /// Creates a statement for `DROP DATABASE`
+    pub fn new(name: ObjectName, drop_if_exists: bool) -> Self {
+        Self {
+            name,
+            drop_if_exists,
+        }
+    }
+
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    pub fn drop_if_exists(&self) -> bool {
+        self.drop_if_exists
+    }
+}
+
+/// DROP VIEW statement.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct DropView {
+    view_name: ObjectName,
+    /// Drop if exists
+    drop_if_exists: bool,
+}
+
+impl DropView {
+    /// Creates a statement for `DROP VIEW`
+    pub fn new(view_name: ObjectName, drop_if_exists: bool) -> Self {
+        Self {
+            view_name,
+            drop_if_exists,
+        }
+    }
+
+    pub fn view_name(&self) -> &ObjectName {
+        &self.view_name
+    }
+
+    pub fn drop_if_exists(&self) -> bool {
+        self.drop_if_exists
+    }
+}
+
+/// DROP FLOW statement.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct DropFlow {
+    flow_name: ObjectName,
+    /// Drop if exists
+    drop_if_exists: bool,
+}
+
+impl DropFlow {
+    /// Creates a statement for `DROP FLOW`
+    pub fn new(flow_name: ObjectName, drop_if_exists: bool) -> Self {
+        Self {
+            flow_name,
+            drop_if_exists,
+        }
+    }
+
+    pub fn flow_name(&self) -> &ObjectName {
+        &self.flow_name
+    }
+
+    pub fn drop_if_exists(&self) -> bool {
+        self.drop_if_exists
+    }
+}
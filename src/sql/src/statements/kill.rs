@@ -0,0 +1,33 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser_derive::{Visit, VisitMut};
+
+/// `KILL [QUERY] id` statement, cancels the running query with the id listed in
+/// `SHOW PROCESSLIST`.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct Kill {
+    id: u64,
+}
+
+impl Kill {
+    /// Creates a statement for `KILL`
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
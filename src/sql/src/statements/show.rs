@@ -62,6 +62,19 @@ pub struct ShowCreateTable {
     pub table_name: ObjectName,
 }
 
+/// SQL structure for `SHOW CREATE VIEW`.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct ShowCreateView {
+    pub view_name: ObjectName,
+}
+
+/// SQL structure for `SHOW [FULL] PROCESSLIST`.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct ShowProcesslist {
+    /// Whether to show the full statements instead of their first 100 characters.
+    pub full: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use std::assert_matches::assert_matches;
@@ -134,9 +147,44 @@ mod tests {
             }
         }
     }
+    #[test]
+    pub fn test_show_create_view() {
+        let sql = "SHOW CREATE VIEW my_schema.my_view";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        match &stmts[0] {
+            Statement::ShowCreateView(show) => {
+                assert_eq!(show.view_name.to_string(), "my_schema.my_view");
+            }
+            _ => unreachable!(),
+        }
+
+        let sql = "SHOW CREATE VIEW";
+        assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
+    }
+
     #[test]
     pub fn test_show_create_missing_table_name() {
         let sql = "SHOW CREATE TABLE";
         assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
     }
+
+    #[test]
+    pub fn test_show_processlist() {
+        let sql = "SHOW PROCESSLIST";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(
+            vec![Statement::ShowProcesslist(ShowProcesslist { full: false })],
+            stmts
+        );
+
+        let sql = "SHOW FULL PROCESSLIST";
+        let stmts = ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).unwrap();
+        assert_eq!(
+            vec![Statement::ShowProcesslist(ShowProcesslist { full: true })],
+            stmts
+        );
+
+        let sql = "SHOW FULL TABLES";
+        assert!(ParserContext::create_with_dialect(sql, &GreptimeDbDialect {}).is_err());
+    }
 }
@@ -17,17 +17,24 @@ use sqlparser::ast::Statement as SpStatement;
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::error::{ConvertToDfStatementSnafu, Error};
-use crate::statements::alter::AlterTable;
-use crate::statements::create::{CreateDatabase, CreateExternalTable, CreateTable};
+use crate::statements::alter::{AlterDatabase, AlterTable};
+use crate::statements::create::{
+    CreateDatabase, CreateExternalTable, CreateFlow, CreateTable, CreateTableAs, CreateView,
+};
 use crate::statements::delete::Delete;
 use crate::statements::describe::DescribeTable;
-use crate::statements::drop::DropTable;
+use crate::statements::drop::{DropDatabase, DropFlow, DropTable, DropView};
+use crate::statements::evaluate::EvaluateFlow;
 use crate::statements::explain::Explain;
 use crate::statements::insert::Insert;
+use crate::statements::kill::Kill;
 use crate::statements::query::Query;
-use crate::statements::show::{ShowCreateTable, ShowDatabases, ShowTables};
+use crate::statements::show::{
+    ShowCreateTable, ShowCreateView, ShowDatabases, ShowProcesslist, ShowTables,
+};
 use crate::statements::tql::Tql;
 use crate::statements::truncate::TruncateTable;
+use crate::statements::update::Update;
 
 /// Tokens parsed by `DFParser` are converted into these values.
 #[allow(clippy::large_enum_variant)]
@@ -39,22 +46,40 @@ pub enum Statement {
     Insert(Box<Insert>),
     // Delete
     Delete(Box<Delete>),
+    // Update
+    Update(Box<Update>),
     /// CREATE TABLE
     CreateTable(CreateTable),
     // CREATE EXTERNAL TABLE
     CreateExternalTable(CreateExternalTable),
+    // CREATE TABLE ... AS SELECT
+    CreateTableAs(CreateTableAs),
     // DROP TABLE
     DropTable(DropTable),
+    // DROP DATABASE
+    DropDatabase(DropDatabase),
     // CREATE DATABASE
     CreateDatabase(CreateDatabase),
     /// ALTER TABLE
     Alter(AlterTable),
+    // ALTER DATABASE
+    AlterDatabase(AlterDatabase),
+    // CREATE FLOW
+    CreateFlow(CreateFlow),
+    // DROP FLOW
+    DropFlow(DropFlow),
+    // CREATE VIEW
+    CreateView(CreateView),
+    // DROP VIEW
+    DropView(DropView),
     // Databases.
     ShowDatabases(ShowDatabases),
     // SHOW TABLES
     ShowTables(ShowTables),
     // SHOW CREATE TABLE
     ShowCreateTable(ShowCreateTable),
+    // SHOW CREATE VIEW
+    ShowCreateView(ShowCreateView),
     // DESCRIBE TABLE
     DescribeTable(DescribeTable),
     // EXPLAIN QUERY
@@ -64,6 +89,12 @@ pub enum Statement {
     Tql(Tql),
     // TRUNCATE TABLE
     TruncateTable(TruncateTable),
+    // SHOW [FULL] PROCESSLIST
+    ShowProcesslist(ShowProcesslist),
+    // KILL [QUERY] id
+    Kill(Kill),
+    // EVALUATE FLOW <name>
+    EvaluateFlow(EvaluateFlow),
 }
 
 /// Comment hints from SQL.
@@ -85,6 +116,7 @@ impl TryFrom<&Statement> for DfStatement {
             Statement::Explain(explain) => explain.inner.clone(),
             Statement::Insert(insert) => insert.inner.clone(),
             Statement::Delete(delete) => delete.inner.clone(),
+            Statement::Update(update) => update.inner.clone(),
             _ => {
                 return ConvertToDfStatementSnafu {
                     statement: format!("{s:?}"),
@@ -0,0 +1,52 @@
+// Copyright 2023 Greptime Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::ast::{Expr, Statement, Value};
+use sqlparser_derive::{Visit, VisitMut};
+
+/// `UPDATE <table> SET <column> = <expr> [, ...] [WHERE <expr>]`
+///
+/// The update reads the matching rows and puts them back with the new values, it's not
+/// atomic: rows written concurrently between the read and the put may be overwritten, and
+/// a failed update may leave some of the rows updated.
+#[derive(Debug, Clone, PartialEq, Eq, Visit, VisitMut)]
+pub struct Update {
+    // Can only be sqlparser::ast::Statement::Update variant
+    pub inner: Statement,
+}
+
+impl Update {
+    /// Returns the names of the columns assigned in the SET clause.
+    pub fn assigned_columns(&self) -> Vec<&String> {
+        match &self.inner {
+            Statement::Update { assignments, .. } => assignments
+                .iter()
+                .filter_map(|assignment| assignment.id.last().map(|ident| &ident.value))
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the names of the columns assigned to a `NULL` literal in the SET clause.
+    pub fn null_assigned_columns(&self) -> Vec<&String> {
+        match &self.inner {
+            Statement::Update { assignments, .. } => assignments
+                .iter()
+                .filter(|assignment| matches!(assignment.value, Expr::Value(Value::Null)))
+                .filter_map(|assignment| assignment.id.last().map(|ident| &ident.value))
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+}
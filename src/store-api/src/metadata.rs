@@ -32,7 +32,7 @@ use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize};
 use snafu::{ensure, Location, OptionExt, ResultExt, Snafu};
 
-use crate::region_request::{AddColumn, AddColumnLocation, AlterKind};
+use crate::region_request::{AddColumn, AddColumnLocation, AlterKind, ModifyColumnType};
 use crate::storage::consts::is_internal_column;
 use crate::storage::{ColumnId, RegionId};
 
@@ -436,6 +436,9 @@ impl RegionMetadataBuilder {
         match kind {
             AlterKind::AddColumns { columns } => self.add_columns(columns)?,
             AlterKind::DropColumns { names } => self.drop_columns(&names),
+            AlterKind::ModifyColumnTypes { columns } => self.modify_column_types(&columns)?,
+            // Options are not a part of the metadata.
+            AlterKind::SetRegionOptions { .. } | AlterKind::UnsetRegionOptions { .. } => {}
         }
         Ok(self)
     }
@@ -516,6 +519,46 @@ impl RegionMetadataBuilder {
         self.column_metadatas
             .retain(|col| !name_set.contains(&col.column_schema.name));
     }
+
+    /// Changes data types of columns if exist.
+    ///
+    /// The default value of a column is cast to the new type.
+    fn modify_column_types(&mut self, columns: &[ModifyColumnType]) -> Result<()> {
+        for modify_column in columns {
+            let Some(column) = self
+                .column_metadatas
+                .iter_mut()
+                .find(|col| col.column_schema.name == modify_column.column_name)
+            else {
+                continue;
+            };
+
+            let target_type = &modify_column.target_type;
+            let default_constraint = match column.column_schema.default_constraint() {
+                Some(ColumnDefaultConstraint::Value(value)) if !value.is_null() => {
+                    let value = target_type.try_cast(value.clone()).with_context(|| {
+                        InvalidRegionRequestSnafu {
+                            region_id: self.region_id,
+                            err: format!(
+                                "default value of column {} could not be cast to {:?}",
+                                modify_column.column_name, target_type
+                            ),
+                        }
+                    })?;
+                    Some(ColumnDefaultConstraint::Value(value))
+                }
+                constraint => constraint.cloned(),
+            };
+
+            let mut column_schema = column.column_schema.clone();
+            column_schema.data_type = target_type.clone();
+            column.column_schema = column_schema
+                .with_default_constraint(default_constraint)
+                .context(InvalidSchemaSnafu)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Fields skipped in serialization.
@@ -611,6 +654,7 @@ impl ErrorExt for MetadataError {
 mod test {
     use datatypes::prelude::ConcreteDataType;
     use datatypes::schema::ColumnSchema;
+    use datatypes::value::Value;
 
     use super::*;
 
@@ -1090,6 +1134,53 @@ mod test {
         check_columns(&metadata, &["a", "c", "d"]);
     }
 
+    #[test]
+    fn test_modify_column_types() {
+        // a (tag), b (field), c (ts)
+        let metadata = build_test_region_metadata();
+        let mut builder = RegionMetadataBuilder::from_existing(metadata);
+        // field d with default value.
+        builder
+            .alter(AlterKind::AddColumns {
+                columns: vec![AddColumn {
+                    column_metadata: ColumnMetadata {
+                        column_schema: ColumnSchema::new(
+                            "d",
+                            ConcreteDataType::int32_datatype(),
+                            true,
+                        )
+                        .with_default_constraint(Some(ColumnDefaultConstraint::Value(
+                            Value::Int32(1),
+                        )))
+                        .unwrap(),
+                        semantic_type: SemanticType::Field,
+                        column_id: 4,
+                    },
+                    location: None,
+                }],
+            })
+            .unwrap();
+        let metadata = builder.build().unwrap();
+
+        let mut builder = RegionMetadataBuilder::from_existing(metadata);
+        builder
+            .alter(AlterKind::ModifyColumnTypes {
+                columns: vec![ModifyColumnType {
+                    column_name: "d".to_string(),
+                    target_type: ConcreteDataType::int64_datatype(),
+                }],
+            })
+            .unwrap();
+        let metadata = builder.build().unwrap();
+        check_columns(&metadata, &["a", "b", "c", "d"]);
+        let column_schema = &metadata.column_by_name("d").unwrap().column_schema;
+        assert_eq!(ConcreteDataType::int64_datatype(), column_schema.data_type);
+        assert_eq!(
+            Some(&ColumnDefaultConstraint::Value(Value::Int64(1))),
+            column_schema.default_constraint()
+        );
+    }
+
     #[test]
     fn test_invalid_column_name() {
         let mut builder = create_builder();
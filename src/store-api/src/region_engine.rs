@@ -25,6 +25,24 @@ use crate::metadata::RegionMetadataRef;
 use crate::region_request::RegionRequest;
 use crate::storage::{RegionId, ScanRequest};
 
+/// Statistics of a region.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegionStatistic {
+    /// Approximate number of rows in the region.
+    pub num_rows: u64,
+    /// Bytes allocated by memtables of the region.
+    pub memtable_size: u64,
+    /// Bytes of SST files of the region.
+    pub sst_size: u64,
+}
+
+impl RegionStatistic {
+    /// Returns the approximate size of the region in bytes.
+    pub fn approximate_bytes(&self) -> u64 {
+        self.memtable_size + self.sst_size
+    }
+}
+
 #[async_trait]
 pub trait RegionEngine: Send + Sync {
     /// Name of this engine
@@ -58,6 +76,12 @@ pub trait RegionEngine: Send + Sync {
     /// the region as readonly doesn't guarantee that write operations in progress will not
     /// take effect.
     fn set_writable(&self, region_id: RegionId, writable: bool) -> Result<(), BoxedError>;
+
+    /// Returns the statistic of the region, or `None` if the region doesn't exist
+    /// or the engine doesn't collect statistics.
+    fn region_statistic(&self, _region_id: RegionId) -> Option<RegionStatistic> {
+        None
+    }
 }
 
 pub type RegionEngineRef = Arc<dyn RegionEngine>;
@@ -18,6 +18,7 @@ use std::fmt;
 use api::v1::add_column_location::LocationType;
 use api::v1::region::{alter_request, region_request, AlterRequest};
 use api::v1::{self, Rows, SemanticType};
+use datatypes::prelude::ConcreteDataType;
 use snafu::{ensure, OptionExt};
 
 use crate::metadata::{
@@ -243,6 +244,21 @@ pub enum AlterKind {
         /// Name of columns to drop.
         names: Vec<String>,
     },
+    /// Modify data types of columns, only fields are allowed to modify.
+    ModifyColumnTypes {
+        /// Columns to modify.
+        columns: Vec<ModifyColumnType>,
+    },
+    /// Set options of the region.
+    SetRegionOptions {
+        /// Options to set.
+        options: HashMap<String, String>,
+    },
+    /// Unset options of the region, the options fall back to their defaults.
+    UnsetRegionOptions {
+        /// Keys of the options to unset.
+        keys: Vec<String>,
+    },
 }
 
 impl AlterKind {
@@ -261,6 +277,13 @@ impl AlterKind {
                     Self::validate_column_to_drop(name, metadata)?;
                 }
             }
+            AlterKind::ModifyColumnTypes { columns } => {
+                for col_to_modify in columns {
+                    col_to_modify.validate(metadata)?;
+                }
+            }
+            // Options are validated by the engine.
+            AlterKind::SetRegionOptions { .. } | AlterKind::UnsetRegionOptions { .. } => {}
         }
         Ok(())
     }
@@ -275,6 +298,11 @@ impl AlterKind {
             AlterKind::DropColumns { names } => names
                 .iter()
                 .any(|name| metadata.column_by_name(name).is_some()),
+            AlterKind::ModifyColumnTypes { columns } => columns
+                .iter()
+                .any(|col_to_modify| col_to_modify.need_alter(metadata)),
+            AlterKind::SetRegionOptions { options } => !options.is_empty(),
+            AlterKind::UnsetRegionOptions { keys } => !keys.is_empty(),
         }
     }
 
@@ -360,6 +388,40 @@ impl AddColumn {
     }
 }
 
+/// Returns true if every value of the `from` type can be cast to the `to` type exactly.
+fn is_widening_cast(from: &ConcreteDataType, to: &ConcreteDataType) -> bool {
+    use ConcreteDataType::{
+        Float32, Float64, Int16, Int32, Int64, Int8, UInt16, UInt32, UInt64, UInt8,
+    };
+
+    from == to
+        || matches!(
+            (from, to),
+            (
+                Int8(_),
+                Int16(_) | Int32(_) | Int64(_) | Float32(_) | Float64(_)
+            ) | (Int16(_), Int32(_) | Int64(_) | Float32(_) | Float64(_))
+                | (Int32(_), Int64(_) | Float64(_))
+                | (
+                    UInt8(_),
+                    UInt16(_)
+                        | UInt32(_)
+                        | UInt64(_)
+                        | Int16(_)
+                        | Int32(_)
+                        | Int64(_)
+                        | Float32(_)
+                        | Float64(_)
+                )
+                | (
+                    UInt16(_),
+                    UInt32(_) | UInt64(_) | Int32(_) | Int64(_) | Float32(_) | Float64(_)
+                )
+                | (UInt32(_), UInt64(_) | Int64(_) | Float64(_))
+                | (Float32(_), Float64(_))
+        )
+}
+
 impl TryFrom<v1::region::AddColumn> for AddColumn {
     type Error = MetadataError;
 
@@ -383,6 +445,61 @@ impl TryFrom<v1::region::AddColumn> for AddColumn {
     }
 }
 
+/// Modifies the data type of a column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModifyColumnType {
+    /// Name of the column to modify.
+    pub column_name: String,
+    /// Data type to cast the column to.
+    pub target_type: ConcreteDataType,
+}
+
+impl ModifyColumnType {
+    /// Returns an error if the column to modify is invalid.
+    ///
+    /// Only existing fields can be modified, and only to a type that holds every value of
+    /// the old type, since values in old files are cast to the new type while reading.
+    pub fn validate(&self, metadata: &RegionMetadata) -> Result<()> {
+        let column = metadata
+            .column_by_name(&self.column_name)
+            .with_context(|| InvalidRegionRequestSnafu {
+                region_id: metadata.region_id,
+                err: format!("column {} not found", self.column_name),
+            })?;
+        ensure!(
+            column.semantic_type == SemanticType::Field,
+            InvalidRegionRequestSnafu {
+                region_id: metadata.region_id,
+                err: format!(
+                    "column {} is not a field and could not be modified",
+                    self.column_name
+                ),
+            }
+        );
+        ensure!(
+            is_widening_cast(&column.column_schema.data_type, &self.target_type),
+            InvalidRegionRequestSnafu {
+                region_id: metadata.region_id,
+                err: format!(
+                    "column {} of type {:?} could not be cast to {:?} without losing values",
+                    self.column_name, column.column_schema.data_type, self.target_type
+                ),
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Returns true if the column has a different type from the target type.
+    pub fn need_alter(&self, metadata: &RegionMetadata) -> bool {
+        debug_assert!(self.validate(metadata).is_ok());
+        metadata
+            .column_by_name(&self.column_name)
+            .map(|column| column.column_schema.data_type != self.target_type)
+            .unwrap_or(false)
+    }
+}
+
 /// Location to add a column.
 #[derive(Debug, PartialEq, Eq)]
 pub enum AddColumnLocation {
@@ -686,6 +803,138 @@ mod tests {
         assert!(kind.need_alter(&metadata));
     }
 
+    #[test]
+    fn test_validate_modify_column_type() {
+        let mut builder = RegionMetadataBuilder::from_existing(new_metadata());
+        builder.push_column_metadata(ColumnMetadata {
+            column_schema: ColumnSchema::new("field_1", ConcreteDataType::int32_datatype(), true),
+            semantic_type: SemanticType::Field,
+            column_id: 4,
+        });
+        let metadata = builder.build().unwrap();
+        let modify_column = |name: &str, target_type| AlterKind::ModifyColumnTypes {
+            columns: vec![ModifyColumnType {
+                column_name: name.to_string(),
+                target_type,
+            }],
+        };
+
+        // Column not found.
+        modify_column("xxxx", ConcreteDataType::int64_datatype())
+            .validate(&metadata)
+            .unwrap_err();
+        // Not a field.
+        modify_column("tag_0", ConcreteDataType::int64_datatype())
+            .validate(&metadata)
+            .unwrap_err();
+        modify_column("ts", ConcreteDataType::int64_datatype())
+            .validate(&metadata)
+            .unwrap_err();
+
+        let kind = modify_column("field_0", ConcreteDataType::string_datatype());
+        kind.validate(&metadata).unwrap();
+        assert!(!kind.need_alter(&metadata));
+
+        // Narrowing or lossy casts.
+        for target_type in [
+            ConcreteDataType::int64_datatype(),
+            ConcreteDataType::binary_datatype(),
+        ] {
+            modify_column("field_0", target_type)
+                .validate(&metadata)
+                .unwrap_err();
+        }
+        for target_type in [
+            ConcreteDataType::int16_datatype(),
+            ConcreteDataType::uint32_datatype(),
+            ConcreteDataType::uint64_datatype(),
+            ConcreteDataType::float32_datatype(),
+            ConcreteDataType::string_datatype(),
+        ] {
+            modify_column("field_1", target_type)
+                .validate(&metadata)
+                .unwrap_err();
+        }
+
+        // Widening casts.
+        for target_type in [
+            ConcreteDataType::int64_datatype(),
+            ConcreteDataType::float64_datatype(),
+        ] {
+            let kind = modify_column("field_1", target_type);
+            kind.validate(&metadata).unwrap();
+            assert!(kind.need_alter(&metadata));
+        }
+    }
+
+    #[test]
+    fn test_is_widening_cast() {
+        let widening = [
+            (
+                ConcreteDataType::int8_datatype(),
+                ConcreteDataType::int16_datatype(),
+            ),
+            (
+                ConcreteDataType::int32_datatype(),
+                ConcreteDataType::int64_datatype(),
+            ),
+            (
+                ConcreteDataType::uint8_datatype(),
+                ConcreteDataType::int16_datatype(),
+            ),
+            (
+                ConcreteDataType::uint32_datatype(),
+                ConcreteDataType::int64_datatype(),
+            ),
+            (
+                ConcreteDataType::int16_datatype(),
+                ConcreteDataType::float32_datatype(),
+            ),
+            (
+                ConcreteDataType::int32_datatype(),
+                ConcreteDataType::float64_datatype(),
+            ),
+            (
+                ConcreteDataType::float32_datatype(),
+                ConcreteDataType::float64_datatype(),
+            ),
+        ];
+        for (from, to) in widening {
+            assert!(is_widening_cast(&from, &to), "{from:?} -> {to:?}");
+            assert!(!is_widening_cast(&to, &from), "{to:?} -> {from:?}");
+        }
+
+        let lossy = [
+            (
+                ConcreteDataType::int8_datatype(),
+                ConcreteDataType::uint64_datatype(),
+            ),
+            (
+                ConcreteDataType::int32_datatype(),
+                ConcreteDataType::float32_datatype(),
+            ),
+            (
+                ConcreteDataType::int64_datatype(),
+                ConcreteDataType::float64_datatype(),
+            ),
+            (
+                ConcreteDataType::uint64_datatype(),
+                ConcreteDataType::int64_datatype(),
+            ),
+            (
+                ConcreteDataType::float64_datatype(),
+                ConcreteDataType::int64_datatype(),
+            ),
+            (
+                ConcreteDataType::int64_datatype(),
+                ConcreteDataType::string_datatype(),
+            ),
+        ];
+        for (from, to) in lossy {
+            assert!(!is_widening_cast(&from, &to), "{from:?} -> {to:?}");
+        }
+    }
+
     #[test]
     fn test_validate_schema_version() {
         let mut metadata = new_metadata();
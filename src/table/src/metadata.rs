@@ -27,7 +27,7 @@ use snafu::{ensure, ResultExt};
 use store_api::storage::{ColumnDescriptor, ColumnDescriptorBuilder, ColumnId, RegionId};
 
 use crate::error::{self, Result};
-use crate::requests::{AddColumnRequest, AlterKind, TableOptions};
+use crate::requests::{AddColumnRequest, AlterKind, TableOptions, VIEW_DEFINITION_KEY};
 
 pub type TableId = u32;
 pub type TableVersion = u64;
@@ -514,6 +514,18 @@ impl TableInfo {
     pub fn full_table_name(&self) -> String {
         common_catalog::format_full_table_name(&self.catalog_name, &self.schema_name, &self.name)
     }
+
+    /// Returns the SQL definition if the table is a view.
+    pub fn view_definition(&self) -> Option<&str> {
+        if self.table_type != TableType::View {
+            return None;
+        }
+        self.meta
+            .options
+            .extra_options
+            .get(VIEW_DEFINITION_KEY)
+            .map(String::as_str)
+    }
 }
 
 impl TableInfoBuilder {
@@ -696,6 +708,36 @@ mod tests {
         assert_eq!(info, info_new);
     }
 
+    #[test]
+    fn test_view_definition() {
+        let mut options = TableOptions::default();
+        let _ = options.extra_options.insert(
+            VIEW_DEFINITION_KEY.to_string(),
+            "SELECT col1 FROM t".to_string(),
+        );
+        let meta = TableMetaBuilder::default()
+            .schema(Arc::new(new_test_schema()))
+            .primary_key_indices(vec![])
+            .next_column_id(3)
+            .options(options)
+            .build()
+            .unwrap();
+        let builder = |table_type| {
+            TableInfoBuilder::default()
+                .name("myview")
+                .meta(meta.clone())
+                .table_type(table_type)
+                .build()
+                .unwrap()
+        };
+
+        let info = builder(TableType::View);
+        assert_eq!(Some("SELECT col1 FROM t"), info.view_definition());
+        // Only views have definitions.
+        let info = builder(TableType::Base);
+        assert_eq!(None, info.view_definition());
+    }
+
     fn add_columns_to_meta(meta: &TableMeta) -> TableMeta {
         let new_tag = ColumnSchema::new("my_tag", ConcreteDataType::string_datatype(), true);
         let new_field = ColumnSchema::new("my_field", ConcreteDataType::string_datatype(), true);
@@ -33,6 +33,10 @@ use crate::error::ParseTableOptionSnafu;
 use crate::metadata::{TableId, TableVersion};
 
 pub const FILE_TABLE_META_KEY: &str = "__private.file_table_meta";
+/// Key of the table option that stores the SQL definition of a view.
+pub const VIEW_DEFINITION_KEY: &str = "__private.view_definition";
+/// Key of the table option that stores the metadata of a Prometheus metric.
+pub const PROM_METRIC_METADATA_KEY: &str = "__private.prom_metric_metadata";
 pub const FILE_TABLE_LOCATION_KEY: &str = "location";
 pub const FILE_TABLE_PATTERN_KEY: &str = "pattern";
 pub const FILE_TABLE_FORMAT_KEY: &str = "format";
@@ -84,6 +88,7 @@ pub struct TableOptions {
 pub const WRITE_BUFFER_SIZE_KEY: &str = "write_buffer_size";
 pub const TTL_KEY: &str = "ttl";
 pub const REGIONS_KEY: &str = "regions";
+pub const MERGE_MODE_KEY: &str = "merge_mode";
 
 impl TryFrom<&HashMap<String, String>> for TableOptions {
     type Error = error::Error;
@@ -342,6 +347,7 @@ pub fn valid_table_option(key: &str) -> bool {
             | WRITE_BUFFER_SIZE_KEY
             | TTL_KEY
             | REGIONS_KEY
+            | MERGE_MODE_KEY
     ) | is_supported_in_s3(key)
 }
 
@@ -367,6 +373,7 @@ mod tests {
         assert!(valid_table_option(TTL_KEY));
         assert!(valid_table_option(REGIONS_KEY));
         assert!(valid_table_option(WRITE_BUFFER_SIZE_KEY));
+        assert!(valid_table_option(MERGE_MODE_KEY));
         assert!(!valid_table_option("foo"));
     }
 
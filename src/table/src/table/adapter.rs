@@ -25,8 +25,6 @@ use datafusion::error::Result as DfResult;
 use datafusion::execution::context::SessionState;
 use datafusion_expr::expr::Expr as DfExpr;
 use datafusion_expr::TableProviderFilterPushDown as DfTableProviderFilterPushDown;
-use datafusion_physical_expr::expressions::Column;
-use datafusion_physical_expr::PhysicalSortExpr;
 use store_api::storage::ScanRequest;
 
 use super::scan::StreamScanAdapter;
@@ -94,27 +92,7 @@ impl TableProvider for DfTableProviderAdapter {
             request.clone()
         };
         let stream = self.table.scan_to_stream(request).await?;
-
-        // build sort physical expr
-        let schema = stream.schema();
-        let sort_expr = stream.output_ordering().map(|order_opts| {
-            order_opts
-                .iter()
-                .map(|order_opt| {
-                    let col_index = schema.column_index_by_name(&order_opt.name).unwrap();
-                    let col_expr = Arc::new(Column::new(&order_opt.name, col_index));
-                    PhysicalSortExpr {
-                        expr: col_expr,
-                        options: order_opt.options,
-                    }
-                })
-                .collect::<Vec<_>>()
-        });
-
-        let mut stream_adapter = StreamScanAdapter::new(stream);
-        if let Some(sort_expr) = sort_expr {
-            stream_adapter = stream_adapter.with_output_ordering(sort_expr);
-        }
+        let stream_adapter = StreamScanAdapter::new(stream);
         Ok(Arc::new(DfPhysicalPlanAdapter(Arc::new(stream_adapter))))
     }
 
@@ -25,6 +25,7 @@ use common_recordbatch::error::Result as RecordBatchResult;
 use common_recordbatch::{RecordBatch, RecordBatchStream, SendableRecordBatchStream};
 use datafusion::execution::context::TaskContext;
 use datafusion::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_expr::PhysicalSortExpr;
 use datatypes::schema::SchemaRef;
 use futures::{Stream, StreamExt};
@@ -50,13 +51,31 @@ impl Debug for StreamScanAdapter {
 }
 
 impl StreamScanAdapter {
+    /// Creates an adapter for the `stream`. The adapter keeps the output ordering
+    /// the stream declares so the planner can avoid sorting it again.
     pub fn new(stream: SendableRecordBatchStream) -> Self {
         let schema = stream.schema();
+        let output_ordering = stream
+            .output_ordering()
+            .map(|order_opts| {
+                order_opts
+                    .iter()
+                    // Only the leading columns present in the schema keep their ordering.
+                    .map_while(|order_opt| {
+                        let col_index = schema.column_index_by_name(&order_opt.name)?;
+                        Some(PhysicalSortExpr {
+                            expr: Arc::new(Column::new(&order_opt.name, col_index)),
+                            options: order_opt.options,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|ordering| !ordering.is_empty());
 
         Self {
             stream: Mutex::new(Some(stream)),
             schema,
-            output_ordering: None,
+            output_ordering,
             metric: ExecutionPlanMetricsSet::new(),
         }
     }
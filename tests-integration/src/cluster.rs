@@ -224,6 +224,7 @@ impl GreptimeDbClusterBuilder {
             .enable_heartbeat()
             .channel_manager(meta_srv.channel_manager)
             .enable_ddl()
+            .enable_cluster()
             .build();
         meta_client.start(&[&meta_srv.server_addr]).await.unwrap();
         let meta_client = Arc::new(meta_client);
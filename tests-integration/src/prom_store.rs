@@ -17,18 +17,24 @@ mod tests {
     use std::sync::Arc;
 
     use api::prom_store::remote::label_matcher::Type as MatcherType;
+    use api::prom_store::remote::metric_metadata::MetricType;
+    use api::prom_store::remote::read_request::ResponseType;
     use api::prom_store::remote::{
-        Label, LabelMatcher, Query, ReadRequest, ReadResponse, Sample, WriteRequest,
+        ChunkedReadResponse, Exemplar, Label, LabelMatcher, MetricMetadata, Query, ReadRequest,
+        ReadResponse, Sample, TimeSeries, WriteRequest,
     };
     use common_catalog::consts::DEFAULT_CATALOG_NAME;
     use frontend::instance::Instance;
+    use futures::TryStreamExt;
     use prost::Message;
     use servers::prom_store;
+    use servers::prom_store::chunk::STREAMED_XOR_CHUNKS_CONTENT_TYPE;
     use servers::query_handler::sql::SqlQueryHandler;
-    use servers::query_handler::PromStoreProtocolHandler;
+    use servers::query_handler::{PromStoreProtocolHandler, PromStoreResponseBody};
     use session::context::QueryContext;
 
     use crate::standalone::GreptimeDbStandaloneBuilder;
+    use crate::test_util::check_output_stream;
     use crate::tests;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -102,10 +108,14 @@ mod tests {
             ..Default::default()
         };
 
-        let resp = instance.read(read_request, ctx).await.unwrap();
+        let queries = read_request.queries.clone();
+        let resp = instance.read(read_request, ctx.clone()).await.unwrap();
         assert_eq!(resp.content_type, "application/x-protobuf");
         assert_eq!(resp.content_encoding, "snappy");
-        let body = prom_store::snappy_decompress(&resp.body).unwrap();
+        let PromStoreResponseBody::Full(body) = resp.body else {
+            unreachable!()
+        };
+        let body = prom_store::snappy_decompress(&body).unwrap();
         let read_response = ReadResponse::decode(&body[..]).unwrap();
         let query_results = read_response.results;
         assert_eq!(2, query_results.len());
@@ -179,5 +189,92 @@ mod tests {
                 }
             ]
         );
+
+        let read_request = ReadRequest {
+            queries,
+            accepted_response_types: vec![ResponseType::StreamedXorChunks as i32],
+            ..Default::default()
+        };
+        let resp = instance.read(read_request, ctx.clone()).await.unwrap();
+        assert_eq!(resp.content_type, STREAMED_XOR_CHUNKS_CONTENT_TYPE);
+        assert!(resp.content_encoding.is_empty());
+        let PromStoreResponseBody::Stream(stream) = resp.body else {
+            unreachable!()
+        };
+        let frames = stream.try_collect::<Vec<_>>().await.unwrap();
+        // one series per query
+        assert_eq!(2, frames.len());
+
+        for (i, (frame, (min_time, max_time, num_samples))) in frames
+            .iter()
+            .zip([(1000, 2000, 2u8), (1000, 3000, 3u8)])
+            .enumerate()
+        {
+            let mut buf = &frame[..];
+            let len = prost::encoding::decode_varint(&mut buf).unwrap() as usize;
+            assert_eq!(len + 4, buf.len());
+            let response = ChunkedReadResponse::decode(&buf[4..]).unwrap();
+
+            assert_eq!(i as i64, response.query_index);
+            assert_eq!(1, response.chunked_series.len());
+            let chunks = &response.chunked_series[0].chunks;
+            assert_eq!(1, chunks.len());
+            assert_eq!(min_time, chunks[0].min_time_ms);
+            assert_eq!(max_time, chunks[0].max_time_ms);
+            assert_eq!([0, num_samples], chunks[0].data[..2]);
+        }
+
+        // metric metadata and exemplars
+        let write_request = WriteRequest {
+            timeseries: vec![TimeSeries {
+                labels: prom_store::mock_timeseries().remove(0).labels,
+                exemplars: vec![Exemplar {
+                    labels: vec![Label {
+                        name: "trace_id".to_string(),
+                        value: "abc".to_string(),
+                    }],
+                    value: 1.0,
+                    timestamp: 1000,
+                }],
+                ..Default::default()
+            }],
+            metadata: vec![MetricMetadata {
+                r#type: MetricType::Gauge as i32,
+                metric_family_name: "metric1".to_string(),
+                help: "the first metric".to_string(),
+                unit: "".to_string(),
+            }],
+        };
+        instance.write(write_request, ctx.clone()).await.unwrap();
+
+        let table = instance
+            .catalog_manager()
+            .table(DEFAULT_CATALOG_NAME, db, "metric1")
+            .await
+            .unwrap()
+            .unwrap();
+        let table_info = table.table_info();
+        let metadata =
+            prom_store::MetricMetadata::from_table_options(&table_info.meta.options).unwrap();
+        assert_eq!("gauge", metadata.metric_type);
+        assert_eq!("the first metric", metadata.help);
+        // the comment is left to users
+        assert!(table_info.desc.is_none());
+
+        let output = SqlQueryHandler::do_query(
+            instance.as_ref(),
+            "SELECT job, trace_id, greptime_value, greptime_timestamp FROM metric1__exemplars",
+            ctx,
+        )
+        .await
+        .remove(0)
+        .unwrap();
+        let expected = "\
++-------+----------+----------------+---------------------+
+| job   | trace_id | greptime_value | greptime_timestamp  |
++-------+----------+----------------+---------------------+
+| spark | abc      | 1.0            | 1970-01-01T00:00:01 |
++-------+----------+----------------+---------------------+";
+        check_output_stream(output, expected).await;
     }
 }
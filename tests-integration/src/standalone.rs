@@ -21,7 +21,9 @@ use common_meta::cache_invalidator::DummyKvCacheInvalidator;
 use common_procedure::options::ProcedureConfig;
 use datanode::config::DatanodeOptions;
 use datanode::datanode::DatanodeBuilder;
-use frontend::instance::{FrontendInstance, Instance, StandaloneDatanodeManager};
+use frontend::instance::{
+    FrontendInstance, Instance, StandaloneDatanodeManager, StandaloneRegionStatsProvider,
+};
 
 use crate::test_util::{self, create_tmp_dir_and_datanode_opts, StorageType, TestGuard};
 
@@ -85,6 +87,9 @@ impl GreptimeDbStandaloneBuilder {
             kv_store.clone(),
             Arc::new(DummyKvCacheInvalidator),
             Arc::new(StandaloneDatanodeManager(datanode.region_server())),
+            Some(Arc::new(StandaloneRegionStatsProvider(
+                datanode.region_server(),
+            ))),
         );
 
         catalog_manager
@@ -38,6 +38,7 @@ use object_store::services::{Azblob, Gcs, Oss, S3};
 use object_store::test_util::TempFolder;
 use object_store::ObjectStore;
 use secrecy::ExposeSecret;
+use servers::grpc::flight_sql::FlightSqlHandler;
 use servers::grpc::greptime_handler::GreptimeRequestHandler;
 use servers::grpc::GrpcServer;
 use servers::http::{HttpOptions, HttpServerBuilder};
@@ -469,6 +470,53 @@ pub async fn setup_grpc_server_with_user_provider(
     (fe_grpc_addr, instance.guard, fe_grpc_server)
 }
 
+/// Starts a gRPC server that serves Flight SQL in place of GreptimeDB's own Flight
+/// service, like the frontend does.
+pub async fn setup_flight_sql_server_with_user_provider(
+    store_type: StorageType,
+    name: &str,
+    user_provider: Option<UserProviderRef>,
+) -> (String, TestGuard, Arc<GrpcServer>) {
+    let instance = setup_standalone_instance(name, store_type).await;
+
+    let runtime = Arc::new(
+        RuntimeBuilder::default()
+            .worker_threads(2)
+            .thread_name("grpc-handlers")
+            .build()
+            .unwrap(),
+    );
+
+    let fe_instance_ref = instance.instance.clone();
+    let fe_grpc_server = Arc::new(
+        GrpcServer::new(
+            Some(ServerGrpcQueryHandlerAdaptor::arc(fe_instance_ref.clone())),
+            Some(fe_instance_ref.clone()),
+            None,
+            None,
+            user_provider.clone(),
+            runtime,
+        )
+        .with_flight_sql_handler(FlightSqlHandler::new(
+            ServerSqlQueryHandlerAdaptor::arc(fe_instance_ref.clone()),
+            fe_instance_ref,
+            user_provider,
+        )),
+    );
+
+    let fe_grpc_addr = "127.0.0.1:0".parse::<SocketAddr>().unwrap();
+    let fe_grpc_addr = fe_grpc_server
+        .start(fe_grpc_addr)
+        .await
+        .unwrap()
+        .to_string();
+
+    // wait for GRPC server to start
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    (fe_grpc_addr, instance.guard, fe_grpc_server)
+}
+
 pub async fn check_output_stream(output: Output, expected: &str) {
     let recordbatches = match output {
         Output::Stream(stream) => util::collect_batches(stream).await.unwrap(),
@@ -443,12 +443,13 @@ async fn test_execute_show_databases_tables(instance: Arc<dyn MockInstance>) {
         Output::RecordBatches(databases) => {
             let databases = databases.take();
             assert_eq!(1, databases[0].num_columns());
-            assert_eq!(databases[0].column(0).len(), 2);
+            assert_eq!(databases[0].column(0).len(), 3);
 
             assert_eq!(
                 *databases[0].column(0),
                 Arc::new(StringVector::from(vec![
                     Some("information_schema"),
+                    Some("pg_catalog"),
                     Some("public")
                 ])) as VectorRef
             );
@@ -1735,25 +1736,51 @@ async fn test_information_schema_dot_tables(instance: Arc<dyn MockInstance>) {
 
     let output = execute_sql(&instance, sql).await;
     let expected = "\
-+---------------+--------------------+------------+-----------------+----------+-------------+
-| table_catalog | table_schema       | table_name | table_type      | table_id | engine      |
-+---------------+--------------------+------------+-----------------+----------+-------------+
-| greptime      | information_schema | columns    | LOCAL TEMPORARY | 4        |             |
-| greptime      | public             | numbers    | LOCAL TEMPORARY | 2        | test_engine |
-| greptime      | information_schema | tables     | LOCAL TEMPORARY | 3        |             |
-+---------------+--------------------+------------+-----------------+----------+-------------+";
++---------------+--------------------+-------------------+-----------------+----------+-------------+
+| table_catalog | table_schema       | table_name        | table_type      | table_id | engine      |
++---------------+--------------------+-------------------+-----------------+----------+-------------+
+| greptime      | information_schema | build_info        | LOCAL TEMPORARY | 11       |             |
+| greptime      | information_schema | columns           | LOCAL TEMPORARY | 4        |             |
+| greptime      | information_schema | engines           | LOCAL TEMPORARY | 7        |             |
+| greptime      | information_schema | key_column_usage  | LOCAL TEMPORARY | 6        |             |
+| greptime      | public             | numbers           | LOCAL TEMPORARY | 2        | test_engine |
+| greptime      | information_schema | partitions        | LOCAL TEMPORARY | 8        |             |
+| greptime      | pg_catalog         | pg_attribute      | LOCAL TEMPORARY | 14       |             |
+| greptime      | pg_catalog         | pg_class          | LOCAL TEMPORARY | 13       |             |
+| greptime      | pg_catalog         | pg_namespace      | LOCAL TEMPORARY | 12       |             |
+| greptime      | pg_catalog         | pg_type           | LOCAL TEMPORARY | 15       |             |
+| greptime      | information_schema | region_peers      | LOCAL TEMPORARY | 9        |             |
+| greptime      | information_schema | region_statistics | LOCAL TEMPORARY | 10       |             |
+| greptime      | information_schema | schemata          | LOCAL TEMPORARY | 5        |             |
+| greptime      | information_schema | slow_queries      | LOCAL TEMPORARY | 16       |             |
+| greptime      | information_schema | tables            | LOCAL TEMPORARY | 3        |             |
+| greptime      | information_schema | views             | LOCAL TEMPORARY | 17       |             |
++---------------+--------------------+-------------------+-----------------+----------+-------------+";
 
     check_output_stream(output, expected).await;
 
     let output = execute_sql_with(&instance, sql, query_ctx).await;
     let expected = "\
-+-----------------+--------------------+---------------+-----------------+----------+--------+
-| table_catalog   | table_schema       | table_name    | table_type      | table_id | engine |
-+-----------------+--------------------+---------------+-----------------+----------+--------+
-| another_catalog | another_schema     | another_table | BASE TABLE      | 1024     | mito   |
-| another_catalog | information_schema | columns       | LOCAL TEMPORARY | 4        |        |
-| another_catalog | information_schema | tables        | LOCAL TEMPORARY | 3        |        |
-+-----------------+--------------------+---------------+-----------------+----------+--------+";
++-----------------+--------------------+-------------------+-----------------+----------+--------+
+| table_catalog   | table_schema       | table_name        | table_type      | table_id | engine |
++-----------------+--------------------+-------------------+-----------------+----------+--------+
+| another_catalog | another_schema     | another_table     | BASE TABLE      | 1024     | mito   |
+| another_catalog | information_schema | build_info        | LOCAL TEMPORARY | 11       |        |
+| another_catalog | information_schema | columns           | LOCAL TEMPORARY | 4        |        |
+| another_catalog | information_schema | engines           | LOCAL TEMPORARY | 7        |        |
+| another_catalog | information_schema | key_column_usage  | LOCAL TEMPORARY | 6        |        |
+| another_catalog | information_schema | partitions        | LOCAL TEMPORARY | 8        |        |
+| another_catalog | pg_catalog         | pg_attribute      | LOCAL TEMPORARY | 14       |        |
+| another_catalog | pg_catalog         | pg_class          | LOCAL TEMPORARY | 13       |        |
+| another_catalog | pg_catalog         | pg_namespace      | LOCAL TEMPORARY | 12       |        |
+| another_catalog | pg_catalog         | pg_type           | LOCAL TEMPORARY | 15       |        |
+| another_catalog | information_schema | region_peers      | LOCAL TEMPORARY | 9        |        |
+| another_catalog | information_schema | region_statistics | LOCAL TEMPORARY | 10       |        |
+| another_catalog | information_schema | schemata          | LOCAL TEMPORARY | 5        |        |
+| another_catalog | information_schema | slow_queries      | LOCAL TEMPORARY | 16       |        |
+| another_catalog | information_schema | tables            | LOCAL TEMPORARY | 3        |        |
+| another_catalog | information_schema | views             | LOCAL TEMPORARY | 17       |        |
++-----------------+--------------------+-------------------+-----------------+----------+--------+";
     check_output_stream(output, expected).await;
 }
 
@@ -1772,45 +1799,213 @@ async fn test_information_schema_dot_columns(instance: Arc<dyn MockInstance>) {
 
     let output = execute_sql(&instance, sql).await;
     let expected = "\
-+---------------+--------------------+------------+---------------+-----------+---------------+
-| table_catalog | table_schema       | table_name | column_name   | data_type | semantic_type |
-+---------------+--------------------+------------+---------------+-----------+---------------+
-| greptime      | information_schema | columns    | table_catalog | String    | FIELD         |
-| greptime      | information_schema | columns    | table_schema  | String    | FIELD         |
-| greptime      | information_schema | columns    | table_name    | String    | FIELD         |
-| greptime      | information_schema | columns    | column_name   | String    | FIELD         |
-| greptime      | information_schema | columns    | data_type     | String    | FIELD         |
-| greptime      | information_schema | columns    | semantic_type | String    | FIELD         |
-| greptime      | public             | numbers    | number        | UInt32    | TAG           |
-| greptime      | information_schema | tables     | table_catalog | String    | FIELD         |
-| greptime      | information_schema | tables     | table_schema  | String    | FIELD         |
-| greptime      | information_schema | tables     | table_name    | String    | FIELD         |
-| greptime      | information_schema | tables     | table_type    | String    | FIELD         |
-| greptime      | information_schema | tables     | table_id      | UInt32    | FIELD         |
-| greptime      | information_schema | tables     | engine        | String    | FIELD         |
-+---------------+--------------------+------------+---------------+-----------+---------------+";
++---------------+--------------------+-------------------+-------------------------------+----------------------+---------------+
+| table_catalog | table_schema       | table_name        | column_name                   | data_type            | semantic_type |
++---------------+--------------------+-------------------+-------------------------------+----------------------+---------------+
+| greptime      | information_schema | build_info        | git_branch                    | String               | FIELD         |
+| greptime      | information_schema | build_info        | git_commit                    | String               | FIELD         |
+| greptime      | information_schema | build_info        | git_commit_short              | String               | FIELD         |
+| greptime      | information_schema | build_info        | git_dirty                     | String               | FIELD         |
+| greptime      | information_schema | build_info        | rustc_version                 | String               | FIELD         |
+| greptime      | information_schema | build_info        | source_time                   | String               | FIELD         |
+| greptime      | information_schema | build_info        | pkg_version                   | String               | FIELD         |
+| greptime      | information_schema | columns           | table_catalog                 | String               | FIELD         |
+| greptime      | information_schema | columns           | table_schema                  | String               | FIELD         |
+| greptime      | information_schema | columns           | table_name                    | String               | FIELD         |
+| greptime      | information_schema | columns           | column_name                   | String               | FIELD         |
+| greptime      | information_schema | columns           | data_type                     | String               | FIELD         |
+| greptime      | information_schema | columns           | semantic_type                 | String               | FIELD         |
+| greptime      | information_schema | engines           | engine                        | String               | FIELD         |
+| greptime      | information_schema | engines           | support                       | String               | FIELD         |
+| greptime      | information_schema | engines           | comment                       | String               | FIELD         |
+| greptime      | information_schema | engines           | transactions                  | String               | FIELD         |
+| greptime      | information_schema | engines           | xa                            | String               | FIELD         |
+| greptime      | information_schema | engines           | savepoints                    | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | constraint_catalog            | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | constraint_schema             | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | constraint_name               | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | table_catalog                 | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | table_schema                  | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | table_name                    | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | column_name                   | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | ordinal_position              | UInt32               | FIELD         |
+| greptime      | information_schema | key_column_usage  | position_in_unique_constraint | UInt32               | FIELD         |
+| greptime      | information_schema | key_column_usage  | referenced_table_schema       | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | referenced_table_name         | String               | FIELD         |
+| greptime      | information_schema | key_column_usage  | referenced_column_name        | String               | FIELD         |
+| greptime      | public             | numbers           | number                        | UInt32               | TAG           |
+| greptime      | information_schema | partitions        | table_catalog                 | String               | FIELD         |
+| greptime      | information_schema | partitions        | table_schema                  | String               | FIELD         |
+| greptime      | information_schema | partitions        | table_name                    | String               | FIELD         |
+| greptime      | information_schema | partitions        | partition_name                | String               | FIELD         |
+| greptime      | information_schema | partitions        | partition_ordinal_position    | Int64                | FIELD         |
+| greptime      | information_schema | partitions        | partition_method              | String               | FIELD         |
+| greptime      | information_schema | partitions        | partition_expression          | String               | FIELD         |
+| greptime      | information_schema | partitions        | partition_description         | String               | FIELD         |
+| greptime      | information_schema | partitions        | greptime_partition_id         | UInt64               | FIELD         |
+| greptime      | pg_catalog         | pg_attribute      | attrelid                      | UInt32               | FIELD         |
+| greptime      | pg_catalog         | pg_attribute      | attname                       | String               | FIELD         |
+| greptime      | pg_catalog         | pg_attribute      | atttypid                      | UInt32               | FIELD         |
+| greptime      | pg_catalog         | pg_attribute      | attnum                        | Int16                | FIELD         |
+| greptime      | pg_catalog         | pg_attribute      | attnotnull                    | Boolean              | FIELD         |
+| greptime      | pg_catalog         | pg_class          | oid                           | UInt32               | FIELD         |
+| greptime      | pg_catalog         | pg_class          | relname                       | String               | FIELD         |
+| greptime      | pg_catalog         | pg_class          | relnamespace                  | UInt32               | FIELD         |
+| greptime      | pg_catalog         | pg_class          | relkind                       | String               | FIELD         |
+| greptime      | pg_catalog         | pg_namespace      | oid                           | UInt32               | FIELD         |
+| greptime      | pg_catalog         | pg_namespace      | nspname                       | String               | FIELD         |
+| greptime      | pg_catalog         | pg_type           | oid                           | UInt32               | FIELD         |
+| greptime      | pg_catalog         | pg_type           | typname                       | String               | FIELD         |
+| greptime      | pg_catalog         | pg_type           | typlen                        | Int16                | FIELD         |
+| greptime      | information_schema | region_peers      | table_catalog                 | String               | FIELD         |
+| greptime      | information_schema | region_peers      | table_schema                  | String               | FIELD         |
+| greptime      | information_schema | region_peers      | table_name                    | String               | FIELD         |
+| greptime      | information_schema | region_peers      | region_id                     | UInt64               | FIELD         |
+| greptime      | information_schema | region_peers      | peer_id                       | UInt64               | FIELD         |
+| greptime      | information_schema | region_peers      | peer_addr                     | String               | FIELD         |
+| greptime      | information_schema | region_peers      | is_leader                     | String               | FIELD         |
+| greptime      | information_schema | region_statistics | region_id                     | UInt64               | FIELD         |
+| greptime      | information_schema | region_statistics | table_id                      | UInt32               | FIELD         |
+| greptime      | information_schema | region_statistics | region_number                 | UInt32               | FIELD         |
+| greptime      | information_schema | region_statistics | engine                        | String               | FIELD         |
+| greptime      | information_schema | region_statistics | region_rows                   | UInt64               | FIELD         |
+| greptime      | information_schema | region_statistics | disk_size                     | UInt64               | FIELD         |
+| greptime      | information_schema | region_statistics | memtable_size                 | UInt64               | FIELD         |
+| greptime      | information_schema | region_statistics | sst_size                      | UInt64               | FIELD         |
+| greptime      | information_schema | schemata          | catalog_name                  | String               | FIELD         |
+| greptime      | information_schema | schemata          | schema_name                   | String               | FIELD         |
+| greptime      | information_schema | schemata          | default_character_set_name    | String               | FIELD         |
+| greptime      | information_schema | schemata          | default_collation_name        | String               | FIELD         |
+| greptime      | information_schema | schemata          | sql_path                      | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | start_time                    | TimestampMillisecond | FIELD         |
+| greptime      | information_schema | slow_queries      | elapsed_ms                    | UInt64               | FIELD         |
+| greptime      | information_schema | slow_queries      | user                          | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | client_addr                   | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | protocol                      | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | db                            | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | query                         | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | plan                          | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | region_rows                   | UInt64               | FIELD         |
+| greptime      | information_schema | slow_queries      | region_memory_size            | UInt64               | FIELD         |
+| greptime      | information_schema | slow_queries      | region_scans                  | String               | FIELD         |
+| greptime      | information_schema | slow_queries      | peak_memory                   | UInt64               | FIELD         |
+| greptime      | information_schema | tables            | table_catalog                 | String               | FIELD         |
+| greptime      | information_schema | tables            | table_schema                  | String               | FIELD         |
+| greptime      | information_schema | tables            | table_name                    | String               | FIELD         |
+| greptime      | information_schema | tables            | table_type                    | String               | FIELD         |
+| greptime      | information_schema | tables            | table_id                      | UInt32               | FIELD         |
+| greptime      | information_schema | tables            | engine                        | String               | FIELD         |
+| greptime      | information_schema | views             | table_catalog                 | String               | FIELD         |
+| greptime      | information_schema | views             | table_schema                  | String               | FIELD         |
+| greptime      | information_schema | views             | table_name                    | String               | FIELD         |
+| greptime      | information_schema | views             | view_definition               | String               | FIELD         |
++---------------+--------------------+-------------------+-------------------------------+----------------------+---------------+";
 
     check_output_stream(output, expected).await;
 
     let output = execute_sql_with(&instance, sql, query_ctx).await;
     let expected = "\
-+-----------------+--------------------+---------------+---------------+----------------------+---------------+
-| table_catalog   | table_schema       | table_name    | column_name   | data_type            | semantic_type |
-+-----------------+--------------------+---------------+---------------+----------------------+---------------+
-| another_catalog | another_schema     | another_table | i             | TimestampMillisecond | TIMESTAMP     |
-| another_catalog | information_schema | columns       | table_catalog | String               | FIELD         |
-| another_catalog | information_schema | columns       | table_schema  | String               | FIELD         |
-| another_catalog | information_schema | columns       | table_name    | String               | FIELD         |
-| another_catalog | information_schema | columns       | column_name   | String               | FIELD         |
-| another_catalog | information_schema | columns       | data_type     | String               | FIELD         |
-| another_catalog | information_schema | columns       | semantic_type | String               | FIELD         |
-| another_catalog | information_schema | tables        | table_catalog | String               | FIELD         |
-| another_catalog | information_schema | tables        | table_schema  | String               | FIELD         |
-| another_catalog | information_schema | tables        | table_name    | String               | FIELD         |
-| another_catalog | information_schema | tables        | table_type    | String               | FIELD         |
-| another_catalog | information_schema | tables        | table_id      | UInt32               | FIELD         |
-| another_catalog | information_schema | tables        | engine        | String               | FIELD         |
-+-----------------+--------------------+---------------+---------------+----------------------+---------------+";
++-----------------+--------------------+-------------------+-------------------------------+----------------------+---------------+
+| table_catalog   | table_schema       | table_name        | column_name                   | data_type            | semantic_type |
++-----------------+--------------------+-------------------+-------------------------------+----------------------+---------------+
+| another_catalog | another_schema     | another_table     | i                             | TimestampMillisecond | TIMESTAMP     |
+| another_catalog | information_schema | build_info        | git_branch                    | String               | FIELD         |
+| another_catalog | information_schema | build_info        | git_commit                    | String               | FIELD         |
+| another_catalog | information_schema | build_info        | git_commit_short              | String               | FIELD         |
+| another_catalog | information_schema | build_info        | git_dirty                     | String               | FIELD         |
+| another_catalog | information_schema | build_info        | rustc_version                 | String               | FIELD         |
+| another_catalog | information_schema | build_info        | source_time                   | String               | FIELD         |
+| another_catalog | information_schema | build_info        | pkg_version                   | String               | FIELD         |
+| another_catalog | information_schema | columns           | table_catalog                 | String               | FIELD         |
+| another_catalog | information_schema | columns           | table_schema                  | String               | FIELD         |
+| another_catalog | information_schema | columns           | table_name                    | String               | FIELD         |
+| another_catalog | information_schema | columns           | column_name                   | String               | FIELD         |
+| another_catalog | information_schema | columns           | data_type                     | String               | FIELD         |
+| another_catalog | information_schema | columns           | semantic_type                 | String               | FIELD         |
+| another_catalog | information_schema | engines           | engine                        | String               | FIELD         |
+| another_catalog | information_schema | engines           | support                       | String               | FIELD         |
+| another_catalog | information_schema | engines           | comment                       | String               | FIELD         |
+| another_catalog | information_schema | engines           | transactions                  | String               | FIELD         |
+| another_catalog | information_schema | engines           | xa                            | String               | FIELD         |
+| another_catalog | information_schema | engines           | savepoints                    | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | constraint_catalog            | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | constraint_schema             | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | constraint_name               | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | table_catalog                 | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | table_schema                  | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | table_name                    | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | column_name                   | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | ordinal_position              | UInt32               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | position_in_unique_constraint | UInt32               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | referenced_table_schema       | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | referenced_table_name         | String               | FIELD         |
+| another_catalog | information_schema | key_column_usage  | referenced_column_name        | String               | FIELD         |
+| another_catalog | information_schema | partitions        | table_catalog                 | String               | FIELD         |
+| another_catalog | information_schema | partitions        | table_schema                  | String               | FIELD         |
+| another_catalog | information_schema | partitions        | table_name                    | String               | FIELD         |
+| another_catalog | information_schema | partitions        | partition_name                | String               | FIELD         |
+| another_catalog | information_schema | partitions        | partition_ordinal_position    | Int64                | FIELD         |
+| another_catalog | information_schema | partitions        | partition_method              | String               | FIELD         |
+| another_catalog | information_schema | partitions        | partition_expression          | String               | FIELD         |
+| another_catalog | information_schema | partitions        | partition_description         | String               | FIELD         |
+| another_catalog | information_schema | partitions        | greptime_partition_id         | UInt64               | FIELD         |
+| another_catalog | pg_catalog         | pg_attribute      | attrelid                      | UInt32               | FIELD         |
+| another_catalog | pg_catalog         | pg_attribute      | attname                       | String               | FIELD         |
+| another_catalog | pg_catalog         | pg_attribute      | atttypid                      | UInt32               | FIELD         |
+| another_catalog | pg_catalog         | pg_attribute      | attnum                        | Int16                | FIELD         |
+| another_catalog | pg_catalog         | pg_attribute      | attnotnull                    | Boolean              | FIELD         |
+| another_catalog | pg_catalog         | pg_class          | oid                           | UInt32               | FIELD         |
+| another_catalog | pg_catalog         | pg_class          | relname                       | String               | FIELD         |
+| another_catalog | pg_catalog         | pg_class          | relnamespace                  | UInt32               | FIELD         |
+| another_catalog | pg_catalog         | pg_class          | relkind                       | String               | FIELD         |
+| another_catalog | pg_catalog         | pg_namespace      | oid                           | UInt32               | FIELD         |
+| another_catalog | pg_catalog         | pg_namespace      | nspname                       | String               | FIELD         |
+| another_catalog | pg_catalog         | pg_type           | oid                           | UInt32               | FIELD         |
+| another_catalog | pg_catalog         | pg_type           | typname                       | String               | FIELD         |
+| another_catalog | pg_catalog         | pg_type           | typlen                        | Int16                | FIELD         |
+| another_catalog | information_schema | region_peers      | table_catalog                 | String               | FIELD         |
+| another_catalog | information_schema | region_peers      | table_schema                  | String               | FIELD         |
+| another_catalog | information_schema | region_peers      | table_name                    | String               | FIELD         |
+| another_catalog | information_schema | region_peers      | region_id                     | UInt64               | FIELD         |
+| another_catalog | information_schema | region_peers      | peer_id                       | UInt64               | FIELD         |
+| another_catalog | information_schema | region_peers      | peer_addr                     | String               | FIELD         |
+| another_catalog | information_schema | region_peers      | is_leader                     | String               | FIELD         |
+| another_catalog | information_schema | region_statistics | region_id                     | UInt64               | FIELD         |
+| another_catalog | information_schema | region_statistics | table_id                      | UInt32               | FIELD         |
+| another_catalog | information_schema | region_statistics | region_number                 | UInt32               | FIELD         |
+| another_catalog | information_schema | region_statistics | engine                        | String               | FIELD         |
+| another_catalog | information_schema | region_statistics | region_rows                   | UInt64               | FIELD         |
+| another_catalog | information_schema | region_statistics | disk_size                     | UInt64               | FIELD         |
+| another_catalog | information_schema | region_statistics | memtable_size                 | UInt64               | FIELD         |
+| another_catalog | information_schema | region_statistics | sst_size                      | UInt64               | FIELD         |
+| another_catalog | information_schema | schemata          | catalog_name                  | String               | FIELD         |
+| another_catalog | information_schema | schemata          | schema_name                   | String               | FIELD         |
+| another_catalog | information_schema | schemata          | default_character_set_name    | String               | FIELD         |
+| another_catalog | information_schema | schemata          | default_collation_name        | String               | FIELD         |
+| another_catalog | information_schema | schemata          | sql_path                      | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | start_time                    | TimestampMillisecond | FIELD         |
+| another_catalog | information_schema | slow_queries      | elapsed_ms                    | UInt64               | FIELD         |
+| another_catalog | information_schema | slow_queries      | user                          | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | client_addr                   | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | protocol                      | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | db                            | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | query                         | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | plan                          | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | region_rows                   | UInt64               | FIELD         |
+| another_catalog | information_schema | slow_queries      | region_memory_size            | UInt64               | FIELD         |
+| another_catalog | information_schema | slow_queries      | region_scans                  | String               | FIELD         |
+| another_catalog | information_schema | slow_queries      | peak_memory                   | UInt64               | FIELD         |
+| another_catalog | information_schema | tables            | table_catalog                 | String               | FIELD         |
+| another_catalog | information_schema | tables            | table_schema                  | String               | FIELD         |
+| another_catalog | information_schema | tables            | table_name                    | String               | FIELD         |
+| another_catalog | information_schema | tables            | table_type                    | String               | FIELD         |
+| another_catalog | information_schema | tables            | table_id                      | UInt32               | FIELD         |
+| another_catalog | information_schema | tables            | engine                        | String               | FIELD         |
+| another_catalog | information_schema | views             | table_catalog                 | String               | FIELD         |
+| another_catalog | information_schema | views             | table_schema                  | String               | FIELD         |
+| another_catalog | information_schema | views             | table_name                    | String               | FIELD         |
+| another_catalog | information_schema | views             | view_definition               | String               | FIELD         |
++-----------------+--------------------+-------------------+-------------------------------+----------------------+---------------+";
 
     check_output_stream(output, expected).await;
 }
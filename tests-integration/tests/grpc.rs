@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use api::v1::alter_expr::Kind;
 use api::v1::promql_request::Promql;
 use api::v1::{
@@ -19,16 +21,31 @@ use api::v1::{
     CreateTableExpr, InsertRequest, InsertRequests, PromInstantQuery, PromRangeQuery,
     PromqlRequest, RequestHeader, SemanticType, TableId,
 };
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, Any, CommandPreparedStatementQuery,
+    CommandStatementUpdate, DoPutUpdateResult, ProstMessageExt,
+};
+use arrow_flight::utils::{batches_to_flight_data, flight_data_to_batches};
+use arrow_flight::{Action, FlightData, FlightDescriptor, Ticket};
 use auth::user_provider_from_option;
 use client::{Client, Database, DEFAULT_CATALOG_NAME, DEFAULT_SCHEMA_NAME};
 use common_catalog::consts::{MIN_USER_TABLE_ID, MITO_ENGINE};
 use common_query::Output;
 use common_recordbatch::RecordBatches;
+use datatypes::arrow::array::{Array, Float64Array, StringArray};
+use datatypes::arrow::datatypes::{DataType, Field, Schema};
+use datatypes::arrow::record_batch::RecordBatch;
+use futures::TryStreamExt;
+use prost::Message;
 use servers::http::prometheus::{PromData, PromSeries, PrometheusJsonResponse, PrometheusResponse};
 use servers::server::Server;
 use tests_integration::test_util::{
-    setup_grpc_server, setup_grpc_server_with_user_provider, StorageType,
+    setup_flight_sql_server_with_user_provider, setup_grpc_server,
+    setup_grpc_server_with_user_provider, StorageType,
 };
+use tonic::transport::Channel;
 
 #[macro_export]
 macro_rules! grpc_test {
@@ -67,6 +84,7 @@ macro_rules! grpc_tests {
                 test_grpc_auth,
                 test_health_check,
                 test_prom_gateway_query,
+                test_flight_sql,
             );
         )*
     };
@@ -563,3 +581,186 @@ pub async fn test_prom_gateway_query(store_type: StorageType) {
     let _ = fe_grpc_server.shutdown().await;
     guard.remove_all().await;
 }
+
+/// `Basic` authorization of `greptime_user:greptime_pwd`.
+const FLIGHT_SQL_AUTH: &str = "Basic Z3JlcHRpbWVfdXNlcjpncmVwdGltZV9wd2Q=";
+/// `Basic` authorization of `other_user:other_pwd`.
+const FLIGHT_SQL_OTHER_AUTH: &str = "Basic b3RoZXJfdXNlcjpvdGhlcl9wd2Q=";
+
+fn flight_sql_request<T>(message: T, authorization: &str) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    let _ = request
+        .metadata_mut()
+        .insert("authorization", authorization.parse().unwrap());
+    request
+}
+
+async fn flight_sql_update(client: &mut FlightServiceClient<Channel>, query: &str) -> i64 {
+    let command = CommandStatementUpdate {
+        query: query.to_string(),
+        transaction_id: None,
+    };
+    let data = FlightData {
+        flight_descriptor: Some(FlightDescriptor::new_cmd(command.as_any().encode_to_vec())),
+        ..Default::default()
+    };
+    let result = client
+        .do_put(flight_sql_request(
+            futures::stream::iter(vec![data]),
+            FLIGHT_SQL_AUTH,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .message()
+        .await
+        .unwrap()
+        .unwrap();
+    DoPutUpdateResult::decode(result.app_metadata)
+        .unwrap()
+        .record_count
+}
+
+async fn flight_sql_query(
+    client: &mut FlightServiceClient<Channel>,
+    handle: &[u8],
+    authorization: &str,
+) -> Result<Vec<RecordBatch>, tonic::Status> {
+    let command = CommandPreparedStatementQuery {
+        prepared_statement_handle: handle.to_vec().into(),
+    };
+    let ticket = Ticket {
+        ticket: command.as_any().encode_to_vec().into(),
+    };
+    let flight_data = client
+        .do_get(flight_sql_request(ticket, authorization))
+        .await?
+        .into_inner()
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(flight_data_to_batches(&flight_data).unwrap())
+}
+
+pub async fn test_flight_sql(store_type: StorageType) {
+    let user_provider = user_provider_from_option(
+        &"static_user_provider:cmd:greptime_user=greptime_pwd,other_user=other_pwd".to_string(),
+    )
+    .unwrap();
+    let (addr, mut guard, fe_grpc_server) =
+        setup_flight_sql_server_with_user_provider(store_type, "flight_sql", Some(user_provider))
+            .await;
+    let mut client = FlightServiceClient::connect(format!("http://{addr}"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        0,
+        flight_sql_update(
+            &mut client,
+            "CREATE TABLE flight_sql_test (host STRING, val DOUBLE, ts TIMESTAMP TIME INDEX, PRIMARY KEY (host))",
+        )
+        .await
+    );
+    assert_eq!(
+        2,
+        flight_sql_update(
+            &mut client,
+            "INSERT INTO flight_sql_test VALUES ('a', 1.0, 1000), ('b', 2.0, 2000)",
+        )
+        .await
+    );
+
+    // Prepares the statement.
+    let request = ActionCreatePreparedStatementRequest {
+        query: "SELECT host, val FROM flight_sql_test WHERE val > $1 ORDER BY host".to_string(),
+        transaction_id: None,
+    };
+    let action = Action {
+        r#type: "CreatePreparedStatement".to_string(),
+        body: request.as_any().encode_to_vec().into(),
+    };
+    let result = client
+        .do_action(flight_sql_request(action, FLIGHT_SQL_AUTH))
+        .await
+        .unwrap()
+        .into_inner()
+        .message()
+        .await
+        .unwrap()
+        .unwrap();
+    let result = Any::decode(result.body)
+        .unwrap()
+        .unpack::<ActionCreatePreparedStatementResult>()
+        .unwrap()
+        .unwrap();
+    let handle = result.prepared_statement_handle.to_vec();
+    assert_eq!(16, handle.len());
+
+    // Binds the parameter.
+    let schema = Schema::new(vec![Field::new("$1", DataType::Float64, true)]);
+    let parameters = RecordBatch::try_new(
+        Arc::new(schema.clone()),
+        vec![Arc::new(Float64Array::from(vec![1.5]))],
+    )
+    .unwrap();
+    let mut flight_data = batches_to_flight_data(schema, vec![parameters]).unwrap();
+    let command = CommandPreparedStatementQuery {
+        prepared_statement_handle: handle.clone().into(),
+    };
+    flight_data[0].flight_descriptor =
+        Some(FlightDescriptor::new_cmd(command.as_any().encode_to_vec()));
+    let _ = client
+        .do_put(flight_sql_request(
+            futures::stream::iter(flight_data),
+            FLIGHT_SQL_AUTH,
+        ))
+        .await
+        .unwrap();
+
+    // Executes the statement.
+    let batches = flight_sql_query(&mut client, &handle, FLIGHT_SQL_AUTH)
+        .await
+        .unwrap();
+    let rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+    assert_eq!(1, rows);
+    let batch = batches.iter().find(|batch| batch.num_rows() > 0).unwrap();
+    let hosts = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("b", hosts.value(0));
+
+    // Other users and unauthenticated requests can't use the statement.
+    assert!(
+        flight_sql_query(&mut client, &handle, FLIGHT_SQL_OTHER_AUTH)
+            .await
+            .is_err()
+    );
+    let status = client
+        .do_get(tonic::Request::new(Ticket {
+            ticket: command.as_any().encode_to_vec().into(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(tonic::Code::Unauthenticated, status.code());
+
+    // Closes the statement.
+    let request = ActionClosePreparedStatementRequest {
+        prepared_statement_handle: handle.clone().into(),
+    };
+    let action = Action {
+        r#type: "ClosePreparedStatement".to_string(),
+        body: request.as_any().encode_to_vec().into(),
+    };
+    let _ = client
+        .do_action(flight_sql_request(action, FLIGHT_SQL_AUTH))
+        .await
+        .unwrap();
+    assert!(flight_sql_query(&mut client, &handle, FLIGHT_SQL_AUTH)
+        .await
+        .is_err());
+
+    let _ = fe_grpc_server.shutdown().await;
+    guard.remove_all().await;
+}
@@ -12,16 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
+use api::prom_store::remote::{Exemplar, Label, WriteRequest};
 use auth::user_provider_from_option;
 use axum::http::StatusCode;
 use axum_test_helper::TestClient;
 use common_error::status_code::StatusCode as ErrorCode;
+use prost::Message;
 use serde_json::json;
 use servers::http::handler::HealthResponse;
-use servers::http::prometheus::{PrometheusJsonResponse, PrometheusResponse};
+use servers::http::prometheus::{
+    PromExemplar, PromExemplars, PrometheusJsonResponse, PrometheusResponse,
+};
 use servers::http::{JsonOutput, JsonResponse};
+use servers::prom_store;
 use tests_integration::test_util::{
     setup_test_http_app, setup_test_http_app_with_frontend,
     setup_test_http_app_with_frontend_and_user_provider, setup_test_prom_app_with_frontend,
@@ -490,6 +495,108 @@ pub async fn test_prom_http_api(store_type: StorageType) {
     assert!(prom_resp.error.is_none());
     assert!(prom_resp.error_type.is_none());
 
+    // metadata
+    let res = client
+        .get("/v1/prometheus/api/v1/metadata?metric=demo")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    assert_eq!(prom_resp.status, "success");
+    let PrometheusResponse::Metadata(metadata) = prom_resp.data else {
+        unreachable!()
+    };
+    assert_eq!(metadata["demo"][0].metric_type, "unknown");
+
+    // exemplars
+    let res = client
+        .get("/v1/prometheus/api/v1/query_exemplars?query=demo&start=0&end=600")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    assert_eq!(prom_resp.status, "success");
+
+    // writes an exemplar by remote write and reads it back
+    let mut timeseries = prom_store::mock_timeseries();
+    timeseries[0].exemplars = vec![Exemplar {
+        labels: vec![Label {
+            name: "trace_id".to_string(),
+            value: "abc".to_string(),
+        }],
+        value: 1.5,
+        timestamp: 1500,
+    }];
+    let write_request = WriteRequest {
+        timeseries,
+        ..Default::default()
+    };
+    let res = client
+        .post("/v1/prometheus/write")
+        .body(prom_store::snappy_compress(&write_request.encode_to_vec()).unwrap())
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let res = client
+        .get("/v1/prometheus/api/v1/query_exemplars?query=rate(metric1%7Bjob%3D%22spark%22%7D%5B5m%5D)&start=1&end=2")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    assert_eq!(prom_resp.status, "success");
+    let PrometheusResponse::Exemplars(exemplars) = prom_resp.data else {
+        unreachable!()
+    };
+    assert_eq!(
+        exemplars,
+        vec![PromExemplars {
+            series_labels: HashMap::from([
+                ("__name__".to_string(), "metric1".to_string()),
+                ("job".to_string(), "spark".to_string()),
+            ]),
+            exemplars: vec![PromExemplar {
+                labels: HashMap::from([("trace_id".to_string(), "abc".to_string())]),
+                value: "1.5".to_string(),
+                timestamp: 1.5,
+            }],
+        }]
+    );
+    // exemplars out of the time range are not returned
+    let res = client
+        .get("/v1/prometheus/api/v1/query_exemplars?query=metric1&start=2&end=3")
+        .send()
+        .await;
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    assert_eq!(prom_resp.status, "success");
+    assert_eq!(prom_resp.data, PrometheusResponse::Labels(vec![]));
+
+    // format query
+    let res = client
+        .get("/v1/prometheus/api/v1/format_query?query=sum(demo)by(host)")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    assert_eq!(prom_resp.status, "success");
+    let res = client
+        .get("/v1/prometheus/api/v1/format_query?query=sum(demo")
+        .send()
+        .await;
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    assert_eq!(prom_resp.status, "error");
+
+    // build info
+    let res = client
+        .get("/v1/prometheus/api/v1/status/buildinfo")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let prom_resp = res.json::<PrometheusJsonResponse>().await;
+    let PrometheusResponse::BuildInfo(build_info) = prom_resp.data else {
+        unreachable!()
+    };
+    assert_eq!(build_info.version, env!("CARGO_PKG_VERSION"));
+
     guard.remove_all().await;
 }
 
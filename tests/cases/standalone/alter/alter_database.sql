@@ -0,0 +1,23 @@
+CREATE DATABASE alter_db_test WITH (ttl = '30d');
+
+USE alter_db_test;
+
+CREATE TABLE t1 (ts TIMESTAMP TIME INDEX);
+
+SHOW CREATE TABLE t1;
+
+ALTER DATABASE alter_db_test SET 'ttl' = '7d';
+
+CREATE TABLE t2 (ts TIMESTAMP TIME INDEX);
+
+SHOW CREATE TABLE t2;
+
+ALTER DATABASE alter_db_test UNSET 'ttl';
+
+CREATE TABLE t3 (ts TIMESTAMP TIME INDEX);
+
+SHOW CREATE TABLE t3;
+
+USE public;
+
+DROP DATABASE alter_db_test;
@@ -0,0 +1,39 @@
+create table monitor(host string, idc string, cpu double, ts timestamp time index, primary key(host, idc));
+
+insert into monitor values ('host1', 'a', 66.6, 1655276557000), ('host2', 'a', 88.8, 1655276558000), ('host1', 'a', 77.7, 1655276559000), ('host3', 'b', 11.1, 1655276557000);
+
+create table cpu_by_host with (primary_key = 'host') as select host, max(ts) as ts, avg(cpu) as avg_cpu from monitor group by host;
+
+select * from cpu_by_host order by host;
+
+desc table cpu_by_host;
+
+create table cpu_by_host as select * from monitor;
+
+create table if not exists cpu_by_host as select * from monitor;
+
+-- Inherits the primary key of monitor, rows sharing a timestamp are kept
+create table monitor_copy as select * from monitor;
+
+select * from monitor_copy order by host, ts;
+
+desc table monitor_copy;
+
+create table cpu_values as select ts, cpu from monitor;
+
+-- The table is dropped if the results can't be inserted
+create table bad_cast with (primary_key = '') as select ts, cast(host as double) as host_value from monitor where host = 'host2';
+
+select * from bad_cast;
+
+create table no_time_index as select host, cpu from monitor;
+
+create table bad_time_index with (time_index = 'cpu') as select * from monitor;
+
+create table bad_primary_key with (time_index = 'ts', primary_key = 'foo') as select * from monitor;
+
+drop table monitor_copy;
+
+drop table cpu_by_host;
+
+drop table monitor;
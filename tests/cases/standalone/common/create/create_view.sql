@@ -0,0 +1,49 @@
+create table monitor(host string, idc string, cpu double, ts timestamp time index, primary key(host, idc));
+
+insert into monitor values ('host1', 'a', 66.6, 1655276557000), ('host2', 'a', 88.8, 1655276558000), ('host1', 'a', 77.7, 1655276559000), ('host3', 'b', 11.1, 1655276557000);
+
+create view cpu_a as select host, cpu, ts from monitor where idc = 'a';
+
+select * from cpu_a order by host, ts;
+
+select host, max(cpu) as max_cpu from cpu_a group by host order by host;
+
+create view cpu_a as select * from monitor;
+
+create view if not exists cpu_a as select * from monitor;
+
+show create view cpu_a;
+
+show create table cpu_a;
+
+select * from information_schema.views;
+
+select table_name, table_type from information_schema.tables where table_schema = 'public' order by table_name;
+
+insert into cpu_a values ('host4', 1.0, 1655276560000);
+
+insert into cpu_a select host, cpu, ts from monitor;
+
+delete from cpu_a where host = 'host1';
+
+update cpu_a set cpu = 1.0 where host = 'host1';
+
+alter table cpu_a add column k int;
+
+truncate table cpu_a;
+
+select count(*) from monitor;
+
+drop table cpu_a;
+
+drop view monitor;
+
+show create view monitor;
+
+drop view cpu_a;
+
+drop view cpu_a;
+
+drop view if exists cpu_a;
+
+drop table monitor;
@@ -0,0 +1,37 @@
+CREATE DATABASE drop_db_test;
+
+USE drop_db_test;
+
+CREATE TABLE t1 (ts TIMESTAMP TIME INDEX);
+
+CREATE TABLE t2 (ts TIMESTAMP TIME INDEX, val DOUBLE);
+
+INSERT INTO t1 VALUES (1), (2);
+
+INSERT INTO t2 VALUES (1, 1.0);
+
+USE public;
+
+SHOW DATABASES LIKE '%drop_db%';
+
+DROP DATABASE drop_db_test;
+
+SELECT * FROM drop_db_test.t1;
+
+DROP DATABASE drop_db_test;
+
+DROP DATABASE IF EXISTS drop_db_test;
+
+DROP DATABASE public;
+
+DROP DATABASE IF EXISTS information_schema;
+
+CREATE DATABASE drop_db_test;
+
+USE drop_db_test;
+
+SHOW TABLES;
+
+USE public;
+
+DROP SCHEMA drop_db_test;
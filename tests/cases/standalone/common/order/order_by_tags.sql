@@ -0,0 +1,14 @@
+-- tags are stored in memcomparable format, ORDER BY must follow the value order of each type
+CREATE TABLE order_tags(i INT, f DOUBLE, s STRING, ts TIMESTAMP TIME INDEX, v DOUBLE, PRIMARY KEY(i, f, s));
+
+INSERT INTO order_tags VALUES (-2, 1.5, 'a', 1, 1), (1, -1.5, 'b', 2, 2), (-1, -0.5, 'a', 3, 3), (0, 2.5, 'c', 4, 4), (1, -2.5, 'a', 5, 5), (-2, -1.5, 'b', 6, 6);
+
+SELECT i, f, s, ts FROM order_tags ORDER BY i, f, s, ts;
+
+SELECT i, ts FROM order_tags ORDER BY i DESC, ts;
+
+SELECT f, ts FROM order_tags ORDER BY f, ts;
+
+SELECT s, i, ts FROM order_tags ORDER BY s, i, ts;
+
+DROP TABLE order_tags;
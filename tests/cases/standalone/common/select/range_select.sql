@@ -94,3 +94,33 @@ INSERT INTO TABLE host_sec VALUES
 -- SELECT ts, host, min(val) RANGE '10s', max(val) RANGE '10s' FROM host_sec ALIGN '5s' ORDER BY host, ts;
 
 DROP TABLE host_sec;
+
+-- Test FILL
+
+CREATE TABLE host_fill (
+  ts timestamp(3) time index,
+  host STRING PRIMARY KEY,
+  val DOUBLE,
+);
+
+INSERT INTO TABLE host_fill VALUES
+    (0,     'host1', 0.0),
+    (5000,  'host1', 1.0),
+    (20000, 'host1', 4.0),
+    (0,     'host2', 9.0),
+    (10000, 'host2', 11.0),
+    (20000, 'host2', 13.0);
+
+SELECT ts, host, min(val) RANGE '5s' FROM host_fill ALIGN '5s' ORDER BY host, ts;
+
+SELECT ts, host, min(val) RANGE '5s' FILL NULL FROM host_fill ALIGN '5s' ORDER BY host, ts;
+
+SELECT ts, host, min(val) RANGE '5s' FILL PREV FROM host_fill ALIGN '5s' ORDER BY host, ts;
+
+SELECT ts, host, min(val) RANGE '5s' FILL LINEAR FROM host_fill ALIGN '5s' ORDER BY host, ts;
+
+SELECT ts, host, min(val) RANGE '5s' FILL 6 FROM host_fill ALIGN '5s' ORDER BY host, ts;
+
+SELECT ts, host, min(val) RANGE '5s', max(val) RANGE '5s' FROM host_fill ALIGN '5s' FILL PREV ORDER BY host, ts;
+
+DROP TABLE host_fill;
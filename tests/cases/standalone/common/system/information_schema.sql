@@ -26,6 +26,7 @@ from information_schema.tables
 where table_catalog = 'greptime'
   and table_schema != 'public'
   and table_schema != 'information_schema'
+  and table_schema != 'pg_catalog'
 order by table_schema, table_name;
 
 select table_catalog, table_schema, table_name, column_name, data_type, semantic_type
@@ -33,8 +34,21 @@ from information_schema.columns
 where table_catalog = 'greptime'
   and table_schema != 'public'
   and table_schema != 'information_schema'
+  and table_schema != 'pg_catalog'
 order by table_schema, table_name;
 
+select *
+from information_schema.schemata
+where schema_name = 'my_db';
+
+select constraint_name, table_schema, table_name, column_name, ordinal_position
+from information_schema.key_column_usage
+where table_schema = 'my_db';
+
+select engine, support
+from information_schema.engines
+order by engine;
+
 use public;
 
 drop schema my_db;
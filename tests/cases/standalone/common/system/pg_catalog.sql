@@ -0,0 +1,91 @@
+create database pg_db;
+
+use pg_db;
+
+create table pg_catalog_test
+(
+    host STRING,
+    val DOUBLE COMMENT 'value',
+    ts TIMESTAMP TIME INDEX,
+    PRIMARY KEY (host)
+);
+
+select c.relname, c.relkind
+from pg_catalog.pg_class c
+         join pg_catalog.pg_namespace n on c.relnamespace = n.oid
+where n.nspname = 'pg_db';
+
+select a.attname, t.typname, a.attnum, a.attnotnull
+from pg_catalog.pg_attribute a
+         join pg_catalog.pg_class c on a.attrelid = c.oid
+         join pg_catalog.pg_namespace n on c.relnamespace = n.oid
+         join pg_catalog.pg_type t on a.atttypid = t.oid
+where n.nspname = 'pg_db'
+  and c.relname = 'pg_catalog_test'
+order by a.attnum;
+
+-- psql \dt, without pg_get_userbyid() and pg_table_is_visible()
+SELECT n.nspname as "Schema",
+       c.relname as "Name",
+       CASE c.relkind WHEN 'r' THEN 'table' WHEN 'v' THEN 'view' END as "Type",
+       c.relowner as "Owner"
+FROM pg_catalog.pg_class c
+         LEFT JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE c.relkind IN ('r', 'p', 'v', '')
+  AND n.nspname = 'pg_db'
+ORDER BY 1, 2;
+
+-- psql \d pg_catalog_test, with the regclass cast and format_type() replaced by joins
+SELECT a.attname, t.typname, a.atttypmod, a.attnotnull, a.attnum
+FROM pg_catalog.pg_attribute a
+         JOIN pg_catalog.pg_class c ON a.attrelid = c.oid
+         JOIN pg_catalog.pg_namespace n ON c.relnamespace = n.oid
+         JOIN pg_catalog.pg_type t ON a.atttypid = t.oid
+WHERE n.nspname = 'pg_db'
+  AND c.relname = 'pg_catalog_test'
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+ORDER BY a.attnum;
+
+-- PgJDBC DatabaseMetaData.getColumns()
+SELECT n.nspname, c.relname, a.attname, t.typname, a.attnotnull, a.atttypmod, a.attnum, dsc.description
+FROM pg_catalog.pg_namespace n
+         JOIN pg_catalog.pg_class c ON (c.relnamespace = n.oid)
+         JOIN pg_catalog.pg_attribute a ON (a.attrelid = c.oid)
+         JOIN pg_catalog.pg_type t ON (a.atttypid = t.oid)
+         LEFT JOIN pg_catalog.pg_description dsc ON (c.oid = dsc.objoid AND a.attnum = dsc.objsubid)
+WHERE c.relkind IN ('r', 'p', 'v', 'f', 'm')
+  AND a.attnum > 0
+  AND NOT a.attisdropped
+  AND n.nspname LIKE 'pg_db'
+  AND c.relname LIKE '%'
+ORDER BY nspname, c.relname, attnum;
+
+-- PgJDBC DatabaseMetaData.getTables(), without the cast of classoid to regclass
+SELECT NULL AS TABLE_CAT, n.nspname AS TABLE_SCHEM, c.relname AS TABLE_NAME, d.description AS REMARKS
+FROM pg_catalog.pg_namespace n
+         JOIN pg_catalog.pg_class c ON (c.relnamespace = n.oid)
+         LEFT JOIN pg_catalog.pg_description d ON (c.oid = d.objoid AND d.objsubid = 0)
+WHERE c.relnamespace = n.oid
+  AND n.nspname LIKE 'pg_db'
+  AND c.relname LIKE '%'
+ORDER BY TABLE_SCHEM, TABLE_NAME;
+
+-- DBeaver loads the schemas, without the cast of classoid to regclass
+SELECT n.nspname, n.nspowner, d.description
+FROM pg_catalog.pg_namespace n
+         LEFT OUTER JOIN pg_catalog.pg_description d ON d.objoid = n.oid AND d.objsubid = 0
+WHERE n.nspname IN ('pg_db', 'pg_catalog')
+ORDER BY nspname;
+
+-- DBeaver loads the data types of the schema
+SELECT t.typname, t.typlen
+FROM pg_catalog.pg_type t
+         JOIN pg_catalog.pg_namespace n ON t.typnamespace = n.oid
+WHERE n.nspname = 'pg_catalog'
+  AND t.typname IN ('int8', 'varchar', 'timestamp')
+ORDER BY t.typname;
+
+drop table pg_catalog_test;
+
+use public;
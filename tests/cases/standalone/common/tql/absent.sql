@@ -0,0 +1,27 @@
+CREATE TABLE absent_test(ts TIMESTAMP(3) TIME INDEX, host STRING PRIMARY KEY, val DOUBLE);
+
+INSERT INTO absent_test VALUES (0, 'a', 1), (10000, 'a', 2), (20000, 'a', 3), (30000, 'a', 4);
+
+-- series exists at every step
+TQL EVAL (0, 30, '10s') absent(absent_test{host="a"});
+
+-- SQLNESS SORT_RESULT 3 1
+-- labels are inferred from equality matchers
+TQL EVAL (0, 30, '10s') absent(absent_test{host="b"});
+
+-- SQLNESS SORT_RESULT 3 1
+-- regex matchers don't contribute labels
+TQL EVAL (0, 30, '10s') absent(absent_test{host=~"b.*"});
+
+-- SQLNESS SORT_RESULT 3 1
+-- label appears in multiple equality matchers is dropped
+TQL EVAL (0, 30, '10s') absent(absent_test{host="b", host="c"});
+
+-- the last sample at 30s is out of lookback at 400s
+TQL EVAL (0, 400, '100s') absent(absent_test{host="a"});
+
+-- SQLNESS SORT_RESULT 3 1
+-- metric that doesn't exist
+TQL EVAL (0, 30, '10s') absent(absent_test_not_exist{job="foo"});
+
+DROP TABLE absent_test;
@@ -0,0 +1,17 @@
+CREATE TABLE at_test(ts TIMESTAMP(3) TIME INDEX, host STRING PRIMARY KEY, val DOUBLE);
+
+INSERT INTO at_test VALUES (0, 'a', 1), (10000, 'a', 2), (20000, 'a', 3), (30000, 'a', 4), (0, 'b', 10), (10000, 'b', 20), (20000, 'b', 30), (30000, 'b', 40);
+
+-- pinned at 20s, every step returns the sample at 20s
+TQL EVAL (0, 30, '10s') at_test @ 20;
+
+-- pinned at 20s and shifted back by 10s, every step returns the sample at 10s
+TQL EVAL (0, 30, '10s') at_test @ 20 offset 10s;
+
+-- end() is 30s, shifted back by 10s to the sample at 20s
+TQL EVAL (0, 30, '10s') at_test @ end() offset 10s;
+
+-- start() is 0s, negative offset looks forward to the sample at 10s
+TQL EVAL (0, 30, '10s') at_test{host="a"} @ start() offset -10s;
+
+DROP TABLE at_test;
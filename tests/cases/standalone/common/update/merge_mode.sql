@@ -0,0 +1,44 @@
+CREATE TABLE last_row_monitor (host STRING, ts TIMESTAMP, cpu DOUBLE, memory DOUBLE, TIME INDEX (ts), PRIMARY KEY(host));
+
+CREATE TABLE last_non_null_monitor (host STRING, ts TIMESTAMP, cpu DOUBLE, memory DOUBLE, TIME INDEX (ts), PRIMARY KEY(host)) WITH ('merge_mode'='last_non_null');
+
+INSERT INTO last_row_monitor(host, ts, cpu, memory) VALUES ('host1', 1, 10, 1024), ('host2', 1, 10, 1024);
+
+INSERT INTO last_row_monitor(host, ts, cpu) VALUES ('host1', 1, 20);
+
+INSERT INTO last_row_monitor(host, ts, memory) VALUES ('host2', 1, 2048);
+
+SELECT host, ts, cpu, memory FROM last_row_monitor ORDER BY host;
+
+INSERT INTO last_non_null_monitor(host, ts, cpu, memory) VALUES ('host1', 1, 10, 1024), ('host2', 1, 10, 1024);
+
+INSERT INTO last_non_null_monitor(host, ts, cpu) VALUES ('host1', 1, 20);
+
+INSERT INTO last_non_null_monitor(host, ts, memory) VALUES ('host2', 1, 2048);
+
+SELECT host, ts, cpu, memory FROM last_non_null_monitor ORDER BY host;
+
+DELETE FROM last_non_null_monitor WHERE host = 'host1';
+
+INSERT INTO last_non_null_monitor(host, ts, cpu) VALUES ('host1', 1, 30);
+
+SELECT host, ts, cpu, memory FROM last_non_null_monitor ORDER BY host;
+
+UPDATE last_non_null_monitor SET memory = NULL WHERE host = 'host2';
+
+UPDATE last_non_null_monitor SET memory = NULLIF(memory, memory) WHERE host = 'host2';
+
+-- memory of host1 is NULL
+UPDATE last_non_null_monitor SET cpu = memory;
+
+UPDATE last_non_null_monitor SET cpu = memory WHERE host = 'host2';
+
+SELECT host, ts, cpu, memory FROM last_non_null_monitor ORDER BY host;
+
+UPDATE last_row_monitor SET memory = NULL WHERE host = 'host2';
+
+SELECT host, ts, cpu, memory FROM last_row_monitor ORDER BY host;
+
+DROP TABLE last_row_monitor;
+
+DROP TABLE last_non_null_monitor;
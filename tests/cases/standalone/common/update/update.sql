@@ -0,0 +1,21 @@
+CREATE TABLE monitor (host STRING, ts TIMESTAMP, cpu DOUBLE, memory DOUBLE, TIME INDEX (ts), PRIMARY KEY(host));
+
+INSERT INTO monitor(ts, host, cpu, memory) VALUES
+(1655276557000, 'host1', 66.6, 1024),
+(1655276557000, 'host2', 66.6, 1024),
+(1655276558000, 'host1', 77.7, 2048),
+(1655276558000, 'host2', 77.7, 2048);
+
+UPDATE monitor SET cpu = cpu * 2 WHERE host = 'host1';
+
+UPDATE monitor SET memory = 0 WHERE ts = 1655276558000::timestamp;
+
+UPDATE monitor SET cpu = 0 WHERE host = 'host3';
+
+SELECT ts, host, cpu, memory FROM monitor ORDER BY ts, host;
+
+UPDATE monitor SET host = 'host3' WHERE host = 'host1';
+
+UPDATE monitor SET ts = 0 WHERE host = 'host1';
+
+DROP TABLE monitor;
@@ -0,0 +1,19 @@
+CREATE TABLE monitor (ts TIMESTAMP TIME INDEX, host STRING PRIMARY KEY, cpu DOUBLE);
+
+CREATE FLOW cpu_flow SINK TO cpu_5m AS SELECT ts, host, avg(cpu) RANGE '5m' FROM monitor ALIGN '5m' BY (host);
+
+CREATE FLOW cpu_flow SINK TO cpu_5m AS SELECT ts, host, avg(cpu) RANGE '5m' FROM monitor ALIGN '5m' BY (host);
+
+CREATE FLOW IF NOT EXISTS cpu_flow SINK TO cpu_5m AS SELECT ts, host, avg(cpu) RANGE '5m' FROM monitor ALIGN '5m' BY (host);
+
+CREATE FLOW invalid_flow SINK TO cpu_sum AS SELECT host, sum(cpu) FROM monitor GROUP BY host;
+
+CREATE FLOW invalid_flow SINK TO cpu_sum AS SELECT host, avg(cpu) RANGE '5m' FROM monitor ALIGN '5m' BY (host);
+
+DROP FLOW cpu_flow;
+
+DROP FLOW cpu_flow;
+
+DROP FLOW IF EXISTS cpu_flow;
+
+DROP TABLE monitor;
@@ -0,0 +1,24 @@
+CREATE TABLE monitor (ts TIMESTAMP TIME INDEX, host STRING PRIMARY KEY, cpu DOUBLE);
+
+CREATE FLOW cpu_flow SINK TO cpu_max AS SELECT ts, host, max(cpu) RANGE '5s' AS max_cpu FROM monitor ALIGN '5s' BY (host);
+
+INSERT INTO monitor VALUES (0, 'a', 1.0), (1000, 'a', 2.0), (5000, 'a', 3.0), (5000, 'b', 4.0);
+
+EVALUATE FLOW cpu_flow;
+
+SELECT ts, host, max_cpu FROM cpu_max ORDER BY host, ts;
+
+-- Windows until now are complete, late rows of them are not reflected in the sink table
+INSERT INTO monitor VALUES (4000, 'a', 10.0), (12000, 'b', 5.0);
+
+EVALUATE FLOW cpu_flow;
+
+SELECT ts, host, max_cpu FROM cpu_max ORDER BY host, ts;
+
+EVALUATE FLOW unknown_flow;
+
+DROP FLOW cpu_flow;
+
+DROP TABLE cpu_max;
+
+DROP TABLE monitor;